@@ -1,4 +1,4 @@
-use traits::IndexView;
+use traits::{IndexView, PartialEq};
 
 use box::BoxTrait;
 use gas::withdraw_gas;
@@ -16,10 +16,29 @@ extern fn array_snapshot_pop_back<T>(ref arr: @Array<T>) -> Option<Box<@T>> nopa
 extern fn array_get<T>(
     arr: @Array<T>, index: usize
 ) -> Option<Box<@T>> implicits(RangeCheck) nopanic;
+extern fn array_get_usize<T>(
+    arr: @Array<T>, index: usize
+) -> Option<@T> implicits(RangeCheck) nopanic;
 extern fn array_slice<T>(
     arr: @Array<T>, start: usize, length: usize
 ) -> Option<@Array<T>> implicits(RangeCheck) nopanic;
+extern fn array_extend_from_span<T>(
+    ref arr: Array<T>, span: @Array<T>
+) -> Option<()> implicits(RangeCheck, GasBuiltin) nopanic;
+extern fn span_eq<T>(
+    lhs: @Array<T>, rhs: @Array<T>
+) -> SpanEqResult implicits(RangeCheck, GasBuiltin) nopanic;
+extern fn span_binary_search(
+    span: @Array<u128>, value: u128
+) -> BinarySearchResult implicits(RangeCheck, GasBuiltin) nopanic;
+extern fn span_sort_verified(
+    span: @Array<u128>
+) -> Option<Array<u128>> implicits(RangeCheck, GasBuiltin) nopanic;
 extern fn array_len<T>(arr: @Array<T>) -> usize nopanic;
+/// Returns whether the length of the array exceeds `max_len`, using a single bounds check.
+extern fn array_len_exceeds<T, const max_len: felt252>(
+    arr: @Array<T>
+) -> bool implicits(RangeCheck) nopanic;
 
 #[generate_trait]
 impl ArrayImpl<T> of ArrayTrait<T> {
@@ -53,6 +72,13 @@ impl ArrayImpl<T> of ArrayTrait<T> {
         array_at(self, index).unbox()
     }
     #[inline(always)]
+    fn try_at(self: @Array<T>, index: usize) -> Option<@T> {
+        array_get_usize(self, index)
+    }
+    fn append_span<impl TCopy: Copy<T>, impl TDrop: Drop<T>>(ref self: Array<T>, span: Span<T>) {
+        array_extend_from_span(ref self, span.snapshot).expect('Out of gas');
+    }
+    #[inline(always)]
     fn len(self: @Array<T>) -> usize {
         array_len(self)
     }
@@ -124,6 +150,13 @@ struct Span<T> {
 impl SpanCopy<T> of Copy<Span<T>>;
 impl SpanDrop<T> of Drop<Span<T>>;
 
+/// Returns a snapshot of the element at `index` of the span, or `None` if it is out of bounds.
+/// The bounds check is done by `array_get_usize`, using a single range check.
+#[inline(always)]
+fn span_at<T>(span: Span<T>, index: usize) -> Option<@T> {
+    array_get_usize(span.snapshot, index)
+}
+
 impl SpanSerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Span<T>> {
     fn serialize(self: @Span<T>, ref output: Array<felt252>) {
         (*self).len().serialize(ref output);
@@ -168,6 +201,10 @@ impl SpanImpl<T> of SpanTrait<T> {
         array_at(self.snapshot, index).unbox()
     }
     #[inline(always)]
+    fn try_at(self: Span<T>, index: usize) -> Option<@T> {
+        span_at(self, index)
+    }
+    #[inline(always)]
     fn slice(self: Span<T>, start: usize, length: usize) -> Span<T> {
         Span { snapshot: array_slice(self.snapshot, start, length).expect('Index out of bounds') }
     }
@@ -181,6 +218,75 @@ impl SpanImpl<T> of SpanTrait<T> {
     }
 }
 
+/// The result of comparing the content of two spans using `span_eq`.
+enum SpanEqResult {
+    Equal: (),
+    NotEqual: (),
+    OutOfGas: (),
+}
+
+/// The result of searching a sorted span using `span_binary_search`.
+enum BinarySearchResult {
+    Found: usize,
+    NotFound: usize,
+    OutOfGas: (),
+}
+
+#[generate_trait]
+impl U128SpanImpl of U128SpanTrait {
+    /// Searches for `value` in a span sorted in ascending order.
+    /// Returns `Result::Ok` with the index of an element equal to `value`, or `Result::Err` with
+    /// the index at which `value` could be inserted while keeping the span sorted.
+    fn binary_search(self: Span<u128>, value: u128) -> Result<usize, usize> {
+        match span_binary_search(self.snapshot, value) {
+            BinarySearchResult::Found(index) => Result::Ok(index),
+            BinarySearchResult::NotFound(index) => Result::Err(index),
+            BinarySearchResult::OutOfGas(_) => panic_with_felt252('Out of gas'),
+        }
+    }
+    /// Returns a new array with the elements of the span, sorted in ascending order.
+    /// The sorting is done by a hint, and only its result is verified, at a linear cost.
+    fn sorted(self: Span<u128>) -> Array<u128> {
+        match span_sort_verified(self.snapshot) {
+            Option::Some(arr) => arr,
+            Option::None(_) => panic_with_felt252('Out of gas'),
+        }
+    }
+}
+
+#[generate_trait]
+impl U128ArrayImpl of U128ArrayTrait {
+    /// Sorts the array in ascending order.
+    fn sort(ref self: Array<u128>) {
+        self = self.span().sorted();
+    }
+}
+
+impl SpanFelt252PartialEq of PartialEq<Span<felt252>> {
+    fn eq(lhs: @Span<felt252>, rhs: @Span<felt252>) -> bool {
+        match span_eq(*lhs.snapshot, *rhs.snapshot) {
+            SpanEqResult::Equal(_) => true,
+            SpanEqResult::NotEqual(_) => false,
+            SpanEqResult::OutOfGas(_) => panic_with_felt252('Out of gas'),
+        }
+    }
+    #[inline(always)]
+    fn ne(lhs: @Span<felt252>, rhs: @Span<felt252>) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+impl ArrayFelt252PartialEq of PartialEq<Array<felt252>> {
+    #[inline(always)]
+    fn eq(lhs: @Array<felt252>, rhs: @Array<felt252>) -> bool {
+        lhs.span() == rhs.span()
+    }
+    #[inline(always)]
+    fn ne(lhs: @Array<felt252>, rhs: @Array<felt252>) -> bool {
+        !(lhs == rhs)
+    }
+}
+
 impl SpanIndex<T> of IndexView<Span<T>, usize, @T> {
     #[inline(always)]
     fn index(self: @Span<T>, index: usize) -> @T {
@@ -0,0 +1,8 @@
+/// Packs `fields`, a tuple of unsigned integers, into a single felt252. The first field is stored
+/// in the lowest bits, and each following field right above the previous one. The total width of
+/// the fields must be at most 251 bits.
+extern fn bitfield_pack<T>(fields: T) -> felt252 nopanic;
+
+/// Unpacks `packed` into `T`, a tuple of unsigned integers, in the layout of `bitfield_pack`.
+/// Returns `None` if `packed` doesn't fit in the total width of the fields.
+extern fn bitfield_unpack<T>(packed: felt252) -> Option<T> implicits(RangeCheck) nopanic;
@@ -0,0 +1,82 @@
+// Applies the Blake2s compression function to the message block `m0, ..., m15`, that is not the
+// last block of the message, using the Bitwise builtin. `h0, ..., h7` is the state, and
+// `byte_count` is the number of bytes of the message compressed so far, including this block.
+// Returns the updated state.
+//
+// Only the compression is applied - padding the message and initializing the state with the
+// parameter block are left to the caller. Messages of `2^32` bytes or more are not supported.
+extern fn blake2s_compress(
+    h0: u32,
+    h1: u32,
+    h2: u32,
+    h3: u32,
+    h4: u32,
+    h5: u32,
+    h6: u32,
+    h7: u32,
+    byte_count: u32,
+    m0: u32,
+    m1: u32,
+    m2: u32,
+    m3: u32,
+    m4: u32,
+    m5: u32,
+    m6: u32,
+    m7: u32,
+    m8: u32,
+    m9: u32,
+    m10: u32,
+    m11: u32,
+    m12: u32,
+    m13: u32,
+    m14: u32,
+    m15: u32
+) -> (
+    u32,
+    u32,
+    u32,
+    u32,
+    u32,
+    u32,
+    u32,
+    u32
+) implicits(Bitwise) nopanic;
+
+// Same as `blake2s_compress`, for the last block of the message. The words of the returned state
+// are the digest, in little-endian order.
+extern fn blake2s_finalize(
+    h0: u32,
+    h1: u32,
+    h2: u32,
+    h3: u32,
+    h4: u32,
+    h5: u32,
+    h6: u32,
+    h7: u32,
+    byte_count: u32,
+    m0: u32,
+    m1: u32,
+    m2: u32,
+    m3: u32,
+    m4: u32,
+    m5: u32,
+    m6: u32,
+    m7: u32,
+    m8: u32,
+    m9: u32,
+    m10: u32,
+    m11: u32,
+    m12: u32,
+    m13: u32,
+    m14: u32,
+    m15: u32
+) -> (
+    u32,
+    u32,
+    u32,
+    u32,
+    u32,
+    u32,
+    u32,
+    u32
+) implicits(Bitwise) nopanic;
@@ -0,0 +1,184 @@
+use array::{ArrayTrait, SpanTrait};
+use box::BoxTrait;
+use bytes_31::{
+    bytes31, bytes31_append_u8, bytes31_const, bytes31_split_u8, Bytes31IntoFelt252,
+    Felt252TryIntoBytes31
+};
+use clone::Clone;
+use option::OptionTrait;
+use serde::Serde;
+use traits::{Into, TryInto};
+
+/// The number of bytes in a full word of a `ByteArray`.
+const BYTES_IN_BYTES31: usize = 31;
+
+/// A dynamic string of bytes.
+///
+/// The bytes are stored as full words of 31 bytes in `data`, followed by a partial word of
+/// `pending_word_len` bytes, where `pending_word_len < 31`. Within a word, the first byte is the
+/// most significant one.
+#[derive(Drop, Clone, Serde)]
+struct ByteArray {
+    data: Array<bytes31>,
+    pending_word: bytes31,
+    pending_word_len: usize,
+}
+
+impl ByteArrayDefault of Default<ByteArray> {
+    #[inline(always)]
+    fn default() -> ByteArray {
+        ByteArrayTrait::new()
+    }
+}
+
+#[generate_trait]
+impl ByteArrayImpl of ByteArrayTrait {
+    /// Creates an empty byte array.
+    fn new() -> ByteArray {
+        ByteArray {
+            data: ArrayTrait::new(), pending_word: bytes31_const::<0>(), pending_word_len: 0
+        }
+    }
+    /// Returns the number of bytes in the byte array.
+    fn len(self: @ByteArray) -> usize {
+        self.data.len() * BYTES_IN_BYTES31 + *self.pending_word_len
+    }
+    /// Appends a single byte to the end of the byte array.
+    fn append_byte(ref self: ByteArray, byte: u8) {
+        // A word of less than 31 bytes is smaller than 2**240, so the byte always fits.
+        let word = bytes31_append_u8(self.pending_word, byte).unwrap();
+        if self.pending_word_len == BYTES_IN_BYTES31 - 1 {
+            self.data.append(word);
+            self.pending_word = bytes31_const::<0>();
+            self.pending_word_len = 0;
+        } else {
+            self.pending_word = word;
+            self.pending_word_len = self.pending_word_len + 1;
+        }
+    }
+    /// Appends the `len` bytes of `word` to the end of the byte array, where `len <= 31`.
+    /// Panics if `word` does not fit in `len` bytes.
+    fn append_word(ref self: ByteArray, word: felt252, len: usize) {
+        assert(len <= BYTES_IN_BYTES31, 'word longer than 31 bytes');
+        let word: bytes31 = word.try_into().expect('word longer than 31 bytes');
+        if len == BYTES_IN_BYTES31 && self.pending_word_len == 0 {
+            self.data.append(word);
+            return;
+        }
+        let mut bytes = split_lowest_bytes(word, len);
+        loop {
+            match bytes.pop_back() {
+                Option::Some(byte) => self.append_byte(*byte),
+                Option::None(_) => {
+                    break ();
+                },
+            };
+        };
+    }
+    /// Appends the bytes of `other` to the end of the byte array.
+    fn append(ref self: ByteArray, other: @ByteArray) {
+        if self.pending_word_len == 0 {
+            self.data.append_span(other.data.span());
+            self.pending_word = *other.pending_word;
+            self.pending_word_len = *other.pending_word_len;
+            return;
+        }
+        let mut words = other.data.span();
+        loop {
+            match words.pop_front() {
+                Option::Some(word) => self.append_word((*word).into(), BYTES_IN_BYTES31),
+                Option::None(_) => {
+                    break ();
+                },
+            };
+        };
+        self.append_word((*other.pending_word).into(), *other.pending_word_len);
+    }
+    /// Returns a new byte array holding the bytes of `left` followed by the bytes of `right`.
+    fn concat(left: @ByteArray, right: @ByteArray) -> ByteArray {
+        let mut result = left.clone();
+        result.append(right);
+        result
+    }
+    /// Returns the byte at `index`, or `None` if `index` is out of bounds.
+    fn at(self: @ByteArray, index: usize) -> Option<u8> {
+        let word_index = index / BYTES_IN_BYTES31;
+        let index_in_word = index % BYTES_IN_BYTES31;
+        if word_index == self.data.len() {
+            let pending_word_len = *self.pending_word_len;
+            if index_in_word >= pending_word_len {
+                return Option::None(());
+            }
+            return Option::Some(
+                byte_at_from_lowest(*self.pending_word, pending_word_len - 1 - index_in_word)
+            );
+        }
+        let word = *self.data.get(word_index)?.unbox();
+        Option::Some(byte_at_from_lowest(word, BYTES_IN_BYTES31 - 1 - index_in_word))
+    }
+}
+
+impl ByteArrayAdd of Add<ByteArray> {
+    #[inline(always)]
+    fn add(lhs: ByteArray, rhs: ByteArray) -> ByteArray {
+        ByteArrayTrait::concat(@lhs, @rhs)
+    }
+}
+
+impl ByteArrayPartialEq of PartialEq<ByteArray> {
+    fn eq(lhs: @ByteArray, rhs: @ByteArray) -> bool {
+        if *lhs.pending_word_len != *rhs.pending_word_len
+            || *lhs.pending_word != *rhs.pending_word
+            || lhs.data.len() != rhs.data.len() {
+            return false;
+        }
+        let mut lhs_words = lhs.data.span();
+        let mut rhs_words = rhs.data.span();
+        loop {
+            match lhs_words.pop_front() {
+                Option::Some(word) => {
+                    if *word != *rhs_words.pop_front().unwrap() {
+                        break false;
+                    }
+                },
+                Option::None(_) => {
+                    break true;
+                },
+            };
+        }
+    }
+    #[inline(always)]
+    fn ne(lhs: @ByteArray, rhs: @ByteArray) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+/// Splits the `len` lowest bytes off `word`, returning them from the lowest to the highest.
+/// Panics if `word` does not fit in `len` bytes.
+fn split_lowest_bytes(mut word: bytes31, mut len: usize) -> Span<u8> {
+    let mut bytes = ArrayTrait::new();
+    loop {
+        if len == 0 {
+            break ();
+        }
+        let (rest, byte) = bytes31_split_u8(word);
+        bytes.append(byte);
+        word = rest;
+        len = len - 1;
+    };
+    let rest: felt252 = word.into();
+    assert(rest == 0, 'word longer than given length');
+    bytes.span()
+}
+
+/// Returns the byte of `word` at `index_from_lowest`, counting from its least significant byte.
+fn byte_at_from_lowest(mut word: bytes31, mut index_from_lowest: usize) -> u8 {
+    loop {
+        let (rest, byte) = bytes31_split_u8(word);
+        if index_from_lowest == 0 {
+            break byte;
+        }
+        word = rest;
+        index_from_lowest = index_from_lowest - 1;
+    }
+}
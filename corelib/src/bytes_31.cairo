@@ -0,0 +1,51 @@
+use traits::{Into, TryInto};
+use option::OptionTrait;
+use serde::Serde;
+
+/// A word of at most 31 bytes - a value smaller than 2**248.
+#[derive(Copy, Drop)]
+extern type bytes31;
+
+extern fn bytes31_const<const value: felt252>() -> bytes31 nopanic;
+extern fn bytes31_to_felt252(value: bytes31) -> felt252 nopanic;
+extern fn bytes31_try_from_felt252(value: felt252) -> Option<bytes31> implicits(RangeCheck) nopanic;
+
+/// Appends `byte` to `value` as its new lowest byte, returning `value * 256 + byte`.
+/// Returns `None` if the result does not fit in 31 bytes.
+extern fn bytes31_append_u8(
+    value: bytes31, byte: u8
+) -> Option<bytes31> implicits(RangeCheck) nopanic;
+
+/// Splits the lowest byte off `value`, returning `(value / 256, value % 256)`.
+extern fn bytes31_split_u8(value: bytes31) -> (bytes31, u8) implicits(RangeCheck) nopanic;
+
+impl Bytes31IntoFelt252 of Into<bytes31, felt252> {
+    fn into(self: bytes31) -> felt252 {
+        bytes31_to_felt252(self)
+    }
+}
+impl Felt252TryIntoBytes31 of TryInto<felt252, bytes31> {
+    fn try_into(self: felt252) -> Option<bytes31> {
+        bytes31_try_from_felt252(self)
+    }
+}
+
+impl Bytes31Serde of Serde<bytes31> {
+    fn serialize(self: @bytes31, ref output: Array<felt252>) {
+        bytes31_to_felt252(*self).serialize(ref output);
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<bytes31> {
+        bytes31_try_from_felt252(Serde::<felt252>::deserialize(ref serialized)?)
+    }
+}
+
+impl Bytes31PartialEq of PartialEq<bytes31> {
+    #[inline(always)]
+    fn eq(lhs: @bytes31, rhs: @bytes31) -> bool {
+        bytes31_to_felt252(*lhs) == bytes31_to_felt252(*rhs)
+    }
+    #[inline(always)]
+    fn ne(lhs: @bytes31, rhs: @bytes31) -> bool {
+        !(lhs == rhs)
+    }
+}
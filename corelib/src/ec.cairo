@@ -1,7 +1,8 @@
 //! This module contains functions and constructs related to elliptic curve operations on the Stark
 //! curve.
 
-use array::ArrayTrait;
+use array::{ArrayTrait, Span};
+use gas::BuiltinCosts;
 use zeroable::IsZeroResult;
 use traits::Into;
 
@@ -54,9 +55,29 @@ fn ec_point_from_x(x: felt252) -> Option<EcPoint> {
     }
 }
 
+/// Creates an EC point from its x coordinate and the parity of its y coordinate (as an integer in
+/// `[0, PRIME)`), as in compressed point encodings.
+/// Returns `None` if no point on the curve has the given x coordinate.
+extern fn ec_point_from_x_parity_nz(
+    x: felt252, y_parity: bool
+) -> Option<NonZeroEcPoint> implicits(RangeCheck) nopanic;
+
+#[inline(always)]
+fn ec_point_from_x_parity(x: felt252, y_parity: bool) -> Option<EcPoint> {
+    match ec_point_from_x_parity_nz(:x, :y_parity) {
+        Option::Some(pt) => Option::Some(pt.into()),
+        Option::None(()) => Option::None(()),
+    }
+}
+
 extern fn ec_point_unwrap(p: NonZeroEcPoint) -> (felt252, felt252) nopanic;
 /// Computes the negation of an elliptic curve point (-p).
 extern fn ec_neg(p: EcPoint) -> EcPoint nopanic;
+/// Computes the sum of two elliptic curve points (p + q), any of which may be the zero point.
+extern fn ec_add(p: EcPoint, q: EcPoint) -> EcPoint nopanic;
+/// Computes the double of a non-zero elliptic curve point (2p), using the slope of the tangent at
+/// the point. The double of a non-zero point on the Stark curve is never the zero point.
+extern fn ec_point_double(p: NonZeroEcPoint) -> NonZeroEcPoint nopanic;
 /// Checks whether the given `EcPoint` is the zero point.
 extern fn ec_point_is_zero(p: EcPoint) -> IsZeroResult<EcPoint> nopanic;
 
@@ -105,26 +126,24 @@ fn ec_mul(p: EcPoint, m: felt252) -> EcPoint {
     }
 }
 
+extern fn ec_msm(
+    costs: BuiltinCosts, terms: @Array<(felt252, NonZeroEcPoint)>
+) -> Option<EcPoint> implicits(RangeCheck, GasBuiltin, EcOp) nopanic;
+
+/// Computes the multi-scalar multiplication `m_1 * p_1 + ... + m_n * p_n` of the `(m, p)` terms,
+/// accumulating all of them into a single EC state.
+fn ec_multi_mul(terms: Span<(felt252, NonZeroEcPoint)>) -> EcPoint {
+    match ec_msm(get_builtin_costs(), terms.snapshot) {
+        Option::Some(p) => p,
+        Option::None(()) => panic_with_felt252('Out of gas'),
+    }
+}
+
 impl EcPointAdd of Add<EcPoint> {
     /// Computes the sum of two points on the curve.
-    // TODO(lior): Implement using a libfunc to make it more efficient.
+    #[inline(always)]
     fn add(lhs: EcPoint, rhs: EcPoint) -> EcPoint {
-        let lhs_nz = match ec_point_is_zero(lhs) {
-            IsZeroResult::Zero(()) => {
-                return rhs;
-            },
-            IsZeroResult::NonZero(pt) => pt,
-        };
-        let rhs_nz = match ec_point_is_zero(rhs) {
-            IsZeroResult::Zero(()) => {
-                return lhs;
-            },
-            IsZeroResult::NonZero(pt) => pt,
-        };
-        let mut state = ec_state_init();
-        ec_state_add(ref state, lhs_nz);
-        ec_state_add(ref state, rhs_nz);
-        ec_state_finalize(state)
+        ec_add(lhs, rhs)
     }
 }
 
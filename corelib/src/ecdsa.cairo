@@ -6,6 +6,18 @@ use ec::{
     ec_state_try_finalize_nz
 };
 
+extern type Ecdsa;
+
+// Verifies that (`signature_r`, `signature_s`) is a valid ECDSA signature for the given
+// `public_key` on the given `message_hash`, using the ECDSA signature builtin.
+//
+// Unlike `check_ecdsa_signature`, an invalid signature is not reported to the caller - it makes
+// the builtin reject the run, so this should only be used for signatures which must be valid.
+// Not available in Starknet contracts, as the Starknet OS does not support the ECDSA builtin.
+extern fn ecdsa_verify(
+    message_hash: felt252, public_key: felt252, signature_r: felt252, signature_s: felt252
+) implicits(Ecdsa) nopanic;
+
 // Checks if (`signature_r`, `signature_s`) is a valid ECDSA signature for the given `public_key`
 // on the given `message`.
 //
@@ -0,0 +1,183 @@
+//! This module contains functions and constructs related to point arithmetic on the Ed25519 curve -
+//! the twisted Edwards curve `-x^2 + y^2 = 1 + d*x^2*y^2` over the field of the prime
+//! `2^255 - 19`, used by Ed25519 signatures.
+//!
+//! The field elements are `u256`s in `[0, 2^255 - 19)`. Products are reduced with
+//! `u512_safe_div_rem_by_u256`, whose result is computed by a hint and verified by the libfunc.
+
+use integer::{u256_wide_mul, u512_safe_div_rem_by_u256};
+use math::inv_mod;
+use option::OptionTrait;
+use traits::TryInto;
+
+/// A point on the Ed25519 curve, in affine coordinates.
+#[derive(Copy, Drop, PartialEq, Serde)]
+struct Ed25519Point {
+    x: u256,
+    y: u256,
+}
+
+/// A point on the Ed25519 curve in projective coordinates `(x : y : z)`, standing for the affine
+/// point `(x / z, y / z)`. Used for chains of additions, to avoid an inversion per addition.
+#[derive(Copy, Drop)]
+struct ProjectivePoint {
+    x: u256,
+    y: u256,
+    z: u256,
+}
+
+/// Returns the prime of the field of the curve, `2^255 - 19`.
+fn ed25519_field_prime() -> u256 {
+    0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed
+}
+
+/// Returns the `d` parameter of the curve, `-121665 / 121666` modulo the field prime.
+fn ed25519_d() -> u256 {
+    0x52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3
+}
+
+/// Returns the order of the subgroup generated by the base point.
+fn ed25519_order() -> u256 {
+    0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed
+}
+
+/// Returns the base point of the curve, used by Ed25519 signatures.
+fn ed25519_generator() -> Ed25519Point {
+    Ed25519Point {
+        x: 0x216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a,
+        y: 0x6666666666666666666666666666666666666666666666666666666666666658,
+    }
+}
+
+/// Returns the identity point of the curve, `(0, 1)`.
+fn ed25519_identity() -> Ed25519Point {
+    Ed25519Point { x: 0, y: 1 }
+}
+
+/// Constructs a point from its (x, y) coordinates.
+/// Returns `None` if the coordinates are not reduced modulo the field prime, or if the point is
+/// not on the curve.
+fn ed25519_point_try_new(x: u256, y: u256) -> Option<Ed25519Point> {
+    let prime = ed25519_field_prime();
+    if x >= prime || y >= prime {
+        return Option::None(());
+    }
+    let x_sq = mul_mod(x, x);
+    let y_sq = mul_mod(y, y);
+    if sub_mod(y_sq, x_sq) == add_mod(1, mul_mod(ed25519_d(), mul_mod(x_sq, y_sq))) {
+        Option::Some(Ed25519Point { x, y })
+    } else {
+        Option::None(())
+    }
+}
+
+/// Computes the sum of the points `p0 + p1`.
+fn ed25519_add(p0: Ed25519Point, p1: Ed25519Point) -> Ed25519Point {
+    to_affine(projective_add(from_affine(p0), from_affine(p1)))
+}
+
+/// Computes the product of the point `p` by the scalar `m`.
+fn ed25519_mul(p: Ed25519Point, m: u256) -> Ed25519Point {
+    let mut result = from_affine(ed25519_identity());
+    let mut base = from_affine(p);
+    add_scalar_multiple(ref result, ref base, m.low);
+    add_scalar_multiple(ref result, ref base, m.high);
+    to_affine(result)
+}
+
+impl Ed25519PointAdd of Add<Ed25519Point> {
+    /// Computes the sum of two points on the curve.
+    #[inline(always)]
+    fn add(lhs: Ed25519Point, rhs: Ed25519Point) -> Ed25519Point {
+        ed25519_add(lhs, rhs)
+    }
+}
+
+impl Ed25519PointAddEq of AddEq<Ed25519Point> {
+    #[inline(always)]
+    fn add_eq(ref self: Ed25519Point, other: Ed25519Point) {
+        self = Add::add(self, other);
+    }
+}
+
+/// Adds `m * base` to `result`, and multiplies `base` by `2^128`.
+fn add_scalar_multiple(ref result: ProjectivePoint, ref base: ProjectivePoint, mut m: u128) {
+    let mut n_bits: usize = 128;
+    loop {
+        if n_bits == 0 {
+            break ();
+        }
+        if m % 2 == 1 {
+            result = projective_add(result, base);
+        }
+        base = projective_add(base, base);
+        m = m / 2;
+        n_bits = n_bits - 1;
+    };
+}
+
+/// Computes the sum of two points in projective coordinates.
+///
+/// Uses the addition formula for twisted Edwards curves with `a = -1`, which is complete for
+/// Ed25519 - it holds for any two points, including equal points and the identity.
+fn projective_add(p0: ProjectivePoint, p1: ProjectivePoint) -> ProjectivePoint {
+    let z_prod = mul_mod(p0.z, p1.z);
+    let z_prod_sq = mul_mod(z_prod, z_prod);
+    let x_prod = mul_mod(p0.x, p1.x);
+    let y_prod = mul_mod(p0.y, p1.y);
+    let d_term = mul_mod(mul_mod(ed25519_d(), x_prod), y_prod);
+    let f = sub_mod(z_prod_sq, d_term);
+    let g = add_mod(z_prod_sq, d_term);
+    // `(x0 + y0) * (x1 + y1) - x0 * x1 - y0 * y1 = x0 * y1 + y0 * x1`.
+    let cross = sub_mod(
+        sub_mod(mul_mod(add_mod(p0.x, p0.y), add_mod(p1.x, p1.y)), x_prod), y_prod
+    );
+    ProjectivePoint {
+        x: mul_mod(mul_mod(z_prod, f), cross),
+        y: mul_mod(mul_mod(z_prod, g), add_mod(y_prod, x_prod)),
+        z: mul_mod(f, g),
+    }
+}
+
+#[inline(always)]
+fn from_affine(p: Ed25519Point) -> ProjectivePoint {
+    ProjectivePoint { x: p.x, y: p.y, z: 1 }
+}
+
+fn to_affine(p: ProjectivePoint) -> Ed25519Point {
+    // `z` is never zero, as the addition formula is complete.
+    let z_inv = inv_mod(p.z.try_into().unwrap(), field_prime_nz()).unwrap();
+    Ed25519Point { x: mul_mod(p.x, z_inv), y: mul_mod(p.y, z_inv) }
+}
+
+#[inline(always)]
+fn field_prime_nz() -> NonZero<u256> {
+    ed25519_field_prime().try_into().unwrap()
+}
+
+/// Returns `a + b` modulo the field prime, for reduced `a` and `b`.
+fn add_mod(a: u256, b: u256) -> u256 {
+    let prime = ed25519_field_prime();
+    // Does not overflow, as the prime is smaller than `2^255`.
+    let sum = a + b;
+    if sum >= prime {
+        sum - prime
+    } else {
+        sum
+    }
+}
+
+/// Returns `a - b` modulo the field prime, for reduced `a` and `b`.
+fn sub_mod(a: u256, b: u256) -> u256 {
+    if a >= b {
+        a - b
+    } else {
+        ed25519_field_prime() - b + a
+    }
+}
+
+/// Returns `a * b` modulo the field prime.
+fn mul_mod(a: u256, b: u256) -> u256 {
+    let (_, rem) = u512_safe_div_rem_by_u256(u256_wide_mul(a, b), field_prime_nz());
+    rem
+}
@@ -0,0 +1,82 @@
+use array::{ArrayTrait, SpanTrait};
+
+/// The result of decoding hex digits using `bytes_hex_decode`.
+enum HexDecodeResult {
+    Ok: Array<u8>,
+    Invalid: (),
+    OutOfGas: (),
+}
+
+extern fn bytes_hex_encode(
+    bytes: @Array<u8>
+) -> Option<Array<u8>> implicits(RangeCheck, GasBuiltin) nopanic;
+extern fn bytes_hex_decode(
+    digits: @Array<u8>
+) -> HexDecodeResult implicits(RangeCheck, GasBuiltin) nopanic;
+extern fn bytes_base64_encode(
+    bytes: @Array<u8>
+) -> Option<Array<u8>> implicits(RangeCheck, GasBuiltin) nopanic;
+
+/// Returns the lowercase hex digits of `bytes`, two per byte.
+fn hex_encode(bytes: Span<u8>) -> Array<u8> {
+    match bytes_hex_encode(bytes.snapshot) {
+        Option::Some(digits) => digits,
+        Option::None(_) => panic_with_felt252('Out of gas'),
+    }
+}
+
+/// Returns the bytes of the hex digits `digits`, two per byte, in either case, or `None` if there
+/// is an odd number of digits, or a byte which is not a hex digit.
+fn hex_decode(digits: Span<u8>) -> Option<Array<u8>> {
+    match bytes_hex_decode(digits.snapshot) {
+        HexDecodeResult::Ok(bytes) => Option::Some(bytes),
+        HexDecodeResult::Invalid(_) => Option::None(()),
+        HexDecodeResult::OutOfGas(_) => panic_with_felt252('Out of gas'),
+    }
+}
+
+/// Returns the base64 encoding of `bytes` (RFC 4648), with padding.
+fn base64_encode(bytes: Span<u8>) -> Array<u8> {
+    // The whole 3-byte groups are encoded by the libfunc, and the trailing bytes are encoded here.
+    let mut encoded = base64_encode_groups(bytes);
+    let len = bytes.len();
+    let n_trailing = len % 3;
+    if n_trailing == 1 {
+        let byte0 = *bytes.at(len - 1);
+        encoded.append(base64_char(byte0 / 4));
+        encoded.append(base64_char((byte0 % 4) * 16));
+        encoded.append('=');
+        encoded.append('=');
+    } else if n_trailing == 2 {
+        let byte0 = *bytes.at(len - 2);
+        let byte1 = *bytes.at(len - 1);
+        encoded.append(base64_char(byte0 / 4));
+        encoded.append(base64_char((byte0 % 4) * 16 + byte1 / 16));
+        encoded.append(base64_char((byte1 % 16) * 4));
+        encoded.append('=');
+    }
+    encoded
+}
+
+/// Returns the base64 encoding of the whole 3-byte groups of `bytes`, without the trailing bytes.
+fn base64_encode_groups(bytes: Span<u8>) -> Array<u8> {
+    match bytes_base64_encode(bytes.snapshot) {
+        Option::Some(encoded) => encoded,
+        Option::None(_) => panic_with_felt252('Out of gas'),
+    }
+}
+
+/// Returns the base64 character of a 6-bit value.
+fn base64_char(value: u8) -> u8 {
+    if value < 26 {
+        'A' + value
+    } else if value < 52 {
+        'a' + value - 26
+    } else if value < 62 {
+        '0' + value - 52
+    } else if value == 62 {
+        '+'
+    } else {
+        '/'
+    }
+}
@@ -1,3 +1,5 @@
+use integer::u128_overflowing_sub;
+
 #[derive(Copy, Drop)]
 extern type BuiltinCosts;
 extern type GasBuiltin;
@@ -7,3 +9,17 @@ extern fn withdraw_gas_all(
     costs: BuiltinCosts
 ) -> Option<()> implicits(RangeCheck, GasBuiltin) nopanic;
 extern fn get_builtin_costs() -> BuiltinCosts nopanic;
+/// Returns the value of the gas counter.
+extern fn get_available_gas() -> u128 implicits(GasBuiltin) nopanic;
+/// Returns the amount of gas available, including the gas already withdrawn and not yet used.
+/// The gas consumed between two checkpoints is the difference between them.
+extern fn gas_checkpoint() -> u128 implicits(GasBuiltin) nopanic;
+
+/// Returns the amount of gas consumed since `checkpoint`, a value returned by `gas_checkpoint`.
+fn gas_consumed_since(checkpoint: u128) -> u128 nopanic {
+    match u128_overflowing_sub(checkpoint, gas_checkpoint()) {
+        Result::Ok(consumed) => consumed,
+        // The available gas never grows, so this is only reached for an invalid checkpoint.
+        Result::Err(_) => 0,
+    }
+}
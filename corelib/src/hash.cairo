@@ -1,10 +1,25 @@
 use traits::Into;
+use array::Span;
+use gas::BuiltinCosts;
 use starknet::ContractAddress;
 
 extern type Pedersen;
 
 extern fn pedersen(a: felt252, b: felt252) -> felt252 implicits(Pedersen) nopanic;
 
+extern fn pedersen_hash_span(
+    costs: BuiltinCosts, state: felt252, values: @Array<felt252>
+) -> Option<felt252> implicits(RangeCheck, GasBuiltin, Pedersen) nopanic;
+
+/// Returns the result of hashing the elements of `values` one after the other into `state` - the
+/// same as calling `pedersen(state, value)` for each value, in a single loop.
+fn pedersen_span(state: felt252, values: Span<felt252>) -> felt252 {
+    match pedersen_hash_span(get_builtin_costs(), state, values.snapshot) {
+        Option::Some(result) => result,
+        Option::None(_) => panic_with_felt252('Out of gas'),
+    }
+}
+
 trait LegacyHash<T> {
     fn hash(state: felt252, value: T) -> felt252;
 }
@@ -47,6 +47,16 @@ extern fn u128_overflowing_sub(
     lhs: u128, rhs: u128
 ) -> Result<u128, u128> implicits(RangeCheck) nopanic;
 
+// Unchecked arithmetic - the result is not verified to fit in the type, and is undefined on
+// overflow. Only usable in functions annotated with `#[allow_unchecked_math]`, where each use is
+// reported by the `unchecked_math` lint when enabled. Not allowed in Starknet contracts.
+#[unchecked_math]
+extern fn u128_unchecked_add(lhs: u128, rhs: u128) -> u128 nopanic;
+#[unchecked_math]
+extern fn u128_unchecked_sub(lhs: u128, rhs: u128) -> u128 nopanic;
+#[unchecked_math]
+extern fn u128_unchecked_mul(lhs: u128, rhs: u128) -> u128 nopanic;
+
 fn u128_wrapping_add(lhs: u128, rhs: u128) -> u128 implicits(RangeCheck) nopanic {
     match u128_overflowing_add(lhs, rhs) {
         Result::Ok(x) => x,
@@ -324,6 +334,13 @@ impl U8PartialOrd of PartialOrd<u8> {
 extern fn u8_overflowing_add(lhs: u8, rhs: u8) -> Result<u8, u8> implicits(RangeCheck) nopanic;
 extern fn u8_overflowing_sub(lhs: u8, rhs: u8) -> Result<u8, u8> implicits(RangeCheck) nopanic;
 
+#[unchecked_math]
+extern fn u8_unchecked_add(lhs: u8, rhs: u8) -> u8 nopanic;
+#[unchecked_math]
+extern fn u8_unchecked_sub(lhs: u8, rhs: u8) -> u8 nopanic;
+#[unchecked_math]
+extern fn u8_unchecked_mul(lhs: u8, rhs: u8) -> u8 nopanic;
+
 fn u8_wrapping_add(lhs: u8, rhs: u8) -> u8 implicits(RangeCheck) nopanic {
     match u8_overflowing_add(lhs, rhs) {
         Result::Ok(x) => x,
@@ -521,6 +538,13 @@ impl U16PartialOrd of PartialOrd<u16> {
 extern fn u16_overflowing_add(lhs: u16, rhs: u16) -> Result<u16, u16> implicits(RangeCheck) nopanic;
 extern fn u16_overflowing_sub(lhs: u16, rhs: u16) -> Result<u16, u16> implicits(RangeCheck) nopanic;
 
+#[unchecked_math]
+extern fn u16_unchecked_add(lhs: u16, rhs: u16) -> u16 nopanic;
+#[unchecked_math]
+extern fn u16_unchecked_sub(lhs: u16, rhs: u16) -> u16 nopanic;
+#[unchecked_math]
+extern fn u16_unchecked_mul(lhs: u16, rhs: u16) -> u16 nopanic;
+
 fn u16_wrapping_add(lhs: u16, rhs: u16) -> u16 implicits(RangeCheck) nopanic {
     match u16_overflowing_add(lhs, rhs) {
         Result::Ok(x) => x,
@@ -719,6 +743,13 @@ impl U32PartialOrd of PartialOrd<u32> {
 extern fn u32_overflowing_add(lhs: u32, rhs: u32) -> Result<u32, u32> implicits(RangeCheck) nopanic;
 extern fn u32_overflowing_sub(lhs: u32, rhs: u32) -> Result<u32, u32> implicits(RangeCheck) nopanic;
 
+#[unchecked_math]
+extern fn u32_unchecked_add(lhs: u32, rhs: u32) -> u32 nopanic;
+#[unchecked_math]
+extern fn u32_unchecked_sub(lhs: u32, rhs: u32) -> u32 nopanic;
+#[unchecked_math]
+extern fn u32_unchecked_mul(lhs: u32, rhs: u32) -> u32 nopanic;
+
 fn u32_wrapping_add(lhs: u32, rhs: u32) -> u32 implicits(RangeCheck) nopanic {
     match u32_overflowing_add(lhs, rhs) {
         Result::Ok(x) => x,
@@ -917,6 +948,13 @@ impl U64PartialOrd of PartialOrd<u64> {
 extern fn u64_overflowing_add(lhs: u64, rhs: u64) -> Result<u64, u64> implicits(RangeCheck) nopanic;
 extern fn u64_overflowing_sub(lhs: u64, rhs: u64) -> Result<u64, u64> implicits(RangeCheck) nopanic;
 
+#[unchecked_math]
+extern fn u64_unchecked_add(lhs: u64, rhs: u64) -> u64 nopanic;
+#[unchecked_math]
+extern fn u64_unchecked_sub(lhs: u64, rhs: u64) -> u64 nopanic;
+#[unchecked_math]
+extern fn u64_unchecked_mul(lhs: u64, rhs: u64) -> u64 nopanic;
+
 fn u64_wrapping_add(lhs: u64, rhs: u64) -> u64 implicits(RangeCheck) nopanic {
     match u64_overflowing_add(lhs, rhs) {
         Result::Ok(x) => x,
@@ -1062,6 +1100,52 @@ impl U64BitOr of BitOr<u64> {
     }
 }
 
+#[derive(Copy, Drop)]
+extern type u96;
+impl NumericLiteralu96 of NumericLiteral<u96>;
+extern fn u96_const<value>() -> u96 nopanic;
+extern fn u96_to_felt252(a: u96) -> felt252 nopanic;
+
+#[panic_with('u96_from Overflow', u96_from_felt252)]
+extern fn u96_try_from_felt252(a: felt252) -> Option<u96> implicits(RangeCheck) nopanic;
+
+extern fn u96_eq(lhs: u96, rhs: u96) -> bool implicits() nopanic;
+extern fn u96_is_zero(a: u96) -> IsZeroResult<u96> implicits() nopanic;
+
+#[panic_with('u96 is 0', u96_as_non_zero)]
+fn u96_try_as_non_zero(a: u96) -> Option<NonZero<u96>> nopanic {
+    match u96_is_zero(a) {
+        IsZeroResult::Zero(()) => Option::None(()),
+        IsZeroResult::NonZero(x) => Option::Some(x),
+    }
+}
+
+impl U96TryIntoNonZero of TryInto<u96, NonZero<u96>> {
+    fn try_into(self: u96) -> Option<NonZero<u96>> {
+        u96_try_as_non_zero(self)
+    }
+}
+
+impl U96Serde of Serde<u96> {
+    fn serialize(self: @u96, ref output: Array<felt252>) {
+        Into::<u96, felt252>::into(*self).serialize(ref output);
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<u96> {
+        Option::Some(((*serialized.pop_front()?).try_into())?)
+    }
+}
+
+impl U96PartialEq of PartialEq<u96> {
+    #[inline(always)]
+    fn eq(lhs: @u96, rhs: @u96) -> bool {
+        u96_eq(*lhs, *rhs)
+    }
+    #[inline(always)]
+    fn ne(lhs: @u96, rhs: @u96) -> bool {
+        !(*lhs == *rhs)
+    }
+}
+
 #[derive(Copy, Drop, PartialEq, Serde, storage_access::StorageAccess)]
 struct u256 {
     low: u128,
@@ -1251,6 +1335,46 @@ fn u256_safe_div_rem(lhs: u256, rhs: NonZero<u256>) -> (u256, u256) implicits(Ra
 }
 extern fn u256_sqrt(a: u256) -> u128 implicits(RangeCheck) nopanic;
 
+/// Returns the inverse of `b` modulo `n`, if it exists.
+/// Additionally returns several `U128MulGuarantee`s that are required for validating the calculation.
+extern fn u256_guarantee_inv_mod_n(
+    b: u256, n: NonZero<u256>
+) -> Result<
+    (
+        NonZero<u256>,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee
+    ),
+    (
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee,
+        U128MulGuarantee
+    )
+> implicits(RangeCheck) nopanic;
+
+/// Returns the inverse of `b` modulo `n`, or `None` if `b` is not invertible modulo `n`.
+/// Note that 1 is not invertible modulo 1, as its inverse would be 0.
+#[inline(always)]
+fn u256_inv_mod_n(
+    b: u256, n: NonZero<u256>
+) -> Option<NonZero<u256>> implicits(RangeCheck) nopanic {
+    match u256_guarantee_inv_mod_n(b, n) {
+        Result::Ok((inv, _, _, _, _, _, _, _, _)) => Option::Some(inv),
+        Result::Err((_, _, _, _, _, _, _, _)) => Option::None(()),
+    }
+}
+
 #[panic_with('u256 is 0', u256_as_non_zero)]
 fn u256_try_as_non_zero(a: u256) -> Option<NonZero<u256>> nopanic {
     match u256_is_zero(a) {
@@ -1413,6 +1537,17 @@ impl BoundedU64 of BoundedInt<u64> {
     }
 }
 
+impl BoundedU96 of BoundedInt<u96> {
+    #[inline(always)]
+    fn min() -> u96 nopanic {
+        0_u96
+    }
+    #[inline(always)]
+    fn max() -> u96 nopanic {
+        0xffffffffffffffffffffffff_u96
+    }
+}
+
 impl BoundedU128 of BoundedInt<u128> {
     #[inline(always)]
     fn min() -> u128 nopanic {
@@ -1486,6 +1621,16 @@ impl U128IntoFelt252 of Into<u128, felt252> {
         u128_to_felt252(self)
     }
 }
+impl Felt252TryIntoU96 of TryInto<felt252, u96> {
+    fn try_into(self: felt252) -> Option<u96> {
+        u96_try_from_felt252(self)
+    }
+}
+impl U96IntoFelt252 of Into<u96, felt252> {
+    fn into(self: u96) -> felt252 {
+        u96_to_felt252(self)
+    }
+}
 impl Felt252IntoU256 of Into<felt252, u256> {
     fn into(self: felt252) -> u256 {
         u256_from_felt252(self)
@@ -1534,6 +1679,32 @@ impl I128IntoFelt252 of Into<i128, felt252> {
     }
 }
 
+impl Felt252TryIntoI8 of TryInto<felt252, i8> {
+    fn try_into(self: felt252) -> Option<i8> {
+        i8_try_from_felt252(self)
+    }
+}
+impl Felt252TryIntoI16 of TryInto<felt252, i16> {
+    fn try_into(self: felt252) -> Option<i16> {
+        i16_try_from_felt252(self)
+    }
+}
+impl Felt252TryIntoI32 of TryInto<felt252, i32> {
+    fn try_into(self: felt252) -> Option<i32> {
+        i32_try_from_felt252(self)
+    }
+}
+impl Felt252TryIntoI64 of TryInto<felt252, i64> {
+    fn try_into(self: felt252) -> Option<i64> {
+        i64_try_from_felt252(self)
+    }
+}
+impl Felt252TryIntoI128 of TryInto<felt252, i128> {
+    fn try_into(self: felt252) -> Option<i128> {
+        i128_try_from_felt252(self)
+    }
+}
+
 // TODO(lior): Restrict the function (using traits) in the high-level compiler so that wrong types
 //   will not lead to Sierra errors.
 extern fn upcast<FromType, ToType>(x: FromType) -> ToType nopanic;
@@ -1814,6 +1985,84 @@ impl U256TryIntoU64 of TryInto<u256, u64> {
     }
 }
 
+impl U8IntoU96 of Into<u8, u96> {
+    fn into(self: u8) -> u96 {
+        upcast(self)
+    }
+}
+
+impl U96TryIntoU8 of TryInto<u96, u8> {
+    fn try_into(self: u96) -> Option<u8> {
+        downcast(self)
+    }
+}
+
+impl U16IntoU96 of Into<u16, u96> {
+    fn into(self: u16) -> u96 {
+        upcast(self)
+    }
+}
+
+impl U96TryIntoU16 of TryInto<u96, u16> {
+    fn try_into(self: u96) -> Option<u16> {
+        downcast(self)
+    }
+}
+
+impl U32IntoU96 of Into<u32, u96> {
+    fn into(self: u32) -> u96 {
+        upcast(self)
+    }
+}
+
+impl U96TryIntoU32 of TryInto<u96, u32> {
+    fn try_into(self: u96) -> Option<u32> {
+        downcast(self)
+    }
+}
+
+impl U64IntoU96 of Into<u64, u96> {
+    fn into(self: u64) -> u96 {
+        upcast(self)
+    }
+}
+
+impl U96TryIntoU64 of TryInto<u96, u64> {
+    fn try_into(self: u96) -> Option<u64> {
+        downcast(self)
+    }
+}
+
+impl U96IntoU128 of Into<u96, u128> {
+    fn into(self: u96) -> u128 {
+        upcast(self)
+    }
+}
+
+impl U128TryIntoU96 of TryInto<u128, u96> {
+    fn try_into(self: u128) -> Option<u96> {
+        downcast(self)
+    }
+}
+
+impl U96IntoU256 of Into<u96, u256> {
+    fn into(self: u96) -> u256 {
+        u256 { low: upcast(self), high: 0_u128 }
+    }
+}
+
+impl U256TryIntoU96 of TryInto<u256, u96> {
+    fn try_into(self: u256) -> Option<u96> {
+        let u256{low: low, high: high } = self;
+
+        if high != 0 {
+            return Option::None(());
+        }
+
+        low.try_into()
+    }
+}
+
 impl U128IntoU256 of Into<u128, u256> {
     fn into(self: u128) -> u256 {
         u256 { low: self, high: 0_u128 }
@@ -1924,6 +2173,7 @@ extern type i8;
 impl NumericLiterali8 of NumericLiteral<i8>;
 extern fn i8_const<value>() -> i8 nopanic;
 extern fn i8_to_felt252(a: i8) -> felt252 nopanic;
+extern fn i8_try_from_felt252(a: felt252) -> Option<i8> implicits(RangeCheck) nopanic;
 
 extern fn i8_is_zero(a: i8) -> IsZeroResult<i8> implicits() nopanic;
 extern fn i8_eq(lhs: i8, rhs: i8) -> bool implicits() nopanic;
@@ -1944,6 +2194,7 @@ extern type i16;
 impl NumericLiterali16 of NumericLiteral<i16>;
 extern fn i16_const<value>() -> i16 nopanic;
 extern fn i16_to_felt252(a: i16) -> felt252 nopanic;
+extern fn i16_try_from_felt252(a: felt252) -> Option<i16> implicits(RangeCheck) nopanic;
 
 extern fn i16_is_zero(a: i16) -> IsZeroResult<i16> implicits() nopanic;
 extern fn i16_eq(lhs: i16, rhs: i16) -> bool implicits() nopanic;
@@ -1964,6 +2215,7 @@ extern type i32;
 impl NumericLiterali32 of NumericLiteral<i32>;
 extern fn i32_const<value>() -> i32 nopanic;
 extern fn i32_to_felt252(a: i32) -> felt252 nopanic;
+extern fn i32_try_from_felt252(a: felt252) -> Option<i32> implicits(RangeCheck) nopanic;
 
 extern fn i32_is_zero(a: i32) -> IsZeroResult<i32> implicits() nopanic;
 extern fn i32_eq(lhs: i32, rhs: i32) -> bool implicits() nopanic;
@@ -1984,6 +2236,7 @@ extern type i64;
 impl NumericLiterali64 of NumericLiteral<i64>;
 extern fn i64_const<value>() -> i64 nopanic;
 extern fn i64_to_felt252(a: i64) -> felt252 nopanic;
+extern fn i64_try_from_felt252(a: felt252) -> Option<i64> implicits(RangeCheck) nopanic;
 
 extern fn i64_is_zero(a: i64) -> IsZeroResult<i64> implicits() nopanic;
 extern fn i64_eq(lhs: i64, rhs: i64) -> bool implicits() nopanic;
@@ -2004,6 +2257,7 @@ extern type i128;
 impl NumericLiterali128 of NumericLiteral<i128>;
 extern fn i128_const<value>() -> i128 nopanic;
 extern fn i128_to_felt252(a: i128) -> felt252 nopanic;
+extern fn i128_try_from_felt252(a: felt252) -> Option<i128> implicits(RangeCheck) nopanic;
 
 extern fn i128_is_zero(a: i128) -> IsZeroResult<i128> implicits() nopanic;
 extern fn i128_eq(lhs: i128, rhs: i128) -> bool implicits() nopanic;
@@ -2018,3 +2272,125 @@ impl I128PartialEq of PartialEq<i128> {
         !(*lhs == *rhs)
     }
 }
+
+// Conversions between the signed integer types.
+
+impl I8IntoI16 of Into<i8, i16> {
+    fn into(self: i8) -> i16 {
+        upcast(self)
+    }
+}
+
+impl I16TryIntoI8 of TryInto<i16, i8> {
+    fn try_into(self: i16) -> Option<i8> {
+        downcast(self)
+    }
+}
+
+impl I8IntoI32 of Into<i8, i32> {
+    fn into(self: i8) -> i32 {
+        upcast(self)
+    }
+}
+
+impl I32TryIntoI8 of TryInto<i32, i8> {
+    fn try_into(self: i32) -> Option<i8> {
+        downcast(self)
+    }
+}
+
+impl I8IntoI64 of Into<i8, i64> {
+    fn into(self: i8) -> i64 {
+        upcast(self)
+    }
+}
+
+impl I64TryIntoI8 of TryInto<i64, i8> {
+    fn try_into(self: i64) -> Option<i8> {
+        downcast(self)
+    }
+}
+
+impl I8IntoI128 of Into<i8, i128> {
+    fn into(self: i8) -> i128 {
+        upcast(self)
+    }
+}
+
+impl I128TryIntoI8 of TryInto<i128, i8> {
+    fn try_into(self: i128) -> Option<i8> {
+        downcast(self)
+    }
+}
+
+impl I16IntoI32 of Into<i16, i32> {
+    fn into(self: i16) -> i32 {
+        upcast(self)
+    }
+}
+
+impl I32TryIntoI16 of TryInto<i32, i16> {
+    fn try_into(self: i32) -> Option<i16> {
+        downcast(self)
+    }
+}
+
+impl I16IntoI64 of Into<i16, i64> {
+    fn into(self: i16) -> i64 {
+        upcast(self)
+    }
+}
+
+impl I64TryIntoI16 of TryInto<i64, i16> {
+    fn try_into(self: i64) -> Option<i16> {
+        downcast(self)
+    }
+}
+
+impl I16IntoI128 of Into<i16, i128> {
+    fn into(self: i16) -> i128 {
+        upcast(self)
+    }
+}
+
+impl I128TryIntoI16 of TryInto<i128, i16> {
+    fn try_into(self: i128) -> Option<i16> {
+        downcast(self)
+    }
+}
+
+impl I32IntoI64 of Into<i32, i64> {
+    fn into(self: i32) -> i64 {
+        upcast(self)
+    }
+}
+
+impl I64TryIntoI32 of TryInto<i64, i32> {
+    fn try_into(self: i64) -> Option<i32> {
+        downcast(self)
+    }
+}
+
+impl I32IntoI128 of Into<i32, i128> {
+    fn into(self: i32) -> i128 {
+        upcast(self)
+    }
+}
+
+impl I128TryIntoI32 of TryInto<i128, i32> {
+    fn try_into(self: i128) -> Option<i32> {
+        downcast(self)
+    }
+}
+
+impl I64IntoI128 of Into<i64, i128> {
+    fn into(self: i64) -> i128 {
+        upcast(self)
+    }
+}
+
+impl I128TryIntoI64 of TryInto<i128, i64> {
+    fn try_into(self: i128) -> Option<i64> {
+        downcast(self)
+    }
+}
@@ -3,6 +3,68 @@ use integer::TryInto;
 use option::OptionTrait;
 use starknet::SyscallResultTrait;
 
+extern type Keccak;
+
+// Computes the Keccak-f[1600] permutation of a state of 25 u64 lanes, using the Keccak builtin.
+// Returns the lanes of the permuted state.
+//
+// Unlike `keccak_syscall`, this only applies the permutation - padding the input and absorbing it
+// into the state are left to the caller.
+// Not available in Starknet contracts, as the Starknet OS does not support the Keccak builtin.
+extern fn keccak_permutation(
+    s0: u64,
+    s1: u64,
+    s2: u64,
+    s3: u64,
+    s4: u64,
+    s5: u64,
+    s6: u64,
+    s7: u64,
+    s8: u64,
+    s9: u64,
+    s10: u64,
+    s11: u64,
+    s12: u64,
+    s13: u64,
+    s14: u64,
+    s15: u64,
+    s16: u64,
+    s17: u64,
+    s18: u64,
+    s19: u64,
+    s20: u64,
+    s21: u64,
+    s22: u64,
+    s23: u64,
+    s24: u64
+) -> (
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64,
+    u64
+) implicits(RangeCheck, Keccak) nopanic;
+
 const KECCAK_FULL_RATE_IN_BYTES: usize = 136;
 const KECCAK_FULL_RATE_IN_U64S: usize = 17;
 const BYTES_IN_U64_WORD: usize = 8;
@@ -94,6 +94,7 @@ extern fn bool_to_felt252(a: bool) -> felt252 implicits() nopanic;
 
 // General purpose implicits.
 extern type RangeCheck;
+extern type RangeCheck96;
 extern type SegmentArena;
 
 // felt252.
@@ -162,6 +163,10 @@ impl Felt252Neg of Neg<felt252> {
 
 extern fn felt252_div(lhs: felt252, rhs: NonZero<felt252>) -> felt252 nopanic;
 
+/// Returns the square root of `a` which is smaller than `PRIME / 2`, or `None` if `a` is not a
+/// quadratic residue.
+extern fn felt252_sqrt(a: felt252) -> Option<felt252> implicits(RangeCheck) nopanic;
+
 impl Felt252PartialEq of PartialEq<felt252> {
     #[inline(always)]
     fn eq(lhs: @felt252, rhs: @felt252) -> bool {
@@ -245,6 +250,9 @@ mod ec;
 use ec::{EcOp, EcPoint, EcState};
 
 mod ecdsa;
+use ecdsa::Ecdsa;
+
+mod ed25519;
 
 // Integer.
 mod integer;
@@ -252,12 +260,18 @@ use integer::{
     i8, i8_const, I8IntoFelt252, i16, i16_const, I16IntoFelt252, i32, i32_const, I32IntoFelt252,
     i64, i64_const, I64IntoFelt252, i128, i128_const, I128IntoFelt252, NumericLiteral, u128,
     u128_const, u128_sqrt, u128_is_zero, u8, u8_const, u16, u16_const, u32, u32_const, u64,
-    u64_const, u256, u256_sqrt, Felt252TryIntoU8, U8IntoFelt252, Felt252TryIntoU16, U16IntoFelt252,
-    Felt252TryIntoU32, U32IntoFelt252, Felt252TryIntoU64, U64IntoFelt252, Felt252TryIntoU128,
-    U128IntoFelt252, U16TryIntoU8, U32TryIntoU16, U64TryIntoU32, U128TryIntoU64, Felt252IntoU256,
-    Bitwise
+    u64_const, u96, u96_const, u256, u256_sqrt, Felt252TryIntoU8, U8IntoFelt252, Felt252TryIntoU16,
+    U16IntoFelt252, Felt252TryIntoU32, U32IntoFelt252, Felt252TryIntoU64, U64IntoFelt252,
+    Felt252TryIntoU96, U96IntoFelt252, Felt252TryIntoU128, U128IntoFelt252, U16TryIntoU8,
+    U32TryIntoU16, U64TryIntoU32, U128TryIntoU64, Felt252IntoU256, Bitwise
 };
 
+// Bytes.
+mod bytes_31;
+use bytes_31::{bytes31, bytes31_const, Bytes31IntoFelt252, Felt252TryIntoBytes31};
+mod byte_array;
+use byte_array::{ByteArray, ByteArrayTrait};
+
 // Math.
 mod math;
 
@@ -295,14 +309,37 @@ mod hash;
 use hash::{pedersen, Pedersen};
 
 mod keccak;
+use keccak::Keccak;
+
+// Blake2s.
+mod blake;
+
+// Modular arithmetic builtins.
+mod modular;
+use modular::{AddMod, MulMod};
 
 // Poseidon
 mod poseidon;
 use poseidon::Poseidon;
 
+// Merkle paths.
+mod merkle;
+
+// Encodings.
+mod encoding;
+
+// Bitfields.
+mod bitfield;
+
+// RLP.
+mod rlp;
+
 // Debug.
 mod debug;
 
+// Oracles.
+mod oracle;
+
 // Starknet
 mod starknet;
 use starknet::System;
@@ -0,0 +1,42 @@
+use array::Span;
+use gas::BuiltinCosts;
+use hash::Pedersen;
+use poseidon::Poseidon;
+
+/// The result of verifying a Merkle path using `merkle_verify_pedersen` or
+/// `merkle_verify_poseidon`.
+enum MerkleVerifyResult {
+    Valid: (),
+    Invalid: (),
+    OutOfGas: (),
+}
+
+extern fn merkle_verify_pedersen(
+    costs: BuiltinCosts, leaf: felt252, index: u128, path: @Array<felt252>, root: felt252
+) -> MerkleVerifyResult implicits(RangeCheck, GasBuiltin, Pedersen) nopanic;
+extern fn merkle_verify_poseidon(
+    costs: BuiltinCosts, leaf: felt252, index: u128, path: @Array<felt252>, root: felt252
+) -> MerkleVerifyResult implicits(RangeCheck, GasBuiltin, Poseidon) nopanic;
+
+/// Returns whether `path` leads from `leaf`, at position `index` of the tree, to `root`, where the
+/// parent of two nodes is their Pedersen hash.
+/// `path` contains the siblings of the nodes from the leaf upwards, and the bits of `index` from the
+/// least significant determine whether each node is the left (0) or right (1) child.
+fn verify_pedersen(leaf: felt252, index: u128, path: Span<felt252>, root: felt252) -> bool {
+    match merkle_verify_pedersen(get_builtin_costs(), leaf, index, path.snapshot, root) {
+        MerkleVerifyResult::Valid(_) => true,
+        MerkleVerifyResult::Invalid(_) => false,
+        MerkleVerifyResult::OutOfGas(_) => panic_with_felt252('Out of gas'),
+    }
+}
+
+/// Returns whether `path` leads from `leaf`, at position `index` of the tree, to `root`, where the
+/// parent of two nodes is their Poseidon hash.
+/// See `verify_pedersen` for the structure of `path` and `index`.
+fn verify_poseidon(leaf: felt252, index: u128, path: Span<felt252>, root: felt252) -> bool {
+    match merkle_verify_poseidon(get_builtin_costs(), leaf, index, path.snapshot, root) {
+        MerkleVerifyResult::Valid(_) => true,
+        MerkleVerifyResult::Invalid(_) => false,
+        MerkleVerifyResult::OutOfGas(_) => panic_with_felt252('Out of gas'),
+    }
+}
@@ -0,0 +1,24 @@
+extern type AddMod;
+extern type MulMod;
+
+// A 384-bit unsigned integer, held as 4 limbs of 96 bits - the operands of the AddMod and MulMod
+// builtins. `limb0` is the least significant limb.
+#[derive(Copy, Drop, PartialEq, Serde)]
+struct u384 {
+    limb0: u96,
+    limb1: u96,
+    limb2: u96,
+    limb3: u96,
+}
+
+// Computes `(a + b) % modulus` using the AddMod builtin.
+// `a` and `b` must be smaller than `modulus`, otherwise the builtin fails.
+extern fn u384_add_mod(
+    a: u384, b: u384, modulus: u384
+) -> u384 implicits(RangeCheck96, AddMod) nopanic;
+
+// Computes `(a * b) % modulus` using the MulMod builtin.
+// `modulus` must not be 0, otherwise the builtin fails.
+extern fn u384_mul_mod(
+    a: u384, b: u384, modulus: u384
+) -> u384 implicits(RangeCheck96, MulMod) nopanic;
@@ -0,0 +1,19 @@
+// Calls the oracle with the given selector - a computation implemented by the runner - returning
+// its serialized output for the serialized input.
+// The output is nondeterministic, so it must be verified before being relied on - only the size of
+// the output span is range checked.
+//
+// Usually called through an `#[oracle]` declaration, which handles the serialization:
+//
+// #[oracle]
+// extern fn isqrt(value: u128) -> u128;
+//
+// fn checked_isqrt(value: u128) -> u128 {
+//     let root = isqrt(value);
+//     assert(root * root <= value, 'root too large');
+//     assert(value < (root + 1) * (root + 1), 'root too small');
+//     root
+// }
+extern fn oracle_call<const selector: felt252>(
+    input: Span<felt252>
+) -> Span<felt252> implicits(RangeCheck) nopanic;
@@ -0,0 +1,54 @@
+use array::{ArrayTrait, SpanTrait};
+
+/// The result of decoding the header of an RLP item using `rlp_decode_header`, with the offset and
+/// the length of the item's payload.
+enum RlpDecodeHeaderResult {
+    String: (usize, usize),
+    List: (usize, usize),
+    Invalid: (),
+}
+
+extern fn bytes_read_u128_be(
+    bytes: @Array<u8>, offset: usize, len: usize
+) -> Option<u128> implicits(RangeCheck) nopanic;
+extern fn rlp_decode_header(
+    bytes: @Array<u8>, offset: usize
+) -> RlpDecodeHeaderResult implicits(RangeCheck) nopanic;
+
+/// The kind of an RLP item.
+#[derive(Copy, Drop, PartialEq)]
+enum RlpItemKind {
+    String: (),
+    List: (),
+}
+
+/// The header of an RLP item.
+#[derive(Copy, Drop)]
+struct RlpHeader {
+    kind: RlpItemKind,
+    payload_offset: usize,
+    payload_len: usize,
+}
+
+/// Returns the big-endian value of the `len` bytes of `bytes` starting at `offset`, or `None` if
+/// `len` is more than 16 or the bytes are out of bounds.
+fn read_u128_be(bytes: Span<u8>, offset: usize, len: usize) -> Option<u128> {
+    bytes_read_u128_be(bytes.snapshot, offset, len)
+}
+
+/// Returns the header of the RLP item of `bytes` starting at `offset`, or `None` if the item is
+/// out of bounds, or the length of its payload doesn't fit in a `usize`.
+/// The encoding of the item is not checked to be canonical.
+fn decode_header(bytes: Span<u8>, offset: usize) -> Option<RlpHeader> {
+    match rlp_decode_header(bytes.snapshot, offset) {
+        RlpDecodeHeaderResult::String((
+            payload_offset, payload_len
+        )) => Option::Some(
+            RlpHeader { kind: RlpItemKind::String(()), payload_offset, payload_len }
+        ),
+        RlpDecodeHeaderResult::List((
+            payload_offset, payload_len
+        )) => Option::Some(RlpHeader { kind: RlpItemKind::List(()), payload_offset, payload_len }),
+        RlpDecodeHeaderResult::Invalid(_) => Option::None(()),
+    }
+}
@@ -1,13 +1,22 @@
 mod array_test;
+mod bitfield_test;
+mod blake_test;
 mod bool_test;
 mod box_test;
+mod byte_array_test;
+mod bytes31_test;
 mod dict_test;
 mod ec_test;
+mod ed25519_test;
+mod encoding_test;
 mod felt_test;
 mod cmp_test;
 mod hash_test;
 mod integer_test;
 mod keccak_test;
+mod merkle_test;
+mod modular_test;
+mod rlp_test;
 mod secp256k1_test;
 mod plugins_test;
 mod testing_test;
@@ -1,7 +1,8 @@
-use array::{ArrayTrait, SpanTrait};
+use array::{ArrayTrait, SpanTrait, U128ArrayTrait, U128SpanTrait};
 use box::BoxTrait;
 use clone::Clone;
 use option::OptionTrait;
+use result::ResultTrait;
 use test::test_utils::{assert_eq, assert_ne};
 
 #[test]
@@ -13,6 +14,19 @@ fn test_array() {
     assert_eq(arr[2], @12, 'array[2] != 12');
 }
 
+#[test]
+fn test_array_len_exceeds() {
+    let arr: Array<felt252> = array![10, 11, 12];
+    assert(!array::array_len_exceeds::<felt252, 3>(@arr), 'length 3 exceeds 3');
+    assert(array::array_len_exceeds::<felt252, 2>(@arr), 'length 3 does not exceed 2');
+    let empty: Array<felt252> = array![];
+    assert(!array::array_len_exceeds::<felt252, 0>(@empty), 'length 0 exceeds 0');
+    // Elements of more than a single cell.
+    let arr: Array<u256> = array![1, 2];
+    assert(!array::array_len_exceeds::<u256, 2>(@arr), 'u256 length 2 exceeds 2');
+    assert(array::array_len_exceeds::<u256, 1>(@arr), 'u256 length 2 does not exceed 1');
+}
+
 #[test]
 #[should_panic]
 fn test_array_out_of_bound_1() {
@@ -38,6 +52,22 @@ fn test_array_clone() {
     assert_eq(felt252_snap_array_clone[2], @12, 'array[2] != 12');
 }
 
+#[test]
+fn test_array_try_at() {
+    let arr: Array<felt252> = array![10, 11, 12];
+    assert_eq(arr.try_at(0).unwrap(), @10, 'array.try_at(0) != 10');
+    assert_eq(arr.try_at(2).unwrap(), @12, 'array.try_at(2) != 12');
+    assert(arr.try_at(3).is_none(), 'array.try_at(3) is not None');
+    assert(arr.try_at(0xffffffff).is_none(), 'array.try_at(max) is not None');
+}
+
+#[test]
+fn test_array_try_at_wide_element() {
+    let arr: Array<u256> = array![10, 11, 12];
+    assert_eq(arr.try_at(1).unwrap(), @11, 'array.try_at(1) != 11');
+    assert(arr.try_at(3).is_none(), 'array.try_at(3) is not None');
+}
+
 #[test]
 fn test_span() {
     let mut span: Span<felt252> = array![10, 11, 12].span();
@@ -50,6 +80,172 @@ fn test_span() {
     assert_eq(@span.len(), @1, 'Unexpected span length.');
 }
 
+#[test]
+fn test_span_try_at() {
+    let span: Span<felt252> = array![10, 11, 12].span();
+    assert_eq(span.try_at(1).unwrap(), @11, 'Unexpected element');
+    assert(span.try_at(3).is_none(), 'span.try_at(3) is not None');
+}
+
+#[test]
+fn test_span_at() {
+    let span: Span<felt252> = array![10, 11, 12].span();
+    assert_eq(array::span_at(span, 2).unwrap(), @12, 'Unexpected element');
+    assert(array::span_at(span, 3).is_none(), 'span_at(3) is not None');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_array_append_span() {
+    let mut arr: Array<felt252> = array![10, 11];
+    arr.append_span(array![12, 13, 14].span());
+    assert_eq(@arr.len(), @5, 'Unexpected array length');
+    assert_eq(arr[1], @11, 'array[1] != 11');
+    assert_eq(arr[2], @12, 'array[2] != 12');
+    assert_eq(arr[4], @14, 'array[4] != 14');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_array_append_empty_span() {
+    let mut arr: Array<felt252> = array![10, 11];
+    arr.append_span(ArrayTrait::new().span());
+    assert_eq(@arr.len(), @2, 'Unexpected array length');
+    arr.append(12);
+    assert_eq(arr[2], @12, 'array[2] != 12');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_array_append_span_wide_element() {
+    let mut arr: Array<u256> = array![1];
+    arr.append_span(array![2, 3].span());
+    assert_eq(@arr.len(), @3, 'Unexpected array length');
+    assert_eq(arr[1], @2, 'array[1] != 2');
+    assert_eq(arr[2], @3, 'array[2] != 3');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_array_append_own_span() {
+    let mut arr: Array<felt252> = array![10, 11];
+    let span = arr.span();
+    arr.append_span(span);
+    assert_eq(@arr.len(), @4, 'Unexpected array length');
+    assert_eq(arr[2], @10, 'array[2] != 10');
+    assert_eq(arr[3], @11, 'array[3] != 11');
+}
+
+#[test]
+#[available_gas(8000)]
+#[should_panic(expected: ('Out of gas', ))]
+fn test_array_append_span_out_of_gas() {
+    let mut arr: Array<felt252> = ArrayTrait::new();
+    arr.append_span(array![10, 11, 12, 13, 14].span());
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_span_eq() {
+    let span: Span<felt252> = array![10, 11, 12].span();
+    assert(span == array![10, 11, 12].span(), 'Spans should be equal');
+    assert(span != array![10, 11, 13].span(), 'Spans should differ at end');
+    assert(span != array![9, 11, 12].span(), 'Spans should differ at start');
+    assert(span != array![10, 11].span(), 'Spans should differ in length');
+    assert(ArrayTrait::<felt252>::new().span() == array![].span(), 'Empty spans should be equal');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_array_eq() {
+    let arr: Array<felt252> = array![10, 11, 12];
+    assert(arr == array![10, 11, 12], 'Arrays should be equal');
+    assert(arr != array![10, 12, 11], 'Arrays should differ');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_span_eq_integer_element() {
+    let span: Span<u128> = array![1, 2].span();
+    match array::span_eq(span.snapshot, array![1, 2].span().snapshot) {
+        array::SpanEqResult::Equal(_) => {},
+        array::SpanEqResult::NotEqual(_) => panic_with_felt252('Spans should be equal'),
+        array::SpanEqResult::OutOfGas(_) => panic_with_felt252('Out of gas'),
+    }
+    match array::span_eq(span.snapshot, array![1, 3].span().snapshot) {
+        array::SpanEqResult::Equal(_) => panic_with_felt252('Spans should differ'),
+        array::SpanEqResult::NotEqual(_) => {},
+        array::SpanEqResult::OutOfGas(_) => panic_with_felt252('Out of gas'),
+    }
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_span_binary_search() {
+    let span: Span<u128> = array![1, 3, 5, 7, 9].span();
+    assert_eq(@span.binary_search(1).unwrap(), @0, 'Wrong index for 1');
+    assert_eq(@span.binary_search(5).unwrap(), @2, 'Wrong index for 5');
+    assert_eq(@span.binary_search(9).unwrap(), @4, 'Wrong index for 9');
+    assert_eq(@span.binary_search(0).unwrap_err(), @0, 'Wrong insertion point for 0');
+    assert_eq(@span.binary_search(4).unwrap_err(), @2, 'Wrong insertion point for 4');
+    assert_eq(@span.binary_search(8).unwrap_err(), @4, 'Wrong insertion point for 8');
+    assert_eq(@span.binary_search(10).unwrap_err(), @5, 'Wrong insertion point for 10');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_span_binary_search_empty() {
+    let span: Span<u128> = ArrayTrait::new().span();
+    assert_eq(@span.binary_search(1).unwrap_err(), @0, 'Wrong insertion point');
+}
+
+#[test]
+#[available_gas(8000)]
+#[should_panic(expected: ('Out of gas', ))]
+fn test_span_binary_search_out_of_gas() {
+    let span: Span<u128> = array![1, 3, 5, 7, 9, 11, 13, 15].span();
+    span.binary_search(14);
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_span_sorted() {
+    let sorted = array![5, 3, 9, 1, 7].span().sorted();
+    assert_eq(@sorted.len(), @5, 'Unexpected array length');
+    assert_eq(sorted.at(0), @1, 'Unexpected element at 0');
+    assert_eq(sorted.at(1), @3, 'Unexpected element at 1');
+    assert_eq(sorted.at(2), @5, 'Unexpected element at 2');
+    assert_eq(sorted.at(3), @7, 'Unexpected element at 3');
+    assert_eq(sorted.at(4), @9, 'Unexpected element at 4');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_array_sort_with_duplicates() {
+    let mut arr: Array<u128> = array![4, 2, 4, 1, 2];
+    arr.sort();
+    assert_eq(@arr.len(), @5, 'Unexpected array length');
+    assert_eq(arr.at(0), @1, 'Unexpected element at 0');
+    assert_eq(arr.at(1), @2, 'Unexpected element at 1');
+    assert_eq(arr.at(2), @2, 'Unexpected element at 2');
+    assert_eq(arr.at(3), @4, 'Unexpected element at 3');
+    assert_eq(arr.at(4), @4, 'Unexpected element at 4');
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_span_sorted_empty() {
+    let span: Span<u128> = ArrayTrait::new().span();
+    assert_eq(@span.sorted().len(), @0, 'Unexpected array length');
+}
+
+#[test]
+#[available_gas(20000)]
+#[should_panic(expected: ('Out of gas', ))]
+fn test_span_sorted_out_of_gas() {
+    array![8, 7, 6, 5, 4, 3, 2, 1, 0, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0].span().sorted();
+}
+
 #[test]
 fn test_slice() {
     let span: Span<felt252> = array![10, 11, 12].span();
@@ -0,0 +1,55 @@
+use bitfield::{bitfield_pack, bitfield_unpack};
+use option::OptionTrait;
+
+#[test]
+fn test_pack() {
+    assert(bitfield_pack((1_u8, 2_u16)) == 0x201, 'pack (u8, u16)');
+    assert(bitfield_pack((0xff_u8, 0_u64, 1_u8)) == 0x10000000000000000ff, 'pack (u8, u64, u8)');
+    assert(
+        bitfield_pack((1_u128, 2_u64)) == 0x200000000000000000000000000000001, 'pack (u128, u64)'
+    );
+}
+
+#[test]
+fn test_unpack() {
+    let (a, b): (u8, u16) = bitfield_unpack(0x201).unwrap();
+    assert(a == 1, 'unpack (u8, u16) a');
+    assert(b == 2, 'unpack (u8, u16) b');
+    let (a, b, c): (u8, u64, u8) = bitfield_unpack(0x10000000000000000ff).unwrap();
+    assert(a == 0xff, 'unpack (u8, u64, u8) a');
+    assert(b == 0, 'unpack (u8, u64, u8) b');
+    assert(c == 1, 'unpack (u8, u64, u8) c');
+}
+
+#[test]
+fn test_pack_unpack_roundtrip() {
+    let fields = (
+        0xffffffffffffffffffffffffffffffff_u128,
+        0x0123456789abcdef_u64,
+        0xfedcba98_u32,
+        0x8001_u16,
+        0x7f_u8
+    );
+    let packed = bitfield_pack(fields);
+    let (a, b, c, d, e): (u128, u64, u32, u16, u8) = bitfield_unpack(packed).unwrap();
+    assert(a == 0xffffffffffffffffffffffffffffffff, 'roundtrip a');
+    assert(b == 0x0123456789abcdef, 'roundtrip b');
+    assert(c == 0xfedcba98, 'roundtrip c');
+    assert(d == 0x8001, 'roundtrip d');
+    assert(e == 0x7f, 'roundtrip e');
+}
+
+#[test]
+fn test_unpack_out_of_range() {
+    let result: Option<(u8, u16)> = bitfield_unpack(0x1000000);
+    assert(result.is_none(), 'value of 24 bits');
+    let result: Option<(u8, u16)> = bitfield_unpack(-1);
+    assert(result.is_none(), 'value of -1');
+    let result: Option<(u128, u64)> = bitfield_unpack(
+        0x1000000000000000000000000000000000000000000000000
+    );
+    assert(result.is_none(), 'value of 193 bits');
+    let (a, b): (u8, u16) = bitfield_unpack(0xffffff).unwrap();
+    assert(a == 0xff, 'max value a');
+    assert(b == 0xffff, 'max value b');
+}
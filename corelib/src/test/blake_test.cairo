@@ -0,0 +1,145 @@
+use blake::{blake2s_compress, blake2s_finalize};
+use test::test_utils::assert_eq;
+
+#[test]
+fn test_blake2s_finalize() {
+    // The message "abc", from the initial state of an unkeyed hash with a 32-byte digest.
+    let (
+        h0,
+        h1,
+        h2,
+        h3,
+        h4,
+        h5,
+        h6,
+        h7
+    ) = blake2s_finalize(
+        0x6b08e647,
+        0xbb67ae85,
+        0x3c6ef372,
+        0xa54ff53a,
+        0x510e527f,
+        0x9b05688c,
+        0x1f83d9ab,
+        0x5be0cd19,
+        3,
+        0x636261,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0
+    );
+    assert_eq(@h0, @0x8c5e8c50, 'Wrong digest word 0');
+    assert_eq(@h1, @0xe2147c32, 'Wrong digest word 1');
+    assert_eq(@h2, @0xa32ba7e1, 'Wrong digest word 2');
+    assert_eq(@h3, @0x2f45eb4e, 'Wrong digest word 3');
+    assert_eq(@h4, @0x208b4537, 'Wrong digest word 4');
+    assert_eq(@h5, @0x293ad69e, 'Wrong digest word 5');
+    assert_eq(@h6, @0x4c9b994d, 'Wrong digest word 6');
+    assert_eq(@h7, @0x82596786, 'Wrong digest word 7');
+}
+
+#[test]
+fn test_blake2s_compress() {
+    // The first block of the message `0x00, 0x01, ..., 0x40`.
+    let (
+        h0,
+        h1,
+        h2,
+        h3,
+        h4,
+        h5,
+        h6,
+        h7
+    ) = blake2s_compress(
+        0x6b08e647,
+        0xbb67ae85,
+        0x3c6ef372,
+        0xa54ff53a,
+        0x510e527f,
+        0x9b05688c,
+        0x1f83d9ab,
+        0x5be0cd19,
+        64,
+        0x3020100,
+        0x7060504,
+        0xb0a0908,
+        0xf0e0d0c,
+        0x13121110,
+        0x17161514,
+        0x1b1a1918,
+        0x1f1e1d1c,
+        0x23222120,
+        0x27262524,
+        0x2b2a2928,
+        0x2f2e2d2c,
+        0x33323130,
+        0x37363534,
+        0x3b3a3938,
+        0x3f3e3d3c
+    );
+    assert_eq(@h0, @0x69b86edc, 'Wrong state word 0');
+    assert_eq(@h1, @0x9c74bb75, 'Wrong state word 1');
+    assert_eq(@h2, @0x25bd102e, 'Wrong state word 2');
+    assert_eq(@h3, @0x83f24029, 'Wrong state word 3');
+    assert_eq(@h4, @0x9a9d32c1, 'Wrong state word 4');
+    assert_eq(@h5, @0xa02250bb, 'Wrong state word 5');
+    assert_eq(@h6, @0x3aa0a774, 'Wrong state word 6');
+    assert_eq(@h7, @0xe4b93e1d, 'Wrong state word 7');
+    // The last block of the message.
+    let (
+        h0,
+        h1,
+        h2,
+        h3,
+        h4,
+        h5,
+        h6,
+        h7
+    ) = blake2s_finalize(
+        h0,
+        h1,
+        h2,
+        h3,
+        h4,
+        h5,
+        h6,
+        h7,
+        65,
+        0x40,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0
+    );
+    assert_eq(@h0, @0x94ee531b, 'Wrong digest word 0');
+    assert_eq(@h1, @0x4b4ef3aa, 'Wrong digest word 1');
+    assert_eq(@h2, @0xde489d15, 'Wrong digest word 2');
+    assert_eq(@h3, @0x67f2c35, 'Wrong digest word 3');
+    assert_eq(@h4, @0xea4d061, 'Wrong digest word 4');
+    assert_eq(@h5, @0xb5af9df, 'Wrong digest word 5');
+    assert_eq(@h6, @0x9b43916, 'Wrong digest word 6');
+    assert_eq(@h7, @0x7244970e, 'Wrong digest word 7');
+}
@@ -0,0 +1,63 @@
+use byte_array::{ByteArray, ByteArrayTrait};
+use bytes_31::bytes31_const;
+use option::OptionTrait;
+use test::test_utils::{assert_eq, assert_ne};
+
+#[test]
+fn test_append_byte() {
+    let mut ba: ByteArray = Default::default();
+    ba.append_byte(0x61_u8);
+    ba.append_byte(0x62_u8);
+    assert_eq(@ba.len(), @2, 'bad len');
+    assert_eq(@ba.pending_word, @bytes31_const::<0x6162>(), 'bad pending word');
+    assert_eq(@ba.at(0).unwrap(), @0x61_u8, 'bad byte 0');
+    assert_eq(@ba.at(1).unwrap(), @0x62_u8, 'bad byte 1');
+    assert(ba.at(2).is_none(), 'byte 2 exists');
+}
+
+#[test]
+fn test_append_word() {
+    let mut ba = ByteArrayTrait::new();
+    ba.append_word('ABCDEFGHIJKLMNOPQRSTUVWXYZabcde', 31);
+    assert_eq(@ba.len(), @31, 'bad len after full word');
+    assert_eq(@ba.data.len(), @1, 'full word not in data');
+    assert_eq(@ba.pending_word_len, @0, 'bad pending len');
+    ba.append_word('fg', 2);
+    ba.append_word('hijklmnopqrstuvwxyz0123456789AB', 31);
+    assert_eq(@ba.len(), @64, 'bad len');
+    assert_eq(@ba.data.len(), @2, 'bad data len');
+    assert_eq(@ba.pending_word_len, @2, 'bad pending len');
+    assert_eq(@ba.at(0).unwrap(), @0x41_u8, 'bad byte 0');
+    assert_eq(@ba.at(31).unwrap(), @0x66_u8, 'bad byte 31');
+    assert_eq(@ba.at(61).unwrap(), @0x39_u8, 'bad byte 61');
+    assert_eq(@ba.at(63).unwrap(), @0x42_u8, 'bad byte 63');
+    assert(ba.at(64).is_none(), 'byte 64 exists');
+}
+
+#[test]
+#[should_panic(expected: ('word longer than given length', ))]
+fn test_append_word_too_long() {
+    let mut ba = ByteArrayTrait::new();
+    ba.append_word('abc', 2);
+}
+
+#[test]
+fn test_concat() {
+    let mut left = ByteArrayTrait::new();
+    left.append_word('abc', 3);
+    let mut right = ByteArrayTrait::new();
+    right.append_word('ABCDEFGHIJKLMNOPQRSTUVWXYZabcde', 31);
+    right.append_word('de', 2);
+    let ba = ByteArrayTrait::concat(@left, @right);
+    assert_eq(@ba.len(), @36, 'bad len');
+    assert_eq(@ba.at(2).unwrap(), @0x63_u8, 'bad byte 2');
+    assert_eq(@ba.at(3).unwrap(), @0x41_u8, 'bad byte 3');
+    assert_eq(@ba.at(35).unwrap(), @0x65_u8, 'bad byte 35');
+
+    let mut expected = ByteArrayTrait::new();
+    expected.append_word('abcABCDEFGHIJKLMNOPQRSTUVWXYZab', 31);
+    expected.append_word('cdede', 5);
+    assert_eq(@ba, @expected, 'bad concat');
+    assert_eq(@(left.clone() + right.clone()), @expected, 'bad add');
+    assert_ne(@(right + left), @expected, 'add is commutative');
+}
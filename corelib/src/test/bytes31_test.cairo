@@ -0,0 +1,53 @@
+use bytes_31::{bytes31_append_u8, bytes31_split_u8};
+use option::OptionTrait;
+use traits::{Into, TryInto};
+use test::test_utils::{assert_eq, assert_ne};
+
+const POW_2_240: felt252 = 0x1000000000000000000000000000000000000000000000000000000000000;
+const POW_2_248: felt252 = 0x100000000000000000000000000000000000000000000000000000000000000;
+
+#[test]
+fn test_bytes31_conversions() {
+    let value: bytes31 = 'abc'.try_into().unwrap();
+    let felt: felt252 = value.into();
+    assert_eq(@felt, @'abc', 'bad abc into felt252');
+    assert_eq(@value, @bytes31_const::<0x616263>(), 'bad abc const');
+    let max: bytes31 = (POW_2_248 - 1).try_into().unwrap();
+    let felt: felt252 = max.into();
+    assert_eq(@felt, @(POW_2_248 - 1), 'bad max into felt252');
+    let over: Option<bytes31> = POW_2_248.try_into();
+    assert(over.is_none(), '2**248 is a bytes31');
+    let negative: Option<bytes31> = (-1).try_into();
+    assert(negative.is_none(), '-1 is a bytes31');
+}
+
+#[test]
+fn test_bytes31_append_u8() {
+    let value = bytes31_append_u8(bytes31_const::<0x6162>(), 0x63_u8).unwrap();
+    assert_eq(@value, @bytes31_const::<0x616263>(), 'bad append to ab');
+    let value = bytes31_append_u8(bytes31_const::<0>(), 0xff_u8).unwrap();
+    assert_eq(@value, @bytes31_const::<0xff>(), 'bad append to 0');
+    let max_fitting: bytes31 = (POW_2_240 - 1).try_into().unwrap();
+    let value: felt252 = bytes31_append_u8(max_fitting, 0xff_u8).unwrap().into();
+    assert_eq(@value, @(POW_2_248 - 1), 'bad append to 2**240 - 1');
+    let full: bytes31 = POW_2_240.try_into().unwrap();
+    assert(bytes31_append_u8(full, 0_u8).is_none(), 'append to 2**240 fits');
+    let full: bytes31 = (POW_2_248 - 1).try_into().unwrap();
+    assert(bytes31_append_u8(full, 0_u8).is_none(), 'append to 2**248 - 1 fits');
+}
+
+#[test]
+fn test_bytes31_split_u8() {
+    let (rest, byte) = bytes31_split_u8(bytes31_const::<0x616263>());
+    assert_eq(@rest, @bytes31_const::<0x6162>(), 'bad rest of abc');
+    assert_eq(@byte, @0x63_u8, 'bad byte of abc');
+    let (rest, byte) = bytes31_split_u8(bytes31_const::<0>());
+    assert_eq(@rest, @bytes31_const::<0>(), 'bad rest of 0');
+    assert_eq(@byte, @0_u8, 'bad byte of 0');
+    let max: bytes31 = (POW_2_248 - 1).try_into().unwrap();
+    let (rest, byte) = bytes31_split_u8(max);
+    let rest_felt: felt252 = rest.into();
+    assert_eq(@rest_felt, @(POW_2_240 - 1), 'bad rest of 2**248 - 1');
+    assert_eq(@byte, @0xff_u8, 'bad byte of 2**248 - 1');
+    assert_ne(@rest, @max, 'rest of 2**248 - 1 is itself');
+}
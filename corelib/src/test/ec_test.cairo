@@ -1,12 +1,15 @@
+use array::ArrayTrait;
 use core::traits::Into;
 use option::OptionTrait;
 use ec::{
-    ec_mul, ec_neg, ec_point_from_x, ec_point_from_x_nz, ec_point_is_zero, ec_point_new,
+    ec_add, ec_mul, ec_multi_mul, ec_neg, ec_point_double, ec_point_from_x, ec_point_from_x_nz,
+    ec_point_from_x_parity, ec_point_from_x_parity_nz, ec_point_is_zero, ec_point_new,
     ec_point_new_nz, ec_point_non_zero, ec_point_try_new, ec_point_try_new_nz, ec_point_unwrap,
     ec_point_zero, ec_state_add_mul, ec_state_add, ec_state_finalize, ec_state_init,
     ec_state_try_finalize_nz
 };
 use test::test_utils::{assert_eq, assert_ne};
+use zeroable::IsZeroResult;
 
 #[test]
 #[should_panic]
@@ -14,6 +17,21 @@ fn test_ec_from_zero() {
     ec_point_from_x_nz(0).expect('Not on curve.');
 }
 
+#[test]
+fn test_ec_point_from_x_parity() {
+    // Beta + 2 is a square, and for x = 1 and alpha = 1, x^3 + alpha * x + beta = beta + 2.
+    // This root is odd, so its negation (`PRIME` minus it) is even.
+    let beta_p2_root = 2487829544412206244690656897973144572467842667075005257202960243805141046681;
+    let (x, y) = ec_point_unwrap(ec_point_from_x_parity_nz(1, true).unwrap());
+    assert_eq(@x, @1, 'x != 1');
+    assert_eq(@y, @beta_p2_root, 'bad odd y');
+    let (x, y) = ec_point_unwrap(ec_point_from_x_parity_nz(1, false).unwrap());
+    assert_eq(@x, @1, 'x != 1');
+    assert(y == -beta_p2_root, 'bad even y');
+    assert(ec_point_from_x_parity(0, true).is_none(), 'x = 0 is not on the curve');
+    assert(ec_point_from_x_parity(0, false).is_none(), 'x = 0 is not on the curve');
+}
+
 #[test]
 fn test_ec_operations() {
     // Beta + 2 is a square, and for x = 1 and alpha = 1, x^3 + alpha * x + beta = beta + 2.
@@ -51,6 +69,33 @@ fn test_ec_operations() {
     );
     assert(double_y == expected_double_y || double_y == -expected_double_y, 'bad double y');
 
+    // Try computing `p + p` using the doubling libfunc.
+    let (qx, qy) = ec_point_unwrap(ec_point_double(p_nz));
+    assert_eq(@qx, @double_x, 'bad ec_point_double x');
+    assert_eq(@qy, @double_y, 'bad ec_point_double y');
+
+    // Try computing `p + p` using the addition libfunc.
+    let (qx, qy) = ec_point_unwrap(ec_point_non_zero(ec_add(p, p)));
+    assert_eq(@qx, @double_x, 'bad ec_add double x');
+    assert_eq(@qy, @double_y, 'bad ec_add double y');
+
+    // Compute `2p + p`, and compare it to `3p` computed using the ec_mul function.
+    let (triple_x, triple_y) = ec_point_unwrap(ec_point_non_zero(ec_add(double_p, p)));
+    let (expected_triple_x, expected_triple_y) = ec_point_unwrap(ec_point_non_zero(ec_mul(p, 3)));
+    assert_eq(@triple_x, @expected_triple_x, 'bad ec_add x');
+    assert_eq(@triple_y, @expected_triple_y, 'bad ec_add y');
+
+    // Compute additions involving the zero point.
+    let zero = ec_point_zero();
+    let (add_zero_x, add_zero_y) = ec_point_unwrap(ec_point_non_zero(ec_add(p, zero)));
+    assert_eq(@add_zero_x, @x, 'bad x for p + 0');
+    assert_eq(@add_zero_y, @y, 'bad y for p + 0');
+    let (zero_add_x, zero_add_y) = ec_point_unwrap(ec_point_non_zero(ec_add(zero, p)));
+    assert_eq(@zero_add_x, @x, 'bad x for 0 + p');
+    assert_eq(@zero_add_y, @y, 'bad y for 0 + p');
+    assert(ec_point_is_zero(ec_add(zero, zero)).into(), '0 + 0 did not return 0.');
+    assert(ec_point_is_zero(ec_add(p, ec_neg(p))).into(), 'p + (-p) did not return 0.');
+
     // Compute `2p - p`.
     let (sub_x, sub_y) = ec_point_unwrap(ec_point_non_zero(double_p - p));
     assert_eq(@sub_x, @x, 'bad x for 2p - p');
@@ -114,6 +159,16 @@ fn test_ecdsa() {
     );
 }
 
+#[test]
+fn test_ecdsa_verify() {
+    ecdsa::ecdsa_verify(
+        message_hash: 0x503f4bea29baee10b22a7f10bdc82dda071c977c1f25b8f3973d34e6b03b2c,
+        public_key: 0x7b7454acbe7845da996377f85eb0892044d75ae95d04d3325a391951f35d2ec,
+        signature_r: 0xbe96d72eb4f94078192c2e84d5230cde2a70f4b45c8797e2c907acff5060bb,
+        signature_s: 0x677ae6bba6daf00d2631fab14c8acf24be6579f9d9e98f67aa7f2770e57a1f5,
+    );
+}
+
 #[test]
 fn test_ec_mul() {
     let p = ec_point_new(
@@ -134,3 +189,32 @@ fn test_ec_mul() {
         'ec_mul failed (y).'
     );
 }
+
+#[test]
+fn test_ec_multi_mul() {
+    let p = ec_point_from_x(1).unwrap();
+    let p_nz = ec_point_non_zero(p);
+    let q_nz = ec_point_double(p_nz);
+    let mut terms = ArrayTrait::new();
+    terms.append((3, p_nz));
+    terms.append((5, q_nz));
+    // `3 * p + 5 * (2 * p) = 13 * p`.
+    let (x, y) = ec_point_unwrap(ec_point_non_zero(ec_multi_mul(terms.span())));
+    let (expected_x, expected_y) = ec_point_unwrap(ec_point_non_zero(ec_mul(p, 13)));
+    assert_eq(@x, @expected_x, 'bad ec_multi_mul x');
+    assert_eq(@y, @expected_y, 'bad ec_multi_mul y');
+
+    // `2 * p - (2 * p)` is the zero point.
+    let mut terms = ArrayTrait::new();
+    terms.append((2, p_nz));
+    terms.append((-1, q_nz));
+    match ec_point_is_zero(ec_multi_mul(terms.span())) {
+        IsZeroResult::Zero(()) => {},
+        IsZeroResult::NonZero(_) => panic_with_felt252('Non-zero cancelling terms sum'),
+    };
+
+    match ec_point_is_zero(ec_multi_mul(ArrayTrait::new().span())) {
+        IsZeroResult::Zero(()) => {},
+        IsZeroResult::NonZero(_) => panic_with_felt252('Non-zero empty sum'),
+    };
+}
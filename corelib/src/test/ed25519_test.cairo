@@ -0,0 +1,50 @@
+use ed25519::{
+    ed25519_add, ed25519_generator, ed25519_identity, ed25519_mul, ed25519_order,
+    ed25519_point_try_new, Ed25519Point
+};
+use option::OptionTrait;
+
+#[test]
+fn test_ed25519_point_try_new() {
+    let g = ed25519_generator();
+    assert(ed25519_point_try_new(g.x, g.y).is_some(), 'generator not on curve');
+    assert(ed25519_point_try_new(0, 1).is_some(), 'identity not on curve');
+    assert(ed25519_point_try_new(g.x, g.y + 1).is_none(), 'invalid point accepted');
+    assert(
+        ed25519_point_try_new(
+            0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed, 1
+        )
+            .is_none(),
+        'unreduced point accepted'
+    );
+}
+
+#[test]
+#[available_gas(100000000)]
+fn test_ed25519_add() {
+    let g = ed25519_generator();
+    let g2 = Ed25519Point {
+        x: 0x36ab384c9f5a046c3d043b7d1833e7ac080d8e4515d7a45f83c5a14e2843ce0e,
+        y: 0x2260cdf3092329c21da25ee8c9a21f5697390f51643851560e5f46ae6af8a3c9,
+    };
+    let g3 = Ed25519Point {
+        x: 0x67ae9c4a22928f491ff4ae743edac83a6343981981624886ac62485fd3f8e25c,
+        y: 0x1267b1d177ee69aba126a18e60269ef79f16ec176724030402c3684878f5b4d4,
+    };
+    assert(ed25519_add(g, g) == g2, 'wrong G + G');
+    assert(g2 + g == g3, 'wrong 2G + G');
+    assert(ed25519_add(g, ed25519_identity()) == g, 'wrong G + 0');
+}
+
+#[test]
+#[available_gas(1000000000)]
+fn test_ed25519_mul() {
+    let g = ed25519_generator();
+    let g3 = Ed25519Point {
+        x: 0x67ae9c4a22928f491ff4ae743edac83a6343981981624886ac62485fd3f8e25c,
+        y: 0x1267b1d177ee69aba126a18e60269ef79f16ec176724030402c3684878f5b4d4,
+    };
+    assert(ed25519_mul(g, 3) == g3, 'wrong 3 * G');
+    assert(ed25519_mul(g, 0) == ed25519_identity(), 'wrong 0 * G');
+    assert(ed25519_mul(g, ed25519_order()) == ed25519_identity(), 'wrong order * G');
+}
@@ -0,0 +1,159 @@
+use array::{ArrayTrait, SpanTrait};
+use encoding::{base64_encode, hex_decode, hex_encode};
+use option::OptionTrait;
+
+fn assert_bytes_eq(mut actual: Span<u8>, mut expected: Span<u8>, err_code: felt252) {
+    assert(actual.len() == expected.len(), err_code);
+    loop {
+        match actual.pop_front() {
+            Option::Some(byte) => assert(*byte == *expected.pop_front().unwrap(), err_code),
+            Option::None(_) => {
+                break;
+            },
+        };
+    };
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_hex_encode() {
+    assert_bytes_eq(
+        hex_encode(array![0x01, 0xab, 0xff, 0x00, 0x9c].span()).span(),
+        array!['0', '1', 'a', 'b', 'f', 'f', '0', '0', '9', 'c'].span(),
+        'Wrong hex encoding'
+    );
+    assert(hex_encode(ArrayTrait::new().span()).len() == 0, 'Wrong empty hex encoding');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_hex_decode() {
+    assert_bytes_eq(
+        hex_decode(array!['0', '1', 'a', 'B', 'F', 'f', '0', '0', '9', 'c'].span()).unwrap().span(),
+        array![0x01, 0xab, 0xff, 0x00, 0x9c].span(),
+        'Wrong hex decoding'
+    );
+    assert(hex_decode(ArrayTrait::new().span()).unwrap().len() == 0, 'Wrong empty hex decoding');
+    assert(hex_decode(array!['0', '1', 'a'].span()).is_none(), 'Odd length');
+    assert(hex_decode(array!['0', '1', 'g', 'b'].span()).is_none(), 'Invalid high digit');
+    assert(hex_decode(array!['0', '1', 'a', 'G'].span()).is_none(), 'Invalid low digit');
+    assert(hex_decode(array!['/', '0'].span()).is_none(), 'Below the digits');
+    assert(hex_decode(array!['0', ':'].span()).is_none(), 'Above the digits');
+    assert(hex_decode(array!['@', '0'].span()).is_none(), 'Below the uppercase digits');
+    assert(hex_decode(array!['`', '0'].span()).is_none(), 'Below the lowercase digits');
+    assert(hex_decode(array![0xff, '0'].span()).is_none(), 'Above all the digits');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_hex_round_trip() {
+    let bytes = array![0x00, 0x10, 0x7f, 0x80, 0xfe, 0xff].span();
+    assert_bytes_eq(
+        hex_decode(hex_encode(bytes).span()).unwrap().span(), bytes, 'Wrong round trip'
+    );
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_base64_encode() {
+    // The test vectors of RFC 4648.
+    let foobar = array!['f', 'o', 'o', 'b', 'a', 'r'].span();
+    assert(base64_encode(foobar.slice(0, 0)).len() == 0, 'Wrong base64 of ""');
+    assert_bytes_eq(
+        base64_encode(foobar.slice(0, 1)).span(), array!['Z', 'g', '=', '='].span(), 'Wrong "f"'
+    );
+    assert_bytes_eq(
+        base64_encode(foobar.slice(0, 2)).span(), array!['Z', 'm', '8', '='].span(), 'Wrong "fo"'
+    );
+    assert_bytes_eq(
+        base64_encode(foobar.slice(0, 3)).span(), array!['Z', 'm', '9', 'v'].span(), 'Wrong "foo"'
+    );
+    assert_bytes_eq(
+        base64_encode(foobar.slice(0, 4)).span(),
+        array!['Z', 'm', '9', 'v', 'Y', 'g', '=', '='].span(),
+        'Wrong "foob"'
+    );
+    assert_bytes_eq(
+        base64_encode(foobar.slice(0, 5)).span(),
+        array!['Z', 'm', '9', 'v', 'Y', 'm', 'E', '='].span(),
+        'Wrong "fooba"'
+    );
+    assert_bytes_eq(
+        base64_encode(foobar).span(),
+        array!['Z', 'm', '9', 'v', 'Y', 'm', 'F', 'y'].span(),
+        'Wrong "foobar"'
+    );
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_base64_encode_all_chars() {
+    // Encodes the 6-bit values 0 to 63 in order.
+    let bytes = array![
+        0x00,
+        0x10,
+        0x83,
+        0x10,
+        0x51,
+        0x87,
+        0x20,
+        0x92,
+        0x8b,
+        0x30,
+        0xd3,
+        0x8f,
+        0x41,
+        0x14,
+        0x93,
+        0x51,
+        0x55,
+        0x97,
+        0x61,
+        0x96,
+        0x9b,
+        0x71,
+        0xd7,
+        0x9f,
+        0x82,
+        0x18,
+        0xa3,
+        0x92,
+        0x59,
+        0xa7,
+        0xa2,
+        0x9a,
+        0xab,
+        0xb2,
+        0xdb,
+        0xaf,
+        0xc3,
+        0x1c,
+        0xb3,
+        0xd3,
+        0x5d,
+        0xb7,
+        0xe3,
+        0x9e,
+        0xbb,
+        0xf3,
+        0xdf,
+        0xbf
+    ];
+    let encoded = base64_encode(bytes.span());
+    assert(encoded.len() == 64, 'Wrong length');
+    assert(*encoded[0] == 'A', 'Wrong char 0');
+    assert(*encoded[25] == 'Z', 'Wrong char 25');
+    assert(*encoded[26] == 'a', 'Wrong char 26');
+    assert(*encoded[51] == 'z', 'Wrong char 51');
+    assert(*encoded[52] == '0', 'Wrong char 52');
+    assert(*encoded[61] == '9', 'Wrong char 61');
+    assert(*encoded[62] == '+', 'Wrong char 62');
+    assert(*encoded[63] == '/', 'Wrong char 63');
+}
+
+#[test]
+#[available_gas(20000)]
+#[should_panic(expected: ('Out of gas', ))]
+fn test_hex_encode_out_of_gas() {
+    hex_encode(array![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a].span());
+}
@@ -1,4 +1,5 @@
 use clone::Clone;
+use option::OptionTrait;
 use test::test_utils::{assert_eq, assert_ne};
 
 #[test]
@@ -18,3 +19,19 @@ fn test_felt252_clone() {
     let felt252_clone = felt252_snap.clone();
     assert_eq(@felt252_clone, @2, 'felt252_clone == 2');
 }
+
+#[test]
+fn test_felt252_sqrt() {
+    assert_eq(@felt252_sqrt(0).unwrap(), @0, 'sqrt(0) == 0');
+    assert_eq(@felt252_sqrt(1).unwrap(), @1, 'sqrt(1) == 1');
+    assert_eq(@felt252_sqrt(4).unwrap(), @2, 'sqrt(4) == 2');
+    assert_eq(@felt252_sqrt(9).unwrap(), @3, 'sqrt(9) == 3');
+    assert_eq(
+        @felt252_sqrt(-1).unwrap(),
+        @0x1dafdc6d65d66b5accedf99bcd607383ad971a9537cdf25d59e99d90becc81e,
+        'sqrt(-1)'
+    );
+    assert(felt252_sqrt(3).is_none(), 'sqrt(3) == None');
+    assert(felt252_sqrt(6).is_none(), 'sqrt(6) == None');
+    assert(felt252_sqrt(-3).is_none(), 'sqrt(-3) == None');
+}
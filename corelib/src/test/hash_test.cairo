@@ -10,6 +10,20 @@ fn test_pedersen_hash() {
     );
 }
 
+#[test]
+#[available_gas(1000000)]
+fn test_pedersen_span() {
+    assert_eq(@hash::pedersen_span(7, array![].span()), @7, 'Wrong empty span');
+    assert_eq(
+        @hash::pedersen_span(1, array![2].span()), @hash::pedersen(1, 2), 'Wrong single hash'
+    );
+    assert_eq(
+        @hash::pedersen_span(1, array![2, 3, 4].span()),
+        @hash::pedersen(hash::pedersen(hash::pedersen(1, 2), 3), 4),
+        'Wrong span hash'
+    );
+}
+
 #[test]
 fn test_poseidon_hades_permutation() {
     let (s0, s1, s2) = poseidon::hades_permutation(1, 2, 3);
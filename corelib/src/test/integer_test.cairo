@@ -909,6 +909,48 @@ fn test_u256_sqrt() {
     assert_eq(@u256_sqrt(u256 { low, high }), @BoundedInt::max(), '(u128::MAX**2)**0.5==u128::MAX');
 }
 
+fn inv_mod_n(b: u256, n: u256) -> Option<u256> {
+    match integer::u256_inv_mod_n(b, n.try_into().unwrap()) {
+        Option::Some(inverse) => Option::Some(inverse.into()),
+        Option::None(()) => Option::None(()),
+    }
+}
+
+#[test]
+fn test_u256_inv_mod_n() {
+    assert_eq(@inv_mod_n(5, 24).unwrap(), @5, 'inv(5, 24) == 5');
+    assert_eq(@inv_mod_n(29, 24).unwrap(), @5, 'inv(29, 24) == 5');
+    assert_eq(@inv_mod_n(1, 24).unwrap(), @1, 'inv(1, 24) == 1');
+    assert_eq(@inv_mod_n(2, 5).unwrap(), @3, 'inv(2, 5) == 3');
+    assert(inv_mod_n(8, 24).is_none(), 'inv(8, 24) == None');
+    assert(inv_mod_n(0, 24).is_none(), 'inv(0, 24) == None');
+    assert(inv_mod_n(24, 24).is_none(), 'inv(24, 24) == None');
+    assert(inv_mod_n(1, 1).is_none(), 'inv(1, 1) == None');
+    assert(inv_mod_n(7, 1).is_none(), 'inv(7, 1) == None');
+
+    let max_u256: u256 = BoundedInt::max();
+    assert_eq(
+        @inv_mod_n(2, max_u256).unwrap(),
+        @0x8000000000000000000000000000000000000000000000000000000000000000,
+        'inv(2, u256::MAX) == 2**255'
+    );
+    assert_eq(@inv_mod_n(max_u256, max_u256 - 1).unwrap(), @1, 'inv(MAX, MAX - 1) == 1');
+    assert(inv_mod_n(2, max_u256 - 1).is_none(), 'inv(2, MAX - 1) == None');
+    assert(inv_mod_n(max_u256, max_u256).is_none(), 'inv(MAX, MAX) == None');
+
+    let secp256k1_prime = 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f;
+    assert_eq(
+        @inv_mod_n(3, secp256k1_prime).unwrap(),
+        @0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa9fffffd75,
+        'inv(3, secp256k1_prime)'
+    );
+    assert_eq(
+        @inv_mod_n(0x123456789abcdef0123456789abcdef0123456789abcdef, secp256k1_prime).unwrap(),
+        @0xbc2b735254c4b6ca9faa82e9a65df7f7aab23f80d1c397f5dbddd451b07b8d4e,
+        'inv(b, secp256k1_prime)'
+    );
+}
+
 #[test]
 fn test_u256_try_into_felt252() {
     let FELT252_PRIME = 0x800000000000011000000000000000000000000000000000000000000000001_u256;
@@ -967,6 +1009,72 @@ fn proper_cast() {
     assert(cast_must_pass(0xFFFFFFFF_u32, 0xFFFFFFFF_u64), 'u32 to_and_fro u64');
     assert(cast_must_pass(0xFFFFFFFF_u32, 0xFFFFFFFF_u128), 'u32 to_and_fro u128');
     assert(cast_must_pass(0xFFFFFFFFFFFFFFFF_u64, 0xFFFFFFFFFFFFFFFF_u128), 'u64 to_and_fro u128');
+    assert(cast_must_pass(0xFF_u8, 0xFF_u96), 'u8 to_and_fro u96');
+    assert(cast_must_pass(0xFFFF_u16, 0xFFFF_u96), 'u16 to_and_fro u96');
+    assert(cast_must_pass(0xFFFFFFFF_u32, 0xFFFFFFFF_u96), 'u32 to_and_fro u96');
+    assert(cast_must_pass(0xFFFFFFFFFFFFFFFF_u64, 0xFFFFFFFFFFFFFFFF_u96), 'u64 to_and_fro u96');
+    assert(
+        cast_must_pass(0xFFFFFFFFFFFFFFFFFFFFFFFF_u96, 0xFFFFFFFFFFFFFFFFFFFFFFFF_u128),
+        'u96 to_and_fro u128'
+    );
+}
+
+#[test]
+fn test_u96_conversions() {
+    let max_u96 = 0xFFFFFFFFFFFFFFFFFFFFFFFF_u96;
+    assert_eq(@max_u96.into(), @0xFFFFFFFFFFFFFFFFFFFFFFFF_felt252, 'u96 into felt252');
+    assert_eq(@0xFFFFFFFFFFFFFFFFFFFFFFFF_felt252.try_into().unwrap(), @max_u96, 'felt252 to u96');
+    let f: Option<u96> = 0x1000000000000000000000000_felt252.try_into();
+    assert(f.is_none(), '2**96 is not u96');
+    assert_eq(@max_u96.into(), @0xFFFFFFFFFFFFFFFFFFFFFFFF_u256, 'u96 into u256');
+    assert_eq(@0xFFFFFFFFFFFFFFFFFFFFFFFF_u256.try_into().unwrap(), @max_u96, 'u256 to u96');
+    let f: Option<u96> = 0x1000000000000000000000000_u256.try_into();
+    assert(f.is_none(), 'u256 2**96 is not u96');
+    let f: Option<u64> = max_u96.try_into();
+    assert(f.is_none(), 'u96::MAX is not u64');
+}
+
+#[test]
+fn test_u96_as_non_zero() {
+    let non_zero: Option<NonZero<u96>> = 0_u96.try_into();
+    assert(non_zero.is_none(), '0 is not non-zero');
+    let non_zero: Option<NonZero<u96>> = 5_u96.try_into();
+    assert_eq(@zeroable::unwrap_non_zero(non_zero.unwrap()), @5_u96, 'wrong non-zero value');
+    let max_u96 = 0xFFFFFFFFFFFFFFFFFFFFFFFF_u96;
+    assert_eq(
+        @zeroable::unwrap_non_zero(integer::u96_as_non_zero(max_u96)), @max_u96, 'wrong max u96'
+    );
+}
+
+#[test]
+fn test_felt252_try_into_signed() {
+    let v: i8 = (-0x80).try_into().unwrap();
+    assert_eq(@v.into(), @-0x80_felt252, 'felt252 to i8');
+    let f: Option<i8> = 0x80.try_into();
+    assert(f.is_none(), '0x80 is not i8');
+    assert_eq(@0x7FFF.try_into().unwrap(), @0x7FFF_i16, 'felt252 to i16');
+    let v: i32 = (-1).try_into().unwrap();
+    assert_eq(@v.into(), @-1_felt252, 'felt252 to i32');
+    let v: i64 = (-1).try_into().unwrap();
+    assert_eq(@v.into(), @-1_felt252, 'felt252 to i64');
+    let f: Option<i128> = 0x80000000000000000000000000000000.try_into();
+    assert(f.is_none(), '2**127 is not i128');
+}
+
+#[test]
+fn test_signed_int_casts() {
+    let v: i8 = (-0x80).try_into().unwrap();
+    let v: i128 = v.into();
+    assert_eq(@v.into(), @-0x80_felt252, 'i8 into i128');
+    let v: i32 = (-0x8000).try_into().unwrap();
+    let v: Option<i16> = v.try_into();
+    assert_eq(@v.unwrap().into(), @-0x8000_felt252, 'i32 to i16');
+    let v: i32 = (-0x8001).try_into().unwrap();
+    let v: Option<i16> = v.try_into();
+    assert(v.is_none(), '-0x8001 is not i16');
+    let v: Option<i8> = 0x80_i64.try_into();
+    assert(v.is_none(), '0x80 is not i8');
+    assert_eq(@0x7F_i128.try_into().unwrap(), @0x7F_i8, 'i128 to i8');
 }
 
 #[test]
@@ -1154,3 +1262,18 @@ fn test_i128_operators() {
     assert_eq(@1_i128, @1_i128, '1 == 1');
     assert_ne(@1_i128, @2_i128, '1 != 2');
 }
+
+#[test]
+#[allow_unchecked_math]
+fn test_unchecked_math() {
+    assert_eq(@integer::u8_unchecked_add(100, 155), @255_u8, '100 + 155 == 255');
+    assert_eq(@integer::u16_unchecked_sub(1000, 1), @999_u16, '1000 - 1 == 999');
+    assert_eq(@integer::u32_unchecked_mul(65535, 65537), @0xffffffff_u32, '65535 * 65537');
+    assert_eq(@integer::u64_unchecked_add(1, 2), @3_u64, '1 + 2 == 3');
+    assert_eq(
+        @integer::u128_unchecked_mul(0xffffffffffffffff, 0x10000000000000001),
+        @0xffffffffffffffffffffffffffffffff_u128,
+        'u128 unchecked mul'
+    );
+    assert_eq(@integer::u128_unchecked_sub(7, 7), @0_u128, '7 - 7 == 0');
+}
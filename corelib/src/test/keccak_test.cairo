@@ -210,3 +210,167 @@ fn test_keccak_u64_full_block_minus_3_words_minus_4_bytes() {
     assert_eq(@res.low, @0x43ccdbe17ae03b02b308ebe4a23c4cc9, 'Wrong hash low 2');
     assert_eq(@res.high, @0xf3cc56e9bd860f83e3e3bc69919b176a, 'Wrong hash high 2');
 }
+
+#[test]
+fn test_keccak_permutation_zero_state() {
+    let (
+        s0,
+        s1,
+        s2,
+        s3,
+        s4,
+        s5,
+        s6,
+        s7,
+        s8,
+        s9,
+        s10,
+        s11,
+        s12,
+        s13,
+        s14,
+        s15,
+        s16,
+        s17,
+        s18,
+        s19,
+        s20,
+        s21,
+        s22,
+        s23,
+        s24
+    ) = keccak::keccak_permutation(
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0
+    );
+    assert_eq(@s0, @0xf1258f7940e1dde7, 'Wrong lane 0');
+    assert_eq(@s1, @0x84d5ccf933c0478a, 'Wrong lane 1');
+    assert_eq(@s2, @0xd598261ea65aa9ee, 'Wrong lane 2');
+    assert_eq(@s3, @0xbd1547306f80494d, 'Wrong lane 3');
+    assert_eq(@s4, @0x8b284e056253d057, 'Wrong lane 4');
+    assert_eq(@s5, @0xff97a42d7f8e6fd4, 'Wrong lane 5');
+    assert_eq(@s6, @0x90fee5a0a44647c4, 'Wrong lane 6');
+    assert_eq(@s7, @0x8c5bda0cd6192e76, 'Wrong lane 7');
+    assert_eq(@s8, @0xad30a6f71b19059c, 'Wrong lane 8');
+    assert_eq(@s9, @0x30935ab7d08ffc64, 'Wrong lane 9');
+    assert_eq(@s10, @0xeb5aa93f2317d635, 'Wrong lane 10');
+    assert_eq(@s11, @0xa9a6e6260d712103, 'Wrong lane 11');
+    assert_eq(@s12, @0x81a57c16dbcf555f, 'Wrong lane 12');
+    assert_eq(@s13, @0x43b831cd0347c826, 'Wrong lane 13');
+    assert_eq(@s14, @0x1f22f1a11a5569f, 'Wrong lane 14');
+    assert_eq(@s15, @0x5e5635a21d9ae61, 'Wrong lane 15');
+    assert_eq(@s16, @0x64befef28cc970f2, 'Wrong lane 16');
+    assert_eq(@s17, @0x613670957bc46611, 'Wrong lane 17');
+    assert_eq(@s18, @0xb87c5a554fd00ecb, 'Wrong lane 18');
+    assert_eq(@s19, @0x8c3ee88a1ccf32c8, 'Wrong lane 19');
+    assert_eq(@s20, @0x940c7922ae3a2614, 'Wrong lane 20');
+    assert_eq(@s21, @0x1841f924a2c509e4, 'Wrong lane 21');
+    assert_eq(@s22, @0x16f53526e70465c2, 'Wrong lane 22');
+    assert_eq(@s23, @0x75f644e97f30a13b, 'Wrong lane 23');
+    assert_eq(@s24, @0xeaf1ff7b5ceca249, 'Wrong lane 24');
+}
+
+#[test]
+fn test_keccak_permutation() {
+    let (
+        s0,
+        s1,
+        s2,
+        s3,
+        s4,
+        s5,
+        s6,
+        s7,
+        s8,
+        s9,
+        s10,
+        s11,
+        s12,
+        s13,
+        s14,
+        s15,
+        s16,
+        s17,
+        s18,
+        s19,
+        s20,
+        s21,
+        s22,
+        s23,
+        s24
+    ) = keccak::keccak_permutation(
+        0,
+        1,
+        2,
+        3,
+        4,
+        5,
+        6,
+        7,
+        8,
+        9,
+        10,
+        11,
+        12,
+        13,
+        14,
+        15,
+        16,
+        17,
+        18,
+        19,
+        20,
+        21,
+        22,
+        23,
+        24
+    );
+    assert_eq(@s0, @0x8374b05252ed8115, 'Wrong lane 0');
+    assert_eq(@s1, @0x1df7a676b6569400, 'Wrong lane 1');
+    assert_eq(@s2, @0xf765194b8a51797d, 'Wrong lane 2');
+    assert_eq(@s3, @0x20477b43d1760545, 'Wrong lane 3');
+    assert_eq(@s4, @0xd15f8ba4f3f6606a, 'Wrong lane 4');
+    assert_eq(@s5, @0xa1d7144f7c8dd493, 'Wrong lane 5');
+    assert_eq(@s6, @0x30d193965138fd3f, 'Wrong lane 6');
+    assert_eq(@s7, @0x487e9472951be3be, 'Wrong lane 7');
+    assert_eq(@s8, @0xcf3a858cbda7a5a, 'Wrong lane 8');
+    assert_eq(@s9, @0x2fe54e389bb17f88, 'Wrong lane 9');
+    assert_eq(@s10, @0xb7338de0d9f268f, 'Wrong lane 10');
+    assert_eq(@s11, @0x55efdff58b256d7f, 'Wrong lane 11');
+    assert_eq(@s12, @0xc8353e94eb2c3e6a, 'Wrong lane 12');
+    assert_eq(@s13, @0x2e2af6948c901f11, 'Wrong lane 13');
+    assert_eq(@s14, @0xe873de0cca309da6, 'Wrong lane 14');
+    assert_eq(@s15, @0xf7afc26c944d31e2, 'Wrong lane 15');
+    assert_eq(@s16, @0xa0f5ea808cc415d7, 'Wrong lane 16');
+    assert_eq(@s17, @0x53f531437e3ed8cf, 'Wrong lane 17');
+    assert_eq(@s18, @0x777f1f3b43a4d221, 'Wrong lane 18');
+    assert_eq(@s19, @0xfd0ca63cb499e985, 'Wrong lane 19');
+    assert_eq(@s20, @0xd4c055c0c5d12330, 'Wrong lane 20');
+    assert_eq(@s21, @0xa72fe58aa6e0a7df, 'Wrong lane 21');
+    assert_eq(@s22, @0x421af5937c9948a3, 'Wrong lane 22');
+    assert_eq(@s23, @0x5e16103071340888, 'Wrong lane 23');
+    assert_eq(@s24, @0xd153f43a297e4a33, 'Wrong lane 24');
+}
@@ -0,0 +1,48 @@
+use array::{ArrayTrait, SpanTrait};
+
+fn poseidon_pair(x: felt252, y: felt252) -> felt252 {
+    let (s0, _, _) = poseidon::hades_permutation(x, y, 2);
+    s0
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_verify_pedersen() {
+    let ab = pedersen(1, 2);
+    let cd = pedersen(3, 4);
+    let root = pedersen(ab, cd);
+    assert(merkle::verify_pedersen(1, 0, array![2, cd].span(), root), 'Leaf 0 should be valid');
+    assert(merkle::verify_pedersen(3, 2, array![4, ab].span(), root), 'Leaf 2 should be valid');
+    assert(merkle::verify_pedersen(4, 3, array![3, ab].span(), root), 'Leaf 3 should be valid');
+    assert(!merkle::verify_pedersen(3, 3, array![4, ab].span(), root), 'Wrong index');
+    assert(!merkle::verify_pedersen(3, 6, array![4, ab].span(), root), 'Index out of tree');
+    assert(!merkle::verify_pedersen(5, 2, array![4, ab].span(), root), 'Wrong leaf');
+    assert(!merkle::verify_pedersen(3, 2, array![4, cd].span(), root), 'Wrong path');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_verify_poseidon() {
+    let ab = poseidon_pair(1, 2);
+    let cd = poseidon_pair(3, 4);
+    let root = poseidon_pair(ab, cd);
+    assert(merkle::verify_poseidon(2, 1, array![1, cd].span(), root), 'Leaf 1 should be valid');
+    assert(merkle::verify_poseidon(3, 2, array![4, ab].span(), root), 'Leaf 2 should be valid');
+    assert(!merkle::verify_poseidon(2, 0, array![1, cd].span(), root), 'Wrong index');
+    assert(!merkle::verify_poseidon(3, 2, array![4, ab].span(), ab), 'Wrong root');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_verify_empty_path() {
+    assert(merkle::verify_poseidon(7, 0, ArrayTrait::new().span(), 7), 'Leaf should be the root');
+    assert(!merkle::verify_poseidon(7, 1, ArrayTrait::new().span(), 7), 'Index out of tree');
+    assert(!merkle::verify_pedersen(7, 0, ArrayTrait::new().span(), 8), 'Leaf is not the root');
+}
+
+#[test]
+#[available_gas(30000)]
+#[should_panic(expected: ('Out of gas', ))]
+fn test_verify_pedersen_out_of_gas() {
+    merkle::verify_pedersen(1, 0, array![2, 3, 4, 5, 6, 7, 8, 9].span(), 0);
+}
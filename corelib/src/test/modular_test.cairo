@@ -0,0 +1,65 @@
+use modular::{u384, u384_add_mod, u384_mul_mod};
+use test::test_utils::assert_eq;
+
+// The modulus of the P-384 curve, `2**384 - 2**128 - 2**96 + 2**32 - 1`.
+fn p384() -> u384 {
+    u384 {
+        limb0: 0xffffffff,
+        limb1: 0xfffffffffffffffeffffffff,
+        limb2: 0xffffffffffffffffffffffff,
+        limb3: 0xffffffffffffffffffffffff
+    }
+}
+
+#[test]
+fn test_u384_add_mod() {
+    let a = u384 { limb0: 5, limb1: 6, limb2: 7, limb3: 8 };
+    let b = u384 { limb0: 1, limb1: 2, limb2: 3, limb3: 4 };
+    assert_eq(
+        @u384_add_mod(a, b, p384()),
+        @u384 { limb0: 6, limb1: 8, limb2: 10, limb3: 12 },
+        'Wrong sum'
+    );
+    // `(p - 1) + 2 = 1 (mod p)`.
+    let p_minus_1 = u384 {
+        limb0: 0xfffffffe,
+        limb1: 0xfffffffffffffffeffffffff,
+        limb2: 0xffffffffffffffffffffffff,
+        limb3: 0xffffffffffffffffffffffff
+    };
+    let two = u384 { limb0: 2, limb1: 0, limb2: 0, limb3: 0 };
+    assert_eq(
+        @u384_add_mod(p_minus_1, two, p384()),
+        @u384 { limb0: 1, limb1: 0, limb2: 0, limb3: 0 },
+        'Wrong reduced sum'
+    );
+}
+
+#[test]
+fn test_u384_mul_mod() {
+    let a = u384 { limb0: 3, limb1: 0, limb2: 0, limb3: 0 };
+    let b = u384 { limb0: 5, limb1: 0, limb2: 0, limb3: 0 };
+    assert_eq(
+        @u384_mul_mod(a, b, p384()),
+        @u384 { limb0: 15, limb1: 0, limb2: 0, limb3: 0 },
+        'Wrong product'
+    );
+    // `(2**200 + 12345) * (p - 3) = p - 3 * (2**200 + 12345) (mod p)`.
+    let a = u384 { limb0: 12345, limb1: 0, limb2: 0x100, limb3: 0 };
+    let p_minus_3 = u384 {
+        limb0: 0xfffffffc,
+        limb1: 0xfffffffffffffffeffffffff,
+        limb2: 0xffffffffffffffffffffffff,
+        limb3: 0xffffffffffffffffffffffff
+    };
+    assert_eq(
+        @u384_mul_mod(a, p_minus_3, p384()),
+        @u384 {
+            limb0: 0xffff6f54,
+            limb1: 0xfffffffffffffffeffffffff,
+            limb2: 0xfffffffffffffffffffffcff,
+            limb3: 0xffffffffffffffffffffffff
+        },
+        'Wrong reduced product'
+    );
+}
@@ -0,0 +1,115 @@
+use array::{ArrayTrait, SpanTrait};
+use option::OptionTrait;
+use rlp::{decode_header, read_u128_be, RlpItemKind};
+
+/// Returns an array of `n` copies of `byte`, after the given prefix.
+fn bytes_after_prefix(mut prefix: Array<u8>, byte: u8, mut n: usize) -> Array<u8> {
+    loop {
+        if n == 0 {
+            break;
+        }
+        prefix.append(byte);
+        n -= 1;
+    };
+    prefix
+}
+
+fn assert_header(
+    bytes: Span<u8>,
+    offset: usize,
+    kind: RlpItemKind,
+    payload_offset: usize,
+    payload_len: usize,
+    err_code: felt252
+) {
+    let header = decode_header(bytes, offset).expect(err_code);
+    assert(header.kind == kind, err_code);
+    assert(header.payload_offset == payload_offset, err_code);
+    assert(header.payload_len == payload_len, err_code);
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_read_u128_be() {
+    let bytes = array![0x01, 0x02, 0x03, 0x04].span();
+    assert(read_u128_be(bytes, 1, 2).unwrap() == 0x0203, 'Wrong middle value');
+    assert(read_u128_be(bytes, 0, 4).unwrap() == 0x01020304, 'Wrong whole value');
+    assert(read_u128_be(bytes, 2, 0).unwrap() == 0, 'Wrong empty value');
+    assert(read_u128_be(bytes, 4, 0).unwrap() == 0, 'Wrong empty value at the end');
+    assert(read_u128_be(bytes, 3, 2).is_none(), 'Out of bounds');
+    assert(read_u128_be(bytes, 5, 0).is_none(), 'Offset out of bounds');
+    let max_bytes = bytes_after_prefix(array![0x00], 0xff, 17).span();
+    assert(
+        read_u128_be(max_bytes, 1, 16).unwrap() == 0xffffffffffffffffffffffffffffffff,
+        'Wrong max value'
+    );
+    assert(read_u128_be(max_bytes, 0, 17).is_none(), 'Too many bytes');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_decode_header_short() {
+    let string = RlpItemKind::String(());
+    let list = RlpItemKind::List(());
+    assert_header(array![0x7f].span(), 0, string, 0, 1, 'Wrong single byte');
+    assert_header(array![0x00].span(), 0, string, 0, 1, 'Wrong zero byte');
+    assert_header(array![0x80].span(), 0, string, 1, 0, 'Wrong empty string');
+    assert_header(array![0x83, 'd', 'o', 'g'].span(), 0, string, 1, 3, 'Wrong short string');
+    assert_header(array![0x00, 0x82, 0x01, 0x02].span(), 1, string, 2, 2, 'Wrong string at offset');
+    assert_header(array![0xc0].span(), 0, list, 1, 0, 'Wrong empty list');
+    assert_header(array![0xc2, 0x01, 0x02].span(), 0, list, 1, 2, 'Wrong short list');
+    assert_header(
+        bytes_after_prefix(array![0xb7], 0, 55).span(),
+        0,
+        string,
+        1,
+        55,
+        'Wrong longest short string'
+    );
+    assert_header(
+        bytes_after_prefix(array![0xf7], 0, 55).span(), 0, list, 1, 55, 'Wrong longest short list'
+    );
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_decode_header_long() {
+    let string = RlpItemKind::String(());
+    let list = RlpItemKind::List(());
+    assert_header(
+        bytes_after_prefix(array![0xb8, 0x38], 0, 56).span(), 0, string, 2, 56, 'Wrong long string'
+    );
+    assert_header(
+        bytes_after_prefix(array![0xf8, 0x38], 0, 56).span(), 0, list, 2, 56, 'Wrong long list'
+    );
+    assert_header(
+        bytes_after_prefix(array![0xb9, 0x01, 0x00], 0, 256).span(),
+        0,
+        string,
+        3,
+        256,
+        'Wrong 2 bytes length'
+    );
+    assert_header(
+        array![0xbb, 0x00, 0x00, 0x00, 0x01, 0x2a].span(), 0, string, 5, 1, 'Wrong 4 bytes length'
+    );
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_decode_header_invalid() {
+    assert(decode_header(ArrayTrait::new().span(), 0).is_none(), 'Empty');
+    assert(decode_header(array![0x80].span(), 1).is_none(), 'Prefix out of bounds');
+    assert(decode_header(array![0x83, 'd', 'o'].span(), 0).is_none(), 'Payload out of bounds');
+    assert(decode_header(array![0xc2, 0x01].span(), 0).is_none(), 'List out of bounds');
+    assert(decode_header(array![0xb9, 0x01].span(), 0).is_none(), 'Length out of bounds');
+    assert(decode_header(array![0xb8, 0x38, 0x00].span(), 0).is_none(), 'Long out of bounds');
+    assert(
+        decode_header(array![0xbc, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00].span(), 0).is_none(),
+        'Length too long'
+    );
+    assert(
+        decode_header(array![0xff, 0, 0, 0, 0, 0, 0, 0, 0].span(), 0).is_none(),
+        'List length too long'
+    );
+}
@@ -1,3 +1,4 @@
+use array::ArrayTrait;
 use test::test_utils::{assert_eq, assert_ne, assert_gt};
 
 #[test]
@@ -28,3 +29,35 @@ fn test_get_available_gas_no_gas_supply() {
 fn test_get_available_gas_with_gas_supply() {
     assert_gt(testing::get_available_gas(), 5000, 'high amount of gas used')
 }
+
+#[test]
+#[available_gas(10000)]
+fn test_gas_checkpoint_includes_wallet() {
+    // The costs of the test are withdrawn at its start, so they are still available.
+    assert_eq(@gas::gas_checkpoint(), @10000, 'wallet gas not included')
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_gas_consumed_since() {
+    let checkpoint = gas::gas_checkpoint();
+    let mut i = 0;
+    loop {
+        if i == 10 {
+            break;
+        }
+        i += 1;
+    };
+    let consumed = gas::gas_consumed_since(checkpoint);
+    assert_gt(consumed, 0, 'no gas consumed');
+    assert_gt(consumed, gas::gas_consumed_since(gas::gas_checkpoint()), 'bad loop consumption');
+}
+
+#[test]
+fn test_gas_consumed_since_no_gas_supply() {
+    // Without gas supply the gas counter stays 0, but the gas wallet is still accounted for.
+    let checkpoint = gas::gas_checkpoint();
+    let mut arr = ArrayTrait::new();
+    arr.append(1);
+    assert_gt(gas::gas_consumed_since(checkpoint), 0, 'no gas consumed')
+}
@@ -1 +1 @@
-extern fn get_available_gas() -> u128 implicits(GasBuiltin) nopanic;
+use gas::get_available_gas;
@@ -0,0 +1,48 @@
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::path::PathBuf;
+
+#[cfg(unix)]
+use anyhow::Context;
+#[cfg(unix)]
+use cairo_lang_daemon::CompilerDaemon;
+use cairo_lang_utils::logging::init_logging;
+use clap::Parser;
+
+/// Command line args parser.
+/// Serves compilation requests on a local socket, until a client requests a shutdown.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The path of the socket to listen on.
+    socket_path: PathBuf,
+    /// Removes an existing file at the socket path, e.g. left by a daemon that was killed.
+    #[arg(long, default_value_t = false)]
+    replace_socket: bool,
+}
+
+#[cfg(unix)]
+fn main() -> anyhow::Result<()> {
+    init_logging(log::LevelFilter::Warn);
+
+    let args = Args::parse();
+
+    if args.replace_socket && args.socket_path.exists() {
+        fs::remove_file(&args.socket_path).context("Failed to remove the existing socket.")?;
+    }
+    let listener = UnixListener::bind(&args.socket_path)
+        .with_context(|| format!("Failed to listen on {:?}.", args.socket_path))?;
+    let result = CompilerDaemon::new().serve(&listener);
+    fs::remove_file(&args.socket_path).context("Failed to remove the socket.")?;
+    result
+}
+
+#[cfg(not(unix))]
+fn main() -> anyhow::Result<()> {
+    init_logging(log::LevelFilter::Warn);
+
+    let _args = Args::parse();
+    anyhow::bail!("The compiler daemon listens on a unix socket, and is only supported on unix.")
+}
@@ -2,10 +2,52 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Context;
+use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::project::check_compiler_path;
-use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
+use cairo_lang_compiler::repro::ReproBundle;
+use cairo_lang_compiler::{
+    call_graph_of_project_at_path, compile_cairo_project_at_path,
+    compile_cairo_project_at_path_with_source_comments, stack_depth_report_of_project_at_path,
+    CompilerConfig,
+};
+use cairo_lang_sierra_generator::instrumentation::InstrumentationGranularity;
 use cairo_lang_utils::logging::init_logging;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+
+/// The output of the compilation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Emit {
+    /// The Sierra program.
+    Sierra,
+    /// The call graph of the project, including calls to other contracts.
+    Callgraph,
+    /// The maximal call depth of each entry point, and the recursion it may run into.
+    StackDepth,
+}
+
+/// The format of an emitted call graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum CallGraphFormat {
+    Json,
+    Dot,
+}
+
+/// The program points at which instrumentation probes are injected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Instrumentation {
+    /// A probe before every statement.
+    Statement,
+    /// A probe at the start of every basic block.
+    BasicBlock,
+}
+impl From<Instrumentation> for InstrumentationGranularity {
+    fn from(instrumentation: Instrumentation) -> Self {
+        match instrumentation {
+            Instrumentation::Statement => InstrumentationGranularity::Statement,
+            Instrumentation::BasicBlock => InstrumentationGranularity::BasicBlock,
+        }
+    }
+}
 
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
@@ -22,6 +64,34 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// Interleaves the Sierra statements with the Cairo source lines they originate from, as
+    /// comments.
+    #[arg(long, default_value_t = false)]
+    source_comments: bool,
+    /// Reports where ap tracking is lost, and what losing it costs, as compiler notes.
+    #[arg(long, default_value_t = false)]
+    ap_change_report: bool,
+    /// Writes an index of the symbols of the compiled crates to this path, for tools to search
+    /// without analyzing the crates again.
+    #[arg(long)]
+    symbol_index: Option<PathBuf>,
+    /// The output to emit.
+    #[arg(long, value_enum, default_value_t = Emit::Sierra)]
+    emit: Emit,
+    /// The format of the call graph, when emitting it.
+    #[arg(long, value_enum, default_value_t = CallGraphFormat::Json)]
+    callgraph_format: CallGraphFormat,
+    /// Writes a bundle reproducing the compilation to this path if the compiler crashes, to attach
+    /// to bug reports.
+    #[arg(long)]
+    repro_bundle: Option<PathBuf>,
+    /// Whether path is a reproduction bundle to replay, rather than a project.
+    #[arg(long, default_value_t = false)]
+    replay: bool,
+    /// Injects instrumentation probes into the Sierra program, for dynamic analyses (such as
+    /// coverage) of its runs.
+    #[arg(long, value_enum)]
+    instrument: Option<Instrumentation>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -30,19 +100,47 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
+    if args.replay {
+        let sierra_program = ReproBundle::load(&args.path)?.replay(&mut RootDatabase::builder())?;
+        return write_output(args.output, sierra_program.to_string());
+    }
+
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
 
-    let sierra_program = compile_cairo_project_at_path(
-        &args.path,
-        CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
-    )?;
-
-    match args.output {
-        Some(path) => {
-            fs::write(path, format!("{sierra_program}")).context("Failed to write output.")?
+    let compiler_config = CompilerConfig {
+        replace_ids: args.replace_ids,
+        ap_change_report: args.ap_change_report,
+        symbol_index_path: args.symbol_index,
+        repro_bundle_path: args.repro_bundle,
+        instrumentation: args.instrument.map(Into::into),
+        ..CompilerConfig::default()
+    };
+    let output = match args.emit {
+        Emit::Callgraph => {
+            let call_graph = call_graph_of_project_at_path(&args.path, compiler_config)?;
+            match args.callgraph_format {
+                CallGraphFormat::Json => call_graph.to_json(),
+                CallGraphFormat::Dot => call_graph.to_dot(),
+            }
+        }
+        Emit::StackDepth => {
+            stack_depth_report_of_project_at_path(&args.path, compiler_config)?.to_string()
         }
-        None => println!("{sierra_program}"),
+        Emit::Sierra if args.source_comments => {
+            compile_cairo_project_at_path_with_source_comments(&args.path, compiler_config)?
+        }
+        Emit::Sierra => compile_cairo_project_at_path(&args.path, compiler_config)?.to_string(),
+    };
+
+    write_output(args.output, output)
+}
+
+/// Writes the output to the given path, or to stdout if no path is given.
+fn write_output(path: Option<String>, output: String) -> anyhow::Result<()> {
+    match path {
+        Some(path) => fs::write(path, output).context("Failed to write output.")?,
+        None => println!("{output}"),
     }
 
     Ok(())
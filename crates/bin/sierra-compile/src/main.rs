@@ -1,8 +1,9 @@
 use std::fs;
 
 use anyhow::Context;
+use cairo_lang_sierra::prune::prune_unreachable_code;
 use cairo_lang_sierra::ProgramParser;
-use cairo_lang_sierra_to_casm::metadata::calc_metadata;
+use cairo_lang_sierra_to_casm::metadata::{calc_metadata, MetadataComputationConfig};
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 use indoc::indoc;
@@ -15,6 +16,20 @@ struct Args {
     /// The file to compile
     file: String,
     output: String,
+    /// A file to write the solved gas variables of each statement to, as JSON.
+    #[arg(long)]
+    gas_report: Option<String>,
+    /// The functions to compile from. If given, all code unreachable from these functions is
+    /// removed before compilation, and a report of the removed code is printed.
+    #[arg(long = "entry-point")]
+    entry_points: Vec<String>,
+    /// Lowers `ec_state_add_mul` without the EC op builtin, for layouts that do not include it.
+    #[arg(long)]
+    software_ec_op: bool,
+    /// Represents enums with a zero niche, such as `Option<NonZero<T>>`, without a variant
+    /// selector.
+    #[arg(long)]
+    enum_zero_niche: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -24,20 +39,37 @@ fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
     let sierra_code = fs::read_to_string(args.file).with_context(|| "Could not read file!")?;
-    let Ok(program) = ProgramParser::new().parse(&sierra_code) else {
+    let Ok(mut program) = ProgramParser::new().parse(&sierra_code) else {
         anyhow::bail!(indoc!{"
             Failed to parse sierra program.
             Note: StarkNet contracts should be compiled with `starknet-sierra-compile`."
     })};
+    if !args.entry_points.is_empty() {
+        let entry_points: Vec<_> = args.entry_points.into_iter().map(Into::into).collect();
+        let (pruned_program, report) = prune_unreachable_code(&program, &entry_points)
+            .with_context(|| "Failed pruning unreachable code.")?;
+        print!("{report}");
+        program = pruned_program;
+    }
 
-    let gas_usage_check = true;
-    let cairo_program = cairo_lang_sierra_to_casm::compiler::compile(
+    let metadata = calc_metadata(
         &program,
-        &calc_metadata(&program, Default::default())
-            .with_context(|| "Failed calculating Sierra variables.")?,
-        gas_usage_check,
+        MetadataComputationConfig {
+            software_ec_op: args.software_ec_op,
+            enum_zero_niche: args.enum_zero_niche,
+            ..Default::default()
+        },
     )
-    .with_context(|| "Compilation failed.")?;
+    .with_context(|| "Failed calculating Sierra variables.")?;
+    if let Some(path) = args.gas_report {
+        let gas_report = serde_json::to_string_pretty(&metadata.gas_info.report())
+            .with_context(|| "Gas report serialization failed.")?;
+        fs::write(path, gas_report).with_context(|| "Failed to write gas report.")?;
+    }
 
+    let gas_usage_check = true;
+    let cairo_program =
+        cairo_lang_sierra_to_casm::compiler::compile(&program, &metadata, gas_usage_check)
+            .with_context(|| "Compilation failed.")?;
     fs::write(args.output, format!("{cairo_program}")).with_context(|| "Failed to write output.")
 }
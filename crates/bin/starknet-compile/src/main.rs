@@ -26,6 +26,9 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// Adds the human-readable names of the sierra ids to the debug info of the contract class.
+    #[arg(long, default_value_t = false)]
+    add_debug_info: bool,
     /// The allowed libfuncs list to use (default: most recent audited list).
     #[arg(long)]
     allowed_libfuncs_list_name: Option<String>,
@@ -46,7 +49,11 @@ fn main() -> anyhow::Result<()> {
     let res = starknet_compile(
         args.path,
         args.contract_path,
-        Some(CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() }),
+        Some(CompilerConfig {
+            replace_ids: args.replace_ids,
+            add_debug_info: args.add_debug_info,
+            ..CompilerConfig::default()
+        }),
         Some(list_selector),
     )?;
     match args.output {
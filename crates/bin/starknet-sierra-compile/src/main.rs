@@ -26,6 +26,9 @@ struct Args {
     /// Add pythonic hints.
     #[arg(long, default_value_t = false)]
     add_pythonic_hints: bool,
+    /// A file to write the compiled class hash preimage to, as JSON.
+    #[arg(long)]
+    class_hash_preimage_output: Option<String>,
 }
 
 /// Same as `ContractClass` - but ignores `abi` in deserialization.
@@ -61,12 +64,19 @@ fn main() -> anyhow::Result<()> {
         contract_class_version,
         entry_points_by_type,
         abi: None,
+        aux_data: Default::default(),
     };
     validate_compatible_sierra_version(&contract_class, list_selector)?;
     let casm_contract =
         CasmContractClass::from_contract_class(contract_class, args.add_pythonic_hints)
             .with_context(|| "Compilation failed.")?;
 
+    if let Some(path) = args.class_hash_preimage_output {
+        let preimage = serde_json::to_string_pretty(&casm_contract.hash_preimage())
+            .with_context(|| "Class hash preimage serialization failed.")?;
+        fs::write(path, preimage).with_context(|| "Failed to write class hash preimage.")?;
+    }
+
     let res = serde_json::to_string_pretty(&casm_contract)
         .with_context(|| "Casm contract Serialization failed.")?;
 
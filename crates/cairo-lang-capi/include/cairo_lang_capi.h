@@ -0,0 +1,47 @@
+/*
+ * C API for embedding the Cairo compiler.
+ *
+ * Every function takes a NUL terminated JSON request and writes a NUL terminated JSON response to
+ * `*response`, which must be released with `cairo_capi_free_string`. On success the response holds
+ * the result, otherwise it is of the form `{"error": "<message>"}`. See the documentation of the
+ * `cairo-lang-capi` crate for the structure of the requests and responses.
+ */
+
+#ifndef CAIRO_LANG_CAPI_H
+#define CAIRO_LANG_CAPI_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The status of a C API call. */
+typedef enum CairoStatus {
+    /* The call succeeded. */
+    CAIRO_STATUS_OK = 0,
+    /* A null pointer, or a string that is not valid UTF-8, was passed. */
+    CAIRO_STATUS_INVALID_ARGUMENT = 1,
+    /* The request is not a JSON of the expected structure. */
+    CAIRO_STATUS_INVALID_REQUEST = 2,
+    /* The compilation failed, e.g. due to errors in the compiled code. */
+    CAIRO_STATUS_COMPILATION_FAILED = 3,
+    /* An unexpected error occurred in the compiler. */
+    CAIRO_STATUS_INTERNAL_ERROR = 4,
+} CairoStatus;
+
+/* Compiles a Cairo project into a Sierra program. */
+CairoStatus cairo_capi_compile_project(const char *request, char **response);
+
+/* Compiles a Starknet contract into a contract class, and optionally into a CASM contract class. */
+CairoStatus cairo_capi_compile_contract(const char *request, char **response);
+
+/* Computes the compiled class hash of a CASM contract class. */
+CairoStatus cairo_capi_compute_class_hash(const char *request, char **response);
+
+/* Releases a response returned by any of the functions above. */
+void cairo_capi_free_string(char *response);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CAIRO_LANG_CAPI_H */
@@ -0,0 +1,261 @@
+//! C API for embedding the Cairo compiler, so tools not written in Rust can compile projects and
+//! contracts without running the compiler binaries.
+//!
+//! Every function takes a NUL terminated JSON request and writes a NUL terminated JSON response to
+//! `*response`, which must be released with [cairo_capi_free_string]. On success the response
+//! holds the result, otherwise it is of the form `{"error": "<message>"}`, and the returned
+//! [CairoStatus] tells what went wrong. The C declarations are in `include/cairo_lang_capi.h`.
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
+use cairo_lang_starknet::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use cairo_lang_starknet::contract_class::{compile_path, ContractClass};
+use cairo_lang_utils::bigint::BigUintAsHex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod test;
+
+/// The status of a C API call.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CairoStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A null pointer, or a string that is not valid UTF-8, was passed.
+    InvalidArgument = 1,
+    /// The request is not a JSON of the expected structure.
+    InvalidRequest = 2,
+    /// The compilation failed, e.g. due to errors in the compiled code.
+    CompilationFailed = 3,
+    /// An unexpected error occurred in the compiler.
+    InternalError = 4,
+}
+
+/// A failed call, with the status to return and the message to respond with.
+struct CallError {
+    status: CairoStatus,
+    message: String,
+}
+impl CallError {
+    fn new(status: CairoStatus, message: impl ToString) -> Self {
+        Self { status, message: message.to_string() }
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompileProjectRequest {
+    /// The path of the project - a `.cairo` file, or a directory with a `cairo_project.toml`.
+    path: PathBuf,
+    /// Whether to replace the Sierra ids with human readable ones.
+    #[serde(default)]
+    replace_ids: bool,
+}
+
+#[derive(Serialize)]
+struct CompileProjectResponse {
+    /// The text of the compiled Sierra program.
+    sierra_program: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompileContractRequest {
+    /// The path of the project containing the contract.
+    path: PathBuf,
+    /// The full path of the contract module, required if the project has several contracts.
+    #[serde(default)]
+    contract_path: Option<String>,
+    /// Whether to replace the Sierra ids with human readable ones.
+    #[serde(default)]
+    replace_ids: bool,
+    /// Whether to add the human readable names of the Sierra ids to the contract class debug info.
+    #[serde(default)]
+    add_debug_info: bool,
+    /// The name of the allowed libfuncs list to validate against, the default list if missing.
+    #[serde(default)]
+    allowed_libfuncs_list_name: Option<String>,
+    /// Whether to also compile the contract class to CASM.
+    #[serde(default)]
+    compile_casm: bool,
+}
+
+#[derive(Serialize)]
+struct CompileContractResponse {
+    contract_class: ContractClass,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    casm_contract_class: Option<CasmContractClass>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ComputeClassHashRequest {
+    casm_contract_class: CasmContractClass,
+}
+
+#[derive(Serialize)]
+struct ComputeClassHashResponse {
+    compiled_class_hash: BigUintAsHex,
+}
+
+/// Compiles a Cairo project into a Sierra program.
+///
+/// Request: `{"path": string, "replace_ids"?: bool}`.
+/// Response: `{"sierra_program": string}`.
+///
+/// # Safety
+/// `request` must be a valid NUL terminated string, and `response` a valid pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_capi_compile_project(
+    request: *const c_char,
+    response: *mut *mut c_char,
+) -> CairoStatus {
+    handle_call(request, response, |request: CompileProjectRequest| {
+        let mut diagnostics = String::new();
+        let sierra_program = compile_cairo_project_at_path(
+            &request.path,
+            CompilerConfig {
+                diagnostics_reporter: DiagnosticsReporter::write_to_string(&mut diagnostics),
+                replace_ids: request.replace_ids,
+                ..CompilerConfig::default()
+            },
+        );
+        let sierra_program = sierra_program.map_err(|err| compilation_error(err, &diagnostics))?;
+        Ok(CompileProjectResponse { sierra_program: sierra_program.to_string() })
+    })
+}
+
+/// Compiles a Starknet contract into a contract class, and optionally into a CASM contract class.
+///
+/// Request: `{"path": string, "contract_path"?: string, "replace_ids"?: bool,
+/// "add_debug_info"?: bool, "allowed_libfuncs_list_name"?: string, "compile_casm"?: bool}`.
+/// Response: `{"contract_class": object, "casm_contract_class"?: object}`.
+///
+/// # Safety
+/// `request` must be a valid NUL terminated string, and `response` a valid pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_capi_compile_contract(
+    request: *const c_char,
+    response: *mut *mut c_char,
+) -> CairoStatus {
+    handle_call(request, response, |request: CompileContractRequest| {
+        let mut diagnostics = String::new();
+        let contract_class = compile_path(
+            &request.path,
+            request.contract_path.as_deref(),
+            CompilerConfig {
+                diagnostics_reporter: DiagnosticsReporter::write_to_string(&mut diagnostics),
+                replace_ids: request.replace_ids,
+                add_debug_info: request.add_debug_info,
+                ..CompilerConfig::default()
+            },
+        );
+        let contract_class = contract_class.map_err(|err| compilation_error(err, &diagnostics))?;
+        let list_selector = match request.allowed_libfuncs_list_name {
+            Some(list_name) => ListSelector::ListName(list_name),
+            None => ListSelector::DefaultList,
+        };
+        validate_compatible_sierra_version(&contract_class, list_selector)
+            .map_err(|err| CallError::new(CairoStatus::CompilationFailed, err))?;
+        let casm_contract_class = if request.compile_casm {
+            let casm_contract_class =
+                CasmContractClass::from_contract_class(contract_class.clone(), false)
+                    .map_err(|err| CallError::new(CairoStatus::CompilationFailed, err))?;
+            Some(casm_contract_class)
+        } else {
+            None
+        };
+        Ok(CompileContractResponse { contract_class, casm_contract_class })
+    })
+}
+
+/// Computes the compiled class hash of a CASM contract class.
+///
+/// Request: `{"casm_contract_class": object}`.
+/// Response: `{"compiled_class_hash": string}`.
+///
+/// # Safety
+/// `request` must be a valid NUL terminated string, and `response` a valid pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_capi_compute_class_hash(
+    request: *const c_char,
+    response: *mut *mut c_char,
+) -> CairoStatus {
+    handle_call(request, response, |request: ComputeClassHashRequest| {
+        let compiled_class_hash = request
+            .casm_contract_class
+            .compiled_class_hash()
+            .map_err(|err| CallError::new(CairoStatus::InvalidRequest, err))?;
+        Ok(ComputeClassHashResponse {
+            compiled_class_hash: BigUintAsHex { value: compiled_class_hash },
+        })
+    })
+}
+
+/// Releases a response returned by any of the C API functions.
+///
+/// # Safety
+/// `response` must be null, or a response returned by the C API that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_capi_free_string(response: *mut c_char) {
+    if !response.is_null() {
+        drop(CString::from_raw(response));
+    }
+}
+
+/// Parses the request, runs `call` on it, and writes its result or error as the response.
+unsafe fn handle_call<Request: DeserializeOwned, Response: Serialize>(
+    request: *const c_char,
+    response: *mut *mut c_char,
+    call: impl FnOnce(Request) -> Result<Response, CallError>,
+) -> CairoStatus {
+    if response.is_null() {
+        return CairoStatus::InvalidArgument;
+    }
+    let result = parse_request(request).and_then(|request| {
+        // Panics must not unwind across the C ABI.
+        catch_unwind(AssertUnwindSafe(|| call(request))).unwrap_or_else(|_| {
+            Err(CallError::new(CairoStatus::InternalError, "The compiler panicked."))
+        })
+    });
+    let (status, response_json) = match result {
+        Ok(value) => (CairoStatus::Ok, serde_json::to_string(&value)),
+        Err(CallError { status, message }) => {
+            (status, serde_json::to_string(&ErrorResponse { error: message }))
+        }
+    };
+    let response_json = response_json.expect("Serializing a response should never fail.");
+    // JSON strings escape NUL characters, so the response contains none.
+    *response = CString::new(response_json).unwrap().into_raw();
+    status
+}
+
+/// Parses a JSON request from a NUL terminated string.
+unsafe fn parse_request<Request: DeserializeOwned>(
+    request: *const c_char,
+) -> Result<Request, CallError> {
+    if request.is_null() {
+        return Err(CallError::new(CairoStatus::InvalidArgument, "The request is null."));
+    }
+    let request = CStr::from_ptr(request)
+        .to_str()
+        .map_err(|_| CallError::new(CairoStatus::InvalidArgument, "The request is not UTF-8."))?;
+    serde_json::from_str(request).map_err(|err| CallError::new(CairoStatus::InvalidRequest, err))
+}
+
+/// Returns the error of a failed compilation, including its diagnostics.
+fn compilation_error(err: anyhow::Error, diagnostics: &str) -> CallError {
+    CallError::new(CairoStatus::CompilationFailed, format!("{diagnostics}{err}"))
+}
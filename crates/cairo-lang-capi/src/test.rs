@@ -0,0 +1,85 @@
+use std::ffi::{c_char, CStr, CString};
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use crate::{
+    cairo_capi_compile_contract, cairo_capi_compile_project, cairo_capi_compute_class_hash,
+    cairo_capi_free_string, CairoStatus,
+};
+
+type CApiFunction = unsafe extern "C" fn(*const c_char, *mut *mut c_char) -> CairoStatus;
+
+/// Calls a C API function with the given request, returning its status and parsed response.
+fn call(function: CApiFunction, request: &str) -> (CairoStatus, Value) {
+    let request = CString::new(request).unwrap();
+    let mut response = std::ptr::null_mut();
+    let status = unsafe { function(request.as_ptr(), &mut response) };
+    let value = serde_json::from_str(unsafe { CStr::from_ptr(response) }.to_str().unwrap());
+    unsafe { cairo_capi_free_string(response) };
+    (status, value.unwrap())
+}
+
+/// Returns the path of a file, relative to the root of the repository.
+fn repo_path(path: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", "..", path].iter().collect()
+}
+
+#[test]
+fn test_compile_project() {
+    let request = json!({"path": repo_path("examples/fib.cairo"), "replace_ids": true});
+    let (status, response) = call(cairo_capi_compile_project, &request.to_string());
+    assert_eq!(status, CairoStatus::Ok);
+    assert!(response["sierra_program"].as_str().unwrap().contains("fib::fib::fib"));
+}
+
+#[test]
+fn test_compile_contract_and_compute_class_hash() {
+    let request = json!({
+        "path": repo_path("crates/cairo-lang-starknet/test_data/minimal_contract.cairo"),
+        "compile_casm": true,
+    });
+    let (status, response) = call(cairo_capi_compile_contract, &request.to_string());
+    assert_eq!(status, CairoStatus::Ok);
+    assert!(response["contract_class"]["sierra_program"].is_array());
+
+    let request = json!({"casm_contract_class": response["casm_contract_class"]});
+    let (status, response) = call(cairo_capi_compute_class_hash, &request.to_string());
+    assert_eq!(status, CairoStatus::Ok);
+    assert_eq!(
+        response,
+        json!({
+            "compiled_class_hash":
+                "0x46f2882281342dea7694207216f95d925ba08ef4be0cff5e81e9057f49ef3c2"
+        })
+    );
+}
+
+#[test]
+fn test_compilation_failure() {
+    let request = json!({"path": repo_path("examples/no_such_file.cairo")});
+    let (status, response) = call(cairo_capi_compile_project, &request.to_string());
+    assert_eq!(status, CairoStatus::CompilationFailed);
+    assert!(response["error"].is_string());
+}
+
+#[test]
+fn test_invalid_request() {
+    let (status, response) = call(cairo_capi_compile_project, r#"{"file": "fib.cairo"}"#);
+    assert_eq!(status, CairoStatus::InvalidRequest);
+    assert!(response["error"].as_str().unwrap().starts_with("unknown field `file`"));
+}
+
+#[test]
+fn test_null_arguments() {
+    let mut response = std::ptr::null_mut();
+    let status = unsafe { cairo_capi_compile_project(std::ptr::null(), &mut response) };
+    assert_eq!(status, CairoStatus::InvalidArgument);
+    let response_json = unsafe { CStr::from_ptr(response) }.to_str().unwrap().to_string();
+    unsafe { cairo_capi_free_string(response) };
+    assert_eq!(response_json, r#"{"error":"The request is null."}"#);
+
+    let request = CString::new("{}").unwrap();
+    let status = unsafe { cairo_capi_compile_project(request.as_ptr(), std::ptr::null_mut()) };
+    assert_eq!(status, CairoStatus::InvalidArgument);
+}
@@ -15,6 +15,7 @@ use crate::instructions::{
     JnzInstruction, JumpInstruction, RetInstruction,
 };
 use crate::operand::{BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand};
+use crate::parser::StraightLineCode;
 
 #[cfg(test)]
 #[path = "builder_test.rs"]
@@ -117,6 +118,9 @@ pub struct CasmBuilder {
     /// Is the current state reachable.
     /// Example for unreachable state is after a unconditional jump, before any label is stated.
     reachable: bool,
+    /// The cells guessed by the hint of the current `nondet` block, that were not yet constrained
+    /// by an assertion. `None` when not inside a verification block.
+    unverified_guesses: Option<Vec<CellRef>>,
 }
 impl CasmBuilder {
     /// Finalizes the builder, with the requested labels as the returning branches.
@@ -129,6 +133,7 @@ impl CasmBuilder {
             self.current_hints.is_empty(),
             "Build cannot be called with hints as the last addition."
         );
+        assert!(self.unverified_guesses.is_none(), "Build cannot be called within a nondet block.");
         let label_offsets = self.compute_label_offsets();
         if self.reachable {
             self.label_state.insert("Fallthrough".to_owned(), self.main_state);
@@ -295,11 +300,34 @@ impl CasmBuilder {
                 }
             },
         };
+        if let Some(unverified_guesses) = &mut self.unverified_guesses {
+            let constrained = [self.main_state.get_value(dst), self.main_state.get_value(res)];
+            unverified_guesses
+                .retain(|cell| !constrained.iter().any(|expr| expression_uses_cell(expr, cell)));
+        }
         let instruction =
             self.get_instruction(InstructionBody::AssertEq(AssertEqInstruction { a, b }), true);
         self.statements.push(Statement::Final(instruction));
     }
 
+    /// Starts the verification of the values guessed by a hint - each of the `guessed` variables
+    /// must be part of an assertion before [Self::end_verification] is called.
+    pub fn start_verification<const GUESS_COUNT: usize>(&mut self, guessed: [Var; GUESS_COUNT]) {
+        assert!(self.unverified_guesses.is_none(), "Nested nondet blocks are not supported.");
+        self.unverified_guesses =
+            Some(guessed.iter().map(|v| self.as_cell_ref(*v, false)).collect());
+    }
+
+    /// Ends the verification started by [Self::start_verification], panicking if any of the
+    /// guessed variables was not constrained.
+    pub fn end_verification(&mut self) {
+        let unverified_guesses = self.unverified_guesses.take().expect("No nondet block to end.");
+        assert!(
+            unverified_guesses.is_empty(),
+            "Guessed cells were not constrained by their verification: {unverified_guesses:?}."
+        );
+    }
+
     /// Writes and increments a buffer.
     /// Useful for RangeCheck and similar buffers.
     /// `buffer` must be a cell reference, or a cell reference with a small added constant.
@@ -364,6 +392,45 @@ impl CasmBuilder {
         self.main_state.allocated += amount.into_or_panic::<i16>();
     }
 
+    /// Appends straight-line code, which uses the `inputs` - the last variables allocated - and
+    /// returns variables for the last `num_outputs` cells it allocates.
+    pub fn inline_straight_line_code(
+        &mut self,
+        code: StraightLineCode,
+        inputs: &[Var],
+        num_outputs: usize,
+    ) -> Vec<Var> {
+        assert!(self.current_hints.is_empty(), "Inline code cannot follow hints.");
+        assert!(
+            self.main_state.ap_change == self.main_state.allocated.into_or_panic::<usize>(),
+            "Inline code must follow a complete ap update."
+        );
+        for (i, input) in inputs.iter().enumerate() {
+            assert_eq!(
+                self.get_value(*input, true),
+                CellExpression::Deref(CellRef {
+                    register: Register::AP,
+                    offset: (i as i16) - inputs.len() as i16
+                }),
+                "Inline code inputs must be the last allocated variables."
+            );
+        }
+        assert!(num_outputs <= code.ap_change, "Inline code outputs must be allocated by it.");
+        code.validate_cells(inputs.len()).unwrap_or_else(|err| panic!("{err}"));
+        self.main_state.steps += code.instructions.len();
+        self.statements.extend(code.instructions.into_iter().map(Statement::Final));
+        self.increase_ap_change(code.ap_change);
+        let allocated = self.main_state.allocated;
+        (0..num_outputs)
+            .map(|i| {
+                self.add_var(CellExpression::Deref(CellRef {
+                    register: Register::AP,
+                    offset: allocated - (num_outputs - i) as i16,
+                }))
+            })
+            .collect()
+    }
+
     /// Returns a variable that is the `op` of `lhs` and `rhs`.
     /// `lhs` must be a cell reference and `rhs` must be deref or immediate.
     pub fn bin_op(&mut self, op: CellOperator, lhs: Var, rhs: Var) -> Var {
@@ -615,6 +682,17 @@ impl CasmBuilder {
     }
 }
 
+/// Returns whether `expr` depends on the value of `cell`.
+fn expression_uses_cell(expr: &CellExpression, cell: &CellRef) -> bool {
+    match expr {
+        CellExpression::Deref(other) | CellExpression::DoubleDeref(other, _) => other == cell,
+        CellExpression::Immediate(_) => false,
+        CellExpression::BinOp { a, b, .. } => {
+            a == cell || matches!(b, DerefOrImmediate::Deref(other) if other == cell)
+        }
+    }
+}
+
 impl Default for CasmBuilder {
     fn default() -> Self {
         Self {
@@ -624,6 +702,7 @@ impl Default for CasmBuilder {
             current_hints: Default::default(),
             var_count: Default::default(),
             reachable: true,
+            unverified_guesses: None,
         }
     }
 }
@@ -843,6 +922,24 @@ macro_rules! casm_build_extend {
             $($arg_name : $arg_value),*
         } into {}; $($tok)*)
     };
+    ($builder:ident, nondet {
+            guess hint $hint_head:ident$(::$hint_tail:ident)* {
+                $($input_name:ident : $input_value:ident),*
+            } into {
+                $($output_name:ident : $output_value:ident),*
+            };
+            verify { $($verify:tt)* }
+        }; $($tok:tt)*) => {
+        $crate::casm_build_extend!($builder, hint $hint_head$(::$hint_tail)* {
+            $($input_name : $input_value),*
+        } into {
+            $($output_name : $output_value),*
+        };);
+        $builder.start_verification([$($output_value),*]);
+        $crate::casm_build_extend!($builder, $($verify)*);
+        $builder.end_verification();
+        $crate::casm_build_extend!($builder, $($tok)*)
+    };
     ($builder:ident, rescope { $($new_var:ident = $value_var:ident),* }; $($tok:tt)*) => {
         $builder.rescope([$(($new_var, $value_var)),*]);
         $crate::casm_build_extend!($builder, $($tok)*)
@@ -5,6 +5,7 @@ use pretty_assertions::assert_eq;
 use super::CasmBuilder;
 use crate::builder::CasmBuildResult;
 use crate::cell_expression::CellExpression;
+use crate::parser::parse_straight_line_code;
 use crate::{casm_build_extend, res};
 
 #[test]
@@ -349,3 +350,83 @@ fn test_fail() {
         "}
     );
 }
+
+#[test]
+fn test_nondet() {
+    let mut builder = CasmBuilder::default();
+    let value = builder.add_var(CellExpression::from_res_operand(res!([fp - 3])));
+    casm_build_extend! {builder,
+        tempvar root;
+        nondet {
+            guess hint SquareRoot {value: value} into {dst: root};
+            verify {
+                assert value = root * root;
+            }
+        };
+    };
+    let CasmBuildResult { instructions, .. } = builder.build(["Fallthrough"]);
+    assert_eq!(
+        join(instructions.iter().map(|inst| format!("{inst};\n")), ""),
+        indoc! {"
+            %{
+            import math
+            memory[ap + 0] = math.isqrt(memory[fp + -3])
+            %}
+            [fp + -3] = [ap + 0] * [ap + 0], ap++;
+        "}
+    );
+}
+
+#[test]
+#[should_panic(expected = "Guessed cells were not constrained by their verification")]
+fn test_nondet_unconstrained_guess() {
+    let mut builder = CasmBuilder::default();
+    let value = builder.add_var(CellExpression::from_res_operand(res!([fp - 3])));
+    casm_build_extend! {builder,
+        tempvar root;
+        tempvar copy;
+        nondet {
+            guess hint SquareRoot {value: value} into {dst: root};
+            verify {
+                assert copy = value;
+            }
+        };
+    };
+}
+
+#[test]
+fn test_inline_straight_line_code() {
+    let mut builder = CasmBuilder::default();
+    let a = builder.add_var(CellExpression::from_res_operand(res!([fp - 4])));
+    let b = builder.add_var(CellExpression::from_res_operand(res!([fp - 3])));
+    casm_build_extend! {builder,
+        tempvar a_copy = a;
+        tempvar b_copy = b;
+    };
+    let code = parse_straight_line_code("[ap + 0] = [ap + -2] * [ap + -1], ap++").unwrap();
+    let [product] = builder.inline_straight_line_code(code, &[a_copy, b_copy], 1)[..] else {
+        panic!("Expected a single output.");
+    };
+    casm_build_extend!(builder, tempvar result = product + a;);
+    let CasmBuildResult { instructions, branches: [(state, _)] } = builder.build(["Fallthrough"]);
+    assert_eq!(state.ap_change, 4);
+    assert_eq!(state.steps, 4);
+    assert_eq!(
+        join(instructions.iter().map(|inst| format!("{inst};\n")), ""),
+        indoc! {"
+            [ap + 0] = [fp + -4], ap++;
+            [ap + 0] = [fp + -3], ap++;
+            [ap + 0] = [ap + -2] * [ap + -1], ap++;
+            [ap + 0] = [ap + -1] + [fp + -4], ap++;
+        "}
+    );
+}
+
+#[test]
+#[should_panic(expected = "Inline code inputs must be the last allocated variables.")]
+fn test_inline_straight_line_code_wrong_inputs() {
+    let mut builder = CasmBuilder::default();
+    let a = builder.add_var(CellExpression::from_res_operand(res!([fp - 3])));
+    let code = parse_straight_line_code("[ap + 0] = [ap + -1], ap++").unwrap();
+    builder.inline_straight_line_code(code, &[a], 1);
+}
@@ -224,6 +224,60 @@ pub enum CoreHint {
     /// Returns an address with `size` free locations afterwards.
     #[codec(index = 26)]
     AllocConstantSize { size: ResOperand, dst: CellRef },
+    /// Allocates a new segment, and writes into it the indices of the values between `start` and
+    /// `end` in the order that sorts the values (ascending, keeping the order of equal values).
+    /// Both `start` and `end` must be pointers.
+    #[codec(index = 27)]
+    SortPermutation { start: ResOperand, end: ResOperand, dst: CellRef },
+    /// Finds the index of the range containing `value`, given the `n_bounds` lower bounds of the
+    /// ranges at `bounds`, sorted in ascending order. That is, the index of the last bound which
+    /// is at most `value`, or 0 if there is no such bound.
+    #[codec(index = 28)]
+    FindRangeIndex { value: ResOperand, bounds: ResOperand, n_bounds: ResOperand, dst: CellRef },
+    /// Reports that the execution reached the instrumentation probe with the given id.
+    #[codec(index = 29)]
+    InstrumentationHit { id: ResOperand },
+    /// Computes the inverse of `b` modulo `n` (both represented by 2 128bit limbs), if it exists.
+    /// Writes `has_inverse` and some `x` and `y` (represented by 2 128bit limbs) such that
+    /// `b * x - n * y = has_inverse`, where `x` is the inverse if it exists, and otherwise a
+    /// non-zero value smaller than `n` (unless `n` is 1, where `x` is 0).
+    /// In all cases - `name`0 is the least significant limb.
+    #[codec(index = 30)]
+    U256InvModN {
+        b0: ResOperand,
+        b1: ResOperand,
+        n0: ResOperand,
+        n1: ResOperand,
+        has_inverse: CellRef,
+        x0: CellRef,
+        x1: CellRef,
+        y0: CellRef,
+        y1: CellRef,
+    },
+    /// Calls the oracle with the given selector on the values between `input_start` and
+    /// `input_end`, and writes the pointers to the start and end of its output.
+    #[codec(index = 31)]
+    Oracle {
+        selector: BigIntAsHex,
+        input_start: ResOperand,
+        input_end: ResOperand,
+        output_start: CellRef,
+        output_end: CellRef,
+    },
+    /// Registers the ECDSA signature `(r, s)` in the ECDSA builtin, for the instance at
+    /// `ecdsa_ptr`.
+    #[codec(index = 32)]
+    AddEcdsaSignature { ecdsa_ptr: ResOperand, r: ResOperand, s: ResOperand },
+    /// Fills the values deduced by the AddMod and MulMod builtins, for the `n_add_mods` operations
+    /// described by the AddMod instance at `add_mod_ptr` and the `n_mul_mods` operations described
+    /// by the MulMod instance at `mul_mod_ptr`. The operations may depend on each other's results.
+    #[codec(index = 33)]
+    FillModBuiltins {
+        add_mod_ptr: ResOperand,
+        n_add_mods: ResOperand,
+        mul_mod_ptr: ResOperand,
+        n_mul_mods: ResOperand,
+    },
 }
 
 /// Represents a deprecated hint which is kept for backward compatibility of previously deployed
@@ -651,6 +705,86 @@ impl PythonicHint for CoreHint {
                     ResOperandFormatter(size)
                 )
             }
+            CoreHint::SortPermutation { start, end, dst } => formatdoc!(
+                "
+
+                    start = {}
+                    values = [memory[start + i] for i in range({} - start)]
+                    memory{dst} = segments.add()
+                    for i, index in enumerate(sorted(range(len(values)), key=lambda i: values[i])):
+                        memory[memory{dst} + i] = index
+                ",
+                ResOperandFormatter(start),
+                ResOperandFormatter(end),
+            ),
+            CoreHint::FindRangeIndex { value, bounds, n_bounds, dst } => formatdoc!(
+                "
+
+                    value = {}
+                    bounds = {}
+                    memory{dst} = max([0] + [i for i in range({}) if memory[bounds + i] <= value])
+                ",
+                ResOperandFormatter(value),
+                ResOperandFormatter(bounds),
+                ResOperandFormatter(n_bounds),
+            ),
+            CoreHint::InstrumentationHit { id } => {
+                let id = ResOperandFormatter(id);
+                formatdoc!(
+                    "
+
+                        if '__instrumentation_hits' not in globals():
+                            __instrumentation_hits = {{}}
+                        __instrumentation_hits[{id}] = __instrumentation_hits.get({id}, 0) + 1
+                    "
+                )
+            }
+            CoreHint::U256InvModN { b0, b1, n0, n1, has_inverse, x0, x1, y0, y1 } => {
+                let [b0, b1, n0, n1] = [b0, b1, n0, n1].map(ResOperandFormatter);
+                formatdoc!(
+                    "
+
+                        import math
+                        b = {b0} + {b1} * 2**128
+                        n = {n0} + {n1} * 2**128
+                        g = math.gcd(b, n)
+                        if n == 1:
+                            memory{has_inverse} = 0
+                            x, y = 0, 0
+                        elif g != 1:
+                            memory{has_inverse} = 0
+                            x, y = n // g, b // g
+                        else:
+                            memory{has_inverse} = 1
+                            x = pow(b, -1, n)
+                            y = (b * x - 1) // n
+                        memory{x0} = x & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{x1} = x >> 128
+                        memory{y0} = y & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+                        memory{y1} = y >> 128
+                    "
+                )
+            }
+            CoreHint::Oracle { .. } => "raise NotImplementedError".to_string(),
+            CoreHint::AddEcdsaSignature { ecdsa_ptr, r, s } => {
+                let [ecdsa_ptr, r, s] = [ecdsa_ptr, r, s].map(ResOperandFormatter);
+                format!("ecdsa_builtin.add_signature({ecdsa_ptr}, ({r}, {s}))")
+            }
+            CoreHint::FillModBuiltins { add_mod_ptr, n_add_mods, mul_mod_ptr, n_mul_mods } => {
+                let [add_mod_ptr, n_add_mods, mul_mod_ptr, n_mul_mods] =
+                    [add_mod_ptr, n_add_mods, mul_mod_ptr, n_mul_mods].map(ResOperandFormatter);
+                formatdoc!(
+                    "
+
+                        from starkware.cairo.lang.builtins.modulo.mod_builtin_runner import ModBuiltinRunner
+                        ModBuiltinRunner.fill_memory(
+                            memory=memory,
+                            add_mod=({add_mod_ptr}, builtin_runners['add_mod_builtin'], {n_add_mods}),
+                            mul_mod=({mul_mod_ptr}, builtin_runners['mul_mod_builtin'], {n_mul_mods}),
+                        )
+                    "
+                )
+            }
         }
     }
 }
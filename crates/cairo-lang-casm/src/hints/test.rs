@@ -116,6 +116,58 @@ fn test_debug_hint_format() {
     );
 }
 
+#[test]
+fn test_sort_permutation_hint_format() {
+    assert_eq!(
+        CoreHint::SortPermutation {
+            start: res!([fp - 4]),
+            end: res!([fp - 3]),
+            dst: CellRef { register: Register::AP, offset: 0 }
+        }
+        .get_pythonic_hint(),
+        indoc! {"
+
+            start = memory[fp + -4]
+            values = [memory[start + i] for i in range(memory[fp + -3] - start)]
+            memory[ap + 0] = segments.add()
+            for i, index in enumerate(sorted(range(len(values)), key=lambda i: values[i])):
+                memory[memory[ap + 0] + i] = index
+        "}
+    );
+}
+
+#[test]
+fn test_find_range_index_hint_format() {
+    assert_eq!(
+        CoreHint::FindRangeIndex {
+            value: res!([ap - 1]),
+            bounds: res!([fp + 2]),
+            n_bounds: res!(7),
+            dst: CellRef { register: Register::AP, offset: 0 }
+        }
+        .get_pythonic_hint(),
+        indoc! {"
+
+            value = memory[ap + -1]
+            bounds = memory[fp + 2]
+            memory[ap + 0] = max([0] + [i for i in range(7) if memory[bounds + i] <= value])
+        "}
+    );
+}
+
+#[test]
+fn test_instrumentation_hit_hint_format() {
+    assert_eq!(
+        CoreHint::InstrumentationHit { id: res!(12) }.get_pythonic_hint(),
+        indoc! {"
+
+            if '__instrumentation_hits' not in globals():
+                __instrumentation_hits = {}
+            __instrumentation_hits[12] = __instrumentation_hits.get(12, 0) + 1
+        "}
+    );
+}
+
 #[test]
 fn encode_hint() {
     let hint = Hint::Core(CoreHintBase::Core(CoreHint::TestLessThan {
@@ -133,3 +185,77 @@ fn encode_hint() {
     let decoded = Hint::decode(&mut encoding.as_slice()).unwrap();
     assert_eq!(hint, decoded);
 }
+
+#[test]
+fn test_u256_inv_mod_n_hint_format() {
+    assert_eq!(
+        CoreHint::U256InvModN {
+            b0: res!([fp - 6]),
+            b1: res!([fp - 5]),
+            n0: res!([fp - 4]),
+            n1: res!([fp - 3]),
+            has_inverse: CellRef { register: Register::AP, offset: 0 },
+            x0: CellRef { register: Register::AP, offset: 1 },
+            x1: CellRef { register: Register::AP, offset: 2 },
+            y0: CellRef { register: Register::AP, offset: 3 },
+            y1: CellRef { register: Register::AP, offset: 4 },
+        }
+        .get_pythonic_hint(),
+        indoc! {"
+
+            import math
+            b = memory[fp + -6] + memory[fp + -5] * 2**128
+            n = memory[fp + -4] + memory[fp + -3] * 2**128
+            g = math.gcd(b, n)
+            if n == 1:
+                memory[ap + 0] = 0
+                x, y = 0, 0
+            elif g != 1:
+                memory[ap + 0] = 0
+                x, y = n // g, b // g
+            else:
+                memory[ap + 0] = 1
+                x = pow(b, -1, n)
+                y = (b * x - 1) // n
+            memory[ap + 1] = x & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+            memory[ap + 2] = x >> 128
+            memory[ap + 3] = y & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+            memory[ap + 4] = y >> 128
+        "}
+    );
+}
+
+#[test]
+fn test_add_ecdsa_signature_hint_format() {
+    assert_eq!(
+        CoreHint::AddEcdsaSignature {
+            ecdsa_ptr: res!([fp - 3]),
+            r: res!([fp - 5]),
+            s: res!([fp - 4]),
+        }
+        .get_pythonic_hint(),
+        "ecdsa_builtin.add_signature(memory[fp + -3], (memory[fp + -5], memory[fp + -4]))"
+    );
+}
+
+#[test]
+fn test_fill_mod_builtins_hint_format() {
+    assert_eq!(
+        CoreHint::FillModBuiltins {
+            add_mod_ptr: res!([fp - 4]),
+            n_add_mods: res!([ap - 1]),
+            mul_mod_ptr: res!(0),
+            n_mul_mods: res!(0),
+        }
+        .get_pythonic_hint(),
+        indoc! {"
+
+            from starkware.cairo.lang.builtins.modulo.mod_builtin_runner import ModBuiltinRunner
+            ModBuiltinRunner.fill_memory(
+                memory=memory,
+                add_mod=(memory[fp + -4], builtin_runners['add_mod_builtin'], memory[ap + -1]),
+                mul_mod=(0, builtin_runners['mul_mod_builtin'], 0),
+            )
+        "}
+    );
+}
@@ -0,0 +1,330 @@
+//! A parser of CASM instructions from their textual representation, the inverse of their
+//! [std::fmt::Display] implementations.
+//!
+//! Instructions are separated by `;` or by new lines, and `//` starts a comment that runs to the
+//! end of the line. Hints are not supported.
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use thiserror::Error;
+
+use crate::instructions::{
+    AddApInstruction, AssertEqInstruction, CallInstruction, Instruction, InstructionBody,
+    JnzInstruction, JumpInstruction, RetInstruction,
+};
+use crate::operand::{BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand};
+
+#[cfg(test)]
+#[path = "parser_test.rs"]
+mod test;
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum CasmParseError {
+    #[error("Instruction {instruction_idx}: expected {expected} at `{found}`.")]
+    Unexpected { instruction_idx: usize, expected: &'static str, found: String },
+    #[error("Instruction {instruction_idx}: offset {offset} is out of range.")]
+    OffsetOutOfRange { instruction_idx: usize, offset: BigInt },
+    #[error(
+        "Instruction {instruction_idx}: `{instruction}` is not allowed in straight-line code."
+    )]
+    NotStraightLine { instruction_idx: usize, instruction: String },
+    #[error("Instruction {instruction_idx}: `{cell}` is out of the bounds of the code.")]
+    CellOutOfBounds { instruction_idx: usize, cell: String },
+}
+
+/// Parses the given CASM code into instructions.
+pub fn parse_instructions(code: &str) -> Result<Vec<Instruction>, CasmParseError> {
+    code.lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .flat_map(|line| line.split(';'))
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .enumerate()
+        .map(|(instruction_idx, text)| {
+            InstructionParser { instruction_idx, text, pos: 0 }.parse_instruction()
+        })
+        .collect()
+}
+
+/// CASM code without any jumps, calls or returns, so it always runs from its first instruction to
+/// its last one.
+#[derive(Debug, Eq, PartialEq)]
+pub struct StraightLineCode {
+    pub instructions: Vec<Instruction>,
+    /// The ap change of running the whole code.
+    pub ap_change: usize,
+}
+
+/// Parses the given CASM code, and verifies it is straight-line code with a known ap change.
+pub fn parse_straight_line_code(code: &str) -> Result<StraightLineCode, CasmParseError> {
+    let instructions = parse_instructions(code)?;
+    let mut ap_change = 0;
+    for (instruction_idx, instruction) in instructions.iter().enumerate() {
+        match &instruction.body {
+            InstructionBody::AssertEq(_) => {}
+            InstructionBody::AddAp(AddApInstruction { operand: ResOperand::Immediate(value) }) => {
+                ap_change +=
+                    value.value.to_usize().ok_or_else(|| CasmParseError::NotStraightLine {
+                        instruction_idx,
+                        instruction: instruction.to_string(),
+                    })?;
+            }
+            InstructionBody::AddAp(_)
+            | InstructionBody::Call(_)
+            | InstructionBody::Jnz(_)
+            | InstructionBody::Jump(_)
+            | InstructionBody::Ret(_) => {
+                return Err(CasmParseError::NotStraightLine {
+                    instruction_idx,
+                    instruction: instruction.to_string(),
+                });
+            }
+        }
+        if instruction.inc_ap {
+            ap_change += 1;
+        }
+    }
+    Ok(StraightLineCode { instructions, ap_change })
+}
+
+impl StraightLineCode {
+    /// Validates that the code only references its `num_inputs` inputs, which are pushed to the
+    /// stack right before it, and the cells it allocates.
+    pub fn validate_cells(&self, num_inputs: usize) -> Result<(), CasmParseError> {
+        let bounds = -(num_inputs as i64)..self.ap_change as i64;
+        let mut ap_offset = 0;
+        for (instruction_idx, instruction) in self.instructions.iter().enumerate() {
+            let mut cells = vec![];
+            match &instruction.body {
+                InstructionBody::AssertEq(AssertEqInstruction { a, b }) => {
+                    cells.push(*a);
+                    push_res_operand_cells(b, &mut cells);
+                }
+                InstructionBody::AddAp(AddApInstruction { operand }) => {
+                    push_res_operand_cells(operand, &mut cells);
+                }
+                InstructionBody::Call(_)
+                | InstructionBody::Jnz(_)
+                | InstructionBody::Jump(_)
+                | InstructionBody::Ret(_) => unreachable!("Not straight-line code."),
+            }
+            if let Some(cell) = cells.into_iter().find(|cell| {
+                cell.register != Register::AP || !bounds.contains(&(ap_offset + cell.offset as i64))
+            }) {
+                return Err(CasmParseError::CellOutOfBounds {
+                    instruction_idx,
+                    cell: cell.to_string(),
+                });
+            }
+            if let InstructionBody::AddAp(AddApInstruction {
+                operand: ResOperand::Immediate(value),
+            }) = &instruction.body
+            {
+                ap_offset += value.value.to_i64().unwrap_or_default();
+            }
+            if instruction.inc_ap {
+                ap_offset += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Pushes the cells referenced by `operand` into `cells`.
+fn push_res_operand_cells(operand: &ResOperand, cells: &mut Vec<CellRef>) {
+    match operand {
+        ResOperand::Deref(cell) | ResOperand::DoubleDeref(cell, _) => cells.push(*cell),
+        ResOperand::Immediate(_) => {}
+        ResOperand::BinOp(BinOpOperand { a, b, .. }) => {
+            cells.push(*a);
+            if let DerefOrImmediate::Deref(b) = b {
+                cells.push(*b);
+            }
+        }
+    }
+}
+
+/// A parser of the text of a single instruction.
+struct InstructionParser<'a> {
+    instruction_idx: usize,
+    text: &'a str,
+    pos: usize,
+}
+impl<'a> InstructionParser<'a> {
+    fn parse_instruction(mut self) -> Result<Instruction, CasmParseError> {
+        let body = if self.eat_keyword("call") {
+            let relative = self.parse_relative()?;
+            InstructionBody::Call(CallInstruction {
+                target: self.parse_deref_or_immediate()?,
+                relative,
+            })
+        } else if self.eat_keyword("jmp") {
+            let relative = self.parse_relative()?;
+            let target = self.parse_deref_or_immediate()?;
+            if self.eat_keyword("if") {
+                if !relative {
+                    return Err(self.unexpected("a relative conditional jump"));
+                }
+                let condition = self.parse_cell_ref()?;
+                self.expect("!=")?;
+                self.expect("0")?;
+                InstructionBody::Jnz(JnzInstruction { jump_offset: target, condition })
+            } else {
+                InstructionBody::Jump(JumpInstruction { target, relative })
+            }
+        } else if self.eat_keyword("ret") {
+            InstructionBody::Ret(RetInstruction {})
+        } else if self.eat_keyword("ap") {
+            self.expect("+=")?;
+            InstructionBody::AddAp(AddApInstruction { operand: self.parse_res_operand()? })
+        } else {
+            let a = self.parse_cell_ref()?;
+            self.expect("=")?;
+            InstructionBody::AssertEq(AssertEqInstruction { a, b: self.parse_res_operand()? })
+        };
+        let inc_ap = if self.eat(",") {
+            self.expect("ap++")?;
+            true
+        } else {
+            false
+        };
+        self.skip_whitespace();
+        if self.pos != self.text.len() {
+            return Err(self.unexpected("the end of the instruction"));
+        }
+        Ok(Instruction::new(body, inc_ap))
+    }
+
+    /// Parses `rel` or `abs`, returning whether it is relative.
+    fn parse_relative(&mut self) -> Result<bool, CasmParseError> {
+        if self.eat_keyword("rel") {
+            Ok(true)
+        } else if self.eat_keyword("abs") {
+            Ok(false)
+        } else {
+            Err(self.unexpected("`rel` or `abs`"))
+        }
+    }
+
+    /// Parses `[reg + offset]`.
+    fn parse_cell_ref(&mut self) -> Result<CellRef, CasmParseError> {
+        self.expect("[")?;
+        let register = if self.eat_keyword("ap") {
+            Register::AP
+        } else if self.eat_keyword("fp") {
+            Register::FP
+        } else {
+            return Err(self.unexpected("`ap` or `fp`"));
+        };
+        let offset = self.parse_offset()?;
+        self.expect("]")?;
+        Ok(CellRef { register, offset })
+    }
+
+    /// Parses an optional `+ offset` or `- offset`, returning 0 if there is none.
+    fn parse_offset(&mut self) -> Result<i16, CasmParseError> {
+        let offset = if self.eat("+") {
+            self.parse_number()?
+        } else if self.eat("-") {
+            -self.parse_number()?
+        } else {
+            return Ok(0);
+        };
+        offset.to_i16().ok_or(CasmParseError::OffsetOutOfRange {
+            instruction_idx: self.instruction_idx,
+            offset,
+        })
+    }
+
+    fn parse_deref_or_immediate(&mut self) -> Result<DerefOrImmediate, CasmParseError> {
+        if self.peek("[") {
+            Ok(DerefOrImmediate::Deref(self.parse_cell_ref()?))
+        } else {
+            Ok(DerefOrImmediate::Immediate(self.parse_number()?.into()))
+        }
+    }
+
+    fn parse_res_operand(&mut self) -> Result<ResOperand, CasmParseError> {
+        if self.peek("[[") {
+            self.expect("[")?;
+            let cell = self.parse_cell_ref()?;
+            let offset = self.parse_offset()?;
+            self.expect("]")?;
+            return Ok(ResOperand::DoubleDeref(cell, offset));
+        }
+        if !self.peek("[") {
+            return Ok(ResOperand::Immediate(self.parse_number()?.into()));
+        }
+        let a = self.parse_cell_ref()?;
+        let op = if self.eat("+") {
+            Operation::Add
+        } else if self.eat("*") {
+            Operation::Mul
+        } else {
+            return Ok(ResOperand::Deref(a));
+        };
+        Ok(ResOperand::BinOp(BinOpOperand { op, a, b: self.parse_deref_or_immediate()? }))
+    }
+
+    /// Parses a decimal or a `0x` prefixed hexadecimal number, with an optional `-` sign.
+    fn parse_number(&mut self) -> Result<BigInt, CasmParseError> {
+        self.skip_whitespace();
+        let negative = self.eat("-");
+        self.skip_whitespace();
+        let rest = &self.text[self.pos..];
+        let (radix, digits) = match rest.strip_prefix("0x") {
+            Some(digits) => (16, digits),
+            None => (10, rest),
+        };
+        let digits_len = digits.find(|c: char| !c.is_digit(radix)).unwrap_or(digits.len());
+        let value = BigInt::parse_bytes(digits[..digits_len].as_bytes(), radix)
+            .ok_or_else(|| self.unexpected("a number"))?;
+        self.pos += rest.len() - digits.len() + digits_len;
+        Ok(if negative { -value } else { value })
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Returns whether the remaining text starts with `token`, after whitespace.
+    fn peek(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        self.text[self.pos..].starts_with(token)
+    }
+
+    /// Consumes `token` if the remaining text starts with it.
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.peek(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    /// Consumes `keyword` if the remaining text starts with it as a whole word.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if !self.peek(keyword) {
+            return false;
+        }
+        let after = &self.text[self.pos + keyword.len()..];
+        if after.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+            return false;
+        }
+        self.pos += keyword.len();
+        true
+    }
+
+    fn expect(&mut self, token: &'static str) -> Result<(), CasmParseError> {
+        if self.eat(token) { Ok(()) } else { Err(self.unexpected(token)) }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> CasmParseError {
+        CasmParseError::Unexpected {
+            instruction_idx: self.instruction_idx,
+            expected,
+            found: self.text[self.pos..].to_string(),
+        }
+    }
+}
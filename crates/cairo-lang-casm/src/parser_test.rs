@@ -0,0 +1,90 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use test_log::test;
+
+use super::{parse_instructions, parse_straight_line_code, CasmParseError};
+
+#[test_case("[ap + 0] = [fp + -3] + 5, ap++")]
+#[test_case("[ap + 1] = [fp + 2] * [ap + -1]")]
+#[test_case("[fp + 0] = [[ap + -2] + 3]")]
+#[test_case("[ap + 0] = -7, ap++")]
+#[test_case("ap += 3")]
+#[test_case("call rel 4")]
+#[test_case("call abs [fp + 1]")]
+#[test_case("jmp rel -2")]
+#[test_case("jmp abs [ap + 0]")]
+#[test_case("jmp rel 3 if [ap + -1] != 0")]
+#[test_case("ret")]
+fn test_parse_round_trip(text: &str) {
+    let instructions = parse_instructions(text).unwrap();
+    assert_eq!(instructions.len(), 1);
+    assert_eq!(instructions[0].to_string(), text);
+}
+
+#[test]
+fn test_parse_multiple_instructions() {
+    let instructions = parse_instructions(indoc! {"
+        // Computes x + 1 and its square.
+        [ap+0] = [fp - 3] + 1, ap++;
+        [ap + 0] = [ap - 1] * [ap - 1], ap++
+        ap += 0x2;
+    "})
+    .unwrap();
+    assert_eq!(
+        instructions.iter().map(|instruction| instruction.to_string()).collect::<Vec<_>>(),
+        vec!["[ap + 0] = [fp + -3] + 1, ap++", "[ap + 0] = [ap + -1] * [ap + -1], ap++", "ap += 2"]
+    );
+}
+
+#[test_case("[ap + 0] = ", "a number"; "missing operand")]
+#[test_case("[sp + 0] = 1", "`ap` or `fp`"; "bad register")]
+#[test_case("[ap + 0] = 1, fp++", "ap++"; "bad increment")]
+#[test_case("jmp abs 3 if [ap + 0] != 0", "a relative conditional jump"; "absolute jnz")]
+#[test_case("ret 5", "the end of the instruction"; "trailing tokens")]
+fn test_parse_failure(text: &str, expected: &str) {
+    assert!(matches!(
+        parse_instructions(text),
+        Err(CasmParseError::Unexpected { instruction_idx: 0, expected: e, .. }) if e == expected
+    ));
+}
+
+#[test]
+fn test_parse_offset_out_of_range() {
+    assert!(matches!(
+        parse_instructions("[ap + 40000] = 1"),
+        Err(CasmParseError::OffsetOutOfRange { instruction_idx: 0, .. })
+    ));
+}
+
+#[test]
+fn test_parse_straight_line_code() {
+    let code =
+        parse_straight_line_code("[ap + 0] = 1, ap++; ap += 3; [ap + -1] = [ap + -4]").unwrap();
+    assert_eq!(code.instructions.len(), 3);
+    assert_eq!(code.ap_change, 4);
+
+    assert_eq!(
+        parse_straight_line_code("[ap + 0] = 1, ap++; jmp rel -2"),
+        Err(CasmParseError::NotStraightLine {
+            instruction_idx: 1,
+            instruction: "jmp rel -2".into()
+        })
+    );
+    assert!(matches!(
+        parse_straight_line_code("ap += [fp + 0]"),
+        Err(CasmParseError::NotStraightLine { instruction_idx: 0, .. })
+    ));
+}
+
+#[test_case("[ap + 0] = [ap + -2] + [ap + -1], ap++", 2 => Ok(()); "inputs and outputs")]
+#[test_case("ap += 2; [ap + -1] = [ap + -3]", 1 => Ok(()); "after ap increase")]
+#[test_case("[ap + 0] = [ap + -2], ap++", 1 => Err(("[ap + -2]".into(), 0)); "below inputs")]
+#[test_case("[ap + 0] = 1, ap++; [ap + 0] = 2", 0 => Err(("[ap + 0]".into(), 1)); "above ap change")]
+#[test_case("[ap + 0] = [fp + -3], ap++", 0 => Err(("[fp + -3]".into(), 0)); "fp based")]
+fn test_validate_cells(code: &str, num_inputs: usize) -> Result<(), (String, usize)> {
+    parse_straight_line_code(code).unwrap().validate_cells(num_inputs).map_err(|err| match err {
+        CasmParseError::CellOutOfBounds { instruction_idx, cell } => (cell, instruction_idx),
+        _ => panic!("Unexpected error: {err}"),
+    })
+}
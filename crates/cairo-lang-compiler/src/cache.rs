@@ -0,0 +1,159 @@
+//! A content-addressed cache of compilation artifacts.
+//!
+//! Artifacts are keyed by an [ArtifactKey] - a digest of the sources of all the crates of a
+//! compilation, the compiler version and the compilation configuration. As the key does not depend
+//! on the location of the sources, a cache directory may be shared by several projects and
+//! machines.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_sierra::ProgramParser;
+use sha3::{Digest, Sha3_256};
+
+use crate::db::RootDatabase;
+use crate::symbol_index::write_symbol_index;
+use crate::{compile_prepared_db, CompilerConfig, SierraProgram};
+
+#[cfg(test)]
+#[path = "cache_test.rs"]
+mod test;
+
+/// The version of the compiler, part of every artifact key.
+const COMPILER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The kind of a cached artifact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArtifactKind {
+    /// The text of a Sierra program.
+    Sierra,
+    /// A CASM program, or a CASM contract class, in a format chosen by the caller.
+    Casm,
+}
+impl ArtifactKind {
+    /// The extension of the files holding artifacts of this kind.
+    fn extension(&self) -> &'static str {
+        match self {
+            ArtifactKind::Sierra => "sierra",
+            ArtifactKind::Casm => "casm",
+        }
+    }
+}
+
+/// The key of a compilation artifact, a hex digest of everything the compilation depends on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArtifactKey(String);
+impl ArtifactKey {
+    /// Returns the key of compiling `main_crate_ids` in `db`, with the given configuration.
+    ///
+    /// The key covers the content of the files of all the crates in the database - including the
+    /// corelib and the dependencies of the main crates - along with the crates settings, the
+    /// `#[cfg(...)]` options and the compiler version. `config` should describe any other setting
+    /// affecting the artifact, e.g. whether Sierra ids are replaced.
+    pub fn new(db: &RootDatabase, main_crate_ids: &[CrateId], config: &str) -> Self {
+        let mut hasher = Sha3_256::new();
+        let mut update = |field: &str| {
+            // Prefixing by the length keeps the fields from running into each other.
+            hasher.update(field.len().to_le_bytes());
+            hasher.update(field.as_bytes());
+        };
+        update(COMPILER_VERSION);
+        update(config);
+        update(&format!("{:?}", db.cfg_set()));
+        for crate_id in main_crate_ids {
+            update(db.lookup_intern_crate(*crate_id).0.as_str());
+        }
+        for crate_id in db.crates() {
+            update(db.lookup_intern_crate(crate_id).0.as_str());
+            update(&format!("{:?}", db.crate_settings(crate_id)));
+            for module_id in db.crate_modules(crate_id).iter() {
+                update(&module_id.full_path(db));
+                for file_id in db.module_files(*module_id).unwrap_or_default() {
+                    update(&file_id.file_name(db));
+                    let content = db.file_content(file_id);
+                    update(content.as_ref().map_or("<missing>", |content| content.as_str()));
+                }
+            }
+        }
+        Self(hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    /// Returns the hex digest of the key.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A cache of compilation artifacts, stored in a directory.
+#[derive(Clone, Debug)]
+pub struct ArtifactCache {
+    root: PathBuf,
+}
+impl ArtifactCache {
+    /// Returns a cache stored in the given directory, which is created on the first insertion.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the cached artifact of the given key and kind, if there is one.
+    pub fn get(&self, key: &ArtifactKey, kind: ArtifactKind) -> Option<String> {
+        fs::read_to_string(self.artifact_path(key, kind)).ok()
+    }
+
+    /// Caches an artifact of the given key and kind, replacing any previous one.
+    pub fn insert(&self, key: &ArtifactKey, kind: ArtifactKind, artifact: &str) -> Result<()> {
+        fs::create_dir_all(&self.root)
+            .with_context(|| format!("Failed to create {}.", self.root.display()))?;
+        let path = self.artifact_path(key, kind);
+        // Writing to a temporary file and renaming it keeps concurrent readers from seeing a
+        // partially written artifact. The process id keeps concurrent writers apart.
+        let temp_path =
+            path.with_extension(format!("{}.{}.tmp", kind.extension(), std::process::id()));
+        fs::write(&temp_path, artifact)
+            .with_context(|| format!("Failed to write {}.", temp_path.display()))?;
+        fs::rename(&temp_path, &path)
+            .with_context(|| format!("Failed to write {}.", path.display()))
+    }
+
+    /// Returns the path of the file holding the artifact of the given key and kind.
+    fn artifact_path(&self, key: &ArtifactKey, kind: ArtifactKind) -> PathBuf {
+        self.root.join(Path::new(key.as_str()).with_extension(kind.extension()))
+    }
+}
+
+/// Runs Cairo compiler as [compile_prepared_db] does, unless the Sierra program is already in
+/// `cache`. Compiled programs are added to `cache`.
+///
+/// Note that diagnostics are only reported when the program is actually compiled, so warnings are
+/// not reported on a cache hit.
+pub fn compile_prepared_db_with_cache(
+    db: &mut RootDatabase,
+    main_crate_ids: Vec<CrateId>,
+    compiler_config: CompilerConfig<'_>,
+    cache: &ArtifactCache,
+) -> Result<SierraProgram> {
+    let config = format!(
+        "replace_ids: {}, allowed_libfuncs_list_name: {:?}, instrumentation: {:?}",
+        compiler_config.replace_ids,
+        compiler_config.allowed_libfuncs_list_name,
+        compiler_config.instrumentation
+    );
+    let key = ArtifactKey::new(db, &main_crate_ids, &config);
+    if let Some(sierra_program) = cache.get(&key, ArtifactKind::Sierra) {
+        // A corrupted artifact is ignored, and replaced by a fresh compilation.
+        if let Ok(sierra_program) = ProgramParser::new().parse(&sierra_program) {
+            if let Some(path) = &compiler_config.symbol_index_path {
+                write_symbol_index(db, &main_crate_ids, path)?;
+            }
+            return Ok(Arc::new(sierra_program));
+        }
+    }
+    let sierra_program = compile_prepared_db(db, main_crate_ids, compiler_config)?;
+    cache.insert(&key, ArtifactKind::Sierra, &sierra_program.to_string())?;
+    Ok(sierra_program)
+}
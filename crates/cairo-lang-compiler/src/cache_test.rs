@@ -0,0 +1,67 @@
+use std::fs;
+use std::sync::Arc;
+
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory};
+
+use crate::cache::{compile_prepared_db_with_cache, ArtifactCache, ArtifactKey, ArtifactKind};
+use crate::db::RootDatabase;
+use crate::CompilerConfig;
+
+/// Sets up a crate at the given root, with the given content of its main file.
+fn setup_crate(root: &str, content: &str) -> (RootDatabase, CrateId) {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory(root.into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    let file_id = root.file(&db, "lib.cairo".into());
+    db.override_file_content(file_id, Some(Arc::new(content.into())));
+    (db, crate_id)
+}
+
+#[test]
+fn test_artifact_key() {
+    let (db, crate_id) = setup_crate("src", "fn foo() -> felt252 { 1 }");
+    let key = ArtifactKey::new(&db, &[crate_id], "");
+
+    // The key does not depend on the location of the crate.
+    let (other_db, other_crate_id) = setup_crate("other/src", "fn foo() -> felt252 { 1 }");
+    assert_eq!(ArtifactKey::new(&other_db, &[other_crate_id], ""), key);
+
+    // The key depends on the sources and on the configuration.
+    let (other_db, other_crate_id) = setup_crate("src", "fn foo() -> felt252 { 2 }");
+    assert_ne!(ArtifactKey::new(&other_db, &[other_crate_id], ""), key);
+    assert_ne!(ArtifactKey::new(&db, &[crate_id], "replace_ids"), key);
+}
+
+#[test]
+fn test_compile_with_cache() {
+    let cache_dir =
+        std::env::temp_dir().join(format!("test_compile_with_cache-{}", std::process::id()));
+    let cache = ArtifactCache::new(&cache_dir);
+    let (mut db, crate_id) = setup_crate("src", "fn foo() -> felt252 { 1 }");
+    let key = ArtifactKey::new(
+        &db,
+        &[crate_id],
+        "replace_ids: true, allowed_libfuncs_list_name: None, instrumentation: None",
+    );
+    assert_eq!(cache.get(&key, ArtifactKind::Sierra), None);
+
+    let compiler_config = || CompilerConfig { replace_ids: true, ..CompilerConfig::default() };
+    let sierra_program =
+        compile_prepared_db_with_cache(&mut db, vec![crate_id], compiler_config(), &cache).unwrap();
+    assert_eq!(cache.get(&key, ArtifactKind::Sierra), Some(sierra_program.to_string()));
+
+    // A crate with the same sources elsewhere is read from the cache.
+    let (mut other_db, other_crate_id) = setup_crate("other/src", "fn foo() -> felt252 { 1 }");
+    let cached_program = compile_prepared_db_with_cache(
+        &mut other_db,
+        vec![other_crate_id],
+        compiler_config(),
+        &cache,
+    )
+    .unwrap();
+    assert_eq!(cached_program.to_string(), sierra_program.to_string());
+
+    fs::remove_dir_all(cache_dir).unwrap();
+}
@@ -1,7 +1,11 @@
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::{
+    format_diagnostics_with_severity, DiagnosticEntry, Diagnostics, Severity,
+};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileLongId;
+use cairo_lang_filesystem::settings::{LintLevel, LintSettings};
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_semantic::db::SemanticGroup;
@@ -101,6 +105,7 @@ impl<'a> DiagnosticsReporter<'a> {
                 found_diagnostics = true;
             }
 
+            let lints = db.crate_settings(crate_id).lints;
             for module_id in &*db.crate_modules(crate_id) {
                 for file_id in db.module_files(*module_id).unwrap_or_default() {
                     let diag = db.file_syntax_diagnostics(file_id);
@@ -111,23 +116,55 @@ impl<'a> DiagnosticsReporter<'a> {
                 }
 
                 if let Ok(diag) = db.module_semantic_diagnostics(*module_id) {
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_diagnostics |= self.report_with_lint_levels(db, &lints, diag);
                 }
 
                 if let Ok(diag) = db.module_lowering_diagnostics(*module_id) {
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_diagnostics |= self.report_with_lint_levels(db, &lints, diag);
                 }
             }
         }
         found_diagnostics
     }
 
+    /// Reports the given diagnostics according to the lint levels of their crate.
+    /// Returns `true` if any of the diagnostics is an error.
+    fn report_with_lint_levels<TEntry: DiagnosticEntry<DbType = dyn SemanticGroup>>(
+        &mut self,
+        db: &(dyn SemanticGroup + 'static),
+        lints: &LintSettings,
+        diagnostics: Diagnostics<TEntry>,
+    ) -> bool {
+        let mut found_errors = false;
+        let mut formatted = String::new();
+        for entry in diagnostics.get_all() {
+            let severity = match entry.lint_id().map(|lint_id| lints.level(lint_id)) {
+                Some(LintLevel::Allow) => continue,
+                Some(LintLevel::Warn) => Severity::Warning,
+                Some(LintLevel::Deny) | None => Severity::Error,
+            };
+            found_errors |= severity == Severity::Error;
+            formatted += &format_diagnostics_with_severity(
+                db.upcast(),
+                severity,
+                &entry.format(db),
+                entry.location(db),
+            );
+            formatted += "\n";
+        }
+        if !formatted.is_empty() {
+            self.callback.on_diagnostic(formatted);
+        }
+        found_errors
+    }
+
+    /// Reports the given notes to the provided callback, unless there are none.
+    pub fn report_notes(&mut self, notes: String) {
+        if !notes.is_empty() {
+            self.callback.on_diagnostic(notes);
+        }
+    }
+
     /// Checks if there are diagnostics and reports them to the provided callback as strings.
     /// Returns `Err` if diagnostics were found.
     pub fn ensure(&mut self, db: &RootDatabase) -> Result<(), DiagnosticsError> {
@@ -1,8 +1,13 @@
+use std::sync::Arc;
+
 use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use cairo_lang_filesystem::settings::{CrateSettings, LintSettings};
+use indoc::indoc;
+use test_case::test_case;
 
 use crate::db::RootDatabase;
-use crate::diagnostics::get_diagnostics_as_string;
+use crate::diagnostics::{get_diagnostics_as_string, DiagnosticsReporter};
 
 #[test]
 fn test_diagnostics() {
@@ -13,3 +18,66 @@ fn test_diagnostics() {
 
     assert_eq!(get_diagnostics_as_string(&mut db), "no/such/path/lib.cairo not found\n");
 }
+
+/// Sets up a crate with an unreachable code diagnostic and the given lint settings.
+fn setup_unreachable_code_crate(lints: LintSettings) -> RootDatabase {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    db.set_crate_settings(crate_id, Some(CrateSettings { lints, ..Default::default() }));
+    let file_id = root.file(&db, "lib.cairo".into());
+    db.override_file_content(
+        file_id,
+        Some(Arc::new(
+            indoc! {"
+                fn foo() -> felt252 {
+                    return 1;
+                    2;
+                    3
+                }
+            "}
+            .into(),
+        )),
+    );
+    db
+}
+
+const UNREACHABLE_CODE_ERROR: &str = indoc! {"
+    error: Unreachable code
+     --> lib.cairo:3:5
+        2;
+        ^^
+
+"};
+
+#[test_case(LintSettings::default(), UNREACHABLE_CODE_ERROR, true; "deny by default")]
+#[test_case(
+    LintSettings { allow: vec!["unreachable_code".into()], ..Default::default() },
+    "",
+    false;
+    "allow"
+)]
+#[test_case(
+    LintSettings { warn: vec!["unreachable_code".into()], ..Default::default() },
+    &UNREACHABLE_CODE_ERROR.replacen("error", "warning", 1),
+    false;
+    "warn"
+)]
+#[test_case(
+    LintSettings {
+        warn: vec!["unreachable_code".into()],
+        deny_warnings: true,
+        ..Default::default()
+    },
+    UNREACHABLE_CODE_ERROR,
+    true;
+    "deny warnings"
+)]
+fn test_lint_levels(lints: LintSettings, expected_diagnostics: &str, expected_error: bool) {
+    let db = setup_unreachable_code_crate(lints);
+    let mut diagnostics = String::new();
+    let found_error = DiagnosticsReporter::write_to_string(&mut diagnostics).check(&db);
+    assert_eq!(diagnostics, expected_diagnostics);
+    assert_eq!(found_error, expected_error);
+}
@@ -2,23 +2,38 @@
 //!
 //! This crate is responsible for compiling a Cairo project into a Sierra program.
 //! It is the main entry point for the compiler.
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use ::cairo_lang_diagnostics::ToOption;
 use anyhow::{Context, Result};
 use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_lowering::call_graph::CallGraph;
+use cairo_lang_lowering::stack_depth::StackDepthReport;
 use cairo_lang_sierra::program::Program;
+use cairo_lang_sierra_generator::ap_change_report::{
+    format_ap_change_report, get_ap_change_report,
+};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::instrumentation::{
+    instrument_program, instrument_statements_locations, InstrumentationGranularity,
+};
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_sierra_generator::source_comments::program_with_source_comments;
 
 use crate::db::RootDatabase;
 use crate::diagnostics::DiagnosticsReporter;
 use crate::project::{get_main_crate_ids_from_project, setup_project, ProjectConfig};
+use crate::repro::{with_repro_bundle, ReproConfig};
+use crate::symbol_index::write_symbol_index;
 
+pub mod cache;
 pub mod db;
 pub mod diagnostics;
+pub mod minimize;
 pub mod project;
+pub mod repro;
+pub mod symbol_index;
 
 /// Configuration for the compiler.
 pub struct CompilerConfig<'c> {
@@ -27,9 +42,29 @@ pub struct CompilerConfig<'c> {
     /// Replaces sierra ids with human-readable ones.
     pub replace_ids: bool,
 
+    /// Adds the human-readable names of the sierra ids to the optional debug info of outputs which
+    /// have one - e.g. the `sierra_program_debug_info` of Starknet contract classes - even when
+    /// `replace_ids` is not set. Such outputs get no debug info when neither is set.
+    pub add_debug_info: bool,
+
     /// The name of the allowed libfuncs list to use in compilation.
     /// If None the default list of audited libfuncs will be used.
     pub allowed_libfuncs_list_name: Option<String>,
+
+    /// Reports where ap tracking is lost, and what losing it costs, as compiler notes.
+    pub ap_change_report: bool,
+
+    /// Writes an index of the symbols of the main crates to this path, for tools to search
+    /// without analyzing the crates again. See [symbol_index].
+    pub symbol_index_path: Option<PathBuf>,
+
+    /// Writes a bundle reproducing the compilation to this path if the compiler panics. See
+    /// [repro].
+    pub repro_bundle_path: Option<PathBuf>,
+
+    /// Injects instrumentation probes into the Sierra program at this granularity, for dynamic
+    /// analyses of its runs. See [instrument_program].
+    pub instrumentation: Option<InstrumentationGranularity>,
 }
 
 /// The default compiler configuration.
@@ -38,7 +73,12 @@ impl Default for CompilerConfig<'static> {
         CompilerConfig {
             diagnostics_reporter: DiagnosticsReporter::default(),
             replace_ids: false,
+            add_debug_info: false,
             allowed_libfuncs_list_name: None,
+            ap_change_report: false,
+            symbol_index_path: None,
+            repro_bundle_path: None,
+            instrumentation: None,
         }
     }
 }
@@ -64,6 +104,73 @@ pub fn compile_cairo_project_at_path(
     compile_prepared_db(&mut db, main_crate_ids, compiler_config)
 }
 
+/// Compiles a Cairo project at the given path, as [compile_cairo_project_at_path] does.
+/// # Returns
+/// * `Ok(String)` - The text of the compiled program, where the statements are interleaved with the
+///   Cairo source lines they originate from, as comments.
+/// * `Err(anyhow::Error)` - Compilation failed.
+pub fn compile_cairo_project_at_path_with_source_comments(
+    path: &Path,
+    compiler_config: CompilerConfig<'_>,
+) -> Result<String> {
+    let mut db = RootDatabase::builder().detect_corelib().build()?;
+    let main_crate_ids = setup_project(&mut db, path)?;
+    let instrumentation = compiler_config.instrumentation;
+    let sierra_program = compile_prepared_db(&mut db, main_crate_ids.clone(), compiler_config)?;
+    let mut statements_locations = db
+        .get_sierra_program_statements_locations(main_crate_ids.clone())
+        .to_option()
+        .context("Compilation failed without any diagnostics")?;
+    if let Some(granularity) = instrumentation {
+        let original_program = db
+            .get_sierra_program(main_crate_ids)
+            .to_option()
+            .context("Compilation failed without any diagnostics")?;
+        statements_locations = Arc::new(instrument_statements_locations(
+            &original_program,
+            granularity,
+            &statements_locations,
+        ));
+    }
+    Ok(program_with_source_comments(&db, &sierra_program, &statements_locations))
+}
+
+/// Builds the call graph of a Cairo project at the given path, with the functions compiled by
+/// [compile_cairo_project_at_path] as its roots.
+/// # Arguments
+/// * `path` - The path to the project.
+/// * `compiler_config` - The compiler configuration.
+/// # Returns
+/// * `Ok(CallGraph)` - The call graph of the project.
+/// * `Err(anyhow::Error)` - The project has errors.
+pub fn call_graph_of_project_at_path(
+    path: &Path,
+    mut compiler_config: CompilerConfig<'_>,
+) -> Result<CallGraph> {
+    let mut db = RootDatabase::builder().detect_corelib().build()?;
+    let main_crate_ids = setup_project(&mut db, path)?;
+    compiler_config.diagnostics_reporter.ensure(&db)?;
+    Ok(CallGraph::build_for_crates(&db, &main_crate_ids))
+}
+
+/// Analyzes the call depth of the entry points of a Cairo project, and the recursion they may run
+/// into.
+/// # Arguments
+/// * `path` - The path to the project.
+/// * `compiler_config` - The compiler configuration.
+/// # Returns
+/// * `Ok(StackDepthReport)` - The call depth of each entry point of the project.
+/// * `Err(anyhow::Error)` - The project has errors.
+pub fn stack_depth_report_of_project_at_path(
+    path: &Path,
+    mut compiler_config: CompilerConfig<'_>,
+) -> Result<StackDepthReport> {
+    let mut db = RootDatabase::builder().detect_corelib().build()?;
+    let main_crate_ids = setup_project(&mut db, path)?;
+    compiler_config.diagnostics_reporter.ensure(&db)?;
+    Ok(StackDepthReport::build_for_crates(&db, &main_crate_ids))
+}
+
 /// Compiles a Cairo project.
 /// The project must be a valid Cairo project.
 /// This function is a wrapper over [`RootDatabase::builder()`] and [`compile_prepared_db`].
@@ -99,13 +206,36 @@ pub fn compile_prepared_db(
     main_crate_ids: Vec<CrateId>,
     mut compiler_config: CompilerConfig<'_>,
 ) -> Result<SierraProgram> {
+    if let Some(bundle_path) = compiler_config.repro_bundle_path.take() {
+        let config = ReproConfig::new(&compiler_config);
+        let bundle_crate_ids = main_crate_ids.clone();
+        return with_repro_bundle(db, &bundle_crate_ids, config, &bundle_path, |db| {
+            compile_prepared_db(db, main_crate_ids, compiler_config)
+        });
+    }
+
     compiler_config.diagnostics_reporter.ensure(db)?;
 
+    if let Some(path) = &compiler_config.symbol_index_path {
+        write_symbol_index(db, &main_crate_ids, path)?;
+    }
+
     let mut sierra_program = db
-        .get_sierra_program(main_crate_ids)
+        .get_sierra_program(main_crate_ids.clone())
         .to_option()
         .context("Compilation failed without any diagnostics")?;
 
+    if compiler_config.ap_change_report {
+        let report = get_ap_change_report(db, main_crate_ids)
+            .to_option()
+            .context("Compilation failed without any diagnostics")?;
+        compiler_config.diagnostics_reporter.report_notes(format_ap_change_report(db, &report));
+    }
+
+    if let Some(granularity) = compiler_config.instrumentation {
+        sierra_program = Arc::new(instrument_program(db, &sierra_program, granularity));
+    }
+
     if compiler_config.replace_ids {
         sierra_program = Arc::new(replace_sierra_ids_in_program(db, &sierra_program));
     }
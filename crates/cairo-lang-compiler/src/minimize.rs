@@ -0,0 +1,215 @@
+//! Minimization of reproduction bundles, shrinking the programs triggering a compiler bug into
+//! small test cases.
+//!
+//! Given a [ReproBundle] and a predicate telling whether a bundle still shows the failure - e.g.
+//! [panics_with] - the minimizer removes files, items and statements from the compiled crates, as
+//! long as the failure is preserved. Items and statements are removed by delta debugging: halves
+//! of them are removed at first, and smaller and smaller chunks when no half can be removed.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_parser::utils::{get_syntax_root_and_diagnostics, SimpleParserDatabase};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+
+use crate::db::RootDatabaseBuilder;
+use crate::repro::ReproBundle;
+
+#[cfg(test)]
+#[path = "minimize_test.rs"]
+mod test;
+
+/// The name of the main file of a crate, which is never removed.
+const CRATE_MAIN_FILE: &str = "lib.cairo";
+
+/// Returns a predicate holding for bundles whose compilation panics with a message containing
+/// `message`. The bundles are replayed with databases built by `builder`.
+pub fn panics_with(
+    message: impl Into<String>,
+    builder: RootDatabaseBuilder,
+) -> impl FnMut(&ReproBundle) -> bool {
+    let message = message.into();
+    move |bundle| {
+        let Err(payload) = catch_unwind(AssertUnwindSafe(|| bundle.replay(&mut builder.clone())))
+        else {
+            return false;
+        };
+        let panic_message = payload
+            .downcast_ref::<&str>()
+            .map(|panic_message| panic_message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned());
+        matches!(panic_message, Some(panic_message) if panic_message.contains(&message))
+    }
+}
+
+/// Returns a minimized version of the given bundle, for which the predicate still holds.
+/// Only the files of the main crates of the bundle are minimized.
+pub fn minimize(
+    bundle: &ReproBundle,
+    predicate: impl FnMut(&ReproBundle) -> bool,
+) -> Result<ReproBundle> {
+    let mut minimizer = Minimizer { bundle: bundle.clone(), predicate };
+    if !(minimizer.predicate)(&minimizer.bundle) {
+        bail!("The failure does not reproduce with the given bundle.");
+    }
+    // Removing code from a file may allow removing code from other files, so the files are
+    // minimized until none of them changes.
+    loop {
+        let mut changed = minimizer.remove_files();
+        for crate_index in 0..minimizer.bundle.crates.len() {
+            if !minimizer.bundle.crates[crate_index].main {
+                continue;
+            }
+            for file_index in 0..minimizer.bundle.crates[crate_index].files.len() {
+                changed |= minimizer.minimize_file(crate_index, file_index);
+            }
+        }
+        if !changed {
+            return Ok(minimizer.bundle);
+        }
+    }
+}
+
+/// Helper for minimizing a bundle.
+struct Minimizer<TPredicate: FnMut(&ReproBundle) -> bool> {
+    /// The smallest bundle found so far for which the predicate holds.
+    bundle: ReproBundle,
+    predicate: TPredicate,
+}
+impl<TPredicate: FnMut(&ReproBundle) -> bool> Minimizer<TPredicate> {
+    /// Replaces the bundle by the candidate if the predicate holds for it. Returns whether it did.
+    fn try_candidate(&mut self, candidate: ReproBundle) -> bool {
+        if !(self.predicate)(&candidate) {
+            return false;
+        }
+        self.bundle = candidate;
+        true
+    }
+
+    /// Removes the files of the main crates which are not needed to reproduce the failure, except
+    /// for their main files. Returns whether any file was removed.
+    fn remove_files(&mut self) -> bool {
+        let mut changed = false;
+        for crate_index in 0..self.bundle.crates.len() {
+            if !self.bundle.crates[crate_index].main {
+                continue;
+            }
+            let mut file_index = 0;
+            while file_index < self.bundle.crates[crate_index].files.len() {
+                if self.bundle.crates[crate_index].files[file_index].path == CRATE_MAIN_FILE {
+                    file_index += 1;
+                    continue;
+                }
+                let mut candidate = self.bundle.clone();
+                candidate.crates[crate_index].files.remove(file_index);
+                if self.try_candidate(candidate) {
+                    changed = true;
+                } else {
+                    file_index += 1;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Removes the items and statements of a file which are not needed to reproduce the failure.
+    /// Returns whether anything was removed.
+    fn minimize_file(&mut self, crate_index: usize, file_index: usize) -> bool {
+        let mut changed = false;
+        let mut granularity = 2;
+        loop {
+            let content = &self.bundle.crates[crate_index].files[file_index].content;
+            let spans = removable_spans(content);
+            if spans.is_empty() {
+                return changed;
+            }
+            granularity = granularity.min(spans.len());
+            let chunk_size = (spans.len() + granularity - 1) / granularity;
+            let mut removed = false;
+            for chunk in spans.chunks(chunk_size) {
+                let mut candidate = self.bundle.clone();
+                let file = &mut candidate.crates[crate_index].files[file_index];
+                file.content = remove_spans(&file.content, chunk);
+                if self.try_candidate(candidate) {
+                    removed = true;
+                    break;
+                }
+            }
+            if removed {
+                changed = true;
+                granularity = (granularity - 1).max(2);
+            } else if granularity < spans.len() {
+                granularity = (granularity * 2).min(spans.len());
+            } else {
+                return changed;
+            }
+        }
+    }
+}
+
+/// Returns the byte ranges of the items and statements of the given code, sorted by their start.
+/// Nested items and statements are included along with the items containing them.
+fn removable_spans(content: &str) -> Vec<(usize, usize)> {
+    let db = SimpleParserDatabase::default();
+    let file_id = FileId::new(&db, PathBuf::from(CRATE_MAIN_FILE));
+    let (root, _) = get_syntax_root_and_diagnostics(&db, file_id, content);
+    let mut spans = vec![];
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if is_removable(node.kind(&db)) {
+            let span = node.span_without_trivia(&db);
+            let start = content.len() - span.start.take_from(content).len();
+            let end = content.len() - span.end.take_from(content).len();
+            if start < end {
+                spans.push((start, end));
+            }
+        }
+        stack.extend(node.children(&db).collect::<Vec<SyntaxNode>>());
+    }
+    spans.sort();
+    spans
+}
+
+/// Returns whether syntax nodes of the given kind may be removed from the code.
+fn is_removable(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::ItemModule
+            | SyntaxKind::ItemConstant
+            | SyntaxKind::FunctionWithBody
+            | SyntaxKind::ItemExternFunction
+            | SyntaxKind::ItemExternType
+            | SyntaxKind::ItemTrait
+            | SyntaxKind::TraitItemFunction
+            | SyntaxKind::ItemImpl
+            | SyntaxKind::ItemImplAlias
+            | SyntaxKind::ItemStruct
+            | SyntaxKind::ItemEnum
+            | SyntaxKind::ItemTypeAlias
+            | SyntaxKind::ItemUse
+            | SyntaxKind::StatementLet
+            | SyntaxKind::StatementExpr
+            | SyntaxKind::StatementContinue
+            | SyntaxKind::StatementReturn
+            | SyntaxKind::StatementBreak
+    )
+}
+
+/// Returns the given code without the given byte ranges, which may overlap.
+fn remove_spans(content: &str, spans: &[(usize, usize)]) -> String {
+    let mut spans = spans.to_vec();
+    spans.sort();
+    let mut result = String::new();
+    let mut position = 0;
+    for (start, end) in spans {
+        if start > position {
+            result.push_str(&content[position..start]);
+        }
+        position = position.max(end);
+    }
+    result.push_str(&content[position..]);
+    result
+}
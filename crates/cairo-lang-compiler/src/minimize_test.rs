@@ -0,0 +1,72 @@
+use cairo_lang_filesystem::cfg::CfgSet;
+use cairo_lang_filesystem::settings::CrateSettings;
+use indoc::indoc;
+
+use super::minimize;
+use crate::repro::{ReproBundle, ReproConfig, ReproCrate, ReproFile};
+
+/// Returns a bundle of a single crate with the given files.
+fn bundle(files: &[(&str, &str)]) -> ReproBundle {
+    ReproBundle {
+        compiler_version: "test".into(),
+        panic_message: None,
+        config: ReproConfig::default(),
+        plugins: vec![],
+        cfg_set: CfgSet::new(),
+        crates: vec![ReproCrate {
+            name: "test".into(),
+            main: true,
+            settings: CrateSettings::default(),
+            files: files
+                .iter()
+                .map(|(path, content)| ReproFile {
+                    path: path.to_string(),
+                    content: content.to_string(),
+                })
+                .collect(),
+        }],
+    }
+}
+
+/// Returns whether the main file of the bundle contains the given text.
+fn main_file_contains(bundle: &ReproBundle, text: &str) -> bool {
+    bundle.crates[0]
+        .files
+        .iter()
+        .any(|file| file.path == "lib.cairo" && file.content.contains(text))
+}
+
+#[test]
+fn test_minimize() {
+    let bundle = bundle(&[
+        (
+            "lib.cairo",
+            indoc! {"
+                mod utils;
+                fn other() -> felt252 {
+                    1
+                }
+                fn important() {
+                    let a = 1;
+                    other();
+                }
+            "},
+        ),
+        ("utils.cairo", "fn bar() {}"),
+    ]);
+    let minimized = minimize(&bundle, |bundle| main_file_contains(bundle, "important")).unwrap();
+
+    assert_eq!(
+        minimized.crates[0].files,
+        [ReproFile {
+            path: "lib.cairo".into(),
+            content: "\n\nfn important() {\n    \n    \n}\n".into()
+        }]
+    );
+}
+
+#[test]
+fn test_minimize_without_failure() {
+    let bundle = bundle(&[("lib.cairo", "fn foo() {}")]);
+    assert!(minimize(&bundle, |bundle| main_file_contains(bundle, "bar")).is_err());
+}
@@ -57,8 +57,12 @@ fn setup_single_file_project(
     }
 }
 
-/// Updates the crate roots from a ProjectConfig object.
+/// Updates the crate roots and settings from a ProjectConfig object.
 pub fn update_crate_roots_from_project_config(db: &mut dyn SemanticGroup, config: ProjectConfig) {
+    for (crate_name, settings) in config.content.crate_settings {
+        let crate_id = db.intern_crate(CrateLongId(crate_name));
+        db.set_crate_settings(crate_id, Some(settings));
+    }
     for (crate_name, directory_path) in config.content.crate_roots {
         let crate_id = db.intern_crate(CrateLongId(crate_name));
         let mut path = PathBuf::from(&directory_path);
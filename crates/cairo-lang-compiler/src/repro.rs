@@ -0,0 +1,233 @@
+//! Self-contained bundles reproducing a compilation, to report internal compiler errors.
+//!
+//! A bundle holds the content of the files of all the crates of a compilation - including the
+//! corelib - along with the crates settings, the `#[cfg(...)]` options, the plugins and the
+//! compiler configuration. It is written as a JSON file when the compiler panics with
+//! [CompilerConfig::repro_bundle_path] set, and may be replayed on any machine with
+//! [ReproBundle::replay], without the original sources.
+
+use std::fs;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::cfg::CfgSet;
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory, FileLongId};
+use cairo_lang_filesystem::settings::CrateSettings;
+use cairo_lang_semantic::db::SemanticGroup;
+use serde::{Deserialize, Serialize};
+
+use crate::db::{RootDatabase, RootDatabaseBuilder};
+use crate::{compile_prepared_db, CompilerConfig, SierraProgram};
+
+#[cfg(test)]
+#[path = "repro_test.rs"]
+mod test;
+
+/// The version of the compiler which created a bundle.
+const COMPILER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The directory under which the crates of a replayed bundle are placed. No file is read from it,
+/// as the content of all the files is taken from the bundle.
+const REPLAY_ROOT: &str = "/repro";
+
+/// The compiler configuration of a reproduced compilation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReproConfig {
+    pub replace_ids: bool,
+    pub allowed_libfuncs_list_name: Option<String>,
+    pub ap_change_report: bool,
+}
+impl ReproConfig {
+    /// Returns the part of the given configuration which affects the compilation.
+    pub fn new(compiler_config: &CompilerConfig<'_>) -> Self {
+        Self {
+            replace_ids: compiler_config.replace_ids,
+            allowed_libfuncs_list_name: compiler_config.allowed_libfuncs_list_name.clone(),
+            ap_change_report: compiler_config.ap_change_report,
+        }
+    }
+
+    /// Returns the compiler configuration to replay the compilation with.
+    fn compiler_config(&self) -> CompilerConfig<'static> {
+        CompilerConfig {
+            replace_ids: self.replace_ids,
+            allowed_libfuncs_list_name: self.allowed_libfuncs_list_name.clone(),
+            ap_change_report: self.ap_change_report,
+            ..CompilerConfig::default()
+        }
+    }
+}
+
+/// A file of a crate of a bundle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReproFile {
+    /// The path of the file, relative to the root of its crate.
+    pub path: String,
+    pub content: String,
+}
+
+/// A crate of a bundle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReproCrate {
+    pub name: String,
+    /// Whether the crate is one of the compiled crates, rather than a dependency.
+    pub main: bool,
+    pub settings: CrateSettings,
+    pub files: Vec<ReproFile>,
+}
+
+/// A self-contained reproduction of a compilation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReproBundle {
+    pub compiler_version: String,
+    /// The message of the panic the compilation ended with, if any.
+    pub panic_message: Option<String>,
+    pub config: ReproConfig,
+    /// The debug representations of the semantic plugins, in order.
+    pub plugins: Vec<String>,
+    pub cfg_set: CfgSet,
+    pub crates: Vec<ReproCrate>,
+}
+impl ReproBundle {
+    /// Creates a bundle reproducing the compilation of `main_crate_ids` in `db`.
+    pub fn new(db: &RootDatabase, main_crate_ids: &[CrateId], config: ReproConfig) -> Self {
+        let crates = db
+            .crates()
+            .into_iter()
+            .map(|crate_id| ReproCrate {
+                name: db.lookup_intern_crate(crate_id).0.to_string(),
+                main: main_crate_ids.contains(&crate_id),
+                settings: db.crate_settings(crate_id),
+                files: crate_files(db, crate_id),
+            })
+            .collect();
+        Self {
+            compiler_version: COMPILER_VERSION.into(),
+            panic_message: None,
+            config,
+            plugins: plugin_names(db),
+            cfg_set: db.cfg_set().as_ref().clone(),
+            crates,
+        }
+    }
+
+    /// Reads a bundle from a JSON file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read bundle {}.", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse bundle {}.", path.display()))
+    }
+
+    /// Writes the bundle to a JSON file.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write bundle {}.", path.display()))
+    }
+
+    /// Builds a database holding the crates of the bundle, and returns it along with the ids of
+    /// the compiled crates.
+    /// The builder should have the plugins the bundle was created with, and no corelib, as the
+    /// bundle holds its own corelib.
+    pub fn build_db(
+        &self,
+        builder: &mut RootDatabaseBuilder,
+    ) -> Result<(RootDatabase, Vec<CrateId>)> {
+        let mut db = builder.with_cfg(self.cfg_set.clone()).build()?;
+        let plugins = plugin_names(&db);
+        if plugins != self.plugins {
+            bail!(
+                "The bundle was created with the plugins [{}], but is replayed with [{}].",
+                self.plugins.join(", "),
+                plugins.join(", ")
+            );
+        }
+        let mut main_crate_ids = vec![];
+        for (index, repro_crate) in self.crates.iter().enumerate() {
+            let crate_id = db.intern_crate(CrateLongId(repro_crate.name.as_str().into()));
+            // Crate names may be paths, so the root is named by the index of the crate instead.
+            let root = PathBuf::from(REPLAY_ROOT).join(index.to_string());
+            db.set_crate_root(crate_id, Some(Directory(root.clone())));
+            db.set_crate_settings(crate_id, Some(repro_crate.settings.clone()));
+            for file in &repro_crate.files {
+                let file_id = Directory(root.clone()).file(&db, file.path.as_str().into());
+                db.override_file_content(file_id, Some(Arc::new(file.content.clone())));
+            }
+            if repro_crate.main {
+                main_crate_ids.push(crate_id);
+            }
+        }
+        Ok((db, main_crate_ids))
+    }
+
+    /// Replays the compilation of the bundle.
+    pub fn replay(&self, builder: &mut RootDatabaseBuilder) -> Result<SierraProgram> {
+        let (mut db, main_crate_ids) = self.build_db(builder)?;
+        compile_prepared_db(&mut db, main_crate_ids, self.config.compiler_config())
+    }
+}
+
+/// Runs `f`, writing a bundle reproducing the compilation of `main_crate_ids` in `db` to
+/// `bundle_path` if it panics. The panic is resumed once the bundle is written.
+pub fn with_repro_bundle<T>(
+    db: &mut RootDatabase,
+    main_crate_ids: &[CrateId],
+    config: ReproConfig,
+    bundle_path: &Path,
+    f: impl FnOnce(&mut RootDatabase) -> T,
+) -> T {
+    match catch_unwind(AssertUnwindSafe(|| f(db))) {
+        Ok(result) => result,
+        Err(payload) => {
+            let mut bundle = ReproBundle::new(db, main_crate_ids, config);
+            bundle.panic_message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned());
+            match bundle.save(bundle_path) {
+                Ok(()) => eprintln!(
+                    "The compiler crashed. A bundle reproducing the crash was written to {}.",
+                    bundle_path.display()
+                ),
+                Err(err) => eprintln!("The compiler crashed, and writing the bundle failed: {err}"),
+            }
+            resume_unwind(payload)
+        }
+    }
+}
+
+/// Returns the debug representations of the semantic plugins of the database.
+fn plugin_names(db: &RootDatabase) -> Vec<String> {
+    db.semantic_plugins().iter().map(|plugin| format!("{plugin:?}")).collect()
+}
+
+/// Returns the files of the modules of a crate which are under its root, in the order of the
+/// modules. Files generated by plugins are not included, as they are generated again on replay.
+fn crate_files(db: &RootDatabase, crate_id: CrateId) -> Vec<ReproFile> {
+    let Some(root) = db.crate_root_dir(crate_id) else {
+        return vec![];
+    };
+    let mut files: Vec<ReproFile> = vec![];
+    for module_id in db.crate_modules(crate_id).iter() {
+        for file_id in db.module_files(*module_id).unwrap_or_default() {
+            let FileLongId::OnDisk(path) = db.lookup_intern_file(file_id) else {
+                continue;
+            };
+            let Ok(relative_path) = path.strip_prefix(&root.0) else {
+                continue;
+            };
+            let path = relative_path.to_string_lossy().replace('\\', "/");
+            if files.iter().any(|file| file.path == path) {
+                continue;
+            }
+            if let Some(content) = db.file_content(file_id) {
+                files.push(ReproFile { path, content: content.as_ref().clone() });
+            }
+        }
+    }
+    files
+}
@@ -0,0 +1,79 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory};
+use cairo_lang_filesystem::settings::{CrateSettings, Edition};
+
+use super::{with_repro_bundle, ReproBundle, ReproConfig};
+use crate::db::RootDatabase;
+use crate::{compile_prepared_db, CompilerConfig};
+
+/// Sets up a crate with a submodule, and returns the database and the id of the crate.
+fn setup_crate() -> (RootDatabase, CrateId) {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    db.set_crate_settings(
+        crate_id,
+        Some(CrateSettings { edition: Edition::V2023_10, ..CrateSettings::default() }),
+    );
+    for (name, content) in [
+        ("lib.cairo", "mod utils;\nfn foo() -> felt252 { utils::bar() }"),
+        ("utils.cairo", "fn bar() -> felt252 { 1 }"),
+    ] {
+        let file_id = root.file(&db, name.into());
+        db.override_file_content(file_id, Some(Arc::new(content.into())));
+    }
+    (db, crate_id)
+}
+
+#[test]
+fn test_replay() {
+    let (mut db, crate_id) = setup_crate();
+    let bundle = ReproBundle::new(&db, &[crate_id], ReproConfig::default());
+    let test_crate = bundle.crates.iter().find(|repro_crate| repro_crate.name == "test").unwrap();
+    assert!(test_crate.main);
+    assert_eq!(test_crate.settings.edition, Edition::V2023_10);
+    assert_eq!(
+        test_crate.files.iter().map(|file| file.path.as_str()).collect::<Vec<_>>(),
+        ["lib.cairo", "utils.cairo"]
+    );
+
+    let bundle_path = std::env::temp_dir().join(format!("test_replay-{}.json", std::process::id()));
+    bundle.save(&bundle_path).unwrap();
+    let loaded_bundle = ReproBundle::load(&bundle_path).unwrap();
+    std::fs::remove_file(&bundle_path).unwrap();
+    assert_eq!(loaded_bundle, bundle);
+
+    let sierra_program =
+        compile_prepared_db(&mut db, vec![crate_id], CompilerConfig::default()).unwrap();
+    let replayed_program = loaded_bundle.replay(&mut RootDatabase::builder()).unwrap();
+    assert_eq!(replayed_program.to_string(), sierra_program.to_string());
+}
+
+#[test]
+fn test_replay_with_other_plugins() {
+    let (db, crate_id) = setup_crate();
+    let bundle = ReproBundle::new(&db, &[crate_id], ReproConfig::default());
+    assert!(bundle.build_db(RootDatabase::builder().clear_plugins()).is_err());
+}
+
+#[test]
+fn test_bundle_on_panic() {
+    let (mut db, crate_id) = setup_crate();
+    let bundle_path =
+        std::env::temp_dir().join(format!("test_bundle_on_panic-{}.json", std::process::id()));
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        with_repro_bundle(&mut db, &[crate_id], ReproConfig::default(), &bundle_path, |_| {
+            panic!("Internal compiler error.")
+        })
+    }));
+    assert!(result.is_err());
+
+    let bundle = ReproBundle::load(&bundle_path).unwrap();
+    std::fs::remove_file(&bundle_path).unwrap();
+    assert_eq!(bundle.panic_message.as_deref(), Some("Internal compiler error."));
+    assert!(bundle.crates.iter().any(|repro_crate| repro_crate.name == "test"));
+}
@@ -0,0 +1,370 @@
+//! An index of the symbols defined in a set of crates, which may be persisted to disk.
+//!
+//! The index maps the names of the items of the crates - modules, functions, types, traits, impls
+//! and the functions of traits and impls - to the locations of their definitions, their kinds and
+//! their signatures. It lets tools search for a symbol without analyzing the crates again.
+//!
+//! The persisted format is a text file with a line per symbol, made of tab separated fields:
+//! ```text
+//! <kind>\t<full path>\t<file>:<line>:<col>\t<end line>:<end col>\t<signature>
+//! ```
+//! where lines and columns are 1 based, so the file is also easy to query with line based tools,
+//! e.g. `grep`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId, TopLevelLanguageElementId};
+use cairo_lang_filesystem::ids::{CrateId, FileId, FileLongId};
+use cairo_lang_filesystem::span::{TextPosition, TextSpan, TextWidth};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+#[cfg(test)]
+#[path = "symbol_index_test.rs"]
+mod test;
+
+/// The first line of a persisted symbol index, identifying its format.
+const SYMBOL_INDEX_HEADER: &str = "# Cairo symbol index v1";
+
+/// The kind of an indexed symbol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    Module,
+    Constant,
+    Function,
+    ExternFunction,
+    Struct,
+    Enum,
+    TypeAlias,
+    ImplAlias,
+    Trait,
+    Impl,
+    ExternType,
+    TraitFunction,
+    ImplFunction,
+}
+impl SymbolKind {
+    /// The name of the kind in a persisted index.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SymbolKind::Module => "mod",
+            SymbolKind::Constant => "const",
+            SymbolKind::Function => "fn",
+            SymbolKind::ExternFunction => "extern_fn",
+            SymbolKind::Struct => "struct",
+            SymbolKind::Enum => "enum",
+            SymbolKind::TypeAlias => "type",
+            SymbolKind::ImplAlias => "impl_alias",
+            SymbolKind::Trait => "trait",
+            SymbolKind::Impl => "impl",
+            SymbolKind::ExternType => "extern_type",
+            SymbolKind::TraitFunction => "trait_fn",
+            SymbolKind::ImplFunction => "impl_fn",
+        }
+    }
+
+    /// Returns the kind with the given name in a persisted index.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "mod" => SymbolKind::Module,
+            "const" => SymbolKind::Constant,
+            "fn" => SymbolKind::Function,
+            "extern_fn" => SymbolKind::ExternFunction,
+            "struct" => SymbolKind::Struct,
+            "enum" => SymbolKind::Enum,
+            "type" => SymbolKind::TypeAlias,
+            "impl_alias" => SymbolKind::ImplAlias,
+            "trait" => SymbolKind::Trait,
+            "impl" => SymbolKind::Impl,
+            "extern_type" => SymbolKind::ExternType,
+            "trait_fn" => SymbolKind::TraitFunction,
+            "impl_fn" => SymbolKind::ImplFunction,
+            _ => return None,
+        })
+    }
+}
+
+/// A symbol defined in a crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: SymbolKind,
+    /// The full path of the symbol, e.g. `my_crate::my_module::MyTrait::my_function`.
+    pub full_path: String,
+    /// The file the symbol is defined in.
+    pub file: PathBuf,
+    /// The start of the definition, including its attributes.
+    pub start: TextPosition,
+    /// The end of the definition.
+    pub end: TextPosition,
+    /// The signature of the symbol - its definition, up to its body, e.g.
+    /// `fn foo(a: felt252) -> felt252` or `impl MyImpl of MyTrait<felt252>`.
+    pub signature: String,
+}
+impl Symbol {
+    /// The name of the symbol, the last segment of its full path.
+    pub fn name(&self) -> &str {
+        self.full_path.rsplit("::").next().unwrap_or_default()
+    }
+}
+
+/// An index of the symbols defined in a set of crates.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolIndex {
+    pub symbols: Vec<Symbol>,
+}
+impl SymbolIndex {
+    /// Builds the index of the symbols defined in the given crates.
+    ///
+    /// Only symbols defined in files on disk are indexed, so items generated by plugins are not.
+    pub fn build(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Self {
+        let mut builder = SymbolIndexBuilder { db, symbols: vec![] };
+        for crate_id in crate_ids {
+            for module_id in db.crate_modules(*crate_id).iter() {
+                for item in db.module_items(*module_id).unwrap_or_default().iter() {
+                    builder.add_module_item(*item);
+                }
+            }
+        }
+        Self { symbols: builder.symbols }
+    }
+
+    /// Returns the symbols matching `query`, best matches first.
+    ///
+    /// The query is matched case-insensitively against the names of the symbols, or against their
+    /// full paths if it contains `::`. Exact matches come first, then prefix matches, then any
+    /// other symbol containing the query.
+    pub fn search(&self, query: &str) -> Vec<&Symbol> {
+        let query = query.to_lowercase();
+        let mut matches = self
+            .symbols
+            .iter()
+            .filter_map(|symbol| {
+                let target = if query.contains("::") {
+                    symbol.full_path.to_lowercase()
+                } else {
+                    symbol.name().to_lowercase()
+                };
+                let rank = if target == query {
+                    0
+                } else if target.starts_with(&query) {
+                    1
+                } else if target.contains(&query) {
+                    2
+                } else {
+                    return None;
+                };
+                Some((rank, symbol))
+            })
+            .collect::<Vec<_>>();
+        // The sort is stable, so symbols of the same rank keep their order in the index.
+        matches.sort_by_key(|(rank, _)| *rank);
+        matches.into_iter().map(|(_, symbol)| symbol).collect()
+    }
+
+    /// Writes the index to the given path.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}.", parent.display()))?;
+        }
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Failed to write {}.", path.display()))
+    }
+
+    /// Reads an index written by [SymbolIndex::save].
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}.", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Failed to parse {}.", path.display()))
+    }
+
+    /// Parses the persisted format of an index.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut lines = content.lines();
+        if lines.next() != Some(SYMBOL_INDEX_HEADER) {
+            bail!("Missing the symbol index header.");
+        }
+        let symbols = lines
+            .enumerate()
+            .map(|(idx, line)| {
+                // The header is the first line, and lines are 1 based.
+                parse_symbol(line).with_context(|| format!("Invalid symbol at line {}.", idx + 2))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { symbols })
+    }
+}
+impl std::fmt::Display for SymbolIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{SYMBOL_INDEX_HEADER}")?;
+        for symbol in &self.symbols {
+            writeln!(
+                f,
+                "{}\t{}\t{}:{}:{}\t{}:{}\t{}",
+                symbol.kind.name(),
+                symbol.full_path,
+                symbol.file.display(),
+                symbol.start.line + 1,
+                symbol.start.col + 1,
+                symbol.end.line + 1,
+                symbol.end.col + 1,
+                symbol.signature
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a line of a persisted index.
+fn parse_symbol(line: &str) -> Result<Symbol> {
+    let [kind, full_path, start, end, signature] = line.splitn(5, '\t').collect::<Vec<_>>()[..]
+    else {
+        bail!("Expected 5 tab separated fields.");
+    };
+    let kind = SymbolKind::from_name(kind).with_context(|| format!("Unknown kind `{kind}`."))?;
+    // The file path may contain colons, so the position is taken from the end.
+    let [start_col, start_line, file] = start.rsplitn(3, ':').collect::<Vec<_>>()[..] else {
+        bail!("Expected a `<file>:<line>:<col>` location.");
+    };
+    let Some((end_line, end_col)) = end.split_once(':') else {
+        bail!("Expected a `<line>:<col>` end position.");
+    };
+    Ok(Symbol {
+        kind,
+        full_path: full_path.into(),
+        file: file.into(),
+        start: parse_position(start_line, start_col)?,
+        end: parse_position(end_line, end_col)?,
+        signature: signature.into(),
+    })
+}
+
+/// Parses a 1 based line and column into a position.
+fn parse_position(line: &str, col: &str) -> Result<TextPosition> {
+    let parse = |value: &str| match value.parse::<usize>() {
+        Ok(value) if value > 0 => Ok(value - 1),
+        _ => bail!("Invalid line or column `{value}`."),
+    };
+    Ok(TextPosition { line: parse(line)?, col: parse(col)? })
+}
+
+/// Collects the symbols of module items.
+struct SymbolIndexBuilder<'a> {
+    db: &'a dyn SemanticGroup,
+    symbols: Vec<Symbol>,
+}
+impl SymbolIndexBuilder<'_> {
+    /// Adds the symbol of a module item, and the symbols of its functions if it is a trait or an
+    /// impl.
+    fn add_module_item(&mut self, item: ModuleItemId) {
+        let db = self.db;
+        let defs_db: &dyn DefsGroup = db.upcast();
+        let kind = match item {
+            ModuleItemId::Use(_) => return,
+            ModuleItemId::Constant(_) => SymbolKind::Constant,
+            ModuleItemId::Submodule(_) => SymbolKind::Module,
+            ModuleItemId::FreeFunction(_) => SymbolKind::Function,
+            ModuleItemId::Struct(_) => SymbolKind::Struct,
+            ModuleItemId::Enum(_) => SymbolKind::Enum,
+            ModuleItemId::TypeAlias(_) => SymbolKind::TypeAlias,
+            ModuleItemId::ImplAlias(_) => SymbolKind::ImplAlias,
+            ModuleItemId::Trait(_) => SymbolKind::Trait,
+            ModuleItemId::Impl(_) => SymbolKind::Impl,
+            ModuleItemId::ExternType(_) => SymbolKind::ExternType,
+            ModuleItemId::ExternFunction(_) => SymbolKind::ExternFunction,
+        };
+        let full_path = item.full_path(defs_db);
+        self.add(kind, full_path.clone(), &item);
+        match item {
+            ModuleItemId::Trait(trait_id) => {
+                for (name, function_id) in db.trait_functions(trait_id).unwrap_or_default() {
+                    self.add(
+                        SymbolKind::TraitFunction,
+                        format!("{full_path}::{name}"),
+                        &function_id,
+                    );
+                }
+            }
+            ModuleItemId::Impl(impl_def_id) => {
+                for (name, function_id) in db.impl_functions(impl_def_id).unwrap_or_default() {
+                    self.add(
+                        SymbolKind::ImplFunction,
+                        format!("{full_path}::{name}"),
+                        &function_id,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds the symbol of the given element, if it is defined in a file on disk.
+    fn add(&mut self, kind: SymbolKind, full_path: String, element: &dyn LanguageElementId) {
+        let defs_db: &dyn DefsGroup = self.db.upcast();
+        let Ok(file_id) = defs_db.module_file(element.module_file_id(defs_db)) else {
+            return;
+        };
+        let FileLongId::OnDisk(file) = defs_db.lookup_intern_file(file_id) else {
+            return;
+        };
+        if let Some((start, end, signature)) =
+            self.symbol_location(file_id, element.untyped_stable_ptr(defs_db))
+        {
+            self.symbols.push(Symbol { kind, full_path, file, start, end, signature });
+        }
+    }
+
+    /// Returns the start, the end and the signature of the definition at `stable_ptr`.
+    fn symbol_location(
+        &self,
+        file_id: FileId,
+        stable_ptr: SyntaxStablePtrId,
+    ) -> Option<(TextPosition, TextPosition, String)> {
+        let db = self.db;
+        let syntax_db: &dyn SyntaxGroup = db.upcast();
+        let content = db.file_content(file_id)?;
+        let syntax = db.file_syntax(file_id).ok()?;
+        let node = syntax.as_syntax_node().lookup_ptr(syntax_db, stable_ptr);
+        let span = node.span_without_trivia(syntax_db);
+        let start = span.start.position_in_file(db.upcast(), file_id)?;
+        let end = span.end.position_in_file(db.upcast(), file_id)?;
+        Some((start, end, signature(syntax_db, &node, &content)))
+    }
+}
+
+/// Returns the signature of the item of the given node - its text, without attributes, up to its
+/// body or its terminating semicolon, with whitespace collapsed.
+fn signature(db: &dyn SyntaxGroup, node: &SyntaxNode, content: &str) -> String {
+    let mut signature_span: Option<TextSpan> = None;
+    for child in node.children(db) {
+        if child.kind(db) == SyntaxKind::AttributeList || child.width(db) == TextWidth::default() {
+            continue;
+        }
+        let span = child.span_without_trivia(db);
+        if span.take(content).starts_with(['{', ';']) {
+            break;
+        }
+        signature_span = Some(TextSpan {
+            start: signature_span.map_or(span.start, |signature_span| signature_span.start),
+            end: span.end,
+        });
+    }
+    signature_span
+        .map(|span| span.take(content).split_whitespace().collect::<Vec<_>>().join(" "))
+        .unwrap_or_default()
+}
+
+/// Builds the index of the symbols defined in the given crates, and writes it to `path`.
+pub fn write_symbol_index(
+    db: &dyn SemanticGroup,
+    crate_ids: &[CrateId],
+    path: &Path,
+) -> Result<()> {
+    SymbolIndex::build(db, crate_ids).save(path)
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use indoc::indoc;
+
+use super::{SymbolIndex, SymbolKind};
+use crate::db::RootDatabase;
+
+/// Builds the symbol index of a crate with the given content of its main file.
+fn build_index(content: &str) -> SymbolIndex {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    let file_id = root.file(&db, "lib.cairo".into());
+    db.override_file_content(file_id, Some(Arc::new(content.into())));
+    SymbolIndex::build(&db, &[crate_id])
+}
+
+#[test]
+fn test_build_symbol_index() {
+    let index = build_index(indoc! {"
+        const LIMIT: felt252 = 5;
+
+        #[derive(Copy, Drop)]
+        struct Point<T> {
+            x: T,
+            y: T,
+        }
+
+        trait Shape<T> {
+            fn area(self: @T) -> felt252;
+        }
+
+        impl PointShape of Shape<Point<felt252>> {
+            fn area(self: @Point<felt252>) -> felt252 {
+                *self.x * *self.y
+            }
+        }
+
+        mod inner {
+            fn scale(
+                value: felt252, factor: felt252
+            ) -> felt252 {
+                value * factor
+            }
+        }
+    "});
+    assert_eq!(
+        index.to_string(),
+        indoc! {"
+            # Cairo symbol index v1
+            const\ttest::LIMIT\tsrc/lib.cairo:1:1\t1:26\tconst LIMIT: felt252 = 5
+            struct\ttest::Point\tsrc/lib.cairo:3:1\t7:2\tstruct Point<T>
+            trait\ttest::Shape\tsrc/lib.cairo:9:1\t11:2\ttrait Shape<T>
+            trait_fn\ttest::Shape::area\tsrc/lib.cairo:10:5\t10:34\tfn area(self: @T) -> felt252
+            impl\ttest::PointShape\tsrc/lib.cairo:13:1\t17:2\timpl PointShape of Shape<Point<felt252>>
+            impl_fn\ttest::PointShape::area\tsrc/lib.cairo:14:5\t16:6\tfn area(self: @Point<felt252>) -> felt252
+            mod\ttest::inner\tsrc/lib.cairo:19:1\t25:2\tmod inner
+            fn\ttest::inner::scale\tsrc/lib.cairo:20:5\t24:6\tfn scale( value: felt252, factor: felt252 ) -> felt252
+        "}
+    );
+    assert_eq!(SymbolIndex::parse(&index.to_string()).unwrap(), index);
+}
+
+#[test]
+fn test_search_symbol_index() {
+    let index = build_index(indoc! {"
+        fn get_area() {}
+        fn area() {}
+        fn area_of_circle() {}
+        mod inner {
+            fn area() {}
+        }
+    "});
+    let search = |query| {
+        index.search(query).into_iter().map(|symbol| symbol.full_path.as_str()).collect::<Vec<_>>()
+    };
+    assert_eq!(
+        search("Area"),
+        ["test::area", "test::inner::area", "test::area_of_circle", "test::get_area"]
+    );
+    assert_eq!(search("inner::area"), ["test::inner::area"]);
+    assert_eq!(search("perimeter"), Vec::<&str>::new());
+    assert!(index.symbols.iter().all(|symbol| symbol.kind != SymbolKind::Struct));
+}
+
+#[test]
+fn test_parse_invalid_symbol_index() {
+    assert_eq!(
+        format!("{:#}", SymbolIndex::parse("fn\ttest::foo\n").unwrap_err()),
+        "Missing the symbol index header."
+    );
+    let content = "# Cairo symbol index v1\nfunction\ttest::foo\tlib.cairo:1:1\t1:2\t\n";
+    assert_eq!(
+        format!("{:#}", SymbolIndex::parse(content).unwrap_err()),
+        "Invalid symbol at line 2.: Unknown kind `function`."
+    );
+}
@@ -0,0 +1,108 @@
+//! A long-running Cairo compiler process, serving compilation requests over a local socket.
+//!
+//! The daemon keeps a warm compilation database per compiled project, so compiling a project again
+//! only recomputes what changed since its last compilation. Clients report changed files with
+//! [Request::FilesChanged], as the daemon does not watch the file system itself. See [protocol] for
+//! the messages sent over the socket. The socket transport is only available on unix platforms.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::project::{setup_project, PROJECT_FILE_NAME};
+use cairo_lang_compiler::{compile_prepared_db, CompilerConfig};
+use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup, PrivRawFileContentQuery};
+use cairo_lang_filesystem::ids::{CrateId, FileLongId};
+
+pub use crate::protocol::{Request, Response};
+#[cfg(unix)]
+pub use crate::socket::DaemonClient;
+
+pub mod protocol;
+#[cfg(unix)]
+mod socket;
+
+#[cfg(test)]
+mod test;
+
+/// A project compiled by the daemon, with the database it was compiled in.
+struct Project {
+    db: RootDatabase,
+    main_crate_ids: Vec<CrateId>,
+}
+
+/// The state of the compiler daemon - the projects it compiled, keyed by their canonical paths.
+#[derive(Default)]
+pub struct CompilerDaemon {
+    projects: HashMap<PathBuf, Project>,
+}
+
+impl CompilerDaemon {
+    /// Returns a daemon that compiled no projects yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handles a single request.
+    pub fn handle(&mut self, request: Request) -> Response {
+        match request {
+            Request::Compile { path, replace_ids } => match self.compile(&path, replace_ids) {
+                Ok(sierra_program) => Response::Compiled { sierra_program },
+                Err(err) => Response::Failed { error: format!("{err:?}") },
+            },
+            Request::FilesChanged { paths } => {
+                self.files_changed(&paths);
+                Response::Done
+            }
+            Request::Shutdown => Response::Done,
+        }
+    }
+
+    /// Compiles the project at the given path, setting it up on its first compilation.
+    fn compile(&mut self, path: &Path, replace_ids: bool) -> anyhow::Result<String> {
+        let path = path.canonicalize().with_context(|| format!("Failed to find {path:?}."))?;
+        let project = match self.projects.entry(path) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let mut db = RootDatabase::builder().detect_corelib().build()?;
+                let main_crate_ids = setup_project(&mut db, entry.key())?;
+                entry.insert(Project { db, main_crate_ids })
+            }
+        };
+        let mut diagnostics = String::new();
+        let sierra_program = compile_prepared_db(
+            &mut project.db,
+            project.main_crate_ids.clone(),
+            CompilerConfig {
+                diagnostics_reporter: DiagnosticsReporter::write_to_string(&mut diagnostics),
+                replace_ids,
+                ..CompilerConfig::default()
+            },
+        );
+        let sierra_program = sierra_program.map_err(|err| anyhow::anyhow!("{diagnostics}{err}"))?;
+        Ok(sierra_program.to_string())
+    }
+
+    /// Invalidates the cached content of the given files in all the projects. A changed project
+    /// file drops its project, which is set up again on its next compilation.
+    fn files_changed(&mut self, paths: &[PathBuf]) {
+        for path in paths {
+            // Removed files can no longer be canonicalized, so their given path is used.
+            let path = path.canonicalize().unwrap_or_else(|_| path.clone());
+            if path.file_name().map_or(false, |name| name == PROJECT_FILE_NAME) {
+                if let Some(project_dir) = path.parent() {
+                    self.projects.remove(project_dir);
+                }
+            }
+            for project in self.projects.values_mut() {
+                let file = project.db.intern_file(FileLongId::OnDisk(path.clone()));
+                PrivRawFileContentQuery
+                    .in_db_mut(project.db.as_files_group_mut())
+                    .invalidate(&file);
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! The protocol between the compiler daemon and its clients.
+//!
+//! Every message is a single line holding a JSON object. A client sends a [Request], and the daemon
+//! answers it with a single [Response], in order. A connection may carry any number of requests.
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// A request sent by a client to the daemon.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum Request {
+    /// Compiles the project at `path` - a `.cairo` file, or a directory with a
+    /// `cairo_project.toml` - into a Sierra program.
+    Compile {
+        path: PathBuf,
+        /// Whether to replace the Sierra ids with human readable ones.
+        #[serde(default)]
+        replace_ids: bool,
+    },
+    /// Notifies the daemon that the given files were changed, created or removed on disk, so
+    /// their next read is from the disk rather than from the cache.
+    FilesChanged { paths: Vec<PathBuf> },
+    /// Stops the daemon, after answering the request.
+    Shutdown,
+}
+
+/// A response of the daemon to a [Request].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Response {
+    /// The project was compiled, into the given Sierra program text.
+    Compiled { sierra_program: String },
+    /// The request failed, e.g. due to diagnostics in the compiled project.
+    Failed { error: String },
+    /// The request was handled, and has no result.
+    Done,
+}
+
+/// Reads a single message, or returns `None` if the stream ended.
+pub fn read_message<T: DeserializeOwned>(reader: &mut impl BufRead) -> anyhow::Result<Option<T>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).context("Failed to read message.")? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_str(&line).context("Failed to parse message.")?))
+}
+
+/// Writes a single message.
+pub fn write_message<T: Serialize>(writer: &mut impl Write, message: &T) -> anyhow::Result<()> {
+    // JSON strings escape newlines, so the message is written in a single line.
+    let mut line = serde_json::to_string(message).context("Failed to serialize message.")?;
+    line.push('\n');
+    writer.write_all(line.as_bytes()).context("Failed to write message.")?;
+    writer.flush().context("Failed to write message.")
+}
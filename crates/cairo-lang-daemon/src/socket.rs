@@ -0,0 +1,91 @@
+//! The local socket transport of the daemon, available on unix platforms only.
+
+use std::io::BufReader;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::protocol::{read_message, write_message};
+use crate::{CompilerDaemon, Request, Response};
+
+impl CompilerDaemon {
+    /// Serves the connections to `listener` one after the other, until a client requests a
+    /// shutdown.
+    pub fn serve(&mut self, listener: &UnixListener) -> anyhow::Result<()> {
+        for stream in listener.incoming() {
+            let stream = stream.context("Failed to accept a connection.")?;
+            match self.serve_connection(stream) {
+                Ok(true) => return Ok(()),
+                Ok(false) => {}
+                Err(err) => log::warn!("Dropped a connection: {err:?}"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Serves the requests of a single connection until it is closed.
+    /// Returns whether a shutdown was requested.
+    fn serve_connection(&mut self, stream: UnixStream) -> anyhow::Result<bool> {
+        let mut writer = stream.try_clone().context("Failed to clone the connection.")?;
+        let mut reader = BufReader::new(stream);
+        while let Some(request) = read_message(&mut reader)? {
+            let shutdown = request == Request::Shutdown;
+            write_message(&mut writer, &self.handle(request))?;
+            if shutdown {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// A client of a compiler daemon, connected over a local socket.
+pub struct DaemonClient {
+    reader: BufReader<UnixStream>,
+    writer: UnixStream,
+}
+
+impl DaemonClient {
+    /// Connects to the daemon listening on the socket at the given path.
+    pub fn connect(socket_path: &Path) -> anyhow::Result<Self> {
+        let writer = UnixStream::connect(socket_path)
+            .with_context(|| format!("Failed to connect to {socket_path:?}."))?;
+        let reader = BufReader::new(writer.try_clone().context("Failed to clone the connection.")?);
+        Ok(Self { reader, writer })
+    }
+
+    /// Sends a request to the daemon, and returns its response.
+    pub fn request(&mut self, request: &Request) -> anyhow::Result<Response> {
+        write_message(&mut self.writer, request)?;
+        read_message(&mut self.reader)?.context("The daemon closed the connection.")
+    }
+
+    /// Compiles the project at the given path, returning the text of the Sierra program.
+    pub fn compile(&mut self, path: &Path, replace_ids: bool) -> anyhow::Result<String> {
+        match self.request(&Request::Compile { path: path.to_path_buf(), replace_ids })? {
+            Response::Compiled { sierra_program } => Ok(sierra_program),
+            Response::Failed { error } => anyhow::bail!(error),
+            Response::Done => anyhow::bail!("Unexpected response to a compilation request."),
+        }
+    }
+
+    /// Notifies the daemon that the given files changed.
+    pub fn files_changed(&mut self, paths: Vec<PathBuf>) -> anyhow::Result<()> {
+        self.expect_done(&Request::FilesChanged { paths })
+    }
+
+    /// Stops the daemon.
+    pub fn shutdown(mut self) -> anyhow::Result<()> {
+        self.expect_done(&Request::Shutdown)
+    }
+
+    /// Sends a request that has no result.
+    fn expect_done(&mut self, request: &Request) -> anyhow::Result<()> {
+        match self.request(request)? {
+            Response::Done => Ok(()),
+            Response::Failed { error } => anyhow::bail!(error),
+            Response::Compiled { .. } => anyhow::bail!("Unexpected response to a notification."),
+        }
+    }
+}
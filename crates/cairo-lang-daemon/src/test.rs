@@ -0,0 +1,86 @@
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::path::PathBuf;
+
+use indoc::indoc;
+
+#[cfg(unix)]
+use crate::DaemonClient;
+use crate::{CompilerDaemon, Request, Response};
+
+/// Returns the path of a file, relative to the root of the repository.
+fn repo_path(path: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", "..", path].iter().collect()
+}
+
+/// Returns a fresh temporary directory for the given test.
+fn temp_dir(test_name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("{test_name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_compile() {
+    let mut daemon = CompilerDaemon::new();
+    let request = Request::Compile { path: repo_path("examples/fib.cairo"), replace_ids: true };
+    let Response::Compiled { sierra_program } = daemon.handle(request) else {
+        panic!("Compilation failed.");
+    };
+    assert!(sierra_program.contains("fib::fib::fib"));
+
+    let request =
+        Request::Compile { path: repo_path("examples/no_such_file.cairo"), replace_ids: false };
+    assert!(matches!(daemon.handle(request), Response::Failed { .. }));
+}
+
+#[test]
+fn test_files_changed() {
+    let dir = temp_dir("test_files_changed");
+    let path = dir.join("lib.cairo");
+    fs::write(&path, "fn foo() -> felt252 { 1 }").unwrap();
+    let mut daemon = CompilerDaemon::new();
+    let compile = |daemon: &mut CompilerDaemon| match daemon
+        .handle(Request::Compile { path: path.clone(), replace_ids: true })
+    {
+        Response::Compiled { sierra_program } => sierra_program,
+        response => panic!("Unexpected response: {response:?}"),
+    };
+    assert!(compile(&mut daemon).contains("foo"));
+
+    let content = indoc! {"
+        fn foo() -> felt252 { 1 }
+        fn bar() -> felt252 { 2 }
+    "};
+    fs::write(&path, content).unwrap();
+    // The change is not seen before it is reported.
+    assert!(!compile(&mut daemon).contains("bar"));
+    assert_eq!(daemon.handle(Request::FilesChanged { paths: vec![path.clone()] }), Response::Done);
+    assert!(compile(&mut daemon).contains("bar"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn test_serve() {
+    let dir = temp_dir("test_serve");
+    let socket_path = dir.join("daemon.sock");
+    let listener = UnixListener::bind(&socket_path).unwrap();
+    let server = std::thread::spawn(move || CompilerDaemon::new().serve(&listener));
+
+    let mut client = DaemonClient::connect(&socket_path).unwrap();
+    let sierra_program = client.compile(&repo_path("examples/fib.cairo"), true).unwrap();
+    assert!(sierra_program.contains("fib::fib::fib"));
+    assert!(client.compile(&repo_path("examples/no_such_file.cairo"), false).is_err());
+    drop(client);
+
+    // The daemon keeps serving new connections, until asked to shut down.
+    let mut client = DaemonClient::connect(&socket_path).unwrap();
+    client.files_changed(vec![repo_path("examples/fib.cairo")]).unwrap();
+    client.shutdown().unwrap();
+    server.join().unwrap().unwrap();
+
+    fs::remove_dir_all(dir).unwrap();
+}
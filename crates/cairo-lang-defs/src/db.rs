@@ -4,6 +4,7 @@ use std::sync::Arc;
 use cairo_lang_diagnostics::{Maybe, ToMaybe};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{CrateId, Directory, FileId, FileLongId, VirtualFile};
+use cairo_lang_filesystem::settings::Edition;
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_syntax::node::ast::MaybeModuleBody;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -75,6 +76,9 @@ pub trait DefsGroup:
     fn module_file(&self, module_id: ModuleFileId) -> Maybe<FileId>;
     /// Gets the directory of a module.
     fn module_dir(&self, module_id: ModuleId) -> Maybe<Directory>;
+    /// Gets the edition of a module, which is the edition of its crate.
+    /// It is passed to the macro plugins expanding the items of the module.
+    fn module_edition(&self, module_id: ModuleId) -> Edition;
 
     // File to module.
     fn crate_modules(&self, crate_id: CrateId) -> Arc<Vec<ModuleId>>;
@@ -195,6 +199,10 @@ fn module_dir(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<Directory> {
     }
 }
 
+fn module_edition(db: &dyn DefsGroup, module_id: ModuleId) -> Edition {
+    db.crate_settings(module_id.owning_crate(db)).edition
+}
+
 /// Appends all the modules under the given module, including nested modules.
 fn collect_modules_under(db: &dyn DefsGroup, modules: &mut Vec<ModuleId>, module_id: ModuleId) {
     modules.push(module_id);
@@ -294,6 +302,7 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
         }
     };
 
+    let edition = db.module_edition(module_id);
     let mut module_queue = VecDeque::new();
     module_queue.push_back((module_file, item_asts));
     let mut res = ModuleData::default();
@@ -311,7 +320,8 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
             // generate new code, remove the original code, or both), breaks the loop. If more
             // plugins might have act on the item, they can do it on the generated code.
             for plugin in db.macro_plugins() {
-                let result = plugin.generate_code(db.upcast(), item_ast.clone());
+                let result =
+                    plugin.generate_code_for_edition(db.upcast(), item_ast.clone(), edition);
                 for plugin_diag in result.diagnostics {
                     res.plugin_diagnostics.push((module_file_id, plugin_diag));
                 }
@@ -2,6 +2,7 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::Arc;
 
+use cairo_lang_filesystem::settings::Edition;
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
@@ -60,6 +61,8 @@ pub struct PluginResult {
 pub struct PluginDiagnostic {
     pub stable_ptr: SyntaxStablePtrId,
     pub message: String,
+    /// The id of the lint reporting the diagnostic, if its level is configurable.
+    pub lint_id: Option<&'static str>,
 }
 
 // TOD(spapini): Move to another place.
@@ -69,4 +72,15 @@ pub trait MacroPlugin: std::fmt::Debug + Sync + Send {
     /// Otherwise, returns (virtual_module_name, module_content), and a virtual submodule
     /// with that name and content should be created.
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult;
+
+    /// Generates code for an item of a crate of the given edition. Plugins whose code depends on
+    /// the edition override this, and it defaults to [MacroPlugin::generate_code].
+    fn generate_code_for_edition(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        _edition: Edition,
+    ) -> PluginResult {
+        self.generate_code(db, item_ast)
+    }
 }
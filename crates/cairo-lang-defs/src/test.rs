@@ -6,6 +6,7 @@ use cairo_lang_filesystem::db::{
     init_files_group, AsFilesGroupMut, FilesDatabase, FilesGroup, FilesGroupEx,
 };
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
+use cairo_lang_filesystem::settings::{CrateSettings, Edition};
 use cairo_lang_parser::db::{ParserDatabase, ParserGroup};
 use cairo_lang_syntax::node::db::{SyntaxDatabase, SyntaxGroup};
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -37,6 +38,7 @@ impl Default for DatabaseForTesting {
             plugins: vec![
                 Arc::new(FooToBarPlugin),
                 Arc::new(RemoveOrigPlugin),
+                Arc::new(EditionGatedPlugin),
                 Arc::new(DummyPlugin),
             ],
         };
@@ -207,6 +209,86 @@ fn test_submodules() {
     );
 }
 
+#[test]
+fn test_module_edition() {
+    let mut db_val = DatabaseForTesting::default();
+    let db = &mut db_val;
+
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    db.set_crate_root(crate_id, Some(Directory("src".into())));
+    set_file_content(db, "src/lib.cairo", "mod submod;");
+    set_file_content(db, "src/submod.cairo", "");
+
+    let module_id = ModuleId::CrateRoot(crate_id);
+    let submodule_id =
+        ModuleId::Submodule(*db.module_submodules_ids(module_id).unwrap().first().unwrap());
+    assert_eq!(db.module_edition(submodule_id), Edition::V2023_01);
+
+    db.set_crate_settings(
+        crate_id,
+        Some(CrateSettings { edition: Edition::V2023_10, ..Default::default() }),
+    );
+    assert_eq!(db.module_edition(module_id), Edition::V2023_10);
+    assert_eq!(db.module_edition(submodule_id), Edition::V2023_10);
+}
+
+/// Adds a function 'baz' for a function annotated with #[edition_gated], only in crates of edition
+/// 2023_10 or later. Doesn't remove the original item.
+#[derive(Debug)]
+struct EditionGatedPlugin;
+impl MacroPlugin for EditionGatedPlugin {
+    fn generate_code(&self, _db: &dyn SyntaxGroup, _item_ast: ast::Item) -> PluginResult {
+        PluginResult::default()
+    }
+
+    fn generate_code_for_edition(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        edition: Edition,
+    ) -> PluginResult {
+        let Some(free_function_ast) = try_extract_matches!(item_ast, ast::Item::FreeFunction) else { return PluginResult::default(); };
+        if edition < Edition::V2023_10 || !free_function_ast.has_attr(db, "edition_gated") {
+            return PluginResult::default();
+        }
+        PluginResult {
+            code: Some(PluginGeneratedFile {
+                name: "virt".into(),
+                content: "fn baz() {}".to_string(),
+                aux_data: DynGeneratedFileAuxData::new(DummyAuxData),
+            }),
+            diagnostics: vec![],
+            remove_original_item: false,
+        }
+    }
+}
+
+#[test]
+fn test_edition_gated_plugin() {
+    let mut db_val = DatabaseForTesting::default();
+    let db = &mut db_val;
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    db.set_crate_root(crate_id, Some(Directory("src".into())));
+    set_file_content(db, "src/lib.cairo", "#[edition_gated] fn foo() {}");
+    let module_id = ModuleId::CrateRoot(crate_id);
+
+    // In the default edition, only the dummy plugin acts on the function.
+    assert_eq!(
+        format!("{:?}", db.module_items(module_id).unwrap().debug(db)),
+        "[FreeFunctionId(test::foo), ExternTypeId(test::B)]"
+    );
+
+    db.set_crate_settings(
+        crate_id,
+        Some(CrateSettings { edition: Edition::V2023_10, ..Default::default() }),
+    );
+    assert_eq!(
+        format!("{:?}", db.module_items(module_id).unwrap().debug(db)),
+        "[FreeFunctionId(test::foo), FreeFunctionId(test::baz), ExternTypeId(test::B), \
+         ExternTypeId(test::B)]"
+    );
+}
+
 #[derive(Debug)]
 struct DummyAuxData;
 impl GeneratedFileAuxData for DummyAuxData {
@@ -245,6 +327,7 @@ impl MacroPlugin for DummyPlugin {
                 diagnostics: vec![PluginDiagnostic {
                     stable_ptr: free_function_ast.stable_ptr().untyped(),
                     message: "bla".into(),
+                    lint_id: None,
                 }],
                 remove_original_item: false,
             },
@@ -18,8 +18,32 @@ pub trait DiagnosticEntry: Clone + std::fmt::Debug + Eq + std::hash::Hash {
     type DbType: Upcast<dyn FilesGroup> + ?Sized;
     fn format(&self, db: &Self::DbType) -> String;
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation;
+    /// The id of the lint this diagnostic is reported by, if its level is configurable.
+    /// Diagnostics without a lint id are always errors.
+    fn lint_id(&self) -> Option<&'static str> {
+        None
+    }
     // TODO(spapini): Add a way to inspect the diagnostic programmatically, e.g, downcast.
 }
+
+/// The severity of a reported diagnostic.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    /// Information that is not a problem in the code, e.g. an explanation of a compilation cost.
+    Note,
+}
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Note => write!(f, "note"),
+        }
+    }
+}
 pub struct DiagnosticLocation {
     pub file_id: FileId,
     pub span: TextSpan,
@@ -109,6 +133,15 @@ pub fn format_diagnostics(
     db: &dyn FilesGroup,
     message: &str,
     location: DiagnosticLocation,
+) -> String {
+    format_diagnostics_with_severity(db, Severity::Error, message, location)
+}
+
+pub fn format_diagnostics_with_severity(
+    db: &dyn FilesGroup,
+    severity: Severity,
+    message: &str,
+    location: DiagnosticLocation,
 ) -> String {
     let file_name = location.file_id.file_name(db);
     let marks = get_location_marks(db, &location);
@@ -116,7 +149,7 @@ pub fn format_diagnostics(
         Some(pos) => format!("{}:{}", pos.line + 1, pos.col + 1),
         None => "?".into(),
     };
-    format!("error: {message}\n --> {file_name}:{pos}\n{marks}\n")
+    format!("{severity}: {message}\n --> {file_name}:{pos}\n{marks}\n")
 }
 
 /// A set of diagnostic entries that arose during a computation.
@@ -5,7 +5,8 @@ mod diagnostics;
 mod location_marks;
 
 pub use self::diagnostics::{
-    format_diagnostics, skip_diagnostic, DiagnosticAdded, DiagnosticEntry, DiagnosticLocation,
-    Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
+    format_diagnostics, format_diagnostics_with_severity, skip_diagnostic, DiagnosticAdded,
+    DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder, Maybe, Severity, ToMaybe,
+    ToOption,
 };
 pub use self::location_marks::get_location_marks;
@@ -4,10 +4,12 @@ use std::sync::Arc;
 
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
+use smol_str::SmolStr;
 
 use crate::cfg::CfgSet;
 use crate::flag::Flag;
 use crate::ids::{CrateId, CrateLongId, Directory, FileId, FileLongId, FlagId, FlagLongId};
+use crate::settings::CrateSettings;
 use crate::span::{FileSummary, TextOffset, TextWidth};
 
 #[cfg(test)]
@@ -29,6 +31,9 @@ pub trait FilesGroup {
     /// Main input of the project. Lists all the crates.
     #[salsa::input]
     fn crate_roots(&self) -> Arc<OrderedHashMap<CrateId, Directory>>;
+    /// The settings of the crates. Crates without explicit settings use the default settings.
+    #[salsa::input]
+    fn crates_settings(&self) -> Arc<OrderedHashMap<CrateId, CrateSettings>>;
 
     /// Overrides for file content. Mostly used by language server and tests.
     /// TODO(spapini): Currently, when this input changes, all the file_content() queries will
@@ -50,6 +55,8 @@ pub trait FilesGroup {
     fn crates(&self) -> Vec<CrateId>;
     /// Root directory of the crate.
     fn crate_root_dir(&self, crate_id: CrateId) -> Option<Directory>;
+    /// Settings of the crate.
+    fn crate_settings(&self, crate_id: CrateId) -> CrateSettings;
 
     /// Query for raw file contents. Private.
     fn priv_raw_file_content(&self, file_id: FileId) -> Option<Arc<String>>;
@@ -65,6 +72,7 @@ pub fn init_files_group(db: &mut (dyn FilesGroup + 'static)) {
     // Initialize inputs.
     db.set_file_overrides(Arc::new(OrderedHashMap::default()));
     db.set_crate_roots(Arc::new(OrderedHashMap::default()));
+    db.set_crates_settings(Arc::new(OrderedHashMap::default()));
     db.set_flags(Arc::new(OrderedHashMap::default()));
     db.set_cfg_set(Arc::new(CfgSet::new()));
 }
@@ -100,6 +108,32 @@ pub trait FilesGroupEx: Upcast<dyn FilesGroup> + AsFilesGroupMut {
         };
         self.as_files_group_mut().set_crate_roots(Arc::new(crate_roots));
     }
+    /// Sets the root directory of the crate, and overrides the content of its files - given by
+    /// their paths relative to the root. Allows compiling the crate where the file system is not
+    /// available, e.g. when targeting wasm.
+    fn set_crate_files(
+        &mut self,
+        crt: CrateId,
+        root: Directory,
+        files: impl IntoIterator<Item = (SmolStr, Arc<String>)>,
+    ) {
+        let db = Upcast::upcast(self);
+        let mut overrides = db.file_overrides().as_ref().clone();
+        for (name, content) in files {
+            overrides.insert(root.file(db, name), content);
+        }
+        self.as_files_group_mut().set_file_overrides(Arc::new(overrides));
+        self.set_crate_root(crt, Some(root));
+    }
+    /// Sets the settings of the crate. None value resets the crate to the default settings.
+    fn set_crate_settings(&mut self, crt: CrateId, settings: Option<CrateSettings>) {
+        let mut crates_settings = Upcast::upcast(self).crates_settings().as_ref().clone();
+        match settings {
+            Some(settings) => crates_settings.insert(crt, settings),
+            None => crates_settings.swap_remove(&crt),
+        };
+        self.as_files_group_mut().set_crates_settings(Arc::new(crates_settings));
+    }
     /// Sets the given flag value. None value removes the flag.
     fn set_flag(&mut self, id: FlagId, value: Option<Arc<Flag>>) {
         let mut flags = Upcast::upcast(self).flags().as_ref().clone();
@@ -129,6 +163,9 @@ fn crates(db: &dyn FilesGroup) -> Vec<CrateId> {
 fn crate_root_dir(db: &dyn FilesGroup, crt: CrateId) -> Option<Directory> {
     db.crate_roots().get(&crt).cloned()
 }
+fn crate_settings(db: &dyn FilesGroup, crt: CrateId) -> CrateSettings {
+    db.crates_settings().get(&crt).cloned().unwrap_or_default()
+}
 
 fn priv_raw_file_content(db: &dyn FilesGroup, file: FileId) -> Option<Arc<String>> {
     match db.lookup_intern_file(file) {
@@ -8,6 +8,7 @@ use crate::cfg::{Cfg, CfgSet};
 use crate::db::FilesGroupEx;
 use crate::flag::Flag;
 use crate::ids::{CrateLongId, Directory, FlagId};
+use crate::settings::{CrateSettings, Edition};
 use crate::test_utils::FilesDatabaseForTesting;
 
 #[test]
@@ -27,6 +28,27 @@ fn test_filesystem() {
     assert_eq!(*db.file_content(file_id).unwrap(), "content\n");
 }
 
+#[test]
+fn test_crate_files() {
+    let mut db = FilesDatabaseForTesting::default();
+
+    let crt = db.intern_crate(CrateLongId("my_crate".into()));
+    let directory = Directory("virtual/src".into());
+    db.set_crate_files(
+        crt,
+        directory.clone(),
+        [
+            ("lib.cairo".into(), Arc::new("mod a;\n".into())),
+            ("a.cairo".into(), Arc::new("fn foo() {}\n".into())),
+        ],
+    );
+
+    assert_eq!(db.crate_root_dir(crt), Some(directory.clone()));
+    assert_eq!(*db.file_content(directory.file(&db, "lib.cairo".into())).unwrap(), "mod a;\n");
+    assert_eq!(*db.file_content(directory.file(&db, "a.cairo".into())).unwrap(), "fn foo() {}\n");
+    assert!(db.file_content(directory.file(&db, "b.cairo".into())).is_none());
+}
+
 #[test]
 fn test_flags() {
     let mut db = FilesDatabaseForTesting::default();
@@ -52,3 +74,18 @@ fn test_cfgs() {
         CfgSet::from_iter([Cfg::name("test"), Cfg::kv("k", "v1"), Cfg::kv("k", "v2")])
     )
 }
+
+#[test]
+fn test_crate_settings() {
+    let mut db = FilesDatabaseForTesting::default();
+
+    let crt = db.intern_crate(CrateLongId("my_crate".into()));
+    let crt2 = db.intern_crate(CrateLongId("my_crate2".into()));
+    db.set_crate_settings(
+        crt,
+        Some(CrateSettings { edition: Edition::V2023_10, ..Default::default() }),
+    );
+
+    assert_eq!(db.crate_settings(crt).edition, Edition::V2023_10);
+    assert_eq!(db.crate_settings(crt2), CrateSettings::default());
+}
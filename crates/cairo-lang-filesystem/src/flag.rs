@@ -4,4 +4,12 @@ pub enum Flag {
     /// Whether automatically add `withdraw_gas` calls in code cycles.
     /// Default is true - automatically add.
     AddWithdrawGas(bool),
+    /// Whether to store the variables that are alive after a convergence of branches in a function
+    /// with an unknown ap change in local variables, instead of enabling ap tracking for aligning
+    /// the branches.
+    /// Default is false - enable ap tracking.
+    PromoteToLocals(bool),
+    /// Whether to inline the functions that should be inlined.
+    /// Default is true - apply inlining.
+    Inlining(bool),
 }
@@ -5,5 +5,6 @@ pub mod db;
 pub mod detect;
 pub mod flag;
 pub mod ids;
+pub mod settings;
 pub mod span;
 pub mod test_utils;
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+/// Per crate compilation settings.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CrateSettings {
+    /// The edition of the crate.
+    #[serde(default)]
+    pub edition: Edition,
+    /// The levels of the lints of the crate.
+    #[serde(default, skip_serializing_if = "LintSettings::is_empty")]
+    pub lints: LintSettings,
+}
+
+/// The Cairo edition of a crate.
+/// Macro plugins are given the edition of the crate whose items they expand, so that code they
+/// generate differently in a later edition keeps its behavior in crates of older editions.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Edition {
+    #[default]
+    #[serde(rename = "2023_01")]
+    V2023_01,
+    #[serde(rename = "2023_10")]
+    V2023_10,
+}
+
+/// The level of a lint, deciding how its diagnostics are reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LintLevel {
+    /// The diagnostics are not reported.
+    Allow,
+    /// The diagnostics are reported as warnings, and do not fail the compilation.
+    Warn,
+    /// The diagnostics are reported as errors.
+    Deny,
+}
+
+/// Lint level configuration of a crate.
+/// Lints not mentioned in any of the lists are denied.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LintSettings {
+    /// Lints whose diagnostics are not reported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// Lints whose diagnostics are reported as warnings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warn: Vec<String>,
+    /// Lints whose diagnostics are reported as errors. Takes precedence over the other lists.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+    /// Whether warnings should be reported as errors.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deny_warnings: bool,
+}
+impl LintSettings {
+    /// Returns the level of the given lint.
+    pub fn level(&self, lint_id: &str) -> LintLevel {
+        let contains = |lints: &[String]| lints.iter().any(|lint| lint == lint_id);
+        if contains(&self.deny) {
+            LintLevel::Deny
+        } else if contains(&self.warn) {
+            if self.deny_warnings { LintLevel::Deny } else { LintLevel::Warn }
+        } else if contains(&self.allow) {
+            LintLevel::Allow
+        } else {
+            LintLevel::Deny
+        }
+    }
+
+    /// Returns whether the given lint is listed as a warning or an error.
+    /// Opt-in lints are only checked in crates which enable them.
+    pub fn is_enabled(&self, lint_id: &str) -> bool {
+        self.warn.iter().chain(&self.deny).any(|lint| lint == lint_id)
+    }
+
+    /// Returns true if no lint level is configured.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
@@ -10,6 +10,7 @@ use std::sync::Arc;
 use anyhow::{bail, Error};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::project::{setup_project, update_crate_roots_from_project_config};
+use cairo_lang_compiler::symbol_index::{self, SymbolIndex};
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::{
     ConstantLongId, EnumLongId, ExternFunctionLongId, ExternTypeLongId, FileIndex,
@@ -21,6 +22,7 @@ use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, ToOption};
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::db::{
     init_dev_corelib, AsFilesGroupMut, FilesGroup, FilesGroupEx, PrivRawFileContentQuery,
+    CORELIB_CRATE_NAME,
 };
 use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileId, FileLongId};
@@ -67,6 +69,8 @@ pub mod completions;
 pub mod vfs;
 
 const MAX_CRATE_DETECTION_DEPTH: usize = 20;
+/// The path of the persisted symbol index, relative to the root of the workspace.
+const SYMBOL_INDEX_PATH: &str = "target/cairo-symbols.idx";
 
 pub async fn serve_language_service() {
     #[cfg(feature = "runtime-agnostic")]
@@ -102,6 +106,16 @@ pub struct State {
 }
 impl std::panic::UnwindSafe for State {}
 
+/// The symbol index persisted by a previous session, serving workspace symbol searches until the
+/// crates of the workspace are analyzed.
+#[derive(Default)]
+pub struct PersistedSymbols {
+    /// The path of the persisted index, if the workspace has a root.
+    pub path: Option<PathBuf>,
+    /// The index read from `path`, if it was found.
+    pub index: Option<SymbolIndex>,
+}
+
 #[derive(Clone)]
 pub struct NotificationService {
     pub client: Client,
@@ -126,6 +140,7 @@ pub struct Backend {
     // State mutex should only be taken after db mutex is taken, to avoid deadlocks.
     pub db_mutex: tokio::sync::Mutex<RootDatabase>,
     pub state_mutex: tokio::sync::Mutex<State>,
+    pub persisted_symbols: tokio::sync::Mutex<PersistedSymbols>,
     pub scarb: ScarbService,
     pub notification: NotificationService,
 }
@@ -140,6 +155,7 @@ impl Backend {
             db_mutex: db.into(),
             notification: notification.clone(),
             state_mutex: State::default().into(),
+            persisted_symbols: PersistedSymbols::default().into(),
             scarb: ScarbService::new(notification),
         }
     }
@@ -352,6 +368,20 @@ impl Backend {
         }
     }
 
+    /// Builds the symbol index of the crates of the workspace, and persists it for the next
+    /// session.
+    async fn save_symbol_index(&self) {
+        let Some(path) = self.persisted_symbols.lock().await.path.clone() else { return };
+        let Ok(index) = self.with_db(workspace_symbol_index).await else { return };
+        // No crate of the workspace was analyzed in this session, so the persisted index is kept.
+        if index.symbols.is_empty() {
+            return;
+        }
+        if let Err(err) = index.save(&path) {
+            warn!("{err:?}");
+        }
+    }
+
     /// Reload crate detection for all open files.
     pub async fn reload(&self) -> LSPResult<()> {
         let mut db = self.db_mut().await;
@@ -418,7 +448,13 @@ impl TryFrom<String> for ServerCommands {
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _: InitializeParams) -> LSPResult<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> LSPResult<InitializeResult> {
+        if let Some(root) = params.root_uri.and_then(|root_uri| root_uri.to_file_path().ok()) {
+            let path = root.join(SYMBOL_INDEX_PATH);
+            let mut persisted_symbols = self.persisted_symbols.lock().await;
+            persisted_symbols.index = SymbolIndex::load(&path).ok();
+            persisted_symbols.path = Some(path);
+        }
         Ok(InitializeResult {
             server_info: None,
             capabilities: ServerCapabilities {
@@ -456,6 +492,7 @@ impl LanguageServer for Backend {
                 document_formatting_provider: Some(OneOf::Left(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
                 ..ServerCapabilities::default()
             },
         })
@@ -485,6 +522,7 @@ impl LanguageServer for Backend {
     }
 
     async fn shutdown(&self) -> LSPResult<()> {
+        self.save_symbol_index().await;
         Ok(())
     }
 
@@ -567,6 +605,8 @@ impl LanguageServer for Backend {
         let file = file(&db, params.text_document.uri);
         PrivRawFileContentQuery.in_db_mut(db.as_files_group_mut()).invalidate(&file);
         db.override_file_content(file, None);
+        drop(db);
+        self.save_symbol_index().await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
@@ -704,6 +744,21 @@ impl LanguageServer for Backend {
         .await
     }
 
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LSPResult<Option<Vec<SymbolInformation>>> {
+        // Until a file is opened, no crate of the workspace is analyzed, so the index of the
+        // previous session is used.
+        if self.state_mutex.lock().await.open_files.is_empty() {
+            if let Some(index) = &self.persisted_symbols.lock().await.index {
+                return Ok(Some(symbol_information(index, &params.query)));
+            }
+        }
+        self.with_db(|db| Some(symbol_information(&workspace_symbol_index(db), &params.query)))
+            .await
+    }
+
     async fn goto_definition(
         &self,
         params: GotoDefinitionParams,
@@ -1032,6 +1087,60 @@ fn nearest_semantic_expr(
     }
 }
 
+/// Builds the symbol index of the crates of the workspace, excluding the corelib.
+fn workspace_symbol_index(db: &RootDatabase) -> SymbolIndex {
+    let crate_ids = db
+        .crates()
+        .into_iter()
+        .filter(|crate_id| db.lookup_intern_crate(*crate_id).0 != CORELIB_CRATE_NAME)
+        .collect::<Vec<_>>();
+    SymbolIndex::build(db, &crate_ids)
+}
+
+/// Converts the symbols of the index matching the query to LSP format.
+fn symbol_information(index: &SymbolIndex, query: &str) -> Vec<SymbolInformation> {
+    index
+        .search(query)
+        .into_iter()
+        .filter_map(|symbol| {
+            let kind = match symbol.kind {
+                symbol_index::SymbolKind::Module => SymbolKind::MODULE,
+                symbol_index::SymbolKind::Constant => SymbolKind::CONSTANT,
+                symbol_index::SymbolKind::Function | symbol_index::SymbolKind::ExternFunction => {
+                    SymbolKind::FUNCTION
+                }
+                symbol_index::SymbolKind::Struct | symbol_index::SymbolKind::ExternType => {
+                    SymbolKind::STRUCT
+                }
+                symbol_index::SymbolKind::Enum => SymbolKind::ENUM,
+                symbol_index::SymbolKind::TypeAlias => SymbolKind::TYPE_PARAMETER,
+                symbol_index::SymbolKind::Trait => SymbolKind::INTERFACE,
+                symbol_index::SymbolKind::Impl | symbol_index::SymbolKind::ImplAlias => {
+                    SymbolKind::OBJECT
+                }
+                symbol_index::SymbolKind::TraitFunction
+                | symbol_index::SymbolKind::ImplFunction => SymbolKind::METHOD,
+            };
+            let uri = Url::from_file_path(&symbol.file).ok()?;
+            let range =
+                Range { start: from_pos(symbol.start.clone()), end: from_pos(symbol.end.clone()) };
+            #[allow(deprecated)]
+            let information = SymbolInformation {
+                name: symbol.name().to_string(),
+                kind,
+                tags: None,
+                deprecated: None,
+                location: Location { uri, range },
+                container_name: symbol
+                    .full_path
+                    .rsplit_once("::")
+                    .map(|(container, _)| container.to_string()),
+            };
+            Some(information)
+        })
+        .collect()
+}
+
 fn update_crate_roots(db: &mut dyn SemanticGroup, crate_roots: Vec<(CrateLongId, Directory)>) {
     for (crate_long_id, crate_root) in crate_roots {
         let crate_id = db.intern_crate(crate_long_id);
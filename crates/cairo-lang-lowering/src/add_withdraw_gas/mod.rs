@@ -92,6 +92,7 @@ fn add_withdraw_gas_to_function(
                         ),
                         block_id: old_root_new_id,
                         var_ids: vec![],
+                        hint: None,
                     },
                     MatchArm {
                         variant_id: option_none_variant(
@@ -100,6 +101,7 @@ fn add_withdraw_gas_to_function(
                         ),
                         block_id: panic_block_id,
                         var_ids: vec![],
+                        hint: None,
                     },
                 ],
                 location,
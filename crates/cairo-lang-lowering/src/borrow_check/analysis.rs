@@ -3,6 +3,7 @@
 
 use std::collections::HashMap;
 
+use cairo_lang_semantic::BranchHint;
 use itertools::Itertools;
 
 use crate::{
@@ -104,7 +105,14 @@ impl<'a, TAnalyzer: Analyzer<'a>> BackAnalysis<'a, TAnalyzer> {
             FlatBlockEnd::Goto(_, _) | FlatBlockEnd::Return(_) | FlatBlockEnd::Panic(_) => false,
             FlatBlockEnd::Match { info } => {
                 let mut missing_cache = false;
-                for arm in info.arms() {
+                // The arm pushed last is visited first, and reaches the blocks the arms merge into
+                // before the other arms. Pushing the arms in increasing order of likelihood lays
+                // the likely arm out right before these blocks (see `reorganize_blocks`).
+                for arm in info.arms().iter().sorted_by_key(|arm| match arm.hint {
+                    Some(BranchHint::Unlikely) => 0,
+                    None => 1,
+                    Some(BranchHint::Likely) => 2,
+                }) {
                     if !self.block_info.contains_key(&arm.block_id) {
                         dfs_stack.push(arm.block_id);
                         missing_cache = true;
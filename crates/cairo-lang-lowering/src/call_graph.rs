@@ -0,0 +1,347 @@
+//! The call graph of the lowered functions of crates, and its export as JSON or DOT, for audit
+//! tooling and dead code reports.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::ids::{
+    FunctionWithBodyId as SemanticFunctionWithBodyId, LanguageElementId, ModuleItemId,
+    TopLevelLanguageElementId,
+};
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::query::SemanticQuery;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+
+use crate::db::LoweringGroup;
+use crate::ids::{
+    ConcreteFunctionWithBodyId, FunctionId, FunctionLongId, FunctionWithBodyId,
+    FunctionWithBodyLongId, SemanticFunctionWithBodyIdEx,
+};
+
+#[cfg(test)]
+#[path = "call_graph_test.rs"]
+mod test;
+
+const CALL_CONTRACT_SYSCALL: &str = "core::starknet::syscalls::call_contract_syscall";
+const LIBRARY_CALL_SYSCALL: &str = "core::starknet::syscalls::library_call_syscall";
+
+/// The kind of a node of a [CallGraph].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallGraphNodeKind {
+    /// A function with a body.
+    Function,
+    /// An extern function, implemented by libfuncs.
+    Extern,
+    /// A function called through a generic impl, which is only resolved once the caller is
+    /// concretized.
+    Unresolved,
+    /// The code of another contract, called by a function through `call_contract_syscall`.
+    ExternalContract,
+    /// The code of another class, called by a function through `library_call_syscall`.
+    ExternalClass,
+}
+impl CallGraphNodeKind {
+    /// Returns the name of the kind, as exported.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CallGraphNodeKind::Function => "function",
+            CallGraphNodeKind::Extern => "extern",
+            CallGraphNodeKind::Unresolved => "unresolved",
+            CallGraphNodeKind::ExternalContract => "external_contract",
+            CallGraphNodeKind::ExternalClass => "external_class",
+        }
+    }
+
+    /// Returns the DOT shape of nodes of the kind.
+    fn shape(&self) -> &'static str {
+        match self {
+            CallGraphNodeKind::Function => "box",
+            CallGraphNodeKind::Extern => "ellipse",
+            CallGraphNodeKind::Unresolved => "hexagon",
+            CallGraphNodeKind::ExternalContract | CallGraphNodeKind::ExternalClass => "diamond",
+        }
+    }
+}
+
+/// A node of a [CallGraph].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallGraphNode {
+    /// The full path of the function, or a description of the external code.
+    pub name: String,
+    pub kind: CallGraphNodeKind,
+    /// Whether the function is defined in one of the crates the graph was built for.
+    pub local: bool,
+    /// Whether the node is reachable from the roots of the graph.
+    pub reachable: bool,
+}
+
+/// The call graph of the functions of a set of crates and of all the functions they call,
+/// including calls to other contracts, which are represented as opaque nodes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallGraph {
+    pub nodes: Vec<CallGraphNode>,
+    /// The calls of the graph, as pairs of the indices of the caller and the callee nodes.
+    pub edges: Vec<(usize, usize)>,
+}
+impl CallGraph {
+    /// Builds the call graph of the functions of the given crates, marking the nodes reachable
+    /// from the given roots.
+    pub fn build(
+        db: &dyn LoweringGroup,
+        crate_ids: &[CrateId],
+        roots: &[FunctionWithBodyId],
+    ) -> Self {
+        let mut builder = CallGraphBuilder::new(db, crate_ids);
+        for function_id in crate_functions(db, crate_ids).into_iter().chain(roots.iter().copied()) {
+            builder.function_node(function_id);
+        }
+        while let Some(function_id) = builder.queue.pop_front() {
+            builder.add_calls(function_id);
+        }
+
+        let mut graph =
+            CallGraph { nodes: builder.nodes, edges: builder.edges.into_iter().collect() };
+        let mut stack = roots
+            .iter()
+            .map(|function_id| builder.function_indices[function_id])
+            .collect::<Vec<_>>();
+        while let Some(index) = stack.pop() {
+            if graph.nodes[index].reachable {
+                continue;
+            }
+            graph.nodes[index].reachable = true;
+            stack.extend(
+                graph.edges.iter().filter(|(caller, _)| *caller == index).map(|(_, callee)| callee),
+            );
+        }
+        graph
+    }
+
+    /// Builds the call graph of the given crates, with the functions compiled for them - the free
+    /// functions without generic parameters - as its roots.
+    pub fn build_for_crates(db: &dyn LoweringGroup, crate_ids: &[CrateId]) -> Self {
+        let roots = crate_entry_points(db, crate_ids)
+            .into_iter()
+            .map(|function| function.function_with_body_id(db))
+            .collect::<Vec<_>>();
+        Self::build(db, crate_ids, &roots)
+    }
+
+    /// Returns the graph as a JSON object, with a list of nodes, and a list of edges referring to
+    /// the nodes by their indices.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\n  \"nodes\": [");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let separator = if index == 0 { "" } else { "," };
+            write!(
+                json,
+                "{separator}\n    {{\"id\": {index}, \"name\": {}, \"kind\": \"{}\", \"local\": \
+                 {}, \"reachable\": {}}}",
+                json_string(&node.name),
+                node.kind.name(),
+                node.local,
+                node.reachable,
+            )
+            .unwrap();
+        }
+        json.push_str("\n  ],\n  \"edges\": [");
+        for (index, (caller, callee)) in self.edges.iter().enumerate() {
+            let separator = if index == 0 { "" } else { "," };
+            write!(json, "{separator}\n    {{\"caller\": {caller}, \"callee\": {callee}}}")
+                .unwrap();
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+
+    /// Returns the graph in the DOT format of Graphviz. Unreachable local functions are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph call_graph {\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let style = if node.local && !node.reachable { ", style=dashed" } else { "" };
+            writeln!(
+                dot,
+                "  {index} [label={}, shape={}{style}];",
+                json_string(&node.name),
+                node.kind.shape()
+            )
+            .unwrap();
+        }
+        for (caller, callee) in &self.edges {
+            writeln!(dot, "  {caller} -> {callee};").unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Returns the given string as a quoted and escaped string literal, valid in both JSON and DOT.
+fn json_string(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Returns the functions compiled for the given crates - their free functions without generic
+/// parameters.
+pub(crate) fn crate_entry_points(
+    db: &dyn LoweringGroup,
+    crate_ids: &[CrateId],
+) -> Vec<ConcreteFunctionWithBodyId> {
+    let mut entry_points = vec![];
+    for crate_id in crate_ids {
+        for module_id in db.crate_modules(*crate_id).iter() {
+            for (free_function_id, _) in db.module_free_functions(*module_id).unwrap_or_default() {
+                entry_points.extend(ConcreteFunctionWithBodyId::from_no_generics_free(
+                    db,
+                    free_function_id,
+                ));
+            }
+        }
+    }
+    entry_points
+}
+
+/// Returns the free functions and impl functions of the given crates.
+fn crate_functions(db: &dyn LoweringGroup, crate_ids: &[CrateId]) -> Vec<FunctionWithBodyId> {
+    let mut functions = vec![];
+    for crate_id in crate_ids {
+        for module_id in db.crate_modules(*crate_id).iter() {
+            for item in db.module_items(*module_id).unwrap_or_default().iter() {
+                match item {
+                    ModuleItemId::FreeFunction(function_id) => {
+                        functions.push(SemanticFunctionWithBodyId::Free(*function_id).lowered(db));
+                    }
+                    ModuleItemId::Impl(impl_def_id) => functions.extend(
+                        db.impl_functions(*impl_def_id).unwrap_or_default().values().map(
+                            |function_id| {
+                                SemanticFunctionWithBodyId::Impl(*function_id).lowered(db)
+                            },
+                        ),
+                    ),
+                    _ => {}
+                }
+            }
+        }
+    }
+    functions
+}
+
+/// Helper for building a [CallGraph].
+struct CallGraphBuilder<'a> {
+    db: &'a dyn LoweringGroup,
+    /// The crates the graph is built for.
+    crate_ids: &'a [CrateId],
+    nodes: Vec<CallGraphNode>,
+    /// The indices of the nodes, by their names.
+    node_indices: UnorderedHashMap<String, usize>,
+    /// The indices of the nodes of the functions with a body.
+    function_indices: UnorderedHashMap<FunctionWithBodyId, usize>,
+    edges: OrderedHashSet<(usize, usize)>,
+    /// The functions whose calls are yet to be added.
+    queue: VecDeque<FunctionWithBodyId>,
+}
+impl<'a> CallGraphBuilder<'a> {
+    fn new(db: &'a dyn LoweringGroup, crate_ids: &'a [CrateId]) -> Self {
+        Self {
+            db,
+            crate_ids,
+            nodes: vec![],
+            node_indices: UnorderedHashMap::default(),
+            function_indices: UnorderedHashMap::default(),
+            edges: OrderedHashSet::default(),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Returns the index of the node of a function with a body, adding the node if missing.
+    fn function_node(&mut self, function_id: FunctionWithBodyId) -> usize {
+        if let Some(index) = self.function_indices.get(&function_id) {
+            return *index;
+        }
+        let db = self.db;
+        let module_id = function_id.base_semantic_function(db).module_file_id(db.upcast()).0;
+        let local = self.crate_ids.contains(&module_id.owning_crate(db.upcast()));
+        let index = self.node(function_name(db, function_id), CallGraphNodeKind::Function, local);
+        self.function_indices.insert(function_id, index);
+        self.queue.push_back(function_id);
+        index
+    }
+
+    /// Returns the index of the node with the given name, adding the node if missing.
+    fn node(&mut self, name: String, kind: CallGraphNodeKind, local: bool) -> usize {
+        if let Some(index) = self.node_indices.get(&name) {
+            return *index;
+        }
+        let index = self.nodes.len();
+        self.node_indices.insert(name.clone(), index);
+        self.nodes.push(CallGraphNode { name, kind, local, reachable: false });
+        index
+    }
+
+    /// Adds the edges from a function to the functions it calls.
+    fn add_calls(&mut self, function_id: FunctionWithBodyId) {
+        let caller = self.function_indices[&function_id];
+        for callee in self.db.function_with_body_direct_callees(function_id).unwrap_or_default() {
+            let callee = self.callee_node(function_id, callee);
+            self.edges.insert((caller, callee));
+        }
+    }
+
+    /// Returns the index of the node called by a call to `callee` in the given function.
+    fn callee_node(&mut self, caller: FunctionWithBodyId, callee: FunctionId) -> usize {
+        let db = self.db;
+        if let FunctionLongId::Semantic(semantic_function) = callee.lookup(db) {
+            if let Some(extern_function_id) =
+                semantic_function.try_get_extern_function_id(db.upcast())
+            {
+                let path = extern_function_id.full_path(db.upcast());
+                return match path.as_str() {
+                    CALL_CONTRACT_SYSCALL => self.node(
+                        format!("<external contract called by {}>", function_name(db, caller)),
+                        CallGraphNodeKind::ExternalContract,
+                        false,
+                    ),
+                    LIBRARY_CALL_SYSCALL => self.node(
+                        format!("<external class called by {}>", function_name(db, caller)),
+                        CallGraphNodeKind::ExternalClass,
+                        false,
+                    ),
+                    _ => self.node(path, CallGraphNodeKind::Extern, false),
+                };
+            }
+        }
+        match callee.body(db) {
+            Ok(Some(body)) => self.function_node(body.function_with_body_id(db)),
+            _ => self.node(
+                format!("{:?}", callee.lookup(db).debug(db)),
+                CallGraphNodeKind::Unresolved,
+                false,
+            ),
+        }
+    }
+}
+
+/// Returns the full path of a function with a body, e.g. `my_crate::MyImpl::my_function` for a
+/// function of an impl, or `my_crate::my_function[expr3]` for a loop of a function.
+pub(crate) fn function_name(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> String {
+    let query = SemanticQuery::new(db.upcast());
+    match db.lookup_intern_lowering_function_with_body(function_id) {
+        FunctionWithBodyLongId::Semantic(semantic_function_id) => {
+            query.function_path(semantic_function_id)
+        }
+        FunctionWithBodyLongId::Generated { parent, element } => {
+            format!("{}[expr{}]", query.function_path(parent), element.index())
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{FunctionWithBodyId, TopLevelLanguageElementId};
+use cairo_lang_semantic::test_utils::setup_test_module;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{CallGraph, CallGraphNodeKind};
+use crate::ids::SemanticFunctionWithBodyIdEx;
+use crate::test_utils::LoweringDatabaseForTesting;
+
+#[test]
+fn test_call_graph() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_module = setup_test_module(
+        db,
+        indoc! {"
+            fn main() -> felt252 {
+                helper(1)
+            }
+
+            fn helper(x: felt252) -> felt252 {
+                x + leaf()
+            }
+
+            fn leaf() -> felt252 {
+                2
+            }
+
+            fn unused() {}
+        "},
+    )
+    .unwrap();
+    let main = db
+        .module_free_functions_ids(test_module.module_id)
+        .unwrap()
+        .into_iter()
+        .find(|function_id| function_id.name(db) == "main")
+        .unwrap();
+    let graph = CallGraph::build(
+        db,
+        &[test_module.crate_id],
+        &[FunctionWithBodyId::Free(main).lowered(db)],
+    );
+
+    assert_eq!(
+        graph.to_json(),
+        indoc! {r#"
+            {
+              "nodes": [
+                {"id": 0, "name": "test::main", "kind": "function", "local": true, "reachable": true},
+                {"id": 1, "name": "test::helper", "kind": "function", "local": true, "reachable": true},
+                {"id": 2, "name": "test::leaf", "kind": "function", "local": true, "reachable": true},
+                {"id": 3, "name": "test::unused", "kind": "function", "local": true, "reachable": false},
+                {"id": 4, "name": "core::Felt252Add::add", "kind": "function", "local": false, "reachable": true},
+                {"id": 5, "name": "core::felt252_add", "kind": "extern", "local": false, "reachable": true}
+              ],
+              "edges": [
+                {"caller": 0, "callee": 1},
+                {"caller": 1, "callee": 2},
+                {"caller": 1, "callee": 4},
+                {"caller": 4, "callee": 5}
+              ]
+            }
+        "#}
+    );
+    assert_eq!(
+        graph.to_dot(),
+        indoc! {r#"
+            digraph call_graph {
+              0 [label="test::main", shape=box];
+              1 [label="test::helper", shape=box];
+              2 [label="test::leaf", shape=box];
+              3 [label="test::unused", shape=box, style=dashed];
+              4 [label="core::Felt252Add::add", shape=box];
+              5 [label="core::felt252_add", shape=ellipse];
+              0 -> 1;
+              1 -> 2;
+              1 -> 4;
+              4 -> 5;
+            }
+        "#}
+    );
+}
+
+#[test]
+fn test_call_graph_external_contract() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_module = setup_test_module(
+        db,
+        indoc! {"
+            use array::ArrayTrait;
+
+            fn call(address: starknet::ContractAddress) {
+                starknet::call_contract_syscall(address, 0, ArrayTrait::new().span());
+            }
+        "},
+    )
+    .unwrap();
+    let graph = CallGraph::build_for_crates(db, &[test_module.crate_id]);
+
+    let index_of = |name: &str| graph.nodes.iter().position(|node| node.name == name).unwrap();
+    let external = index_of("<external contract called by test::call>");
+    assert_eq!(graph.nodes[external].kind, CallGraphNodeKind::ExternalContract);
+    assert!(graph.nodes[external].reachable);
+    assert!(graph.edges.contains(&(index_of("test::call"), external)));
+}
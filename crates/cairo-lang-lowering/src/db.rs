@@ -17,11 +17,12 @@ use crate::destructs::add_destructs;
 use crate::diagnostic::LoweringDiagnostic;
 use crate::implicits::lower_implicits;
 use crate::inline::{apply_inlining, PrivInlineData};
+use crate::lint::security_lints;
 use crate::lower::{lower_semantic_function, MultiLowering};
 use crate::optimizations::delay_var_def::delay_var_def;
 use crate::optimizations::match_optimizer::optimize_matches;
 use crate::optimizations::remappings::optimize_remappings;
-use crate::panic::lower_panics;
+use crate::panic::{lower_panics, no_panic_diagnostics};
 use crate::reorganize_blocks::reorganize_blocks;
 use crate::{ids, FlatBlockEnd, FlatLowered, Location, MatchInfo, Statement};
 
@@ -430,7 +431,9 @@ fn function_with_body_lowering_diagnostics(
     let mut diagnostics = DiagnosticsBuilder::default();
 
     if let Ok(lowered) = db.function_with_body_lowering(function_id) {
-        diagnostics.extend(lowered.diagnostics.clone())
+        diagnostics.extend(lowered.diagnostics.clone());
+        diagnostics.extend(security_lints(db, function_id, &lowered));
+        diagnostics.extend(no_panic_diagnostics(db, function_id, &lowered));
     }
 
     diagnostics.extend(
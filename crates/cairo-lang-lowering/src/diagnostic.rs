@@ -8,7 +8,7 @@ use cairo_lang_semantic::expr::inference::InferenceError;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use itertools::Itertools;
 
-use crate::Location;
+use crate::{lint, Location};
 
 pub struct LoweringDiagnostics {
     pub diagnostics: DiagnosticsBuilder<LoweringDiagnostic>,
@@ -91,6 +91,18 @@ impl DiagnosticEntry for LoweringDiagnostic {
             LoweringDiagnosticKind::MemberPathLoop => {
                 "Currently, loops must change the entire variable.".into()
             }
+            LoweringDiagnosticKind::UncheckedExternalCallResult => {
+                "The result of an external call is never checked.".into()
+            }
+            LoweringDiagnosticKind::StorageWriteAfterExternalCall => {
+                "Storage may be written after an external call, allowing reentrancy.".into()
+            }
+            LoweringDiagnosticKind::MissingCallerAssertion => {
+                "Admin function may write to storage before the caller is checked.".into()
+            }
+            LoweringDiagnosticKind::PanicInNoPanicFunction => {
+                "This may panic, but the function is marked `#[no_panic]`.".into()
+            }
         };
 
         itertools::chain!(self.location.notes.iter(), std::iter::once(&msg)).join(",\n")
@@ -109,6 +121,19 @@ impl DiagnosticEntry for LoweringDiagnostic {
         }
         self.location.stable_location.diagnostic_location(db.upcast())
     }
+    fn lint_id(&self) -> Option<&'static str> {
+        match &self.kind {
+            LoweringDiagnosticKind::Unreachable { .. } => Some("unreachable_code"),
+            LoweringDiagnosticKind::UncheckedExternalCallResult => {
+                Some(lint::UNCHECKED_EXTERNAL_CALL)
+            }
+            LoweringDiagnosticKind::StorageWriteAfterExternalCall => {
+                Some(lint::STORAGE_WRITE_AFTER_EXTERNAL_CALL)
+            }
+            LoweringDiagnosticKind::MissingCallerAssertion => Some(lint::MISSING_CALLER_ASSERTION),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -127,4 +152,8 @@ pub enum LoweringDiagnosticKind {
     UnsupportedMatchArmOutOfOrder,
     CannotInlineFunctionThatMightCallItself,
     MemberPathLoop,
+    UncheckedExternalCallResult,
+    StorageWriteAfterExternalCall,
+    MissingCallerAssertion,
+    PanicInNoPanicFunction,
 }
@@ -1,5 +1,5 @@
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_semantic::ConcreteVariant;
+use cairo_lang_semantic::{BranchHint, ConcreteVariant};
 use id_arena::Arena;
 
 use crate::db::LoweringGroup;
@@ -224,7 +224,13 @@ impl DebugWithDb<LoweredFormatter<'_>> for ConcreteVariant {
 
 impl DebugWithDb<LoweredFormatter<'_>> for MatchArm {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>, ctx: &LoweredFormatter<'_>) -> std::fmt::Result {
-        write!(f, "    {:?}", self.variant_id.debug(ctx))?;
+        write!(f, "    ")?;
+        match self.hint {
+            Some(BranchHint::Likely) => write!(f, "#[likely] ")?,
+            Some(BranchHint::Unlikely) => write!(f, "#[unlikely] ")?,
+            None => {}
+        }
+        write!(f, "{:?}", self.variant_id.debug(ctx))?;
 
         if !self.var_ids.is_empty() {
             write!(f, "(")?;
@@ -162,7 +162,7 @@ fn lower_block_implicits(ctx: &mut Context<'_>, block_id: BlockId) -> Maybe<()>
         }
         FlatBlockEnd::Match { info } => match info {
             MatchInfo::Enum(stmt) => {
-                for MatchArm { variant_id: _, block_id, var_ids: _ } in &stmt.arms {
+                for MatchArm { variant_id: _, block_id, var_ids: _, hint: _ } in &stmt.arms {
                     assert!(
                         ctx.implicit_vars_for_block.insert(*block_id, implicits.clone()).is_none(),
                         "Multiple jumps to arm blocks are not allowed."
@@ -180,7 +180,7 @@ fn lower_block_implicits(ctx: &mut Context<'_>, block_id: BlockId) -> Maybe<()>
                 stmt.inputs.splice(0..0, implicit_input_vars);
                 let location = stmt.location.with_auto_generation_note(ctx.db, "implicits");
 
-                for MatchArm { variant_id: _, block_id, var_ids } in stmt.arms.iter_mut() {
+                for MatchArm { variant_id: _, block_id, var_ids, hint: _ } in stmt.arms.iter_mut() {
                     let mut arm_implicits = implicits.clone();
                     let mut implicit_input_vars = vec![];
                     for ty in callee_implicits.iter().copied() {
@@ -6,7 +6,10 @@ use std::sync::Arc;
 
 use cairo_lang_defs::ids::LanguageElementId;
 use cairo_lang_diagnostics::{Diagnostics, Maybe};
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::FlagId;
 use cairo_lang_semantic::items::functions::InlineConfiguration;
+use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{izip, Itertools};
 
@@ -403,6 +406,11 @@ pub fn apply_inlining(
     function_id: ConcreteFunctionWithBodyId,
     flat_lowered: &mut FlatLowered,
 ) -> Maybe<()> {
+    if let Some(flag) = db.get_flag(FlagId::new(db.upcast(), "inlining")) {
+        if !extract_matches!(*flag, Flag::Inlining) {
+            return Ok(());
+        }
+    }
     let function_with_body_id = function_id.function_with_body_id(db);
     let variables = VariableAllocator::new(
         db,
@@ -3,6 +3,7 @@
 //! This crate is responsible for handling the lowering phase.
 pub mod add_withdraw_gas;
 pub mod borrow_check;
+pub mod call_graph;
 pub mod concretize;
 pub mod db;
 pub mod destructs;
@@ -12,12 +13,14 @@ pub mod graph_algorithms;
 pub mod ids;
 pub mod implicits;
 pub mod inline;
+pub mod lint;
 pub mod lower;
 pub mod objects;
 pub mod optimizations;
 pub mod panic;
 pub mod reorganize_blocks;
 pub mod scc;
+pub mod stack_depth;
 pub mod utils;
 
 #[cfg(test)]
@@ -0,0 +1,145 @@
+//! This module introduces the ForwardAnalysis utility that allows writing dataflow analyses that go
+//! forward in the flow of the program, on a Lowered representation.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+
+use crate::borrow_check::analysis::StatementLocation;
+use crate::{
+    BlockId, FlatBlockEnd, FlatLowered, MatchArm, MatchInfo, Statement, VarRemapping, VarUsage,
+    VariableId,
+};
+
+/// The facts computed by a forward analysis at a point of the program.
+pub trait Lattice: Clone + Eq {
+    /// Returns the facts holding where the flows with the two given facts merge.
+    fn join(&self, other: &Self) -> Self;
+}
+
+/// A set of facts which hold if they hold in any of the merged flows.
+impl<T: Clone + Hash + Eq> Lattice for OrderedHashSet<T> {
+    fn join(&self, other: &Self) -> Self {
+        let mut joined = self.clone();
+        joined.extend(other.iter().cloned());
+        joined
+    }
+}
+
+/// Analyzer trait to implement for each specific forward analysis.
+#[allow(unused_variables)]
+pub trait ForwardAnalyzer {
+    type Info: Lattice;
+    fn visit_stmt(
+        &mut self,
+        info: &mut Self::Info,
+        statement_location: StatementLocation,
+        stmt: &Statement,
+    ) {
+    }
+    fn visit_goto(
+        &mut self,
+        info: &mut Self::Info,
+        statement_location: StatementLocation,
+        target_block_id: BlockId,
+        remapping: &VarRemapping,
+    ) {
+    }
+    fn visit_match(
+        &mut self,
+        info: &mut Self::Info,
+        statement_location: StatementLocation,
+        match_info: &MatchInfo,
+    ) {
+    }
+    /// Updates the info flowing from a match into one of its arms.
+    fn visit_match_arm(&mut self, info: &mut Self::Info, match_info: &MatchInfo, arm: &MatchArm) {}
+    fn visit_return(
+        &mut self,
+        info: &Self::Info,
+        statement_location: StatementLocation,
+        vars: &[VarUsage],
+    ) {
+    }
+    fn visit_panic(
+        &mut self,
+        info: &Self::Info,
+        statement_location: StatementLocation,
+        var: &VariableId,
+    ) {
+    }
+}
+
+/// Main analysis type that allows traversing the flow forward, until a fixed point is reached.
+pub struct ForwardAnalysis<'a, TAnalyzer: ForwardAnalyzer> {
+    pub lowered: &'a FlatLowered,
+    /// The info at the start of each reachable block.
+    pub block_info: HashMap<BlockId, TAnalyzer::Info>,
+    pub analyzer: TAnalyzer,
+}
+impl<'a, TAnalyzer: ForwardAnalyzer> ForwardAnalysis<'a, TAnalyzer> {
+    pub fn new(lowered: &'a FlatLowered, analyzer: TAnalyzer) -> Self {
+        Self { lowered, block_info: HashMap::new(), analyzer }
+    }
+
+    /// Runs the analysis, starting with the given info at the root block.
+    /// A block is revisited whenever the info at its start changes, so the analysis terminates as
+    /// long as the lattice has no infinite ascending chains.
+    pub fn run(&mut self, root_info: TAnalyzer::Info) {
+        let lowered = self.lowered;
+        self.block_info.insert(BlockId::root(), root_info);
+        let mut worklist = vec![BlockId::root()];
+        while let Some(block_id) = worklist.pop() {
+            let mut info = self.block_info[&block_id].clone();
+            let block = &lowered.blocks[block_id];
+            for (i, stmt) in block.statements.iter().enumerate() {
+                self.analyzer.visit_stmt(&mut info, (block_id, i), stmt);
+            }
+            let statement_location = (block_id, block.statements.len());
+            match &block.end {
+                FlatBlockEnd::NotSet => {}
+                FlatBlockEnd::Return(vars) => {
+                    self.analyzer.visit_return(&info, statement_location, vars)
+                }
+                FlatBlockEnd::Panic(var) => {
+                    self.analyzer.visit_panic(&info, statement_location, var)
+                }
+                FlatBlockEnd::Goto(target_block_id, remapping) => {
+                    self.analyzer.visit_goto(
+                        &mut info,
+                        statement_location,
+                        *target_block_id,
+                        remapping,
+                    );
+                    self.propagate(&mut worklist, *target_block_id, info);
+                }
+                FlatBlockEnd::Match { info: match_info } => {
+                    self.analyzer.visit_match(&mut info, statement_location, match_info);
+                    for arm in match_info.arms() {
+                        let mut arm_info = info.clone();
+                        self.analyzer.visit_match_arm(&mut arm_info, match_info, arm);
+                        self.propagate(&mut worklist, arm.block_id, arm_info);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Merges the given info into the info at the start of a block, and schedules the block to be
+    /// visited if it changed.
+    fn propagate(&mut self, worklist: &mut Vec<BlockId>, block_id: BlockId, info: TAnalyzer::Info) {
+        let info = match self.block_info.get(&block_id) {
+            Some(current) => {
+                let joined = current.join(&info);
+                if joined == *current {
+                    return;
+                }
+                joined
+            }
+            None => info,
+        };
+        self.block_info.insert(block_id, info);
+        worklist.push(block_id);
+    }
+}
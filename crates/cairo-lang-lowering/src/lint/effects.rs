@@ -0,0 +1,100 @@
+//! Summaries of the effects of functions relevant to the security of contracts, computed over the
+//! call graph.
+
+use cairo_lang_defs::ids::TopLevelLanguageElementId;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+
+use crate::db::LoweringGroup;
+use crate::ids::{FunctionId, FunctionLongId, FunctionWithBodyId};
+
+pub const CALL_CONTRACT_SYSCALL: &str = "core::starknet::syscalls::call_contract_syscall";
+pub const STORAGE_WRITE_SYSCALL: &str = "core::starknet::syscalls::storage_write_syscall";
+pub const GET_CALLER_ADDRESS: &str = "core::starknet::info::get_caller_address";
+
+/// The effects a function may have, directly or through its callees.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Effects {
+    /// Calls another contract.
+    pub external_call: bool,
+    /// Writes to the storage of the contract.
+    pub storage_write: bool,
+    /// Reads the address of the caller.
+    pub caller_read: bool,
+}
+impl Effects {
+    fn union(self, other: Self) -> Self {
+        Self {
+            external_call: self.external_call || other.external_call,
+            storage_write: self.storage_write || other.storage_write,
+            caller_read: self.caller_read || other.caller_read,
+        }
+    }
+}
+
+/// Returns the full path of the given function if it is an extern function.
+pub fn extern_function_path(db: &dyn LoweringGroup, function: FunctionId) -> Option<String> {
+    let FunctionLongId::Semantic(function) = function.lookup(db) else {
+        return None;
+    };
+    let extern_function_id = function.try_get_extern_function_id(db.upcast())?;
+    Some(extern_function_id.full_path(db.upcast()))
+}
+
+/// Computes the effects of functions by traversing the call graph, caching the effects of every
+/// visited function.
+pub struct EffectsComputer<'a> {
+    db: &'a dyn LoweringGroup,
+    cache: UnorderedHashMap<FunctionWithBodyId, Effects>,
+    /// The functions whose effects are being computed. A call to such a function closes a cycle,
+    /// and contributes no effects, so the effects of recursive functions may be incomplete.
+    in_progress: UnorderedHashSet<FunctionWithBodyId>,
+}
+impl<'a> EffectsComputer<'a> {
+    pub fn new(db: &'a dyn LoweringGroup) -> Self {
+        Self { db, cache: Default::default(), in_progress: Default::default() }
+    }
+
+    /// Returns the effects of calling the given function.
+    pub fn function_effects(&mut self, function: FunctionId) -> Effects {
+        let db = self.db;
+        if let Some(path) = extern_function_path(db, function) {
+            return Effects {
+                external_call: path == CALL_CONTRACT_SYSCALL,
+                storage_write: path == STORAGE_WRITE_SYSCALL,
+                caller_read: false,
+            };
+        }
+        if let FunctionLongId::Semantic(semantic_function) = function.lookup(db) {
+            if let GenericFunctionId::Free(free_function_id) =
+                semantic_function.get_concrete(db.upcast()).generic_function
+            {
+                if free_function_id.full_path(db.upcast()) == GET_CALLER_ADDRESS {
+                    return Effects { caller_read: true, ..Effects::default() };
+                }
+            }
+        }
+        match function.body(db) {
+            Ok(Some(body)) => self.body_effects(body.function_with_body_id(db)),
+            _ => Effects::default(),
+        }
+    }
+
+    /// Returns the effects of running the body of the given function.
+    pub fn body_effects(&mut self, function_id: FunctionWithBodyId) -> Effects {
+        if let Some(effects) = self.cache.get(&function_id) {
+            return *effects;
+        }
+        if !self.in_progress.insert(function_id) {
+            return Effects::default();
+        }
+        let mut effects = Effects::default();
+        for callee in self.db.function_with_body_direct_callees(function_id).unwrap_or_default() {
+            effects = effects.union(self.function_effects(callee));
+        }
+        self.in_progress.remove(&function_id);
+        self.cache.insert(function_id, effects);
+        effects
+    }
+}
@@ -0,0 +1,361 @@
+//! Security lints for Starknet contracts, checked on the lowered representation of functions.
+//!
+//! The lints are opt-in: they are only checked in crates listing them as warnings or errors in
+//! their lint settings. A lint can be suppressed for a single function with `#[allow(<lint>)]`.
+
+use cairo_lang_defs::ids::LanguageElementId;
+use cairo_lang_diagnostics::Diagnostics;
+use cairo_lang_semantic::ConcreteVariant;
+use cairo_lang_syntax::attribute::structured::Attribute;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+
+use self::dataflow::{ForwardAnalysis, ForwardAnalyzer, Lattice};
+use self::effects::{extern_function_path, EffectsComputer, CALL_CONTRACT_SYSCALL};
+use crate::borrow_check::analysis::StatementLocation;
+use crate::db::LoweringGroup;
+use crate::diagnostic::{LoweringDiagnostic, LoweringDiagnosticKind, LoweringDiagnostics};
+use crate::ids::{FunctionId, FunctionWithBodyId, FunctionWithBodyLongId, LocationId};
+use crate::{
+    BlockId, FlatLowered, MatchArm, MatchInfo, Statement, VarRemapping, VarUsage, VariableId,
+};
+
+pub mod dataflow;
+pub mod effects;
+
+/// Reports external calls whose result is dropped without being checked.
+pub const UNCHECKED_EXTERNAL_CALL: &str = "unchecked_external_call";
+/// Reports storage writes which may happen after an external call, allowing reentrancy.
+pub const STORAGE_WRITE_AFTER_EXTERNAL_CALL: &str = "storage_write_after_external_call";
+/// Reports storage writes of admin functions which may happen before the caller is read.
+pub const MISSING_CALLER_ASSERTION: &str = "missing_caller_assertion";
+
+/// The attribute marking a function as an admin function, which only privileged callers may call.
+pub const ADMIN_ATTR: &str = "admin";
+/// The attribute suppressing lints for a function.
+pub const ALLOW_ATTR: &str = "allow";
+
+/// Checks the security lints enabled for the given function, on its lowered representation.
+pub fn security_lints(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+    lowered: &FlatLowered,
+) -> Diagnostics<LoweringDiagnostic> {
+    let semantic_function_id = function_id.base_semantic_function(db);
+    let module_file_id = semantic_function_id.module_file_id(db.upcast());
+    let mut diagnostics = LoweringDiagnostics::new(module_file_id);
+    if lowered.blocks.has_root().is_err() {
+        return diagnostics.build();
+    }
+    let lints = db.crate_settings(module_file_id.0.owning_crate(db.upcast())).lints;
+    let attributes = db.function_with_body_attributes(semantic_function_id).unwrap_or_default();
+    let is_enabled =
+        |lint_id: &str| lints.is_enabled(lint_id) && !is_allowed(db, &attributes, lint_id);
+
+    if is_enabled(UNCHECKED_EXTERNAL_CALL) {
+        let analyzer = UncheckedCallsAnalyzer {
+            db,
+            calls: OrderedHashSet::default(),
+            checked: UnorderedHashSet::default(),
+        };
+        let mut analysis = ForwardAnalysis::new(lowered, analyzer);
+        analysis.run(CallResults::default());
+        for location in analysis.analyzer.calls.iter() {
+            if !analysis.analyzer.checked.contains(location) {
+                diagnostics.report_by_location(
+                    location.get(db),
+                    LoweringDiagnosticKind::UncheckedExternalCallResult,
+                );
+            }
+        }
+    }
+
+    let mut effects = EffectsComputer::new(db);
+    if is_enabled(STORAGE_WRITE_AFTER_EXTERNAL_CALL) {
+        let analyzer = StorageWritesAfterCallAnalyzer {
+            effects: &mut effects,
+            storage_writes: OrderedHashSet::default(),
+        };
+        let mut analysis = ForwardAnalysis::new(lowered, analyzer);
+        analysis.run(OrderedHashSet::default());
+        for location in analysis.analyzer.storage_writes.iter() {
+            diagnostics.report_by_location(
+                location.get(db),
+                LoweringDiagnosticKind::StorageWriteAfterExternalCall,
+            );
+        }
+    }
+
+    // Functions generated for loops are checked as part of the function containing them, as
+    // whether the caller was read depends on the flow leading to the loop.
+    let is_generated = matches!(
+        db.lookup_intern_lowering_function_with_body(function_id),
+        FunctionWithBodyLongId::Generated { .. }
+    );
+    let is_admin = attributes.iter().any(|attr| attr.id == ADMIN_ATTR);
+    if is_admin && !is_generated && is_enabled(MISSING_CALLER_ASSERTION) {
+        let analyzer = UncheckedCallerAnalyzer {
+            effects: &mut effects,
+            storage_writes: OrderedHashSet::default(),
+        };
+        let mut analysis = ForwardAnalysis::new(lowered, analyzer);
+        analysis.run(CallerRead(false));
+        for location in analysis.analyzer.storage_writes.iter() {
+            diagnostics.report_by_location(
+                location.get(db),
+                LoweringDiagnosticKind::MissingCallerAssertion,
+            );
+        }
+    }
+
+    diagnostics.build()
+}
+
+/// Returns whether the given lint is suppressed by an `#[allow(<lint>)]` attribute.
+fn is_allowed(db: &dyn LoweringGroup, attributes: &[Attribute], lint_id: &str) -> bool {
+    attributes.iter().any(|attr| {
+        attr.id == ALLOW_ATTR && attr.args.iter().any(|arg| arg.text(db.upcast()) == lint_id)
+    })
+}
+
+/// The variables holding the results of external calls, along with the locations of the calls.
+#[derive(Clone, Default, PartialEq, Eq)]
+struct CallResults {
+    /// The variables introduced in the arms of an external call, along with the variants of the
+    /// arms.
+    arm_vars: OrderedHashSet<(VariableId, ConcreteVariant, LocationId)>,
+    /// The variables holding the result of an external call, or a value containing it.
+    results: OrderedHashSet<(VariableId, LocationId)>,
+}
+impl CallResults {
+    /// Returns the locations of the calls whose results are held by the given variable.
+    fn calls_of(&self, var_id: VariableId) -> Vec<LocationId> {
+        self.results.iter().filter(|(var, _)| *var == var_id).map(|(_, call)| *call).collect()
+    }
+}
+impl Lattice for CallResults {
+    fn join(&self, other: &Self) -> Self {
+        Self {
+            arm_vars: self.arm_vars.join(&other.arm_vars),
+            results: self.results.join(&other.results),
+        }
+    }
+}
+
+/// Tracks the results of external calls, which are checked once they are matched on, passed to a
+/// function or returned.
+struct UncheckedCallsAnalyzer<'a> {
+    db: &'a dyn LoweringGroup,
+    /// The locations of the external calls whose results are kept in a variable.
+    calls: OrderedHashSet<LocationId>,
+    /// The locations of the external calls whose results are checked.
+    checked: UnorderedHashSet<LocationId>,
+}
+impl UncheckedCallsAnalyzer<'_> {
+    fn check(&mut self, info: &CallResults, vars: impl IntoIterator<Item = VariableId>) {
+        for var_id in vars {
+            self.checked.extend(info.calls_of(var_id));
+        }
+    }
+}
+impl ForwardAnalyzer for UncheckedCallsAnalyzer<'_> {
+    type Info = CallResults;
+
+    fn visit_stmt(
+        &mut self,
+        info: &mut Self::Info,
+        _statement_location: StatementLocation,
+        stmt: &Statement,
+    ) {
+        if let Statement::Call(stmt) = stmt {
+            self.check(info, stmt.inputs.iter().map(|input| input.var_id));
+            return;
+        }
+        let mut calls = vec![];
+        for input in stmt.inputs() {
+            calls.extend(info.calls_of(input.var_id));
+        }
+        if let Statement::EnumConstruct(stmt) = stmt {
+            // An external call whose result is kept in a variable is followed by the construction
+            // of the result from the outputs of each of its arms.
+            let arm_calls = info
+                .arm_vars
+                .iter()
+                .filter(|(var, variant, _)| *var == stmt.input.var_id && *variant == stmt.variant);
+            calls.extend(arm_calls.map(|(_, _, call)| *call));
+        }
+        self.calls.extend(calls.iter().copied());
+        for output in stmt.outputs() {
+            info.results.extend(calls.iter().map(|call| (output, *call)));
+        }
+    }
+
+    fn visit_goto(
+        &mut self,
+        info: &mut Self::Info,
+        _statement_location: StatementLocation,
+        _target_block_id: BlockId,
+        remapping: &VarRemapping,
+    ) {
+        for (dst, src) in remapping.iter() {
+            let calls = info.calls_of(src.var_id);
+            info.results.extend(calls.into_iter().map(|call| (*dst, call)));
+        }
+    }
+
+    fn visit_match(
+        &mut self,
+        info: &mut Self::Info,
+        _statement_location: StatementLocation,
+        match_info: &MatchInfo,
+    ) {
+        self.check(info, match_info.inputs().into_iter().map(|input| input.var_id));
+    }
+
+    fn visit_match_arm(&mut self, info: &mut Self::Info, match_info: &MatchInfo, arm: &MatchArm) {
+        let MatchInfo::Extern(match_info) = match_info else { return };
+        if extern_function_path(self.db, match_info.function).as_deref()
+            == Some(CALL_CONTRACT_SYSCALL)
+        {
+            info.arm_vars.extend(
+                arm.var_ids
+                    .iter()
+                    .map(|var_id| (*var_id, arm.variant_id.clone(), match_info.location)),
+            );
+        }
+    }
+
+    fn visit_return(
+        &mut self,
+        info: &Self::Info,
+        _statement_location: StatementLocation,
+        vars: &[VarUsage],
+    ) {
+        self.check(info, vars.iter().map(|var| var.var_id));
+    }
+
+    fn visit_panic(
+        &mut self,
+        info: &Self::Info,
+        _statement_location: StatementLocation,
+        var: &VariableId,
+    ) {
+        self.check(info, [*var]);
+    }
+}
+
+/// Returns the called function and the location of the call, if the statement is a call.
+fn call_of_stmt(stmt: &Statement) -> Option<(FunctionId, LocationId)> {
+    match stmt {
+        Statement::Call(stmt) => Some((stmt.function, stmt.location)),
+        _ => None,
+    }
+}
+
+/// Returns the called function and the location of the call, if the match is a call of an extern
+/// function.
+fn call_of_match(match_info: &MatchInfo) -> Option<(FunctionId, LocationId)> {
+    match match_info {
+        MatchInfo::Extern(match_info) => Some((match_info.function, match_info.location)),
+        MatchInfo::Enum(_) => None,
+    }
+}
+
+/// Finds the storage writes which may happen after an external call. The info is the set of
+/// locations of the external calls which may have happened.
+struct StorageWritesAfterCallAnalyzer<'a, 'b> {
+    effects: &'b mut EffectsComputer<'a>,
+    storage_writes: OrderedHashSet<LocationId>,
+}
+impl StorageWritesAfterCallAnalyzer<'_, '_> {
+    fn visit_call(
+        &mut self,
+        info: &mut OrderedHashSet<LocationId>,
+        call: (FunctionId, LocationId),
+    ) {
+        let (function, location) = call;
+        let effects = self.effects.function_effects(function);
+        if effects.storage_write && !info.is_empty() {
+            self.storage_writes.insert(location);
+        }
+        if effects.external_call {
+            info.insert(location);
+        }
+    }
+}
+impl ForwardAnalyzer for StorageWritesAfterCallAnalyzer<'_, '_> {
+    type Info = OrderedHashSet<LocationId>;
+
+    fn visit_stmt(
+        &mut self,
+        info: &mut Self::Info,
+        _statement_location: StatementLocation,
+        stmt: &Statement,
+    ) {
+        if let Some(call) = call_of_stmt(stmt) {
+            self.visit_call(info, call);
+        }
+    }
+
+    fn visit_match(
+        &mut self,
+        info: &mut Self::Info,
+        _statement_location: StatementLocation,
+        match_info: &MatchInfo,
+    ) {
+        if let Some(call) = call_of_match(match_info) {
+            self.visit_call(info, call);
+        }
+    }
+}
+
+/// Whether the address of the caller was read in every flow reaching a point of the program.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct CallerRead(bool);
+impl Lattice for CallerRead {
+    fn join(&self, other: &Self) -> Self {
+        CallerRead(self.0 && other.0)
+    }
+}
+
+/// Finds the storage writes which may happen before the address of the caller is read.
+struct UncheckedCallerAnalyzer<'a, 'b> {
+    effects: &'b mut EffectsComputer<'a>,
+    storage_writes: OrderedHashSet<LocationId>,
+}
+impl UncheckedCallerAnalyzer<'_, '_> {
+    fn visit_call(&mut self, info: &mut CallerRead, call: (FunctionId, LocationId)) {
+        let (function, location) = call;
+        let effects = self.effects.function_effects(function);
+        if effects.caller_read {
+            info.0 = true;
+        } else if effects.storage_write && !info.0 {
+            self.storage_writes.insert(location);
+        }
+    }
+}
+impl ForwardAnalyzer for UncheckedCallerAnalyzer<'_, '_> {
+    type Info = CallerRead;
+
+    fn visit_stmt(
+        &mut self,
+        info: &mut Self::Info,
+        _statement_location: StatementLocation,
+        stmt: &Statement,
+    ) {
+        if let Some(call) = call_of_stmt(stmt) {
+            self.visit_call(info, call);
+        }
+    }
+
+    fn visit_match(
+        &mut self,
+        info: &mut Self::Info,
+        _statement_location: StatementLocation,
+        match_info: &MatchInfo,
+    ) {
+        if let Some(call) = call_of_match(match_info) {
+            self.visit_call(info, call);
+        }
+    }
+}
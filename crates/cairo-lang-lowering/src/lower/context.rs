@@ -367,7 +367,12 @@ impl LoweredExprExternEnum {
             function: self.function.lowered(ctx.db),
             inputs: self.inputs,
             arms: zip_eq(zip_eq(concrete_variants, block_ids), arm_var_ids)
-                .map(|((variant_id, block_id), var_ids)| MatchArm { variant_id, block_id, var_ids })
+                .map(|((variant_id, block_id), var_ids)| MatchArm {
+                    variant_id,
+                    block_id,
+                    var_ids,
+                    hint: None,
+                })
                 .collect(),
             location: self.location,
         });
@@ -83,11 +83,13 @@ pub fn lower_logical_op(
                 variant_id: corelib::false_variant(semantic_db),
                 block_id: lhs_false_block_id,
                 var_ids: vec![ctx.new_var(VarRequest { ty: unit_ty, location })],
+                hint: None,
             },
             MatchArm {
                 variant_id: corelib::true_variant(semantic_db),
                 block_id: lhs_true_block_id,
                 var_ids: vec![ctx.new_var(VarRequest { ty: unit_ty, location })],
+                hint: None,
             },
         ],
         location,
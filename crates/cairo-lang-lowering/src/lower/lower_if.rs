@@ -111,11 +111,13 @@ pub fn lower_expr_if_bool(
                 variant_id: corelib::false_variant(semantic_db),
                 block_id: block_else_id,
                 var_ids: vec![else_block_input_var_id],
+                hint: None,
             },
             MatchArm {
                 variant_id: corelib::true_variant(semantic_db),
                 block_id: block_main_id,
                 var_ids: vec![main_block_var_id],
+                hint: None,
             },
         ],
         location: if_location,
@@ -185,11 +187,13 @@ pub fn lower_expr_if_eq(
                 variant_id: corelib::jump_nz_zero_variant(semantic_db),
                 block_id: block_main_id,
                 var_ids: vec![],
+                hint: None,
             },
             MatchArm {
                 variant_id: corelib::jump_nz_nonzero_variant(semantic_db),
                 block_id: block_else_id,
                 var_ids: vec![else_block_input_var_id],
+                hint: None,
             },
         ],
         location: if_location,
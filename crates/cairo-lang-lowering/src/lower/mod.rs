@@ -888,8 +888,13 @@ fn lower_expr_match(
     let match_info = MatchInfo::Enum(MatchEnumInfo {
         concrete_enum_id,
         input: VarUsage { var_id: expr_var, location },
-        arms: zip_eq(zip_eq(concrete_variants, block_ids), arm_var_ids.into_iter())
-            .map(|((variant_id, block_id), var_ids)| MatchArm { variant_id, block_id, var_ids })
+        arms: zip_eq(zip_eq(concrete_variants, block_ids), zip_eq(arm_var_ids, &expr.arms))
+            .map(|((variant_id, block_id), (var_ids, arm))| MatchArm {
+                variant_id,
+                block_id,
+                var_ids,
+                hint: arm.hint,
+            })
             .collect(),
         location,
     });
@@ -970,8 +975,13 @@ fn lower_optimized_extern_match(
     let match_info = MatchInfo::Extern(MatchExternInfo {
         function: extern_enum.function.lowered(ctx.db),
         inputs: extern_enum.inputs,
-        arms: zip_eq(zip_eq(concrete_variants, block_ids), arm_var_ids.into_iter())
-            .map(|((variant_id, block_id), var_ids)| MatchArm { variant_id, block_id, var_ids })
+        arms: zip_eq(zip_eq(concrete_variants, block_ids), zip_eq(arm_var_ids, match_arms))
+            .map(|((variant_id, block_id), (var_ids, arm))| MatchArm {
+                variant_id,
+                block_id,
+                var_ids,
+                hint: arm.hint,
+            })
             .collect(),
         location,
     });
@@ -989,18 +999,20 @@ fn lower_expr_match_felt252(
     log::trace!("Lowering a match-felt252 expression.");
     let location = ctx.get_location(expr.stable_ptr.untyped());
     // Check that the match has the expected form.
-    let (literal, block0, block_otherwise) = if let [
+    let (literal, block0, hint0, block_otherwise, hint_otherwise) = if let [
         semantic::MatchArm {
             pattern: semantic::Pattern::Literal(semantic::PatternLiteral { literal, .. }),
             expression: block0,
+            hint: hint0,
         },
         semantic::MatchArm {
             pattern: semantic::Pattern::Otherwise(_),
             expression: block_otherwise,
+            hint: hint_otherwise,
         },
     ] = &expr.arms[..]
     {
-        (literal, block0, block_otherwise)
+        (literal, block0, hint0, block_otherwise, hint_otherwise)
     } else {
         return Err(LoweringFlowError::Failed(
             ctx.diagnostics.report(expr.stable_ptr.untyped(), OnlyMatchZeroIsSupported),
@@ -1040,11 +1052,13 @@ fn lower_expr_match_felt252(
                 variant_id: jump_nz_zero_variant(semantic_db),
                 block_id: zero_block_id,
                 var_ids: vec![],
+                hint: *hint0,
             },
             MatchArm {
                 variant_id: jump_nz_nonzero_variant(semantic_db),
                 block_id: nonzero_block_id,
                 var_ids: vec![var_nz],
+                hint: *hint_otherwise,
             },
         ],
         location,
@@ -1266,11 +1280,13 @@ fn lower_expr_error_propagate(
                 variant_id: ok_variant.clone(),
                 block_id: block_ok_id,
                 var_ids: vec![expr_var],
+                hint: None,
             },
             MatchArm {
                 variant_id: err_variant.clone(),
                 block_id: block_err_id,
                 var_ids: vec![err_value],
+                hint: None,
             },
         ],
         location,
@@ -1332,11 +1348,13 @@ fn lower_optimized_extern_error_propagate(
                 variant_id: ok_variant.clone(),
                 block_id: block_ok_id,
                 var_ids: block_ok_input_vars,
+                hint: None,
             },
             MatchArm {
                 variant_id: err_variant.clone(),
                 block_id: block_err_id,
                 var_ids: block_err_input_vars,
+                hint: None,
             },
         ],
         location,
@@ -8,7 +8,7 @@ use std::ops::{Deref, DerefMut};
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Diagnostics;
 use cairo_lang_semantic as semantic;
-use cairo_lang_semantic::{ConcreteEnumId, ConcreteVariant};
+use cairo_lang_semantic::{BranchHint, ConcreteEnumId, ConcreteVariant};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use id_arena::{Arena, Id};
 use num_bigint::BigInt;
@@ -285,6 +285,9 @@ pub struct MatchArm {
 
     /// The list of variable ids introduced in this arm.
     pub var_ids: Vec<VariableId>,
+
+    /// The branch hint given to the arm in the source code, if any.
+    pub hint: Option<BranchHint>,
 }
 
 /// A statement that calls an extern function with branches, and "calls" a possibly different block
@@ -322,6 +325,12 @@ pub enum MatchInfo {
     Extern(MatchExternInfo),
 }
 impl MatchInfo {
+    pub fn location(&self) -> &LocationId {
+        match self {
+            MatchInfo::Enum(s) => &s.location,
+            MatchInfo::Extern(s) => &s.location,
+        }
+    }
     pub fn inputs(&self) -> Vec<VarUsage> {
         match self {
             MatchInfo::Enum(s) => vec![s.input],
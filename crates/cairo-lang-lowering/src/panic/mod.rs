@@ -1,6 +1,7 @@
 use std::collections::VecDeque;
 
-use cairo_lang_diagnostics::Maybe;
+use cairo_lang_defs::ids::LanguageElementId;
+use cairo_lang_diagnostics::{Diagnostics, Maybe};
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::corelib::{get_core_enum_concrete_variant, get_panic_ty};
 use cairo_lang_semantic::GenericArgumentId;
@@ -10,8 +11,11 @@ use semantic::{ConcreteVariant, TypeId};
 
 use crate::blocks::FlatBlocksBuilder;
 use crate::db::{ConcreteSCCRepresentative, LoweringGroup};
+use crate::diagnostic::{LoweringDiagnostic, LoweringDiagnosticKind, LoweringDiagnostics};
 use crate::graph_algorithms::strongly_connected_components::concrete_function_with_body_scc;
-use crate::ids::{ConcreteFunctionWithBodyId, FunctionId, Signature};
+use crate::ids::{
+    ConcreteFunctionWithBodyId, FunctionId, FunctionWithBodyId, FunctionWithBodyLongId, Signature,
+};
 use crate::lower::context::{VarRequest, VariableAllocator};
 use crate::{
     BlockId, FlatBlock, FlatBlockEnd, FlatLowered, MatchArm, MatchEnumInfo, MatchInfo, Statement,
@@ -252,11 +256,13 @@ impl<'a> PanicBlockLoweringContext<'a> {
                         variant_id: callee_info.ok_variant,
                         block_id: block_ok,
                         var_ids: vec![inner_ok_value],
+                        hint: None,
                     },
                     MatchArm {
                         variant_id: callee_info.err_variant,
                         block_id: block_err,
                         var_ids: vec![err_var],
+                        hint: None,
                     },
                 ],
                 location,
@@ -313,6 +319,57 @@ impl<'a> PanicBlockLoweringContext<'a> {
     }
 }
 
+/// The attribute marking a function which must not panic.
+pub const NO_PANIC_ATTR: &str = "no_panic";
+
+/// Reports the operations which may panic in a function marked with `#[no_panic]` - explicit
+/// panics, and calls to functions which may (transitively) panic, such as overflow-checked
+/// arithmetic, `unwrap` and array indexing.
+///
+/// Unlike a `nopanic` signature, which requires all the callees to be declared `nopanic` as well,
+/// the check uses the lowered bodies of the callees, so calling a function which cannot panic in
+/// practice is allowed. Loops and recursive calls are reported, as they may run out of gas.
+pub fn no_panic_diagnostics(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+    lowered: &FlatLowered,
+) -> Diagnostics<LoweringDiagnostic> {
+    let semantic_function_id = function_id.base_semantic_function(db);
+    let mut diagnostics =
+        LoweringDiagnostics::new(semantic_function_id.module_file_id(db.upcast()));
+    // Functions generated for loops are covered by the calls to them from the marked function.
+    let is_generated = matches!(
+        db.lookup_intern_lowering_function_with_body(function_id),
+        FunctionWithBodyLongId::Generated { .. }
+    );
+    let attributes = db.function_with_body_attributes(semantic_function_id).unwrap_or_default();
+    if is_generated
+        || !attributes.iter().any(|attr| attr.id == NO_PANIC_ATTR)
+        || lowered.blocks.has_root().is_err()
+    {
+        return diagnostics.build();
+    }
+    for (_, block) in lowered.blocks.iter() {
+        for statement in &block.statements {
+            if let Statement::Call(call) = statement {
+                if db.function_may_panic(call.function).unwrap_or_default() {
+                    diagnostics.report_by_location(
+                        call.location.get(db),
+                        LoweringDiagnosticKind::PanicInNoPanicFunction,
+                    );
+                }
+            }
+        }
+        if let FlatBlockEnd::Panic(err_data) = &block.end {
+            diagnostics.report_by_location(
+                lowered.variables[*err_data].location.get(db),
+                LoweringDiagnosticKind::PanicInNoPanicFunction,
+            );
+        }
+    }
+    diagnostics.build()
+}
+
 // ============= Query implementations =============
 
 /// Query implementation of [crate::db::LoweringGroup::function_may_panic].
@@ -14,6 +14,8 @@ use crate::{
 /// Removes unreachable blocks.
 /// Blocks that are reachable only through goto are combined with the block that does the goto.
 /// The order of the blocks is changed to be a topologically sorted.
+/// The likely arm of a match is placed right before the blocks the arms merge into, so that its
+/// flow falls through to them, while the other arms jump to them.
 pub fn reorganize_blocks(lowered: &mut FlatLowered) {
     if !lowered.blocks.is_empty() {
         let ctx = TopSortContext {
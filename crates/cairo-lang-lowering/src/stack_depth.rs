@@ -0,0 +1,184 @@
+//! Static analysis of the call depth of entry points over the lowered call graph, and of the
+//! recursion they may run into.
+//!
+//! Recursion is only bounded at runtime by the gas withdrawn in each cycle of the call graph. When
+//! no gas is withdrawn - since withdrawing gas is disabled, or since a function of the cycle may
+//! not panic when running out of gas - the recursion is unbounded, and may only fail by exhausting
+//! the resources of the runner.
+
+use std::fmt;
+
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+
+use crate::call_graph::{crate_entry_points, function_name};
+use crate::db::LoweringGroup;
+use crate::ids::ConcreteFunctionWithBodyId;
+
+#[cfg(test)]
+#[path = "stack_depth_test.rs"]
+mod test;
+
+/// A cycle of the call graph, reachable from an entry point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recursion {
+    /// The full paths of the functions of the cycle, sorted.
+    pub functions: Vec<String>,
+    /// Whether gas is withdrawn in the cycle, bounding the depth of the recursion.
+    pub gas_bounded: bool,
+}
+
+/// The call depth analysis of a single entry point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryPointStackDepth {
+    /// The full path of the entry point.
+    pub name: String,
+    /// The maximal number of nested calls of functions with a body, including the entry point
+    /// itself, or `None` if the entry point may recurse.
+    /// Inlining may only decrease the actual depth.
+    pub max_call_depth: Option<usize>,
+    /// The cycles of the call graph reachable from the entry point.
+    pub recursions: Vec<Recursion>,
+}
+impl EntryPointStackDepth {
+    /// Returns whether the entry point may run into a recursion which is not bounded by gas.
+    pub fn has_unbounded_recursion(&self) -> bool {
+        self.recursions.iter().any(|recursion| !recursion.gas_bounded)
+    }
+}
+
+/// The call depth analysis of a set of entry points.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StackDepthReport {
+    pub entry_points: Vec<EntryPointStackDepth>,
+}
+impl StackDepthReport {
+    /// Analyzes the call depth of the given entry points.
+    pub fn build(db: &dyn LoweringGroup, entry_points: &[ConcreteFunctionWithBodyId]) -> Self {
+        let mut analyzer = StackDepthAnalyzer::new(db);
+        let entry_points = entry_points
+            .iter()
+            .map(|entry_point| EntryPointStackDepth {
+                name: function_name(db, entry_point.function_with_body_id(db)),
+                max_call_depth: analyzer.max_call_depth(*entry_point),
+                recursions: analyzer.recursions(*entry_point),
+            })
+            .collect();
+        Self { entry_points }
+    }
+
+    /// Analyzes the call depth of the functions compiled for the given crates - the free functions
+    /// without generic parameters.
+    pub fn build_for_crates(db: &dyn LoweringGroup, crate_ids: &[CrateId]) -> Self {
+        Self::build(db, &crate_entry_points(db, crate_ids))
+    }
+
+    /// Returns whether any of the entry points may run into a recursion which is not bounded by
+    /// gas.
+    pub fn has_unbounded_recursion(&self) -> bool {
+        self.entry_points.iter().any(EntryPointStackDepth::has_unbounded_recursion)
+    }
+}
+impl fmt::Display for StackDepthReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry_point in &self.entry_points {
+            match entry_point.max_call_depth {
+                Some(depth) => writeln!(f, "{}: max call depth {depth}", entry_point.name)?,
+                None => writeln!(f, "{}: unbounded call depth", entry_point.name)?,
+            }
+            for recursion in &entry_point.recursions {
+                let bound = if recursion.gas_bounded { "bounded" } else { "not bounded" };
+                writeln!(
+                    f,
+                    "  recursion through {}, {bound} by gas",
+                    recursion.functions.join(", ")
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Helper for analyzing the call depth of entry points, caching the results of the functions
+/// shared between them.
+struct StackDepthAnalyzer<'a> {
+    db: &'a dyn LoweringGroup,
+    /// The maximal call depths of the visited functions.
+    depths: UnorderedHashMap<ConcreteFunctionWithBodyId, Option<usize>>,
+}
+impl<'a> StackDepthAnalyzer<'a> {
+    fn new(db: &'a dyn LoweringGroup) -> Self {
+        Self { db, depths: UnorderedHashMap::default() }
+    }
+
+    /// Returns the direct callees of a function which have a body.
+    fn callees(&self, function: ConcreteFunctionWithBodyId) -> Vec<ConcreteFunctionWithBodyId> {
+        self.db.concrete_function_with_body_direct_callees_with_body(function).unwrap_or_default()
+    }
+
+    /// Returns whether the function is in a cycle of the call graph.
+    fn is_recursive(&self, function: ConcreteFunctionWithBodyId) -> bool {
+        self.db.concrete_function_with_body_scc(function).len() > 1
+            || self.callees(function).contains(&function)
+    }
+
+    /// Returns the maximal call depth of the function, or `None` if it may recurse.
+    fn max_call_depth(&mut self, function: ConcreteFunctionWithBodyId) -> Option<usize> {
+        if let Some(depth) = self.depths.get(&function) {
+            return *depth;
+        }
+        // The callees of a function outside of any cycle may not call it back, so this terminates.
+        let depth = if self.is_recursive(function) {
+            None
+        } else {
+            let mut max_callee_depth = 0;
+            for callee in self.callees(function) {
+                max_callee_depth = max_callee_depth.max(self.max_call_depth(callee)?);
+            }
+            Some(max_callee_depth + 1)
+        };
+        self.depths.insert(function, depth);
+        depth
+    }
+
+    /// Returns the cycles of the call graph reachable from the function.
+    fn recursions(&self, function: ConcreteFunctionWithBodyId) -> Vec<Recursion> {
+        let db = self.db;
+        let mut representatives = OrderedHashSet::<ConcreteFunctionWithBodyId>::default();
+        let mut visited = UnorderedHashSet::<ConcreteFunctionWithBodyId>::default();
+        let mut stack = vec![function];
+        while let Some(function) = stack.pop() {
+            if !visited.insert(function) {
+                continue;
+            }
+            if self.is_recursive(function) {
+                representatives
+                    .insert(db.concrete_function_with_body_scc_representative(function).0);
+            }
+            stack.extend(self.callees(function));
+        }
+        representatives
+            .iter()
+            .map(|representative| {
+                let scc = db.concrete_function_with_body_scc(*representative);
+                let mut functions = scc
+                    .iter()
+                    .map(|function| function_name(db, function.function_with_body_id(db)))
+                    .collect::<Vec<_>>();
+                functions.sort();
+                Recursion { functions, gas_bounded: is_gas_bounded(db, &scc) }
+            })
+            .collect()
+    }
+}
+
+/// Returns whether gas is withdrawn in the given cycle of the call graph. Withdrawing gas may
+/// panic, so it is not possible in functions which may not panic.
+fn is_gas_bounded(db: &dyn LoweringGroup, scc: &[ConcreteFunctionWithBodyId]) -> bool {
+    scc.iter().any(|function| db.needs_withdraw_gas(*function) == Ok(true))
+        && scc.iter().all(|function| {
+            function.signature(db).map(|signature| signature.panicable).unwrap_or_default()
+        })
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::FlagId;
+use cairo_lang_semantic::test_utils::setup_test_module;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::StackDepthReport;
+use crate::test_utils::LoweringDatabaseForTesting;
+
+#[test]
+fn test_stack_depth() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_module = setup_test_module(
+        db,
+        indoc! {"
+            fn main() -> felt252 {
+                helper(1)
+            }
+
+            fn helper(x: felt252) -> felt252 {
+                x + leaf()
+            }
+
+            fn leaf() -> felt252 {
+                2
+            }
+        "},
+    )
+    .unwrap();
+    let report = StackDepthReport::build_for_crates(db, &[test_module.crate_id]);
+
+    assert_eq!(
+        report.to_string(),
+        indoc! {"
+            test::main: max call depth 3
+            test::helper: max call depth 2
+            test::leaf: max call depth 1
+        "}
+    );
+    assert!(!report.has_unbounded_recursion());
+}
+
+#[test]
+fn test_stack_depth_recursion() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_module = setup_test_module(
+        db,
+        indoc! {"
+            fn main(n: felt252) -> felt252 {
+                ping(n)
+            }
+
+            fn ping(n: felt252) -> felt252 {
+                if n == 0 { 0 } else { pong(n - 1) }
+            }
+
+            fn pong(n: felt252) -> felt252 {
+                ping(n)
+            }
+
+            fn spin() nopanic {
+                spin()
+            }
+        "},
+    )
+    .unwrap();
+    let report = StackDepthReport::build_for_crates(db, &[test_module.crate_id]);
+
+    assert_eq!(
+        report.to_string(),
+        indoc! {"
+            test::main: unbounded call depth
+              recursion through test::ping, test::pong, bounded by gas
+            test::ping: unbounded call depth
+              recursion through test::ping, test::pong, bounded by gas
+            test::pong: unbounded call depth
+              recursion through test::ping, test::pong, bounded by gas
+            test::spin: unbounded call depth
+              recursion through test::spin, not bounded by gas
+        "}
+    );
+    assert!(report.has_unbounded_recursion());
+}
+
+#[test]
+fn test_stack_depth_recursion_without_withdraw_gas() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let add_withdraw_gas_flag_id = FlagId::new(db, "add_withdraw_gas");
+    db.set_flag(add_withdraw_gas_flag_id, Some(Arc::new(Flag::AddWithdrawGas(false))));
+    let test_module = setup_test_module(
+        db,
+        indoc! {"
+            fn count(n: felt252) -> felt252 {
+                if n == 0 { 0 } else { count(n - 1) + 1 }
+            }
+        "},
+    )
+    .unwrap();
+    let report = StackDepthReport::build_for_crates(db, &[test_module.crate_id]);
+
+    assert_eq!(
+        report.to_string(),
+        indoc! {"
+            test::count: unbounded call depth
+              recursion through test::count, not bounded by gas
+        "}
+    );
+    assert!(report.has_unbounded_recursion());
+}
@@ -3,9 +3,11 @@ use std::ops::Deref;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::LanguageElementId;
 use cairo_lang_diagnostics::DiagnosticsBuilder;
+use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_filesystem::settings::{CrateSettings, LintSettings};
 use cairo_lang_plugins::get_default_plugins;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::test_utils::{setup_test_expr, setup_test_function};
+use cairo_lang_semantic::test_utils::{setup_test_expr, setup_test_function, setup_test_module};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::add_withdraw_gas::add_withdraw_gas;
@@ -62,6 +64,24 @@ cairo_lang_test_utils::test_file_test!(
     test_function_lowering_phases
 );
 
+cairo_lang_test_utils::test_file_test!(
+    security_lints,
+    "src/test_data",
+    {
+        security_lints :"security_lints",
+    },
+    test_security_lints
+);
+
+cairo_lang_test_utils::test_file_test!(
+    no_panic,
+    "src/test_data",
+    {
+        no_panic :"no_panic",
+    },
+    test_no_panic
+);
+
 fn test_function_lowering(
     inputs: &OrderedHashMap<String, String>,
 ) -> OrderedHashMap<String, String> {
@@ -95,6 +115,40 @@ fn test_function_lowering(
     ])
 }
 
+/// Tests the security lints of a module, with the given lints enabled as errors.
+fn test_security_lints(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (test_module, semantic_diagnostics) =
+        setup_test_module(db, inputs["module_code"].as_str()).split();
+    let lints = LintSettings {
+        deny: inputs["enabled_lints"].lines().map(|lint| lint.into()).collect(),
+        ..Default::default()
+    };
+    db.set_crate_settings(
+        test_module.crate_id,
+        Some(CrateSettings { lints, ..Default::default() }),
+    );
+    let diagnostics = db.module_lowering_diagnostics(test_module.module_id).unwrap_or_default();
+
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("lowering_diagnostics".into(), diagnostics.format(db)),
+    ])
+}
+
+/// Tests the `#[no_panic]` checks of a module.
+fn test_no_panic(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (test_module, semantic_diagnostics) =
+        setup_test_module(db, inputs["module_code"].as_str()).split();
+    let diagnostics = db.module_lowering_diagnostics(test_module.module_id).unwrap_or_default();
+
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("lowering_diagnostics".into(), diagnostics.format(db)),
+    ])
+}
+
 /// Tests all the lowering phases of a function (tracking logic in
 /// `concrete_function_with_body_lowered`).
 /// Can be used to debug cases where the transition of a specific lowering phase fails.
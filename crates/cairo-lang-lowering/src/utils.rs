@@ -103,6 +103,7 @@ pub trait RebuilderEx: Rebuilder {
                                     .iter()
                                     .map(|var_id| self.map_var_id(*var_id))
                                     .collect(),
+                                hint: arm.hint,
                             })
                             .collect(),
                         location: stmt.location,
@@ -121,6 +122,7 @@ pub trait RebuilderEx: Rebuilder {
                                     .iter()
                                     .map(|var_id| self.map_var_id(*var_id))
                                     .collect(),
+                                hint: arm.hint,
                             })
                             .collect(),
                         location: stmt.location,
@@ -1286,10 +1286,14 @@ impl<'a> Parser<'a> {
 
     /// Returns a GreenId of a node with a MatchArm kind or None if a match arm can't be parsed.
     pub fn try_parse_match_arm(&mut self) -> Option<MatchArmGreen> {
-        let pattern = self.try_parse_pattern()?;
+        let attributes =
+            self.try_parse_attribute_list("Match arm", |x| x != SyntaxKind::TerminalHash);
+        let pattern =
+            if attributes.is_some() { self.parse_pattern() } else { self.try_parse_pattern()? };
+        let attributes = attributes.unwrap_or_else(|| AttributeList::new_green(self.db, vec![]));
         let arrow = self.parse_token::<TerminalMatchArrow>();
         let expr = self.parse_expr();
-        Some(MatchArm::new_green(self.db, pattern, arrow, expr))
+        Some(MatchArm::new_green(self.db, attributes, pattern, arrow, expr))
     }
 
     /// Returns a GreenId of a node with some Pattern kind (see
@@ -4,7 +4,8 @@ use std::sync::Arc;
 use cairo_lang_semantic::plugin::SemanticPlugin;
 
 use crate::plugins::{
-    ConfigPlugin, DerivePlugin, GenerateTraitPlugin, InlineMacroPlugin, PanicablePlugin,
+    ConfigPlugin, DerivePlugin, GenerateTraitPlugin, InlineMacroPlugin, OraclePlugin,
+    PanicablePlugin,
 };
 
 pub mod plugins;
@@ -18,6 +19,7 @@ pub fn get_default_plugins() -> Vec<Arc<dyn SemanticPlugin>> {
         Arc::new(DerivePlugin::default()),
         Arc::new(GenerateTraitPlugin::default()),
         Arc::new(PanicablePlugin::default()),
+        Arc::new(OraclePlugin::default()),
         Arc::new(ConfigPlugin::default()),
         Arc::new(InlineMacroPlugin::default()),
     ]
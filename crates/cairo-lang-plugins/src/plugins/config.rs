@@ -72,6 +72,7 @@ fn parse_predicate_item(
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: arg.arg_stable_ptr.untyped(),
                 message: "This attribute does not support field initialization shorthands.".into(),
+                lint_id: None,
             });
             None
         }
@@ -80,6 +81,7 @@ fn parse_predicate_item(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: value_stable_ptr.untyped(),
                     message: "Expected short string.".into(),
+                    lint_id: None,
                 });
                 return None;
             };
@@ -93,6 +95,7 @@ fn parse_predicate_item(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: value_stable_ptr.untyped(),
                     message: "Expected identifier.".into(),
+                    lint_id: None,
                 });
                 return None;
             };
@@ -100,6 +103,7 @@ fn parse_predicate_item(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: value_stable_ptr.untyped(),
                     message: "Expected simple path.".into(),
+                    lint_id: None,
                 });
                 return None;
             };
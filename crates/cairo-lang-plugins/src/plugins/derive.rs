@@ -3,18 +3,22 @@ use std::sync::Arc;
 use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
-use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
+use cairo_lang_filesystem::settings::Edition;
+use cairo_lang_semantic::items::imp::SYNTHESIZED_IMPL_ATTR;
+use cairo_lang_semantic::plugin::{
+    AsDynMacroPlugin, SemanticPlugin, SynthesizedImplsAuxData, TrivialPluginAuxData,
+};
 use cairo_lang_syntax::attribute::structured::{
     AttributeArg, AttributeArgVariant, AttributeStructurize,
 };
 use cairo_lang_syntax::node::ast::{
-    AttributeList, ItemStruct, MemberList, OptionWrappedGenericParamList,
+    AttributeList, ItemEnum, ItemStruct, MemberList, OptionWrappedGenericParamList,
 };
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use indoc::formatdoc;
-use itertools::Itertools;
+use itertools::{chain, Itertools};
 use smol_str::SmolStr;
 
 #[derive(Debug, Default)]
@@ -23,24 +27,38 @@ pub struct DerivePlugin;
 
 impl MacroPlugin for DerivePlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        self.generate_code_for_edition(db, item_ast, Edition::default())
+    }
+
+    fn generate_code_for_edition(
+        &self,
+        db: &dyn SyntaxGroup,
+        item_ast: ast::Item,
+        edition: Edition,
+    ) -> PluginResult {
+        // Copy and Drop impls are synthesized only for crates of editions opting into it.
+        let synthesize = edition >= Edition::V2023_10;
         match item_ast {
             ast::Item::Struct(struct_ast) => generate_derive_code_for_type(
                 db,
                 struct_ast.name(db),
                 struct_ast.attributes(db),
                 extract_struct_extra_info(db, &struct_ast),
+                if synthesize { Some(member_types(db, struct_ast.members(db))) } else { None },
             ),
             ast::Item::Enum(enum_ast) => generate_derive_code_for_type(
                 db,
                 enum_ast.name(db),
                 enum_ast.attributes(db),
                 ExtraInfo::Enum(member_names(db, enum_ast.variants(db))),
+                if synthesize { enum_variant_types(db, &enum_ast) } else { None },
             ),
             ast::Item::ExternType(extern_type_ast) => generate_derive_code_for_type(
                 db,
                 extern_type_ast.name(db),
                 extern_type_ast.attributes(db),
                 ExtraInfo::Extern,
+                None,
             ),
             _ => PluginResult::default(),
         }
@@ -66,6 +84,25 @@ fn member_names(db: &dyn SyntaxGroup, members: MemberList) -> Vec<SmolStr> {
     members.elements(db).into_iter().map(|member| member.name(db).text(db)).collect()
 }
 
+/// Returns the distinct types of the given members, as written.
+fn member_types(db: &dyn SyntaxGroup, members: MemberList) -> Vec<String> {
+    members
+        .elements(db)
+        .into_iter()
+        .map(|member| member.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db))
+        .unique()
+        .collect()
+}
+
+/// Returns the distinct types of the variants of the given enum, or None if the enum is generic, as
+/// impls for generic enums are not supported.
+fn enum_variant_types(db: &dyn SyntaxGroup, enum_ast: &ItemEnum) -> Option<Vec<String>> {
+    match enum_ast.generic_params(db) {
+        OptionWrappedGenericParamList::Empty(_) => Some(member_types(db, enum_ast.variants(db))),
+        OptionWrappedGenericParamList::WrappedGenericParamList(_) => None,
+    }
+}
+
 fn extract_struct_extra_info(db: &dyn SyntaxGroup, struct_ast: &ItemStruct) -> ExtraInfo {
     let members = member_names(db, struct_ast.members(db));
     let mut type_generics = vec![];
@@ -114,14 +151,18 @@ fn format_generics(type_generics: &[SmolStr], other_generics: &[String]) -> Stri
 }
 
 /// Adds an implementation for all requested derives for the type.
+/// If `synthesized_member_types` is given, also adds synthesized Copy and Drop impls, conditioned
+/// on the given member types, for the ones not derived explicitly.
 fn generate_derive_code_for_type(
     db: &dyn SyntaxGroup,
     ident: ast::TerminalIdentifier,
     attributes: AttributeList,
     extra_info: ExtraInfo,
+    synthesized_member_types: Option<Vec<String>>,
 ) -> PluginResult {
     let mut diagnostics = vec![];
     let mut impls = vec![];
+    let mut derived_traits = vec![];
     for attr in attributes.query_attr(db, "derive") {
         let attr = attr.structurize(db);
 
@@ -129,6 +170,7 @@ fn generate_derive_code_for_type(
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.args_stable_ptr.untyped(),
                 message: "Expected args.".into(),
+                lint_id: None,
             });
             continue;
         }
@@ -145,6 +187,7 @@ fn generate_derive_code_for_type(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: arg.arg_stable_ptr.untyped(),
                     message: "Expected path.".into(),
+                    lint_id: None,
                 });
                 continue;
             };
@@ -155,6 +198,7 @@ fn generate_derive_code_for_type(
 
             let name = ident.text(db);
             let derived = segment.ident(db).text(db);
+            derived_traits.push(derived.clone());
             match derived.as_str() {
                 "Copy" | "Drop" => impls.push(get_empty_impl(&name, &derived, &extra_info)),
                 "Clone" if !matches!(extra_info, ExtraInfo::Extern) => {
@@ -176,6 +220,7 @@ fn generate_derive_code_for_type(
                     diagnostics.push(PluginDiagnostic {
                         stable_ptr: value_stable_ptr.untyped(),
                         message: "Unsupported trait for derive for extern types.".into(),
+                        lint_id: None,
                     })
                 }
                 _ => {
@@ -185,6 +230,19 @@ fn generate_derive_code_for_type(
             }
         }
     }
+    let has_synthesized_impls = synthesized_member_types.is_some();
+    if let Some(member_types) = synthesized_member_types {
+        let name = ident.text(db);
+        let is_derived = |derived: &str| derived_traits.iter().any(|t| t == derived);
+        if !is_derived("Copy") {
+            impls.push(get_synthesized_impl(&name, "Copy", &extra_info, &member_types));
+        }
+        // A type deriving a destruct trait controls its destruction explicitly, e.g. since it
+        // holds a dict, so it must not be droppable.
+        if !is_derived("Drop") && !is_derived("Destruct") && !is_derived("PanicDestruct") {
+            impls.push(get_synthesized_impl(&name, "Drop", &extra_info, &member_types));
+        }
+    }
     PluginResult {
         code: if impls.is_empty() {
             None
@@ -192,7 +250,11 @@ fn generate_derive_code_for_type(
             Some(PluginGeneratedFile {
                 name: "impls".into(),
                 content: impls.join(""),
-                aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
+                aux_data: if has_synthesized_impls {
+                    DynGeneratedFileAuxData(Arc::new(SynthesizedImplsAuxData {}))
+                } else {
+                    DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {}))
+                },
             })
         },
         diagnostics,
@@ -200,6 +262,33 @@ fn generate_derive_code_for_type(
     }
 }
 
+/// Returns an impl of `derived_trait` for the type, synthesized by the compiler, that applies only
+/// if all the member types implement the trait.
+fn get_synthesized_impl(
+    name: &str,
+    derived_trait: &str,
+    extra_info: &ExtraInfo,
+    member_types: &[String],
+) -> String {
+    let member_impls = member_types
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| format!("impl Member{i}{derived_trait}: {derived_trait}<{ty}>"));
+    let (generics, generic_params) = match extra_info {
+        ExtraInfo::Struct { type_generics, other_generics, .. } => (
+            format_generics(type_generics, other_generics),
+            chain!(type_generics.iter().map(|t| t.to_string()), other_generics.iter().cloned())
+                .collect_vec(),
+        ),
+        ExtraInfo::Enum(_) | ExtraInfo::Extern => ("".to_string(), vec![]),
+    };
+    format!(
+        "#[{SYNTHESIZED_IMPL_ATTR}]\nimpl {name}Synthesized{derived_trait}<{}> of \
+         {derived_trait}::<{name}{generics}>;\n",
+        chain!(generic_params, member_impls).join(", ")
+    )
+}
+
 fn get_clone_impl(name: &str, extra_info: &ExtraInfo) -> String {
     match extra_info {
         ExtraInfo::Enum(variants) => {
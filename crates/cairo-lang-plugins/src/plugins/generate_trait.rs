@@ -49,6 +49,7 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
                 PluginDiagnostic {
                     stable_ptr: trait_ast.stable_ptr().untyped(),
                     message: "Generated trait must have a single element path.".to_string(),
+                    lint_id: None,
                 }],
             remove_original_item: false,
         };
@@ -76,6 +77,7 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: attr_arg.arg_stable_ptr.untyped(),
                     message: "Expected an argument with the name `trait_attrs`.".to_string(),
+                    lint_id: None,
                 });
                 vec![]
             }
@@ -124,6 +126,7 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
             stable_ptr: trait_ast.stable_ptr().untyped(),
             message: "Generated trait must have generic args matching the impl's generic params."
                 .to_string(),
+            lint_id: None,
         });
     }
     let signatures = match impl_ast.body(db) {
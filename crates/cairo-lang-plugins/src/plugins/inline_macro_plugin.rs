@@ -114,6 +114,7 @@ impl InlineMacroExpanderData {
             self.diagnostics.push(PluginDiagnostic {
                 stable_ptr: inline_macro.stable_ptr().untyped(),
                 message: format!("Unknown inline macro: {}", macro_name),
+                lint_id: None,
             });
         }
     }
@@ -139,6 +140,7 @@ impl InlineMacroExpanderData {
                     "Macro {} does not support this bracket type",
                     macro_ast.path(db).as_syntax_node().get_text(db)
                 ),
+                lint_id: None,
             });
             None
         }
@@ -52,6 +52,7 @@ pub fn extract_consteval_macro_expression(
         diagnostics.push(PluginDiagnostic {
             stable_ptr: macro_arguments.stable_ptr().untyped(),
             message: "consteval_int macro must have a single unnamed argument.".to_string(),
+            lint_id: None,
         });
         return None;
     }
@@ -104,6 +105,7 @@ pub fn compute_constant_expr(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: bin_expr.stable_ptr().untyped(),
                     message: "Unsupported binary operator in consteval_int macro".to_string(),
+                    lint_id: None,
                 });
                 None
             }
@@ -116,6 +118,7 @@ pub fn compute_constant_expr(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: un_expr.stable_ptr().untyped(),
                     message: "Unsupported unary operator in consteval_int macro".to_string(),
+                    lint_id: None,
                 });
                 None
             }
@@ -127,6 +130,7 @@ pub fn compute_constant_expr(
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: value.stable_ptr().untyped(),
                 message: "Unsupported expression in consteval_int macro".to_string(),
+                lint_id: None,
             });
             None
         }
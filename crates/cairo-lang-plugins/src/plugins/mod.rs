@@ -2,6 +2,7 @@ pub use config::*;
 pub use derive::*;
 pub use generate_trait::*;
 pub use inline_macro_plugin::*;
+pub use oracle::*;
 pub use panicable::*;
 
 mod config;
@@ -9,4 +10,5 @@ mod derive;
 mod generate_trait;
 mod inline_macro_plugin;
 mod inline_macros;
+mod oracle;
 mod panicable;
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use indoc::formatdoc;
+
+/// The attribute declaring an extern function as an oracle.
+pub const ORACLE_ATTR: &str = "oracle";
+
+/// The maximal length of the name of an oracle, as it is used as its selector short string.
+const MAX_ORACLE_NAME_LEN: usize = 31;
+
+/// Plugin replacing extern functions marked with `#[oracle]` by functions calling the oracle of
+/// the same name - serializing the parameters as its input, and deserializing its output as the
+/// return value.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct OraclePlugin;
+
+impl MacroPlugin for OraclePlugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        match item_ast {
+            ast::Item::ExternFunction(extern_func_ast)
+                if extern_func_ast.has_attr(db, ORACLE_ATTR) =>
+            {
+                generate_oracle_code(db, extern_func_ast)
+            }
+            _ => PluginResult::default(),
+        }
+    }
+}
+impl AsDynMacroPlugin for OraclePlugin {
+    fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
+    where
+        Self: 'a,
+    {
+        self
+    }
+}
+impl SemanticPlugin for OraclePlugin {}
+
+/// Generates the function calling the oracle declared by the given extern function.
+fn generate_oracle_code(
+    db: &dyn SyntaxGroup,
+    extern_func_ast: ast::ItemExternFunction,
+) -> PluginResult {
+    let declaration = extern_func_ast.declaration(db);
+    let diagnostic = |stable_ptr, message: &str| PluginResult {
+        code: None,
+        diagnostics: vec![PluginDiagnostic { stable_ptr, message: message.into(), lint_id: None }],
+        remove_original_item: true,
+    };
+
+    let generic_params = declaration.generic_params(db);
+    if !matches!(generic_params, ast::OptionWrappedGenericParamList::Empty(_)) {
+        return diagnostic(
+            generic_params.stable_ptr().untyped(),
+            "Oracle functions cannot be generic.",
+        );
+    }
+    let name = declaration.name(db);
+    let function_name = name.text(db);
+    if function_name.len() > MAX_ORACLE_NAME_LEN {
+        return diagnostic(
+            name.stable_ptr().untyped(),
+            "Oracle function names must be at most 31 characters long.",
+        );
+    }
+
+    let signature = declaration.signature(db);
+    let mut params = vec![];
+    let mut body = vec!["let mut __oracle_input = array::array_new::<felt252>();".to_string()];
+    for param in signature.parameters(db).elements(db) {
+        if !param.modifiers(db).elements(db).is_empty() {
+            return diagnostic(
+                param.stable_ptr().untyped(),
+                "Oracle function parameters cannot have modifiers.",
+            );
+        }
+        params.push(param.as_syntax_node().get_text_without_trivia(db));
+        body.push(format!(
+            "serde::Serde::serialize(@{}, ref __oracle_input);",
+            param.name(db).text(db)
+        ));
+    }
+    let call = format!(
+        "oracle::oracle_call::<'{function_name}'>(array::ArrayTrait::span(@__oracle_input))"
+    );
+    let ret_ty_clause = match signature.ret_ty(db) {
+        ast::OptionReturnTypeClause::Empty(_) => {
+            body.push(format!("{call};"));
+            String::new()
+        }
+        ast::OptionReturnTypeClause::ReturnTypeClause(ret_ty_clause) => {
+            body.push(format!("let mut __oracle_output = {call};"));
+            body.push(
+                "option::OptionTrait::expect(serde::Serde::deserialize(ref __oracle_output), \
+                 'Invalid oracle output')"
+                    .to_string(),
+            );
+            format!(" {}", ret_ty_clause.as_syntax_node().get_text_without_trivia(db))
+        }
+    };
+
+    PluginResult {
+        code: Some(PluginGeneratedFile {
+            name: "oracle".into(),
+            content: formatdoc!(
+                "
+                    fn {function_name}({params}){ret_ty_clause} {{
+                        {body}
+                    }}
+                ",
+                params = params.join(", "),
+                body = body.join("\n    "),
+            ),
+            aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
+        }),
+        diagnostics: vec![],
+        remove_original_item: true,
+    }
+}
@@ -61,6 +61,7 @@ fn generate_panicable_code(
                 stable_ptr: extra_attr.stable_ptr().untyped(),
                 message: "`#[panic_with]` cannot be applied multiple times to the same item."
                     .into(),
+                lint_id: None,
             }],
             remove_original_item: false,
         };
@@ -76,6 +77,7 @@ fn generate_panicable_code(
                 stable_ptr: signature.ret_ty(db).stable_ptr().untyped(),
                 message: "Currently only wrapping functions returning an Option<T> or \
                     Result<T, E>".into(),
+                lint_id: None,
             }],
             remove_original_item: false,
         };
@@ -89,6 +91,7 @@ fn generate_panicable_code(
             diagnostics: vec![PluginDiagnostic {
                 stable_ptr: attr.stable_ptr.untyped(),
                 message: "Failed to extract panic data attribute".into(),
+                lint_id: None,
             }],
             remove_original_item: false,
         };
@@ -4,6 +4,7 @@ use cairo_lang_defs::plugin::PluginGeneratedFile;
 use cairo_lang_diagnostics::{format_diagnostics, DiagnosticLocation};
 use cairo_lang_filesystem::cfg::CfgSet;
 use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::settings::Edition;
 use cairo_lang_parser::test_utils::create_virtual_file;
 use cairo_lang_parser::utils::{get_syntax_file_and_diagnostics, SimpleParserDatabase};
 use cairo_lang_syntax::node::TypedSyntaxNode;
@@ -20,6 +21,7 @@ cairo_lang_test_utils::test_file_test!(
         derive: "derive",
         generate_trait: "generate_trait",
         panicable: "panicable",
+        oracle: "oracle",
     },
     test_expand_plugin
 );
@@ -35,6 +37,11 @@ pub fn test_expand_plugin(
         db.set_cfg_set(Arc::new(cfg_set));
     }
 
+    let edition: Edition = inputs
+        .get("edition")
+        .map(|s| serde_json::from_str(s.as_str()).unwrap())
+        .unwrap_or_default();
+
     let cairo_code = &inputs["cairo_code"];
     let file_id = create_virtual_file(db, "dummy_file.cairo", cairo_code);
 
@@ -48,7 +55,11 @@ pub fn test_expand_plugin(
         let mut remove_original_item = false;
         let mut local_generated_items = Vec::<String>::new();
         for plugin in &plugins {
-            let result = plugin.clone().as_dyn_macro_plugin().generate_code(db, item.clone());
+            let result = plugin.clone().as_dyn_macro_plugin().generate_code_for_edition(
+                db,
+                item.clone(),
+                edition,
+            );
 
             diagnostic_items.extend(result.diagnostics.iter().map(|diag| {
                 let syntax_node = file_syntax_node.lookup_ptr(db, diag.stable_ptr);
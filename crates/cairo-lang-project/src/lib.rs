@@ -5,6 +5,7 @@ mod test;
 use std::path::{Path, PathBuf};
 
 use cairo_lang_filesystem::ids::Directory;
+use cairo_lang_filesystem::settings::CrateSettings;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
@@ -18,7 +19,8 @@ pub enum DeserializationError {
     #[error("PathError")]
     PathError,
 }
-const PROJECT_FILE_NAME: &str = "cairo_project.toml";
+/// The name of the file describing a Cairo project, at the root of the project directory.
+pub const PROJECT_FILE_NAME: &str = "cairo_project.toml";
 
 /// Cairo project config, including its file content and metadata about the file.
 /// This file is expected to be at a root of a crate and specify the crate name and location and
@@ -33,6 +35,9 @@ pub struct ProjectConfig {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProjectConfigContent {
     pub crate_roots: OrderedHashMap<SmolStr, PathBuf>,
+    /// Settings of the crates, by crate name. Crates not listed use the default settings.
+    #[serde(default, skip_serializing_if = "OrderedHashMap::is_empty")]
+    pub crate_settings: OrderedHashMap<SmolStr, CrateSettings>,
 }
 
 impl ProjectConfig {
@@ -1,3 +1,4 @@
+use cairo_lang_filesystem::settings::{CrateSettings, Edition, LintSettings};
 use indoc::indoc;
 
 use crate::ProjectConfigContent;
@@ -6,6 +7,7 @@ use crate::ProjectConfigContent;
 fn test_serde() {
     let config = ProjectConfigContent {
         crate_roots: [("crate".into(), "dir".into())].into_iter().collect(),
+        crate_settings: Default::default(),
     };
     let serialized = toml::to_string(&config).unwrap();
     assert_eq!(
@@ -17,3 +19,50 @@ fn test_serde() {
     );
     assert_eq!(config, toml::from_str(&serialized).unwrap());
 }
+
+#[test]
+fn test_crate_settings_serde() {
+    let config: ProjectConfigContent = toml::from_str(indoc! { r#"
+        [crate_roots]
+        crate = "dir"
+        old_crate = "old_dir"
+
+        [crate_settings.crate]
+        edition = "2023_10"
+    "# })
+    .unwrap();
+    assert_eq!(
+        config.crate_settings,
+        [("crate".into(), CrateSettings { edition: Edition::V2023_10, ..Default::default() })]
+            .into_iter()
+            .collect()
+    );
+    assert_eq!(config, toml::from_str(&toml::to_string(&config).unwrap()).unwrap());
+}
+
+#[test]
+fn test_lint_settings_serde() {
+    let config: ProjectConfigContent = toml::from_str(indoc! { r#"
+        [crate_roots]
+        crate = "dir"
+
+        [crate_settings.crate.lints]
+        allow = ["unreachable_code"]
+        warn = ["redundant_inline_attribute"]
+        deny_warnings = true
+    "# })
+    .unwrap();
+    assert_eq!(
+        config.crate_settings["crate"],
+        CrateSettings {
+            edition: Edition::default(),
+            lints: LintSettings {
+                allow: vec!["unreachable_code".into()],
+                warn: vec!["redundant_inline_attribute".into()],
+                deny: vec![],
+                deny_warnings: true,
+            },
+        }
+    );
+    assert_eq!(config, toml::from_str(&toml::to_string(&config).unwrap()).unwrap());
+}
@@ -0,0 +1,173 @@
+//! Python bindings for compiling Cairo programs and Starknet contracts, and for computing compiled
+//! class hashes, so Python pipelines can use the compiler without running its binaries.
+//!
+//! The `cairo_lang` Python module is built with `maturin`, see `pyproject.toml`. Contract classes
+//! are passed in and returned as JSON strings, in the format of the `starknet-compile` and
+//! `starknet-sierra-compile` outputs. Failures raise `cairo_lang.CompilationError`.
+
+use std::path::Path;
+
+use anyhow::Context;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
+use cairo_lang_starknet::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use cairo_lang_starknet::contract_class::{starknet_compile, ContractClass};
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+#[cfg(test)]
+mod test;
+
+create_exception!(cairo_lang, CompilationError, PyException);
+
+/// Compiles a Cairo project into the text of a Sierra program.
+pub fn compile_cairo_project(path: &Path, replace_ids: bool) -> anyhow::Result<String> {
+    let mut diagnostics = String::new();
+    let sierra_program = compile_cairo_project_at_path(
+        path,
+        CompilerConfig {
+            diagnostics_reporter: DiagnosticsReporter::write_to_string(&mut diagnostics),
+            replace_ids,
+            ..CompilerConfig::default()
+        },
+    );
+    Ok(sierra_program.map_err(|err| with_diagnostics(err, diagnostics))?.to_string())
+}
+
+/// Compiles a Starknet contract into the JSON of its contract class.
+pub fn compile_starknet_contract(
+    path: &Path,
+    contract_path: Option<String>,
+    replace_ids: bool,
+    allowed_libfuncs_list_name: Option<String>,
+) -> anyhow::Result<String> {
+    let mut diagnostics = String::new();
+    let contract_class = starknet_compile(
+        path.to_path_buf(),
+        contract_path,
+        Some(CompilerConfig {
+            diagnostics_reporter: DiagnosticsReporter::write_to_string(&mut diagnostics),
+            replace_ids,
+            ..CompilerConfig::default()
+        }),
+        Some(list_selector(allowed_libfuncs_list_name)),
+    );
+    contract_class.map_err(|err| with_diagnostics(err, diagnostics))
+}
+
+/// Compiles the JSON of a contract class into the JSON of its CASM contract class.
+pub fn compile_starknet_contract_to_casm(
+    contract_class: &str,
+    add_pythonic_hints: bool,
+    allowed_libfuncs_list_name: Option<String>,
+) -> anyhow::Result<String> {
+    let contract_class: ContractClass =
+        serde_json::from_str(contract_class).with_context(|| "deserialization Failed.")?;
+    validate_compatible_sierra_version(&contract_class, list_selector(allowed_libfuncs_list_name))?;
+    let casm_contract = CasmContractClass::from_contract_class(contract_class, add_pythonic_hints)
+        .with_context(|| "Compilation failed.")?;
+    serde_json::to_string_pretty(&casm_contract)
+        .with_context(|| "Casm contract Serialization failed.")
+}
+
+/// Computes the compiled class hash of the JSON of a CASM contract class, as a hex string.
+pub fn compute_compiled_class_hash(casm_contract_class: &str) -> anyhow::Result<String> {
+    let casm_contract: CasmContractClass =
+        serde_json::from_str(casm_contract_class).with_context(|| "deserialization Failed.")?;
+    Ok(format!("{:#x}", casm_contract.compiled_class_hash()?))
+}
+
+/// Returns the selector of the allowed libfuncs list with the given name, or of the default list.
+fn list_selector(allowed_libfuncs_list_name: Option<String>) -> ListSelector {
+    match allowed_libfuncs_list_name {
+        Some(list_name) => ListSelector::ListName(list_name),
+        None => ListSelector::DefaultList,
+    }
+}
+
+/// Adds the diagnostics reported during a failed compilation to its error.
+fn with_diagnostics(err: anyhow::Error, diagnostics: String) -> anyhow::Error {
+    if diagnostics.is_empty() {
+        err
+    } else {
+        err.context(diagnostics)
+    }
+}
+
+/// Converts an error of the compiler into a Python exception.
+fn to_py_err(err: anyhow::Error) -> PyErr {
+    CompilationError::new_err(format!("{err:?}"))
+}
+
+/// Compiles a Cairo project into the text of a Sierra program.
+#[pyfunction]
+#[pyo3(name = "compile_cairo_project", signature = (path, replace_ids = false))]
+fn py_compile_cairo_project(py: Python<'_>, path: &str, replace_ids: bool) -> PyResult<String> {
+    py.allow_threads(|| compile_cairo_project(Path::new(path), replace_ids)).map_err(to_py_err)
+}
+
+/// Compiles a Starknet contract into the JSON of its contract class.
+#[pyfunction]
+#[pyo3(
+    name = "compile_starknet_contract",
+    signature = (path, contract_path = None, replace_ids = false, allowed_libfuncs_list_name = None)
+)]
+fn py_compile_starknet_contract(
+    py: Python<'_>,
+    path: &str,
+    contract_path: Option<String>,
+    replace_ids: bool,
+    allowed_libfuncs_list_name: Option<String>,
+) -> PyResult<String> {
+    py.allow_threads(|| {
+        compile_starknet_contract(
+            Path::new(path),
+            contract_path,
+            replace_ids,
+            allowed_libfuncs_list_name,
+        )
+    })
+    .map_err(to_py_err)
+}
+
+/// Compiles the JSON of a contract class into the JSON of its CASM contract class.
+#[pyfunction]
+#[pyo3(
+    name = "compile_starknet_contract_to_casm",
+    signature = (contract_class, add_pythonic_hints = false, allowed_libfuncs_list_name = None)
+)]
+fn py_compile_starknet_contract_to_casm(
+    py: Python<'_>,
+    contract_class: &str,
+    add_pythonic_hints: bool,
+    allowed_libfuncs_list_name: Option<String>,
+) -> PyResult<String> {
+    py.allow_threads(|| {
+        compile_starknet_contract_to_casm(
+            contract_class,
+            add_pythonic_hints,
+            allowed_libfuncs_list_name,
+        )
+    })
+    .map_err(to_py_err)
+}
+
+/// Computes the compiled class hash of the JSON of a CASM contract class, as a hex string.
+#[pyfunction]
+#[pyo3(name = "compute_compiled_class_hash")]
+fn py_compute_compiled_class_hash(py: Python<'_>, casm_contract_class: &str) -> PyResult<String> {
+    py.allow_threads(|| compute_compiled_class_hash(casm_contract_class)).map_err(to_py_err)
+}
+
+/// The `cairo_lang` Python module.
+#[pymodule]
+fn cairo_lang(py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add("CompilationError", py.get_type::<CompilationError>())?;
+    module.add_function(wrap_pyfunction!(py_compile_cairo_project, module)?)?;
+    module.add_function(wrap_pyfunction!(py_compile_starknet_contract, module)?)?;
+    module.add_function(wrap_pyfunction!(py_compile_starknet_contract_to_casm, module)?)?;
+    module.add_function(wrap_pyfunction!(py_compute_compiled_class_hash, module)?)?;
+    Ok(())
+}
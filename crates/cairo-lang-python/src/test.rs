@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use crate::{
+    compile_cairo_project, compile_starknet_contract, compile_starknet_contract_to_casm,
+    compute_compiled_class_hash,
+};
+
+/// Returns the path of a file, relative to the root of the repository.
+fn repo_path(path: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", "..", path].iter().collect()
+}
+
+#[test]
+fn test_compile_cairo_project() {
+    let sierra_program = compile_cairo_project(&repo_path("examples/fib.cairo"), true).unwrap();
+    assert!(sierra_program.contains("fib::fib::fib"));
+}
+
+#[test]
+fn test_compile_contract_and_compute_class_hash() {
+    let contract_class = compile_starknet_contract(
+        &repo_path("crates/cairo-lang-starknet/test_data/minimal_contract.cairo"),
+        None,
+        false,
+        None,
+    )
+    .unwrap();
+    let casm_contract_class =
+        compile_starknet_contract_to_casm(&contract_class, false, None).unwrap();
+    assert_eq!(
+        compute_compiled_class_hash(&casm_contract_class).unwrap(),
+        "0x46f2882281342dea7694207216f95d925ba08ef4be0cff5e81e9057f49ef3c2"
+    );
+}
+
+#[test]
+fn test_compilation_failure() {
+    assert!(compile_cairo_project(&repo_path("examples/no_such_file.cairo"), false).is_err());
+    assert!(compile_starknet_contract_to_casm("{}", false, None).is_err());
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use cairo_felt::Felt252;
+use thiserror::Error;
+
+/// An error in fetching state from a forked network.
+#[derive(Debug, Error)]
+#[error("Failed fetching forked state: {0}")]
+pub struct ForkError(pub String);
+
+/// A source of the state of a live Starknet network, e.g. an RPC client.
+pub trait ForkStateReader {
+    /// Returns the value at `key` in the storage of the contract at `contract_address`.
+    fn get_storage_at(
+        &self,
+        contract_address: &Felt252,
+        key: &Felt252,
+    ) -> Result<Felt252, ForkError>;
+
+    /// Returns the class hash of the contract at `contract_address`, or `None` if no contract is
+    /// deployed there.
+    fn get_class_hash_at(&self, contract_address: &Felt252) -> Result<Option<Felt252>, ForkError>;
+
+    /// Returns the nonce of the contract at `contract_address`.
+    fn get_nonce(&self, contract_address: &Felt252) -> Result<Felt252, ForkError>;
+}
+
+/// The state of a forked network, lazily fetched from a [ForkStateReader] and cached.
+#[derive(Clone)]
+pub struct StarknetFork {
+    /// The reader of the forked network state.
+    reader: Arc<dyn ForkStateReader>,
+    /// The storage values fetched so far, per contract.
+    storage: HashMap<Felt252, HashMap<Felt252, Felt252>>,
+    /// The class hashes fetched so far, per contract address.
+    class_hashes: HashMap<Felt252, Option<Felt252>>,
+    /// The nonces fetched so far, per contract address.
+    nonces: HashMap<Felt252, Felt252>,
+}
+impl StarknetFork {
+    pub fn new(reader: Arc<dyn ForkStateReader>) -> Self {
+        Self {
+            reader,
+            storage: Default::default(),
+            class_hashes: Default::default(),
+            nonces: Default::default(),
+        }
+    }
+
+    /// Returns the value at `key` in the storage of the contract at `contract_address`.
+    pub fn storage_at(
+        &mut self,
+        contract_address: &Felt252,
+        key: &Felt252,
+    ) -> Result<Felt252, ForkError> {
+        let contract_storage = self.storage.entry(contract_address.clone()).or_default();
+        if let Some(value) = contract_storage.get(key) {
+            return Ok(value.clone());
+        }
+        let value = self.reader.get_storage_at(contract_address, key)?;
+        contract_storage.insert(key.clone(), value.clone());
+        Ok(value)
+    }
+
+    /// Returns the class hash of the contract at `contract_address`, if deployed.
+    pub fn class_hash_at(
+        &mut self,
+        contract_address: &Felt252,
+    ) -> Result<Option<Felt252>, ForkError> {
+        if let Some(class_hash) = self.class_hashes.get(contract_address) {
+            return Ok(class_hash.clone());
+        }
+        let class_hash = self.reader.get_class_hash_at(contract_address)?;
+        self.class_hashes.insert(contract_address.clone(), class_hash.clone());
+        Ok(class_hash)
+    }
+
+    /// Returns the nonce of the contract at `contract_address`.
+    pub fn nonce(&mut self, contract_address: &Felt252) -> Result<Felt252, ForkError> {
+        if let Some(nonce) = self.nonces.get(contract_address) {
+            return Ok(nonce.clone());
+        }
+        let nonce = self.reader.get_nonce(contract_address)?;
+        self.nonces.insert(contract_address.clone(), nonce.clone());
+        Ok(nonce)
+    }
+}
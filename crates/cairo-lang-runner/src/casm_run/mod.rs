@@ -2,16 +2,18 @@ use std::any::Any;
 use std::borrow::Cow;
 use std::collections::{HashMap, VecDeque};
 use std::ops::{Deref, Shl};
+use std::sync::Arc;
 
 use ark_ff::fields::{Fp256, MontBackend, MontConfig};
 use ark_ff::{BigInteger, Field, PrimeField};
 use ark_std::UniformRand;
 use cairo_felt::{felt_str as felt252_str, Felt252};
-use cairo_lang_casm::hints::{CoreHint, DeprecatedHint, Hint, StarknetHint};
+use cairo_lang_casm::hints::{CoreHint, CoreHintBase, DeprecatedHint, Hint, StarknetHint};
 use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_casm::operand::{
     BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand,
 };
+use cairo_lang_sierra::extensions::modular::{MOD_BUILTIN_INSTANCE_SIZE, MOD_BUILTIN_N_LIMBS};
 use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_utils::bigint::BigIntAsHex;
 use cairo_lang_utils::extract_matches;
@@ -31,9 +33,11 @@ use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
 use cairo_vm::vm::runners::cairo_runner::{CairoRunner, ResourceTracker, RunResources};
 use cairo_vm::vm::vm_core::VirtualMachine;
 use dict_manager::DictManagerExecScope;
-use num_bigint::BigUint;
-use num_integer::Integer;
-use num_traits::{FromPrimitive, ToPrimitive, Zero};
+use fork::StarknetFork;
+pub use fork::{ForkError, ForkStateReader};
+use num_bigint::{BigInt, BigUint};
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
 use {ark_secp256k1 as secp256k1, ark_secp256r1 as secp256r1};
 
 use self::dict_manager::DictSquashExecScope;
@@ -44,6 +48,7 @@ use crate::{build_hints_dict, Arg, RunResultValue, SierraCasmRunner};
 mod test;
 
 mod dict_manager;
+mod fork;
 
 // TODO(orizi): This def is duplicated.
 /// Returns the Beta value of the Starkware elliptic curve.
@@ -96,6 +101,8 @@ pub struct CairoHintProcessor<'a> {
     pub starknet_state: StarknetState,
     // Maintains the resources of the run.
     pub run_resources: RunResources,
+    // The number of times each instrumentation probe was hit, by the id of the probe.
+    pub instrumentation_hits: HashMap<usize, usize>,
 }
 
 pub fn cell_ref_to_relocatable(cell_ref: &CellRef, vm: &VirtualMachine) -> Relocatable {
@@ -131,12 +138,94 @@ pub struct StarknetState {
     /// The simulated execution info.
     exec_info: ExecutionInfo,
     next_id: Felt252,
+    /// The forked network state, used for state not set in the simulated state.
+    fork: Option<StarknetFork>,
 }
 impl StarknetState {
+    /// Sets the state to fall back to the state read by `reader` for storage values, deployed
+    /// contracts and nonces it does not have.
+    pub fn with_fork(mut self, reader: Arc<dyn ForkStateReader>) -> Self {
+        self.fork = Some(StarknetFork::new(reader));
+        self
+    }
+
+    /// Returns the class hash of the locally deployed contract at `contract_address`.
+    pub fn deployed_class_hash(&self, contract_address: &Felt252) -> Option<&Felt252> {
+        self.deployed_contracts.get(contract_address)
+    }
+
+    /// Returns the events emitted and not popped yet, as the emitting contract address, the event
+    /// keys and the event data.
+    pub fn remaining_events(&self) -> impl Iterator<Item = (&Felt252, &[Felt252], &[Felt252])> {
+        self.logs.iter().flat_map(|(contract_address, logs)| {
+            logs.iter().map(move |(keys, data)| (contract_address, &keys[..], &data[..]))
+        })
+    }
+
+    /// Registers the contract at `contract_address` as deployed with class `class_hash`.
+    pub(crate) fn set_deployed_class_hash(
+        &mut self,
+        contract_address: Felt252,
+        class_hash: Felt252,
+    ) {
+        self.deployed_contracts.insert(contract_address, class_hash);
+    }
+
+    /// Sets the address of the contract in whose context the code is run, returning the previous
+    /// one.
+    pub(crate) fn replace_contract_address(&mut self, contract_address: Felt252) -> Felt252 {
+        std::mem::replace(&mut self.exec_info.contract_address, contract_address)
+    }
+
     pub fn get_next_id(&mut self) -> Felt252 {
         self.next_id += Felt252::from(1);
         self.next_id.clone()
     }
+
+    /// Returns the value at `key` in the storage of the contract at `contract_address`.
+    pub fn read_storage(
+        &mut self,
+        contract_address: &Felt252,
+        key: &Felt252,
+    ) -> Result<Felt252, ForkError> {
+        if let Some(value) = self
+            .storage
+            .get(contract_address)
+            .and_then(|contract_storage| contract_storage.get(key))
+        {
+            return Ok(value.clone());
+        }
+        match &mut self.fork {
+            Some(fork) => fork.storage_at(contract_address, key),
+            None => Ok(Felt252::from(0)),
+        }
+    }
+
+    /// Returns the class hash of the contract at `contract_address`, if deployed.
+    fn get_class_hash(&mut self, contract_address: &Felt252) -> Result<Option<Felt252>, ForkError> {
+        if let Some(class_hash) = self.deployed_contracts.get(contract_address) {
+            return Ok(Some(class_hash.clone()));
+        }
+        match &mut self.fork {
+            Some(fork) => fork.class_hash_at(contract_address),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the nonce of the transaction, if not set - the nonce of its account.
+    fn get_tx_nonce(&mut self) -> Result<Felt252, ForkError> {
+        let tx_info = &self.exec_info.tx_info;
+        match (&tx_info.nonce, &mut self.fork) {
+            (Some(nonce), _) => Ok(nonce.clone()),
+            (None, Some(fork)) => fork.nonce(&tx_info.account_contract_address),
+            (None, None) => Ok(Felt252::from(0)),
+        }
+    }
+}
+
+/// Converts an error in fetching the forked state into a hint error.
+fn fork_error_to_hint_error(err: ForkError) -> HintError {
+    HintError::CustomHint(Box::from(err.to_string()))
 }
 
 /// Copy of the cairo `ExecutionInfo` struct.
@@ -165,7 +254,8 @@ struct TxInfo {
     signature: Vec<Felt252>,
     transaction_hash: Felt252,
     chain_id: Felt252,
-    nonce: Felt252,
+    /// The nonce of the transaction, `None` if not explicitly set.
+    nonce: Option<Felt252>,
 }
 /// Execution scope for constant memory allocation.
 struct MemoryExecScope {
@@ -324,6 +414,25 @@ impl HintProcessorLogic for CairoHintProcessor<'_> {
     ) -> Result<(), HintError> {
         let hint = hint_data.downcast_ref::<Hint>().unwrap();
         let hint = match hint {
+            Hint::Core(CoreHintBase::Core(CoreHint::InstrumentationHit { id })) => {
+                let id = get_val(vm, id)?.to_usize().expect("Probe id too large.");
+                *self.instrumentation_hits.entry(id).or_default() += 1;
+                return Ok(());
+            }
+            Hint::Core(CoreHintBase::Core(CoreHint::Oracle {
+                selector,
+                input_start,
+                input_end,
+                output_start,
+                output_end,
+            })) => {
+                return self.execute_oracle(
+                    selector,
+                    [input_start, input_end],
+                    [output_start, output_end],
+                    vm,
+                );
+            }
             Hint::Core(core_hint_base) => {
                 return execute_core_hint_base(vm, exec_scopes, core_hint_base);
             }
@@ -721,13 +830,9 @@ impl<'a> CairoHintProcessor<'a> {
             // Only address_domain 0 is currently supported.
             fail_syscall!(b"Unsupported address domain");
         }
-        let value = self
-            .starknet_state
-            .storage
-            .get(&self.starknet_state.exec_info.contract_address)
-            .and_then(|contract_storage| contract_storage.get(&addr))
-            .cloned()
-            .unwrap_or_else(|| Felt252::from(0));
+        let contract = self.starknet_state.exec_info.contract_address.clone();
+        let value =
+            self.starknet_state.read_storage(&contract, &addr).map_err(fork_error_to_hint_error)?;
         Ok(SyscallResult::Success(vec![value.into()]))
     }
 
@@ -751,6 +856,7 @@ impl<'a> CairoHintProcessor<'a> {
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, 50);
+        let nonce = self.starknet_state.get_tx_nonce().map_err(fork_error_to_hint_error)?;
         let exec_info = &self.starknet_state.exec_info;
         let block_info = &exec_info.block_info;
         let tx_info = &exec_info.tx_info;
@@ -766,7 +872,7 @@ impl<'a> CairoHintProcessor<'a> {
         res_segment.write(signature_end)?;
         res_segment.write(tx_info.transaction_hash.clone())?;
         res_segment.write(tx_info.chain_id.clone())?;
-        res_segment.write(tx_info.nonce.clone())?;
+        res_segment.write(nonce)?;
         let block_info_ptr = res_segment.ptr;
         res_segment.write(block_info.block_number.clone())?;
         res_segment.write(block_info.block_timestamp.clone())?;
@@ -859,16 +965,20 @@ impl<'a> CairoHintProcessor<'a> {
         deduct_gas!(gas_counter, 50);
 
         // Get the class hash of the contract.
-        let Some(class_hash) = self.starknet_state.deployed_contracts.get(&contract_address) else {
+        let Some(class_hash) = self
+            .starknet_state
+            .get_class_hash(&contract_address)
+            .map_err(fork_error_to_hint_error)?
+        else {
             fail_syscall!(b"CONTRACT_NOT_DEPLOYED");
         };
 
         // Prepare runner for running the ctor.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let contract_info = runner
-            .starknet_contracts_info
-            .get(class_hash)
-            .expect("Deployed contract not found in registry.");
+        // Contracts deployed on the forked network may have classes unknown to the runner.
+        let Some(contract_info) = runner.starknet_contracts_info.get(&class_hash) else {
+            fail_syscall!(b"CLASS_HASH_NOT_FOUND");
+        };
 
         // Call the function.
         let Some(entry_point) = contract_info.externals.get(&selector) else {
@@ -977,6 +1087,38 @@ impl<'a> CairoHintProcessor<'a> {
         }
     }
 
+    /// Executes an oracle call, using the oracle handler of the runner.
+    fn execute_oracle(
+        &self,
+        selector: &BigIntAsHex,
+        [input_start, input_end]: [&ResOperand; 2],
+        [output_start, output_end]: [&CellRef; 2],
+        vm: &mut VirtualMachine,
+    ) -> Result<(), HintError> {
+        let selector = &selector.value.to_bytes_be().1;
+        let selector = std::str::from_utf8(selector).map_err(|_| {
+            HintError::CustomHint(Box::from("failed to parse oracle selector".to_string()))
+        })?;
+        let runner = self.runner.ok_or_else(|| {
+            HintError::CustomHint(Box::from(format!("No oracle handler for `{selector}`.")))
+        })?;
+
+        let input_start = extract_relocatable(vm, input_start)?;
+        let input_end = extract_relocatable(vm, input_end)?;
+        let inputs = vm_get_range(vm, input_start, input_end)?;
+        let outputs = runner.oracle_handler.call(selector, &inputs).map_err(|err| {
+            HintError::CustomHint(Box::from(format!("Oracle `{selector}` failed: {err}")))
+        })?;
+
+        let mut res_segment = MemBuffer::new_segment(vm);
+        let res_segment_start = res_segment.ptr;
+        res_segment.write_data(outputs.iter())?;
+        let res_segment_end = res_segment.ptr;
+        insert_value_to_cellref!(vm, output_start, res_segment_start)?;
+        insert_value_to_cellref!(vm, output_end, res_segment_end)?;
+        Ok(())
+    }
+
     /// Executes a cheatcode.
     fn execute_cheatcode(
         &mut self,
@@ -1045,7 +1187,7 @@ impl<'a> CairoHintProcessor<'a> {
                 self.starknet_state.exec_info.tx_info.chain_id = as_single_input(inputs)?;
             }
             "set_nonce" => {
-                self.starknet_state.exec_info.tx_info.nonce = as_single_input(inputs)?;
+                self.starknet_state.exec_info.tx_info.nonce = Some(as_single_input(inputs)?);
             }
             "set_signature" => {
                 self.starknet_state.exec_info.tx_info.signature = inputs;
@@ -1815,12 +1957,266 @@ pub fn execute_core_hint(
             insert_value_to_cellref!(vm, dst, memory_exec_scope.next_address)?;
             memory_exec_scope.next_address.offset += object_size;
         }
+        CoreHint::SortPermutation { start, end, dst } => {
+            let start = extract_relocatable(vm, start)?;
+            let end = extract_relocatable(vm, end)?;
+            let mut values = vec![];
+            let mut curr = start;
+            while curr != end {
+                values.push(vm.get_integer(curr)?.to_biguint());
+                curr += 1;
+            }
+            // `sort_by_key` is stable, so equal values keep their original order.
+            let mut permutation: Vec<usize> = (0..values.len()).collect();
+            permutation.sort_by_key(|index| &values[*index]);
+            let permutation_start = vm.add_memory_segment();
+            insert_value_to_cellref!(vm, dst, permutation_start)?;
+            for (i, index) in permutation.into_iter().enumerate() {
+                vm.insert_value((permutation_start + i)?, Felt252::from(index))?;
+            }
+        }
+        CoreHint::FindRangeIndex { value, bounds, n_bounds, dst } => {
+            let value = get_val(vm, value)?.to_biguint();
+            let bounds = extract_relocatable(vm, bounds)?;
+            let n_bounds = get_val(vm, n_bounds)?.to_usize().unwrap();
+            let mut index = 0;
+            for i in 0..n_bounds {
+                if vm.get_integer((bounds + i)?)?.to_biguint() <= value {
+                    index = i;
+                }
+            }
+            insert_value_to_cellref!(vm, dst, Felt252::from(index))?;
+        }
+        // The hits of the probes are counted by the hint processor running the program, if any.
+        CoreHint::InstrumentationHit { .. } => {}
+        CoreHint::U256InvModN { b0, b1, n0, n1, has_inverse, x0, x1, y0, y1 } => {
+            let pow_2_128 = BigUint::from(u128::MAX) + 1u32;
+            let b0 = get_val(vm, b0)?.to_biguint();
+            let b1 = get_val(vm, b1)?.to_biguint();
+            let n0 = get_val(vm, n0)?.to_biguint();
+            let n1 = get_val(vm, n1)?.to_biguint();
+            let b = BigInt::from(b0 + b1.shl(128));
+            let n = BigInt::from(n0 + n1.shl(128));
+            let ExtendedGcd { gcd, x: inverse, .. } = b.extended_gcd(&n);
+            let (has_inverse_val, x, y) = if n.is_one() {
+                (0u32, BigInt::zero(), BigInt::zero())
+            } else if !gcd.is_one() {
+                // `b * (n / gcd) = n * (b / gcd)`, proving that `b` has no inverse.
+                (0u32, &n / &gcd, &b / &gcd)
+            } else {
+                let x = inverse.mod_floor(&n);
+                let y = (&b * &x - 1u32) / &n;
+                (1u32, x, y)
+            };
+            insert_value_to_cellref!(vm, has_inverse, Felt252::from(has_inverse_val))?;
+            let (limb1, limb0) = x.to_biguint().unwrap().div_rem(&pow_2_128);
+            insert_value_to_cellref!(vm, x0, Felt252::from(limb0))?;
+            insert_value_to_cellref!(vm, x1, Felt252::from(limb1))?;
+            let (limb1, limb0) = y.to_biguint().unwrap().div_rem(&pow_2_128);
+            insert_value_to_cellref!(vm, y0, Felt252::from(limb0))?;
+            insert_value_to_cellref!(vm, y1, Felt252::from(limb1))?;
+        }
+        // Oracles are implemented by the runner, so they are handled by its hint processor.
+        CoreHint::Oracle { .. } => Err(HintError::CustomHint(Box::from(
+            "Oracles are only supported when running through a `SierraCasmRunner`.".to_string(),
+        )))?,
+        CoreHint::AddEcdsaSignature { ecdsa_ptr, r, s } => {
+            let ecdsa_ptr = extract_relocatable(vm, ecdsa_ptr)?;
+            let signature = (get_val(vm, r)?, get_val(vm, s)?);
+            vm.get_signature_builtin()?
+                .add_signature(ecdsa_ptr, &signature)
+                .map_err(VirtualMachineError::from)?;
+        }
+        CoreHint::FillModBuiltins { add_mod_ptr, n_add_mods, mul_mod_ptr, n_mul_mods } => {
+            let mut operations = vec![];
+            for (is_mul, ptr, n) in
+                [(false, add_mod_ptr, n_add_mods), (true, mul_mod_ptr, n_mul_mods)]
+            {
+                let n = get_val(vm, n)?.to_usize().unwrap();
+                if n > 0 {
+                    let ptr = extract_relocatable(vm, ptr)?;
+                    operations.extend(read_mod_builtin_operations(vm, ptr, n, is_mul)?);
+                }
+            }
+            fill_mod_builtin_values(vm, operations)?;
+        }
+    };
+    Ok(())
+}
+
+/// An operation of the AddMod or MulMod builtins, asserting that `a + b = c` or `a * b = c`
+/// modulo `p`, for the values at `addresses`.
+struct ModBuiltinOperation {
+    is_mul: bool,
+    p: BigInt,
+    addresses: [Relocatable; 3],
+}
+
+/// Reads the `n` operations described by the mod builtin instance at `ptr`.
+///
+/// The code using the builtin only sets its first instance, so the following instances are filled
+/// the way the builtin expects them - each describing the operations from its own onwards.
+fn read_mod_builtin_operations(
+    vm: &mut VirtualMachine,
+    ptr: Relocatable,
+    n: usize,
+    is_mul: bool,
+) -> Result<Vec<ModBuiltinOperation>, HintError> {
+    let p = read_mod_builtin_value(vm, ptr)?.ok_or_else(|| {
+        HintError::CustomHint(Box::from("The modulus of a mod builtin is not set.".to_string()))
+    })?;
+    let values_ptr = vm.get_relocatable((ptr + MOD_BUILTIN_N_LIMBS)?)?;
+    let offsets_ptr = vm.get_relocatable((ptr + (MOD_BUILTIN_N_LIMBS + 1))?)?;
+    for i in 1..n {
+        let instance = (ptr + i * MOD_BUILTIN_INSTANCE_SIZE)?;
+        write_mod_builtin_value(vm, instance, &p)?;
+        vm.insert_value((instance + MOD_BUILTIN_N_LIMBS)?, values_ptr)?;
+        vm.insert_value((instance + (MOD_BUILTIN_N_LIMBS + 1))?, (offsets_ptr + 3 * i)?)?;
+        vm.insert_value((instance + (MOD_BUILTIN_N_LIMBS + 2))?, Felt252::from(n - i))?;
+    }
+    let mut operations = vec![];
+    for i in 0..n {
+        let mut addresses = [values_ptr; 3];
+        for (j, address) in addresses.iter_mut().enumerate() {
+            let offset = vm.get_integer((offsets_ptr + (3 * i + j))?)?.to_usize().unwrap();
+            *address = (values_ptr + offset)?;
+        }
+        operations.push(ModBuiltinOperation { is_mul, p: p.clone(), addresses });
+    }
+    Ok(operations)
+}
+
+/// Fills the values of the given mod builtin operations deduced by the builtins. An operation may
+/// depend on values deduced by other operations, so the operations are retried until all of them
+/// are complete.
+fn fill_mod_builtin_values(
+    vm: &mut VirtualMachine,
+    mut operations: Vec<ModBuiltinOperation>,
+) -> Result<(), HintError> {
+    while !operations.is_empty() {
+        let n_operations = operations.len();
+        let mut incomplete = vec![];
+        for operation in operations {
+            if !fill_mod_builtin_operation(vm, &operation)? {
+                incomplete.push(operation);
+            }
+        }
+        if incomplete.len() == n_operations {
+            return Err(HintError::CustomHint(Box::from(
+                "Could not deduce the values of the mod builtin operations.".to_string(),
+            )));
+        }
+        operations = incomplete;
+    }
+    Ok(())
+}
+
+/// Deduces the missing value of a mod builtin operation, if possible. Returns whether all the
+/// values of the operation are set.
+fn fill_mod_builtin_operation(
+    vm: &mut VirtualMachine,
+    operation: &ModBuiltinOperation,
+) -> Result<bool, HintError> {
+    let ModBuiltinOperation { is_mul, p, addresses: [a_addr, b_addr, c_addr] } = operation;
+    let a = read_mod_builtin_value(vm, *a_addr)?;
+    let b = read_mod_builtin_value(vm, *b_addr)?;
+    let c = read_mod_builtin_value(vm, *c_addr)?;
+    let (address, value) = match (a, b, c) {
+        (Some(a), Some(b), Some(c)) => {
+            let diff = if *is_mul { a * b } else { a + b } - c;
+            let holds = if p.is_zero() { diff.is_zero() } else { diff.mod_floor(p).is_zero() };
+            if !holds {
+                return Err(HintError::CustomHint(Box::from(format!(
+                    "A {} builtin operation does not hold modulo {p}.",
+                    if *is_mul { "MulMod" } else { "AddMod" }
+                ))));
+            }
+            return Ok(true);
+        }
+        (Some(a), Some(b), None) if *is_mul => {
+            if p.is_zero() {
+                return Err(HintError::CustomHint(Box::from(
+                    "A MulMod builtin operation has a modulus of 0.".to_string(),
+                )));
+            }
+            (c_addr, (a * b).mod_floor(p))
+        }
+        (Some(a), Some(b), None) => {
+            let sum = a + b;
+            (c_addr, if &sum >= p { sum - p } else { sum })
+        }
+        (Some(a), None, Some(c)) => match deduce_mod_builtin_operand(*is_mul, p, a, c) {
+            Some(b) => (b_addr, b),
+            None => return Ok(false),
+        },
+        (None, Some(b), Some(c)) => match deduce_mod_builtin_operand(*is_mul, p, b, c) {
+            Some(a) => (a_addr, a),
+            None => return Ok(false),
+        },
+        _ => return Ok(false),
     };
+    write_mod_builtin_value(vm, *address, &value)?;
+    Ok(true)
+}
+
+/// Returns the operand `x` satisfying `known + x = c` or `known * x = c` modulo `p`, if it can be
+/// deduced - multiplicative operands can only be deduced if `known` is invertible modulo `p`.
+fn deduce_mod_builtin_operand(
+    is_mul: bool,
+    p: &BigInt,
+    known: BigInt,
+    c: BigInt,
+) -> Option<BigInt> {
+    if is_mul {
+        let ExtendedGcd { gcd, x: inverse, .. } = known.extended_gcd(p);
+        gcd.is_one().then(|| (c * inverse).mod_floor(p))
+    } else {
+        let diff = c - known;
+        Some(if diff < BigInt::zero() { diff + p } else { diff })
+    }
+}
+
+/// Reads a value of the mod builtins from its 96-bit limbs at `addr`, if all of them are set.
+///
+/// The limbs are range checked, as done by the RangeCheck96 builtin holding the values.
+fn read_mod_builtin_value(
+    vm: &VirtualMachine,
+    addr: Relocatable,
+) -> Result<Option<BigInt>, HintError> {
+    let mut value = BigInt::zero();
+    for i in (0..MOD_BUILTIN_N_LIMBS).rev() {
+        let Some(MaybeRelocatable::Int(limb)) = vm.get_maybe(&(addr + i)?) else {
+            return Ok(None);
+        };
+        let limb = limb.to_bigint();
+        if limb >= BigInt::one() << 96 {
+            return Err(HintError::CustomHint(Box::from(format!(
+                "A limb of a mod builtin value is out of range: {limb}."
+            ))));
+        }
+        value = (value << 96) + limb;
+    }
+    Ok(Some(value))
+}
+
+/// Writes a value of the mod builtins as 96-bit limbs at `addr`.
+fn write_mod_builtin_value(
+    vm: &mut VirtualMachine,
+    addr: Relocatable,
+    value: &BigInt,
+) -> Result<(), HintError> {
+    let limb_mask = (BigInt::one() << 96) - 1;
+    for i in 0..MOD_BUILTIN_N_LIMBS {
+        vm.insert_value((addr + i)?, Felt252::from((value >> (96 * i)) & &limb_mask))?;
+    }
     Ok(())
 }
 
 /// Reads the result of a function call that returns `Array<felt252>`.
-fn read_array_result_as_vec(memory: &[Option<Felt252>], value: &[Felt252]) -> Vec<Felt252> {
+pub(crate) fn read_array_result_as_vec(
+    memory: &[Option<Felt252>],
+    value: &[Felt252],
+) -> Vec<Felt252> {
     // TODO(spapini): Handle failures.
     let [res_start, res_end] = value else {
         panic!("Unexpected return value from contract call");
@@ -1884,30 +2280,35 @@ where
         string_to_hint,
         starknet_state: StarknetState::default(),
         run_resources: RunResources::default(),
+        instrumentation_hits: HashMap::new(),
     };
-    run_function(instructions, builtins, additional_initialization, &mut hint_processor, hints_dict)
-        .map(|(mem, val)| (mem, val, hint_processor.starknet_state))
+    let bytecode: Vec<BigInt> =
+        instructions.flat_map(|instruction| instruction.assemble().encode()).collect();
+    run_function(
+        bytecode.iter(),
+        builtins,
+        additional_initialization,
+        &mut hint_processor,
+        hints_dict,
+    )
+    .map(|(mem, val)| (mem, val, hint_processor.starknet_state))
 }
 
 /// Runs `program` on layout with prime, and returns the memory layout and ap value.
 /// Allows injecting custom HintProcessor.
-pub fn run_function<'a, 'b: 'a, Instructions>(
-    instructions: Instructions,
+pub fn run_function<'a, Bytecode, AdditionalInitialization>(
+    bytecode: Bytecode,
     builtins: Vec<BuiltinName>,
-    additional_initialization: fn(
-        context: RunFunctionContext<'_>,
-    ) -> Result<(), Box<CairoRunError>>,
+    additional_initialization: AdditionalInitialization,
     hint_processor: &mut dyn HintProcessor,
     hints_dict: HashMap<usize, Vec<HintParams>>,
 ) -> Result<RunFunctionRes, Box<CairoRunError>>
 where
-    Instructions: Iterator<Item = &'a Instruction> + Clone,
+    Bytecode: Iterator<Item = &'a BigInt> + Clone,
+    AdditionalInitialization: FnOnce(RunFunctionContext<'_>) -> Result<(), Box<CairoRunError>>,
 {
-    let data: Vec<MaybeRelocatable> = instructions
-        .flat_map(|inst| inst.assemble().encode())
-        .map(Felt252::from)
-        .map(MaybeRelocatable::from)
-        .collect();
+    let data: Vec<MaybeRelocatable> =
+        bytecode.cloned().map(Felt252::from).map(MaybeRelocatable::from).collect();
 
     let data_len = data.len();
     let program = Program::new(
@@ -1,3 +1,6 @@
+use std::cell::Cell;
+use std::sync::Arc;
+
 use cairo_felt::Felt252;
 use cairo_lang_casm::inline::CasmContext;
 use cairo_lang_casm::{casm, deref};
@@ -5,7 +8,9 @@ use itertools::Itertools;
 use num_traits::ToPrimitive;
 use test_case::test_case;
 
-use crate::casm_run::run_function_with_starknet_context;
+use crate::casm_run::{
+    run_function_with_starknet_context, ForkError, ForkStateReader, StarknetState,
+};
 
 #[test_case(
     casm! {
@@ -133,3 +138,54 @@ fn test_allocate_segment() {
         .expect("Number not in index range.");
     assert_eq!(memory[ptr], Some(Felt252::from(1337)));
 }
+
+/// A forked state reader, returning fixed values and counting the fetches.
+#[derive(Default)]
+struct MockForkStateReader {
+    n_fetches: Cell<usize>,
+}
+impl ForkStateReader for MockForkStateReader {
+    fn get_storage_at(
+        &self,
+        contract_address: &Felt252,
+        key: &Felt252,
+    ) -> Result<Felt252, ForkError> {
+        self.n_fetches.set(self.n_fetches.get() + 1);
+        Ok(contract_address + key)
+    }
+
+    fn get_class_hash_at(&self, contract_address: &Felt252) -> Result<Option<Felt252>, ForkError> {
+        self.n_fetches.set(self.n_fetches.get() + 1);
+        Ok(if contract_address == &Felt252::from(1) { Some(Felt252::from(100)) } else { None })
+    }
+
+    fn get_nonce(&self, _contract_address: &Felt252) -> Result<Felt252, ForkError> {
+        self.n_fetches.set(self.n_fetches.get() + 1);
+        Ok(Felt252::from(7))
+    }
+}
+
+#[test]
+fn test_forked_state() {
+    let reader = Arc::new(MockForkStateReader::default());
+    let mut state = StarknetState::default().with_fork(reader.clone());
+
+    // Storage not set locally is fetched once, and then cached.
+    assert_eq!(state.read_storage(&Felt252::from(1), &Felt252::from(2)).unwrap(), 3.into());
+    assert_eq!(state.read_storage(&Felt252::from(1), &Felt252::from(2)).unwrap(), 3.into());
+    assert_eq!(reader.n_fetches.get(), 1);
+    // Storage set locally overrides the forked state.
+    state.storage.entry(Felt252::from(1)).or_default().insert(Felt252::from(5), 8.into());
+    assert_eq!(state.read_storage(&Felt252::from(1), &Felt252::from(5)).unwrap(), 8.into());
+    assert_eq!(reader.n_fetches.get(), 1);
+
+    assert_eq!(state.get_class_hash(&Felt252::from(1)).unwrap(), Some(100.into()));
+    assert_eq!(state.get_class_hash(&Felt252::from(2)).unwrap(), None);
+    assert_eq!(state.get_class_hash(&Felt252::from(2)).unwrap(), None);
+    assert_eq!(reader.n_fetches.get(), 3);
+
+    assert_eq!(state.get_tx_nonce().unwrap(), 7.into());
+    state.exec_info.tx_info.nonce = Some(Felt252::from(9));
+    assert_eq!(state.get_tx_nonce().unwrap(), 9.into());
+    assert_eq!(reader.n_fetches.get(), 4);
+}
@@ -0,0 +1,539 @@
+//! Differential testing of the compilation of Sierra to casm, by running programs both with the
+//! Sierra simulator and on the VM, and comparing the results.
+//!
+//! The programs are either given (such as the examples of the repository), or randomly generated
+//! well-typed programs over the libfuncs supported by the simulator.
+
+use std::collections::HashMap;
+
+use ark_std::rand::rngs::StdRng;
+use ark_std::rand::{Rng, SeedableRng};
+use cairo_felt::Felt252;
+use cairo_lang_sierra::extensions::felt252::Felt252Type;
+use cairo_lang_sierra::extensions::gas::{CostTokenType, GasBuiltinType};
+use cairo_lang_sierra::extensions::int::unsigned::{Uint16Type, Uint32Type, Uint64Type, Uint8Type};
+use cairo_lang_sierra::extensions::int::unsigned128::Uint128Type;
+use cairo_lang_sierra::extensions::range_check::RangeCheckType;
+use cairo_lang_sierra::extensions::NamedType;
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{Function, Program, StatementIdx};
+use cairo_lang_sierra::simulation::value::CoreValue;
+use cairo_lang_sierra::simulation::{self, SimulationError};
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use thiserror::Error;
+
+use crate::{Arg, RunResultValue, RunnerError, SierraCasmRunner};
+
+#[cfg(test)]
+#[path = "differential_test.rs"]
+mod test;
+
+/// The name of the function of the generated programs.
+pub const FUZZ_FUNCTION_NAME: &str = "fuzz";
+
+/// The maximal number of operations in a block of a generated program, before it branches or
+/// returns.
+const MAX_BLOCK_LEN: usize = 6;
+
+/// The maximal number of nested branches in a generated program.
+const MAX_BRANCH_DEPTH: usize = 3;
+
+/// The weight of the accumulated value when folding the values of a generated program into its
+/// return value, making the result depend on the order of the values.
+const FOLD_WEIGHT: u32 = 3;
+
+#[derive(Debug, Error)]
+pub enum DifferentialError {
+    #[error("Type `{0}` is not supported by the Sierra simulator.")]
+    UnsupportedType(ConcreteTypeId),
+    #[error("Simulated value `{0:?}` has no memory representation to compare.")]
+    UnsupportedValue(CoreValue),
+    #[error("The arguments do not match the parameters of the function.")]
+    ArgumentsMismatch,
+    #[error(transparent)]
+    SimulationError(#[from] SimulationError),
+    #[error(transparent)]
+    RunnerError(#[from] RunnerError),
+}
+
+/// The outcome of a single run of a function.
+#[derive(Debug, Eq, PartialEq)]
+pub enum RunOutcome {
+    /// The run ended, with the given value and final gas counter.
+    Finished { value: RunResultValue, gas_counter: Option<Felt252> },
+    /// The run failed, with the given error.
+    Failed(String),
+}
+
+/// A run where the compiled casm behaved differently from the Sierra simulator.
+#[derive(Debug)]
+pub struct Divergence {
+    /// The arguments of the run.
+    pub args: Vec<Felt252>,
+    /// The outcome of the simulation of the Sierra code.
+    pub simulated: RunOutcome,
+    /// The outcome of the run of the casm code on the VM.
+    pub casm: RunOutcome,
+}
+
+impl SierraCasmRunner {
+    /// Runs `func` both with the Sierra simulator and on the VM, and returns the divergence
+    /// between the runs, if any.
+    ///
+    /// The parameters of `func` may only be builtins supported by the simulator (`RangeCheck` and
+    /// `GasBuiltin`) and unsigned integers or felt252s, where `args` holds the values of the
+    /// latter. The simulator is the reference, so failing to simulate the run is an error, while
+    /// failing to run the casm code is a divergence.
+    pub fn find_divergence(
+        &self,
+        func: &Function,
+        args: &[Felt252],
+        available_gas: Option<usize>,
+    ) -> Result<Option<Divergence>, DifferentialError> {
+        let initial_gas = self.get_initial_available_gas(func, available_gas)?;
+        let mut remaining_args = args.iter();
+        let mut next_arg = || remaining_args.next().ok_or(DifferentialError::ArgumentsMismatch);
+        let inputs = func
+            .signature
+            .param_types
+            .iter()
+            .map(|ty| {
+                let generic_id = &self.get_info(ty).long_id.generic_id;
+                Ok(if *generic_id == RangeCheckType::ID {
+                    CoreValue::RangeCheck
+                } else if *generic_id == GasBuiltinType::ID {
+                    CoreValue::GasBuiltin(initial_gas as i64)
+                } else if *generic_id == Felt252Type::ID {
+                    CoreValue::Felt252(next_arg()?.to_bigint())
+                } else if *generic_id == Uint8Type::ID {
+                    CoreValue::Uint8(
+                        next_arg()?.to_u8().ok_or(DifferentialError::ArgumentsMismatch)?,
+                    )
+                } else if *generic_id == Uint16Type::ID {
+                    CoreValue::Uint16(
+                        next_arg()?.to_u16().ok_or(DifferentialError::ArgumentsMismatch)?,
+                    )
+                } else if *generic_id == Uint32Type::ID {
+                    CoreValue::Uint32(
+                        next_arg()?.to_u32().ok_or(DifferentialError::ArgumentsMismatch)?,
+                    )
+                } else if *generic_id == Uint64Type::ID {
+                    CoreValue::Uint64(
+                        next_arg()?.to_u64().ok_or(DifferentialError::ArgumentsMismatch)?,
+                    )
+                } else if *generic_id == Uint128Type::ID {
+                    CoreValue::Uint128(
+                        next_arg()?.to_u128().ok_or(DifferentialError::ArgumentsMismatch)?,
+                    )
+                } else {
+                    return Err(DifferentialError::UnsupportedType(ty.clone()));
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if remaining_args.next().is_some() {
+            return Err(DifferentialError::ArgumentsMismatch);
+        }
+
+        // The simulator takes the gas withdrawn and redeposited at every statement, which is the
+        // constant cost variable of the statement in the casm code.
+        let statement_gas_info = (0..self.sierra_program.statements.len())
+            .map(|idx| {
+                let idx = StatementIdx(idx);
+                let variable = (idx, CostTokenType::Const);
+                (idx, self.metadata.gas_info.variable_values.get(&variable).copied().unwrap_or(0))
+            })
+            .collect::<HashMap<_, _>>();
+        let mut values = vec![];
+        let mut gas_counter = None;
+        for output in simulation::run(&self.sierra_program, &statement_gas_info, &func.id, inputs)?
+        {
+            match output {
+                CoreValue::RangeCheck => {}
+                CoreValue::GasBuiltin(gas) => gas_counter = Some(Felt252::from(gas)),
+                value => push_memory_repr(value, &mut values)?,
+            }
+        }
+        let simulated =
+            RunOutcome::Finished { value: RunResultValue::Success(values), gas_counter };
+
+        let casm = match self.run_function_with_starknet_context(
+            func,
+            &args.iter().cloned().map(Arg::Value).collect_vec(),
+            available_gas,
+            Default::default(),
+        ) {
+            Ok(result) => {
+                RunOutcome::Finished { value: result.value, gas_counter: result.gas_counter }
+            }
+            Err(RunnerError::CairoRunError(error)) => RunOutcome::Failed(error.to_string()),
+            Err(error) => return Err(error.into()),
+        };
+        Ok(if simulated == casm {
+            None
+        } else {
+            Some(Divergence { args: args.to_vec(), simulated, casm })
+        })
+    }
+}
+
+/// Pushes the memory representation of a simulated value to `cells`.
+fn push_memory_repr(value: CoreValue, cells: &mut Vec<Felt252>) -> Result<(), DifferentialError> {
+    match value {
+        CoreValue::Felt252(value) => cells.push(Felt252::from(value)),
+        CoreValue::Uint8(value) => cells.push(Felt252::from(value)),
+        CoreValue::Uint16(value) => cells.push(Felt252::from(value)),
+        CoreValue::Uint32(value) => cells.push(Felt252::from(value)),
+        CoreValue::Uint64(value) => cells.push(Felt252::from(value)),
+        CoreValue::Uint128(value) => cells.push(Felt252::from(value)),
+        CoreValue::NonZero(value) => push_memory_repr(*value, cells)?,
+        CoreValue::Struct(members) => {
+            for member in members {
+                push_memory_repr(member, cells)?;
+            }
+        }
+        value => return Err(DifferentialError::UnsupportedValue(value)),
+    }
+    Ok(())
+}
+
+/// Generates `programs` random programs from `seed`, runs each of them with `runs_per_program`
+/// random arguments, and returns the divergences found, along with the diverging programs.
+pub fn fuzz(
+    seed: u64,
+    programs: usize,
+    runs_per_program: usize,
+) -> Result<Vec<(Program, Divergence)>, DifferentialError> {
+    let mut generator = ProgramGenerator::new(seed);
+    let mut divergences = vec![];
+    for _ in 0..programs {
+        let program = generator.generate_program();
+        let runner = SierraCasmRunner::new(program.clone(), None, Default::default())?;
+        let func = runner.find_function(FUZZ_FUNCTION_NAME)?;
+        for _ in 0..runs_per_program {
+            let args = generator.generate_args();
+            if let Some(divergence) = runner.find_divergence(func, &args, None)? {
+                divergences.push((program.clone(), divergence));
+            }
+        }
+    }
+    Ok(divergences)
+}
+
+/// Returns a random well-typed program, generated from `seed`.
+///
+/// The program has a single function, named [FUZZ_FUNCTION_NAME], taking a `RangeCheck`, two
+/// felt252s and two u128s, and returning the `RangeCheck` and a felt252 folding all the values
+/// computed along the taken branch.
+pub fn generate_program(seed: u64) -> Program {
+    ProgramGenerator::new(seed).generate_program()
+}
+
+/// The variables available at a point of a generated program.
+#[derive(Clone)]
+struct BlockState {
+    /// The name of the `RangeCheck` variable.
+    range_check: String,
+    /// The names of the felt252 variables.
+    felt252s: Vec<String>,
+    /// The names of the u128 variables.
+    u128s: Vec<String>,
+    /// The number of branches leading to the block.
+    depth: usize,
+}
+
+/// Generator of random well-typed Sierra programs, written as Sierra code.
+///
+/// Every computed value is stored as a temporary variable, and every consumed variable is
+/// duplicated first, so all the variables stay usable until the function returns. Branches are
+/// never merged, so every block ends with its own return.
+struct ProgramGenerator {
+    rng: StdRng,
+    /// The declarations of the libfuncs of the constants of the current program.
+    const_libfuncs: Vec<String>,
+    /// The number of variables declared in the current program.
+    n_vars: usize,
+}
+impl ProgramGenerator {
+    fn new(seed: u64) -> Self {
+        Self { rng: StdRng::seed_from_u64(seed), const_libfuncs: vec![], n_vars: 0 }
+    }
+
+    /// Generates a new random program.
+    fn generate_program(&mut self) -> Program {
+        self.const_libfuncs.clear();
+        self.n_vars = 0;
+        let state = BlockState {
+            range_check: "rc".into(),
+            felt252s: vec!["a".into(), "b".into()],
+            u128s: vec!["x".into(), "y".into()],
+            depth: 0,
+        };
+        let statements = self.generate_block(state, 0);
+        let code = format!(
+            "type RangeCheck = RangeCheck;
+type felt252 = felt252;
+type u128 = u128;
+type NonZeroFelt252 = NonZero<felt252>;
+type NonZeroU128 = NonZero<u128>;
+
+libfunc branch_align = branch_align;
+libfunc dup_felt252 = dup<felt252>;
+libfunc dup_u128 = dup<u128>;
+libfunc felt252_add = felt252_add;
+libfunc felt252_sub = felt252_sub;
+libfunc felt252_mul = felt252_mul;
+libfunc felt252_mul_weight = felt252_mul_const<{FOLD_WEIGHT}>;
+libfunc felt252_is_zero = felt252_is_zero;
+libfunc u128_overflowing_add = u128_overflowing_add;
+libfunc u128_overflowing_sub = u128_overflowing_sub;
+libfunc u128_is_zero = u128_is_zero;
+libfunc u128_to_felt252 = u128_to_felt252;
+libfunc unwrap_non_zero_felt252 = unwrap_non_zero<felt252>;
+libfunc unwrap_non_zero_u128 = unwrap_non_zero<u128>;
+libfunc store_temp_rc = store_temp<RangeCheck>;
+libfunc store_temp_felt252 = store_temp<felt252>;
+libfunc store_temp_u128 = store_temp<u128>;
+{const_libfuncs}
+
+{statements}
+
+{FUZZ_FUNCTION_NAME}@0(rc: RangeCheck, a: felt252, b: felt252, x: u128, y: u128) -> \
+             (RangeCheck, felt252);
+",
+            const_libfuncs = self.const_libfuncs.join("\n"),
+            statements = statements.join("\n"),
+        );
+        cairo_lang_sierra::ProgramParser::new().parse(&code).expect("Generated an invalid program.")
+    }
+
+    /// Generates random arguments for the function of the generated programs.
+    fn generate_args(&mut self) -> Vec<Felt252> {
+        let a = self.felt252_value();
+        let b = self.felt252_value();
+        let x = self.u128_value();
+        let y = self.u128_value();
+        vec![Felt252::from(a), Felt252::from(b), Felt252::from(x), Felt252::from(y)]
+    }
+
+    /// Returns a random felt252 value, biased towards edge cases.
+    fn felt252_value(&mut self) -> BigInt {
+        match self.rng.gen_range(0..5) {
+            0 => BigInt::from(0),
+            1 => BigInt::from(1),
+            2 => BigInt::from(-1),
+            3 => BigInt::from(self.rng.gen_range(0..100_u128)),
+            _ => BigInt::from(self.rng.gen::<u128>()),
+        }
+    }
+
+    /// Returns a random u128 value, biased towards edge cases.
+    fn u128_value(&mut self) -> u128 {
+        match self.rng.gen_range(0..5) {
+            0 => 0,
+            1 => 1,
+            2 => u128::MAX,
+            3 => self.rng.gen_range(0..100),
+            _ => self.rng.gen(),
+        }
+    }
+
+    /// Returns the name of a new variable.
+    fn new_var(&mut self) -> String {
+        self.n_vars += 1;
+        format!("v{}", self.n_vars)
+    }
+
+    /// Returns a random variable out of `vars`.
+    fn choose(&mut self, vars: &[String]) -> String {
+        vars[self.rng.gen_range(0..vars.len())].clone()
+    }
+
+    /// Generates the statements of a block starting at statement `start`, up to the return of the
+    /// function.
+    fn generate_block(&mut self, mut state: BlockState, start: usize) -> Vec<String> {
+        let mut statements = vec![];
+        let can_branch = state.depth < MAX_BRANCH_DEPTH;
+        for _ in 0..self.rng.gen_range(1..=MAX_BLOCK_LEN) {
+            match self.rng.gen_range(0..if can_branch { 7 } else { 4 }) {
+                0 => {
+                    let value = self.felt252_value();
+                    let libfunc = format!("felt252_const_{}", self.const_libfuncs.len());
+                    self.const_libfuncs
+                        .push(format!("libfunc {libfunc} = felt252_const<{value}>;"));
+                    let var = self.new_var();
+                    statements.push(format!("{libfunc}() -> ({var});"));
+                    statements.push(format!("store_temp_felt252({var}) -> ({var});"));
+                    state.felt252s.push(var);
+                }
+                1 => {
+                    let value = self.u128_value();
+                    let libfunc = format!("u128_const_{}", self.const_libfuncs.len());
+                    self.const_libfuncs.push(format!("libfunc {libfunc} = u128_const<{value}>;"));
+                    let var = self.new_var();
+                    statements.push(format!("{libfunc}() -> ({var});"));
+                    statements.push(format!("store_temp_u128({var}) -> ({var});"));
+                    state.u128s.push(var);
+                }
+                2 => {
+                    let lhs = self.choose(&state.felt252s);
+                    let rhs = self.choose(&state.felt252s);
+                    let libfunc =
+                        ["felt252_add", "felt252_sub", "felt252_mul"][self.rng.gen_range(0..3)];
+                    let [lhs_copy, rhs_copy, var] = [(); 3].map(|_| self.new_var());
+                    statements.push(format!("dup_felt252({lhs}) -> ({lhs}, {lhs_copy});"));
+                    statements.push(format!("dup_felt252({rhs}) -> ({rhs}, {rhs_copy});"));
+                    statements.push(format!("{libfunc}({lhs_copy}, {rhs_copy}) -> ({var});"));
+                    statements.push(format!("store_temp_felt252({var}) -> ({var});"));
+                    state.felt252s.push(var);
+                }
+                3 => {
+                    let value = self.choose(&state.u128s);
+                    let [copy, var] = [(); 2].map(|_| self.new_var());
+                    statements.push(format!("dup_u128({value}) -> ({value}, {copy});"));
+                    statements.push(format!("u128_to_felt252({copy}) -> ({var});"));
+                    statements.push(format!("store_temp_felt252({var}) -> ({var});"));
+                    state.felt252s.push(var);
+                }
+                4 => {
+                    let value = self.choose(&state.felt252s);
+                    let [copy, non_zero, var] = [(); 3].map(|_| self.new_var());
+                    statements.push(format!("dup_felt252({value}) -> ({value}, {copy});"));
+                    let zero_state = BlockState { depth: state.depth + 1, ..state.clone() };
+                    let mut non_zero_state = zero_state.clone();
+                    non_zero_state.felt252s.push(var.clone());
+                    self.push_branches(
+                        &mut statements,
+                        start,
+                        |target| {
+                            format!(
+                                "felt252_is_zero({copy}) {{ fallthrough() {target}({non_zero}) }};"
+                            )
+                        },
+                        [
+                            (vec![], zero_state),
+                            (
+                                vec![format!("unwrap_non_zero_felt252({non_zero}) -> ({var});")],
+                                non_zero_state,
+                            ),
+                        ],
+                    );
+                    return statements;
+                }
+                5 => {
+                    let value = self.choose(&state.u128s);
+                    let [copy, non_zero, var] = [(); 3].map(|_| self.new_var());
+                    statements.push(format!("dup_u128({value}) -> ({value}, {copy});"));
+                    let zero_state = BlockState { depth: state.depth + 1, ..state.clone() };
+                    let mut non_zero_state = zero_state.clone();
+                    non_zero_state.u128s.push(var.clone());
+                    self.push_branches(
+                        &mut statements,
+                        start,
+                        |target| {
+                            format!(
+                                "u128_is_zero({copy}) {{ fallthrough() {target}({non_zero}) }};"
+                            )
+                        },
+                        [
+                            (vec![], zero_state),
+                            (
+                                vec![format!("unwrap_non_zero_u128({non_zero}) -> ({var});")],
+                                non_zero_state,
+                            ),
+                        ],
+                    );
+                    return statements;
+                }
+                _ => {
+                    let lhs = self.choose(&state.u128s);
+                    let rhs = self.choose(&state.u128s);
+                    let libfunc =
+                        ["u128_overflowing_add", "u128_overflowing_sub"][self.rng.gen_range(0..2)];
+                    let [lhs_copy, rhs_copy, var] = [(); 3].map(|_| self.new_var());
+                    statements.push(format!("dup_u128({lhs}) -> ({lhs}, {lhs_copy});"));
+                    statements.push(format!("dup_u128({rhs}) -> ({rhs}, {rhs_copy});"));
+                    let mut branch_state = BlockState { depth: state.depth + 1, ..state.clone() };
+                    branch_state.u128s.push(var.clone());
+                    let rc = state.range_check.clone();
+                    let store_result = vec![format!("store_temp_u128({var}) -> ({var});")];
+                    self.push_branches(
+                        &mut statements,
+                        start,
+                        |target| {
+                            format!(
+                                "{libfunc}({rc}, {lhs_copy}, {rhs_copy}) {{ fallthrough({rc}, \
+                                 {var}) {target}({rc}, {var}) }};"
+                            )
+                        },
+                        [
+                            (store_result.clone(), branch_state.clone()),
+                            (store_result, branch_state),
+                        ],
+                    );
+                    return statements;
+                }
+            }
+        }
+        statements.extend(self.generate_return(state));
+        statements
+    }
+
+    /// Pushes a branching statement to `statements` (of the block starting at statement
+    /// `start`), followed by the blocks of its fallthrough and jump branches. Each branch starts
+    /// with `branch_align` followed by its given statements, and continues with the given state.
+    ///
+    /// `invocation` returns the branching statement, given the index of the statement the jump
+    /// branch starts at.
+    fn push_branches(
+        &mut self,
+        statements: &mut Vec<String>,
+        start: usize,
+        invocation: impl FnOnce(usize) -> String,
+        branches: [(Vec<String>, BlockState); 2],
+    ) {
+        let mut branch_idx = start + statements.len() + 1;
+        let [fallthrough, jump] = branches;
+        let mut generate_branch = |(prefix, state): (Vec<String>, BlockState), idx: usize| {
+            let mut branch = vec!["branch_align() -> ();".to_string()];
+            branch.extend(prefix);
+            let block_start = idx + branch.len();
+            branch.extend(self.generate_block(state, block_start));
+            branch
+        };
+        let fallthrough = generate_branch(fallthrough, branch_idx);
+        branch_idx += fallthrough.len();
+        let jump = generate_branch(jump, branch_idx);
+        statements.push(invocation(branch_idx));
+        statements.extend(fallthrough);
+        statements.extend(jump);
+    }
+
+    /// Generates the statements folding all the variables of the block into a single felt252,
+    /// and returning it along with the `RangeCheck`.
+    fn generate_return(&mut self, state: BlockState) -> Vec<String> {
+        let mut statements = vec![];
+        let mut values = state.felt252s;
+        for value in state.u128s {
+            let var = self.new_var();
+            statements.push(format!("u128_to_felt252({value}) -> ({var});"));
+            values.push(var);
+        }
+        let mut values = values.into_iter();
+        let mut acc = values.next().unwrap();
+        for value in values {
+            let [weighted, sum] = [(); 2].map(|_| self.new_var());
+            statements.push(format!("felt252_mul_weight({acc}) -> ({weighted});"));
+            statements.push(format!("store_temp_felt252({weighted}) -> ({weighted});"));
+            statements.push(format!("felt252_add({weighted}, {value}) -> ({sum});"));
+            statements.push(format!("store_temp_felt252({sum}) -> ({sum});"));
+            acc = sum;
+        }
+        let rc = state.range_check;
+        statements.push(format!("store_temp_rc({rc}) -> ({rc});"));
+        statements.push(format!("store_temp_felt252({acc}) -> ({acc});"));
+        statements.push(format!("return({rc}, {acc});"));
+        statements
+    }
+}
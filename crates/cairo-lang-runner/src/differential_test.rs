@@ -0,0 +1,65 @@
+use std::fs;
+use std::path::PathBuf;
+
+use cairo_felt::Felt252;
+use cairo_lang_sierra::program::Program;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{fuzz, generate_program};
+use crate::SierraCasmRunner;
+
+/// Returns a parsed example program from the examples of the Sierra crate.
+fn get_example_program(name: &str) -> Program {
+    let path: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "..",
+        "cairo-lang-sierra",
+        "examples",
+        &format!("{name}.sierra"),
+    ]
+    .iter()
+    .collect();
+    cairo_lang_sierra::ProgramParser::new().parse(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test_case("fib_jumps", &[0], Some(100000); "fib_jumps(0)")]
+#[test_case("fib_jumps", &[7], Some(100000); "fib_jumps(7)")]
+#[test_case("fib_jumps", &[80], Some(1000); "fib_jumps out of gas")]
+#[test_case("fib_no_gas", &[1, 1, 0], None; "fib_no_gas(0)")]
+#[test_case("fib_no_gas", &[1, 1, 8], None; "fib_no_gas(8)")]
+fn examples_match_simulation(name: &str, args: &[u64], available_gas: Option<usize>) {
+    let runner = SierraCasmRunner::new(
+        get_example_program(name),
+        available_gas.map(|_| Default::default()),
+        Default::default(),
+    )
+    .unwrap();
+    let divergence = runner
+        .find_divergence(
+            runner.find_function("Fibonacci").unwrap(),
+            &args.iter().copied().map(Felt252::from).collect_vec(),
+            available_gas,
+        )
+        .unwrap();
+    assert!(divergence.is_none(), "Diverged from the simulation: {divergence:#?}");
+}
+
+#[test]
+fn generated_programs_match_simulation() {
+    let divergences = fuzz(0, 50, 4).unwrap();
+    assert!(
+        divergences.is_empty(),
+        "Diverged from the simulation:\n{}",
+        divergences
+            .iter()
+            .map(|(program, divergence)| format!("{program}\n{divergence:#?}"))
+            .join("\n\n")
+    );
+}
+
+#[test]
+fn generated_programs_are_deterministic() {
+    assert_eq!(generate_program(7), generate_program(7));
+}
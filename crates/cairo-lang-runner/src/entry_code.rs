@@ -0,0 +1,200 @@
+//! Generation of the code wrapping a compiled Sierra program when running it.
+//!
+//! The runner surrounds the compiled program with entry code, calling the function to run, and a
+//! footer appended after the program. Embedders targeting runtimes with different conventions for
+//! passing builtins and arguments may provide their own [EntryCodeGenerator].
+use cairo_lang_casm::instructions::Instruction;
+use cairo_lang_casm::{casm, casm_extend};
+use cairo_lang_sierra::extensions::modular::{AddModType, MulModType};
+use cairo_lang_sierra::extensions::range_check::RangeCheck96Type;
+use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
+use cairo_lang_sierra::extensions::NamedType;
+use cairo_lang_sierra::ids::GenericTypeId;
+use cairo_lang_sierra::program::Function;
+use cairo_lang_sierra_to_casm::builtins::{builtin_info, layout_builtins, BuiltinKind};
+use cairo_lang_utils::extract_matches;
+use cairo_vm::serde::deserialize_program::BuiltinName;
+
+use crate::{Arg, RunnerError, SierraCasmRunner};
+
+/// The layout builtins not supported by the VM, emulated by fresh segments. Their libfuncs fill the
+/// memory of their instances through the `FillModBuiltins` hint, which validates the constraints
+/// the builtins enforce.
+const EMULATED_BUILTINS: [GenericTypeId; 3] =
+    [RangeCheck96Type::ID, AddModType::ID, MulModType::ID];
+
+/// Generator of the code wrapping a compiled Sierra program when running it.
+pub trait EntryCodeGenerator: Send + Sync {
+    /// Returns the instructions to add to the beginning of the code to successfully call `func`,
+    /// as well as the builtins required to execute the program.
+    fn create_entry_code(
+        &self,
+        runner: &SierraCasmRunner,
+        func: &Function,
+        args: &[Arg],
+        initial_gas: usize,
+    ) -> Result<(Vec<Instruction>, Vec<BuiltinName>), RunnerError>;
+
+    /// Returns the instructions to append to the program's bytecode.
+    fn create_code_footer(&self, runner: &SierraCasmRunner) -> Vec<Instruction>;
+}
+
+/// The default entry code generator, passing the builtins of the Starknet OS and allocating a
+/// fresh segment for the system builtin.
+#[derive(Default)]
+pub struct StarknetEntryCodeGenerator;
+impl EntryCodeGenerator for StarknetEntryCodeGenerator {
+    fn create_entry_code(
+        &self,
+        runner: &SierraCasmRunner,
+        func: &Function,
+        args: &[Arg],
+        initial_gas: usize,
+    ) -> Result<(Vec<Instruction>, Vec<BuiltinName>), RunnerError> {
+        let mut arg_iter = args.iter().peekable();
+        let mut expected_arguments_size = 0;
+        let mut ctx = casm! {};
+        // The layout builtins passed by the VM, in the formatting expected by the runner.
+        let vm_builtins = layout_builtins()
+            .filter(|builtin| !EMULATED_BUILTINS.contains(&builtin.generic_id))
+            .map(|builtin| Ok((&builtin.generic_id, vm_builtin_name(builtin.name)?)))
+            .collect::<Result<Vec<_>, RunnerError>>()?;
+        // Load all vecs to memory.
+        let mut vecs = vec![];
+        let mut ap_offset: i16 = 0;
+        for arg in args {
+            let Arg::Array(values) = arg else { continue };
+            vecs.push(ap_offset);
+            casm_extend! {ctx,
+                %{ memory[ap + 0] = segments.add() %}
+                ap += 1;
+            }
+            for (i, v) in values.iter().enumerate() {
+                let arr_at = (i + 1) as i16;
+                casm_extend! {ctx,
+                    [ap + 0] = (v.to_bigint());
+                    [ap + 0] = [[ap - arr_at] + (i as i16)], ap++;
+                };
+            }
+            ap_offset += (1 + values.len()) as i16;
+        }
+        let after_vecs_offset = ap_offset;
+        if func
+            .signature
+            .param_types
+            .iter()
+            .any(|ty| runner.get_info(ty).long_id.generic_id == SegmentArenaType::ID)
+        {
+            casm_extend! {ctx,
+                // SegmentArena segment.
+                %{ memory[ap + 0] = segments.add() %}
+                // Infos segment.
+                %{ memory[ap + 1] = segments.add() %}
+                ap += 2;
+                [ap + 0] = 0, ap++;
+                // Write Infos segment, n_constructed (0), and n_destructed (0) to the segment.
+                [ap - 2] = [[ap - 3]];
+                [ap - 1] = [[ap - 3] + 1];
+                [ap - 1] = [[ap - 3] + 2];
+            }
+            ap_offset += 3;
+        }
+        for ty in func.signature.param_types.iter() {
+            let info = runner.get_info(ty);
+            let ty_size = runner.get_type_sizes()[ty];
+            let generic_ty = &info.long_id.generic_id;
+            match builtin_info(generic_ty).map(|builtin| builtin.kind) {
+                Some(BuiltinKind::Layout { .. }) => {
+                    match vm_builtins.iter().position(|(generic_id, _)| *generic_id == generic_ty) {
+                        Some(index) => {
+                            // The builtins are passed right before the return address and the
+                            // previous `fp`, so the last of them is at [fp - 3].
+                            let offset = (2 + vm_builtins.len() - index) as i16;
+                            casm_extend! {ctx,
+                                [ap + 0] = [fp - offset], ap++;
+                            }
+                        }
+                        None => {
+                            // An emulated builtin.
+                            casm_extend! {ctx,
+                                %{ memory[ap + 0] = segments.add() %}
+                                ap += 1;
+                            }
+                        }
+                    }
+                }
+                Some(BuiltinKind::System) => {
+                    casm_extend! {ctx,
+                        %{ memory[ap + 0] = segments.add() %}
+                        ap += 1;
+                    }
+                }
+                Some(BuiltinKind::Gas) => {
+                    casm_extend! {ctx,
+                        [ap + 0] = initial_gas, ap++;
+                    }
+                }
+                Some(BuiltinKind::SegmentArena) => {
+                    let offset = -ap_offset + after_vecs_offset;
+                    casm_extend! {ctx,
+                        [ap + 0] = [ap + offset] + 3, ap++;
+                    }
+                }
+                None if matches!(arg_iter.peek(), Some(Arg::Array(_))) => {
+                    let values = extract_matches!(arg_iter.next().unwrap(), Arg::Array);
+                    let offset = -ap_offset + vecs.pop().unwrap();
+                    expected_arguments_size += 1;
+                    casm_extend! {ctx,
+                        [ap + 0] = [ap + (offset)], ap++;
+                        [ap + 0] = [ap - 1] + (values.len()), ap++;
+                    }
+                }
+                None => {
+                    let arg_size = ty_size;
+                    expected_arguments_size += arg_size as usize;
+                    for _ in 0..arg_size {
+                        if let Some(value) = arg_iter.next() {
+                            let value = extract_matches!(value, Arg::Value);
+                            casm_extend! {ctx,
+                                [ap + 0] = (value.to_bigint()), ap++;
+                            }
+                        }
+                    }
+                }
+            }
+            ap_offset += ty_size;
+        }
+        if expected_arguments_size != args.len() {
+            return Err(RunnerError::ArgumentsSizeMismatch {
+                expected: expected_arguments_size,
+                actual: args.len(),
+            });
+        }
+        let before_final_call = ctx.current_code_offset;
+        let final_call_size = 3;
+        let offset = final_call_size
+            + runner.get_casm_program().debug_info.sierra_statement_info[func.entry_point.0]
+                .code_offset;
+        casm_extend! {ctx,
+            call rel offset;
+            ret;
+        }
+        assert_eq!(before_final_call + final_call_size, ctx.current_code_offset);
+        Ok((ctx.instructions, vm_builtins.into_iter().map(|(_, name)| name).collect()))
+    }
+
+    fn create_code_footer(&self, _runner: &SierraCasmRunner) -> Vec<Instruction> {
+        casm! {
+            // Add a `ret` instruction used in libfuncs that retrieve the current value of the `fp`
+            // and `pc` registers.
+            ret;
+        }
+        .instructions
+    }
+}
+
+/// Returns the name the VM gives to the layout builtin with the given name.
+fn vm_builtin_name(name: &str) -> Result<BuiltinName, RunnerError> {
+    serde_json::from_value(serde_json::Value::from(name))
+        .map_err(|_| RunnerError::UnsupportedBuiltin(name.into()))
+}
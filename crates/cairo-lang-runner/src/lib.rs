@@ -4,22 +4,16 @@ use std::collections::HashMap;
 use cairo_felt::Felt252;
 use cairo_lang_casm::hints::Hint;
 use cairo_lang_casm::instructions::Instruction;
-use cairo_lang_casm::{casm, casm_extend};
-use cairo_lang_sierra::extensions::bitwise::BitwiseType;
 use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
-use cairo_lang_sierra::extensions::ec::EcOpType;
 use cairo_lang_sierra::extensions::enm::EnumType;
 use cairo_lang_sierra::extensions::gas::{CostTokenType, GasBuiltinType};
-use cairo_lang_sierra::extensions::pedersen::PedersenType;
-use cairo_lang_sierra::extensions::poseidon::PoseidonType;
-use cairo_lang_sierra::extensions::range_check::RangeCheckType;
-use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
-use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
 use cairo_lang_sierra::extensions::{ConcreteType, NamedType};
 use cairo_lang_sierra::program::{Function, GenericArg};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
 use cairo_lang_sierra_ap_change::{calc_ap_changes, ApChangeError};
 use cairo_lang_sierra_gas::gas_info::GasInfo;
+use cairo_lang_sierra_gas::CostError;
+use cairo_lang_sierra_to_casm::builtins::builtin_info;
 use cairo_lang_sierra_to_casm::compiler::{CairoProgram, CompilationError};
 use cairo_lang_sierra_to_casm::metadata::{
     calc_metadata, Metadata, MetadataComputationConfig, MetadataError,
@@ -33,13 +27,20 @@ use cairo_vm::serde::deserialize_program::{BuiltinName, HintParams};
 use cairo_vm::vm::errors::cairo_run_errors::CairoRunError;
 use cairo_vm::vm::runners::cairo_runner::RunResources;
 use casm_run::hint_to_hint_params;
-pub use casm_run::{CairoHintProcessor, StarknetState};
-use itertools::chain;
+pub use casm_run::{CairoHintProcessor, ForkError, ForkStateReader, StarknetState};
+use entry_code::{EntryCodeGenerator, StarknetEntryCodeGenerator};
+use num_bigint::BigInt;
 use num_traits::ToPrimitive;
+use oracle::{OracleHandler, Oracles};
 use thiserror::Error;
 
 pub mod casm_run;
+pub mod differential;
+pub mod entry_code;
+pub mod oracle;
+pub mod scenario;
 pub mod short_string;
+pub mod soundness;
 
 #[derive(Debug, Error)]
 pub enum RunnerError {
@@ -48,13 +49,15 @@ pub enum RunnerError {
     #[error("GasBuiltin is required while `available_gas` value is provided.")]
     GasBuiltinRequired,
     #[error(
-        "Failed calculating gas usage, it is likely a call for `gas::withdraw_gas` is missing."
+        "Failed calculating gas usage, it is likely a call for `gas::withdraw_gas` is missing: {0}."
     )]
-    FailedGasCalculation,
+    FailedGasCalculation(CostError),
     #[error("Function with suffix `{suffix}` to run not found.")]
     MissingFunction { suffix: String },
     #[error("Function expects arguments of size {expected} and received {actual} instead.")]
     ArgumentsSizeMismatch { expected: usize, actual: usize },
+    #[error("Builtin `{0}` is not supported by the VM.")]
+    UnsupportedBuiltin(String),
     #[error(transparent)]
     ProgramRegistryError(#[from] Box<ProgramRegistryError>),
     #[error(transparent)]
@@ -71,6 +74,8 @@ pub struct RunResultStarknet {
     pub memory: Vec<Option<Felt252>>,
     pub value: RunResultValue,
     pub starknet_state: StarknetState,
+    /// The number of times each instrumentation probe was hit, by the id of the probe.
+    pub instrumentation_hits: HashMap<usize, usize>,
 }
 
 /// The full result of a run.
@@ -128,6 +133,24 @@ pub fn build_hints_dict<'b>(
     (hints_dict, string_to_hint)
 }
 
+/// Builds hints_dict required in cairo_vm::types::program::Program from hints, given along with
+/// the offsets of their instructions in the bytecode.
+pub fn build_hints_dict_by_offsets(
+    hints: &[(usize, Vec<Hint>)],
+) -> (HashMap<usize, Vec<HintParams>>, HashMap<String, Hint>) {
+    let mut hints_dict: HashMap<usize, Vec<HintParams>> = HashMap::new();
+    let mut string_to_hint: HashMap<String, Hint> = HashMap::new();
+    for (offset, hints) in hints {
+        // Register hint with string for the hint processor.
+        for hint in hints {
+            string_to_hint.insert(hint.representing_string(), hint.clone());
+        }
+        // Add hint, associated with the instruction offset.
+        hints_dict.insert(*offset, hints.iter().map(hint_to_hint_params).collect());
+    }
+    (hints_dict, string_to_hint)
+}
+
 /// Runner enabling running a Sierra program on the vm.
 pub struct SierraCasmRunner {
     /// The sierra program.
@@ -143,6 +166,10 @@ pub struct SierraCasmRunner {
     #[allow(dead_code)]
     // Mapping from class_hash to contract info.
     starknet_contracts_info: OrderedHashMap<Felt252, ContractInfo>,
+    /// The generator of the code wrapping the casm program when running it.
+    entry_code_generator: Box<dyn EntryCodeGenerator>,
+    /// The implementations of the oracles called by the program.
+    oracle_handler: Box<dyn OracleHandler>,
 }
 impl SierraCasmRunner {
     pub fn new(
@@ -154,7 +181,9 @@ impl SierraCasmRunner {
         let metadata = create_metadata(&sierra_program, metadata_config)?;
         let sierra_program_registry =
             ProgramRegistry::<CoreType, CoreLibfunc>::new(&sierra_program)?;
-        let type_sizes = get_type_size_map(&sierra_program, &sierra_program_registry).unwrap();
+        let type_sizes =
+            get_type_size_map(&sierra_program, &sierra_program_registry, metadata.enum_zero_niche)
+                .unwrap();
         let casm_program = cairo_lang_sierra_to_casm::compiler::compile(
             &sierra_program,
             &metadata,
@@ -169,9 +198,27 @@ impl SierraCasmRunner {
             type_sizes,
             casm_program,
             starknet_contracts_info,
+            entry_code_generator: Box::new(StarknetEntryCodeGenerator),
+            oracle_handler: Box::<Oracles>::default(),
         })
     }
 
+    /// Sets the generator of the code wrapping the casm program when running it, for runtimes
+    /// other than Starknet.
+    pub fn with_entry_code_generator(
+        mut self,
+        entry_code_generator: Box<dyn EntryCodeGenerator>,
+    ) -> Self {
+        self.entry_code_generator = entry_code_generator;
+        self
+    }
+
+    /// Sets the implementations of the oracles called by the program.
+    pub fn with_oracle_handler(mut self, oracle_handler: Box<dyn OracleHandler>) -> Self {
+        self.oracle_handler = oracle_handler;
+        self
+    }
+
     /// Runs the vm starting from a function in the context of a given starknet state.
     pub fn run_function_with_starknet_context(
         &self,
@@ -183,41 +230,47 @@ impl SierraCasmRunner {
         let initial_gas = self.get_initial_available_gas(func, available_gas)?;
         let (entry_code, builtins) = self.create_entry_code(func, args, initial_gas)?;
         let footer = self.create_code_footer();
-        let instructions =
-            chain!(entry_code.iter(), self.casm_program.instructions.iter(), footer.iter());
-        let (hints_dict, string_to_hint) = build_hints_dict(instructions.clone());
+        let assembled_program = self.casm_program.assemble_ex(&entry_code, &footer);
+        let (hints_dict, string_to_hint) = build_hints_dict_by_offsets(&assembled_program.hints);
         let mut hint_processor = CairoHintProcessor {
             runner: Some(self),
             starknet_state,
             string_to_hint,
             run_resources: RunResources::default(),
+            instrumentation_hits: HashMap::new(),
         };
-        self.run_function(func, &mut hint_processor, hints_dict, instructions, builtins).map(|v| {
-            RunResultStarknet {
-                gas_counter: v.gas_counter,
-                memory: v.memory,
-                value: v.value,
-                starknet_state: hint_processor.starknet_state,
-            }
+        self.run_function(
+            func,
+            &mut hint_processor,
+            hints_dict,
+            assembled_program.bytecode.iter(),
+            builtins,
+        )
+        .map(|v| RunResultStarknet {
+            gas_counter: v.gas_counter,
+            memory: v.memory,
+            value: v.value,
+            starknet_state: hint_processor.starknet_state,
+            instrumentation_hits: hint_processor.instrumentation_hits,
         })
     }
 
     /// Runs the vm starting from a function with custom hint processor. Function may have
     /// implicits, but no other ref params. The cost of the function is deducted from
     /// available_gas before the execution begins.
-    pub fn run_function<'a, Instructions>(
+    pub fn run_function<'a, Bytecode>(
         &self,
         func: &Function,
         hint_processor: &mut dyn HintProcessor,
         hints_dict: HashMap<usize, Vec<HintParams>>,
-        instructions: Instructions,
+        bytecode: Bytecode,
         builtins: Vec<BuiltinName>,
     ) -> Result<RunResult, RunnerError>
     where
-        Instructions: Iterator<Item = &'a Instruction> + Clone,
+        Bytecode: Iterator<Item = &'a BigInt> + Clone,
     {
         let (cells, ap) = casm_run::run_function(
-            instructions,
+            bytecode,
             builtins,
             |context| {
                 let vm = context.vm;
@@ -251,13 +304,7 @@ impl SierraCasmRunner {
                 assert!(values.is_empty());
                 false
             } else {
-                *generic_ty != RangeCheckType::ID
-                    && *generic_ty != BitwiseType::ID
-                    && *generic_ty != EcOpType::ID
-                    && *generic_ty != PedersenType::ID
-                    && *generic_ty != PoseidonType::ID
-                    && *generic_ty != SystemType::ID
-                    && *generic_ty != SegmentArenaType::ID
+                builtin_info(generic_ty).is_none()
             }
         });
         assert!(results_data.len() <= 1);
@@ -340,7 +387,8 @@ impl SierraCasmRunner {
             .ok_or_else(|| RunnerError::MissingFunction { suffix: name_suffix.to_owned() })
     }
 
-    fn get_info(
+    /// Returns the type info of the given concrete type.
+    pub fn get_info(
         &self,
         ty: &cairo_lang_sierra::ids::ConcreteTypeId,
     ) -> &cairo_lang_sierra::extensions::types::TypeInfo {
@@ -355,125 +403,7 @@ impl SierraCasmRunner {
         args: &[Arg],
         initial_gas: usize,
     ) -> Result<(Vec<Instruction>, Vec<BuiltinName>), RunnerError> {
-        let mut arg_iter = args.iter().peekable();
-        let mut expected_arguments_size = 0;
-        let mut ctx = casm! {};
-        // The builtins in the formatting expected by the runner.
-        let builtins = vec![
-            BuiltinName::pedersen,
-            BuiltinName::range_check,
-            BuiltinName::bitwise,
-            BuiltinName::ec_op,
-            BuiltinName::poseidon,
-        ];
-        // The offset [fp - i] for each of this builtins in this configuration.
-        let builtin_offset: HashMap<cairo_lang_sierra::ids::GenericTypeId, i16> = HashMap::from([
-            (PedersenType::ID, 7),
-            (RangeCheckType::ID, 6),
-            (BitwiseType::ID, 5),
-            (EcOpType::ID, 4),
-            (PoseidonType::ID, 3),
-        ]);
-        // Load all vecs to memory.
-        let mut vecs = vec![];
-        let mut ap_offset: i16 = 0;
-        for arg in args {
-            let Arg::Array(values) = arg else { continue };
-            vecs.push(ap_offset);
-            casm_extend! {ctx,
-                %{ memory[ap + 0] = segments.add() %}
-                ap += 1;
-            }
-            for (i, v) in values.iter().enumerate() {
-                let arr_at = (i + 1) as i16;
-                casm_extend! {ctx,
-                    [ap + 0] = (v.to_bigint());
-                    [ap + 0] = [[ap - arr_at] + (i as i16)], ap++;
-                };
-            }
-            ap_offset += (1 + values.len()) as i16;
-        }
-        let after_vecs_offset = ap_offset;
-        if func
-            .signature
-            .param_types
-            .iter()
-            .any(|ty| self.get_info(ty).long_id.generic_id == SegmentArenaType::ID)
-        {
-            casm_extend! {ctx,
-                // SegmentArena segment.
-                %{ memory[ap + 0] = segments.add() %}
-                // Infos segment.
-                %{ memory[ap + 1] = segments.add() %}
-                ap += 2;
-                [ap + 0] = 0, ap++;
-                // Write Infos segment, n_constructed (0), and n_destructed (0) to the segment.
-                [ap - 2] = [[ap - 3]];
-                [ap - 1] = [[ap - 3] + 1];
-                [ap - 1] = [[ap - 3] + 2];
-            }
-            ap_offset += 3;
-        }
-        for ty in func.signature.param_types.iter() {
-            let info = self.get_info(ty);
-            let ty_size = self.type_sizes[ty];
-            let generic_ty = &info.long_id.generic_id;
-            if let Some(offset) = builtin_offset.get(generic_ty) {
-                casm_extend! {ctx,
-                    [ap + 0] = [fp - offset], ap++;
-                }
-            } else if generic_ty == &SystemType::ID {
-                casm_extend! {ctx,
-                    %{ memory[ap + 0] = segments.add() %}
-                    ap += 1;
-                }
-            } else if generic_ty == &GasBuiltinType::ID {
-                casm_extend! {ctx,
-                    [ap + 0] = initial_gas, ap++;
-                }
-            } else if generic_ty == &SegmentArenaType::ID {
-                let offset = -ap_offset + after_vecs_offset;
-                casm_extend! {ctx,
-                    [ap + 0] = [ap + offset] + 3, ap++;
-                }
-            } else if let Some(Arg::Array(_)) = arg_iter.peek() {
-                let values = extract_matches!(arg_iter.next().unwrap(), Arg::Array);
-                let offset = -ap_offset + vecs.pop().unwrap();
-                expected_arguments_size += 1;
-                casm_extend! {ctx,
-                    [ap + 0] = [ap + (offset)], ap++;
-                    [ap + 0] = [ap - 1] + (values.len()), ap++;
-                }
-            } else {
-                let arg_size = ty_size;
-                expected_arguments_size += arg_size as usize;
-                for _ in 0..arg_size {
-                    if let Some(value) = arg_iter.next() {
-                        let value = extract_matches!(value, Arg::Value);
-                        casm_extend! {ctx,
-                            [ap + 0] = (value.to_bigint()), ap++;
-                        }
-                    }
-                }
-            };
-            ap_offset += ty_size;
-        }
-        if expected_arguments_size != args.len() {
-            return Err(RunnerError::ArgumentsSizeMismatch {
-                expected: expected_arguments_size,
-                actual: args.len(),
-            });
-        }
-        let before_final_call = ctx.current_code_offset;
-        let final_call_size = 3;
-        let offset = final_call_size
-            + self.casm_program.debug_info.sierra_statement_info[func.entry_point.0].code_offset;
-        casm_extend! {ctx,
-            call rel offset;
-            ret;
-        }
-        assert_eq!(before_final_call + final_call_size, ctx.current_code_offset);
-        Ok((ctx.instructions, builtins))
+        self.entry_code_generator.create_entry_code(self, func, args, initial_gas)
     }
 
     /// Returns the initial value for the gas counter.
@@ -509,17 +439,16 @@ impl SierraCasmRunner {
 
     /// Creates a list of instructions that will be appended to the program's bytecode.
     pub fn create_code_footer(&self) -> Vec<Instruction> {
-        casm! {
-            // Add a `ret` instruction used in libfuncs that retrieve the current value of the `fp`
-            // and `pc` registers.
-            ret;
-        }
-        .instructions
+        self.entry_code_generator.create_code_footer(self)
     }
 
     pub fn get_casm_program(&self) -> &CairoProgram {
         &self.casm_program
     }
+
+    pub fn get_type_sizes(&self) -> &TypeSizeMap {
+        &self.type_sizes
+    }
 }
 
 /// Creates the metadata required for a Sierra program lowering to casm.
@@ -530,15 +459,18 @@ fn create_metadata(
     if let Some(metadata_config) = metadata_config {
         calc_metadata(sierra_program, metadata_config).map_err(|err| match err {
             MetadataError::ApChangeError(err) => RunnerError::ApChangeError(err),
-            MetadataError::CostError(_) => RunnerError::FailedGasCalculation,
+            MetadataError::CostError(err) => RunnerError::FailedGasCalculation(err),
         })
     } else {
         Ok(Metadata {
-            ap_change_info: calc_ap_changes(sierra_program, |_, _| 0)?,
+            ap_change_info: calc_ap_changes(sierra_program, false, false, |_, _| 0)?,
             gas_info: GasInfo {
                 variable_values: Default::default(),
                 function_costs: Default::default(),
             },
+            gas_solving_timings: vec![],
+            software_ec_op: false,
+            enum_zero_niche: false,
         })
     }
 }
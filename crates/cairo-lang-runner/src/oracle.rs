@@ -0,0 +1,44 @@
+//! Runner-side implementations of the oracles called by Cairo programs.
+//!
+//! An oracle is a computation done outside of the program (such as a division witness, a sorting
+//! permutation or off-chain data), declared in Cairo with an `#[oracle]` extern function. Its
+//! output is nondeterministic, so the program must verify it before relying on it.
+
+use std::collections::HashMap;
+
+use cairo_felt::Felt252;
+
+/// Implements the oracles called by a program.
+pub trait OracleHandler: Send + Sync {
+    /// Returns the serialized output of the oracle with the given selector (the name of its
+    /// declaration) for the serialized `input`, or a message describing why the call failed.
+    fn call(&self, selector: &str, input: &[Felt252]) -> Result<Vec<Felt252>, String>;
+}
+
+/// The implementation of a single oracle - from its serialized input to its serialized output.
+pub type OracleFn = Box<dyn Fn(&[Felt252]) -> Result<Vec<Felt252>, String> + Send + Sync>;
+
+/// An [OracleHandler] dispatching the calls to the oracle functions registered by their
+/// selectors.
+#[derive(Default)]
+pub struct Oracles {
+    oracles: HashMap<String, OracleFn>,
+}
+impl Oracles {
+    /// Registers the implementation of the oracle with the given selector.
+    pub fn with(
+        mut self,
+        selector: impl Into<String>,
+        oracle: impl Fn(&[Felt252]) -> Result<Vec<Felt252>, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.oracles.insert(selector.into(), Box::new(oracle));
+        self
+    }
+}
+impl OracleHandler for Oracles {
+    fn call(&self, selector: &str, input: &[Felt252]) -> Result<Vec<Felt252>, String> {
+        let oracle =
+            self.oracles.get(selector).ok_or_else(|| format!("Unknown oracle `{selector}`."))?;
+        oracle(input)
+    }
+}
@@ -0,0 +1,310 @@
+//! Scripted multi-step scenarios - declaring classes, deploying and invoking contracts and
+//! asserting on their storage - executed against the simulated Starknet state.
+//!
+//! Scenarios may be built in Rust, or parsed from JSON, e.g.:
+//! ```json
+//! {"steps": [
+//!     {"step": "deploy", "name": "token", "class_hash": "0x1234", "calldata": ["0x1"]},
+//!     {
+//!         "step": "invoke",
+//!         "contract": "token",
+//!         "entry_point": "get_name",
+//!         "expected": {"returns": ["0x1"]}
+//!     },
+//!     {"step": "assert_storage", "contract": "token", "key": "0x5678", "value": "0x1"}
+//! ]}
+//! ```
+
+use cairo_felt::Felt252;
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_starknet::contract::{starknet_keccak, ContractInfo};
+use cairo_lang_utils::bigint::BigUintAsHex;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::casm_run::read_array_result_as_vec;
+use crate::short_string::as_cairo_short_string;
+use crate::{Arg, ForkError, RunResultValue, RunnerError, SierraCasmRunner, StarknetState};
+
+#[cfg(test)]
+#[path = "scenario_test.rs"]
+mod test;
+
+/// A scenario of steps, executed one after the other.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Scenario {
+    pub steps: Vec<ScenarioStep>,
+}
+impl Scenario {
+    /// Parses a scenario from its JSON representation.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
+
+/// A single step of a scenario.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum ScenarioStep {
+    /// Checks that the class is known to the runner, and may therefore be deployed.
+    Declare { class_hash: BigUintAsHex },
+    /// Deploys a contract of the class, calling its constructor with `calldata`.
+    /// Later steps refer to the deployed contract by `name`.
+    Deploy {
+        name: String,
+        class_hash: BigUintAsHex,
+        #[serde(default)]
+        calldata: Vec<BigUintAsHex>,
+    },
+    /// Invokes an external function of a deployed contract.
+    Invoke {
+        contract: String,
+        entry_point: String,
+        #[serde(default)]
+        calldata: Vec<BigUintAsHex>,
+        #[serde(default)]
+        expected: ExpectedOutcome,
+    },
+    /// Asserts the value at `key` in the storage of a deployed contract.
+    AssertStorage { contract: String, key: BigUintAsHex, value: BigUintAsHex },
+}
+
+/// The expected outcome of an invocation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpectedOutcome {
+    /// The invocation succeeds, regardless of the returned data.
+    #[default]
+    Success,
+    /// The invocation succeeds, returning exactly the given data.
+    Returns(Vec<BigUintAsHex>),
+    /// The invocation reverts with exactly the given reason.
+    Reverts(Vec<BigUintAsHex>),
+}
+
+/// An error in executing a scenario step.
+#[derive(Debug, Error)]
+pub enum StepError {
+    #[error("Class {0} is not known to the runner.")]
+    UnknownClass(Felt252),
+    #[error("A contract named `{0}` was already deployed.")]
+    DuplicateContract(String),
+    #[error("No contract named `{0}` was deployed.")]
+    UnknownContract(String),
+    #[error("Contract `{contract}` has no external function `{entry_point}`.")]
+    UnknownEntryPoint { contract: String, entry_point: String },
+    #[error("Constructor reverted with {}.", format_felts(.0))]
+    ConstructorFailed(Vec<Felt252>),
+    #[error("Expected the invocation to {expected}, but it {actual}.")]
+    UnexpectedOutcome { expected: String, actual: String },
+    #[error("Expected storage value {expected}, found {actual}.")]
+    StorageMismatch { expected: Felt252, actual: Felt252 },
+    #[error(transparent)]
+    Runner(#[from] RunnerError),
+    #[error(transparent)]
+    Fork(#[from] ForkError),
+}
+
+/// An error in executing a scenario, with the index of the failing step.
+#[derive(Debug, Error)]
+#[error("Scenario step #{step_idx} failed: {error}")]
+pub struct ScenarioError {
+    pub step_idx: usize,
+    pub error: StepError,
+}
+
+/// The result of a successfully executed scenario.
+pub struct ScenarioResult {
+    /// The addresses of the deployed contracts, by name.
+    pub contracts: OrderedHashMap<String, Felt252>,
+    /// The Starknet state at the end of the scenario.
+    pub starknet_state: StarknetState,
+}
+
+impl SierraCasmRunner {
+    /// Executes the steps of `scenario` one after the other, starting from `starknet_state`.
+    /// Every constructor and invocation is run with `available_gas`.
+    pub fn run_scenario(
+        &self,
+        scenario: &Scenario,
+        starknet_state: StarknetState,
+        available_gas: Option<usize>,
+    ) -> Result<ScenarioResult, ScenarioError> {
+        let mut executor = ScenarioExecutor {
+            runner: self,
+            available_gas,
+            contracts: Default::default(),
+            starknet_state,
+        };
+        for (step_idx, step) in scenario.steps.iter().enumerate() {
+            executor.run_step(step).map_err(|error| ScenarioError { step_idx, error })?;
+        }
+        Ok(ScenarioResult {
+            contracts: executor.contracts,
+            starknet_state: executor.starknet_state,
+        })
+    }
+}
+
+/// The state of a scenario in execution.
+struct ScenarioExecutor<'a> {
+    runner: &'a SierraCasmRunner,
+    available_gas: Option<usize>,
+    /// The addresses of the contracts deployed so far, by name.
+    contracts: OrderedHashMap<String, Felt252>,
+    starknet_state: StarknetState,
+}
+impl<'a> ScenarioExecutor<'a> {
+    /// Executes a single step of the scenario.
+    fn run_step(&mut self, step: &ScenarioStep) -> Result<(), StepError> {
+        match step {
+            ScenarioStep::Declare { class_hash } => {
+                self.contract_info(&to_felt252(class_hash))?;
+            }
+            ScenarioStep::Deploy { name, class_hash, calldata } => {
+                if self.contracts.contains_key(name) {
+                    return Err(StepError::DuplicateContract(name.clone()));
+                }
+                let class_hash = to_felt252(class_hash);
+                let contract_info = self.contract_info(&class_hash)?;
+                // Assign an arbitrary address to the contract, as done by the deploy syscall.
+                let contract_address = self.starknet_state.get_next_id();
+                if let Some(constructor) = &contract_info.constructor {
+                    self.call_entry_point(&contract_address, constructor, calldata)?
+                        .map_err(StepError::ConstructorFailed)?;
+                }
+                self.starknet_state.set_deployed_class_hash(contract_address.clone(), class_hash);
+                self.contracts.insert(name.clone(), contract_address);
+            }
+            ScenarioStep::Invoke { contract, entry_point, calldata, expected } => {
+                let contract_address = self.contract_address(contract)?;
+                let class_hash = self
+                    .starknet_state
+                    .deployed_class_hash(&contract_address)
+                    .expect("Contracts deployed by the scenario are registered in the state.")
+                    .clone();
+                let selector = Felt252::from(starknet_keccak(entry_point.as_bytes()));
+                let Some(function_id) = self.contract_info(&class_hash)?.externals.get(&selector)
+                else {
+                    return Err(StepError::UnknownEntryPoint {
+                        contract: contract.clone(),
+                        entry_point: entry_point.clone(),
+                    });
+                };
+                let outcome = self.call_entry_point(&contract_address, function_id, calldata)?;
+                check_outcome(expected, outcome)?;
+            }
+            ScenarioStep::AssertStorage { contract, key, value } => {
+                let contract_address = self.contract_address(contract)?;
+                let actual =
+                    self.starknet_state.read_storage(&contract_address, &to_felt252(key))?;
+                let expected = to_felt252(value);
+                if actual != expected {
+                    return Err(StepError::StorageMismatch { expected, actual });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the info of the class with the given hash.
+    fn contract_info(&self, class_hash: &Felt252) -> Result<&'a ContractInfo, StepError> {
+        self.runner
+            .starknet_contracts_info
+            .get(class_hash)
+            .ok_or_else(|| StepError::UnknownClass(class_hash.clone()))
+    }
+
+    /// Returns the address of the contract deployed under `name`.
+    fn contract_address(&self, name: &str) -> Result<Felt252, StepError> {
+        self.contracts.get(name).cloned().ok_or_else(|| StepError::UnknownContract(name.into()))
+    }
+
+    /// Calls an entry point in the context of the contract at `contract_address`, returning its
+    /// return data, or its revert reason if it panicked.
+    /// The changes to the state are only kept if the call succeeded.
+    fn call_entry_point(
+        &mut self,
+        contract_address: &Felt252,
+        entry_point: &FunctionId,
+        calldata: &[BigUintAsHex],
+    ) -> Result<Result<Vec<Felt252>, Vec<Felt252>>, StepError> {
+        let function = self
+            .runner
+            .sierra_program_registry
+            .get_function(entry_point)
+            .expect("Entrypoint exists, but not found.");
+        let mut starknet_state = self.starknet_state.clone();
+        let caller_contract_address =
+            starknet_state.replace_contract_address(contract_address.clone());
+        let calldata = calldata.iter().map(to_felt252).collect();
+        let res = self.runner.run_function_with_starknet_context(
+            function,
+            &[Arg::Array(calldata)],
+            self.available_gas,
+            starknet_state,
+        )?;
+        Ok(match res.value {
+            RunResultValue::Success(value) => {
+                self.starknet_state = res.starknet_state;
+                self.starknet_state.replace_contract_address(caller_contract_address);
+                Ok(read_array_result_as_vec(&res.memory, &value))
+            }
+            RunResultValue::Panic(revert_reason) => Err(revert_reason),
+        })
+    }
+}
+
+/// Checks the outcome of an invocation - its return data or revert reason - against the expected
+/// one.
+fn check_outcome(
+    expected: &ExpectedOutcome,
+    actual: Result<Vec<Felt252>, Vec<Felt252>>,
+) -> Result<(), StepError> {
+    let matches = match (expected, &actual) {
+        (ExpectedOutcome::Success, Ok(_)) => true,
+        (ExpectedOutcome::Returns(expected), Ok(actual))
+        | (ExpectedOutcome::Reverts(expected), Err(actual)) => {
+            expected.iter().map(to_felt252).eq(actual.iter().cloned())
+        }
+        _ => false,
+    };
+    if matches {
+        return Ok(());
+    }
+    Err(StepError::UnexpectedOutcome {
+        expected: match expected {
+            ExpectedOutcome::Success => "succeed".into(),
+            ExpectedOutcome::Returns(data) => {
+                format!("return {}", format_felts(&data.iter().map(to_felt252).collect_vec()))
+            }
+            ExpectedOutcome::Reverts(data) => {
+                format!("revert with {}", format_felts(&data.iter().map(to_felt252).collect_vec()))
+            }
+        },
+        actual: match actual {
+            Ok(data) => format!("returned {}", format_felts(&data)),
+            Err(data) => format!("reverted with {}", format_felts(&data)),
+        },
+    })
+}
+
+/// Formats a list of felts for display, showing printable short strings as such.
+fn format_felts(felts: &[Felt252]) -> String {
+    let formatted = felts.iter().map(|felt| match as_cairo_short_string(felt) {
+        Some(as_string)
+            if !as_string.is_empty()
+                && as_string.chars().all(|c| c.is_ascii_graphic() || c == ' ') =>
+        {
+            format!("'{as_string}'")
+        }
+        _ => felt.to_string(),
+    });
+    format!("[{}]", formatted.format(", "))
+}
+
+fn to_felt252(value: &BigUintAsHex) -> Felt252 {
+    Felt252::from(value.value.clone())
+}
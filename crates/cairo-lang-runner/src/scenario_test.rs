@@ -0,0 +1,176 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use cairo_felt::Felt252;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::setup_project;
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
+use cairo_lang_starknet::contract::{get_contracts_info, starknet_keccak};
+use cairo_lang_starknet::plugin::StarkNetPlugin;
+use cairo_lang_utils::bigint::BigUintAsHex;
+use num_bigint::BigUint;
+use test_case::test_case;
+
+use super::{ExpectedOutcome, Scenario, ScenarioStep};
+use crate::{SierraCasmRunner, StarknetState};
+
+const AVAILABLE_GAS: Option<usize> = Some(10000000);
+
+/// Returns a runner for the ERC20 example contract, along with its class hash.
+fn erc20_runner() -> (SierraCasmRunner, Felt252) {
+    let path: PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "..", "cairo-lang-starknet", "test_data", "erc20.cairo"]
+            .iter()
+            .collect();
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_cfg(CfgSet::from_iter([Cfg::name("test")]))
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let main_crate_ids = setup_project(db, &path).unwrap();
+    let sierra_program = db.get_sierra_program(main_crate_ids.clone()).to_option().unwrap();
+    let replacer = DebugReplacer { db };
+    let contracts_info = get_contracts_info(db, main_crate_ids, &replacer).unwrap();
+    let class_hash = contracts_info.keys().next().unwrap().clone();
+    let runner = SierraCasmRunner::new(
+        replacer.apply(&sierra_program),
+        Some(Default::default()),
+        contracts_info,
+    )
+    .unwrap();
+    (runner, class_hash)
+}
+
+fn hex(value: impl Into<BigUint>) -> BigUintAsHex {
+    BigUintAsHex { value: value.into() }
+}
+
+fn short_string(value: &str) -> BigUintAsHex {
+    hex(BigUint::from_bytes_be(value.as_bytes()))
+}
+
+fn deploy_token(class_hash: &Felt252, recipient: u32) -> ScenarioStep {
+    ScenarioStep::Deploy {
+        name: "token".into(),
+        class_hash: hex(class_hash.to_biguint()),
+        calldata: vec![
+            short_string("TKN"),
+            short_string("TKN"),
+            hex(18_u32),
+            hex(1000_u32),
+            hex(0_u32),
+            hex(recipient),
+        ],
+    }
+}
+
+#[test]
+fn test_json_scenario() {
+    let (runner, class_hash) = erc20_runner();
+    let scenario = Scenario::from_json(&format!(
+        r#"{{"steps": [
+            {{"step": "declare", "class_hash": "{class_hash:#x}"}},
+            {{
+                "step": "deploy",
+                "name": "token",
+                "class_hash": "{class_hash:#x}",
+                "calldata": ["0x544b4e", "0x544b4e", "0x12", "0x3e8", "0x0", "0x10"]
+            }},
+            {{
+                "step": "invoke",
+                "contract": "token",
+                "entry_point": "get_total_supply",
+                "expected": {{"returns": ["0x3e8", "0x0"]}}
+            }},
+            {{
+                "step": "invoke",
+                "contract": "token",
+                "entry_point": "transfer",
+                "calldata": ["0x11", "0x1", "0x0"],
+                "expected": {{"reverts": ["{transfer_from_zero:#x}"]}}
+            }},
+            {{
+                "step": "assert_storage",
+                "contract": "token",
+                "key": "{name_key:#x}",
+                "value": "0x544b4e"
+            }}
+        ]}}"#,
+        class_hash = class_hash.to_biguint(),
+        transfer_from_zero = BigUint::from_bytes_be(b"ERC20: transfer from 0"),
+        name_key = starknet_keccak(b"name"),
+    ))
+    .unwrap();
+    let result = runner.run_scenario(&scenario, StarknetState::default(), AVAILABLE_GAS).unwrap();
+    let token_address = &result.contracts["token"];
+    assert_eq!(result.starknet_state.deployed_class_hash(token_address), Some(&class_hash));
+}
+
+#[test_case(
+    0,
+    vec![],
+    "Scenario step #0 failed: Constructor reverted with ['ERC20: mint to the 0 address'].";
+    "constructor failure"
+)]
+#[test_case(
+    16,
+    vec![ScenarioStep::Declare { class_hash: hex(1_u32) }],
+    "Scenario step #1 failed: Class 1 is not known to the runner.";
+    "unknown class"
+)]
+#[test_case(
+    16,
+    vec![ScenarioStep::AssertStorage {
+        contract: "other".into(),
+        key: hex(starknet_keccak(b"name")),
+        value: short_string("TKN"),
+    }],
+    "Scenario step #1 failed: No contract named `other` was deployed.";
+    "unknown contract"
+)]
+#[test_case(
+    16,
+    vec![ScenarioStep::Invoke {
+        contract: "token".into(),
+        entry_point: "mint".into(),
+        calldata: vec![],
+        expected: ExpectedOutcome::Success,
+    }],
+    "Scenario step #1 failed: Contract `token` has no external function `mint`.";
+    "unknown entry point"
+)]
+#[test_case(
+    16,
+    vec![ScenarioStep::Invoke {
+        contract: "token".into(),
+        entry_point: "get_total_supply".into(),
+        calldata: vec![],
+        expected: ExpectedOutcome::Reverts(vec![]),
+    }],
+    "Scenario step #1 failed: Expected the invocation to revert with [], but it returned [1000, \
+     0].";
+    "unexpected outcome"
+)]
+#[test_case(
+    16,
+    vec![ScenarioStep::AssertStorage {
+        contract: "token".into(),
+        key: hex(starknet_keccak(b"name")),
+        value: short_string("ABC"),
+    }],
+    "Scenario step #1 failed: Expected storage value 4276803, found 5524302.";
+    "storage mismatch"
+)]
+fn test_failing_scenario(recipient: u32, steps: Vec<ScenarioStep>, expected_error: &str) {
+    let (runner, class_hash) = erc20_runner();
+    let steps = [deploy_token(&class_hash, recipient)].into_iter().chain(steps).collect();
+    let error = runner
+        .run_scenario(&Scenario { steps }, StarknetState::default(), AVAILABLE_GAS)
+        .err()
+        .unwrap();
+    assert_eq!(error.to_string(), expected_error);
+}
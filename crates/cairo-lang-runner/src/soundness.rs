@@ -0,0 +1,211 @@
+//! Soundness checking of the hints of a program, by perturbing their results and making sure the
+//! code verifying them rejects the perturbed values.
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use cairo_felt::Felt252;
+use cairo_lang_casm::hints::Hint;
+use cairo_lang_sierra::program::Function;
+use cairo_vm::hint_processor::hint_processor_definition::{HintProcessorLogic, HintReference};
+use cairo_vm::serde::deserialize_program::ApTracking;
+use cairo_vm::types::exec_scope::ExecutionScopes;
+use cairo_vm::types::relocatable::MaybeRelocatable;
+use cairo_vm::vm::errors::hint_errors::HintError;
+use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
+use cairo_vm::vm::runners::cairo_runner::{ResourceTracker, RunResources};
+use cairo_vm::vm::vm_core::VirtualMachine;
+
+use crate::{
+    build_hints_dict_by_offsets, Arg, CairoHintProcessor, RunResult, RunResultValue, RunnerError,
+    SierraCasmRunner,
+};
+
+/// The number of cells from `ap` onwards that are checked for being written by a hint.
+/// Hints write their results to cells that are yet to be allocated, so the results of all the
+/// hints generated by the compiler are in this range.
+const HINT_OUTPUT_WINDOW: usize = 32;
+
+/// The integer cells written by a single hint execution.
+#[derive(Clone, Debug)]
+struct HintOutputs {
+    /// The executed hint.
+    hint: Hint,
+    /// The written cells, as offsets from `ap` at the time of the execution, and their values.
+    cells: Vec<(usize, Felt252)>,
+}
+
+/// A perturbation of the result of a single hint execution.
+struct Perturbation {
+    /// The index of the perturbed hint execution.
+    execution_idx: usize,
+    /// The cells written by the perturbed execution in the honest run.
+    outputs: HintOutputs,
+    /// The index in `outputs.cells` of the perturbed cell.
+    cell_idx: usize,
+}
+
+/// A perturbation of a hint result that was accepted, while changing the result of the run.
+#[derive(Debug)]
+pub struct UnsoundHint {
+    /// The hint whose result was perturbed.
+    pub hint: Hint,
+    /// The index of the hint execution in the honest run.
+    pub execution_idx: usize,
+    /// The perturbed cell, as an offset from `ap` at the time of the hint execution.
+    pub ap_offset: usize,
+    /// The value returned by the run with the perturbed hint result.
+    pub value: RunResultValue,
+    /// The gas counter at the end of the run with the perturbed hint result.
+    pub gas_counter: Option<Felt252>,
+}
+
+/// HintProcessor recording the results of the executed hints, and optionally replacing the result
+/// of one of them with a perturbed one.
+struct PerturbingHintProcessor<'a> {
+    /// The hint processor actually executing the hints.
+    inner: CairoHintProcessor<'a>,
+    /// The perturbation to apply during the run, if any.
+    perturbation: Option<Perturbation>,
+    /// The outputs of the hints executed so far.
+    executions: Vec<HintOutputs>,
+}
+
+impl HintProcessorLogic for PerturbingHintProcessor<'_> {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, Felt252>,
+    ) -> Result<(), HintError> {
+        let hint = hint_data.downcast_ref::<Hint>().unwrap();
+        let ap = vm.get_ap();
+        if let Some(perturbation) = &self.perturbation {
+            if perturbation.execution_idx == self.executions.len() {
+                // Replaying the honest result of the hint, with a single cell perturbed, instead
+                // of executing it.
+                for (i, (offset, value)) in perturbation.outputs.cells.iter().enumerate() {
+                    let value =
+                        if i == perturbation.cell_idx { value + 1_usize } else { value.clone() };
+                    vm.insert_value((ap + *offset)?, value)?;
+                }
+                self.executions.push(perturbation.outputs.clone());
+                return Ok(());
+            }
+        }
+        let unset_offsets = (0..HINT_OUTPUT_WINDOW)
+            .filter(|offset| matches!(ap + *offset, Ok(addr) if vm.get_maybe(&addr).is_none()))
+            .collect::<Vec<_>>();
+        self.inner.execute_hint(vm, exec_scopes, hint_data, constants)?;
+        let cells = unset_offsets
+            .into_iter()
+            .filter_map(|offset| match vm.get_maybe(&(ap + offset).ok()?)? {
+                MaybeRelocatable::Int(value) => Some((offset, value)),
+                MaybeRelocatable::RelocatableValue(_) => None,
+            })
+            .collect();
+        self.executions.push(HintOutputs { hint: hint.clone(), cells });
+        Ok(())
+    }
+
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        ap_tracking_data: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &[HintReference],
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        self.inner.compile_hint(hint_code, ap_tracking_data, reference_ids, references)
+    }
+}
+
+impl ResourceTracker for PerturbingHintProcessor<'_> {
+    fn consumed(&self) -> bool {
+        self.inner.consumed()
+    }
+
+    fn consume_step(&mut self) {
+        self.inner.consume_step()
+    }
+
+    fn get_n_steps(&self) -> Option<usize> {
+        self.inner.get_n_steps()
+    }
+
+    fn run_resources(&self) -> &RunResources {
+        self.inner.run_resources()
+    }
+}
+
+impl SierraCasmRunner {
+    /// Checks that the code verifying the hints executed when running `func` rejects perturbed
+    /// hint results.
+    ///
+    /// The function is first run honestly, and then once for every integer cell written by every
+    /// executed hint, with the value of that cell incremented. A run with a perturbed hint result
+    /// must either fail or end with the same value and gas counter as the honest run - the
+    /// perturbations that don't are returned.
+    pub fn find_unsound_hints(
+        &self,
+        func: &Function,
+        args: &[Arg],
+        available_gas: Option<usize>,
+    ) -> Result<Vec<UnsoundHint>, RunnerError> {
+        let initial_gas = self.get_initial_available_gas(func, available_gas)?;
+        let (entry_code, builtins) = self.create_entry_code(func, args, initial_gas)?;
+        let footer = self.create_code_footer();
+        let assembled_program = self.casm_program.assemble_ex(&entry_code, &footer);
+        let run = |perturbation: Option<Perturbation>| -> (Result<RunResult, RunnerError>, _) {
+            let (hints_dict, string_to_hint) =
+                build_hints_dict_by_offsets(&assembled_program.hints);
+            let mut hint_processor = PerturbingHintProcessor {
+                inner: CairoHintProcessor {
+                    runner: Some(self),
+                    starknet_state: Default::default(),
+                    string_to_hint,
+                    run_resources: RunResources::default(),
+                    instrumentation_hits: Default::default(),
+                },
+                perturbation,
+                executions: vec![],
+            };
+            let result = self.run_function(
+                func,
+                &mut hint_processor,
+                hints_dict,
+                assembled_program.bytecode.iter(),
+                builtins.clone(),
+            );
+            (result, hint_processor.executions)
+        };
+        let (honest_result, executions) = run(None);
+        let honest_result = honest_result?;
+        let mut unsound_hints = vec![];
+        for (execution_idx, outputs) in executions.into_iter().enumerate() {
+            for cell_idx in 0..outputs.cells.len() {
+                let ap_offset = outputs.cells[cell_idx].0;
+                let hint = outputs.hint.clone();
+                let perturbation =
+                    Perturbation { execution_idx, outputs: outputs.clone(), cell_idx };
+                let result = match run(Some(perturbation)).0 {
+                    Ok(result) => result,
+                    // A failing run means the perturbation was rejected.
+                    Err(_) => continue,
+                };
+                if result.value != honest_result.value
+                    || result.gas_counter != honest_result.gas_counter
+                {
+                    unsound_hints.push(UnsoundHint {
+                        hint,
+                        execution_idx,
+                        ap_offset,
+                        value: result.value,
+                        gas_counter: result.gas_counter,
+                    });
+                }
+            }
+        }
+        Ok(unsound_hints)
+    }
+}
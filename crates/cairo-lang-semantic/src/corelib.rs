@@ -637,6 +637,8 @@ pub fn get_const_libfunc_name_by_type(db: &dyn SemanticGroup, ty: TypeId) -> Str
         "u32_const".into()
     } else if ty == get_core_ty_by_name(db, "u64".into(), vec![]) {
         "u64_const".into()
+    } else if ty == get_core_ty_by_name(db, "u96".into(), vec![]) {
+        "u96_const".into()
     } else if ty == get_core_ty_by_name(db, "u128".into(), vec![]) {
         "u128_const".into()
     } else if ty == get_core_ty_by_name(db, "i8".into(), vec![]) {
@@ -677,6 +679,8 @@ pub fn validate_literal(
         value.to_u32().is_none()
     } else if ty == get_core_ty_by_name(db, "u64".into(), vec![]) {
         value.to_u64().is_none()
+    } else if ty == get_core_ty_by_name(db, "u96".into(), vec![]) {
+        value.is_negative() || value.bits() > 96
     } else if ty == get_core_ty_by_name(db, "u128".into(), vec![]) {
         value.to_u128().is_none()
     } else if ty == get_core_ty_by_name(db, "u256".into(), vec![]) {
@@ -765,6 +765,18 @@ pub trait SemanticGroup:
         &self,
         function_id: ExternFunctionId,
     ) -> Maybe<items::functions::FunctionDeclarationData>;
+    /// Private query to compute the inline CASM code implementing an extern function, if any.
+    #[salsa::invoke(items::extern_function::priv_extern_function_inline_casm_data)]
+    fn priv_extern_function_inline_casm_data(
+        &self,
+        function_id: ExternFunctionId,
+    ) -> Maybe<items::extern_function::ExternFunctionInlineCasmData>;
+    /// Returns the inline CASM code implementing an extern function, if any.
+    #[salsa::invoke(items::extern_function::extern_function_inline_casm)]
+    fn extern_function_inline_casm(
+        &self,
+        function_id: ExternFunctionId,
+    ) -> Maybe<Option<items::extern_function::InlineCasm>>;
     /// Returns the inline configuration of an extern function's declaration.
     #[salsa::invoke(items::extern_function::extern_function_declaration_inline_config)]
     fn extern_function_declaration_inline_config(
@@ -17,6 +17,7 @@ use smol_str::SmolStr;
 
 use crate::db::SemanticGroup;
 use crate::expr::inference::InferenceError;
+use crate::items::function_with_body::UNCHECKED_MATH_LINT;
 use crate::items::imp::UninferredImpl;
 use crate::plugin::PluginMappedDiagnostic;
 use crate::resolve::ResolvedConcreteItem;
@@ -567,6 +568,40 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::InlineAttrForExternFunctionNotAllowed => {
                 "`inline` attribute is not allowed for extern functions.".into()
             }
+            SemanticDiagnosticKind::UnsupportedInlineCasmArguments => {
+                "Unsupported `unsafe_inline_casm` arguments. Expected `code: '<code>', ap_change: \
+                 <number>`."
+                    .into()
+            }
+            SemanticDiagnosticKind::InlineCasmUnsupportedSignature => {
+                "Extern functions with inline CASM must only take and return felt252 values, \
+                 without generic parameters or implicits."
+                    .into()
+            }
+            SemanticDiagnosticKind::InvalidInlineCasmCode { error } => {
+                format!("Invalid inline CASM code: {error}")
+            }
+            SemanticDiagnosticKind::InlineCasmApChangeMismatch { declared, actual } => {
+                format!(
+                    "The ap change of the inline CASM code is {actual}, but {declared} was \
+                     declared."
+                )
+            }
+            SemanticDiagnosticKind::InlineCasmOutputsNotAllocated { num_outputs } => {
+                format!(
+                    "The inline CASM code must allocate at least {num_outputs} cells for its \
+                     outputs."
+                )
+            }
+            SemanticDiagnosticKind::UnsupportedBranchHintArguments => {
+                "`likely` and `unlikely` attributes take no arguments.".into()
+            }
+            SemanticDiagnosticKind::ConflictingBranchHints => {
+                "A match arm can't be both `likely` and `unlikely`.".into()
+            }
+            SemanticDiagnosticKind::MultipleLikelyArms => {
+                "At most one arm of a match can be `likely`.".into()
+            }
             SemanticDiagnosticKind::InlineAlwaysWithImplGenericArgNotAllowed => {
                 "`#[inline(always)]` is not allowed for functions with impl generic parameters."
                     .into()
@@ -600,6 +635,15 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::UnsupportedImplicitPrecedenceArguments => {
                 "Unsupported `implicit_precedence` arguments.".into()
             }
+            SemanticDiagnosticKind::UncheckedMathNotAllowed { function_name } => {
+                format!(
+                    "Unchecked math function `{function_name}` can only be called from functions \
+                     annotated with `#[allow_unchecked_math]`."
+                )
+            }
+            SemanticDiagnosticKind::UncheckedMathUsage { function_name } => {
+                format!("Usage of unchecked math function `{function_name}`.")
+            }
         }
     }
 
@@ -615,6 +659,20 @@ impl DiagnosticEntry for SemanticDiagnostic {
             _ => location,
         }
     }
+    fn lint_id(&self) -> Option<&'static str> {
+        match &self.kind {
+            SemanticDiagnosticKind::RedundantInlineAttribute => Some("redundant_inline_attribute"),
+            SemanticDiagnosticKind::RedundantImplicitPrecedenceAttribute => {
+                Some("redundant_implicit_precedence_attribute")
+            }
+            SemanticDiagnosticKind::UncheckedMathUsage { .. } => Some(UNCHECKED_MATH_LINT),
+            SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => diagnostic.lint_id,
+            SemanticDiagnosticKind::WrappedPluginDiagnostic { original_diag, .. } => {
+                original_diag.lint_id()
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -875,6 +933,21 @@ pub enum SemanticDiagnosticKind {
     RedundantInlineAttribute,
     InlineWithoutArgumentNotSupported,
     InlineAttrForExternFunctionNotAllowed,
+    UnsupportedInlineCasmArguments,
+    InlineCasmUnsupportedSignature,
+    InvalidInlineCasmCode {
+        error: String,
+    },
+    InlineCasmApChangeMismatch {
+        declared: usize,
+        actual: usize,
+    },
+    InlineCasmOutputsNotAllocated {
+        num_outputs: usize,
+    },
+    UnsupportedBranchHintArguments,
+    ConflictingBranchHints,
+    MultipleLikelyArms,
     InlineAlwaysWithImplGenericArgNotAllowed,
     TailExpressionNotAllowedInLoop,
     ContinueOnlyAllowedInsideALoop,
@@ -883,6 +956,12 @@ pub enum SemanticDiagnosticKind {
     ImplicitPrecedenceAttrForExternFunctionNotAllowed,
     RedundantImplicitPrecedenceAttribute,
     UnsupportedImplicitPrecedenceArguments,
+    UncheckedMathNotAllowed {
+        function_name: SmolStr,
+    },
+    UncheckedMathUsage {
+        function_name: SmolStr,
+    },
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -6,6 +6,8 @@ use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, PluginGeneratedFile, PluginResult,
 };
 use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_filesystem::settings::{CrateSettings, LintSettings};
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -34,6 +36,7 @@ cairo_lang_test_utils::test_file_test!(
         missing: "missing",
         plus_eq: "plus_eq",
         inline: "inline",
+        unchecked_math: "unchecked_math",
     },
     test_expr_diagnostics
 );
@@ -246,3 +249,37 @@ fn test_inline_inline_module_diagnostics() {
     "#},
     );
 }
+
+#[test]
+fn test_unchecked_math_lint() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[allow_unchecked_math]
+            fn foo(a: u128, b: u128) -> u128 {
+                integer::u128_unchecked_add(a, b)
+            }
+       "},
+    );
+    assert_eq!(get_crate_semantic_diagnostics(db, crate_id).format(db), "");
+
+    let lints = LintSettings { warn: vec!["unchecked_math".into()], ..Default::default() };
+    db.set_crate_settings(crate_id, Some(CrateSettings { lints, ..Default::default() }));
+    let diagnostics = get_crate_semantic_diagnostics(db, crate_id);
+    assert_eq!(
+        diagnostics.get_all().iter().map(|diagnostic| diagnostic.lint_id()).collect::<Vec<_>>(),
+        vec![Some("unchecked_math")]
+    );
+    assert_eq!(
+        diagnostics.format(db),
+        indoc! {"
+            error: Usage of unchecked math function `u128_unchecked_add`.
+             --> lib.cairo:3:5
+                integer::u128_unchecked_add(a, b)
+                ^*******************************^
+
+            "},
+    );
+}
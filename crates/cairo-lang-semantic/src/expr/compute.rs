@@ -8,6 +8,7 @@ use std::ops::Deref;
 use ast::PathSegment;
 use cairo_lang_defs::ids::{FunctionTitleId, LanguageElementId, LocalVarLongId, MemberId, TraitId};
 use cairo_lang_diagnostics::{Maybe, ToMaybe, ToOption};
+use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
 use cairo_lang_syntax::node::ast::{BlockOrIf, ExprPtr, PatternStructParam, UnaryOperator};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{GetIdentifier, PathSegmentEx};
@@ -55,6 +56,12 @@ use crate::{
     Signature,
 };
 
+/// An attribute marking a match arm as likely to be taken.
+pub const LIKELY_ATTR: &str = "likely";
+
+/// An attribute marking a match arm as unlikely to be taken.
+pub const UNLIKELY_ATTR: &str = "unlikely";
+
 /// Expression with its id.
 #[derive(Debug, Clone)]
 pub struct ExprAndId {
@@ -773,9 +780,9 @@ fn compute_expr_match_semantic(
     }
     // Compute semantic representation of the match arms.
     let pattern_and_exprs: Vec<_> = pattern_and_expr_options.into_iter().collect::<Maybe<_>>()?;
-    let semantic_arms = pattern_and_exprs
-        .into_iter()
-        .map(|(pattern, arm_expr)| MatchArm { pattern, expression: arm_expr.id })
+    let hints = compute_branch_hints(ctx, &syntax_arms);
+    let semantic_arms = zip_eq(pattern_and_exprs, hints)
+        .map(|((pattern, arm_expr), hint)| MatchArm { pattern, expression: arm_expr.id, hint })
         .collect();
     Ok(Expr::Match(ExprMatch {
         matched_expr: expr.id,
@@ -785,6 +792,51 @@ fn compute_expr_match_semantic(
     }))
 }
 
+/// Computes the branch hints given to the arms of a match by `#[likely]` and `#[unlikely]`
+/// attributes.
+fn compute_branch_hints(
+    ctx: &mut ComputationContext<'_>,
+    syntax_arms: &[ast::MatchArm],
+) -> Vec<Option<BranchHint>> {
+    let syntax_db = ctx.db.upcast();
+    let mut likely_found = false;
+    syntax_arms
+        .iter()
+        .map(|syntax_arm| {
+            let mut hint = None;
+            for attr in syntax_arm.attributes(syntax_db).structurize(syntax_db) {
+                let attr_hint = match attr.id.as_str() {
+                    LIKELY_ATTR => BranchHint::Likely,
+                    UNLIKELY_ATTR => BranchHint::Unlikely,
+                    _ => continue,
+                };
+                if !attr.args.is_empty() {
+                    ctx.diagnostics.report_by_ptr(
+                        attr.args_stable_ptr.untyped(),
+                        UnsupportedBranchHintArguments,
+                    );
+                }
+                match hint {
+                    Some(prev_hint) if prev_hint != attr_hint => {
+                        ctx.diagnostics
+                            .report_by_ptr(attr.stable_ptr.untyped(), ConflictingBranchHints);
+                    }
+                    Some(_) => {}
+                    None if attr_hint == BranchHint::Likely && likely_found => {
+                        ctx.diagnostics
+                            .report_by_ptr(attr.stable_ptr.untyped(), MultipleLikelyArms);
+                    }
+                    None => {
+                        likely_found |= attr_hint == BranchHint::Likely;
+                        hint = Some(attr_hint);
+                    }
+                }
+            }
+            hint
+        })
+        .collect()
+}
+
 /// Computes the semantic model of an expression of type [ast::ExprIf].
 fn compute_expr_if_semantic(ctx: &mut ComputationContext<'_>, syntax: &ast::ExprIf) -> Maybe<Expr> {
     let syntax_db = ctx.db.upcast();
@@ -129,7 +129,8 @@ impl Solver {
         &mut self,
         db: &dyn SemanticGroup,
     ) -> InferenceResult<SolutionSet<CanonicalImpl>> {
-        let mut unique_solution: Option<CanonicalImpl> = None;
+        // The unique solution, and whether it is a synthesized impl.
+        let mut unique_solution: Option<(CanonicalImpl, bool)> = None;
         for candidate_solver in &mut self.candidate_solvers {
             let candidate_solution_set = candidate_solver.solution_set(db)?;
             let candidate_solution = match candidate_solution_set {
@@ -137,15 +138,24 @@ impl Solver {
                 SolutionSet::Unique(candidate_solution) => candidate_solution,
                 SolutionSet::Ambiguous(ambiguity) => return Ok(SolutionSet::Ambiguous(ambiguity)),
             };
-            if let Some(unique_solution) = unique_solution {
-                return Ok(SolutionSet::Ambiguous(Ambiguity::MultipleImplsFound {
-                    concrete_trait_id: self.canonical_trait.0,
-                    impls: vec![unique_solution.0, candidate_solution.0],
-                }));
+            let synthesized = candidate_solver.candidate.is_synthesized(db);
+            match unique_solution {
+                // Other impls take precedence over synthesized ones.
+                Some((_, false)) if synthesized => continue,
+                Some((_, true)) if !synthesized => {}
+                Some((unique_solution, _)) => {
+                    return Ok(SolutionSet::Ambiguous(Ambiguity::MultipleImplsFound {
+                        concrete_trait_id: self.canonical_trait.0,
+                        impls: vec![unique_solution.0, candidate_solution.0],
+                    }));
+                }
+                None => {}
             }
-            unique_solution = Some(candidate_solution);
+            unique_solution = Some((candidate_solution, synthesized));
         }
-        Ok(unique_solution.map(SolutionSet::Unique).unwrap_or(SolutionSet::None))
+        Ok(unique_solution
+            .map(|(solution, _)| SolutionSet::Unique(solution))
+            .unwrap_or(SolutionSet::None))
     }
 }
 
@@ -326,6 +326,17 @@ pub struct ExprIf {
 pub struct MatchArm {
     pub pattern: Pattern,
     pub expression: ExprId,
+    /// The branch hint given to the arm by a `#[likely]` or `#[unlikely]` attribute.
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub hint: Option<BranchHint>,
+}
+
+/// A hint of how likely a branch is to be taken, used for laying out the generated code.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum BranchHint {
+    Likely,
+    Unlikely,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
@@ -1,15 +1,19 @@
 use std::sync::Arc;
 
+use cairo_lang_casm::parser::parse_straight_line_code;
 use cairo_lang_defs::ids::{ExternFunctionId, FunctionTitleId, GenericKind, LanguageElementId};
-use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
-use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
-use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe};
+use cairo_lang_syntax::attribute::structured::{
+    Attribute, AttributeArgVariant, AttributeListStructurize,
+};
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::extract_matches;
+use num_traits::ToPrimitive;
 
 use super::function_with_body::get_inline_config;
 use super::functions::{FunctionDeclarationData, GenericFunctionId, InlineConfiguration};
 use super::generics::{semantic_generic_params, GenericParamsData};
-use crate::corelib::get_core_generic_function_id;
+use crate::corelib::{core_felt252_ty, get_core_generic_function_id};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::SemanticDiagnostics;
@@ -19,12 +23,34 @@ use crate::items::function_with_body::get_implicit_precedence;
 use crate::items::functions::ImplicitPrecedence;
 use crate::resolve::{Resolver, ResolverData};
 use crate::substitution::SemanticRewriter;
-use crate::{semantic, Mutability, Parameter, SemanticDiagnostic, TypeId};
+use crate::{semantic, Mutability, Parameter, SemanticDiagnostic, TypeId, TypeLongId};
 
 #[cfg(test)]
 #[path = "extern_function_test.rs"]
 mod test;
 
+/// The attribute implementing an extern function by a block of CASM code.
+pub const INLINE_CASM_ATTR: &str = "unsafe_inline_casm";
+
+/// The CASM code implementing an extern function, given by an `unsafe_inline_casm` attribute.
+/// The code is straight-line code, which takes the felt252 inputs of the function from the stack
+/// right before it, and leaves its felt252 outputs as the last cells it allocates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InlineCasm {
+    /// The text of the code.
+    pub code: String,
+    /// The ap change of the code, as declared by the attribute.
+    pub ap_change: usize,
+    pub num_inputs: usize,
+    pub num_outputs: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternFunctionInlineCasmData {
+    pub diagnostics: Diagnostics<SemanticDiagnostic>,
+    pub inline_casm: Option<InlineCasm>,
+}
+
 // --- Selectors ---
 
 /// Query implementation of [crate::db::SemanticGroup::extern_function_declaration_inline_config].
@@ -40,9 +66,21 @@ pub fn extern_function_declaration_diagnostics(
     db: &dyn SemanticGroup,
     extern_function_id: ExternFunctionId,
 ) -> Diagnostics<SemanticDiagnostic> {
-    db.priv_extern_function_declaration_data(extern_function_id)
-        .map(|data| data.diagnostics)
-        .unwrap_or_default()
+    let mut diagnostics = DiagnosticsBuilder::default();
+    if let Ok(data) = db.priv_extern_function_declaration_data(extern_function_id) {
+        diagnostics.extend(data.diagnostics);
+    }
+    if let Ok(data) = db.priv_extern_function_inline_casm_data(extern_function_id) {
+        diagnostics.extend(data.diagnostics);
+    }
+    diagnostics.build()
+}
+/// Query implementation of [crate::db::SemanticGroup::extern_function_inline_casm].
+pub fn extern_function_inline_casm(
+    db: &dyn SemanticGroup,
+    extern_function_id: ExternFunctionId,
+) -> Maybe<Option<InlineCasm>> {
+    Ok(db.priv_extern_function_inline_casm_data(extern_function_id)?.inline_casm)
 }
 /// Query implementation of [crate::db::SemanticGroup::extern_function_signature].
 pub fn extern_function_signature(
@@ -207,3 +245,99 @@ pub fn priv_extern_function_declaration_data(
         implicit_precedence: ImplicitPrecedence::UNSPECIFIED,
     })
 }
+
+/// Query implementation of [crate::db::SemanticGroup::priv_extern_function_inline_casm_data].
+pub fn priv_extern_function_inline_casm_data(
+    db: &dyn SemanticGroup,
+    extern_function_id: ExternFunctionId,
+) -> Maybe<ExternFunctionInlineCasmData> {
+    let module_file_id = extern_function_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let declaration_data = db.priv_extern_function_declaration_data(extern_function_id)?;
+    let inline_casm =
+        match declaration_data.attributes.iter().find(|attr| attr.id == INLINE_CASM_ATTR) {
+            Some(attr) => get_inline_casm(db, &mut diagnostics, attr, &declaration_data),
+            None => None,
+        };
+    Ok(ExternFunctionInlineCasmData { diagnostics: diagnostics.build(), inline_casm })
+}
+
+/// Extracts the inline CASM code given by an `unsafe_inline_casm` attribute, and validates it
+/// against the signature of the function.
+fn get_inline_casm(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    attr: &Attribute,
+    declaration_data: &FunctionDeclarationData,
+) -> Option<InlineCasm> {
+    let syntax_db = db.upcast();
+    let parsed_args = match (named_attr_arg(attr, 0, "code"), named_attr_arg(attr, 1, "ap_change"))
+    {
+        (Some(ast::Expr::ShortString(code_syntax)), Some(ast::Expr::Literal(ap_change)))
+            if attr.args.len() == 2 =>
+        {
+            code_syntax
+                .string_value(syntax_db)
+                .zip(ap_change.numeric_value(syntax_db).and_then(|value| value.to_usize()))
+                .map(|(code, ap_change)| (code_syntax, code, ap_change))
+        }
+        _ => None,
+    };
+    let Some((code_syntax, code, ap_change)) = parsed_args else {
+        diagnostics.report_by_ptr(attr.args_stable_ptr.untyped(), UnsupportedInlineCasmArguments);
+        return None;
+    };
+
+    let felt252_ty = core_felt252_ty(db);
+    let signature = &declaration_data.signature;
+    let outputs = match db.lookup_intern_type(signature.return_type) {
+        TypeLongId::Tuple(tys) => tys,
+        _ => vec![signature.return_type],
+    };
+    if !declaration_data.generic_params.is_empty()
+        || !signature.implicits.is_empty()
+        || signature
+            .params
+            .iter()
+            .any(|param| param.ty != felt252_ty || param.mutability == Mutability::Reference)
+        || outputs.iter().any(|ty| *ty != felt252_ty)
+    {
+        diagnostics.report_by_ptr(attr.stable_ptr.untyped(), InlineCasmUnsupportedSignature);
+        return None;
+    }
+    let num_inputs = signature.params.len();
+    let num_outputs = outputs.len();
+
+    let parsed = match parse_straight_line_code(&code)
+        .and_then(|parsed| parsed.validate_cells(num_inputs).map(|_| parsed))
+    {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            diagnostics.report(code_syntax, InvalidInlineCasmCode { error: err.to_string() });
+            return None;
+        }
+    };
+    if parsed.ap_change != ap_change {
+        diagnostics.report_by_ptr(
+            attr.args_stable_ptr.untyped(),
+            InlineCasmApChangeMismatch { declared: ap_change, actual: parsed.ap_change },
+        );
+        return None;
+    }
+    if num_outputs > ap_change {
+        diagnostics.report_by_ptr(
+            attr.stable_ptr.untyped(),
+            InlineCasmOutputsNotAllocated { num_outputs },
+        );
+        return None;
+    }
+    Some(InlineCasm { code, ap_change, num_inputs, num_outputs })
+}
+
+/// Returns the value of the argument of the attribute at the given index, if it is named `name`.
+fn named_attr_arg<'a>(attr: &'a Attribute, idx: usize, name: &str) -> Option<&'a ast::Expr> {
+    match &attr.args.get(idx)?.variant {
+        AttributeArgVariant::Named { value, name: arg_name, .. } if arg_name == name => Some(value),
+        _ => None,
+    }
+}
@@ -6,7 +6,9 @@ use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
 use cairo_lang_syntax::node::TypedSyntaxNode;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 
-use super::function_with_body::{get_inline_config, FunctionBody, FunctionBodyData};
+use super::function_with_body::{
+    get_inline_config, validate_unchecked_math_usage, FunctionBody, FunctionBodyData,
+};
 use super::functions::{
     forbid_inline_always_with_impl_generic_param, FunctionDeclarationData, InlineConfiguration,
 };
@@ -229,6 +231,7 @@ pub fn priv_free_function_body_data(
     let function_body = function_syntax.body(db.upcast());
     let return_type = declaration.signature.return_type;
     let body_expr = compute_root_expr(&mut ctx, &function_body, return_type)?;
+    validate_unchecked_math_usage(db, ctx.diagnostics, &declaration.attributes, &ctx.exprs);
     let ComputationContext { exprs, statements, resolver, .. } = ctx;
 
     let expr_lookup: UnorderedHashMap<_, _> =
@@ -18,6 +18,14 @@ use crate::items::functions::ImplicitPrecedence;
 use crate::resolve::ResolverData;
 use crate::{semantic, ExprId, SemanticDiagnostic, TypeId};
 
+/// The attribute marking extern functions that don't verify their results are in range.
+pub const UNCHECKED_MATH_ATTR: &str = "unchecked_math";
+/// The attribute allowing a function to call `#[unchecked_math]` functions.
+pub const ALLOW_UNCHECKED_MATH_ATTR: &str = "allow_unchecked_math";
+/// The lint reporting the calls to `#[unchecked_math]` functions from functions annotated with
+/// `#[allow_unchecked_math]`.
+pub const UNCHECKED_MATH_LINT: &str = "unchecked_math";
+
 // === Declaration ===
 
 // --- Selectors ---
@@ -321,3 +329,43 @@ pub fn get_implicit_precedence<'a>(
 
     Ok((precedence, Some(attr)))
 }
+
+/// Reports the calls to extern functions annotated with `#[unchecked_math]` in the body of a
+/// function, unless the function is annotated with `#[allow_unchecked_math]`.
+/// In functions annotated with it, the calls are reported by the opt-in [UNCHECKED_MATH_LINT]
+/// lint instead, so that the crates enabling it get every use flagged for audit.
+pub fn validate_unchecked_math_usage(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    attributes: &[Attribute],
+    exprs: &Arena<semantic::Expr>,
+) {
+    let allowed = attributes.iter().any(|attr| attr.id == ALLOW_UNCHECKED_MATH_ATTR);
+    if allowed {
+        let crate_id = diagnostics.module_file_id.0.owning_crate(db.upcast());
+        if !db.crate_settings(crate_id).lints.is_enabled(UNCHECKED_MATH_LINT) {
+            return;
+        }
+    }
+    for (_, expr) in exprs.iter() {
+        let semantic::Expr::FunctionCall(call) = expr else { continue };
+        let Some(extern_function_id) = call.function.try_get_extern_function_id(db) else {
+            continue;
+        };
+        let is_unchecked_math = db
+            .priv_extern_function_declaration_data(extern_function_id)
+            .map(|data| data.attributes.iter().any(|attr| attr.id == UNCHECKED_MATH_ATTR))
+            .unwrap_or_default();
+        if is_unchecked_math {
+            let function_name = extern_function_id.name(db.upcast());
+            diagnostics.report_by_ptr(
+                call.stable_ptr.untyped(),
+                if allowed {
+                    SemanticDiagnosticKind::UncheckedMathUsage { function_name }
+                } else {
+                    SemanticDiagnosticKind::UncheckedMathNotAllowed { function_name }
+                },
+            );
+        }
+    }
+}
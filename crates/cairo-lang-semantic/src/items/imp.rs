@@ -5,8 +5,8 @@ use std::vec;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
     FunctionTitleId, GenericKind, GenericParamId, ImplAliasId, ImplDefId, ImplFunctionId,
-    ImplFunctionLongId, LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitFunctionId,
-    TraitId,
+    ImplFunctionLongId, LanguageElementId, ModuleFileId, ModuleId, TopLevelLanguageElementId,
+    TraitFunctionId, TraitId,
 };
 use cairo_lang_diagnostics::{
     skip_diagnostic, Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
@@ -26,8 +26,11 @@ use itertools::{chain, izip, Itertools};
 use smol_str::SmolStr;
 use syntax::node::db::SyntaxGroup;
 
+use super::attribute::SemanticQueryAttrs;
 use super::enm::SemanticEnumEx;
-use super::function_with_body::{get_inline_config, FunctionBody, FunctionBodyData};
+use super::function_with_body::{
+    get_inline_config, validate_unchecked_math_usage, FunctionBody, FunctionBodyData,
+};
 use super::functions::{
     forbid_inline_always_with_impl_generic_param, FunctionDeclarationData, InlineConfiguration,
 };
@@ -45,6 +48,7 @@ use crate::expr::inference::{ImplVarId, InferenceData, InferenceResult};
 use crate::items::function_with_body::get_implicit_precedence;
 use crate::items::functions::ImplicitPrecedence;
 use crate::items::us::SemanticUseEx;
+use crate::plugin::SynthesizedImplsAuxData;
 use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem, Resolver, ResolverData};
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
 use crate::{
@@ -57,6 +61,11 @@ use crate::{
 #[path = "imp_test.rs"]
 mod test;
 
+/// An attribute marking an impl synthesized by the compiler, rather than written or derived by the
+/// user. Other impls of the same trait take precedence over it. Only honored in files generated
+/// by the derive plugin (see [SynthesizedImplsAuxData]).
+pub const SYNTHESIZED_IMPL_ATTR: &str = "synthesized_impl";
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub struct ConcreteImplLongId {
     pub impl_def_id: ImplDefId,
@@ -459,15 +468,19 @@ pub fn priv_impl_definition_data(
     let generic_params_ids =
         generic_params.iter().map(|generic_param| generic_param.id()).collect();
     let lookup_context = ImplLookupContext::new(module_file_id.0, generic_params_ids);
-    check_special_impls(
-        db,
-        &mut diagnostics,
-        lookup_context,
-        concrete_trait,
-        impl_ast.stable_ptr().untyped(),
-    )
-    // Ignore the result.
-    .ok();
+    // Synthesized impls are only valid if their generic params can be inferred, so the inner types
+    // are not checked.
+    if !UninferredImpl::Def(impl_def_id).is_synthesized(db) {
+        check_special_impls(
+            db,
+            &mut diagnostics,
+            lookup_context,
+            concrete_trait,
+            impl_ast.stable_ptr().untyped(),
+        )
+        // Ignore the result.
+        .ok();
+    }
 
     // TODO(yuval): verify that all functions of `concrete_trait` appear in this impl.
 
@@ -831,6 +844,25 @@ impl UninferredImpl {
             UninferredImpl::GenericParam(param) => param.module_file_id(defs_db).0,
         }
     }
+    /// Returns whether this is an impl synthesized by the compiler (see [SYNTHESIZED_IMPL_ATTR]).
+    /// The attribute is only honored in files generated by the derive plugin.
+    pub fn is_synthesized(&self, db: &dyn SemanticGroup) -> bool {
+        match self {
+            UninferredImpl::Def(impl_def_id) => {
+                let ModuleFileId(module_id, file_index) = impl_def_id.module_file_id(db.upcast());
+                let in_synthesized_impls_file = db
+                    .module_generated_file_infos(module_id)
+                    .ok()
+                    .and_then(|file_infos| file_infos.get(file_index.0).cloned().flatten())
+                    .map_or(false, |file_info| {
+                        file_info.aux_data.as_any().is::<SynthesizedImplsAuxData>()
+                    });
+                in_synthesized_impls_file
+                    && matches!(impl_def_id.has_attr(db, SYNTHESIZED_IMPL_ATTR), Ok(true))
+            }
+            UninferredImpl::ImplAlias(_) | UninferredImpl::GenericParam(_) => false,
+        }
+    }
 }
 impl DebugWithDb<dyn SemanticGroup> for UninferredImpl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>, db: &dyn SemanticGroup) -> std::fmt::Result {
@@ -1382,6 +1414,12 @@ pub fn priv_impl_function_body_data(
     let function_body = function_syntax.body(db.upcast());
     let return_type = declaration.function_declaration_data.signature.return_type;
     let body_expr = compute_root_expr(&mut ctx, &function_body, return_type)?;
+    validate_unchecked_math_usage(
+        db,
+        ctx.diagnostics,
+        &declaration.function_declaration_data.attributes,
+        &ctx.exprs,
+    );
     let ComputationContext { exprs, statements, resolver, .. } = ctx;
 
     let expr_lookup: UnorderedHashMap<_, _> =
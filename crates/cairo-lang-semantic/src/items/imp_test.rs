@@ -4,6 +4,7 @@ use cairo_lang_utils::extract_matches;
 use pretty_assertions::assert_eq;
 use test_log::test;
 
+use super::UninferredImpl;
 use crate::db::SemanticGroup;
 use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
 
@@ -52,3 +53,31 @@ fn test_impl() {
         "ConcreteTraitId(0)"
     );
 }
+
+#[test]
+fn test_user_synthesized_impl_attr_ignored() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let (test_module, diagnostics) = setup_test_module(
+        db,
+        indoc::indoc! {"
+            struct A {
+                dict: Felt252Dict<felt252>,
+            }
+
+            #[synthesized_impl]
+            impl ACopy of Copy<A>;
+        "},
+    )
+    .split();
+
+    // The attribute is only honored on impls generated by the derive plugin, so the members of
+    // the type are still validated.
+    assert!(diagnostics.contains("Invalid copy trait implementation"), "{diagnostics}");
+
+    let impl_def_id = extract_matches!(
+        db.module_item_by_name(test_module.module_id, "ACopy".into()).unwrap().unwrap(),
+        ModuleItemId::Impl
+    );
+    assert!(!UninferredImpl::Def(impl_def_id).is_synthesized(db));
+}
@@ -12,6 +12,7 @@ pub mod lookup_item;
 pub mod lsp_helpers;
 pub mod patcher;
 pub mod plugin;
+pub mod query;
 pub mod resolve;
 pub mod substitution;
 pub mod types;
@@ -4,6 +4,8 @@ use std::sync::Arc;
 
 use cairo_lang_defs::plugin::{GeneratedFileAuxData, MacroPlugin};
 use cairo_lang_filesystem::span::TextSpan;
+use serde::Serialize;
+use smol_str::SmolStr;
 
 use crate::db::SemanticGroup;
 
@@ -27,6 +29,12 @@ pub trait PluginAuxData:
         db: &(dyn SemanticGroup + 'static),
         diag: &dyn Any,
     ) -> Option<PluginMappedDiagnostic>;
+
+    /// Returns data to emit alongside the compilation artifacts of the code containing the
+    /// generated file, if any.
+    fn artifact_aux_data(&self) -> Option<PluginArtifactAuxData> {
+        None
+    }
 }
 pub trait AsDynGeneratedFileAuxData {
     fn as_dyn_macro_token(&self) -> &(dyn GeneratedFileAuxData + 'static);
@@ -38,6 +46,24 @@ pub struct PluginMappedDiagnostic {
     pub message: String,
 }
 
+/// Auxiliary data a plugin attaches to the compilation artifacts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluginArtifactAuxData {
+    /// The name of the plugin that generated the data. Used as the key of the data in the
+    /// artifacts.
+    pub plugin_name: SmolStr,
+    /// The data, in JSON format.
+    pub data: serde_json::Value,
+}
+impl PluginArtifactAuxData {
+    pub fn new<T: Serialize>(
+        plugin_name: impl Into<SmolStr>,
+        data: &T,
+    ) -> serde_json::Result<Self> {
+        Ok(Self { plugin_name: plugin_name.into(), data: serde_json::to_value(data)? })
+    }
+}
+
 // `dyn` wrapper for `PluginAuxData`.
 #[derive(Clone, Debug)]
 pub struct DynPluginAuxData(pub Arc<dyn PluginAuxData>);
@@ -92,3 +118,18 @@ impl PluginAuxData for TrivialPluginAuxData {
         None
     }
 }
+
+/// Aux data of a file generated by the derive plugin, marking that the impls of the file
+/// annotated with `#[synthesized_impl]` are indeed synthesized by the compiler.
+/// The attribute is ignored in any other file, so it can't be used to skip impl validation.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SynthesizedImplsAuxData {}
+impl GeneratedFileAuxData for SynthesizedImplsAuxData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq(&self, other: &dyn GeneratedFileAuxData) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() { self == other } else { false }
+    }
+}
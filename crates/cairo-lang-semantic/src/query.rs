@@ -0,0 +1,192 @@
+//! A read-only query facade over the semantic model, for external analysis tools.
+//!
+//! The salsa query groups of the compiler are internal, and change often. [SemanticQuery] exposes
+//! a small set of queries analysis tools commonly need - resolving paths, listing the impls of a
+//! trait, listing the callers of a function and finding the type of an expression - on top of any
+//! database implementing [SemanticGroup], so such tools can be built as separate crates.
+
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{
+    FunctionWithBodyId, ImplDefId, LanguageElementId, ModuleId, ModuleItemId,
+    TopLevelLanguageElementId, TraitId,
+};
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId, FileId};
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+
+use crate::db::SemanticGroup;
+use crate::items::functions::GenericFunctionId;
+use crate::resolve::ResolvedGenericItem;
+use crate::{Expr, TypeId};
+
+#[cfg(test)]
+#[path = "query_test.rs"]
+mod test;
+
+/// A read-only view of the semantic model of the crates in a database.
+#[derive(Clone, Copy)]
+pub struct SemanticQuery<'a> {
+    db: &'a dyn SemanticGroup,
+}
+impl<'a> SemanticQuery<'a> {
+    pub fn new(db: &'a dyn SemanticGroup) -> Self {
+        Self { db }
+    }
+
+    /// Resolves a full path, e.g. `core::array::ArrayTrait::append`, to the item it refers to.
+    ///
+    /// The first segment is the name of a crate, and every other segment is an item of the module
+    /// or the trait before it. `use` items are followed. Generic arguments are not supported.
+    pub fn resolve_path(&self, path: &str) -> Option<ResolvedGenericItem> {
+        let db = self.db;
+        let mut segments = path.split("::");
+        let crate_id = db.intern_crate(CrateLongId(segments.next()?.into()));
+        db.crate_root_dir(crate_id)?;
+        let mut item = ResolvedGenericItem::Module(ModuleId::CrateRoot(crate_id));
+        for segment in segments {
+            item = match item {
+                ResolvedGenericItem::Module(module_id) => {
+                    let module_item = db.module_item_by_name(module_id, segment.into()).ok()??;
+                    ResolvedGenericItem::from_module_item(db, module_item).ok()?
+                }
+                ResolvedGenericItem::Trait(trait_id) => ResolvedGenericItem::TraitFunction(
+                    db.trait_function_by_name(trait_id, segment.into()).ok()??,
+                ),
+                _ => return None,
+            };
+        }
+        Some(item)
+    }
+
+    /// Returns the impls of the given trait defined in the given crates.
+    pub fn impls_of_trait(&self, trait_id: TraitId, crate_ids: &[CrateId]) -> Vec<ImplDefId> {
+        let db = self.db;
+        self.modules(crate_ids)
+            .into_iter()
+            .flat_map(|module_id| db.module_impls_ids(module_id).unwrap_or_default())
+            .filter(|impl_def_id| db.impl_def_trait(*impl_def_id) == Ok(trait_id))
+            .collect()
+    }
+
+    /// Returns the functions of the given crates which directly call `callee`, in the order they
+    /// are defined.
+    ///
+    /// `callee` is a function, or a trait function - in which case calls to the function through
+    /// any impl of the trait are included.
+    pub fn callers_of_function(
+        &self,
+        callee: &ResolvedGenericItem,
+        crate_ids: &[CrateId],
+    ) -> Vec<FunctionWithBodyId> {
+        let db = self.db;
+        let is_callee = |generic_function: GenericFunctionId| match (callee, generic_function) {
+            (ResolvedGenericItem::GenericFunction(callee), _) => *callee == generic_function,
+            (ResolvedGenericItem::TraitFunction(callee), GenericFunctionId::Impl(function)) => {
+                *callee == function.function
+            }
+            _ => false,
+        };
+        self.functions_with_body(crate_ids)
+            .into_iter()
+            .filter(|function_id| {
+                let Ok(body) = db.function_body(*function_id) else { return false };
+                body.exprs.iter().any(|(_, expr)| match expr {
+                    Expr::FunctionCall(call) => {
+                        is_callee(call.function.get_concrete(db).generic_function)
+                    }
+                    _ => false,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the type of the innermost expression containing `span`, in the given file.
+    pub fn type_of_expression_at(&self, file_id: FileId, span: TextSpan) -> Option<TypeId> {
+        let db = self.db;
+        let syntax_db = db.upcast();
+        let root = db.file_syntax(file_id).ok()?.as_syntax_node();
+        let crate_ids = db
+            .file_modules(file_id)
+            .ok()?
+            .into_iter()
+            .map(|module_id| module_id.owning_crate(db.upcast()))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let mut innermost: Option<(TextSpan, TypeId)> = None;
+        for function_id in self.functions_with_body(&crate_ids) {
+            if db.module_file(function_id.module_file_id(db.upcast())) != Ok(file_id) {
+                continue;
+            }
+            let Ok(body) = db.function_body(function_id) else { continue };
+            for (_, expr) in body.exprs.iter() {
+                let expr_span =
+                    root.lookup_ptr(syntax_db, expr.stable_ptr().untyped()).span(syntax_db);
+                let is_inner =
+                    innermost.map_or(true, |(inner_span, _)| inner_span.contains(expr_span));
+                if expr_span.contains(span) && is_inner {
+                    innermost = Some((expr_span, expr.ty()));
+                }
+            }
+        }
+        innermost.map(|(_, ty)| ty)
+    }
+
+    /// Returns the full path of an item, e.g. `core::array::ArrayTrait`.
+    pub fn full_path(&self, item: &dyn TopLevelLanguageElementId) -> String {
+        item.full_path(self.db.upcast())
+    }
+
+    /// Returns the full path of a function, e.g. `my_crate::MyImpl::my_function` for a function of
+    /// an impl.
+    pub fn function_path(&self, function_id: FunctionWithBodyId) -> String {
+        let defs_db = self.db.upcast();
+        match function_id {
+            FunctionWithBodyId::Free(function_id) => function_id.full_path(defs_db),
+            FunctionWithBodyId::Impl(function_id) => format!(
+                "{}::{}",
+                function_id.impl_def_id(defs_db).full_path(defs_db),
+                function_id.name(defs_db)
+            ),
+        }
+    }
+
+    /// Returns the name of a type, as written in Cairo code.
+    pub fn type_name(&self, ty: TypeId) -> String {
+        ty.format(self.db)
+    }
+
+    /// Returns the modules of the given crates.
+    fn modules(&self, crate_ids: &[CrateId]) -> Vec<ModuleId> {
+        crate_ids
+            .iter()
+            .flat_map(|crate_id| {
+                self.db.crate_modules(*crate_id).iter().copied().collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns the free functions and impl functions of the given crates.
+    fn functions_with_body(&self, crate_ids: &[CrateId]) -> Vec<FunctionWithBodyId> {
+        let db = self.db;
+        let mut functions = vec![];
+        for module_id in self.modules(crate_ids) {
+            for item in db.module_items(module_id).unwrap_or_default().iter() {
+                match item {
+                    ModuleItemId::FreeFunction(function_id) => {
+                        functions.push(FunctionWithBodyId::Free(*function_id));
+                    }
+                    ModuleItemId::Impl(impl_def_id) => functions.extend(
+                        db.impl_functions(*impl_def_id)
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|(_, function_id)| FunctionWithBodyId::Impl(function_id)),
+                    ),
+                    _ => {}
+                }
+            }
+        }
+        functions
+    }
+}
@@ -0,0 +1,87 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
+use cairo_lang_utils::extract_matches;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::SemanticQuery;
+use crate::resolve::ResolvedGenericItem;
+use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
+
+const CONTENT: &str = indoc::indoc! {"
+    struct Square {
+        side: felt252,
+    }
+    struct Rect {
+        width: felt252,
+        height: felt252,
+    }
+    trait Shape<T> {
+        fn area(self: @T) -> felt252;
+    }
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> felt252 {
+            double(*self.side)
+        }
+    }
+    impl RectShape of Shape<Rect> {
+        fn area(self: @Rect) -> felt252 {
+            *self.width * *self.height
+        }
+    }
+    fn double(x: felt252) -> felt252 {
+        x + x
+    }
+    fn total_area(square: @Square, rect: @Rect) -> felt252 {
+        square.area() + double(rect.area())
+    }
+"};
+
+#[test]
+fn test_semantic_query() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let test_module = setup_test_module(db, CONTENT).unwrap();
+    let query = SemanticQuery::new(db);
+    let crate_ids = [test_module.crate_id];
+
+    // Paths.
+    let resolve = |path| query.resolve_path(path).map(|item| item.full_path(db));
+    assert_eq!(resolve("test::Shape::area"), Some("test::Shape::area".into()));
+    assert_eq!(resolve("core::array::ArrayTrait"), Some("core::array::ArrayTrait".into()));
+    assert_eq!(resolve("test::Circle"), None);
+    assert_eq!(resolve("missing::Shape"), None);
+
+    // Impls of a trait.
+    let shape =
+        extract_matches!(query.resolve_path("test::Shape").unwrap(), ResolvedGenericItem::Trait);
+    let impls = query.impls_of_trait(shape, &crate_ids);
+    assert_eq!(
+        impls.iter().map(|impl_def_id| query.full_path(impl_def_id)).collect::<Vec<_>>(),
+        ["test::SquareShape", "test::RectShape"]
+    );
+
+    // Callers of a function, and of a trait function.
+    let callers = |path| {
+        query
+            .callers_of_function(&query.resolve_path(path).unwrap(), &crate_ids)
+            .iter()
+            .map(|function_id| query.function_path(*function_id))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(callers("test::double"), ["test::SquareShape::area", "test::total_area"]);
+    assert_eq!(callers("test::Shape::area"), ["test::total_area"]);
+    assert_eq!(callers("test::total_area"), Vec::<String>::new());
+
+    // Types of expressions, at the last occurrence of the given text.
+    let file_id = db.module_main_file(test_module.module_id).unwrap();
+    let type_at = |text: &str| {
+        let start = TextOffset::default()
+            .add_width(TextWidth::from_str(&CONTENT[..CONTENT.rfind(text).unwrap()]));
+        let span = TextSpan { start, end: start.add_width(TextWidth::from_str(text)) };
+        query.type_of_expression_at(file_id, span).map(|ty| query.type_name(ty))
+    };
+    assert_eq!(type_at("square"), Some("@test::Square".into()));
+    assert_eq!(type_at("rect.area()"), Some("core::felt252".into()));
+    assert_eq!(type_at("struct Square"), None);
+}
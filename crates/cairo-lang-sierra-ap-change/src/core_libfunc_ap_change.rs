@@ -1,10 +1,17 @@
 use cairo_lang_sierra::extensions::ap_tracking::ApTrackingConcreteLibfunc;
 use cairo_lang_sierra::extensions::array::ArrayConcreteLibfunc;
+use cairo_lang_sierra::extensions::bitfield::BitfieldConcreteLibfunc;
+use cairo_lang_sierra::extensions::blake::BlakeConcreteLibfunc;
 use cairo_lang_sierra::extensions::boolean::BoolConcreteLibfunc;
+use cairo_lang_sierra::extensions::bounded_int::BoundedIntConcreteLibfunc;
 use cairo_lang_sierra::extensions::boxing::BoxConcreteLibfunc;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
 use cairo_lang_sierra::extensions::casts::CastConcreteLibfunc;
+use cairo_lang_sierra::extensions::circuit::CircuitConcreteLibfunc;
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
+use cairo_lang_sierra::extensions::ecdsa::EcdsaConcreteLibfunc;
+use cairo_lang_sierra::extensions::encoding::EncodingConcreteLibfunc;
 use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
 use cairo_lang_sierra::extensions::felt252::{
     Felt252BinaryOperationConcrete, Felt252BinaryOperator, Felt252Concrete,
@@ -21,12 +28,16 @@ use cairo_lang_sierra::extensions::int::unsigned::{UintConcrete, UintTraits};
 use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
 use cairo_lang_sierra::extensions::int::unsigned256::Uint256Concrete;
 use cairo_lang_sierra::extensions::int::unsigned512::Uint512Concrete;
+use cairo_lang_sierra::extensions::int::unsigned96::Uint96Concrete;
 use cairo_lang_sierra::extensions::int::{IntMulTraits, IntOperator};
 use cairo_lang_sierra::extensions::is_zero::IsZeroTraits;
+use cairo_lang_sierra::extensions::keccak::KeccakConcreteLibfunc;
 use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc;
+use cairo_lang_sierra::extensions::modular::ModularConcreteLibfunc;
 use cairo_lang_sierra::extensions::nullable::NullableConcreteLibfunc;
 use cairo_lang_sierra::extensions::pedersen::PedersenConcreteLibfunc;
 use cairo_lang_sierra::extensions::poseidon::PoseidonConcreteLibfunc;
+use cairo_lang_sierra::extensions::rlp::RlpConcreteLibfunc;
 use cairo_lang_sierra::extensions::starknet::testing::TestingConcreteLibfunc;
 use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
@@ -41,8 +52,13 @@ pub trait InvocationApChangeInfoProvider {
     /// Number of tokens provided by the libfunc invocation (currently only relevant for
     /// `withdraw_gas_all`).
     fn token_usages(&self, token_type: CostTokenType) -> usize;
+    /// Whether `ec_state_add_mul` is lowered without the EC op builtin.
+    fn software_ec_op(&self) -> bool;
 }
 
+/// The ap change of `ec_state_add_mul` when lowered without the EC op builtin.
+pub const SOFTWARE_EC_STATE_ADD_MUL_AP_CHANGE: usize = 6498;
+
 /// Returns the ap change for a core libfunc.
 /// Values with unknown values will return as None.
 pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
@@ -73,16 +89,71 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
                     .map(ApChange::Known)
                     .to_vec()
             }
+            ArrayConcreteLibfunc::GetUsize(libfunc) => {
+                if info_provider.type_size(&libfunc.ty) == 1 { [5, 3] } else { [6, 4] }
+                    .map(ApChange::Known)
+                    .to_vec()
+            }
             ArrayConcreteLibfunc::Slice(libfunc) => {
                 if info_provider.type_size(&libfunc.ty) == 1 { [4, 5] } else { [6, 6] }
                     .map(ApChange::Known)
                     .to_vec()
             }
+            ArrayConcreteLibfunc::ExtendFromSpan(_) => {
+                vec![ApChange::Unknown, ApChange::Known(5)]
+            }
+            ArrayConcreteLibfunc::SpanEq(_) => {
+                vec![ApChange::Unknown, ApChange::Unknown, ApChange::Known(7)]
+            }
+            ArrayConcreteLibfunc::SpanBinarySearch(_) => {
+                vec![ApChange::Unknown, ApChange::Unknown, ApChange::Unknown]
+            }
+            ArrayConcreteLibfunc::SpanSortVerified(_) => {
+                vec![ApChange::Unknown, ApChange::Known(5)]
+            }
             ArrayConcreteLibfunc::Len(libfunc) => {
                 vec![ApChange::Known(if info_provider.type_size(&libfunc.ty) == 1 { 0 } else { 1 })]
             }
+            ArrayConcreteLibfunc::LenExceeds(_) => vec![ApChange::Known(3), ApChange::Known(3)],
+        },
+        CoreConcreteLibfunc::Bitfield(libfunc) => match libfunc {
+            BitfieldConcreteLibfunc::Pack(libfunc) => {
+                vec![ApChange::Known(2 * (libfunc.widths.len() - 1))]
+            }
+            BitfieldConcreteLibfunc::Unpack(libfunc) => {
+                let n_fields = libfunc.widths.len();
+                let n_short_fields = libfunc.widths.iter().filter(|width| **width < 128).count();
+                // The fits flag, the guessed fields, the shifted short fields, and the repacking.
+                vec![
+                    ApChange::Known(
+                        1 + 2 * (n_fields - 1)
+                            + n_short_fields
+                            + (2 * (n_fields - 1)).saturating_sub(1),
+                    ),
+                    ApChange::Known(16),
+                ]
+            }
+        },
+        CoreConcreteLibfunc::Blake(libfunc) => match libfunc {
+            BlakeConcreteLibfunc::Compress(_) | BlakeConcreteLibfunc::Finalize(_) => {
+                vec![ApChange::Known(2107)]
+            }
         },
         CoreConcreteLibfunc::BranchAlign(_) => vec![ApChange::FromMetadata],
+        CoreConcreteLibfunc::BoundedInt(libfunc) => match libfunc {
+            BoundedIntConcreteLibfunc::BinaryOperation(_) => vec![ApChange::Known(0)],
+            BoundedIntConcreteLibfunc::DivRem(_) => vec![ApChange::Known(6)],
+        },
+        CoreConcreteLibfunc::Bytes31(libfunc) => match libfunc {
+            Bytes31ConcreteLibfunc::Const(_) | Bytes31ConcreteLibfunc::ToFelt252(_) => {
+                vec![ApChange::Known(0)]
+            }
+            Bytes31ConcreteLibfunc::TryFromFelt252(_) => {
+                vec![ApChange::Known(5), ApChange::Known(6)]
+            }
+            Bytes31ConcreteLibfunc::AppendU8(_) => vec![ApChange::Known(7), ApChange::Known(6)],
+            Bytes31ConcreteLibfunc::SplitU8(_) => vec![ApChange::Known(8)],
+        },
         CoreConcreteLibfunc::Bool(libfunc) => match libfunc {
             BoolConcreteLibfunc::And(_) => vec![ApChange::Known(0)],
             BoolConcreteLibfunc::Not(_) => vec![ApChange::Known(1)],
@@ -95,21 +166,69 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             BoxConcreteLibfunc::Unbox(_) => vec![ApChange::Known(0)],
         },
         CoreConcreteLibfunc::Cast(libfunc) => match libfunc {
-            CastConcreteLibfunc::Downcast(_) => vec![ApChange::Known(2), ApChange::Known(2)],
+            CastConcreteLibfunc::Downcast(libfunc) => {
+                let n_checked_bounds = usize::from(libfunc.checks_lower_bound())
+                    + usize::from(libfunc.checks_upper_bound());
+                // When both bounds may be exceeded, the failure branch guesses which one is.
+                let failure_ap_change = if n_checked_bounds == 2 { 3 } else { 2 };
+                vec![ApChange::Known(1 + n_checked_bounds), ApChange::Known(failure_ap_change)]
+            }
             CastConcreteLibfunc::Upcast(_) => vec![ApChange::Known(0)],
         },
+        CoreConcreteLibfunc::Circuit(libfunc) => match libfunc {
+            CircuitConcreteLibfunc::InitCircuitData(_) => vec![ApChange::Known(1)],
+            CircuitConcreteLibfunc::AddInput(_) => vec![ApChange::Known(3), ApChange::Known(3)],
+            CircuitConcreteLibfunc::GetDescriptor(libfunc) => {
+                let info = &libfunc.circuit_info;
+                let n_operations = info.add_offsets.len() + info.mul_offsets.len();
+                vec![ApChange::Known(2 + 3 * n_operations)]
+            }
+            CircuitConcreteLibfunc::Eval(libfunc) => {
+                let info = &libfunc.circuit_info;
+                let n_used_builtins = usize::from(!info.add_offsets.is_empty())
+                    + usize::from(!info.mul_offsets.is_empty());
+                let one_ap_change = if info.one_index.is_some() { 2 } else { 0 };
+                vec![ApChange::Known(one_ap_change + n_used_builtins)]
+            }
+            CircuitConcreteLibfunc::GetOutput(_) => vec![ApChange::Known(4)],
+        },
+        CoreConcreteLibfunc::ConstAsSpan(_) => vec![ApChange::Known(4)],
         CoreConcreteLibfunc::Ec(libfunc) => match libfunc {
+            EcConcreteLibfunc::Add(_) => vec![ApChange::Known(14)],
             EcConcreteLibfunc::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
             EcConcreteLibfunc::Neg(_) => vec![ApChange::Known(0)],
+            EcConcreteLibfunc::PointDouble(_) => vec![ApChange::Known(11)],
             EcConcreteLibfunc::StateAdd(_) => vec![ApChange::Known(9)],
             EcConcreteLibfunc::TryNew(_) => vec![ApChange::Known(6), ApChange::Known(6)],
             EcConcreteLibfunc::StateFinalize(_) => vec![ApChange::Known(11), ApChange::Known(3)],
             EcConcreteLibfunc::StateInit(_) => vec![ApChange::Known(7)],
-            EcConcreteLibfunc::StateAddMul(_) => vec![ApChange::Known(0)],
+            EcConcreteLibfunc::StateAddMul(_) => {
+                vec![ApChange::Known(if info_provider.software_ec_op() {
+                    SOFTWARE_EC_STATE_ADD_MUL_AP_CHANGE
+                } else {
+                    0
+                })]
+            }
+            EcConcreteLibfunc::Msm(_) => vec![ApChange::Unknown, ApChange::Known(8)],
             EcConcreteLibfunc::PointFromX(_) => vec![ApChange::Known(11), ApChange::Known(7)],
+            EcConcreteLibfunc::PointFromXParity(_) => {
+                vec![ApChange::Known(16), ApChange::Known(7)]
+            }
             EcConcreteLibfunc::UnwrapPoint(_) => vec![ApChange::Known(0)],
             EcConcreteLibfunc::Zero(_) => vec![ApChange::Known(0)],
         },
+        CoreConcreteLibfunc::Ecdsa(libfunc) => match libfunc {
+            EcdsaConcreteLibfunc::Verify(_) => vec![ApChange::Known(0)],
+        },
+        CoreConcreteLibfunc::Encoding(libfunc) => match libfunc {
+            EncodingConcreteLibfunc::HexEncode(_) => vec![ApChange::Unknown, ApChange::Known(8)],
+            EncodingConcreteLibfunc::HexDecode(_) => {
+                vec![ApChange::Unknown, ApChange::Unknown, ApChange::Known(12)]
+            }
+            EncodingConcreteLibfunc::Base64Encode(_) => {
+                vec![ApChange::Unknown, ApChange::Known(12)]
+            }
+        },
         CoreConcreteLibfunc::Drop(_) | CoreConcreteLibfunc::Dup(_) => vec![ApChange::Known(0)],
         CoreConcreteLibfunc::Felt252(libfunc) => match libfunc {
             Felt252Concrete::Const(_) => {
@@ -123,6 +242,7 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
                 vec![ApChange::Known(if op == Felt252BinaryOperator::Div { 1 } else { 0 })]
             }
             Felt252Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Felt252Concrete::Sqrt(_) => vec![ApChange::Known(7), ApChange::Known(3)],
         },
         CoreConcreteLibfunc::FunctionCall(libfunc) => {
             vec![ApChange::FunctionCall(libfunc.function.id.clone())]
@@ -131,6 +251,7 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             GasConcreteLibfunc::WithdrawGas(_) => vec![ApChange::Known(2), ApChange::Known(2)],
             GasConcreteLibfunc::RedepositGas(_) => vec![ApChange::Known(0)],
             GasConcreteLibfunc::GetAvailableGas(_) => vec![ApChange::Known(0)],
+            GasConcreteLibfunc::Checkpoint(_) => vec![ApChange::Known(0)],
             GasConcreteLibfunc::BuiltinWithdrawGas(_) => {
                 let cost_computation_ap_change: usize =
                     BuiltinCostWithdrawGasLibfunc::cost_computation_steps(|token_type| {
@@ -143,16 +264,33 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             }
             GasConcreteLibfunc::GetBuiltinCosts(_) => vec![ApChange::Known(3)],
         },
+        // Pushing the inputs, and running the code.
+        CoreConcreteLibfunc::InlineCasm(libfunc) => {
+            vec![ApChange::Known(libfunc.num_inputs + libfunc.ap_change)]
+        }
+        CoreConcreteLibfunc::Instrumentation(_) => vec![ApChange::Known(0)],
+        CoreConcreteLibfunc::Keccak(libfunc) => match libfunc {
+            KeccakConcreteLibfunc::Permutation(_) => vec![ApChange::Known(233)],
+        },
         CoreConcreteLibfunc::Uint8(libfunc) => uint_ap_change(libfunc),
         CoreConcreteLibfunc::Uint16(libfunc) => uint_ap_change(libfunc),
         CoreConcreteLibfunc::Uint32(libfunc) => uint_ap_change(libfunc),
         CoreConcreteLibfunc::Uint64(libfunc) => uint_ap_change(libfunc),
+        CoreConcreteLibfunc::Uint96(libfunc) => match libfunc {
+            Uint96Concrete::Const(_) | Uint96Concrete::ToFelt252(_) => vec![ApChange::Known(0)],
+            Uint96Concrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+            Uint96Concrete::FromFelt252(_) => vec![ApChange::Known(2), ApChange::Known(7)],
+            Uint96Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Uint96Concrete::OverflowingAdd(_) => vec![ApChange::Known(3), ApChange::Known(3)],
+            Uint96Concrete::WideMul(_) => vec![ApChange::Known(0)],
+        },
         CoreConcreteLibfunc::Uint128(libfunc) => match libfunc {
             Uint128Concrete::Operation(libfunc) => match libfunc.operator {
                 IntOperator::OverflowingAdd | IntOperator::OverflowingSub => {
                     vec![ApChange::Known(2), ApChange::Known(3)]
                 }
             },
+            Uint128Concrete::UncheckedOperation(_) => vec![ApChange::Known(0)],
             Uint128Concrete::Divmod(_) => vec![ApChange::Known(7)],
             Uint128Concrete::GuaranteeMul(_) => vec![ApChange::Known(2)],
             Uint128Concrete::MulGuaranteeVerify(_) => vec![ApChange::Known(15)],
@@ -170,6 +308,7 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             Uint256Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
             Uint256Concrete::Divmod(_) => vec![ApChange::Known(21)],
             Uint256Concrete::SquareRoot(_) => vec![ApChange::Known(25)],
+            Uint256Concrete::InvModN(_) => vec![ApChange::Known(47), ApChange::Known(47)],
         },
         CoreConcreteLibfunc::Uint512(libfunc) => match libfunc {
             Uint512Concrete::DivModU256(_) => vec![ApChange::Known(43)],
@@ -199,6 +338,11 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
                 vec![ApChange::Known(0)]
             }
         },
+        CoreConcreteLibfunc::Modular(libfunc) => match libfunc {
+            ModularConcreteLibfunc::AddMod(_) | ModularConcreteLibfunc::MulMod(_) => {
+                vec![ApChange::Known(26)]
+            }
+        },
         CoreConcreteLibfunc::UnwrapNonZero(_) => vec![ApChange::Known(0)],
         CoreConcreteLibfunc::UnconditionalJump(_) => vec![ApChange::Known(0)],
         CoreConcreteLibfunc::Enum(libfunc) => match libfunc {
@@ -220,9 +364,22 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
         },
         CoreConcreteLibfunc::Pedersen(libfunc) => match libfunc {
             PedersenConcreteLibfunc::PedersenHash(_) => vec![ApChange::Known(0)],
+            PedersenConcreteLibfunc::MerkleVerify(_) => {
+                vec![ApChange::Unknown, ApChange::Unknown, ApChange::Known(7)]
+            }
+            PedersenConcreteLibfunc::HashSpan(_) => vec![ApChange::Unknown, ApChange::Known(7)],
         },
         CoreConcreteLibfunc::Poseidon(libfunc) => match libfunc {
             PoseidonConcreteLibfunc::HadesPermutation(_) => vec![ApChange::Known(0)],
+            PoseidonConcreteLibfunc::MerkleVerify(_) => {
+                vec![ApChange::Unknown, ApChange::Unknown, ApChange::Known(7)]
+            }
+        },
+        CoreConcreteLibfunc::Rlp(libfunc) => match libfunc {
+            RlpConcreteLibfunc::ReadU128Be(_) => vec![ApChange::Unknown, ApChange::Unknown],
+            RlpConcreteLibfunc::DecodeHeader(_) => {
+                vec![ApChange::Unknown, ApChange::Unknown, ApChange::Unknown]
+            }
         },
         CoreConcreteLibfunc::StarkNet(libfunc) => match libfunc {
             StarkNetConcreteLibfunc::ClassHashConst(_)
@@ -267,6 +424,7 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
                 vec![ApChange::Known(0), ApChange::Known(0)]
             }
         },
+        CoreConcreteLibfunc::Oracle(_) => vec![ApChange::Known(3)],
         CoreConcreteLibfunc::Debug(_) => vec![ApChange::Known(0)],
         CoreConcreteLibfunc::SnapshotTake(_) => vec![ApChange::Known(0)],
         CoreConcreteLibfunc::Felt252DictEntry(libfunc) => match libfunc {
@@ -290,6 +448,7 @@ fn uint_ap_change<TUintTraits: UintTraits + IntMulTraits + IsZeroTraits>(
                 vec![ApChange::Known(2), ApChange::Known(3)]
             }
         },
+        UintConcrete::UncheckedOperation(_) => vec![ApChange::Known(0)],
         UintConcrete::SquareRoot(_) => vec![ApChange::Known(6)],
         UintConcrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
         UintConcrete::FromFelt252(_) => vec![ApChange::Known(2), ApChange::Known(7)],
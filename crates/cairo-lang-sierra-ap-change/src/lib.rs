@@ -64,6 +64,8 @@ struct InvocationApChangeInfoProviderForEqGen<'a, TokenUsages: Fn(CostTokenType)
     type_sizes: &'a TypeSizeMap,
     /// Closure providing the token usages for the invocation.
     token_usages: TokenUsages,
+    /// Whether `ec_state_add_mul` is lowered without the EC op builtin.
+    software_ec_op: bool,
 }
 
 impl<'a, TokenUsages: Fn(CostTokenType) -> usize> InvocationApChangeInfoProvider
@@ -76,15 +78,23 @@ impl<'a, TokenUsages: Fn(CostTokenType) -> usize> InvocationApChangeInfoProvider
     fn token_usages(&self, token_type: CostTokenType) -> usize {
         (self.token_usages)(token_type)
     }
+
+    fn software_ec_op(&self) -> bool {
+        self.software_ec_op
+    }
 }
 
 /// Calculates gas information for a given program.
+/// `software_ec_op` - whether `ec_state_add_mul` is lowered without the EC op builtin.
+/// `enum_zero_niche` - whether enums with a zero niche are represented without a variant selector.
 pub fn calc_ap_changes<TokenUsages: Fn(StatementIdx, CostTokenType) -> usize>(
     program: &Program,
+    software_ec_op: bool,
+    enum_zero_niche: bool,
     token_usages: TokenUsages,
 ) -> Result<ApChangeInfo, ApChangeError> {
     let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
-    let type_sizes = get_type_size_map(program, &registry).unwrap();
+    let type_sizes = get_type_size_map(program, &registry, enum_zero_niche).unwrap();
     let equations = generate_equations::generate_equations(program, |idx, libfunc_id| {
         let libfunc = registry.get_libfunc(libfunc_id)?;
         core_libfunc_ap_change::core_libfunc_ap_change(
@@ -92,6 +102,7 @@ pub fn calc_ap_changes<TokenUsages: Fn(StatementIdx, CostTokenType) -> usize>(
             &InvocationApChangeInfoProviderForEqGen {
                 type_sizes: &type_sizes,
                 token_usages: |token_type| token_usages(idx, token_type),
+                software_ec_op,
             },
         )
         .into_iter()
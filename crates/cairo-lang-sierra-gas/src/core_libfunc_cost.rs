@@ -7,8 +7,20 @@ use itertools::zip_eq;
 
 use crate::core_libfunc_cost_base::{core_libfunc_postcost, core_libfunc_precost, CostOperations};
 pub use crate::core_libfunc_cost_base::{
-    InvocationCostInfoProvider, DICT_SQUASH_FIXED_COST, DICT_SQUASH_REPEATED_ACCESS_COST,
-    DICT_SQUASH_UNIQUE_KEY_COST, SEGMENT_ARENA_ALLOCATION_COST,
+    InvocationCostInfoProvider, ARRAY_EXTEND_FROM_SPAN_FIXED_COST,
+    ARRAY_EXTEND_FROM_SPAN_PER_CELL_COST, BASE64_ENCODE_FIXED_COST, BASE64_ENCODE_PER_GROUP_COST,
+    BYTES_READ_U128_BE_FIXED_COST, BYTES_READ_U128_BE_MAX_BYTES, BYTES_READ_U128_BE_PER_BYTE_COST,
+    DICT_SQUASH_FIXED_COST, DICT_SQUASH_REPEATED_ACCESS_COST, DICT_SQUASH_UNIQUE_KEY_COST,
+    EC_MSM_FIXED_COST, EC_MSM_PER_PAIR_COST, HEX_DECODE_FIXED_COST, HEX_DECODE_PER_BYTE_COST,
+    HEX_ENCODE_FIXED_COST, HEX_ENCODE_PER_BYTE_COST, MERKLE_VERIFY_PEDERSEN_FIXED_COST,
+    MERKLE_VERIFY_PEDERSEN_PER_LEVEL_COST, MERKLE_VERIFY_POSEIDON_FIXED_COST,
+    MERKLE_VERIFY_POSEIDON_PER_LEVEL_COST, PEDERSEN_HASH_SPAN_FIXED_COST,
+    PEDERSEN_HASH_SPAN_PER_ELEMENT_COST, RLP_DECODE_HEADER_FIXED_COST,
+    RLP_DECODE_HEADER_MAX_LENGTH_BYTES, RLP_DECODE_HEADER_PER_LENGTH_BYTE_COST,
+    SEGMENT_ARENA_ALLOCATION_COST, SOFTWARE_EC_OP_FIXED_COST, SOFTWARE_EC_OP_N_BITS,
+    SOFTWARE_EC_OP_PER_BIT_COST, SPAN_BINARY_SEARCH_FIXED_COST, SPAN_BINARY_SEARCH_ITERATION_COST,
+    SPAN_EQ_FIXED_COST, SPAN_EQ_PER_CELL_COST, SPAN_SORT_VERIFIED_FIXED_COST,
+    SPAN_SORT_VERIFIED_PER_ELEMENT_COST,
 };
 use crate::gas_info::GasInfo;
 pub use crate::starknet_libfunc_cost_base::SYSTEM_CALL_COST;
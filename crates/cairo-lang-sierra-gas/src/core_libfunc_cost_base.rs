@@ -1,11 +1,18 @@
 use std::iter;
 
 use cairo_lang_sierra::extensions::array::ArrayConcreteLibfunc;
+use cairo_lang_sierra::extensions::bitfield::BitfieldConcreteLibfunc;
+use cairo_lang_sierra::extensions::blake::BlakeConcreteLibfunc;
 use cairo_lang_sierra::extensions::boolean::BoolConcreteLibfunc;
+use cairo_lang_sierra::extensions::bounded_int::BoundedIntConcreteLibfunc;
 use cairo_lang_sierra::extensions::boxing::BoxConcreteLibfunc;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
 use cairo_lang_sierra::extensions::casts::CastConcreteLibfunc;
+use cairo_lang_sierra::extensions::circuit::CircuitConcreteLibfunc;
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc::{self, *};
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
+use cairo_lang_sierra::extensions::ecdsa::EcdsaConcreteLibfunc;
+use cairo_lang_sierra::extensions::encoding::EncodingConcreteLibfunc;
 use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
 use cairo_lang_sierra::extensions::felt252::{
     Felt252BinaryOperationConcrete, Felt252BinaryOperator, Felt252Concrete,
@@ -15,7 +22,7 @@ use cairo_lang_sierra::extensions::felt252_dict::{
 };
 use cairo_lang_sierra::extensions::function_call::FunctionCallConcreteLibfunc;
 use cairo_lang_sierra::extensions::gas::GasConcreteLibfunc::{
-    BuiltinWithdrawGas, GetAvailableGas, GetBuiltinCosts, RedepositGas, WithdrawGas,
+    BuiltinWithdrawGas, Checkpoint, GetAvailableGas, GetBuiltinCosts, RedepositGas, WithdrawGas,
 };
 use cairo_lang_sierra::extensions::gas::{BuiltinCostWithdrawGasLibfunc, CostTokenType};
 use cairo_lang_sierra::extensions::int::signed::{SintConcrete, SintTraits};
@@ -24,14 +31,18 @@ use cairo_lang_sierra::extensions::int::unsigned::{UintConcrete, UintTraits};
 use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
 use cairo_lang_sierra::extensions::int::unsigned256::Uint256Concrete;
 use cairo_lang_sierra::extensions::int::unsigned512::Uint512Concrete;
+use cairo_lang_sierra::extensions::int::unsigned96::Uint96Concrete;
 use cairo_lang_sierra::extensions::int::{IntMulTraits, IntOperator};
 use cairo_lang_sierra::extensions::is_zero::IsZeroTraits;
+use cairo_lang_sierra::extensions::keccak::KeccakConcreteLibfunc;
 use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc::{
     AllocLocal, FinalizeLocals, Rename, StoreLocal, StoreTemp,
 };
+use cairo_lang_sierra::extensions::modular::{ModularConcreteLibfunc, MOD_BUILTIN_N_LIMBS};
 use cairo_lang_sierra::extensions::nullable::NullableConcreteLibfunc;
 use cairo_lang_sierra::extensions::pedersen::PedersenConcreteLibfunc;
 use cairo_lang_sierra::extensions::poseidon::PoseidonConcreteLibfunc;
+use cairo_lang_sierra::extensions::rlp::RlpConcreteLibfunc;
 use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
 use cairo_lang_sierra::ids::ConcreteTypeId;
 use cairo_lang_sierra::program::Function;
@@ -53,11 +64,143 @@ pub const DICT_SQUASH_REPEATED_ACCESS_COST: ConstCost =
 /// The cost not dependent on the number of keys and access.
 pub const DICT_SQUASH_FIXED_COST: ConstCost = ConstCost { steps: 57, holes: 0, range_checks: 3 };
 
+/// The cost of the Blake2s compression libfuncs, excluding the Bitwise builtin instances.
+pub const BLAKE2S_COMPRESSION_COST: ConstCost = ConstCost::steps(4061);
+/// The number of Bitwise builtin instances used by the Blake2s compression libfuncs: one for
+/// mixing in the byte count, 12 for each of the 80 applications of the mixing function, and two for
+/// each word of the output state.
+pub const BLAKE2S_COMPRESSION_BITWISE_INSTANCES: i32 = 977;
+
+/// The cost of `keccak_permutation`: splitting the 7 input lanes crossing word boundaries, packing
+/// the 8 input words, and splitting the 8 output words back into range checked lanes.
+pub const KECCAK_PERMUTATION_COST: ConstCost = ConstCost { steps: 286, holes: 0, range_checks: 92 };
+
+/// The cost of `u384_add_mod` and `u384_mul_mod`: writing the operands and offsets to the
+/// RangeCheck96 builtin, filling the mod builtin instance, copying the result, and validating that
+/// it is smaller than the modulus. Each of the 19 cells of the values is charged as a range check.
+pub const MOD_BUILTIN_OPERATION_COST: ConstCost =
+    ConstCost { steps: 48, holes: 0, range_checks: 19 };
+
 /// The cost of allocating a segment in the segment arena. This is charged to pay for the
 /// finalization step of the segment arena.
 pub const SEGMENT_ARENA_ALLOCATION_COST: ConstCost =
     ConstCost { steps: 8, holes: 0, range_checks: 0 };
 
+/// The cost per each cell copied by `array_extend_from_span`. This cost is withdrawn from the gas
+/// builtin at runtime, as the number of copied cells is only known then.
+pub const ARRAY_EXTEND_FROM_SPAN_PER_CELL_COST: ConstCost =
+    ConstCost { steps: 6, holes: 0, range_checks: 0 };
+/// The cost of `array_extend_from_span` not dependent on the number of copied cells.
+pub const ARRAY_EXTEND_FROM_SPAN_FIXED_COST: ConstCost =
+    ConstCost { steps: 20, holes: 0, range_checks: 1 };
+
+/// The cost per each cell compared by `span_eq`. This cost is withdrawn from the gas builtin at
+/// runtime, and refunded for the cells left uncompared when a difference is found.
+pub const SPAN_EQ_PER_CELL_COST: ConstCost = ConstCost { steps: 8, holes: 0, range_checks: 0 };
+/// The cost of `span_eq` not dependent on the number of compared cells.
+pub const SPAN_EQ_FIXED_COST: ConstCost = ConstCost { steps: 27, holes: 0, range_checks: 1 };
+
+/// The cost per each iteration of the search loop of `span_binary_search`. This cost is withdrawn
+/// from the gas builtin at runtime, as the number of iterations is only known then.
+pub const SPAN_BINARY_SEARCH_ITERATION_COST: ConstCost =
+    ConstCost { steps: 24, holes: 0, range_checks: 3 };
+/// The cost of a completed `span_binary_search` not dependent on the number of iterations.
+pub const SPAN_BINARY_SEARCH_FIXED_COST: ConstCost =
+    ConstCost { steps: 22, holes: 0, range_checks: 0 };
+
+/// The cost per each element verified by `span_sort_verified`. This cost is withdrawn from the gas
+/// builtin at runtime, as the number of elements is only known then.
+pub const SPAN_SORT_VERIFIED_PER_ELEMENT_COST: ConstCost =
+    ConstCost { steps: 17, holes: 0, range_checks: 3 };
+/// The cost of `span_sort_verified` not dependent on the number of elements.
+pub const SPAN_SORT_VERIFIED_FIXED_COST: ConstCost =
+    ConstCost { steps: 25, holes: 0, range_checks: 1 };
+
+/// The cost per each level of the path verified by `merkle_verify_pedersen`, not including the
+/// cost of the Pedersen builtin itself. This cost is withdrawn from the gas builtin at runtime, as
+/// the length of the path is only known then.
+pub const MERKLE_VERIFY_PEDERSEN_PER_LEVEL_COST: ConstCost =
+    ConstCost { steps: 19, holes: 0, range_checks: 1 };
+/// The cost of `merkle_verify_pedersen` not dependent on the length of the path.
+pub const MERKLE_VERIFY_PEDERSEN_FIXED_COST: ConstCost =
+    ConstCost { steps: 30, holes: 0, range_checks: 1 };
+
+/// The cost per each element hashed by `pedersen_hash_span`, not including the cost of the
+/// Pedersen builtin itself. This cost is withdrawn from the gas builtin at runtime, as the length
+/// of the span is only known then.
+pub const PEDERSEN_HASH_SPAN_PER_ELEMENT_COST: ConstCost =
+    ConstCost { steps: 8, holes: 0, range_checks: 0 };
+/// The cost of `pedersen_hash_span` not dependent on the length of the span.
+pub const PEDERSEN_HASH_SPAN_FIXED_COST: ConstCost =
+    ConstCost { steps: 23, holes: 0, range_checks: 1 };
+
+/// The cost per each level of the path verified by `merkle_verify_poseidon`, not including the
+/// cost of the Poseidon builtin itself. This cost is withdrawn from the gas builtin at runtime, as
+/// the length of the path is only known then.
+pub const MERKLE_VERIFY_POSEIDON_PER_LEVEL_COST: ConstCost =
+    ConstCost { steps: 21, holes: 0, range_checks: 1 };
+/// The cost of `merkle_verify_poseidon` not dependent on the length of the path.
+pub const MERKLE_VERIFY_POSEIDON_FIXED_COST: ConstCost =
+    ConstCost { steps: 30, holes: 0, range_checks: 1 };
+
+/// The cost per each byte encoded by `bytes_hex_encode`. This cost is withdrawn from the gas
+/// builtin at runtime, as the length of the array is only known then.
+pub const HEX_ENCODE_PER_BYTE_COST: ConstCost = ConstCost { steps: 18, holes: 0, range_checks: 3 };
+/// The cost of `bytes_hex_encode` not dependent on the length of the array.
+pub const HEX_ENCODE_FIXED_COST: ConstCost = ConstCost { steps: 25, holes: 0, range_checks: 1 };
+
+/// The cost per each byte decoded by `bytes_hex_decode`, from two hex digits. This cost is
+/// withdrawn from the gas builtin at runtime, as the length of the array is only known then.
+pub const HEX_DECODE_PER_BYTE_COST: ConstCost = ConstCost { steps: 38, holes: 0, range_checks: 8 };
+/// The cost of `bytes_hex_decode` not dependent on the length of the array.
+pub const HEX_DECODE_FIXED_COST: ConstCost = ConstCost { steps: 93, holes: 0, range_checks: 2 };
+
+/// The cost per each 3-byte group encoded by `bytes_base64_encode`. This cost is withdrawn from the
+/// gas builtin at runtime, as the length of the array is only known then.
+pub const BASE64_ENCODE_PER_GROUP_COST: ConstCost =
+    ConstCost { steps: 40, holes: 0, range_checks: 7 };
+/// The cost of `bytes_base64_encode` not dependent on the length of the array.
+pub const BASE64_ENCODE_FIXED_COST: ConstCost = ConstCost { steps: 31, holes: 0, range_checks: 4 };
+
+/// The cost per each byte read by `bytes_read_u128_be`.
+pub const BYTES_READ_U128_BE_PER_BYTE_COST: ConstCost =
+    ConstCost { steps: 6, holes: 0, range_checks: 0 };
+/// The cost of `bytes_read_u128_be` not dependent on the number of bytes read.
+pub const BYTES_READ_U128_BE_FIXED_COST: ConstCost =
+    ConstCost { steps: 20, holes: 0, range_checks: 2 };
+/// The maximal number of bytes read by `bytes_read_u128_be`. As the number of bytes is bounded,
+/// the reading is charged for the maximal number of bytes, instead of at runtime.
+pub const BYTES_READ_U128_BE_MAX_BYTES: i32 = 16;
+
+/// The cost per each byte of the payload length read by `rlp_decode_header`, for the long forms.
+pub const RLP_DECODE_HEADER_PER_LENGTH_BYTE_COST: ConstCost =
+    ConstCost { steps: 6, holes: 0, range_checks: 0 };
+/// The cost of `rlp_decode_header` not dependent on the number of payload length bytes.
+pub const RLP_DECODE_HEADER_FIXED_COST: ConstCost =
+    ConstCost { steps: 50, holes: 0, range_checks: 6 };
+/// The maximal number of bytes of the payload length decoded by `rlp_decode_header`, so that the
+/// length fits in a u32. The decoding is charged for the maximal number of bytes.
+pub const RLP_DECODE_HEADER_MAX_LENGTH_BYTES: i32 = 4;
+
+/// The cost per each bit of the scalar in the lowering of `ec_state_add_mul` without the EC op
+/// builtin. Charged by the more expensive path, where the doubled point is added to the state.
+pub const SOFTWARE_EC_OP_PER_BIT_COST: ConstCost =
+    ConstCost { steps: 27, holes: 0, range_checks: 0 };
+/// The cost of the lowering of `ec_state_add_mul` without the EC op builtin not dependent on the
+/// number of bits.
+pub const SOFTWARE_EC_OP_FIXED_COST: ConstCost =
+    ConstCost { steps: 124, holes: 0, range_checks: 0 };
+/// The number of bits of the scalar processed by the lowering of `ec_state_add_mul` without the EC
+/// op builtin. The scalar is a felt252, so all of its bits are always processed.
+pub const SOFTWARE_EC_OP_N_BITS: i32 = 252;
+
+/// The cost per each `(scalar, point)` pair of `ec_msm`, not including the cost of the EC op
+/// builtin itself. This cost is withdrawn from the gas builtin at runtime, as the number of pairs
+/// is only known then.
+pub const EC_MSM_PER_PAIR_COST: ConstCost = ConstCost { steps: 14, holes: 0, range_checks: 0 };
+/// The cost of `ec_msm` not dependent on the number of pairs.
+pub const EC_MSM_FIXED_COST: ConstCost = ConstCost { steps: 44, holes: 0, range_checks: 1 };
+
 /// The operation required for extracting a libfunc's cost.
 pub trait CostOperations {
     type CostType: Clone;
@@ -108,12 +251,18 @@ pub trait InvocationCostInfoProvider {
     fn token_usages(&self, token_type: CostTokenType) -> usize;
     /// Provides the ap change variable value of the current statement.
     fn ap_change_var_value(&self) -> usize;
+    /// Whether `ec_state_add_mul` is lowered without the EC op builtin.
+    fn software_ec_op(&self) -> bool;
 }
 
 impl<InfoProvider: InvocationCostInfoProvider> CostInfoProvider for InfoProvider {
     fn type_size(&self, ty: &ConcreteTypeId) -> usize {
         self.type_size(ty)
     }
+
+    fn software_ec_op(&self) -> bool {
+        self.software_ec_op()
+    }
 }
 
 /// Returns a postcost value for a libfunc - the cost of step token.
@@ -128,6 +277,42 @@ pub fn core_libfunc_cost(
                 function: function.clone(),
             }]
         }
+        Bitfield(libfunc) => {
+            bitfield_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect()
+        }
+        Blake(libfunc) => match libfunc {
+            BlakeConcreteLibfunc::Compress(_) | BlakeConcreteLibfunc::Finalize(_) => {
+                vec![BranchCost::Regular {
+                    const_cost: BLAKE2S_COMPRESSION_COST,
+                    pre_cost: PreCost(OrderedHashMap::from_iter([(
+                        CostTokenType::Bitwise,
+                        BLAKE2S_COMPRESSION_BITWISE_INSTANCES,
+                    )])),
+                }]
+            }
+        },
+        BoundedInt(libfunc) => match libfunc {
+            BoundedIntConcreteLibfunc::BinaryOperation(_) => vec![ConstCost::default().into()],
+            BoundedIntConcreteLibfunc::DivRem(_) => {
+                vec![ConstCost { steps: 9, holes: 0, range_checks: 4 }.into()]
+            }
+        },
+        Bytes31(libfunc) => match libfunc {
+            Bytes31ConcreteLibfunc::Const(_) | Bytes31ConcreteLibfunc::ToFelt252(_) => {
+                vec![ConstCost::steps(0).into()]
+            }
+            Bytes31ConcreteLibfunc::TryFromFelt252(_) => vec![
+                ConstCost { steps: 7, holes: 0, range_checks: 3 }.into(),
+                ConstCost { steps: 9, holes: 0, range_checks: 3 }.into(),
+            ],
+            Bytes31ConcreteLibfunc::AppendU8(_) => vec![
+                ConstCost { steps: 9, holes: 0, range_checks: 3 }.into(),
+                ConstCost { steps: 10, holes: 0, range_checks: 4 }.into(),
+            ],
+            Bytes31ConcreteLibfunc::SplitU8(_) => {
+                vec![ConstCost { steps: 11, holes: 0, range_checks: 5 }.into()]
+            }
+        },
         Bool(libfunc) => match libfunc {
             BoolConcreteLibfunc::And(_) => vec![ConstCost::steps(0).into()],
             BoolConcreteLibfunc::Not(_) => vec![ConstCost::steps(1).into()],
@@ -136,19 +321,29 @@ pub fn core_libfunc_cost(
             BoolConcreteLibfunc::ToFelt252(_) => vec![ConstCost::steps(0).into()],
         },
         Cast(libfunc) => match libfunc {
-            CastConcreteLibfunc::Downcast(_) => {
+            CastConcreteLibfunc::Downcast(libfunc) => {
+                let n_checked_bounds = i32::from(libfunc.checks_lower_bound())
+                    + i32::from(libfunc.checks_upper_bound());
+                // When both bounds may be exceeded, the failure branch guesses which one is.
+                let failure_steps = if n_checked_bounds == 2 { 5 } else { 4 };
                 vec![
-                    (ConstCost::steps(3) + ConstCost::range_checks(1)).into(),
-                    (ConstCost::steps(4) + ConstCost::range_checks(1)).into(),
+                    (ConstCost::steps(1 + 2 * n_checked_bounds)
+                        + ConstCost::range_checks(n_checked_bounds))
+                    .into(),
+                    (ConstCost::steps(failure_steps) + ConstCost::range_checks(1)).into(),
                 ]
             }
             CastConcreteLibfunc::Upcast(_) => vec![ConstCost::default().into()],
         },
+        Circuit(libfunc) => circuit_libfunc_cost(libfunc),
+        ConstAsSpan(_) => vec![ConstCost::steps(4).into()],
         Ec(libfunc) => match libfunc {
+            EcConcreteLibfunc::Add(_) => vec![ConstCost::steps(19).into()],
             EcConcreteLibfunc::IsZero(_) => {
                 vec![ConstCost::steps(1).into(), ConstCost::steps(1).into()]
             }
             EcConcreteLibfunc::Neg(_) => vec![ConstCost::default().into()],
+            EcConcreteLibfunc::PointDouble(_) => vec![ConstCost::steps(12).into()],
             EcConcreteLibfunc::StateAdd(_) => vec![ConstCost::steps(10).into()],
             EcConcreteLibfunc::TryNew(_) => {
                 vec![ConstCost::steps(7).into(), ConstCost::steps(7).into()]
@@ -159,17 +354,58 @@ pub fn core_libfunc_cost(
             EcConcreteLibfunc::StateInit(_) => vec![ConstCost::steps(7).into()],
             EcConcreteLibfunc::StateAddMul(_) => {
                 vec![BranchCost::Regular {
-                    const_cost: ConstCost::steps(5),
+                    const_cost: if info_provider.software_ec_op() {
+                        bounded_loop_cost(
+                            SOFTWARE_EC_OP_FIXED_COST,
+                            SOFTWARE_EC_OP_PER_BIT_COST,
+                            SOFTWARE_EC_OP_N_BITS,
+                        )
+                    } else {
+                        ConstCost::steps(5)
+                    },
                     pre_cost: PreCost::builtin(CostTokenType::EcOp),
                 }]
             }
+            EcConcreteLibfunc::Msm(_) => {
+                // The multiplication loop itself is charged at runtime, see
+                // `EC_MSM_PER_PAIR_COST`.
+                vec![
+                    EC_MSM_FIXED_COST.into(),
+                    (ConstCost::steps(10) + ConstCost::range_checks(1)).into(),
+                ]
+            }
             EcConcreteLibfunc::PointFromX(_) => vec![
                 (ConstCost::steps(14) + ConstCost::range_checks(3)).into(), // Success.
                 ConstCost::steps(9).into(),                                 // Failure.
             ],
+            EcConcreteLibfunc::PointFromXParity(_) => vec![
+                (ConstCost::steps(21) + ConstCost::range_checks(3)).into(), // Success.
+                ConstCost::steps(9).into(),                                 // Failure.
+            ],
             EcConcreteLibfunc::UnwrapPoint(_) => vec![ConstCost::default().into()],
             EcConcreteLibfunc::Zero(_) => vec![ConstCost::default().into()],
         },
+        // The ECDSA builtin is not supported by the Starknet OS, so it has no builtin cost, and
+        // only the steps of the libfunc are charged.
+        Ecdsa(libfunc) => match libfunc {
+            EcdsaConcreteLibfunc::Verify(_) => vec![ConstCost::steps(2).into()],
+        },
+        // The encoding loops are charged at runtime, according to the length of the array.
+        Encoding(libfunc) => match libfunc {
+            EncodingConcreteLibfunc::HexEncode(_) => vec![
+                HEX_ENCODE_FIXED_COST.into(),
+                (ConstCost::steps(10) + ConstCost::range_checks(1)).into(),
+            ],
+            EncodingConcreteLibfunc::HexDecode(_) => vec![
+                HEX_DECODE_FIXED_COST.into(),
+                HEX_DECODE_FIXED_COST.into(),
+                (ConstCost::steps(16) + ConstCost::range_checks(2)).into(),
+            ],
+            EncodingConcreteLibfunc::Base64Encode(_) => vec![
+                BASE64_ENCODE_FIXED_COST.into(),
+                (ConstCost::steps(16) + ConstCost::range_checks(4)).into(),
+            ],
+        },
         Gas(libfunc) => match libfunc {
             WithdrawGas(_) => vec![
                 BranchCost::WithdrawGas {
@@ -185,6 +421,7 @@ pub fn core_libfunc_cost(
             ],
             RedepositGas(_) => vec![BranchCost::RedepositGas],
             GetAvailableGas(_) => vec![ConstCost::default().into()],
+            Checkpoint(_) => vec![ConstCost::default().into()],
             BuiltinWithdrawGas(_) => {
                 vec![
                     BranchCost::WithdrawGas {
@@ -226,6 +463,52 @@ pub fn core_libfunc_cost(
                     ]
                 }
             }
+            ArrayConcreteLibfunc::GetUsize(libfunc) => {
+                if info_provider.type_size(&libfunc.ty) == 1 {
+                    vec![
+                        (ConstCost::steps(6) + ConstCost::range_checks(1)).into(),
+                        (ConstCost::steps(5) + ConstCost::range_checks(1)).into(),
+                    ]
+                } else {
+                    vec![
+                        (ConstCost::steps(7) + ConstCost::range_checks(1)).into(),
+                        (ConstCost::steps(6) + ConstCost::range_checks(1)).into(),
+                    ]
+                }
+            }
+            ArrayConcreteLibfunc::ExtendFromSpan(_) => {
+                // The copying itself is charged at runtime, see
+                // `ARRAY_EXTEND_FROM_SPAN_PER_CELL_COST`.
+                vec![
+                    ARRAY_EXTEND_FROM_SPAN_FIXED_COST.into(),
+                    (ConstCost::steps(7) + ConstCost::range_checks(1)).into(),
+                ]
+            }
+            ArrayConcreteLibfunc::SpanEq(_) => {
+                // The comparison itself is charged at runtime, see `SPAN_EQ_PER_CELL_COST`.
+                vec![
+                    SPAN_EQ_FIXED_COST.into(),
+                    SPAN_EQ_FIXED_COST.into(),
+                    (ConstCost::steps(10) + ConstCost::range_checks(1)).into(),
+                ]
+            }
+            ArrayConcreteLibfunc::SpanBinarySearch(_) => {
+                // The search loop itself is charged at runtime, see
+                // `SPAN_BINARY_SEARCH_ITERATION_COST`.
+                vec![
+                    SPAN_BINARY_SEARCH_FIXED_COST.into(),
+                    SPAN_BINARY_SEARCH_FIXED_COST.into(),
+                    (ConstCost::steps(25) + ConstCost::range_checks(1)).into(),
+                ]
+            }
+            ArrayConcreteLibfunc::SpanSortVerified(_) => {
+                // The verification itself is charged at runtime, see
+                // `SPAN_SORT_VERIFIED_PER_ELEMENT_COST`.
+                vec![
+                    SPAN_SORT_VERIFIED_FIXED_COST.into(),
+                    (ConstCost::steps(7) + ConstCost::range_checks(1)).into(),
+                ]
+            }
             ArrayConcreteLibfunc::Slice(libfunc) => {
                 if info_provider.type_size(&libfunc.ty) == 1 {
                     vec![
@@ -245,11 +528,25 @@ pub fn core_libfunc_cost(
                         .into(),
                 ]
             }
+            ArrayConcreteLibfunc::LenExceeds(_) => vec![
+                ConstCost { steps: 4, holes: 0, range_checks: 1 }.into(),
+                ConstCost { steps: 5, holes: 0, range_checks: 1 }.into(),
+            ],
+        },
+        // Pushing the inputs, and running the code.
+        InlineCasm(libfunc) => {
+            vec![ConstCost::steps((libfunc.num_inputs + libfunc.num_instructions) as i32).into()]
+        }
+        Instrumentation(_) => vec![ConstCost::steps(1).into()],
+        // The Keccak builtin is not supported by the Starknet OS, so it has no builtin cost.
+        Keccak(libfunc) => match libfunc {
+            KeccakConcreteLibfunc::Permutation(_) => vec![KECCAK_PERMUTATION_COST.into()],
         },
         Uint8(libfunc) => uint_libfunc_cost(libfunc),
         Uint16(libfunc) => uint_libfunc_cost(libfunc),
         Uint32(libfunc) => uint_libfunc_cost(libfunc),
         Uint64(libfunc) => uint_libfunc_cost(libfunc),
+        Uint96(libfunc) => u96_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
         Uint128(libfunc) => u128_libfunc_cost(libfunc),
         Uint256(libfunc) => u256_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
         Uint512(libfunc) => u512_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
@@ -286,6 +583,12 @@ pub fn core_libfunc_cost(
             FinalizeLocals(_) => vec![ConstCost::steps(1).into()],
             Rename(_) => vec![ConstCost::default().into()],
         },
+        // The mod builtins are not supported by the Starknet OS, so they have no builtin cost.
+        Modular(libfunc) => match libfunc {
+            ModularConcreteLibfunc::AddMod(_) | ModularConcreteLibfunc::MulMod(_) => {
+                vec![MOD_BUILTIN_OPERATION_COST.into()]
+            }
+        },
         UnconditionalJump(_) => {
             vec![ConstCost::steps(1).into()]
         }
@@ -296,7 +599,19 @@ pub fn core_libfunc_cost(
                 match n {
                     0 => vec![],
                     1 => vec![ConstCost::default().into()],
-                    2 => vec![ConstCost::steps(1).into(); 2],
+                    2 => {
+                        let enum_size =
+                            info_provider.type_size(&sig.signature.param_signatures[0].ty);
+                        let first_variant_size =
+                            info_provider.type_size(&sig.signature.branch_signatures[0].vars[0].ty);
+                        if first_variant_size >= enum_size {
+                            // The enum has a zero niche and its first variant is the non-zero one,
+                            // so matching the second variant requires an additional jump.
+                            vec![ConstCost::steps(1).into(), ConstCost::steps(2).into()]
+                        } else {
+                            vec![ConstCost::steps(1).into(); 2]
+                        }
+                    }
                     _ => chain!(
                         iter::once(ConstCost::steps(1).into()),
                         itertools::repeat_n(ConstCost::steps(2).into(), n - 1)
@@ -337,12 +652,59 @@ pub fn core_libfunc_cost(
                     pre_cost: PreCost::builtin(CostTokenType::Pedersen),
                 }]
             }
+            PedersenConcreteLibfunc::MerkleVerify(_) => {
+                // The hashing loop itself is charged at runtime, see
+                // `MERKLE_VERIFY_PEDERSEN_PER_LEVEL_COST`.
+                vec![
+                    MERKLE_VERIFY_PEDERSEN_FIXED_COST.into(),
+                    MERKLE_VERIFY_PEDERSEN_FIXED_COST.into(),
+                    (ConstCost::steps(9) + ConstCost::range_checks(1)).into(),
+                ]
+            }
+            PedersenConcreteLibfunc::HashSpan(_) => {
+                // The hashing loop itself is charged at runtime, see
+                // `PEDERSEN_HASH_SPAN_PER_ELEMENT_COST`.
+                vec![
+                    PEDERSEN_HASH_SPAN_FIXED_COST.into(),
+                    (ConstCost::steps(9) + ConstCost::range_checks(1)).into(),
+                ]
+            }
         },
         Poseidon(libfunc) => match libfunc {
             PoseidonConcreteLibfunc::HadesPermutation(_) => vec![BranchCost::Regular {
                 const_cost: ConstCost::steps(3),
                 pre_cost: PreCost::builtin(CostTokenType::Poseidon),
             }],
+            PoseidonConcreteLibfunc::MerkleVerify(_) => {
+                // The hashing loop itself is charged at runtime, see
+                // `MERKLE_VERIFY_POSEIDON_PER_LEVEL_COST`.
+                vec![
+                    MERKLE_VERIFY_POSEIDON_FIXED_COST.into(),
+                    MERKLE_VERIFY_POSEIDON_FIXED_COST.into(),
+                    (ConstCost::steps(9) + ConstCost::range_checks(1)).into(),
+                ]
+            }
+        },
+        // The reading loops are bounded, so they are charged for their maximal number of
+        // iterations.
+        Rlp(libfunc) => match libfunc {
+            RlpConcreteLibfunc::ReadU128Be(_) => vec![
+                bounded_loop_cost(
+                    BYTES_READ_U128_BE_FIXED_COST,
+                    BYTES_READ_U128_BE_PER_BYTE_COST,
+                    BYTES_READ_U128_BE_MAX_BYTES,
+                )
+                .into(),
+                (ConstCost::steps(11) + ConstCost::range_checks(2)).into(),
+            ],
+            RlpConcreteLibfunc::DecodeHeader(_) => {
+                let cost = bounded_loop_cost(
+                    RLP_DECODE_HEADER_FIXED_COST,
+                    RLP_DECODE_HEADER_PER_LENGTH_BYTE_COST,
+                    RLP_DECODE_HEADER_MAX_LENGTH_BYTES,
+                );
+                vec![cost.clone().into(), cost.clone().into(), cost.into()]
+            }
         },
         CoreConcreteLibfunc::StarkNet(libfunc) => {
             starknet_libfunc_cost_base(libfunc).into_iter().map(BranchCost::from).collect()
@@ -354,6 +716,9 @@ pub fn core_libfunc_cost(
                 vec![ConstCost::steps(1).into(), ConstCost::steps(1).into()]
             }
         },
+        CoreConcreteLibfunc::Oracle(_) => {
+            vec![ConstCost { steps: 3, holes: 0, range_checks: 1 }.into()]
+        }
         CoreConcreteLibfunc::Debug(_) => vec![ConstCost::steps(1).into()],
         CoreConcreteLibfunc::SnapshotTake(_) => vec![ConstCost::default().into()],
         CoreConcreteLibfunc::Felt252DictEntry(libfunc) => match libfunc {
@@ -425,6 +790,10 @@ impl CostInfoProvider for DummyCostInfoProvider {
     fn type_size(&self, _ty: &ConcreteTypeId) -> usize {
         0
     }
+
+    fn software_ec_op(&self) -> bool {
+        false
+    }
 }
 
 /// Returns a precost value for a libfunc - the cost of non-step tokens.
@@ -482,7 +851,10 @@ fn uint_libfunc_cost<TUintTraits: UintTraits + IsZeroTraits + IntMulTraits>(
     libfunc: &UintConcrete<TUintTraits>,
 ) -> Vec<BranchCost> {
     match libfunc {
-        UintConcrete::Const(_) | UintConcrete::ToFelt252(_) | UintConcrete::WideMul(_) => {
+        UintConcrete::Const(_)
+        | UintConcrete::ToFelt252(_)
+        | UintConcrete::WideMul(_)
+        | UintConcrete::UncheckedOperation(_) => {
             vec![ConstCost::default().into()]
         }
         UintConcrete::Operation(libfunc) => match libfunc.operator {
@@ -524,6 +896,25 @@ fn uint_libfunc_cost<TUintTraits: UintTraits + IsZeroTraits + IntMulTraits>(
     }
 }
 
+/// Returns costs for u96 libfuncs.
+fn u96_libfunc_cost(libfunc: &Uint96Concrete) -> Vec<ConstCost> {
+    match libfunc {
+        Uint96Concrete::Const(_) | Uint96Concrete::ToFelt252(_) | Uint96Concrete::WideMul(_) => {
+            vec![ConstCost::default()]
+        }
+        Uint96Concrete::Equal(_) => vec![ConstCost::steps(2), ConstCost::steps(3)],
+        Uint96Concrete::IsZero(_) => vec![ConstCost::steps(1), ConstCost::steps(1)],
+        Uint96Concrete::FromFelt252(_) => vec![
+            ConstCost { steps: 4, holes: 0, range_checks: 2 },
+            ConstCost { steps: 10, holes: 0, range_checks: 3 },
+        ],
+        Uint96Concrete::OverflowingAdd(_) => vec![
+            ConstCost { steps: 4, holes: 0, range_checks: 1 },
+            ConstCost { steps: 5, holes: 0, range_checks: 1 },
+        ],
+    }
+}
+
 /// Returns costs for u128 libfuncs.
 fn u128_libfunc_cost(libfunc: &Uint128Concrete) -> Vec<BranchCost> {
     match libfunc {
@@ -544,7 +935,9 @@ fn u128_libfunc_cost(libfunc: &Uint128Concrete) -> Vec<BranchCost> {
         Uint128Concrete::MulGuaranteeVerify(_) => {
             vec![ConstCost { steps: 23, holes: 0, range_checks: 9 }.into()]
         }
-        Uint128Concrete::Const(_) | Uint128Concrete::ToFelt252(_) => {
+        Uint128Concrete::Const(_)
+        | Uint128Concrete::ToFelt252(_)
+        | Uint128Concrete::UncheckedOperation(_) => {
             vec![ConstCost::default().into()]
         }
         Uint128Concrete::FromFelt252(_) => {
@@ -577,6 +970,36 @@ fn u128_libfunc_cost(libfunc: &Uint128Concrete) -> Vec<BranchCost> {
     }
 }
 
+/// Returns costs for circuit libfuncs.
+fn circuit_libfunc_cost(libfunc: &CircuitConcreteLibfunc) -> Vec<BranchCost> {
+    match libfunc {
+        CircuitConcreteLibfunc::InitCircuitData(libfunc) => {
+            // The values of the circuit are reserved in the RangeCheck96 builtin, and each of their
+            // limbs is charged as a range check.
+            let n_limbs = libfunc.circuit_info.n_values * MOD_BUILTIN_N_LIMBS;
+            vec![(ConstCost::steps(1) + ConstCost::range_checks(n_limbs as i32)).into()]
+        }
+        CircuitConcreteLibfunc::AddInput(_) => {
+            vec![ConstCost::steps(8).into(), ConstCost::steps(8).into()]
+        }
+        CircuitConcreteLibfunc::GetDescriptor(libfunc) => {
+            let info = &libfunc.circuit_info;
+            let n_operations = (info.add_offsets.len() + info.mul_offsets.len()) as i32;
+            vec![ConstCost::steps(2 + 6 * n_operations).into()]
+        }
+        CircuitConcreteLibfunc::Eval(libfunc) => {
+            let info = &libfunc.circuit_info;
+            let one_steps = if info.one_index.is_some() { 6 } else { 0 };
+            let n_used_builtins = [&info.add_offsets, &info.mul_offsets]
+                .into_iter()
+                .filter(|offsets| !offsets.is_empty())
+                .count() as i32;
+            vec![ConstCost::steps(one_steps + 8 * n_used_builtins).into()]
+        }
+        CircuitConcreteLibfunc::GetOutput(_) => vec![ConstCost::steps(4).into()],
+    }
+}
+
 /// Returns costs for u256 libfuncs.
 fn u256_libfunc_cost(libfunc: &Uint256Concrete) -> Vec<ConstCost> {
     let steps = |value| ConstCost { steps: value, ..Default::default() };
@@ -586,6 +1009,10 @@ fn u256_libfunc_cost(libfunc: &Uint256Concrete) -> Vec<ConstCost> {
         }
         Uint256Concrete::Divmod(_) => vec![ConstCost { steps: 29, holes: 0, range_checks: 8 }],
         Uint256Concrete::SquareRoot(_) => vec![ConstCost { steps: 30, holes: 0, range_checks: 7 }],
+        Uint256Concrete::InvModN(_) => vec![
+            ConstCost { steps: 43, holes: 0, range_checks: 11 },
+            ConstCost { steps: 48, holes: 0, range_checks: 11 },
+        ],
     }
 }
 
@@ -657,5 +1084,47 @@ fn felt252_libfunc_cost(libfunc: &Felt252Concrete) -> Vec<ConstCost> {
         Felt252Concrete::IsZero(_) => {
             vec![ConstCost::steps(1), ConstCost::steps(1)]
         }
+        Felt252Concrete::Sqrt(_) => vec![
+            ConstCost { steps: 10, holes: 0, range_checks: 3 }, // Quadratic residue.
+            ConstCost::steps(5),                                // Non-residue.
+        ],
+    }
+}
+
+/// Returns costs for bitfield libfuncs.
+fn bitfield_libfunc_cost(libfunc: &BitfieldConcreteLibfunc) -> Vec<ConstCost> {
+    match libfunc {
+        // Shifting and adding each field but the highest.
+        BitfieldConcreteLibfunc::Pack(libfunc) => {
+            vec![ConstCost::steps(2 * (libfunc.widths.len() as i32 - 1))]
+        }
+        BitfieldConcreteLibfunc::Unpack(libfunc) => {
+            let n_fields = libfunc.widths.len() as i32;
+            // A field of 128 bits takes a single range check, while a shorter field is also
+            // shifted and range checked to its width.
+            let (range_check_steps, range_checks) = libfunc
+                .widths
+                .iter()
+                .map(|width| if *width == 128 { (1, 1) } else { (3, 2) })
+                .fold((0, 0), |(steps, rcs), (s, r)| (steps + s, rcs + r));
+            vec![
+                ConstCost {
+                    steps: 1 + range_check_steps + 2 * (n_fields - 1),
+                    holes: 0,
+                    range_checks,
+                },
+                ConstCost { steps: 20, holes: 0, range_checks: 4 },
+            ]
+        }
+    }
+}
+
+/// Returns the cost of a loop with a bounded number of iterations, charged for the maximal number
+/// of iterations.
+fn bounded_loop_cost(fixed: ConstCost, per_iteration: ConstCost, max_iterations: i32) -> ConstCost {
+    ConstCost {
+        steps: fixed.steps + per_iteration.steps * max_iterations,
+        holes: fixed.holes + per_iteration.holes * max_iterations,
+        range_checks: fixed.range_checks + per_iteration.range_checks * max_iterations,
     }
 }
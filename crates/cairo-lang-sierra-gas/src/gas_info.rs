@@ -6,6 +6,7 @@ use cairo_lang_sierra::program::StatementIdx;
 use cairo_lang_utils::collection_arithmetics::sub_maps;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{chain, Itertools};
+use serde::{Deserialize, Serialize};
 
 /// Gas information for a Sierra program.
 #[derive(Debug, Eq, PartialEq)]
@@ -56,6 +57,37 @@ impl GasInfo {
         GasInfo { variable_values, function_costs }
     }
 
+    /// Returns the values of the variables by statement index, sorted by the statement index.
+    pub fn statements_costs(
+        &self,
+    ) -> OrderedHashMap<StatementIdx, OrderedHashMap<CostTokenType, i64>> {
+        let mut var_values: OrderedHashMap<StatementIdx, OrderedHashMap<CostTokenType, i64>> =
+            Default::default();
+        for ((statement_idx, cost_type), value) in self.variable_values.iter() {
+            var_values.entry(*statement_idx).or_default().insert(*cost_type, *value);
+        }
+        var_values.into_iter().sorted_by(|(a, _), (b, _)| a.0.cmp(&b.0)).collect()
+    }
+
+    /// Returns a serializable report of the gas information.
+    pub fn report(&self) -> GasReport {
+        let named_costs = |costs: &OrderedHashMap<CostTokenType, i64>| {
+            costs.iter().map(|(token, value)| (token.name(), *value)).collect()
+        };
+        GasReport {
+            statements: self
+                .statements_costs()
+                .iter()
+                .map(|(statement_idx, costs)| (statement_idx.0, named_costs(costs)))
+                .collect(),
+            function_costs: self
+                .function_costs
+                .iter()
+                .map(|(function_id, costs)| (function_id.to_string(), named_costs(costs)))
+                .collect(),
+        }
+    }
+
     pub fn assert_eq(&self, other: &GasInfo) {
         for (key, val) in sub_maps(self.variable_values.clone(), other.variable_values.clone()) {
             assert!(
@@ -85,15 +117,8 @@ impl GasInfo {
 
 impl Display for GasInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // Reorder the variable values by statement index.
-        let mut var_values: OrderedHashMap<StatementIdx, OrderedHashMap<CostTokenType, i64>> =
-            Default::default();
-        for ((statement_idx, cost_type), value) in self.variable_values.iter() {
-            var_values.entry(*statement_idx).or_default().insert(*cost_type, *value);
-        }
-
-        for statement_idx in var_values.keys().sorted_by(|a, b| a.0.cmp(&b.0)) {
-            writeln!(f, "#{statement_idx}: {:?}", var_values[*statement_idx])?;
+        for (statement_idx, costs) in self.statements_costs().iter() {
+            writeln!(f, "#{statement_idx}: {costs:?}")?;
         }
         writeln!(f)?;
         for (function_id, costs) in self.function_costs.iter() {
@@ -102,3 +127,14 @@ impl Display for GasInfo {
         Ok(())
     }
 }
+
+/// A machine-readable report of the solved gas variables of a Sierra program, so that profiling
+/// tools can attribute gas to statements without re-deriving the gas equations.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasReport {
+    /// The values of the variables of each statement with gas variables, by statement index and
+    /// cost token name.
+    pub statements: OrderedHashMap<usize, OrderedHashMap<String, i64>>,
+    /// The costs of calling each function, by function id and cost token name.
+    pub function_costs: OrderedHashMap<String, OrderedHashMap<String, i64>>,
+}
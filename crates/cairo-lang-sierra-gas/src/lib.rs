@@ -19,6 +19,7 @@ use gas_info::GasInfo;
 use generate_equations::StatementFutureCost;
 use itertools::Itertools;
 use objects::CostInfoProvider;
+use solving::{CallGraph, SolvingConfig, SolvingTiming};
 use thiserror::Error;
 
 pub mod compute_costs;
@@ -29,6 +30,7 @@ mod cost_expr;
 pub mod gas_info;
 mod generate_equations;
 pub mod objects;
+pub mod solving;
 mod starknet_libfunc_cost_base;
 
 #[cfg(test)]
@@ -41,8 +43,11 @@ pub enum CostError {
     ProgramRegistryError(#[from] Box<ProgramRegistryError>),
     #[error("found an illegal statement index during cost calculations")]
     StatementOutOfBounds(StatementIdx),
-    #[error("failed solving the symbol tables")]
-    SolvingGasEquationFailed,
+    #[error(
+        "failed solving the {token_type:?} gas equations of the functions: {}",
+        .functions.iter().join(", ")
+    )]
+    SolvingGasEquationFailed { token_type: CostTokenType, functions: Vec<FunctionId> },
 }
 
 /// Helper to implement the `InvocationCostInfoProvider` for the equation generation.
@@ -57,6 +62,8 @@ struct InvocationCostInfoProviderForEqGen<
     token_usages: TokenUsages,
     /// Closure providing the ap changes for the invocation.
     ap_change_var_value: ApChangeVarValue,
+    /// Whether `ec_state_add_mul` is lowered without the EC op builtin.
+    software_ec_op: bool,
 }
 
 impl<'a, TokenUsages: Fn(CostTokenType) -> usize, ApChangeVarValue: Fn() -> usize>
@@ -74,6 +81,10 @@ impl<'a, TokenUsages: Fn(CostTokenType) -> usize, ApChangeVarValue: Fn() -> usiz
     fn ap_change_var_value(&self) -> usize {
         (self.ap_change_var_value)()
     }
+
+    fn software_ec_op(&self) -> bool {
+        self.software_ec_op
+    }
 }
 
 /// Implementation of [CostInfoProvider] for [TypeSizeMap].
@@ -81,14 +92,20 @@ impl CostInfoProvider for TypeSizeMap {
     fn type_size(&self, ty: &ConcreteTypeId) -> usize {
         self[ty].into_or_panic()
     }
+
+    fn software_ec_op(&self) -> bool {
+        false
+    }
 }
 
 /// Calculates gas pre-cost information for a given program - the gas costs of non-step tokens.
+/// Returns the timings of the equations solving along with the information.
 // TODO(lior): Remove this function once [compute_precost_info] is used.
 pub fn calc_gas_precost_info(
     program: &Program,
     function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
-) -> Result<GasInfo, CostError> {
+    solving_config: &SolvingConfig,
+) -> Result<(GasInfo, Vec<SolvingTiming>), CostError> {
     let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
     calc_gas_info_inner(
         program,
@@ -100,13 +117,15 @@ pub fn calc_gas_precost_info(
         },
         function_set_costs,
         &registry,
+        solving_config,
     )
 }
 
 /// Calculates gas pre-cost information for a given program - the gas costs of non-step tokens.
 pub fn compute_precost_info(program: &Program) -> Result<GasInfo, CostError> {
     let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
-    let type_sizes = get_type_size_map(program, &registry).unwrap();
+    // The pre-costs do not depend on the layout of enums.
+    let type_sizes = get_type_size_map(program, &registry, false).unwrap();
 
     Ok(compute_costs::compute_costs(
         program,
@@ -121,14 +140,20 @@ pub fn compute_precost_info(program: &Program) -> Result<GasInfo, CostError> {
 }
 
 /// Calculates gas postcost information for a given program - the gas costs of step token.
+/// Returns the timings of the equations solving along with the information.
+/// `software_ec_op` - whether `ec_state_add_mul` is lowered without the EC op builtin.
+/// `enum_zero_niche` - whether enums with a zero niche are represented without a variant selector.
 pub fn calc_gas_postcost_info<ApChangeVarValue: Fn(StatementIdx) -> usize>(
     program: &Program,
     function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
     precost_gas_info: &GasInfo,
     ap_change_var_value: ApChangeVarValue,
-) -> Result<GasInfo, CostError> {
+    software_ec_op: bool,
+    enum_zero_niche: bool,
+    solving_config: &SolvingConfig,
+) -> Result<(GasInfo, Vec<SolvingTiming>), CostError> {
     let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
-    let type_sizes = get_type_size_map(program, &registry).unwrap();
+    let type_sizes = get_type_size_map(program, &registry, enum_zero_niche).unwrap();
     calc_gas_info_inner(
         program,
         |statement_future_cost, idx, libfunc_id| {
@@ -145,11 +170,13 @@ pub fn calc_gas_postcost_info<ApChangeVarValue: Fn(StatementIdx) -> usize>(
                         precost_gas_info.variable_values[(*idx, token_type)].into_or_panic()
                     },
                     ap_change_var_value: || ap_change_var_value(*idx),
+                    software_ec_op,
                 },
             )
         },
         function_set_costs,
         &registry,
+        solving_config,
     )
 }
 
@@ -161,8 +188,11 @@ fn calc_gas_info_inner<
     get_cost: GetCost,
     function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
     registry: &ProgramRegistry<CoreType, CoreLibfunc>,
-) -> Result<GasInfo, CostError> {
+    solving_config: &SolvingConfig,
+) -> Result<(GasInfo, Vec<SolvingTiming>), CostError> {
     let mut equations = generate_equations::generate_equations(program, get_cost)?;
+    let call_graph = CallGraph::new(program, registry);
+    let mut timings = vec![];
     let non_set_cost_func_entry_points: UnorderedHashSet<_> = program
         .funcs
         .iter()
@@ -230,9 +260,14 @@ fn calc_gas_info_inner<
             }]
             .push(v.clone())
         }
-        let solution =
-            cairo_lang_eq_solver::try_solve_equations(token_equations, minimization_vars)
-                .ok_or(CostError::SolvingGasEquationFailed)?;
+        let solution = solving::solve_equations(
+            token_type,
+            token_equations,
+            minimization_vars,
+            &call_graph,
+            solving_config,
+            &mut timings,
+        )?;
         for func in &program.funcs {
             let id = &func.id;
             if !function_costs.contains_key(id) {
@@ -253,5 +288,5 @@ fn calc_gas_info_inner<
             }
         }
     }
-    Ok(GasInfo { variable_values, function_costs })
+    Ok((GasInfo { variable_values, function_costs }, timings))
 }
@@ -100,4 +100,6 @@ impl From<ConstCost> for BranchCost {
 pub trait CostInfoProvider {
     /// Provides the sizes of types.
     fn type_size(&self, ty: &ConcreteTypeId) -> usize;
+    /// Whether `ec_state_add_mul` is lowered without the EC op builtin.
+    fn software_ec_op(&self) -> bool;
 }
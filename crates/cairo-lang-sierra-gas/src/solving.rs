@@ -0,0 +1,303 @@
+//! Strategies for solving the gas equations of a program, and the timing of the solving.
+
+use std::time::{Duration, Instant};
+
+use cairo_lang_eq_solver::{try_solve_equations, Expr};
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::{Program, Statement};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+use cairo_lang_utils::graph_algos::graph_node::GraphNode;
+use cairo_lang_utils::graph_algos::strongly_connected_components::compute_scc;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+use itertools::Itertools;
+
+use crate::cost_expr::Var;
+use crate::CostError;
+
+#[cfg(test)]
+#[path = "solving_test.rs"]
+mod test;
+
+/// A strategy for solving the gas equations of a program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolvingStrategy {
+    /// Solves the equations of the whole program at once, finding the optimal costs.
+    Global,
+    /// Solves the equations of every strongly connected component of the call graph separately,
+    /// callees first. Each component is minimized without regard to its callers, so the costs may
+    /// be higher than optimal, but every solved system is much smaller.
+    PerScc,
+    /// Solves the equations of the whole program with a single minimization of all the variables,
+    /// instead of ranked minimizations. The function costs are upper bounds of the optimal ones.
+    ApproximateUpperBound,
+}
+
+/// Configuration for solving the gas equations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolvingConfig {
+    /// The strategies to try, in order, until one of them succeeds.
+    pub strategies: Vec<SolvingStrategy>,
+}
+impl Default for SolvingConfig {
+    fn default() -> Self {
+        Self { strategies: vec![SolvingStrategy::Global] }
+    }
+}
+
+/// The timing of an attempt to solve the equations of a single token type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolvingTiming {
+    /// The token type of the solved equations.
+    pub token_type: CostTokenType,
+    /// The strategy used.
+    pub strategy: SolvingStrategy,
+    /// The number of equations.
+    pub num_equations: usize,
+    /// The time the attempt took.
+    pub duration: Duration,
+    /// Whether the attempt succeeded.
+    pub solved: bool,
+}
+
+/// The call graph of a program, used for splitting its equations.
+pub struct CallGraph {
+    /// The ids of the functions of the program.
+    function_ids: Vec<FunctionId>,
+    /// The index of the function each statement belongs to, if it is reachable.
+    statement_function: Vec<Option<usize>>,
+    /// The strongly connected components of the call graph, as function indices. Every component
+    /// comes after the components it calls.
+    sccs: Vec<Vec<usize>>,
+    /// The index of the component of every function.
+    function_scc: Vec<usize>,
+}
+impl CallGraph {
+    pub fn new(program: &Program, registry: &ProgramRegistry<CoreType, CoreLibfunc>) -> Self {
+        let function_indices: UnorderedHashMap<FunctionId, usize> =
+            program.funcs.iter().enumerate().map(|(i, f)| (f.id.clone(), i)).collect();
+        let mut statement_function = vec![None; program.statements.len()];
+        let mut calls = vec![vec![]; program.funcs.len()];
+        for (function, func) in program.funcs.iter().enumerate() {
+            let mut stack = vec![func.entry_point];
+            while let Some(idx) = stack.pop() {
+                match statement_function.get(idx.0) {
+                    Some(None) => statement_function[idx.0] = Some(function),
+                    // Already visited, or out of bounds, which fails the equation generation.
+                    _ => continue,
+                }
+                let Statement::Invocation(invocation) = &program.statements[idx.0] else {
+                    continue;
+                };
+                if let Ok(CoreConcreteLibfunc::FunctionCall(libfunc)) =
+                    registry.get_libfunc(&invocation.libfunc_id)
+                {
+                    calls[function].push(function_indices[&libfunc.function.id]);
+                }
+                stack.extend(invocation.branches.iter().map(|branch| idx.next(&branch.target)));
+            }
+        }
+
+        let mut function_scc = vec![None; program.funcs.len()];
+        let mut unordered_sccs = vec![];
+        for function in 0..program.funcs.len() {
+            if function_scc[function].is_none() {
+                let scc = compute_scc(&CallGraphNode { function, calls: &calls });
+                for member in &scc {
+                    function_scc[*member] = Some(unordered_sccs.len());
+                }
+                unordered_sccs.push(scc);
+            }
+        }
+        let function_scc = function_scc.into_iter().map(Option::unwrap).collect_vec();
+        // Ordering the components in post order of the condensed graph, so callees come first.
+        let mut order = vec![];
+        let mut visited = vec![false; unordered_sccs.len()];
+        for scc in 0..unordered_sccs.len() {
+            order_sccs(scc, &unordered_sccs, &function_scc, &calls, &mut visited, &mut order);
+        }
+        let mut sccs = vec![vec![]; order.len()];
+        let mut ordered_function_scc = vec![0; function_scc.len()];
+        for (ordered_idx, scc) in order.into_iter().enumerate() {
+            for function in &unordered_sccs[scc] {
+                ordered_function_scc[*function] = ordered_idx;
+            }
+            sccs[ordered_idx] = unordered_sccs[scc].clone();
+        }
+
+        Self {
+            function_ids: program.funcs.iter().map(|f| f.id.clone()).collect(),
+            statement_function,
+            sccs,
+            function_scc: ordered_function_scc,
+        }
+    }
+
+    /// Returns the index of the function the variable belongs to.
+    fn var_function(&self, var: &Var) -> Option<usize> {
+        match var {
+            Var::LibfuncImplicitGasVariable(idx, _) | Var::StatementFuture(idx, _) => {
+                self.statement_function.get(idx.0).copied().flatten()
+            }
+        }
+    }
+
+    /// Returns the index of the component the variable belongs to.
+    fn var_scc(&self, var: &Var) -> usize {
+        self.var_function(var).map_or(0, |function| self.function_scc[function])
+    }
+
+    /// Returns the ids of the functions with variables in the given equations.
+    fn equations_functions(&self, equations: &[Expr<Var>]) -> Vec<FunctionId> {
+        equations
+            .iter()
+            .flat_map(|eq| eq.var_to_coef.keys())
+            .filter_map(|var| self.var_function(var))
+            .unique()
+            .sorted()
+            .map(|function| self.function_ids[function].clone())
+            .collect()
+    }
+}
+
+/// Adds the component and the components it calls to `order`, callees first.
+fn order_sccs(
+    scc: usize,
+    sccs: &[Vec<usize>],
+    function_scc: &[usize],
+    calls: &[Vec<usize>],
+    visited: &mut [bool],
+    order: &mut Vec<usize>,
+) {
+    if visited[scc] {
+        return;
+    }
+    visited[scc] = true;
+    for function in &sccs[scc] {
+        for callee in &calls[*function] {
+            order_sccs(function_scc[*callee], sccs, function_scc, calls, visited, order);
+        }
+    }
+    order.push(scc);
+}
+
+/// A node of the call graph, for computing its strongly connected components.
+#[derive(Clone)]
+struct CallGraphNode<'a> {
+    function: usize,
+    calls: &'a [Vec<usize>],
+}
+impl<'a> GraphNode for CallGraphNode<'a> {
+    type NodeId = usize;
+
+    fn get_neighbors(&self) -> Vec<Self> {
+        self.calls[self.function]
+            .iter()
+            .map(|function| CallGraphNode { function: *function, calls: self.calls })
+            .collect()
+    }
+
+    fn get_id(&self) -> Self::NodeId {
+        self.function
+    }
+}
+
+/// Solves the equations of a single token type, trying the configured strategies in order, and
+/// adds the timing of every attempt to `timings`.
+/// `minimization_vars` are the variables to minimize, in ranked ordering, as in
+/// [try_solve_equations].
+pub fn solve_equations(
+    token_type: CostTokenType,
+    equations: Vec<Expr<Var>>,
+    minimization_vars: Vec<Vec<Var>>,
+    call_graph: &CallGraph,
+    config: &SolvingConfig,
+    timings: &mut Vec<SolvingTiming>,
+) -> Result<OrderedHashMap<Var, i64>, CostError> {
+    // If all the strategies fail, the functions of the last failure are reported.
+    let mut failed_functions = call_graph.equations_functions(&equations);
+    for strategy in &config.strategies {
+        let start = Instant::now();
+        let result = match strategy {
+            SolvingStrategy::Global => {
+                try_solve_equations(equations.clone(), minimization_vars.clone())
+                    .ok_or_else(|| call_graph.equations_functions(&equations))
+            }
+            SolvingStrategy::PerScc => {
+                solve_per_scc(equations.clone(), &minimization_vars, call_graph)
+            }
+            SolvingStrategy::ApproximateUpperBound => {
+                try_solve_equations(equations.clone(), vec![minimization_vars.concat()])
+                    .ok_or_else(|| call_graph.equations_functions(&equations))
+            }
+        };
+        timings.push(SolvingTiming {
+            token_type,
+            strategy: *strategy,
+            num_equations: equations.len(),
+            duration: start.elapsed(),
+            solved: result.is_ok(),
+        });
+        match result {
+            Ok(solution) => return Ok(solution),
+            Err(functions) => failed_functions = functions,
+        }
+    }
+    Err(CostError::SolvingGasEquationFailed { token_type, functions: failed_functions })
+}
+
+/// Solves the equations per strongly connected component of the call graph, callees first,
+/// substituting the solved variables into the equations of their callers.
+/// Returns the functions of the first component that could not be solved on failure.
+fn solve_per_scc(
+    mut equations: Vec<Expr<Var>>,
+    minimization_vars: &[Vec<Var>],
+    call_graph: &CallGraph,
+) -> Result<OrderedHashMap<Var, i64>, Vec<FunctionId>> {
+    let mut solution = OrderedHashMap::<Var, i64>::default();
+    for (scc_idx, scc) in call_graph.sccs.iter().enumerate() {
+        let scc_failure = || scc.iter().map(|f| call_graph.function_ids[*f].clone()).collect_vec();
+        // The equations with no variables of components yet to be solved.
+        let (scc_equations, rest): (Vec<_>, Vec<_>) = equations
+            .into_iter()
+            .partition(|eq| eq.var_to_coef.keys().all(|var| call_graph.var_scc(var) <= scc_idx));
+        equations = rest;
+        let mut substituted_equations = vec![];
+        for eq in scc_equations {
+            let mut const_term = eq.const_term as i64;
+            let mut var_to_coef = OrderedHashMap::default();
+            for (var, coef) in eq.var_to_coef {
+                match solution.get(&var) {
+                    Some(value) => const_term += value * coef,
+                    None => {
+                        var_to_coef.insert(var, coef);
+                    }
+                }
+            }
+            if var_to_coef.is_empty() {
+                if const_term != 0 {
+                    return Err(scc_failure());
+                }
+                continue;
+            }
+            let const_term = i32::try_from(const_term).map_err(|_| scc_failure())?;
+            substituted_equations.push(Expr { var_to_coef, const_term });
+        }
+        if substituted_equations.is_empty() {
+            continue;
+        }
+        let scc_vars: UnorderedHashSet<&Var> =
+            substituted_equations.iter().flat_map(|eq| eq.var_to_coef.keys()).collect();
+        let scc_minimization_vars = minimization_vars
+            .iter()
+            .map(|layer| layer.iter().filter(|var| scc_vars.contains(var)).cloned().collect())
+            .collect();
+        let scc_solution = try_solve_equations(substituted_equations, scc_minimization_vars)
+            .ok_or_else(scc_failure)?;
+        solution.extend(scc_solution);
+    }
+    Ok(solution)
+}
@@ -0,0 +1,103 @@
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::Program;
+use cairo_lang_sierra::ProgramParser;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use indoc::indoc;
+use test_case::test_case;
+
+use super::{SolvingConfig, SolvingStrategy};
+use crate::gas_info::GasInfo;
+use crate::{calc_gas_postcost_info, calc_gas_precost_info, CostError};
+
+/// Returns a program with a function calling another function.
+fn get_program() -> Program {
+    ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+
+            libfunc felt252_const_1 = felt252_const<1>;
+            libfunc store_temp_felt252 = store_temp<felt252>;
+            libfunc call_inner = function_call<user@inner>;
+
+            felt252_const_1() -> (x);
+            store_temp_felt252(x) -> (x);
+            return(x);
+            call_inner() -> (x);
+            store_temp_felt252(x) -> (x);
+            return(x);
+
+            inner@0() -> (felt252);
+            outer@3() -> (felt252);
+        "})
+        .unwrap()
+}
+
+/// Returns the gas information of the program, solved with the given strategies.
+fn solve_program(program: &Program, strategies: Vec<SolvingStrategy>) -> GasInfo {
+    let config = SolvingConfig { strategies: strategies.clone() };
+    let (precost_info, precost_timings) =
+        calc_gas_precost_info(program, Default::default(), &config).unwrap();
+    let (postcost_info, postcost_timings) = calc_gas_postcost_info(
+        program,
+        Default::default(),
+        &precost_info,
+        |_| 0,
+        false,
+        false,
+        &config,
+    )
+    .unwrap();
+    assert_eq!(postcost_timings.len(), 1);
+    assert_eq!(postcost_timings[0].token_type, CostTokenType::Const);
+    for timing in precost_timings.iter().chain(&postcost_timings) {
+        assert_eq!((timing.strategy, timing.solved), (strategies[0], true));
+    }
+    precost_info.combine(postcost_info)
+}
+
+#[test_case(SolvingStrategy::PerScc; "per_scc")]
+#[test_case(SolvingStrategy::ApproximateUpperBound; "approximate_upper_bound")]
+fn test_solving_strategy(strategy: SolvingStrategy) {
+    let program = get_program();
+    // The costs of the program are fully determined, so all the strategies find them.
+    assert_eq!(
+        solve_program(&program, vec![strategy]),
+        solve_program(&program, vec![SolvingStrategy::Global])
+    );
+}
+
+#[test]
+fn test_solving_failure() {
+    let program = get_program();
+    let (precost_info, _) =
+        calc_gas_precost_info(&program, Default::default(), &SolvingConfig::default()).unwrap();
+    // The cost of `inner` is too low, so the equations have no solution.
+    let function_set_costs = OrderedHashMap::from_iter([(
+        FunctionId::from_string("inner"),
+        OrderedHashMap::from_iter([(CostTokenType::Const, 0)]),
+    )]);
+    let solve = |strategies| {
+        calc_gas_postcost_info(
+            &program,
+            function_set_costs.clone(),
+            &precost_info,
+            |_| 0,
+            false,
+            false,
+            &SolvingConfig { strategies },
+        )
+    };
+
+    // Solving the whole program only tells that some of its functions are involved.
+    assert_eq!(
+        solve(vec![SolvingStrategy::Global]).unwrap_err(),
+        CostError::SolvingGasEquationFailed {
+            token_type: CostTokenType::Const,
+            functions: vec![FunctionId::from_string("inner"), FunctionId::from_string("outer")],
+        }
+    );
+    // Solving per component finds the function that cannot be solved.
+    let err = solve(vec![SolvingStrategy::Global, SolvingStrategy::PerScc]).unwrap_err();
+    assert_eq!(err.to_string(), "failed solving the Const gas equations of the functions: inner");
+}
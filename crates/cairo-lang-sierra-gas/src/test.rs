@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use cairo_lang_sierra::program::Program;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
+use crate::solving::SolvingConfig;
 use crate::{calc_gas_postcost_info, calc_gas_precost_info};
 
 cairo_lang_test_utils::test_file_test!(
@@ -27,10 +28,22 @@ fn test_solve_gas(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<Str
     let path = &inputs["test_file_name"];
     let program = get_example_program(path);
 
-    let gas_info0 = calc_gas_precost_info(&program, Default::default()).unwrap();
-    let gas_info1 =
-        calc_gas_postcost_info(&program, Default::default(), &gas_info0, |_| 0).unwrap();
+    let config = SolvingConfig::default();
+    let (gas_info0, _) = calc_gas_precost_info(&program, Default::default(), &config).unwrap();
+    let (gas_info1, _) = calc_gas_postcost_info(
+        &program,
+        Default::default(),
+        &gas_info0,
+        |_| 0,
+        false,
+        false,
+        &config,
+    )
+    .unwrap();
     let gas_info = gas_info0.combine(gas_info1);
 
-    OrderedHashMap::from([("gas_solution".into(), format!("{gas_info}"))])
+    OrderedHashMap::from([
+        ("gas_solution".into(), format!("{gas_info}")),
+        ("gas_report".into(), serde_json::to_string_pretty(&gas_info.report()).unwrap()),
+    ])
 }
@@ -0,0 +1,209 @@
+//! A report of the places where ap tracking is lost, and of what losing it costs.
+//!
+//! Ap tracking is lost by invoking a libfunc with an unknown ap change - usually a call to a
+//! function with an unknown ap change, e.g. due to a loop or recursion. A function losing ap
+//! tracking has an unknown ap change itself, so the loss cascades to its callers, and the variables
+//! used after the loss have to be stored in local variables.
+
+#[cfg(test)]
+#[path = "ap_change_report_test.rs"]
+mod test;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_diagnostics::{format_diagnostics_with_severity, Maybe, Severity};
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_sierra::extensions::lib_func::SierraApChange;
+use cairo_lang_sierra::ids::{ConcreteLibfuncId, FunctionId};
+use cairo_lang_sierra::program::{GenStatement, GenericArg};
+use cairo_lang_utils::try_extract_matches;
+use itertools::Itertools;
+
+use crate::db::SierraGenGroup;
+use crate::pre_sierra;
+use crate::program_generator::{collect_functions_and_statements, crates_function_ids};
+use crate::replace_ids::{DebugReplacer, SierraIdReplacer};
+use crate::utils::get_libfunc_signature;
+
+/// A statement where ap tracking is lost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApTrackingLoss {
+    /// The invoked libfunc, which has an unknown ap change.
+    pub libfunc_id: ConcreteLibfuncId,
+    /// The called function, if the libfunc is a call to a function with an unknown ap change.
+    pub callee: Option<FunctionId>,
+    /// The source location of the statement, if it has one.
+    pub location: Option<StableLocation>,
+}
+
+/// The ap tracking losses of a single function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionApChangeReport {
+    /// The Sierra id of the function.
+    pub function_id: FunctionId,
+    /// The source location of the function, if it has one.
+    pub location: Option<StableLocation>,
+    /// The statements of the function where ap tracking is lost.
+    pub losses: Vec<ApTrackingLoss>,
+    /// The number of variables of the function stored in local variables.
+    pub num_local_variables: usize,
+    /// The other functions of the program that lose ap tracking by calling this function.
+    pub affected_callers: Vec<FunctionId>,
+}
+
+/// Returns the report of the functions of the Sierra program of the requested crates that lose ap
+/// tracking, in the order of the program.
+pub fn get_ap_change_report(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: Vec<CrateId>,
+) -> Maybe<Vec<FunctionApChangeReport>> {
+    let (functions, _) =
+        collect_functions_and_statements(db, crates_function_ids(db, requested_crate_ids)?)?;
+    let mut reports = vec![];
+    for function in functions {
+        let losses = function_ap_tracking_losses(db, &function);
+        if losses.is_empty() {
+            continue;
+        }
+        let num_local_variables = function
+            .body
+            .iter()
+            .filter_map(|statement| {
+                try_extract_matches!(
+                    statement,
+                    pre_sierra::Statement::Sierra(GenStatement::Invocation)
+                )
+            })
+            .filter(|invocation| {
+                db.lookup_intern_concrete_lib_func(invocation.libfunc_id.clone()).generic_id
+                    == "alloc_local".into()
+            })
+            .count();
+        let location =
+            match db.lookup_intern_sierra_function(function.id.clone()).body(db.upcast())? {
+                Some(function_id) => Some(function_id.stable_location(db.upcast())?),
+                None => None,
+            };
+        reports.push(FunctionApChangeReport {
+            function_id: function.id.clone(),
+            location,
+            losses,
+            num_local_variables,
+            affected_callers: vec![],
+        });
+    }
+    for caller_idx in 0..reports.len() {
+        let callees = reports[caller_idx]
+            .losses
+            .iter()
+            .filter_map(|loss| loss.callee.clone())
+            .filter(|callee| *callee != reports[caller_idx].function_id)
+            .unique()
+            .collect_vec();
+        let caller = reports[caller_idx].function_id.clone();
+        for report in reports.iter_mut().filter(|report| callees.contains(&report.function_id)) {
+            report.affected_callers.push(caller.clone());
+        }
+    }
+    Ok(reports)
+}
+
+/// Returns the statements of the given function where ap tracking is lost.
+fn function_ap_tracking_losses(
+    db: &dyn SierraGenGroup,
+    function: &pre_sierra::Function,
+) -> Vec<ApTrackingLoss> {
+    let mut losses = vec![];
+    let mut current_location = None;
+    for statement in &function.body {
+        let invocation = match statement {
+            pre_sierra::Statement::Sierra(GenStatement::Invocation(invocation)) => invocation,
+            pre_sierra::Statement::Label(_) => {
+                current_location = None;
+                continue;
+            }
+            pre_sierra::Statement::Location(location) => {
+                current_location = Some(location.get(db.upcast()).stable_location);
+                continue;
+            }
+            pre_sierra::Statement::Sierra(GenStatement::Return(_))
+            | pre_sierra::Statement::PushValues(_) => continue,
+        };
+        let signature = get_libfunc_signature(db, invocation.libfunc_id.clone());
+        if !signature
+            .branch_signatures
+            .iter()
+            .any(|branch| matches!(branch.ap_change, SierraApChange::Unknown))
+        {
+            continue;
+        }
+        let long_id = db.lookup_intern_concrete_lib_func(invocation.libfunc_id.clone());
+        let callee = if long_id.generic_id == "function_call".into() {
+            long_id
+                .generic_args
+                .first()
+                .and_then(|arg| try_extract_matches!(arg, GenericArg::UserFunc))
+                .cloned()
+        } else {
+            None
+        };
+        losses.push(ApTrackingLoss {
+            libfunc_id: invocation.libfunc_id.clone(),
+            callee,
+            location: current_location,
+        });
+    }
+    losses
+}
+
+/// Formats the given report as compiler notes.
+pub fn format_ap_change_report(
+    db: &dyn SierraGenGroup,
+    reports: &[FunctionApChangeReport],
+) -> String {
+    let replacer = DebugReplacer { db };
+    let function_name = |id: &FunctionId| replacer.replace_function_id(id).to_string();
+    let mut res = String::new();
+    for report in reports {
+        let mut cost = format!("{} local variables", report.num_local_variables);
+        if !report.affected_callers.is_empty() {
+            cost += &format!(
+                ", and losing ap tracking in the functions calling it: {}",
+                report.affected_callers.iter().map(function_name).join(", ")
+            );
+        }
+        let message = format!(
+            "The ap change of `{}` is unknown, at a cost of {cost}.",
+            function_name(&report.function_id)
+        );
+        res += &format_note(db, &message, report.location);
+        for loss in &report.losses {
+            let message = match &loss.callee {
+                Some(callee) => format!(
+                    "Ap tracking is lost by calling `{}`, whose ap change is unknown.",
+                    function_name(callee)
+                ),
+                None => format!(
+                    "Ap tracking is lost by invoking `{}`.",
+                    replacer.replace_libfunc_id(&loss.libfunc_id)
+                ),
+            };
+            res += &format_note(db, &message, loss.location);
+        }
+    }
+    res
+}
+
+/// Formats a single note, at the given location if there is one.
+fn format_note(db: &dyn SierraGenGroup, message: &str, location: Option<StableLocation>) -> String {
+    let defs_db: &dyn DefsGroup = db.upcast();
+    match location {
+        Some(location) => format_diagnostics_with_severity(
+            defs_db.upcast(),
+            Severity::Note,
+            message,
+            location.diagnostic_location(defs_db),
+        ),
+        None => format!("{}: {message}\n", Severity::Note),
+    }
+}
@@ -0,0 +1,53 @@
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::{format_ap_change_report, get_ap_change_report};
+use crate::replace_ids::{DebugReplacer, SierraIdReplacer};
+use crate::test_utils::setup_db_and_get_crate_id;
+
+#[test]
+fn test_ap_change_report() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn foo(a: felt252) -> felt252 {
+            bar(a) + 1
+        }
+
+        fn bar(a: felt252) -> felt252 {
+            if a == 0 { 0 } else { bar(a - 1) }
+        }
+
+        fn baz(a: felt252) -> felt252 {
+            a + 1
+        }
+    "});
+    let report = get_ap_change_report(&db, vec![crate_id]).unwrap();
+    let replacer = DebugReplacer { db: &db };
+    let function_name = |id| replacer.replace_function_id(id).to_string();
+
+    // Only the recursive function and its caller lose ap tracking.
+    assert_eq!(
+        report.iter().map(|function| function_name(&function.function_id)).collect_vec(),
+        vec!["test::foo", "test::bar"]
+    );
+    for function in &report {
+        assert!(function.location.is_some());
+        for loss in &function.losses {
+            assert_eq!(loss.callee.as_ref().map(function_name), Some("test::bar".into()));
+            assert!(loss.location.is_some());
+        }
+    }
+    assert_eq!(report[0].affected_callers, vec![]);
+    assert_eq!(
+        report[1].affected_callers.iter().map(function_name).collect_vec(),
+        vec!["test::foo"]
+    );
+
+    let notes = format_ap_change_report(&db, &report);
+    assert!(notes.contains(
+        "note: Ap tracking is lost by calling `test::bar`, whose ap change is unknown.\n --> \
+         lib.cairo:2:"
+    ));
+    assert!(notes.contains("losing ap tracking in the functions calling it: test::foo."));
+}
@@ -54,6 +54,9 @@ pub fn generate_block_body_code(
     // Process the statements.
     for (i, statement) in block.statements.iter().enumerate() {
         let statement_location = (block_id, i);
+        if let Some(location) = lowering_statement_location(context, statement) {
+            statements.push(pre_sierra::Statement::Location(location));
+        }
         statements.extend(generate_statement_code(context, statement, &statement_location)?);
         let drop_location = &DropLocation::PostStatement(statement_location);
         add_drop_statements(context, drops, drop_location, &mut statements)?;
@@ -69,6 +72,21 @@ pub fn generate_block_body_code(
     Ok(statements)
 }
 
+/// Returns the source location of a lowering statement - the location of the call, or of its first
+/// output or input variable otherwise.
+fn lowering_statement_location(
+    context: &ExprGeneratorContext<'_>,
+    statement: &lowering::Statement,
+) -> Option<lowering::ids::LocationId> {
+    if let lowering::Statement::Call(statement_call) = statement {
+        return Some(statement_call.location);
+    }
+    match statement.outputs().first() {
+        Some(output) => Some(context.get_variable_location(*output)),
+        None => statement.inputs().first().map(|input| input.location),
+    }
+}
+
 /// Adds calls to the `drop` libfunc for the given [DropLocation], according to the `drops`
 /// argument (computed by [find_variable_lifetime](crate::lifetime::find_variable_lifetime)).
 fn add_drop_statements(
@@ -107,6 +125,9 @@ pub fn generate_block_code(
 
     match &block.end {
         lowering::FlatBlockEnd::Return(returned_variables) => {
+            if let Some(returned_variable) = returned_variables.first() {
+                statements.push(pre_sierra::Statement::Location(returned_variable.location));
+            }
             statements.extend(generate_return_code(
                 context,
                 returned_variables,
@@ -141,6 +162,7 @@ pub fn generate_block_code(
         // Process the block end if it's a match.
         lowering::FlatBlockEnd::Match { info } => {
             let statement_location = (block_id, block.statements.len());
+            statements.push(pre_sierra::Statement::Location(*info.location()));
 
             if context.should_enable_ap_tracking(&block_id) {
                 context.set_ap_tracking(true);
@@ -456,7 +478,7 @@ fn generate_match_code(
     let ap_tracking_enabled = context.get_ap_tracking();
 
     // Generate the blocks.
-    for (i, MatchArm { variant_id: _, block_id, var_ids: _ }) in enumerate(arms) {
+    for (i, MatchArm { variant_id: _, block_id, var_ids: _, hint: _ }) in enumerate(arms) {
         // Reset ap_tracking to the state before the match.
         context.set_ap_tracking(ap_tracking_enabled);
 
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::db::LoweringGroup;
@@ -92,6 +93,22 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         &self,
         requested_crate_ids: Vec<CrateId>,
     ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
+
+    /// Returns the source locations of the statements of the Sierra program of the requested
+    /// functions, as returned by [SierraGenGroup::get_sierra_program_for_functions].
+    #[salsa::invoke(program_generator::get_sierra_program_statements_locations_for_functions)]
+    fn get_sierra_program_statements_locations_for_functions(
+        &self,
+        requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+    ) -> Maybe<Arc<Vec<Option<StableLocation>>>>;
+
+    /// Returns the source locations of the statements of the Sierra program of the requested
+    /// crates, as returned by [SierraGenGroup::get_sierra_program].
+    #[salsa::invoke(program_generator::get_sierra_program_statements_locations)]
+    fn get_sierra_program_statements_locations(
+        &self,
+        requested_crate_ids: Vec<CrateId>,
+    ) -> Maybe<Arc<Vec<Option<StableLocation>>>>;
 }
 
 fn get_function_signature(
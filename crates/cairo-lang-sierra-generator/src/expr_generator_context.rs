@@ -6,7 +6,7 @@ use cairo_lang_sierra::extensions::NamedType;
 use cairo_lang_sierra::program::{ConcreteTypeLongId, GenericArg};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
-use lowering::ids::ConcreteFunctionWithBodyId;
+use lowering::ids::{ConcreteFunctionWithBodyId, LocationId};
 use lowering::{BlockId, FlatLowered, VariableId};
 
 use crate::ap_tracking::ApTrackingConfiguration;
@@ -152,6 +152,11 @@ impl<'a> ExprGeneratorContext<'a> {
         self.lowered.variables[var_id].ty
     }
 
+    /// Returns the source location of the variable given by `var_id`.
+    pub fn get_variable_location(&self, var_id: VariableId) -> LocationId {
+        self.lowered.variables[var_id].location
+    }
+
     /// Gets the current ap tracking state.
     pub fn get_ap_tracking(&self) -> bool {
         self.ap_tracking_enabled
@@ -0,0 +1,147 @@
+//! Instrumentation of Sierra programs for dynamic analyses.
+//!
+//! Instrumenting a program injects a probe - an invocation of `instrumentation_hit<id>` - before
+//! its statements or basic blocks. The id of a probe is the index of the statement it precedes in
+//! the original program. Probes are compiled to a hint and have no effect on the state of the
+//! program, so a runner can count the hits of each probe (for coverage, concolic execution, etc.)
+//! without changes to the compiler for each analysis.
+
+use cairo_lang_sierra::extensions::instrumentation::InstrumentationHitLibfunc;
+use cairo_lang_sierra::extensions::NamedLibfunc;
+use cairo_lang_sierra::ids::GenericLibfuncId;
+use cairo_lang_sierra::program::{
+    BranchInfo, BranchTarget, ConcreteLibfuncLongId, GenericArg, Invocation, LibfuncDeclaration,
+    Program, Statement, StatementIdx,
+};
+use num_bigint::BigInt;
+
+use crate::db::SierraGenGroup;
+
+#[cfg(test)]
+#[path = "instrumentation_test.rs"]
+mod test;
+
+/// The program points at which probes are injected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstrumentationGranularity {
+    /// A probe before every statement.
+    Statement,
+    /// A probe at the start of every basic block - the function entry points, the branch targets,
+    /// and the statements following branches and returns.
+    BasicBlock,
+}
+
+/// Returns the given program with probes injected at the given granularity.
+pub fn instrument_program(
+    db: &dyn SierraGenGroup,
+    program: &Program,
+    granularity: InstrumentationGranularity,
+) -> Program {
+    let probed = probed_statements(program, granularity);
+    // The index of each original statement in the instrumented program, where a jump to the
+    // statement should land - at its probe, if it has one.
+    let mut new_indices = Vec::with_capacity(program.statements.len());
+    let mut next_index = 0;
+    for is_probed in &probed {
+        new_indices.push(StatementIdx(next_index));
+        next_index += if *is_probed { 2 } else { 1 };
+    }
+    let remap = |target: &BranchTarget| match target {
+        BranchTarget::Fallthrough => BranchTarget::Fallthrough,
+        BranchTarget::Statement(idx) => BranchTarget::Statement(new_indices[idx.0]),
+    };
+
+    let mut instrumented = program.clone();
+    instrumented.statements = Vec::with_capacity(next_index);
+    for (index, statement) in program.statements.iter().enumerate() {
+        if probed[index] {
+            let long_id = hit_libfunc_long_id(index);
+            let libfunc_id = db.intern_concrete_lib_func(long_id.clone());
+            instrumented
+                .libfunc_declarations
+                .push(LibfuncDeclaration { id: libfunc_id.clone(), long_id });
+            instrumented.statements.push(Statement::Invocation(Invocation {
+                libfunc_id,
+                args: vec![],
+                branches: vec![BranchInfo { target: BranchTarget::Fallthrough, results: vec![] }],
+            }));
+        }
+        instrumented.statements.push(match statement {
+            Statement::Invocation(invocation) => Statement::Invocation(Invocation {
+                branches: invocation
+                    .branches
+                    .iter()
+                    .map(|branch| BranchInfo {
+                        target: remap(&branch.target),
+                        results: branch.results.clone(),
+                    })
+                    .collect(),
+                ..invocation.clone()
+            }),
+            Statement::Return(_) => statement.clone(),
+        });
+    }
+    for function in &mut instrumented.funcs {
+        function.entry_point = new_indices[function.entry_point.0];
+    }
+    instrumented
+}
+
+/// Returns the locations of the statements of the program instrumented by [instrument_program],
+/// given the locations of the statements of the original program. A probe shares the location of
+/// the statement it precedes.
+pub fn instrument_statements_locations<T: Clone>(
+    program: &Program,
+    granularity: InstrumentationGranularity,
+    statements_locations: &[T],
+) -> Vec<T> {
+    let probed = probed_statements(program, granularity);
+    let mut instrumented = vec![];
+    for (is_probed, location) in probed.into_iter().zip(statements_locations) {
+        if is_probed {
+            instrumented.push(location.clone());
+        }
+        instrumented.push(location.clone());
+    }
+    instrumented
+}
+
+/// Returns whether each of the statements of the program should be preceded by a probe.
+fn probed_statements(program: &Program, granularity: InstrumentationGranularity) -> Vec<bool> {
+    let n_statements = program.statements.len();
+    if granularity == InstrumentationGranularity::Statement {
+        return vec![true; n_statements];
+    }
+    let mut probed = vec![false; n_statements];
+    for function in &program.funcs {
+        probed[function.entry_point.0] = true;
+    }
+    for (index, statement) in program.statements.iter().enumerate() {
+        let ends_block = match statement {
+            Statement::Return(_) => true,
+            Statement::Invocation(invocation) => {
+                for branch in &invocation.branches {
+                    if let BranchTarget::Statement(target) = &branch.target {
+                        probed[target.0] = true;
+                    }
+                }
+                !matches!(
+                    &invocation.branches[..],
+                    [BranchInfo { target: BranchTarget::Fallthrough, .. }]
+                )
+            }
+        };
+        if ends_block && index + 1 < n_statements {
+            probed[index + 1] = true;
+        }
+    }
+    probed
+}
+
+/// Returns the long id of the libfunc of the probe with the given id.
+fn hit_libfunc_long_id(id: usize) -> ConcreteLibfuncLongId {
+    ConcreteLibfuncLongId {
+        generic_id: GenericLibfuncId::from_string(InstrumentationHitLibfunc::STR_ID),
+        generic_args: vec![GenericArg::Value(BigInt::from(id))],
+    }
+}
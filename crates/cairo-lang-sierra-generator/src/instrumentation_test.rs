@@ -0,0 +1,78 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{instrument_program, InstrumentationGranularity};
+use crate::db::SierraGenGroup;
+use crate::replace_ids::replace_sierra_ids_in_program;
+use crate::test_utils::setup_db_and_get_crate_id;
+
+const CODE: &str = indoc! {"
+    fn foo(a: felt252) -> felt252 {
+        bar(5)
+    }
+
+    fn bar(a: felt252) -> felt252 {
+        felt252_add(felt252_add(a, a), a)
+    }
+"};
+
+#[test]
+fn test_instrument_basic_blocks() {
+    let (db, crate_id) = setup_db_and_get_crate_id(CODE);
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let instrumented = instrument_program(&db, &program, InstrumentationGranularity::BasicBlock);
+
+    assert_eq!(
+        replace_sierra_ids_in_program(&db, &instrumented).to_string(),
+        indoc! {"
+            type felt252 = felt252;
+
+            libfunc drop<felt252> = drop<felt252>;
+            libfunc felt252_const<5> = felt252_const<5>;
+            libfunc store_temp<felt252> = store_temp<felt252>;
+            libfunc function_call<user@test::bar> = function_call<user@test::bar>;
+            libfunc rename<felt252> = rename<felt252>;
+            libfunc dup<felt252> = dup<felt252>;
+            libfunc felt252_add = felt252_add;
+            libfunc instrumentation_hit<0> = instrumentation_hit<0>;
+            libfunc instrumentation_hit<6> = instrumentation_hit<6>;
+
+            instrumentation_hit<0>() -> ();
+            drop<felt252>([0]) -> ();
+            felt252_const<5>() -> ([1]);
+            store_temp<felt252>([1]) -> ([3]);
+            function_call<user@test::bar>([3]) -> ([2]);
+            rename<felt252>([2]) -> ([4]);
+            return([4]);
+            instrumentation_hit<6>() -> ();
+            dup<felt252>([0]) -> ([0], [2]);
+            dup<felt252>([0]) -> ([0], [3]);
+            felt252_add([2], [3]) -> ([1]);
+            store_temp<felt252>([1]) -> ([1]);
+            felt252_add([1], [0]) -> ([4]);
+            store_temp<felt252>([4]) -> ([5]);
+            return([5]);
+
+            test::foo@0([0]: felt252) -> (felt252);
+            test::bar@7([0]: felt252) -> (felt252);
+        "},
+    );
+}
+
+#[test]
+fn test_instrument_statements() {
+    let (db, crate_id) = setup_db_and_get_crate_id(CODE);
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let instrumented = instrument_program(&db, &program, InstrumentationGranularity::Statement);
+
+    assert_eq!(instrumented.statements.len(), 2 * program.statements.len());
+    assert_eq!(
+        instrumented.libfunc_declarations.len(),
+        program.libfunc_declarations.len() + program.statements.len()
+    );
+    for (index, statement) in program.statements.iter().enumerate() {
+        assert_eq!(&instrumented.statements[2 * index + 1], statement);
+    }
+    let entry_points = instrumented.funcs.iter().map(|function| function.entry_point.0);
+    assert_eq!(entry_points.collect::<Vec<_>>(), vec![0, 12]);
+}
@@ -2,6 +2,7 @@
 //! [cairo_lang_sierra].
 
 mod ap_change;
+pub mod ap_change_report;
 mod ap_tracking;
 mod block_generator;
 pub mod canonical_id_replacer;
@@ -11,6 +12,7 @@ mod function_generator;
 #[cfg(any(feature = "testing", test))]
 pub mod function_generator_test_utils;
 mod id_allocator;
+pub mod instrumentation;
 mod lifetime;
 mod local_variables;
 mod next_statement_index_fetch;
@@ -18,6 +20,7 @@ pub mod pre_sierra;
 mod program_generator;
 pub mod replace_ids;
 mod resolve_labels;
+pub mod source_comments;
 mod specialization_context;
 mod store_variables;
 #[cfg(any(feature = "testing", test))]
@@ -3,10 +3,13 @@
 mod test;
 
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::FlagId;
 use cairo_lang_lowering as lowering;
 use cairo_lang_lowering::{BlockId, VariableId};
 use cairo_lang_sierra::extensions::lib_func::{BranchSignature, LibfuncSignature};
 use cairo_lang_sierra::extensions::OutputVarReferenceInfo;
+use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
@@ -60,7 +63,7 @@ pub fn analyze_ap_changes(
     let peeled_used_after_revoke: OrderedHashSet<_> =
         ctx.used_after_revoke.iter().map(|var| ctx.peel_aliases(var)).copied().collect();
     // Any used after revoke variable that might be revoked should be a local.
-    let locals: OrderedHashSet<VariableId> = peeled_used_after_revoke
+    let mut locals: OrderedHashSet<VariableId> = peeled_used_after_revoke
         .iter()
         .filter(|var| ctx.might_be_revoked(&peeled_used_after_revoke, var))
         .cloned()
@@ -87,6 +90,13 @@ pub fn analyze_ap_changes(
         }
     }
 
+    if promote_to_locals(db) {
+        // Local variables are fp based, so they are valid after the convergence regardless of the
+        // ap changes of the branches.
+        locals.extend(need_ap_alignment.iter().map(|var| *ctx.peel_aliases(var)));
+        need_ap_alignment.clear();
+    }
+
     Ok(AnalyzeApChangesResult {
         known_ap_change: root_info.known_ap_change,
         local_variables: locals,
@@ -98,6 +108,13 @@ pub fn analyze_ap_changes(
     })
 }
 
+/// Returns whether the variables requiring ap alignment should be stored in local variables, see
+/// [Flag::PromoteToLocals].
+fn promote_to_locals(db: &dyn SierraGenGroup) -> bool {
+    db.get_flag(FlagId::new(db.upcast(), "promote_to_locals"))
+        .map_or(false, |flag| extract_matches!(*flag, Flag::PromoteToLocals))
+}
+
 /// Context for the find_local_variables logic.
 struct FindLocalsContext<'a> {
     db: &'a dyn SierraGenGroup,
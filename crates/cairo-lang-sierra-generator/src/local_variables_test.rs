@@ -9,6 +9,7 @@ use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_semantic::test_utils::setup_test_function;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::UpcastMut;
+use indoc::indoc;
 use itertools::Itertools;
 use lowering::ids::ConcreteFunctionWithBodyId;
 
@@ -83,3 +84,42 @@ cairo_lang_test_utils::test_file_test!(
     {e2e: "e2e"},
     test_function_generator
 );
+
+#[test]
+fn test_promote_to_locals() {
+    let analyze = |promote_to_locals: bool| {
+        let db = &mut SierraGenDatabaseForTesting::default();
+        let promote_to_locals_flag_id = FlagId::new(db.upcast_mut(), "promote_to_locals");
+        db.set_flag(
+            promote_to_locals_flag_id,
+            Some(Arc::new(Flag::PromoteToLocals(promote_to_locals))),
+        );
+        let test_function = setup_test_function(
+            db,
+            indoc! {"
+                fn foo(x: felt252) -> felt252 {
+                    internal::revoke_ap_tracking();
+                    let y = x + 1;
+                    let z = match x { 0 => 1, _ => 2 };
+                    y + z
+                }
+            "},
+            "foo",
+            "",
+        )
+        .unwrap();
+        let function_id =
+            ConcreteFunctionWithBodyId::from_semantic(db, test_function.concrete_function_id);
+        let lowered_function = &*db.concrete_function_with_body_lowered(function_id).unwrap();
+        let AnalyzeApChangesResult { local_variables, ap_tracking_configuration, .. } =
+            super::analyze_ap_changes(db, lowered_function).unwrap();
+        (local_variables.len(), ap_tracking_configuration.enable_ap_tracking.len())
+    };
+
+    // `y` is alive after the convergence of the match arms, so it requires aligning the arms.
+    let (num_locals, num_enables) = analyze(false);
+    assert_eq!(num_locals, 0);
+    assert!(num_enables > 0);
+    // Storing `y` in a local variable removes the need to align the arms.
+    assert_eq!(analyze(true), (1, 0));
+}
@@ -32,6 +32,7 @@ impl NextStatementIndexFetch {
                     "Unexpected pre_sierra::Statement::PushValues in \
                      NextStatementIndexFetch::new()."
                 ),
+                Statement::Location(_) => {}
             }
         }
         Self { label_to_statement }
@@ -1,6 +1,7 @@
 use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
 
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
@@ -49,7 +50,8 @@ fn collect_used_libfuncs(
                 Some(invocation.libfunc_id.clone())
             }
             pre_sierra::Statement::Sierra(program::GenStatement::Return(_))
-            | pre_sierra::Statement::Label(_) => None,
+            | pre_sierra::Statement::Label(_)
+            | pre_sierra::Statement::Location(_) => None,
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in collect_used_libfuncs().")
             }
@@ -127,10 +129,11 @@ fn collect_used_types(
         .collect()
 }
 
-pub fn get_sierra_program_for_functions(
+/// Collects the pre-Sierra functions required for the requested functions, and their statements.
+pub(crate) fn collect_functions_and_statements(
     db: &dyn SierraGenGroup,
     requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
-) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+) -> Maybe<(Vec<Arc<pre_sierra::Function>>, Vec<pre_sierra::Statement>)> {
     let mut functions: Vec<Arc<pre_sierra::Function>> = vec![];
     let mut statements: Vec<pre_sierra::Statement> = vec![];
     let mut processed_function_ids = UnorderedHashSet::<ConcreteFunctionWithBodyId>::default();
@@ -150,7 +153,14 @@ pub fn get_sierra_program_for_functions(
             }
         }
     }
+    Ok((functions, statements))
+}
 
+pub fn get_sierra_program_for_functions(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    let (functions, statements) = collect_functions_and_statements(db, requested_function_ids)?;
     let libfunc_declarations =
         generate_libfunc_declarations(db, collect_used_libfuncs(&statements).iter());
     let type_declarations =
@@ -202,10 +212,47 @@ fn try_get_function_with_body_id(
     .expect("No diagnostics at this stage.")
 }
 
-pub fn get_sierra_program(
+/// Returns the source locations of the statements of the Sierra program of the requested
+/// functions. A statement's location is the location of the lowered statement it was generated
+/// from, if any.
+pub fn get_sierra_program_statements_locations_for_functions(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+) -> Maybe<Arc<Vec<Option<StableLocation>>>> {
+    let (_, statements) = collect_functions_and_statements(db, requested_function_ids)?;
+    let mut locations = vec![];
+    let mut current_location = None;
+    for statement in statements {
+        match statement {
+            pre_sierra::Statement::Sierra(_) => locations.push(current_location),
+            pre_sierra::Statement::Label(_) => current_location = None,
+            pre_sierra::Statement::Location(location) => {
+                current_location = Some(location.get(db.upcast()).stable_location)
+            }
+            pre_sierra::Statement::PushValues(_) => panic!(
+                "Unexpected pre_sierra::Statement::PushValues in \
+                 get_sierra_program_statements_locations_for_functions()."
+            ),
+        }
+    }
+    Ok(Arc::new(locations))
+}
+
+pub fn get_sierra_program_statements_locations(
     db: &dyn SierraGenGroup,
     requested_crate_ids: Vec<CrateId>,
-) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+) -> Maybe<Arc<Vec<Option<StableLocation>>>> {
+    db.get_sierra_program_statements_locations_for_functions(crates_function_ids(
+        db,
+        requested_crate_ids,
+    )?)
+}
+
+/// Returns the functions of the requested crates that are compiled into their Sierra program.
+pub(crate) fn crates_function_ids(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: Vec<CrateId>,
+) -> Maybe<Vec<ConcreteFunctionWithBodyId>> {
     let mut requested_function_ids = vec![];
     for crate_id in requested_crate_ids {
         for module_id in db.crate_modules(crate_id).iter() {
@@ -219,5 +266,12 @@ pub fn get_sierra_program(
             }
         }
     }
-    db.get_sierra_program_for_functions(requested_function_ids)
+    Ok(requested_function_ids)
+}
+
+pub fn get_sierra_program(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: Vec<CrateId>,
+) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    db.get_sierra_program_for_functions(crates_function_ids(db, requested_crate_ids)?)
 }
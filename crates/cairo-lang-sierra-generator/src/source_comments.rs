@@ -0,0 +1,64 @@
+#[cfg(test)]
+#[path = "source_comments_test.rs"]
+mod test;
+
+use std::fmt::Write;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_sierra::program::Program;
+
+use crate::db::SierraGenGroup;
+
+/// Returns the text of the given Sierra program, where the statements are interleaved with the
+/// Cairo source lines they originate from, as comments.
+///
+/// `statements_locations` are the locations of the program's statements, as returned by
+/// [SierraGenGroup::get_sierra_program_statements_locations]. The source line of a statement is
+/// only printed if it differs from the source line of the previous statement.
+pub fn program_with_source_comments(
+    db: &dyn SierraGenGroup,
+    program: &Program,
+    statements_locations: &[Option<StableLocation>],
+) -> String {
+    let defs_db: &dyn DefsGroup = db.upcast();
+    let mut res = String::new();
+    for declaration in &program.type_declarations {
+        writeln!(res, "{declaration};").unwrap();
+    }
+    writeln!(res).unwrap();
+    for declaration in &program.libfunc_declarations {
+        writeln!(res, "{declaration};").unwrap();
+    }
+    writeln!(res).unwrap();
+    let mut prev_comment = String::new();
+    for (statement, location) in program.statements.iter().zip(statements_locations) {
+        let comment =
+            location.map(|location| source_comment(defs_db, &location)).unwrap_or_default();
+        if comment != prev_comment {
+            res.push_str(&comment);
+            prev_comment = comment;
+        }
+        writeln!(res, "{statement};").unwrap();
+    }
+    writeln!(res).unwrap();
+    for func in &program.funcs {
+        writeln!(res, "{func};").unwrap();
+    }
+    res
+}
+
+/// Returns the first source line of the given location as a comment.
+fn source_comment(db: &dyn DefsGroup, location: &StableLocation) -> String {
+    let location = location.diagnostic_location(db);
+    let file_id = location.file_id;
+    let Some(position) = location.span.start.position_in_file(db.upcast(), file_id) else {
+        return String::new();
+    };
+    let Some(text) = db.file_content(file_id).and_then(|content| {
+        content.lines().nth(position.line).map(|line| line.trim().to_string())
+    }) else {
+        return String::new();
+    };
+    format!("// {}:{}: {text}\n", file_id.file_name(db.upcast()), position.line + 1)
+}
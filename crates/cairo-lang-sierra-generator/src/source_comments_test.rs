@@ -0,0 +1,59 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::program_with_source_comments;
+use crate::db::SierraGenGroup;
+use crate::replace_ids::replace_sierra_ids_in_program;
+use crate::test_utils::setup_db_and_get_crate_id;
+
+#[test]
+fn test_program_with_source_comments() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn foo(a: felt252) -> felt252 {
+            bar(5)
+        }
+
+        fn bar(a: felt252) -> felt252 {
+            felt252_add(felt252_add(a, a), a)
+        }
+    "});
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let program = replace_sierra_ids_in_program(&db, &program);
+    let statements_locations = db.get_sierra_program_statements_locations(vec![crate_id]).unwrap();
+    assert_eq!(statements_locations.len(), program.statements.len());
+
+    assert_eq!(
+        program_with_source_comments(&db, &program, &statements_locations),
+        indoc! {"
+            type felt252 = felt252;
+
+            libfunc drop<felt252> = drop<felt252>;
+            libfunc felt252_const<5> = felt252_const<5>;
+            libfunc store_temp<felt252> = store_temp<felt252>;
+            libfunc function_call<user@test::bar> = function_call<user@test::bar>;
+            libfunc rename<felt252> = rename<felt252>;
+            libfunc dup<felt252> = dup<felt252>;
+            libfunc felt252_add = felt252_add;
+
+            drop<felt252>([0]) -> ();
+            // lib.cairo:2: bar(5)
+            felt252_const<5>() -> ([1]);
+            store_temp<felt252>([1]) -> ([3]);
+            function_call<user@test::bar>([3]) -> ([2]);
+            rename<felt252>([2]) -> ([4]);
+            return([4]);
+            // lib.cairo:6: felt252_add(felt252_add(a, a), a)
+            dup<felt252>([0]) -> ([0], [2]);
+            dup<felt252>([0]) -> ([0], [3]);
+            felt252_add([2], [3]) -> ([1]);
+            store_temp<felt252>([1]) -> ([1]);
+            felt252_add([1], [0]) -> ([4]);
+            store_temp<felt252>([4]) -> ([5]);
+            return([5]);
+
+            test::foo@0([0]: felt252) -> (felt252);
+            test::bar@6([0]: felt252) -> (felt252);
+        "},
+    );
+}
@@ -1,16 +1,18 @@
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
+use cairo_lang_sierra::extensions::inline_casm::{encode_inline_casm_code, InlineCasmLibfunc};
 use cairo_lang_sierra::extensions::lib_func::LibfuncSignature;
 use cairo_lang_sierra::extensions::snapshot::SnapshotType;
 use cairo_lang_sierra::extensions::{
-    ExtensionError, GenericLibfuncEx, NamedType, SpecializationError,
+    ExtensionError, GenericLibfuncEx, NamedLibfunc, NamedType, SpecializationError,
 };
 use cairo_lang_sierra::ids::{ConcreteLibfuncId, GenericLibfuncId};
 use cairo_lang_sierra::program;
 use cairo_lang_utils::extract_matches;
 use num_bigint::BigInt;
 use semantic::corelib::get_const_libfunc_name_by_type;
+use semantic::items::extern_function::InlineCasm;
 use semantic::items::functions::GenericFunctionId;
 use smol_str::SmolStr;
 use {cairo_lang_defs as defs, cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
@@ -291,6 +293,20 @@ pub fn generic_libfunc_id(
     })
 }
 
+/// Returns the [ConcreteLibfuncId] running the given inline CASM code.
+fn inline_casm_libfunc_id(db: &dyn SierraGenGroup, inline_casm: &InlineCasm) -> ConcreteLibfuncId {
+    let generic_args = [inline_casm.num_inputs, inline_casm.num_outputs, inline_casm.ap_change]
+        .into_iter()
+        .map(BigInt::from)
+        .chain(encode_inline_casm_code(&inline_casm.code))
+        .map(cairo_lang_sierra::program::GenericArg::Value)
+        .collect();
+    db.intern_concrete_lib_func(cairo_lang_sierra::program::ConcreteLibfuncLongId {
+        generic_id: GenericLibfuncId::from_string(InlineCasmLibfunc::STR_ID),
+        generic_args,
+    })
+}
+
 /// Returns the [ConcreteLibfuncId] used for calling a function (either user-defined or libfunc).
 pub fn get_concrete_libfunc_id(
     db: &dyn SierraGenGroup,
@@ -304,6 +320,11 @@ pub fn get_concrete_libfunc_id(
         extract_matches!(function.lookup(db.upcast()), lowering::ids::FunctionLongId::Semantic);
     let concrete_function = db.lookup_intern_function(semantic).function;
     let extern_id = extract_matches!(concrete_function.generic_function, GenericFunctionId::Extern);
+    if let Some(inline_casm) =
+        db.extern_function_inline_casm(extern_id).expect("No diagnostics at this stage.")
+    {
+        return (None, inline_casm_libfunc_id(db, &inline_casm));
+    }
 
     let mut generic_args = vec![];
     for generic_arg in &concrete_function.generic_args {
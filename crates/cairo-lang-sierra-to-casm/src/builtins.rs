@@ -0,0 +1,168 @@
+//! The builtins compiled programs may receive, and the order in which entry points receive them.
+//!
+//! Entry points receive their builtins in the order of [BUILTINS] - first the builtins of the VM
+//! layout, and then the builtins set up by the code calling the entry point. The runner, the
+//! Starknet OS entry points and the implicit precedence of contracts all follow this table, so
+//! supporting a new builtin only requires adding its entry here, along with the invocations of its
+//! libfuncs. Contracts only receive the builtins supported by the Starknet OS.
+
+use cairo_lang_sierra::extensions::bitwise::BitwiseType;
+use cairo_lang_sierra::extensions::ec::EcOpType;
+use cairo_lang_sierra::extensions::ecdsa::EcdsaType;
+use cairo_lang_sierra::extensions::gas::GasBuiltinType;
+use cairo_lang_sierra::extensions::keccak::KeccakType;
+use cairo_lang_sierra::extensions::modular::{AddModType, MulModType};
+use cairo_lang_sierra::extensions::pedersen::PedersenType;
+use cairo_lang_sierra::extensions::poseidon::PoseidonType;
+use cairo_lang_sierra::extensions::range_check::{RangeCheck96Type, RangeCheckType};
+use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
+use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
+use cairo_lang_sierra::extensions::NamedType;
+use cairo_lang_sierra::ids::GenericTypeId;
+
+#[cfg(test)]
+#[path = "builtins_test.rs"]
+mod test;
+
+/// The way a builtin is provided to an entry point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuiltinKind {
+    /// A builtin of the VM layout, pointing to a segment of instances of the given size in cells.
+    Layout { cells_per_instance: usize },
+    /// The gas counter, initialized by the calling code.
+    Gas,
+    /// The segment arena, allocated by the calling code.
+    SegmentArena,
+    /// The system pointer, used for syscalls.
+    System,
+}
+
+/// A builtin compiled programs may receive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BuiltinInfo {
+    /// The id of the Sierra type of the builtin.
+    pub generic_id: GenericTypeId,
+    /// The name of the builtin in the VM layouts and in contract classes.
+    pub name: &'static str,
+    pub kind: BuiltinKind,
+    /// Whether the Starknet OS supports the builtin, so that contracts may receive it.
+    pub starknet: bool,
+}
+
+/// The builtins compiled programs may receive, in the order entry points receive them.
+pub static BUILTINS: [BuiltinInfo; 13] = [
+    BuiltinInfo {
+        generic_id: PedersenType::ID,
+        name: "pedersen",
+        kind: BuiltinKind::Layout { cells_per_instance: 3 },
+        starknet: true,
+    },
+    BuiltinInfo {
+        generic_id: RangeCheckType::ID,
+        name: "range_check",
+        kind: BuiltinKind::Layout { cells_per_instance: 1 },
+        starknet: true,
+    },
+    BuiltinInfo {
+        generic_id: EcdsaType::ID,
+        name: "ecdsa",
+        kind: BuiltinKind::Layout { cells_per_instance: 2 },
+        starknet: false,
+    },
+    BuiltinInfo {
+        generic_id: BitwiseType::ID,
+        name: "bitwise",
+        kind: BuiltinKind::Layout { cells_per_instance: 5 },
+        starknet: true,
+    },
+    BuiltinInfo {
+        generic_id: EcOpType::ID,
+        name: "ec_op",
+        kind: BuiltinKind::Layout { cells_per_instance: 7 },
+        starknet: true,
+    },
+    BuiltinInfo {
+        generic_id: KeccakType::ID,
+        name: "keccak",
+        kind: BuiltinKind::Layout { cells_per_instance: 16 },
+        starknet: false,
+    },
+    BuiltinInfo {
+        generic_id: PoseidonType::ID,
+        name: "poseidon",
+        kind: BuiltinKind::Layout { cells_per_instance: 6 },
+        starknet: true,
+    },
+    BuiltinInfo {
+        generic_id: RangeCheck96Type::ID,
+        name: "range_check96",
+        kind: BuiltinKind::Layout { cells_per_instance: 1 },
+        starknet: false,
+    },
+    BuiltinInfo {
+        generic_id: AddModType::ID,
+        name: "add_mod",
+        kind: BuiltinKind::Layout { cells_per_instance: 7 },
+        starknet: false,
+    },
+    BuiltinInfo {
+        generic_id: MulModType::ID,
+        name: "mul_mod",
+        kind: BuiltinKind::Layout { cells_per_instance: 7 },
+        starknet: false,
+    },
+    BuiltinInfo {
+        generic_id: SegmentArenaType::ID,
+        name: "segment_arena",
+        kind: BuiltinKind::SegmentArena,
+        starknet: true,
+    },
+    BuiltinInfo {
+        generic_id: GasBuiltinType::ID,
+        name: "gas_builtin",
+        kind: BuiltinKind::Gas,
+        starknet: true,
+    },
+    BuiltinInfo {
+        generic_id: SystemType::ID,
+        name: "system",
+        kind: BuiltinKind::System,
+        starknet: true,
+    },
+];
+
+/// Returns the index of the builtin with the given type in [BUILTINS], if it is a builtin.
+pub fn builtin_index(generic_id: &GenericTypeId) -> Option<usize> {
+    BUILTINS.iter().position(|builtin| builtin.generic_id == *generic_id)
+}
+
+/// Returns the builtin with the given type, if it is a builtin.
+pub fn builtin_info(generic_id: &GenericTypeId) -> Option<&'static BuiltinInfo> {
+    builtin_index(generic_id).map(|index| &BUILTINS[index])
+}
+
+/// Returns the builtins of the VM layout, in order.
+pub fn layout_builtins() -> impl Iterator<Item = &'static BuiltinInfo> {
+    BUILTINS.iter().filter(|builtin| matches!(builtin.kind, BuiltinKind::Layout { .. }))
+}
+
+/// Returns the builtins Starknet contracts may receive, in order.
+pub fn starknet_builtins() -> impl Iterator<Item = &'static BuiltinInfo> {
+    BUILTINS.iter().filter(|builtin| builtin.starknet)
+}
+
+/// Returns whether the given builtin types are in the order entry points receive them, without
+/// repetitions. Types which are not builtins are not allowed.
+pub fn is_builtin_order<'a>(generic_ids: impl IntoIterator<Item = &'a GenericTypeId>) -> bool {
+    let mut prev_index = None;
+    for generic_id in generic_ids {
+        let Some(index) = builtin_index(generic_id) else {
+            return false;
+        };
+        if matches!(prev_index, Some(prev_index) if prev_index >= index) {
+            return false;
+        }
+        prev_index = Some(index);
+    }
+    true
+}
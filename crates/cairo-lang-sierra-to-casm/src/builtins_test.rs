@@ -0,0 +1,63 @@
+use cairo_lang_sierra::extensions::gas::GasBuiltinType;
+use cairo_lang_sierra::extensions::pedersen::PedersenType;
+use cairo_lang_sierra::extensions::range_check::RangeCheckType;
+use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
+use cairo_lang_sierra::extensions::NamedType;
+use cairo_lang_sierra::ids::GenericTypeId;
+use itertools::Itertools;
+use test_case::test_case;
+
+use super::{builtin_info, is_builtin_order, layout_builtins, starknet_builtins, BuiltinKind};
+
+#[test]
+fn test_layout_builtins() {
+    assert_eq!(
+        layout_builtins().map(|builtin| builtin.name).collect_vec(),
+        [
+            "pedersen",
+            "range_check",
+            "ecdsa",
+            "bitwise",
+            "ec_op",
+            "keccak",
+            "poseidon",
+            "add_mod",
+            "mul_mod"
+        ]
+    );
+}
+
+#[test]
+fn test_starknet_builtins() {
+    assert_eq!(
+        starknet_builtins().map(|builtin| builtin.name).collect_vec(),
+        [
+            "pedersen",
+            "range_check",
+            "bitwise",
+            "ec_op",
+            "poseidon",
+            "segment_arena",
+            "gas_builtin",
+            "system"
+        ]
+    );
+}
+
+#[test]
+fn test_builtin_info() {
+    assert_eq!(builtin_info(&GasBuiltinType::ID).unwrap().kind, BuiltinKind::Gas);
+    assert_eq!(builtin_info(&GenericTypeId::new_inline("felt252")), None);
+}
+
+#[test_case(
+    &[PedersenType::ID, RangeCheckType::ID, GasBuiltinType::ID, SystemType::ID],
+    true;
+    "ordered"
+)]
+#[test_case(&[RangeCheckType::ID, PedersenType::ID], false; "unordered")]
+#[test_case(&[RangeCheckType::ID, RangeCheckType::ID], false; "repeated")]
+#[test_case(&[GenericTypeId::new_inline("felt252")], false; "not a builtin")]
+fn test_is_builtin_order(generic_ids: &[GenericTypeId], expected: bool) {
+    assert_eq!(is_builtin_order(generic_ids), expected);
+}
@@ -1,19 +1,22 @@
 use std::fmt::Display;
 
+use cairo_lang_casm::hints::Hint;
 use cairo_lang_casm::instructions::{Instruction, InstructionBody, RetInstruction};
 use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::ConcreteLibfunc;
-use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::ids::{ConcreteTypeId, VarId};
 use cairo_lang_sierra::program::{BranchTarget, Invocation, Program, Statement, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
 use cairo_lang_sierra_type_size::get_type_size_map;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use itertools::zip_eq;
+use num_bigint::BigInt;
 use thiserror::Error;
 
 use crate::annotations::{AnnotationError, ProgramAnnotations, StatementAnnotations};
 use crate::invocations::{
-    check_references_on_stack, compile_invocation, InvocationError, ProgramInfo,
+    check_references_on_stack, compile_invocation, EncodingTable, InvocationError, ProgramInfo,
 };
 use crate::metadata::Metadata;
 use crate::references::{check_types_match, ReferencesError};
@@ -48,20 +51,78 @@ pub enum CompilationError {
     },
 }
 
+/// The version of the Cairo VM a program is compiled for.
+///
+/// Libfuncs may have several lowerings, the lowering used for each libfunc is selected by the
+/// features supported by the target VM. The same Sierra program can thus be compiled for VMs before
+/// and after a network upgrade.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VmVersion {
+    /// VMs not supporting the `AllocConstantSize` hint.
+    V0,
+    /// The latest VM.
+    #[default]
+    V1,
+}
+
 /// The casm program representation.
 #[derive(Debug, Eq, PartialEq)]
 pub struct CairoProgram {
     pub instructions: Vec<Instruction>,
     pub debug_info: CairoProgramDebugInfo,
+    pub consts_info: ConstsInfo,
 }
 impl Display for CairoProgram {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for instruction in &self.instructions {
             writeln!(f, "{instruction};")?
         }
+        for value in &self.consts_info.values {
+            writeln!(f, "dw {value};")?
+        }
         Ok(())
     }
 }
+impl CairoProgram {
+    /// Assembles the program, along with its const data segments, without a header or a footer.
+    pub fn assemble(&self) -> AssembledCairoProgram {
+        self.assemble_ex(&[], &[])
+    }
+
+    /// Assembles the program with the given header before it and footer after it. The const data
+    /// segments are placed right after the program code, before the footer.
+    pub fn assemble_ex(
+        &self,
+        header: &[Instruction],
+        footer: &[Instruction],
+    ) -> AssembledCairoProgram {
+        let mut assembled = AssembledCairoProgram { bytecode: vec![], hints: vec![] };
+        assembled.add_instructions(header.iter().chain(&self.instructions));
+        assembled.bytecode.extend(self.consts_info.values.iter().cloned());
+        assembled.add_instructions(footer.iter());
+        assembled
+    }
+}
+
+/// The assembled bytecode of a casm program.
+#[derive(Debug, Eq, PartialEq)]
+pub struct AssembledCairoProgram {
+    /// The bytecode of the program.
+    pub bytecode: Vec<BigInt>,
+    /// The hints of the program, by the offsets of their instructions in the bytecode.
+    pub hints: Vec<(usize, Vec<Hint>)>,
+}
+impl AssembledCairoProgram {
+    /// Appends the given instructions to the bytecode.
+    fn add_instructions<'a>(&mut self, instructions: impl Iterator<Item = &'a Instruction>) {
+        for instruction in instructions {
+            if !instruction.hints.is_empty() {
+                self.hints.push((self.bytecode.len(), instruction.hints.clone()))
+            }
+            self.bytecode.extend(instruction.assemble().encode());
+        }
+    }
+}
 
 /// The debug information of a compilation from Sierra to casm.
 #[derive(Debug, Eq, PartialEq)]
@@ -77,6 +138,58 @@ pub struct CairoProgramDebugInfo {
     pub sierra_statement_info: Vec<SierraStatementDebugInfo>,
 }
 
+/// The const data segments of a program.
+///
+/// The segments are part of the program - they are placed right after the program code, before
+/// the code footer appended to the program.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct ConstsInfo {
+    /// The values of all the segments, in order.
+    pub values: Vec<BigInt>,
+    /// The offset within `values` of the segment of each const array type.
+    pub segment_offsets: UnorderedHashMap<ConcreteTypeId, usize>,
+    /// The offset within `values` of the segment of each encoding table.
+    pub table_offsets: UnorderedHashMap<EncodingTable, usize>,
+}
+impl ConstsInfo {
+    /// Collects the segments of the const arrays used by the `const_as_span` libfuncs of the
+    /// program, and of the tables used by its encoding libfuncs.
+    pub fn new(
+        program: &Program,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> Result<Self, CompilationError> {
+        let mut consts_info = Self::default();
+        for declaration in &program.libfunc_declarations {
+            let libfunc = registry
+                .get_libfunc(&declaration.id)
+                .map_err(CompilationError::ProgramRegistryError)?;
+            consts_info.add_libfunc_segments(libfunc);
+        }
+        Ok(consts_info)
+    }
+
+    /// Adds the segments used by `libfunc`. Each segment is added once, however many libfuncs use
+    /// it.
+    pub fn add_libfunc_segments(&mut self, libfunc: &CoreConcreteLibfunc) {
+        match libfunc {
+            CoreConcreteLibfunc::ConstAsSpan(libfunc) => {
+                if !self.segment_offsets.contains_key(&libfunc.const_array_ty) {
+                    self.segment_offsets.insert(libfunc.const_array_ty.clone(), self.values.len());
+                    self.values.extend(libfunc.values.iter().cloned());
+                }
+            }
+            CoreConcreteLibfunc::Encoding(libfunc) => {
+                let table = EncodingTable::used_by(libfunc);
+                if !self.table_offsets.contains_key(&table) {
+                    self.table_offsets.insert(table, self.values.len());
+                    self.values.extend(table.values());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Ensure the basic structure of the invocation is the same as the library function.
 pub fn check_basic_structure(
     statement_idx: StatementIdx,
@@ -101,10 +214,21 @@ pub fn check_basic_structure(
     }
 }
 
+/// Compiles a Sierra program to casm, for the latest VM.
 pub fn compile(
     program: &Program,
     metadata: &Metadata,
     gas_usage_check: bool,
+) -> Result<CairoProgram, Box<CompilationError>> {
+    compile_for_vm_version(program, metadata, gas_usage_check, VmVersion::default())
+}
+
+/// Compiles a Sierra program to casm, for the given VM version.
+pub fn compile_for_vm_version(
+    program: &Program,
+    metadata: &Metadata,
+    gas_usage_check: bool,
+    vm_version: VmVersion,
 ) -> Result<CairoProgram, Box<CompilationError>> {
     let mut instructions = Vec::new();
     let mut relocations: Vec<RelocationEntry> = Vec::new();
@@ -118,7 +242,7 @@ pub fn compile(
         metadata.ap_change_info.function_ap_change.clone(),
     )
     .map_err(CompilationError::ProgramRegistryError)?;
-    let type_sizes = get_type_size_map(program, &registry)
+    let type_sizes = get_type_size_map(program, &registry, metadata.enum_zero_niche)
         .ok_or(CompilationError::FailedBuildingTypeInformation)?;
     let mut program_annotations = ProgramAnnotations::create(
         program.statements.len(),
@@ -128,6 +252,7 @@ pub fn compile(
         &type_sizes,
     )
     .map_err(|err| Box::new(err.into()))?;
+    let consts_info = ConstsInfo::new(program, &registry)?;
 
     let mut program_offset: usize = 0;
 
@@ -188,7 +313,12 @@ pub fn compile(
                 })?;
                 invoke_refs.iter().for_each(|r| r.validate(&type_sizes));
                 let compiled_invocation = compile_invocation(
-                    ProgramInfo { metadata, type_sizes: &type_sizes },
+                    ProgramInfo {
+                        metadata,
+                        type_sizes: &type_sizes,
+                        consts_info: &consts_info,
+                        vm_version,
+                    },
                     invocation,
                     libfunc,
                     statement_idx,
@@ -250,7 +380,9 @@ pub fn compile(
     // Push the final offset at the end of `statement_offsets`.
     statement_offsets.push(program_offset);
 
-    relocate_instructions(&relocations, &statement_offsets, &mut instructions);
+    // The const data segments are placed right after the code, so the program ends after them.
+    let program_end = program_offset + consts_info.values.len();
+    relocate_instructions(&relocations, &statement_offsets, program_end, &mut instructions);
 
     Ok(CairoProgram {
         instructions,
@@ -260,6 +392,7 @@ pub fn compile(
                 .map(|code_offset| SierraStatementDebugInfo { code_offset })
                 .collect(),
         },
+        consts_info,
     })
 }
 
@@ -1,10 +1,16 @@
+use cairo_lang_sierra::ids::ConcreteTypeId;
 use cairo_lang_sierra::ProgramParser;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use indoc::indoc;
+use num_bigint::BigInt;
 use pretty_assertions;
 use test_case::test_case;
 
 use crate::compiler::compile;
-use crate::test_utils::{build_metadata, read_sierra_example_file, strip_comments_and_linebreaks};
+use crate::metadata::{calc_metadata, MetadataComputationConfig};
+use crate::test_utils::{
+    build_metadata, read_sierra_example_file, strip_comments_and_linebreaks, SierraToCasmTestRunner,
+};
 
 #[test_case(indoc! {"
                 type felt252 = felt252;
@@ -231,6 +237,141 @@ use crate::test_utils::{build_metadata, read_sierra_example_file, strip_comments
                 ret;
             "};
             "u128")]
+#[test_case(indoc! {"
+                type u96 = u96;
+                type RangeCheck = RangeCheck;
+
+                libfunc branch_align = branch_align;
+                libfunc revoke_ap_tracking = revoke_ap_tracking;
+                libfunc u96_overflowing_add = u96_overflowing_add;
+                libfunc drop<u96> = drop<u96>;
+                libfunc store_temp<RangeCheck> = store_temp<RangeCheck>;
+
+                revoke_ap_tracking() -> ();
+                u96_overflowing_add([1], [2], [3]) {fallthrough([1], [2]) 6([1], [2]) };
+                branch_align() -> ();
+                drop<u96>([2]) -> ();
+                store_temp<RangeCheck>([1]) -> ([1]);
+                return ([1]);
+                branch_align() -> ();
+                drop<u96>([2]) -> ();
+                store_temp<RangeCheck>([1]) -> ([1]);
+                return ([1]);
+
+                test_program@0([1]: RangeCheck, [2]: u96, [3]: u96) -> (RangeCheck);
+            "},
+            false,
+            indoc! {"
+                %{ memory[ap + 0] = memory[fp + -4] + memory[fp + -3] < 79228162514264337593543950336 %}
+                jmp rel 8 if [ap + 0] != 0, ap++;
+                [ap + 0] = [fp + -4] + [fp + -3], ap++;
+                [ap + -1] = [ap + 0] + 79228162514264337593543950336, ap++;
+                [ap + -1] = [[fp + -5] + 0];
+                jmp rel 9;
+                [ap + 1] = [fp + -4] + [fp + -3], ap++;
+                [ap + -1] = [ap + 0] + 340282366841710300949110269838224261120, ap++;
+                [ap + -2] = [[fp + -5] + 0];
+                [ap + 0] = [fp + -5] + 1, ap++;
+                ret;
+                [ap + 0] = [fp + -5] + 1, ap++;
+                ret;
+            "};
+            "u96")]
+#[test_case(indoc! {"
+                type RangeCheck = RangeCheck;
+                type Digit = BoundedInt<0, 9>;
+                type NonZeroDigit = BoundedInt<1, 9>;
+                type DigitsRemainder = BoundedInt<0, 8>;
+                type DigitsSum = BoundedInt<0, 17>;
+
+                libfunc div_rem = bounded_int_div_rem<Digit, NonZeroDigit>;
+                libfunc add = bounded_int_add<Digit, DigitsRemainder>;
+                libfunc store_temp<RangeCheck> = store_temp<RangeCheck>;
+                libfunc store_temp<DigitsSum> = store_temp<DigitsSum>;
+
+                div_rem([1], [2], [3]) -> ([1], [4], [5]);
+                add([4], [5]) -> ([6]);
+                store_temp<RangeCheck>([1]) -> ([1]);
+                store_temp<DigitsSum>([6]) -> ([6]);
+                return ([1], [6]);
+
+                test_program@0([1]: RangeCheck, [2]: Digit, [3]: NonZeroDigit) -> (RangeCheck, DigitsSum);
+            "},
+            false,
+            indoc! {"
+                %{ (memory[ap + 4], memory[ap + 5]) = divmod(memory[fp + -4], memory[fp + -3]) %}
+                [ap + 5] = [[fp + -5] + 0], ap++;
+                [ap + -1] = [ap + 4] + 1, ap++;
+                [fp + -3] = [ap + -1] + [ap + -2], ap++;
+                [ap + -2] = [[fp + -5] + 1], ap++;
+                [ap + 0] = [[fp + -5] + 2], ap++;
+                [ap + -2] = [ap + -1] + 340282366920938463463374607431768211446, ap++;
+                [ap + -3] = [[fp + -5] + 3];
+                [ap + -4] = [fp + -3] * [ap + -2];
+                [fp + -4] = [ap + -4] + [ap + -1];
+                [ap + 0] = [fp + -5] + 4, ap++;
+                [ap + 0] = [ap + -3] + [ap + -2], ap++;
+                ret;
+            "};
+            "bounded_int")]
+#[test_case(indoc! {"
+                type u8 = u8;
+                type ArrayU8 = Array<u8>;
+                type SnapshotArrayU8 = Snapshot<ArrayU8>;
+                type Digits = ConstArray<u8, 1, 2, 3>;
+                type Bytes = ConstArray<u8, 255, 0>;
+
+                libfunc digits = const_as_span<Digits>;
+                libfunc bytes = const_as_span<Bytes>;
+                libfunc drop<SnapshotArrayU8> = drop<SnapshotArrayU8>;
+
+                bytes() -> ([1]);
+                drop<SnapshotArrayU8>([1]) -> ();
+                digits() -> ([2]);
+                return ([2]);
+
+                test_program@0() -> (SnapshotArrayU8);
+            "},
+            false,
+            indoc! {"
+                call rel 18;
+                [ap + 0] = [ap + -1] + 14, ap++;
+                [ap + 0] = [ap + -1] + 2, ap++;
+                call rel 12;
+                [ap + 0] = [ap + -1] + 5, ap++;
+                [ap + 0] = [ap + -1] + 3, ap++;
+                ret;
+                dw 1;
+                dw 2;
+                dw 3;
+                dw 255;
+                dw 0;
+            "};
+            "const_as_span")]
+#[test_case(indoc! {"
+                type felt252 = felt252;
+
+                libfunc hit = instrumentation_hit<7>;
+                libfunc store_temp<felt252> = store_temp<felt252>;
+
+                hit() -> ();
+                store_temp<felt252>([1]) -> ([1]);
+                return ([1]);
+
+                test_program@0([1]: felt252) -> (felt252);
+            "},
+            false,
+            indoc! {"
+                %{
+                if '__instrumentation_hits' not in globals():
+                    __instrumentation_hits = {}
+                __instrumentation_hits[7] = __instrumentation_hits.get(7, 0) + 1
+                %}
+                ap += 0;
+                [ap + 0] = [fp + -3], ap++;
+                ret;
+            "};
+            "instrumentation_hit")]
 #[test_case(read_sierra_example_file("fib_no_gas").as_str(),
             false,
             indoc! {"
@@ -396,6 +537,105 @@ fn sierra_to_casm(sierra_code: &str, check_gas_usage: bool, expected_casm: &str)
     );
 }
 
+#[test_case(false,
+            indoc! {"
+                jmp rel 6 if [fp + -4] != 0;
+                [ap + 0] = 0, ap++;
+                [ap + 0] = [fp + -3], ap++;
+                ret;
+                [ap + 0] = 1, ap++;
+                [ap + 0] = 0, ap++;
+                ret;
+            "};
+            "variant selector")]
+#[test_case(true,
+            indoc! {"
+                jmp rel 4 if [fp + -3] != 0;
+                jmp rel 4;
+                [ap + 0] = [fp + -3], ap++;
+                ret;
+                [ap + 0] = 0, ap++;
+                ret;
+            "};
+            "zero niche")]
+fn enum_zero_niche(enum_zero_niche: bool, expected_casm: &str) {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type u128 = u128;
+            type NonZeroU128 = NonZero<u128>;
+            type Unit = Struct<ut@Tuple>;
+            type Option = Enum<ut@Option, NonZeroU128, Unit>;
+
+            libfunc branch_align = branch_align;
+            libfunc enum_match<Option> = enum_match<Option>;
+            libfunc enum_init<Option, 0> = enum_init<Option, 0>;
+            libfunc enum_init<Option, 1> = enum_init<Option, 1>;
+            libfunc store_temp<Option> = store_temp<Option>;
+
+            enum_match<Option>([1]) { fallthrough([2]) 5([3]) };
+            branch_align() -> ();
+            enum_init<Option, 0>([2]) -> ([1]);
+            store_temp<Option>([1]) -> ([1]);
+            return ([1]);
+            branch_align() -> ();
+            enum_init<Option, 1>([3]) -> ([1]);
+            store_temp<Option>([1]) -> ([1]);
+            return ([1]);
+
+            test_program@0([1]: Option) -> (Option);
+        "})
+        .unwrap();
+    let metadata = calc_metadata(
+        &program,
+        MetadataComputationConfig { enum_zero_niche, ..Default::default() },
+    )
+    .unwrap();
+    pretty_assertions::assert_eq!(
+        compile(&program, &metadata, false).expect("Compilation failed.").to_string(),
+        strip_comments_and_linebreaks(expected_casm)
+    );
+}
+
+#[test]
+fn const_segments() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            type ArrayFelt252 = Array<felt252>;
+            type SnapshotArrayFelt252 = Snapshot<ArrayFelt252>;
+            type Primes = ConstArray<felt252, 2, 3, 5>;
+            type Powers = ConstArray<felt252, 1, 10>;
+
+            libfunc primes = const_as_span<Primes>;
+            libfunc powers = const_as_span<Powers>;
+            libfunc also_primes = const_as_span<Primes>;
+            libfunc drop<SnapshotArrayFelt252> = drop<SnapshotArrayFelt252>;
+
+            primes() -> ([1]);
+            drop<SnapshotArrayFelt252>([1]) -> ();
+            also_primes() -> ([1]);
+            drop<SnapshotArrayFelt252>([1]) -> ();
+            powers() -> ([1]);
+            return ([1]);
+
+            test_program@0() -> (SnapshotArrayFelt252);
+        "})
+        .unwrap();
+    let consts_info =
+        compile(&program, &build_metadata(&program, false), false).unwrap().consts_info;
+    // Both libfuncs over `Primes` share its segment.
+    pretty_assertions::assert_eq!(
+        consts_info.values,
+        [2, 3, 5, 1, 10].into_iter().map(BigInt::from).collect::<Vec<_>>()
+    );
+    pretty_assertions::assert_eq!(
+        consts_info.segment_offsets,
+        [(ConcreteTypeId::from("Primes"), 0), (ConcreteTypeId::from("Powers"), 3)]
+            .into_iter()
+            .collect::<UnorderedHashMap<_, _>>()
+    );
+}
+
 // TODO(ilya, 10/10/2022): Improve error messages.
 #[test_case(indoc! {"
                 type felt252 = felt252;
@@ -804,3 +1044,12 @@ fn compiler_errors(sierra_code: &str, expected_result: &str) {
         expected_result
     );
 }
+
+cairo_lang_test_utils::test_file_test_with_runner!(
+    compile_expectations,
+    "src/test_data",
+    {
+        felt252: "felt252",
+    },
+    SierraToCasmTestRunner
+);
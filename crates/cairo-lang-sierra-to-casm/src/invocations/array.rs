@@ -1,12 +1,25 @@
-use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::builder::{CasmBuildResult, CasmBuilder};
 use cairo_lang_casm::casm_build_extend;
 use cairo_lang_sierra::extensions::array::ArrayConcreteLibfunc;
 use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
+use cairo_lang_sierra_ap_change::core_libfunc_ap_change::core_libfunc_ap_change;
+use cairo_lang_sierra_ap_change::ApChange;
+use cairo_lang_sierra_gas::core_libfunc_cost::{
+    ARRAY_EXTEND_FROM_SPAN_FIXED_COST, ARRAY_EXTEND_FROM_SPAN_PER_CELL_COST,
+    SPAN_BINARY_SEARCH_FIXED_COST, SPAN_BINARY_SEARCH_ITERATION_COST, SPAN_EQ_FIXED_COST,
+    SPAN_EQ_PER_CELL_COST, SPAN_SORT_VERIFIED_FIXED_COST, SPAN_SORT_VERIFIED_PER_ELEMENT_COST,
+};
+use cairo_lang_sierra_gas::objects::ConstCost;
+use itertools::{chain, Itertools};
+use num_bigint::BigInt;
 
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
 use crate::invocations::{
     add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
 };
+use crate::references::ReferenceExpression;
+use crate::relocations::{Relocation, RelocationEntry};
 
 /// Builds instructions for Sierra array operations.
 pub fn build(
@@ -27,8 +40,16 @@ pub fn build(
             build_pop_back(&libfunc.ty, builder, false)
         }
         ArrayConcreteLibfunc::Get(libfunc) => build_array_get(&libfunc.ty, builder),
+        ArrayConcreteLibfunc::GetUsize(libfunc) => build_array_get_unboxed(&libfunc.ty, builder),
         ArrayConcreteLibfunc::Slice(libfunc) => build_array_slice(&libfunc.ty, builder),
+        ArrayConcreteLibfunc::ExtendFromSpan(_) => build_array_extend_from_span(builder),
+        ArrayConcreteLibfunc::SpanEq(_) => build_span_eq(builder),
+        ArrayConcreteLibfunc::SpanBinarySearch(_) => build_span_binary_search(builder),
+        ArrayConcreteLibfunc::SpanSortVerified(_) => build_span_sort_verified(builder),
         ArrayConcreteLibfunc::Len(libfunc) => build_array_len(&libfunc.ty, builder),
+        ArrayConcreteLibfunc::LenExceeds(libfunc) => {
+            build_array_len_exceeds(&libfunc.ty, &libfunc.max_len, builder)
+        }
     }
 }
 
@@ -215,6 +236,84 @@ fn build_array_get(
     ))
 }
 
+/// Handles a Sierra statement for fetching an unboxed snapshot of an array element at a specific
+/// index. The bound check is fused into the fetch, so a single range check is used in both
+/// branches, and the element cells are returned as direct references into the array segment.
+fn build_array_get_unboxed(
+    elem_ty: &ConcreteTypeId,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_arr, expr_index] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let [arr_start, arr_end] = expr_arr.try_unpack()?;
+    let index = expr_index.try_unpack_single()?;
+
+    let element_size = builder.program_info.type_sizes[elem_ty];
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref_or_immediate index;
+        deref arr_start;
+        deref arr_end;
+        buffer(1) range_check;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        // Compute the length of the array (in cells).
+        tempvar array_length_in_cells = arr_end - arr_start;
+    };
+    let element_offset_in_cells = if element_size == 1 {
+        index
+    } else {
+        casm_build_extend! {casm_builder,
+            const element_size = element_size;
+            // Compute the offset of the element (in cells).
+            tempvar element_offset = index * element_size;
+        };
+        element_offset
+    };
+    casm_build_extend! {casm_builder,
+        // Check that offset is in range.
+        // Note that the offset may be as large as `(2^15 - 1) * (2^32 - 1)`.
+        tempvar is_in_range;
+        hint TestLessThan {lhs: element_offset_in_cells, rhs: array_length_in_cells} into {dst: is_in_range};
+        jump InRange if is_in_range != 0;
+        // Index out of bounds. Compute offset - length.
+        tempvar offset_length_diff = element_offset_in_cells - array_length_in_cells;
+        // Assert offset - length >= 0. Note that offset_length_diff is smaller than 2^128 as the index type is u32.
+        assert offset_length_diff = *(range_check++);
+        jump FailureHandle;
+
+        InRange:
+        // Assert offset < length, or that length - (offset + 1) is in [0, 2^128).
+        const one = 1;
+        tempvar element_offset_in_cells_plus_1 = element_offset_in_cells + one;
+        tempvar offset_length_diff = array_length_in_cells - element_offset_in_cells_plus_1;
+        assert offset_length_diff = *(range_check++);
+        // The start address of the target cells, stored so the element cells can be referenced
+        // directly.
+        tempvar target_cell = arr_start + element_offset_in_cells;
+    };
+    let element_cells: Vec<_> = (0..element_size)
+        .map(|i| {
+            casm_build_extend!(casm_builder, let cell = target_cell[i];);
+            cell
+        })
+        .collect();
+    let failure_handle = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &element_cells], None),
+            ("FailureHandle", &[&[range_check]], Some(failure_handle)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
 /// Handles a Sierra statement for returning a snapshot of a slice of an array.
 fn build_array_slice(
     elem_ty: &ConcreteTypeId,
@@ -304,6 +403,844 @@ fn build_array_slice(
     ))
 }
 
+/// Handles a Sierra statement for appending the content of a span to the end of an array.
+/// The cells are copied by a loop, whose cost is withdrawn from the gas builtin at runtime.
+fn build_array_extend_from_span(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_gas_builtin, expr_arr, expr_span] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let [arr_start, arr_end] = expr_arr.try_unpack()?;
+    let [span_start, span_end] = expr_span.try_unpack()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_cell_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref gas_builtin;
+        deref arr_start;
+        deref arr_end;
+        deref span_start;
+        deref span_end;
+    };
+    casm_build_extend! {casm_builder,
+        const per_cell_cost = ARRAY_EXTEND_FROM_SPAN_PER_CELL_COST.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar n_cells = span_end - span_start;
+        tempvar required_gas = n_cells * per_cell_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        jump NonEmpty if n_cells != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the copy function.
+        tempvar copy_range_check = range_check;
+        tempvar copy_arr_start = arr_start;
+        tempvar copy_src = span_start;
+        tempvar copy_dst = arr_end;
+        let (final_range_check, final_gas_builtin, final_arr_start, final_arr_end) =
+            call ArrayExtendFromSpanCopy;
+        jump Done;
+        Empty:
+        // Nothing to copy - return the values in the same layout as the copy function.
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        tempvar empty_arr_start = arr_start;
+        tempvar empty_arr_end = arr_end;
+        rescope {
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_arr_start = empty_arr_start,
+            final_arr_end = empty_arr_end
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Copies `n_cells` cells from `copy_src` to `copy_dst`, where `n_cells` is not 0.
+        ArrayExtendFromSpanCopy:
+        tempvar src = copy_src;
+        tempvar dst = copy_dst;
+        tempvar remaining = n_cells;
+        rescope {
+            src = src,
+            dst = dst,
+            remaining = remaining,
+            copy_range_check = copy_range_check,
+            updated_gas = updated_gas,
+            copy_arr_start = copy_arr_start
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        ArrayExtendFromSpanLoop:
+        const one = 1;
+        tempvar value = src[0];
+        assert value = dst[0];
+        tempvar next_src = src + one;
+        tempvar next_dst = dst + one;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            src = next_src,
+            dst = next_dst,
+            remaining = next_remaining,
+            copy_range_check = copy_range_check,
+            updated_gas = updated_gas,
+            copy_arr_start = copy_arr_start
+        };
+        jump ArrayExtendFromSpanLoop if remaining != 0;
+        #{ per_cell_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_range_check = copy_range_check;
+        tempvar returned_gas_builtin = updated_gas;
+        tempvar returned_arr_start = copy_arr_start;
+        tempvar returned_arr_end = dst;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(
+        ConstCost { steps: fixed_steps, holes: 0, range_checks: 1 },
+        ARRAY_EXTEND_FROM_SPAN_FIXED_COST
+    );
+    assert_eq!(
+        ConstCost { steps: per_cell_steps, holes: 0, range_checks: 0 },
+        ARRAY_EXTEND_FROM_SPAN_PER_CELL_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches: [(state, _), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Failure"]);
+    assert_eq!(failure_state.steps, 7, "Must match the failure branch cost.");
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[1],
+        ApChange::Known(failure_state.ap_change)
+    );
+    let relocations = failure_relocations
+        .into_iter()
+        .map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        })
+        .collect();
+
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression {
+                    cells: vec![
+                        state.get_adjusted(final_arr_start),
+                        state.get_adjusted(final_arr_end),
+                    ],
+                },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+                ReferenceExpression {
+                    cells: vec![
+                        failure_state.get_adjusted(arr_start),
+                        failure_state.get_adjusted(arr_end),
+                    ],
+                },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
+
+/// Handles a Sierra statement for comparing the content of two spans.
+/// The cells are compared by a loop exiting on the first difference. The cost of the loop is
+/// withdrawn from the gas builtin at runtime, and the cost of the uncompared cells is refunded.
+fn build_span_eq(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_gas_builtin, expr_lhs, expr_rhs] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let [lhs_start, lhs_end] = expr_lhs.try_unpack()?;
+    let [rhs_start, rhs_end] = expr_rhs.try_unpack()?;
+    let (not_equal_statement_id, failure_handle_statement_id) =
+        match builder.invocation.branches.as_slice() {
+            [
+                BranchInfo { target: BranchTarget::Fallthrough, .. },
+                BranchInfo { target: BranchTarget::Statement(not_equal_statement_id), .. },
+                BranchInfo { target: BranchTarget::Statement(failure_handle_statement_id), .. },
+            ] => (*not_equal_statement_id, *failure_handle_statement_id),
+            _ => panic!("malformed invocation"),
+        };
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_cell_steps: i32 = 0;
+    let mut equal_return_steps: i32 = 0;
+    let mut not_equal_return_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref gas_builtin;
+        deref lhs_start;
+        deref lhs_end;
+        deref rhs_start;
+        deref rhs_end;
+    };
+    casm_build_extend! {casm_builder,
+        const zero = 0;
+        const per_cell_cost = SPAN_EQ_PER_CELL_COST.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar lhs_len = lhs_end - lhs_start;
+        tempvar rhs_len = rhs_end - rhs_start;
+        tempvar len_diff = lhs_len - rhs_len;
+        jump LengthMismatch if len_diff != 0;
+        tempvar required_gas = lhs_len * per_cell_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        jump NonEmpty if lhs_len != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the compare function.
+        tempvar compare_range_check = range_check;
+        tempvar compare_lhs = lhs_start;
+        tempvar compare_rhs = rhs_start;
+        let (final_diff, final_range_check, final_gas_builtin) = call SpanEqCompare;
+        jump Done;
+        Empty:
+        // Both spans are empty - return in the same layout as the compare function.
+        tempvar empty_diff = zero;
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        rescope {
+            final_diff = empty_diff,
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin
+        };
+        jump Done;
+        LengthMismatch:
+        // The lengths differ - return in the same layout as the compare function.
+        tempvar mismatch_diff = len_diff;
+        tempvar mismatch_range_check = range_check;
+        tempvar mismatch_gas_builtin = gas_builtin;
+        rescope {
+            final_diff = mismatch_diff,
+            final_range_check = mismatch_range_check,
+            final_gas_builtin = mismatch_gas_builtin
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Compares `lhs_len` cells starting at `compare_lhs` and `compare_rhs`, where `lhs_len` is
+        // not 0. Returns the difference of the first differing cells, or 0 if there is none.
+        SpanEqCompare:
+        tempvar lhs = compare_lhs;
+        tempvar rhs = compare_rhs;
+        tempvar remaining = lhs_len;
+        rescope {
+            lhs = lhs,
+            rhs = rhs,
+            remaining = remaining,
+            compare_range_check = compare_range_check,
+            updated_gas = updated_gas
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        SpanEqLoop:
+        const one = 1;
+        tempvar lhs_value = lhs[0];
+        tempvar rhs_value = rhs[0];
+        tempvar diff = lhs_value - rhs_value;
+        jump SpanEqMismatch if diff != 0;
+        tempvar next_lhs = lhs + one;
+        tempvar next_rhs = rhs + one;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            lhs = next_lhs,
+            rhs = next_rhs,
+            remaining = next_remaining,
+            compare_range_check = compare_range_check,
+            updated_gas = updated_gas
+        };
+        jump SpanEqLoop if remaining != 0;
+        #{ per_cell_steps += steps; steps = 0; }
+        const equal_diff = 0;
+        tempvar returned_equal_diff = equal_diff;
+        tempvar returned_equal_range_check = compare_range_check;
+        tempvar returned_equal_gas_builtin = updated_gas;
+        ret;
+        #{ equal_return_steps += steps; steps = 0; }
+        SpanEqMismatch:
+        // Refund the cost of the current cell and the cells after it, as the cost of the current
+        // iteration is included in the fixed cost.
+        const refund_per_cell = SPAN_EQ_PER_CELL_COST.cost();
+        tempvar refund = remaining * refund_per_cell;
+        tempvar returned_not_equal_diff = diff;
+        tempvar returned_not_equal_range_check = compare_range_check;
+        tempvar returned_not_equal_gas_builtin = updated_gas + refund;
+        ret;
+        #{ not_equal_return_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+        jump NotEqual if final_diff != 0;
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    fixed_steps += std::cmp::max(equal_return_steps, not_equal_return_steps);
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(ConstCost { steps: fixed_steps, holes: 0, range_checks: 1 }, SPAN_EQ_FIXED_COST);
+    assert_eq!(
+        ConstCost { steps: per_cell_steps, holes: 0, range_checks: 0 },
+        SPAN_EQ_PER_CELL_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches:
+            [
+                (equal_state, _),
+                (not_equal_state, not_equal_relocations),
+                (failure_state, failure_relocations),
+            ],
+    } = casm_builder.build(["Fallthrough", "NotEqual", "Failure"]);
+    assert_eq!(failure_state.steps, 10, "Must match the failure branch cost.");
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[2],
+        ApChange::Known(failure_state.ap_change)
+    );
+    // Relocations are applied in a single pass, so they must be sorted by instruction index.
+    let relocations = chain!(
+        not_equal_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(not_equal_statement_id),
+        }),
+        failure_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        }),
+    )
+    .sorted_by_key(|entry| entry.instruction_idx)
+    .collect();
+
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![equal_state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![equal_state.get_adjusted(final_gas_builtin)] },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression {
+                    cells: vec![not_equal_state.get_adjusted(final_range_check)],
+                },
+                ReferenceExpression {
+                    cells: vec![not_equal_state.get_adjusted(final_gas_builtin)],
+                },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
+
+/// Handles a Sierra statement for searching for a value in a sorted span of `u128`s.
+/// Each iteration of the search loop verifies the midpoint of the searched range, and halves the
+/// range. The cost of each iteration is withdrawn from the gas builtin at runtime.
+fn build_span_binary_search(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_gas_builtin, expr_span, expr_value] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let [span_start, span_end] = expr_span.try_unpack()?;
+    let value = expr_value.try_unpack_single()?;
+    let (not_found_statement_id, failure_handle_statement_id) =
+        match builder.invocation.branches.as_slice() {
+            [
+                BranchInfo { target: BranchTarget::Fallthrough, .. },
+                BranchInfo { target: BranchTarget::Statement(not_found_statement_id), .. },
+                BranchInfo { target: BranchTarget::Statement(failure_handle_statement_id), .. },
+            ] => (*not_found_statement_id, *failure_handle_statement_id),
+            _ => panic!("malformed invocation"),
+        };
+    // Counters for the amount of steps in the generated search function.
+    let mut entry_steps: i32 = 0;
+    let mut iteration_steps: i32 = 0;
+    let mut found_steps: i32 = 0;
+    let mut not_found_steps: i32 = 0;
+    let mut failure_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(0) range_check;
+        deref gas_builtin;
+        deref span_start;
+        deref span_end;
+        deref value;
+    };
+    casm_build_extend! {casm_builder,
+        const zero = 0;
+        const one = 1;
+        tempvar span_len = span_end - span_start;
+        jump NonEmpty if span_len != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the search function.
+        tempvar search_range_check = range_check;
+        tempvar search_gas_builtin = gas_builtin;
+        tempvar search_start = span_start;
+        tempvar search_value = value;
+        tempvar search_value_plus_one = value + one;
+        let (out_of_gas, not_found, final_range_check, final_gas_builtin, final_index) =
+            call SpanBinarySearch;
+        jump Done;
+        Empty:
+        // The span is empty - return in the same layout as the search function.
+        tempvar empty_out_of_gas = zero;
+        tempvar empty_not_found = one;
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = gas_builtin;
+        tempvar empty_index = zero;
+        rescope {
+            out_of_gas = empty_out_of_gas,
+            not_found = empty_not_found,
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_index = empty_index
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Searches for `search_value` in the `span_len` cells starting at `search_start`, where
+        // `span_len` is not 0. Returns whether it ran out of gas, whether the value was not found,
+        // and the index of the value or the index it should be inserted at.
+        SpanBinarySearch:
+        tempvar loop_range_check = search_range_check;
+        tempvar loop_gas_builtin = search_gas_builtin;
+        tempvar low = zero;
+        tempvar high = span_len;
+        tempvar range_size = span_len;
+        rescope {
+            loop_range_check = loop_range_check,
+            loop_gas_builtin = loop_gas_builtin,
+            low = low,
+            high = high,
+            range_size = range_size,
+            search_start = search_start,
+            search_value = search_value,
+            search_value_plus_one = search_value_plus_one
+        };
+        #{ entry_steps += steps; steps = 0; }
+        SpanBinarySearchLoop:
+        const zero = 0;
+        const one = 1;
+        const two = 2;
+        const iteration_cost = SPAN_BINARY_SEARCH_ITERATION_COST.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        // Withdraw the cost of the iteration from the gas builtin.
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: iteration_cost, rhs: loop_gas_builtin} into {dst: has_enough_gas};
+        jump SpanBinarySearchHasEnoughGas if has_enough_gas != 0;
+        // Prove that `loop_gas_builtin < iteration_cost`.
+        tempvar gas_builtin_plus_bound = loop_gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - iteration_cost;
+        assert gas_diff = *(loop_range_check++);
+        tempvar failure_out_of_gas = one;
+        tempvar failure_not_found = zero;
+        tempvar failure_range_check = loop_range_check;
+        tempvar failure_gas_builtin = loop_gas_builtin;
+        tempvar failure_index = low;
+        ret;
+        #{ failure_steps += steps; steps = 0; }
+    };
+    casm_build_extend! {casm_builder,
+        SpanBinarySearchHasEnoughGas:
+        tempvar updated_gas = loop_gas_builtin - iteration_cost;
+        assert updated_gas = *(loop_range_check++);
+        // Verify that `mid` is `(low + high) / 2` rounded down: `parity` is a bit, and `mid - low`
+        // is small, so `mid` can't be a field element not representing the rounded midpoint.
+        tempvar low_plus_high = low + high;
+        tempvar mid;
+        tempvar parity;
+        hint DivMod {lhs: low_plus_high, rhs: two} into {quotient: mid, remainder: parity};
+        tempvar parity_squared = parity * parity;
+        assert parity_squared = parity;
+        tempvar twice_mid = mid * two;
+        assert low_plus_high = twice_mid + parity;
+        tempvar mid_offset = mid - low;
+        assert mid_offset = *(loop_range_check++);
+        tempvar mid_ptr = search_start + mid;
+        tempvar element = mid_ptr[0];
+        tempvar element_diff = search_value - element;
+        jump SpanBinarySearchNotAtMid if element_diff != 0;
+        tempvar found_out_of_gas = zero;
+        tempvar found_not_found = zero;
+        tempvar found_range_check = loop_range_check;
+        tempvar found_gas_builtin = updated_gas;
+        tempvar found_index = mid;
+        ret;
+        #{ found_steps += steps; steps = 0; }
+    };
+    casm_build_extend! {casm_builder,
+        SpanBinarySearchNotAtMid:
+        tempvar element_is_smaller;
+        hint TestLessThan {lhs: element, rhs: search_value} into {dst: element_is_smaller};
+        jump SpanBinarySearchUpperHalf if element_is_smaller != 0;
+        // Prove that `element > search_value`, and continue with the lower half.
+        tempvar element_gap = element - search_value_plus_one;
+        assert element_gap = *(loop_range_check++);
+        tempvar next_range_check = loop_range_check;
+        tempvar next_gas_builtin = updated_gas;
+        tempvar next_low = low;
+        tempvar next_high = mid;
+        tempvar next_range_size = next_high - next_low;
+        rescope {
+            loop_range_check = next_range_check,
+            loop_gas_builtin = next_gas_builtin,
+            low = next_low,
+            high = next_high,
+            range_size = next_range_size,
+            search_start = search_start,
+            search_value = search_value,
+            search_value_plus_one = search_value_plus_one
+        };
+        jump SpanBinarySearchContinue;
+    };
+    casm_build_extend! {casm_builder,
+        SpanBinarySearchUpperHalf:
+        // Prove that `element < search_value`, and continue with the upper half.
+        tempvar value_gap = element_diff - one;
+        assert value_gap = *(loop_range_check++);
+        tempvar next_range_check = loop_range_check;
+        tempvar next_gas_builtin = updated_gas;
+        tempvar next_low = mid + one;
+        tempvar next_high = high;
+        tempvar next_range_size = next_high - next_low;
+        rescope {
+            loop_range_check = next_range_check,
+            loop_gas_builtin = next_gas_builtin,
+            low = next_low,
+            high = next_high,
+            range_size = next_range_size,
+            search_start = search_start,
+            search_value = search_value,
+            search_value_plus_one = search_value_plus_one
+        };
+        SpanBinarySearchContinue:
+        jump SpanBinarySearchLoop if range_size != 0;
+        #{ iteration_steps += steps; steps = 0; }
+        const zero = 0;
+        const one = 1;
+        // The range is empty - the value is not in the span, and should be inserted at `low`.
+        tempvar not_found_out_of_gas = zero;
+        tempvar not_found_not_found = one;
+        tempvar not_found_range_check = loop_range_check;
+        tempvar not_found_gas_builtin = loop_gas_builtin;
+        tempvar not_found_index = low;
+        ret;
+        #{ not_found_steps += steps; steps = 0; }
+        Done:
+        jump Failure if out_of_gas != 0;
+        jump NotFound if not_found != 0;
+    };
+    // The cost of the iteration in which the value is found is withdrawn as well, so it must cover
+    // the steps until returning.
+    assert!(found_steps <= iteration_steps, "Found path must be covered by the iteration cost.");
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(
+        ConstCost { steps: iteration_steps, holes: 0, range_checks: 3 },
+        SPAN_BINARY_SEARCH_ITERATION_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches:
+            [
+                (found_state, _),
+                (not_found_state, not_found_relocations),
+                (failure_state, failure_relocations),
+            ],
+    } = casm_builder.build(["Fallthrough", "NotFound", "Failure"]);
+    let found_fixed_steps = found_state.steps as i32 + entry_steps;
+    let not_found_fixed_steps = not_found_state.steps as i32 + entry_steps + not_found_steps;
+    assert_eq!(
+        ConstCost {
+            steps: std::cmp::max(found_fixed_steps, not_found_fixed_steps),
+            holes: 0,
+            range_checks: 0
+        },
+        SPAN_BINARY_SEARCH_FIXED_COST
+    );
+    assert_eq!(
+        failure_state.steps as i32 + entry_steps + failure_steps,
+        25,
+        "Must match the failure branch cost."
+    );
+    // Relocations are applied in a single pass, so they must be sorted by instruction index.
+    let relocations = chain!(
+        not_found_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(not_found_statement_id),
+        }),
+        failure_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        }),
+    )
+    .sorted_by_key(|entry| entry.instruction_idx)
+    .collect();
+
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![found_state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![found_state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression { cells: vec![found_state.get_adjusted(final_index)] },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression {
+                    cells: vec![not_found_state.get_adjusted(final_range_check)],
+                },
+                ReferenceExpression {
+                    cells: vec![not_found_state.get_adjusted(final_gas_builtin)],
+                },
+                ReferenceExpression { cells: vec![not_found_state.get_adjusted(final_index)] },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(final_gas_builtin)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
+
+/// Handles a Sierra statement for creating a sorted copy of a span of `u128`s.
+/// The sorting permutation is supplied by a hint, and only verified by a loop, whose cost is
+/// withdrawn from the gas builtin at runtime.
+fn build_span_sort_verified(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_gas_builtin, expr_span] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let [span_start, span_end] = expr_span.try_unpack()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_element_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref gas_builtin;
+        deref span_start;
+        deref span_end;
+    };
+    casm_build_extend! {casm_builder,
+        const per_element_cost = SPAN_SORT_VERIFIED_PER_ELEMENT_COST.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar n_elements = span_end - span_start;
+        tempvar required_gas = n_elements * per_element_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        tempvar sorted_start;
+        hint AllocSegment {} into {dst: sorted_start};
+        ap += 1;
+        jump NonEmpty if n_elements != 0;
+        jump Empty;
+        NonEmpty:
+        const one = 1;
+        tempvar permutation_start;
+        hint SortPermutation {start: span_start, end: span_end} into {dst: permutation_start};
+        tempvar taken_start;
+        hint AllocSegment {} into {dst: taken_start};
+        ap += 2;
+        tempvar max_index = n_elements - one;
+        // Push the arguments of the verification function.
+        tempvar verify_range_check = range_check;
+        tempvar verify_span_start = span_start;
+        tempvar verify_permutation = permutation_start;
+        tempvar verify_dst = sorted_start;
+        let (final_range_check, final_gas_builtin, final_sorted_start, final_sorted_end) =
+            call SpanSortVerify;
+        jump Done;
+        Empty:
+        // Nothing to sort - return the values in the same layout as the verification function.
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        tempvar empty_sorted_start = sorted_start;
+        tempvar empty_sorted_end = sorted_start;
+        rescope {
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_sorted_start = empty_sorted_start,
+            final_sorted_end = empty_sorted_end
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Copies the `n_elements` elements starting at `verify_span_start` to `verify_dst`, in the
+        // order given by the indices at `verify_permutation`, where `n_elements` is not 0.
+        // The indices are verified to be distinct, by writing the address of their destination into
+        // `taken_start + index`, and the copied elements are verified to be sorted.
+        SpanSortVerify:
+        const zero = 0;
+        tempvar loop_range_check = verify_range_check;
+        tempvar permutation = verify_permutation;
+        tempvar dst = verify_dst;
+        tempvar prev_value = zero;
+        tempvar remaining = n_elements;
+        rescope {
+            loop_range_check = loop_range_check,
+            permutation = permutation,
+            dst = dst,
+            prev_value = prev_value,
+            remaining = remaining,
+            updated_gas = updated_gas,
+            sorted_start = sorted_start,
+            taken_start = taken_start,
+            max_index = max_index,
+            verify_span_start = verify_span_start
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        SpanSortVerifyLoop:
+        const one = 1;
+        // Verify that `0 <= index <= max_index`.
+        tempvar index = permutation[0];
+        assert index = *(loop_range_check++);
+        tempvar index_gap = max_index - index;
+        assert index_gap = *(loop_range_check++);
+        // Mark the index as taken - fails if it was already taken by a previous destination.
+        tempvar taken_ptr = taken_start + index;
+        assert dst = taken_ptr[0];
+        tempvar element_ptr = verify_span_start + index;
+        tempvar value = element_ptr[0];
+        assert value = dst[0];
+        // Verify that `prev_value <= value`.
+        tempvar value_gap = value - prev_value;
+        assert value_gap = *(loop_range_check++);
+        tempvar next_range_check = loop_range_check;
+        tempvar next_permutation = permutation + one;
+        tempvar next_dst = dst + one;
+        tempvar next_prev_value = value;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            loop_range_check = next_range_check,
+            permutation = next_permutation,
+            dst = next_dst,
+            prev_value = next_prev_value,
+            remaining = next_remaining,
+            updated_gas = updated_gas,
+            sorted_start = sorted_start,
+            taken_start = taken_start,
+            max_index = max_index,
+            verify_span_start = verify_span_start
+        };
+        jump SpanSortVerifyLoop if remaining != 0;
+        #{ per_element_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_range_check = loop_range_check;
+        tempvar returned_gas_builtin = updated_gas;
+        tempvar returned_sorted_start = sorted_start;
+        tempvar returned_sorted_end = dst;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(
+        ConstCost { steps: fixed_steps, holes: 0, range_checks: 1 },
+        SPAN_SORT_VERIFIED_FIXED_COST
+    );
+    assert_eq!(
+        ConstCost { steps: per_element_steps, holes: 0, range_checks: 3 },
+        SPAN_SORT_VERIFIED_PER_ELEMENT_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches: [(state, _), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Failure"]);
+    assert_eq!(failure_state.steps, 7, "Must match the failure branch cost.");
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[1],
+        ApChange::Known(failure_state.ap_change)
+    );
+    let relocations = failure_relocations
+        .into_iter()
+        .map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        })
+        .collect();
+
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression {
+                    cells: vec![
+                        state.get_adjusted(final_sorted_start),
+                        state.get_adjusted(final_sorted_end),
+                    ],
+                },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
+
 /// Handles a Sierra statement for getting the length of an array.
 fn build_array_len(
     elem_ty: &ConcreteTypeId,
@@ -335,3 +1272,54 @@ fn build_array_len(
         Default::default(),
     ))
 }
+
+/// Handles a Sierra statement for checking whether the length of an array exceeds a maximal
+/// length.
+fn build_array_len_exceeds(
+    elem_ty: &ConcreteTypeId,
+    max_len: &BigInt,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_arr] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let [arr_start, arr_end] = expr_arr.try_unpack()?;
+    // Comparing the number of cells saves dividing by the element size.
+    let max_cells = max_len * builder.program_info.type_sizes[elem_ty];
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref arr_start;
+        deref arr_end;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const max_cells_plus_one = max_cells.clone() + 1;
+        const max_cells_fixer = (BigInt::from(1) << 128) - max_cells.clone() - 1;
+        const max_cells = max_cells;
+        tempvar n_cells = arr_end - arr_start;
+        tempvar within_max_len;
+        hint TestLessThanOrEqual {lhs: n_cells, rhs: max_cells} into {dst: within_max_len};
+        jump WithinMaxLen if within_max_len != 0;
+        // Prove that `n_cells > max_cells`.
+        tempvar excess_cells = n_cells - max_cells_plus_one;
+        assert excess_cells = *(range_check++);
+        jump Target;
+        WithinMaxLen:
+        // Prove that `n_cells <= max_cells`.
+        tempvar n_cells_fixed = n_cells + max_cells_fixer;
+        assert n_cells_fixed = *(range_check++);
+    };
+    let target_statement_id = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check]], None),
+            ("Target", &[&[range_check]], Some(target_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
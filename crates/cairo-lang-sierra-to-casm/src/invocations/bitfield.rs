@@ -0,0 +1,152 @@
+use cairo_lang_casm::builder::{CasmBuilder, Var};
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::bitfield::BitfieldConcreteLibfunc;
+use itertools::zip_eq;
+use num_bigint::BigInt;
+
+use super::felt252_dict::validate_felt252_le;
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
+};
+
+/// Builds instructions for Sierra bitfield packing operations.
+pub fn build(
+    libfunc: &BitfieldConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        BitfieldConcreteLibfunc::Pack(libfunc) => build_bitfield_pack(&libfunc.widths, builder),
+        BitfieldConcreteLibfunc::Unpack(libfunc) => build_bitfield_unpack(&libfunc.widths, builder),
+    }
+}
+
+/// Handles a Sierra statement for packing the fields of a tuple into a felt252.
+/// The packed value is accumulated from the highest field, by shifting the accumulated value by
+/// the width of the next field and adding it.
+fn build_bitfield_pack(
+    widths: &[usize],
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_fields] = builder.try_get_refs()?;
+    if expr_fields.cells.len() != widths.len() {
+        return Err(InvocationError::InvalidReferenceExpressionForArgument);
+    }
+    let mut casm_builder = CasmBuilder::default();
+    let mut fields = vec![];
+    for cell in &expr_fields.cells {
+        add_input_variables!(casm_builder, deref cell;);
+        fields.push(cell);
+    }
+    let mut packed = *fields.last().unwrap();
+    for (field, width) in fields.iter().zip(widths).rev().skip(1) {
+        let field = *field;
+        casm_build_extend! {casm_builder,
+            const shift = BigInt::from(1) << *width;
+            tempvar shifted = packed * shift;
+            tempvar next_packed = shifted + field;
+        };
+        packed = next_packed;
+    }
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[packed]], None)],
+        Default::default(),
+    ))
+}
+
+/// Handles a Sierra statement for unpacking a felt252 into the fields of a tuple.
+/// The fields are guessed by hints, range checked to their widths, and then packed back to be
+/// compared to the original value.
+fn build_bitfield_unpack(
+    widths: &[usize],
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let total_width: usize = widths.iter().sum();
+    let success_range_checks: usize =
+        widths.iter().map(|width| if *width == 128 { 1 } else { 2 }).sum();
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(success_range_checks.max(4) as i16) range_check;
+        deref value;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const bound = BigInt::from(1) << total_width;
+        tempvar fits;
+        hint TestLessThan {lhs: value, rhs: bound} into {dst: fits};
+        jump Fits if fits != 0;
+        // Prove that `value >= bound`.
+        tempvar bound_value = bound;
+    };
+    validate_felt252_le(&mut casm_builder, range_check, bound_value, value);
+    casm_build_extend! {casm_builder,
+        jump Failure;
+        Fits:
+    };
+    // Split the value into the fields, starting from the lowest bits.
+    let mut fields = vec![];
+    let mut rest = value;
+    for width in &widths[..widths.len() - 1] {
+        casm_build_extend! {casm_builder,
+            const field_bound = BigInt::from(1) << *width;
+            tempvar field;
+            tempvar next_rest;
+            hint DivMod {lhs: rest, rhs: field_bound} into {quotient: next_rest, remainder: field};
+        };
+        fields.push(field);
+        rest = next_rest;
+    }
+    fields.push(rest);
+    // Verify that each field fits in its width.
+    for (field, width) in zip_eq(&fields, widths) {
+        let field = *field;
+        if *width == 128 {
+            casm_build_extend!(casm_builder, assert field = *(range_check++););
+        } else {
+            let field_shift: BigInt = (BigInt::from(1) << 128) - (BigInt::from(1) << *width);
+            casm_build_extend! {casm_builder,
+                const field_shift = field_shift;
+                tempvar shifted_field = field + field_shift;
+                assert shifted_field = *(range_check++);
+                assert field = *(range_check++);
+            };
+        }
+    }
+    // Verify that the fields pack back into the value. As the total width is less than the prime,
+    // the fields are unique.
+    if let [lowest, middle @ .., highest] = &fields[..] {
+        let lowest = *lowest;
+        let mut packed = *highest;
+        for (field, width) in middle.iter().zip(&widths[1..widths.len() - 1]).rev() {
+            let field = *field;
+            casm_build_extend! {casm_builder,
+                const shift = BigInt::from(1) << *width;
+                tempvar shifted = packed * shift;
+                tempvar next_packed = shifted + field;
+            };
+            packed = next_packed;
+        }
+        casm_build_extend! {casm_builder,
+            const lowest_shift = BigInt::from(1) << widths[0];
+            tempvar shifted = packed * lowest_shift;
+            assert value = shifted + lowest;
+        };
+    }
+    let output_vars: Vec<[Var; 1]> =
+        [range_check].into_iter().chain(fields).map(|var| [var]).collect();
+    let success_vars: Vec<&[Var]> = output_vars.iter().map(|vars| &vars[..]).collect();
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &success_vars, None),
+            ("Failure", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
@@ -0,0 +1,230 @@
+use cairo_lang_casm::builder::{CasmBuilder, Var};
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::blake::{
+    BlakeConcreteLibfunc, BLAKE2S_BLOCK_WORDS, BLAKE2S_STATE_WORDS,
+};
+use itertools::Itertools;
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::add_input_variables;
+
+#[cfg(test)]
+#[path = "blake_test.rs"]
+mod test;
+
+/// The initialization vector of Blake2s.
+const IV: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+/// The permutations of the message words used by the rounds of the compression.
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+/// The indices of the working vector mixed by each of the applications of `G` in a round - the
+/// columns first, then the diagonals.
+const MIXED_INDICES: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+/// The number of cells of an instance of the Bitwise builtin.
+const BITWISE_INSTANCE_SIZE: usize = 5;
+/// The number of Bitwise builtin instances used by a compression: one for mixing the byte count
+/// into the working vector, 12 for each of the 80 applications of `G`, and two for each word of the
+/// output state.
+const N_BITWISE_INSTANCES: usize =
+    1 + SIGMA.len() * MIXED_INDICES.len() * 12 + 2 * BLAKE2S_STATE_WORDS;
+
+/// Builds instructions for Sierra Blake operations.
+pub fn build(
+    libfunc: &BlakeConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        BlakeConcreteLibfunc::Compress(_) => build_blake2s_compression(builder, false),
+        BlakeConcreteLibfunc::Finalize(_) => build_blake2s_compression(builder, true),
+    }
+}
+
+/// Handles instruction for applying the Blake2s compression function to a message block, where
+/// `finalize` marks the last block of the message.
+///
+/// All the 32-bit operations are verified by the Bitwise builtin: additions are reduced modulo
+/// `2**32` by masking, and rotations are computed by masking the word duplicated to 64 bits and
+/// shifted.
+fn build_blake2s_compression(
+    builder: CompiledInvocationBuilder<'_>,
+    finalize: bool,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [bitwise, inputs @ ..] =
+        builder.try_get_single_cells::<{ 1 + BLAKE2S_STATE_WORDS + 1 + BLAKE2S_BLOCK_WORDS }>()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer((BITWISE_INSTANCE_SIZE * N_BITWISE_INSTANCES) as i16) bitwise;
+    };
+    let mut input_vars = vec![];
+    for input in inputs {
+        add_input_variables!(casm_builder, deref input;);
+        input_vars.push(input);
+    }
+    let (state, rest) = input_vars.split_at(BLAKE2S_STATE_WORDS);
+    let (byte_count, message) = (rest[0], &rest[1..]);
+
+    let mut ops = Blake2sOps::new(casm_builder, bitwise);
+    // Initialize the working vector with the state and the initialization vector, mixing in the
+    // byte count and the finalization flag.
+    let mut v = state.to_vec();
+    for (i, iv) in IV.into_iter().enumerate() {
+        let flag = if i == 6 && finalize { u32::MAX } else { 0 };
+        let word = ops.constant(iv ^ flag);
+        v.push(if i == 4 { ops.xor(byte_count, word) } else { word });
+    }
+    let mut v: [Var; 16] = v.try_into().unwrap();
+    // Apply the rounds.
+    for sigma in SIGMA {
+        for (g_index, indices) in MIXED_INDICES.into_iter().enumerate() {
+            let x = message[sigma[2 * g_index]];
+            let y = message[sigma[2 * g_index + 1]];
+            ops.mix(&mut v, indices, x, y);
+        }
+    }
+    // Compute the output state.
+    let output_state = (0..BLAKE2S_STATE_WORDS)
+        .map(|i| {
+            let partial = ops.xor(state[i], v[i]);
+            [ops.xor(partial, v[i + BLAKE2S_STATE_WORDS])]
+        })
+        .collect_vec();
+
+    let Blake2sOps { casm_builder, bitwise, .. } = ops;
+    let output_vars = [[bitwise]].into_iter().chain(output_state).collect_vec();
+    let success_vars = output_vars.iter().map(|vars| &vars[..]).collect_vec();
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &success_vars, None)],
+        Default::default(),
+    ))
+}
+
+/// Helper for adding the 32-bit operations of Blake2s to a `CasmBuilder`.
+struct Blake2sOps {
+    casm_builder: CasmBuilder,
+    bitwise: Var,
+    /// A cell holding `2**32 - 1`.
+    u32_mask: Var,
+    /// A cell holding `(2**32 - 1) * 2**32`.
+    high_mask: Var,
+}
+impl Blake2sOps {
+    fn new(mut casm_builder: CasmBuilder, bitwise: Var) -> Self {
+        casm_build_extend! {casm_builder,
+            const u32_mask_imm = u32::MAX;
+            const high_mask_imm = BigInt::from(u32::MAX) << 32;
+            tempvar u32_mask = u32_mask_imm;
+            tempvar high_mask = high_mask_imm;
+        };
+        Self { casm_builder, bitwise, u32_mask, high_mask }
+    }
+
+    /// Returns a cell holding `value`.
+    fn constant(&mut self, value: u32) -> Var {
+        let casm_builder = &mut self.casm_builder;
+        casm_build_extend! {casm_builder,
+            const imm = value;
+            tempvar constant = imm;
+        };
+        constant
+    }
+
+    /// Returns `x & y`.
+    fn and(&mut self, x: Var, y: Var) -> Var {
+        let casm_builder = &mut self.casm_builder;
+        let bitwise = self.bitwise;
+        casm_build_extend! {casm_builder,
+            assert x = *(bitwise++);
+            assert y = *(bitwise++);
+            tempvar and = *(bitwise++);
+            let _xor = *(bitwise++);
+            let _or = *(bitwise++);
+        };
+        and
+    }
+
+    /// Returns `x ^ y`.
+    fn xor(&mut self, x: Var, y: Var) -> Var {
+        let casm_builder = &mut self.casm_builder;
+        let bitwise = self.bitwise;
+        casm_build_extend! {casm_builder,
+            assert x = *(bitwise++);
+            assert y = *(bitwise++);
+            let _and = *(bitwise++);
+            tempvar xor = *(bitwise++);
+            let _or = *(bitwise++);
+        };
+        xor
+    }
+
+    /// Returns the sum of the given u32 values modulo `2**32`.
+    fn add(&mut self, terms: &[Var]) -> Var {
+        let casm_builder = &mut self.casm_builder;
+        let mut sum = terms[0];
+        for term in &terms[1..] {
+            let term = *term;
+            casm_build_extend!(casm_builder, tempvar next_sum = sum + term;);
+            sum = next_sum;
+        }
+        self.and(sum, self.u32_mask)
+    }
+
+    /// Returns the u32 value `x` rotated right by `n` bits.
+    ///
+    /// `x * (2**32 + 1)` holds `x` twice, so shifting it left by `32 - n` bits places the rotated
+    /// value in bits 32 to 63.
+    fn rotr(&mut self, x: Var, n: usize) -> Var {
+        let casm_builder = &mut self.casm_builder;
+        casm_build_extend! {casm_builder,
+            const duplicate_and_shift = ((BigInt::from(1) << 32) + 1) << (32 - n);
+            tempvar shifted = x * duplicate_and_shift;
+        };
+        let high = self.and(shifted, self.high_mask);
+        let casm_builder = &mut self.casm_builder;
+        casm_build_extend! {casm_builder,
+            const two_pow_32 = BigInt::from(1) << 32;
+            tempvar rotated = high / two_pow_32;
+        };
+        rotated
+    }
+
+    /// Applies the mixing function `G` to the words of the working vector at `indices`, with the
+    /// message words `x` and `y`.
+    fn mix(&mut self, v: &mut [Var; 16], [a, b, c, d]: [usize; 4], x: Var, y: Var) {
+        v[a] = self.add(&[v[a], v[b], x]);
+        let d_xor_a = self.xor(v[d], v[a]);
+        v[d] = self.rotr(d_xor_a, 16);
+        v[c] = self.add(&[v[c], v[d]]);
+        let b_xor_c = self.xor(v[b], v[c]);
+        v[b] = self.rotr(b_xor_c, 12);
+        v[a] = self.add(&[v[a], v[b], y]);
+        let d_xor_a = self.xor(v[d], v[a]);
+        v[d] = self.rotr(d_xor_a, 8);
+        v[c] = self.add(&[v[c], v[d]]);
+        let b_xor_c = self.xor(v[b], v[c]);
+        v[b] = self.rotr(b_xor_c, 7);
+    }
+}
@@ -0,0 +1,36 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_casm::operand::{CellRef, Register};
+use itertools::chain;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use crate::invocations::test_utils::{compile_libfunc, ReducedBranchChanges};
+use crate::ref_expr;
+use crate::references::ReferenceExpression;
+
+/// Returns a reference expression to the given cell.
+fn cell_ref(register: Register, offset: i16) -> ReferenceExpression {
+    ReferenceExpression::from_cell(CellExpression::Deref(CellRef { register, offset }))
+}
+
+#[test_case("blake2s_compress"; "compress")]
+#[test_case("blake2s_finalize"; "finalize")]
+fn test_blake2s_compression(libfunc: &str) {
+    let compiled = compile_libfunc(
+        libfunc,
+        chain!([ref_expr!([fp + 1])], (2..27).map(|i| cell_ref(Register::FP, i))).collect(),
+    );
+    assert_eq!(compiled.instructions.len(), 4061);
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: chain!(
+                [ref_expr!([fp + 1] + 4885)],
+                [-15, -13, -11, -9, -7, -5, -3, -1].map(|offset| cell_ref(Register::AP, offset))
+            )
+            .collect(),
+            ap_change: ApChange::Known(2107),
+        }]
+    );
+}
@@ -0,0 +1,107 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::cell_expression::CellOperator;
+use cairo_lang_sierra::extensions::bounded_int::{
+    BoundedIntBinaryOperator, BoundedIntConcreteLibfunc, BOUNDED_INT_DIV_BOUND_LOG2,
+};
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{add_input_variables, CostValidationInfo};
+
+/// Builds instructions for Sierra bounded integer operations.
+pub fn build(
+    libfunc: &BoundedIntConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        BoundedIntConcreteLibfunc::BinaryOperation(libfunc) => {
+            build_bounded_int_op(builder, libfunc.operator)
+        }
+        BoundedIntConcreteLibfunc::DivRem(libfunc) => {
+            build_bounded_int_div_rem(builder, &libfunc.quotient_range.max)
+        }
+    }
+}
+
+/// Handles a bounded integer binary operation. The bounds of the result are known at compile
+/// time, so the result is the field operation, deferred to its use.
+fn build_bounded_int_op(
+    builder: CompiledInvocationBuilder<'_>,
+    op: BoundedIntBinaryOperator,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [a, b] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref a;
+        deref b;
+    };
+    let op = match op {
+        BoundedIntBinaryOperator::Add => CellOperator::Add,
+        BoundedIntBinaryOperator::Sub => CellOperator::Sub,
+        BoundedIntBinaryOperator::Mul => CellOperator::Mul,
+    };
+    let res = casm_builder.bin_op(op, a, b);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[res]], None)],
+        Default::default(),
+    ))
+}
+
+/// Handles the division with remainder of a non-negative bounded integer by a positive one, where
+/// the quotient is at most `quotient_max`.
+fn build_bounded_int_div_rem(
+    builder: CompiledInvocationBuilder<'_>,
+    quotient_max: &BigInt,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, a, b] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(3) range_check;
+        deref a;
+        deref b;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        tempvar r_plus_1;
+        tempvar b_minus_r_minus_1;
+        tempvar bq;
+        tempvar fixed_q;
+        tempvar q;
+        tempvar r;
+        hint DivMod { lhs: a, rhs: b } into { quotient: q, remainder: r };
+
+        // Verify `0 <= r`.
+        assert r = *(range_check++);
+
+        // Verify `r < b` by constraining `0 <= b - (r + 1)`.
+        const one = 1;
+        assert r_plus_1 = r + one;
+        assert b_minus_r_minus_1 = b - r_plus_1;
+        assert b_minus_r_minus_1 = *(range_check++);
+
+        // Verify `0 <= q <= quotient_max` by constraining `0 <= q < 2**128` and
+        // `0 <= q + (2**128 - 1 - quotient_max) < 2**128`.
+        const q_upper_fixer =
+            (BigInt::from(1) << BOUNDED_INT_DIV_BOUND_LOG2) - BigInt::from(1) - quotient_max;
+        assert q = *(range_check++);
+        assert fixed_q = q + q_upper_fixer;
+        assert fixed_q = *(range_check++);
+
+        // Check that `a = q * b + r`. Both hands are in the range [0, (quotient_max + 1) * b),
+        // which is within [0, PRIME) by the bounds validated at specialization.
+        // Therefore, the equality is an equality as integers (rather than only as field
+        // elements).
+        assert bq = b * q;
+        assert a = bq + r;
+    }
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[range_check], &[q], &[r]], None)],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
@@ -3,10 +3,10 @@ use cairo_lang_casm::casm_build_extend;
 use cairo_lang_casm::cell_expression::CellExpression;
 use cairo_lang_sierra::extensions::boxing::BoxConcreteLibfunc;
 use cairo_lang_sierra::ids::ConcreteTypeId;
-use num_bigint::ToBigInt;
 
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
 use crate::invocations::add_input_variables;
+use crate::invocations::misc::add_alloc_constant_size_hint;
 use crate::references::ReferenceExpression;
 
 /// Builds instructions for Sierra box operations.
@@ -34,10 +34,12 @@ fn build_into_box(
         );
         addr
     } else {
-        casm_build_extend!(casm_builder,
-            const operand_size = operand.cells.len().to_bigint().unwrap();
-            tempvar addr;
-            hint AllocConstantSize { size: operand_size } into { dst: addr };
+        casm_build_extend!(casm_builder, tempvar addr;);
+        add_alloc_constant_size_hint(
+            &mut casm_builder,
+            builder.program_info.vm_version,
+            operand.cells.len().into(),
+            addr,
         );
         for (index, cell) in operand.cells.iter().enumerate() {
             add_input_variables!(casm_builder, deref cell;);
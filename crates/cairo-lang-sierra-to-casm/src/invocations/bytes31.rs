@@ -0,0 +1,150 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
+use num_bigint::BigInt;
+
+use super::misc::{
+    build_identity, build_single_cell_const, build_try_from_felt252_under_bound, BoundValidation,
+};
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
+};
+
+/// Builds instructions for Sierra bytes31 operations.
+pub fn build(
+    libfunc: &Bytes31ConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Bytes31ConcreteLibfunc::Const(libfunc) => {
+            build_single_cell_const(builder, libfunc.c.clone())
+        }
+        Bytes31ConcreteLibfunc::ToFelt252(_) => build_identity(builder),
+        Bytes31ConcreteLibfunc::TryFromFelt252(_) => build_try_from_felt252_under_bound(
+            builder,
+            BigInt::from(1) << 248,
+            BoundValidation::LinearSplit,
+        ),
+        Bytes31ConcreteLibfunc::AppendU8(_) => build_bytes31_append_u8(builder),
+        Bytes31ConcreteLibfunc::SplitU8(_) => build_bytes31_split_u8(builder),
+    }
+}
+
+/// Handles appending a byte to a bytes31, failing if the result does not fit in 31 bytes.
+fn build_bytes31_append_u8(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value, byte] = builder.try_get_single_cells()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(3) range_check;
+        deref value;
+        deref byte;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const byte_limit = 256;
+        const u128_limit = BigInt::from(1) << 128;
+        const high_limit = BigInt::from(1) << 112;
+        const high_fixer = (BigInt::from(1) << 128) - (BigInt::from(1) << 112);
+        const high_excess_fixer =
+            (BigInt::from(1) << 128) - (BigInt::from(1) << 120) + (BigInt::from(1) << 112);
+        tempvar high_shifted;
+        tempvar high;
+        tempvar low;
+        hint DivMod { lhs: value, rhs: u128_limit } into { quotient: high, remainder: low };
+        // Verify `value = high * 2**128 + low`, where `0 <= high, low < 2**128`.
+        assert low = *(range_check++);
+        assert high = *(range_check++);
+        assert high_shifted = high * u128_limit;
+        assert value = high_shifted + low;
+        // `value < 2**240` if and only if `high < 2**112`.
+        tempvar fits;
+        hint TestLessThan { lhs: high, rhs: high_limit } into { dst: fits };
+        jump Fits if fits != 0;
+        // Verify `2**112 <= high < 2**120`, so `value >= 2**240`. The upper bound holds for any
+        // bytes31, and makes the split unique, as `high * 2**128 + low < 2**248 < PRIME`.
+        tempvar high_excess = high - high_limit;
+        assert high_excess = *(range_check++);
+        tempvar high_excess_fixed = high_excess + high_excess_fixer;
+        assert high_excess_fixed = *(range_check++);
+        jump Full;
+        Fits:
+        // Verify `high < 2**112`, so `value < 2**240` and the result fits in 31 bytes.
+        tempvar high_fixed = high + high_fixer;
+        assert high_fixed = *(range_check++);
+        tempvar value_shifted = value * byte_limit;
+        tempvar result = value_shifted + byte;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[result]], None),
+            ("Full", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Handles splitting the lowest byte off a bytes31.
+fn build_bytes31_split_u8(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(4) range_check;
+        deref value;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const byte_limit = 256;
+        const u128_limit = BigInt::from(1) << 128;
+        const byte_fixer = (BigInt::from(1) << 128) - 256;
+        const rest_high_fixer = (BigInt::from(1) << 128) - (BigInt::from(1) << 112);
+        tempvar byte_fixed;
+        tempvar rest_high_fixed;
+        tempvar rest_high_shifted;
+        tempvar rest_shifted;
+        tempvar rest_high;
+        tempvar rest_low;
+        tempvar rest;
+        tempvar byte;
+        hint DivMod { lhs: value, rhs: byte_limit } into { quotient: rest, remainder: byte };
+        hint DivMod {
+            lhs: rest, rhs: u128_limit
+        } into { quotient: rest_high, remainder: rest_low };
+
+        // Verify `0 <= byte < 256`.
+        assert byte = *(range_check++);
+        assert byte_fixed = byte + byte_fixer;
+        assert byte_fixed = *(range_check++);
+
+        // Verify `0 <= rest < 2**240`, by constraining `rest = rest_high * 2**128 + rest_low`,
+        // where `0 <= rest_high < 2**112` and `0 <= rest_low < 2**128`.
+        assert rest_low = *(range_check++);
+        assert rest_high = *(range_check++);
+        assert rest_high_fixed = rest_high + rest_high_fixer;
+        assert rest_high_fixed = *(range_check++);
+        assert rest_high_shifted = rest_high * u128_limit;
+        assert rest = rest_high_shifted + rest_low;
+
+        // Check that `value = rest * 256 + byte`. The right hand is smaller than 2**248 < PRIME,
+        // so the equality is an equality as integers.
+        assert rest_shifted = rest * byte_limit;
+        assert value = rest_shifted + byte;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[range_check], &[rest], &[byte]], None)],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
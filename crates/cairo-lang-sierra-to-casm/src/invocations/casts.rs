@@ -1,8 +1,10 @@
+use std::ops::Shl;
+
 use cairo_lang_casm::builder::CasmBuilder;
 use cairo_lang_casm::casm_build_extend;
 use cairo_lang_sierra::extensions::casts::{CastConcreteLibfunc, DowncastConcreteLibfunc};
-use num_bigint::BigUint;
-use num_traits::Pow;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
 
 use super::misc::build_identity;
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
@@ -28,45 +30,108 @@ pub fn build_downcast(
 ) -> Result<CompiledInvocation, InvocationError> {
     let [range_check, value] = builder.try_get_single_cells()?;
     let mut casm_builder = CasmBuilder::default();
+    let checks_lower_bound = libfunc.checks_lower_bound();
+    let checks_upper_bound = libfunc.checks_upper_bound();
     add_input_variables!(casm_builder,
-        buffer(0) range_check;
+        buffer(if checks_lower_bound && checks_upper_bound { 1 } else { 0 }) range_check;
         deref value;
     );
 
-    // The casm code below assumes both types are at most 128 bits.
+    // The casm code below assumes the values of both types are in the range [-2**127, 2**128),
+    // and that both types have at most 2**128 values.
+    let rc_bound = BigInt::one().shl(128);
+    let from_range = &libfunc.from_range;
+    let to_range = &libfunc.to_range;
+    let min_value = -BigInt::one().shl(127);
     assert!(
-        libfunc.from_nbits <= 128 && libfunc.to_nbits <= 128,
+        [from_range, to_range].into_iter().all(|range| {
+            range.lower >= min_value && range.upper <= rc_bound && range.size() <= rc_bound
+        }),
         "Downcasting from types of size > 128 bit is not supported."
     );
 
-    let two = BigUint::from(2u64);
-    let bound = two.clone().pow(libfunc.to_nbits);
-    let rc_bound = two.pow(128_usize);
-
+    // The value, offset so that it is in range (is_valid=1) if and only if it is smaller than the
+    // number of values of the destination type.
+    let offset_value = if to_range.lower.is_zero() {
+        value
+    } else {
+        casm_build_extend! {casm_builder,
+            const minus_lower = -to_range.lower.clone();
+            let offset_value = value + minus_lower;
+        };
+        offset_value
+    };
     casm_build_extend! {casm_builder,
         let orig_range_check = range_check;
 
         // Use a hint to guess whether the result is in range (is_valid=1) or overflows
         // (is_valid=0).
         tempvar is_valid;
-        const limit = bound.clone();
-        hint TestLessThan {lhs: value, rhs: limit} into {dst: is_valid};
+        const size = to_range.size();
+        hint TestLessThan {lhs: offset_value, rhs: size} into {dst: is_valid};
         jump Success if is_valid != 0;
         // Failure.
-        // value >= bound  <=>  value - bound >= 0.
-        // Note that we know that 0 <= value < 2^128.
-        tempvar shifted_value = value - limit;
-        assert shifted_value = *(range_check++);
-        jump Failure;
-
+    };
+    // `value + below_shift` is in the range [0, 2**128) if and only if `value` is smaller than the
+    // minimal value of the destination type (as `value >= -2**127`).
+    let below_shift = &rc_bound - &to_range.lower;
+    if checks_lower_bound && checks_upper_bound {
+        casm_build_extend! {casm_builder,
+            const below_shift = below_shift.clone();
+            const rc_bound_imm = rc_bound.clone();
+            let shifted_value = value + below_shift;
+            tempvar is_below;
+            hint TestLessThan {lhs: shifted_value, rhs: rc_bound_imm} into {dst: is_below};
+            jump Below if is_below != 0;
+        };
+    }
+    if checks_upper_bound {
+        casm_build_extend! {casm_builder,
+            // value >= upper  <=>  value - upper >= 0.
+            // Note that we know that value < 2^128.
+            const upper = to_range.upper.clone();
+            tempvar shifted_value = value - upper;
+            assert shifted_value = *(range_check++);
+            jump Failure;
+        };
+    }
+    if checks_lower_bound {
+        if checks_upper_bound {
+            casm_build_extend! {casm_builder,
+                Below:
+            };
+        }
+        casm_build_extend! {casm_builder,
+            // value < lower  <=>  value + (2^128 - lower) < 2^128.
+            const below_shift = below_shift;
+            tempvar shifted_value = value + below_shift;
+            assert shifted_value = *(range_check++);
+            jump Failure;
+        };
+    }
+    casm_build_extend! {casm_builder,
         // Success.
         Success:
-        // Verify that the value is in range:
-        // value < limit  <=>  value + (rc_bound - bound) < rc_bound.
-        const pos_shift = rc_bound - bound;
-        tempvar shifted_value = value + pos_shift;
-        assert shifted_value = *(range_check++);
     };
+    if checks_lower_bound {
+        casm_build_extend! {casm_builder,
+            // Verify that the value is at least the lower bound:
+            // value >= lower  <=>  value - lower >= 0.
+            // Note that we know that value < lower + 2^128.
+            const minus_lower = -to_range.lower.clone();
+            tempvar shifted_value = value + minus_lower;
+            assert shifted_value = *(range_check++);
+        };
+    }
+    if checks_upper_bound {
+        casm_build_extend! {casm_builder,
+            // Verify that the value is in range:
+            // value < upper  <=>  value + (rc_bound - upper) < rc_bound.
+            const pos_shift = rc_bound - &to_range.upper;
+            tempvar shifted_value = value + pos_shift;
+            assert shifted_value = *(range_check++);
+        };
+    }
 
     let target_statement_id = get_non_fallthrough_statement_id(&builder);
     Ok(builder.build_from_casm_builder(
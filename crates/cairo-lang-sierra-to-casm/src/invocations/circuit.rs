@@ -0,0 +1,268 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::circuit::{CircuitConcreteLibfunc, CircuitInfo};
+use cairo_lang_sierra::extensions::modular::{MOD_BUILTIN_INSTANCE_SIZE, MOD_BUILTIN_N_LIMBS};
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
+};
+
+#[cfg(test)]
+#[path = "circuit_test.rs"]
+mod test;
+
+/// Builds instructions for Sierra circuit operations.
+pub fn build(
+    libfunc: &CircuitConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        CircuitConcreteLibfunc::InitCircuitData(libfunc) => {
+            build_init_circuit_data(&libfunc.circuit_info, builder)
+        }
+        CircuitConcreteLibfunc::AddInput(libfunc) => {
+            build_add_circuit_input(&libfunc.circuit_info, builder)
+        }
+        CircuitConcreteLibfunc::GetDescriptor(libfunc) => {
+            build_get_circuit_descriptor(&libfunc.circuit_info, builder)
+        }
+        CircuitConcreteLibfunc::Eval(libfunc) => build_eval_circuit(&libfunc.circuit_info, builder),
+        CircuitConcreteLibfunc::GetOutput(libfunc) => {
+            build_get_circuit_output(libfunc.value_index, builder)
+        }
+    }
+}
+
+/// Handles instruction for reserving the values of a circuit in the RangeCheck96 builtin.
+fn build_init_circuit_data(
+    circuit_info: &CircuitInfo,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check96] = builder.try_get_single_cells()?;
+    let values_size = circuit_info.n_values * MOD_BUILTIN_N_LIMBS;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(values_size as i16) range_check96;
+    };
+    casm_build_extend! {casm_builder,
+        const values_size_imm = values_size;
+        tempvar start = range_check96;
+        let new_range_check96 = start + values_size_imm;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[new_range_check96], &[start, start]], None)],
+        CostValidationInfo {
+            range_check_info: Some((start, new_range_check96)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Handles instruction for filling the next input of a circuit.
+fn build_add_circuit_input(
+    circuit_info: &CircuitInfo,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_accumulator, expr_input] = builder.try_get_refs()?;
+    let [start, end] = expr_accumulator.try_unpack()?;
+    let input: [_; MOD_BUILTIN_N_LIMBS] = expr_input.try_unpack()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref start;
+        deref end;
+    };
+    for (i, limb) in input.into_iter().enumerate() {
+        let limb_offset = i as i16;
+        add_input_variables!(casm_builder, deref limb;);
+        casm_build_extend!(casm_builder, assert limb = end[limb_offset];);
+    }
+    casm_build_extend! {casm_builder,
+        const limbs_imm = MOD_BUILTIN_N_LIMBS;
+        const inputs_size_imm = circuit_info.n_inputs * MOD_BUILTIN_N_LIMBS;
+        tempvar new_end = end + limbs_imm;
+        tempvar inputs_end = start + inputs_size_imm;
+        tempvar remaining = inputs_end - new_end;
+        jump More if remaining != 0;
+    };
+    let more_handle = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[start]], None), ("More", &[&[start, new_end]], Some(more_handle))],
+        Default::default(),
+    ))
+}
+
+/// Handles instruction for writing the offsets of the values of the mod builtin operations
+/// evaluating a circuit. The offsets of each operation are the cells of its values `a`, `b` and
+/// `c`, relative to the start of the values.
+fn build_get_circuit_descriptor(
+    circuit_info: &CircuitInfo,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    builder.try_get_refs::<0>()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    let mut offsets_ptrs = vec![];
+    for operations in [&circuit_info.add_offsets, &circuit_info.mul_offsets] {
+        casm_build_extend! {casm_builder,
+            tempvar offsets;
+            hint AllocSegment {} into {dst: offsets};
+            ap += 1;
+        };
+        for (i, value_index) in operations.iter().flatten().enumerate() {
+            let offset_cell = i as i16;
+            casm_build_extend! {casm_builder,
+                const offset_imm = value_index * MOD_BUILTIN_N_LIMBS;
+                tempvar offset = offset_imm;
+                assert offset = offsets[offset_cell];
+            };
+        }
+        offsets_ptrs.push(offsets);
+    }
+
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&offsets_ptrs], None)],
+        Default::default(),
+    ))
+}
+
+/// Handles instruction for evaluating a circuit using the AddMod and MulMod builtins.
+///
+/// The first instance of each used builtin is set to the modulus, the values of the circuit and the
+/// offsets of its operations - the runner fills the following instances, as well as the values
+/// deduced by the operations. If the operand of an inverse gate is not invertible, the MulMod
+/// builtin cannot deduce the values of the circuit, and the run fails.
+fn build_eval_circuit(
+    circuit_info: &CircuitInfo,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_add_mod, expr_mul_mod, expr_descriptor, expr_data, expr_modulus] =
+        builder.try_get_refs()?;
+    let add_mod = expr_add_mod.try_unpack_single()?;
+    let mul_mod = expr_mul_mod.try_unpack_single()?;
+    let [add_offsets, mul_offsets] = expr_descriptor.try_unpack()?;
+    let values = expr_data.try_unpack_single()?;
+    let [modulus0, modulus1, modulus2, modulus3] = expr_modulus.try_unpack()?;
+    let n_add_mods = circuit_info.add_offsets.len();
+    let n_mul_mods = circuit_info.mul_offsets.len();
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer((n_add_mods * MOD_BUILTIN_INSTANCE_SIZE) as i16) add_mod;
+        buffer((n_mul_mods * MOD_BUILTIN_INSTANCE_SIZE) as i16) mul_mod;
+        deref add_offsets;
+        deref mul_offsets;
+        deref values;
+        deref modulus0;
+        deref modulus1;
+        deref modulus2;
+        deref modulus3;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_add_mod = add_mod;
+        let orig_mul_mod = mul_mod;
+        const n_add_mods_imm = n_add_mods;
+        const n_mul_mods_imm = n_mul_mods;
+    };
+    // The constant one, used by the inverse gates, follows the inputs.
+    if let Some(one_index) = circuit_info.one_index {
+        casm_build_extend! {casm_builder,
+            const one_imm = 1;
+            const zero_imm = 0;
+            tempvar one = one_imm;
+            tempvar zero = zero_imm;
+        };
+        for i in 0..MOD_BUILTIN_N_LIMBS {
+            let limb = if i == 0 { one } else { zero };
+            let limb_offset = (one_index * MOD_BUILTIN_N_LIMBS + i) as i16;
+            casm_build_extend!(casm_builder, assert limb = values[limb_offset];);
+        }
+    }
+    // Set the first instance of each used builtin, except for its number of operations.
+    let mut used_builtins = vec![];
+    for (builtin, offsets, n_operations, n_operations_imm) in [
+        (add_mod, add_offsets, n_add_mods, n_add_mods_imm),
+        (mul_mod, mul_offsets, n_mul_mods, n_mul_mods_imm),
+    ] {
+        if n_operations == 0 {
+            continue;
+        }
+        for limb in [modulus0, modulus1, modulus2, modulus3] {
+            casm_build_extend!(casm_builder, assert limb = *(builtin++););
+        }
+        casm_build_extend! {casm_builder,
+            assert values = *(builtin++);
+            assert offsets = *(builtin++);
+        };
+        used_builtins.push((builtin, n_operations_imm));
+    }
+    let mut n_operations_vars = vec![];
+    for (_, n_operations_imm) in &used_builtins {
+        let n_operations_imm = *n_operations_imm;
+        casm_build_extend!(casm_builder, tempvar n_operations = n_operations_imm;);
+        n_operations_vars.push(n_operations);
+    }
+    // The hint is carried by the first write of a number of operations, as all the other cells of
+    // the first instances are set by then.
+    if !used_builtins.is_empty() {
+        casm_build_extend! {casm_builder,
+            hint FillModBuiltins {
+                add_mod_ptr: orig_add_mod, n_add_mods: n_add_mods_imm,
+                mul_mod_ptr: orig_mul_mod, n_mul_mods: n_mul_mods_imm
+            } into {};
+        };
+    }
+    for ((builtin, _), n_operations) in used_builtins.into_iter().zip(n_operations_vars) {
+        casm_build_extend!(casm_builder, assert n_operations = *(builtin++););
+    }
+    // Skip the instances filled by the runner.
+    let mut new_builtins = vec![];
+    for (orig_builtin, n_operations) in [(orig_add_mod, n_add_mods), (orig_mul_mod, n_mul_mods)] {
+        if n_operations == 0 {
+            new_builtins.push(orig_builtin);
+            continue;
+        }
+        casm_build_extend! {casm_builder,
+            const instances_size_imm = n_operations * MOD_BUILTIN_INSTANCE_SIZE;
+            let new_builtin = orig_builtin + instances_size_imm;
+        };
+        new_builtins.push(new_builtin);
+    }
+    let [new_add_mod, new_mul_mod] = new_builtins.try_into().unwrap();
+
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[new_add_mod], &[new_mul_mod], &[values]], None)],
+        Default::default(),
+    ))
+}
+
+/// Handles instruction for getting the value at the given index of an evaluated circuit.
+fn build_get_circuit_output(
+    value_index: usize,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [values] = builder.try_get_single_cells()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref values;
+    };
+    let mut limbs = vec![];
+    for i in 0..MOD_BUILTIN_N_LIMBS {
+        let limb_offset = (value_index * MOD_BUILTIN_N_LIMBS + i) as i16;
+        casm_build_extend!(casm_builder, tempvar limb = values[limb_offset];);
+        limbs.push(limb);
+    }
+
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&limbs], None)],
+        Default::default(),
+    ))
+}
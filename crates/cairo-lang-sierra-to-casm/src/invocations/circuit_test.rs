@@ -0,0 +1,125 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_casm::operand::{CellRef, Register};
+use pretty_assertions::assert_eq;
+
+use crate::invocations::test_utils::{compile_libfunc, ReducedBranchChanges};
+use crate::ref_expr;
+use crate::references::ReferenceExpression;
+
+/// The gate computing `in0 + in1`.
+const SUM: &str = "AddModGate<CircuitInput<0>, CircuitInput<1>>";
+
+/// Returns the circuit computing `1 / (in0 + in1)`. Its values are the two inputs, the constant
+/// one, the sum and its inverse.
+fn circuit() -> String {
+    format!("Circuit<Struct<ut@Tuple, InverseGate<{SUM}>>>")
+}
+
+/// Returns a reference expression to the given cells.
+fn cells_ref(cells: &[(Register, i16)]) -> ReferenceExpression {
+    ReferenceExpression {
+        cells: cells
+            .iter()
+            .map(|(register, offset)| {
+                CellExpression::Deref(CellRef { register: *register, offset: *offset })
+            })
+            .collect(),
+    }
+}
+
+/// Returns a reference expression to the given consecutive cells.
+fn range_ref(register: Register, offsets: std::ops::Range<i16>) -> ReferenceExpression {
+    ReferenceExpression {
+        cells: offsets.map(|offset| CellExpression::Deref(CellRef { register, offset })).collect(),
+    }
+}
+
+#[test]
+fn test_init_circuit_data() {
+    let compiled =
+        compile_libfunc(&format!("init_circuit_data<{}>", circuit()), vec![ref_expr!([fp + 1])]);
+    assert_eq!(compiled.instructions.len(), 1);
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: vec![
+                ref_expr!([ap - 1] + 20),
+                cells_ref(&[(Register::AP, -1), (Register::AP, -1)])
+            ],
+            ap_change: ApChange::Known(1),
+        }]
+    );
+}
+
+#[test]
+fn test_add_circuit_input() {
+    let compiled = compile_libfunc(
+        &format!("add_circuit_input<{}>", circuit()),
+        vec![range_ref(Register::FP, 1..3), range_ref(Register::FP, 3..7)],
+    );
+    assert_eq!(compiled.instructions.len(), 8);
+    assert_eq!(
+        compiled.results,
+        vec![
+            ReducedBranchChanges { refs: vec![ref_expr!([fp + 1])], ap_change: ApChange::Known(3) },
+            ReducedBranchChanges {
+                refs: vec![cells_ref(&[(Register::FP, 1), (Register::AP, -3)])],
+                ap_change: ApChange::Known(3),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_get_circuit_descriptor() {
+    let compiled = compile_libfunc(&format!("get_circuit_descriptor<{}>", circuit()), vec![]);
+    // Two segment allocations, and 3 offsets for each of the add and inverse operations.
+    assert_eq!(compiled.instructions.len(), 14);
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: vec![cells_ref(&[(Register::AP, -8), (Register::AP, -4)])],
+            ap_change: ApChange::Known(8),
+        }]
+    );
+}
+
+#[test]
+fn test_eval_circuit() {
+    let compiled = compile_libfunc(
+        &format!("eval_circuit<{}>", circuit()),
+        vec![
+            ref_expr!([fp + 1]),
+            ref_expr!([fp + 2]),
+            range_ref(Register::FP, 3..5),
+            ref_expr!([fp + 5]),
+            range_ref(Register::FP, 6..10),
+        ],
+    );
+    // Setting the constant one, and the first instance of each of the builtins.
+    assert_eq!(compiled.instructions.len(), 22);
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: vec![ref_expr!([fp + 1] + 7), ref_expr!([fp + 2] + 7), ref_expr!([fp + 5])],
+            ap_change: ApChange::Known(4),
+        }]
+    );
+}
+
+#[test]
+fn test_get_circuit_output() {
+    let compiled = compile_libfunc(
+        &format!("get_circuit_output<{}, InverseGate<{SUM}>>", circuit()),
+        vec![ref_expr!([fp + 1])],
+    );
+    assert_eq!(compiled.instructions.len(), 4);
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: vec![range_ref(Register::AP, -4..0)],
+            ap_change: ApChange::Known(4),
+        }]
+    );
+}
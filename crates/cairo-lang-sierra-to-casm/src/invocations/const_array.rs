@@ -0,0 +1,46 @@
+use cairo_lang_casm::casm;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_casm::operand::{CellRef, Register};
+use cairo_lang_sierra::extensions::const_array::ConstAsSpanConcreteLibfunc;
+
+use super::misc::get_pointer_after_program_code;
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::references::ReferenceExpression;
+use crate::relocations::InstructionsWithRelocations;
+
+#[cfg(test)]
+#[path = "const_array_test.rs"]
+mod test;
+
+/// Handles instruction for getting a span over the values of a const array, whose segment is
+/// placed right after the program code, as part of the program's const data segments.
+pub fn build(
+    libfunc: &ConstAsSpanConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    builder.try_get_refs::<0>()?;
+    let consts_info = builder.program_info.consts_info;
+    let segment_offset = *consts_info
+        .segment_offsets
+        .get(&libfunc.const_array_ty)
+        .ok_or_else(|| InvocationError::UnknownTypeId(libfunc.const_array_ty.clone()))?;
+    // The const data segments end where the program ends, so the offset from the end is negative.
+    let offset = -i32::try_from(consts_info.values.len() - segment_offset)
+        .map_err(|_| InvocationError::IntegerOverflow)?;
+    let (InstructionsWithRelocations { mut instructions, relocations, .. }, _) =
+        get_pointer_after_program_code(offset);
+    let len = libfunc.values.len();
+    instructions.extend(casm! { [ap] = [ap - 1] + (len), ap++; }.instructions);
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [vec![ReferenceExpression {
+            cells: vec![
+                CellExpression::Deref(CellRef { register: Register::AP, offset: -2 }),
+                CellExpression::Deref(CellRef { register: Register::AP, offset: -1 }),
+            ],
+        }]
+        .into_iter()]
+        .into_iter(),
+    ))
+}
@@ -0,0 +1,32 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_casm::casm;
+use pretty_assertions::assert_eq;
+
+use crate::invocations::test_utils::{
+    compile_libfunc, ReducedBranchChanges, ReducedCompiledInvocation,
+};
+use crate::ref_expr;
+use crate::relocations::{Relocation, RelocationEntry};
+
+#[test]
+fn test_const_as_span() {
+    assert_eq!(
+        compile_libfunc("const_as_span<ConstArray<u8, 1, 2, 3>>", vec![]),
+        ReducedCompiledInvocation {
+            instructions: casm! {
+                call rel 0;
+                [ap] = [ap - 1] + (-3), ap++;
+                [ap] = [ap - 1] + 3, ap++;
+            }
+            .instructions,
+            relocations: vec![
+                RelocationEntry { instruction_idx: 0, relocation: Relocation::EndOfProgram },
+                RelocationEntry { instruction_idx: 1, relocation: Relocation::EndOfProgram },
+            ],
+            results: vec![ReducedBranchChanges {
+                refs: vec![ref_expr!([ap - 2], [ap - 1])],
+                ap_change: ApChange::Known(4),
+            }]
+        }
+    );
+}
@@ -1,21 +1,50 @@
 use std::str::FromStr;
 
-use cairo_felt::Felt252;
-use cairo_lang_casm::builder::{CasmBuilder, Var};
+use cairo_lang_casm::builder::{CasmBuildResult, CasmBuilder, Var};
 use cairo_lang_casm::casm_build_extend;
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
-use num_bigint::{BigInt, ToBigInt};
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra_ap_change::core_libfunc_ap_change::{
+    core_libfunc_ap_change, SOFTWARE_EC_STATE_ADD_MUL_AP_CHANGE,
+};
+use cairo_lang_sierra_ap_change::ApChange;
+use cairo_lang_sierra_gas::core_libfunc_cost::{
+    EC_MSM_FIXED_COST, EC_MSM_PER_PAIR_COST, SOFTWARE_EC_OP_FIXED_COST, SOFTWARE_EC_OP_N_BITS,
+    SOFTWARE_EC_OP_PER_BIT_COST,
+};
+use cairo_lang_sierra_gas::objects::ConstCost;
+use num_bigint::BigInt;
 
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
-use crate::invocations::misc::validate_under_limit;
+use crate::invocations::misc::{add_alloc_constant_size_hint, validate_under_half_prime};
 use crate::invocations::{
     add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
 };
+use crate::references::ReferenceExpression;
+use crate::relocations::{Relocation, RelocationEntry};
 
-/// Returns the Beta value of the Starkware elliptic curve.
-fn get_beta() -> BigInt {
-    BigInt::from_str("3141592653589793238462643383279502884197169399375105820974944592307816406665")
-        .unwrap()
+#[cfg(test)]
+#[path = "ec_test.rs"]
+mod test;
+
+/// The parameters of a short Weierstrass curve `y^2 = x^3 + alpha * x + beta` over the felt252
+/// field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurveParams {
+    pub alpha: BigInt,
+    pub beta: BigInt,
+}
+impl CurveParams {
+    /// Returns the parameters of the Starkware elliptic curve.
+    pub fn stark_curve() -> Self {
+        Self {
+            alpha: BigInt::from(1),
+            beta: BigInt::from_str(
+                "3141592653589793238462643383279502884197169399375105820974944592307816406665",
+            )
+            .unwrap(),
+        }
+    }
 }
 
 /// Builds instructions for Sierra EC operations.
@@ -23,24 +52,38 @@ pub fn build(
     libfunc: &EcConcreteLibfunc,
     builder: CompiledInvocationBuilder<'_>,
 ) -> Result<CompiledInvocation, InvocationError> {
+    // The EC types and the EC op builtin are defined over the Starkware curve only.
+    let curve = CurveParams::stark_curve();
     match libfunc {
+        EcConcreteLibfunc::Add(_) => build_ec_add(builder, &curve),
         EcConcreteLibfunc::IsZero(_) => build_is_zero(builder),
         EcConcreteLibfunc::Neg(_) => build_ec_neg(builder),
+        EcConcreteLibfunc::PointDouble(_) => build_ec_point_double(builder, &curve),
         EcConcreteLibfunc::StateAdd(_) => build_ec_state_add(builder),
-        EcConcreteLibfunc::TryNew(_) => build_ec_point_try_new_nz(builder),
+        EcConcreteLibfunc::TryNew(_) => build_ec_point_try_new_nz(builder, &curve),
         EcConcreteLibfunc::StateFinalize(_) => build_ec_state_finalize(builder),
-        EcConcreteLibfunc::StateInit(_) => build_ec_state_init(builder),
-        EcConcreteLibfunc::StateAddMul(_) => build_ec_state_add_mul(builder),
-        EcConcreteLibfunc::PointFromX(_) => build_ec_point_from_x_nz(builder),
+        EcConcreteLibfunc::StateInit(_) => build_ec_state_init(builder, &curve),
+        EcConcreteLibfunc::StateAddMul(_) => {
+            if builder.program_info.metadata.software_ec_op {
+                build_ec_state_add_mul_software(builder, &curve)
+            } else {
+                build_ec_state_add_mul(builder)
+            }
+        }
+        EcConcreteLibfunc::Msm(_) => build_ec_msm(builder, &curve),
+        EcConcreteLibfunc::PointFromX(_) => build_ec_point_from_x_nz(builder, &curve),
+        EcConcreteLibfunc::PointFromXParity(_) => build_ec_point_from_x_parity_nz(builder, &curve),
         EcConcreteLibfunc::UnwrapPoint(_) => build_ec_point_unwrap(builder),
         EcConcreteLibfunc::Zero(_) => build_ec_zero(builder),
     }
 }
 
-/// Extends the CASM builder to include computation of `y^2` and `x^3 + x + BETA` for the given
-/// pair (x, y). Populates the two "output vars" with the computed LHS and RHS of the EC equation.
+/// Extends the CASM builder to include computation of `y^2` and `x^3 + ALPHA * x + BETA` for the
+/// given pair (x, y). Populates the two "output vars" with the computed LHS and RHS of the EC
+/// equation.
 fn compute_ec_equation(
     casm_builder: &mut CasmBuilder,
+    curve: &CurveParams,
     x: Var,
     y: Var,
     auxiliary_vars: [Var; 3],
@@ -48,7 +91,7 @@ fn compute_ec_equation(
     computed_rhs: Var,
 ) {
     compute_lhs(casm_builder, y, computed_lhs);
-    compute_rhs(casm_builder, x, auxiliary_vars, computed_rhs);
+    compute_rhs(casm_builder, curve, x, auxiliary_vars, computed_rhs);
 }
 
 /// Computes the left-hand side of the EC equation, namely `y^2`.
@@ -58,20 +101,37 @@ fn compute_lhs(casm_builder: &mut CasmBuilder, y: Var, computed_lhs: Var) {
     };
 }
 
-/// Computes the right-hand side of the EC equation, namely `x^3 + x + BETA`.
+/// Computes the right-hand side of the EC equation, namely `x^3 + ALPHA * x + BETA`.
 fn compute_rhs(
     casm_builder: &mut CasmBuilder,
+    curve: &CurveParams,
     x: Var,
-    [x2, x3, alpha_x_plus_beta]: [Var; 3],
+    [aux0, aux1, aux2]: [Var; 3],
     computed_rhs: Var,
 ) {
     casm_build_extend! {casm_builder,
-        const beta = (get_beta());
-        assert x2 = x * x;
-        assert x3 = x2 * x;
-        assert alpha_x_plus_beta = x + beta; // Here we use the fact that Alpha is 1.
-        assert computed_rhs = x3 + alpha_x_plus_beta;
+        const beta = curve.beta.clone();
     };
+    if curve.alpha == BigInt::from(1) {
+        // Saves the multiplication by alpha.
+        let (x2, x3, alpha_x_plus_beta) = (aux0, aux1, aux2);
+        casm_build_extend! {casm_builder,
+            assert x2 = x * x;
+            assert x3 = x2 * x;
+            assert alpha_x_plus_beta = x + beta;
+            assert computed_rhs = x3 + alpha_x_plus_beta;
+        };
+    } else {
+        // Computed as `(x^2 + ALPHA) * x + BETA`.
+        let (x2, x2_plus_alpha, x3_plus_alpha_x) = (aux0, aux1, aux2);
+        casm_build_extend! {casm_builder,
+            const alpha = curve.alpha.clone();
+            assert x2 = x * x;
+            assert x2_plus_alpha = x2 + alpha;
+            assert x3_plus_alpha_x = x2_plus_alpha * x;
+            assert computed_rhs = x3_plus_alpha_x + beta;
+        };
+    }
 }
 
 /// Extends the CASM builder to compute the sum, or difference, of two EC points, and store the
@@ -131,6 +191,7 @@ fn build_ec_zero(
 /// Handles instruction for creating an EC point.
 fn build_ec_point_try_new_nz(
     builder: CompiledInvocationBuilder<'_>,
+    curve: &CurveParams,
 ) -> Result<CompiledInvocation, InvocationError> {
     let [x, y] = builder.try_get_single_cells()?;
 
@@ -140,7 +201,7 @@ fn build_ec_point_try_new_nz(
         deref y;
     };
 
-    // Check if `(x, y)` is on the curve, by computing `y^2` and `x^3 + x + beta`.
+    // Check if `(x, y)` is on the curve, by computing `y^2` and `x^3 + alpha * x + beta`.
     casm_build_extend! {casm_builder,
         tempvar y2;
         tempvar expected_y2;
@@ -148,7 +209,7 @@ fn build_ec_point_try_new_nz(
         tempvar aux1;
         tempvar aux2;
     };
-    compute_ec_equation(&mut casm_builder, x, y, [aux0, aux1, aux2], y2, expected_y2);
+    compute_ec_equation(&mut casm_builder, curve, x, y, [aux0, aux1, aux2], y2, expected_y2);
     casm_build_extend! {casm_builder,
         tempvar diff = y2 - expected_y2;
         jump NotOnCurve if diff != 0;
@@ -162,26 +223,17 @@ fn build_ec_point_try_new_nz(
     ))
 }
 
-/// Handles instruction for creating an EC point.
-fn build_ec_point_from_x_nz(
-    builder: CompiledInvocationBuilder<'_>,
-) -> Result<CompiledInvocation, InvocationError> {
-    let [range_check, x] = builder.try_get_single_cells()?;
-
-    let mut casm_builder = CasmBuilder::default();
-    add_input_variables! {casm_builder,
-        buffer(2) range_check;
-        deref x;
-    };
-
+/// Extends the CASM builder to guess `y` such that `(x, y)` is on the curve, jumping to the
+/// `NotOnCurve` label if no such `y` exists. Returns the guessed `y`, which may be either of the
+/// two square roots.
+fn guess_y_from_x(casm_builder: &mut CasmBuilder, curve: &CurveParams, x: Var) -> Var {
     casm_build_extend! {casm_builder,
-        let orig_range_check = range_check;
         tempvar rhs;
         tempvar aux0;
         tempvar aux1;
         tempvar aux2;
     };
-    compute_rhs(&mut casm_builder, x, [aux0, aux1, aux2], rhs);
+    compute_rhs(casm_builder, curve, x, [aux0, aux1, aux2], rhs);
 
     // Guess y, by either computing the square root of `rhs`, or of `3 * rhs`.
     casm_build_extend! {casm_builder,
@@ -189,7 +241,7 @@ fn build_ec_point_from_x_nz(
         hint FieldSqrt {val: rhs} into {sqrt: y};
         tempvar lhs;
     };
-    compute_lhs(&mut casm_builder, y, lhs);
+    compute_lhs(casm_builder, y, lhs);
 
     casm_build_extend! {casm_builder,
         tempvar diff = lhs - rhs;
@@ -210,19 +262,28 @@ fn build_ec_point_from_x_nz(
 
         OnCurve:
     };
+    y
+}
+
+/// Handles instruction for creating an EC point.
+fn build_ec_point_from_x_nz(
+    builder: CompiledInvocationBuilder<'_>,
+    curve: &CurveParams,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, x] = builder.try_get_single_cells()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref x;
+    };
+
+    casm_build_extend!(casm_builder, let orig_range_check = range_check;);
+    let y = guess_y_from_x(&mut casm_builder, curve, x);
 
     // Check that y < PRIME / 2 to enforce a deterministic behavior (otherwise, the prover can
     // choose either y or -y).
-    let auxiliary_vars: [_; 4] = std::array::from_fn(|_| casm_builder.alloc_var(false));
-    validate_under_limit::<1>(
-        &mut casm_builder,
-        // Note that `1/2 (mod PRIME) = (PRIME + 1) / 2 = ceil(PRIME / 2)`.
-        // Thus, `y < 1/2 (mod PRIME)` if and only if `y < PRIME / 2`.
-        &(Felt252::from(1) / Felt252::from(2)).to_biguint().to_bigint().unwrap(),
-        y,
-        range_check,
-        &auxiliary_vars,
-    );
+    validate_under_half_prime(&mut casm_builder, y, range_check);
 
     // Fallthrough - success.
 
@@ -240,6 +301,66 @@ fn build_ec_point_from_x_nz(
     ))
 }
 
+/// Handles instruction for creating an EC point from its x coordinate and the parity of its y
+/// coordinate.
+fn build_ec_point_from_x_parity_nz(
+    builder: CompiledInvocationBuilder<'_>,
+    curve: &CurveParams,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, x, y_parity] = builder.try_get_single_cells()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref x;
+        deref y_parity;
+    };
+
+    casm_build_extend!(casm_builder, let orig_range_check = range_check;);
+    let y = guess_y_from_x(&mut casm_builder, curve, x);
+
+    // Split `y = 2 * y_half + y_bit`, where `y_bit` is 0 or 1 and `y_half < PRIME / 2`. As
+    // `y != 0` (the curve has odd order), this is the unique split of `y` as an integer in
+    // `[0, PRIME)`, so `y_bit` is its parity.
+    casm_build_extend! {casm_builder,
+        const two = 2;
+        const minus_one = -1;
+        tempvar y_bit;
+        tempvar y_half;
+        hint DivMod {lhs: y, rhs: two} into {quotient: y_half, remainder: y_bit};
+        assert y_bit = y_bit * y_bit;
+        tempvar y_half_doubled = y_half * two;
+        assert y = y_half_doubled + y_bit;
+    };
+    validate_under_half_prime(&mut casm_builder, y_half, range_check);
+
+    // Since `PRIME` is odd, `y` and `-y` have different parities, so exactly one of them matches
+    // the requested parity.
+    casm_build_extend! {casm_builder,
+        tempvar result_y;
+        tempvar parity_diff = y_bit - y_parity;
+        jump Negate if parity_diff != 0;
+        assert result_y = y;
+        jump Done;
+        Negate:
+        assert result_y = y * minus_one;
+        Done:
+    };
+
+    let not_on_curve = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[x, result_y]], None),
+            ("NotOnCurve", &[&[range_check]], Some(not_on_curve)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
 /// Handles instruction for unwrapping an EC point.
 fn build_ec_point_unwrap(
     builder: CompiledInvocationBuilder<'_>,
@@ -307,6 +428,7 @@ fn build_ec_neg(
 /// Handles instruction for initializing an EC state.
 fn build_ec_state_init(
     builder: CompiledInvocationBuilder<'_>,
+    curve: &CurveParams,
 ) -> Result<CompiledInvocation, InvocationError> {
     let mut casm_builder = CasmBuilder::default();
 
@@ -323,16 +445,22 @@ fn build_ec_state_init(
         tempvar random_y;
         tempvar random_ptr;
         hint RandomEcPoint {} into { x: random_x, y: random_y };
-        // Initalize `random_ptr` and copy the random point into it.
-        const ec_point_size = 2;
-        hint AllocConstantSize { size: ec_point_size } into {dst: random_ptr};
+    }
+    // Initalize `random_ptr` and copy the random point into it.
+    add_alloc_constant_size_hint(
+        &mut casm_builder,
+        builder.program_info.vm_version,
+        2.into(),
+        random_ptr,
+    );
+    casm_build_extend! {casm_builder,
         assert random_x = random_ptr[0];
         assert random_y = random_ptr[1];
     }
 
     // Assert that the random point is on the curve by passing `y2' as both
     // the left and right hand side of the curve equation.
-    compute_ec_equation(&mut casm_builder, random_x, random_y, [aux0, aux1, aux2], y2, y2);
+    compute_ec_equation(&mut casm_builder, curve, random_x, random_y, [aux0, aux1, aux2], y2, y2);
 
     // The third entry in the EC state is a pointer to the sampled random EC point.
     Ok(builder.build_from_casm_builder(
@@ -380,6 +508,145 @@ fn build_ec_state_add(
     ))
 }
 
+/// Handles instruction for doubling a non-zero EC point.
+fn build_ec_point_double(
+    builder: CompiledInvocationBuilder<'_>,
+    curve: &CurveParams,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [x, y] = builder.try_get_refs::<1>()?[0].try_unpack()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref x;
+        deref y;
+    };
+
+    casm_build_extend! {casm_builder,
+        // The slope of the tangent at (x, y) is `(3 * x^2 + alpha) / (2 * y)`.
+        tempvar denominator = y + y;
+        jump NotZeroY if denominator != 0;
+        // A point with `y = 0` is of order 2, and its double is the point at infinity. There are no
+        // such points on the Stark curve, as its order is odd.
+        fail;
+        NotZeroY:
+        const three = 3;
+        const alpha = curve.alpha.clone();
+        tempvar x2 = x * x;
+        tempvar x2_times_3 = x2 * three;
+        tempvar numerator = x2_times_3 + alpha;
+    };
+
+    let (result_x, result_y) =
+        add_ec_points_inner(&mut casm_builder, (x, y), x, numerator, denominator);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[result_x, result_y]], None)],
+        Default::default(),
+    ))
+}
+
+/// Handles instruction for adding two EC points, any of which may be the zero point.
+///
+/// All the cases write the result to the same cells, and are padded to the AP change of the
+/// longest one (doubling a point), so that they merge at the end of the libfunc.
+fn build_ec_add(
+    builder: CompiledInvocationBuilder<'_>,
+    curve: &CurveParams,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_p, expr_q] = builder.try_get_refs()?;
+    let [px, py] = expr_p.try_unpack()?;
+    let [qx, qy] = expr_q.try_unpack()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref px;
+        deref py;
+        deref qx;
+        deref qy;
+    };
+
+    casm_build_extend! {casm_builder,
+        tempvar result_x;
+        tempvar result_y;
+        // A point is the zero point if and only if `y = 0`, since there is no point on the curve
+        // with `y = 0`.
+        jump PNotZero if py != 0;
+        // `0 + q = q`.
+        assert result_x = qx;
+        assert result_y = qy;
+    };
+    skip_cells(&mut casm_builder, 12);
+    casm_build_extend! {casm_builder,
+        jump Done;
+        PNotZero:
+        jump QNotZero if qy != 0;
+        // `p + 0 = p`.
+        assert result_x = px;
+        assert result_y = py;
+    };
+    skip_cells(&mut casm_builder, 12);
+    casm_build_extend! {casm_builder,
+        jump Done;
+        QNotZero:
+        tempvar x_diff = px - qx;
+        jump NotSameX if x_diff != 0;
+        // X coordinate is identical, so either `q = -p` or `q = p`.
+        tempvar y_sum = py + qy;
+        jump NotOpposite if y_sum != 0;
+        // `p + (-p) = 0`.
+        const zero = 0;
+        assert result_x = zero;
+        assert result_y = zero;
+    };
+    skip_cells(&mut casm_builder, 10);
+    casm_build_extend! {casm_builder,
+        jump Done;
+        NotOpposite:
+        // `q = p`, so `y_sum = 2 * y`, and the slope is the one of the tangent at `p`:
+        // `(3 * x^2 + alpha) / (2 * y)`.
+        const three = 3;
+        const alpha = curve.alpha.clone();
+        tempvar x2 = px * px;
+        tempvar x2_times_3 = x2 * three;
+        tempvar numerator = x2_times_3 + alpha;
+    };
+    let (double_x, double_y) =
+        add_ec_points_inner(&mut casm_builder, (px, py), px, numerator, y_sum);
+    casm_build_extend! {casm_builder,
+        assert result_x = double_x;
+        assert result_y = double_y;
+        jump Done;
+        NotSameX:
+        tempvar numerator = py - qy;
+    };
+    let (sum_x, sum_y) = add_ec_points_inner(&mut casm_builder, (px, py), qx, numerator, x_diff);
+    casm_build_extend! {casm_builder,
+        assert result_x = sum_x;
+        assert result_y = sum_y;
+    };
+    skip_cells(&mut casm_builder, 3);
+    casm_build_extend! {casm_builder,
+        Done:
+    };
+
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[result_x, result_y]], None)],
+        Default::default(),
+    ))
+}
+
+/// Allocates `count` unused cells, to align a branch on AP change with the longer branches it
+/// merges with.
+fn skip_cells(casm_builder: &mut CasmBuilder, count: usize) {
+    for _ in 0..count {
+        casm_builder.alloc_var(false);
+    }
+    casm_build_extend! {casm_builder,
+        ap += count;
+    };
+}
+
 /// Handles instruction for finalizing an EC state.
 fn build_ec_state_finalize(
     builder: CompiledInvocationBuilder<'_>,
@@ -464,3 +731,509 @@ fn build_ec_state_add_mul(
         Default::default(),
     ))
 }
+
+/// Handles instruction for computing `S + M * Q` where `S` is an EC state, `M` is a scalar
+/// (felt252) and `Q` is an EC point, without the EC op builtin.
+///
+/// Goes over the bits of `M` from the least significant one, adding `Q` to `S` if the bit is set,
+/// and doubling `Q`. The bits are computed in chunks, and the quotients of `M` at the chunk
+/// boundaries are used to verify that the bits represent `M` as an integer smaller than the prime.
+fn build_ec_state_add_mul_software(
+    builder: CompiledInvocationBuilder<'_>,
+    curve: &CurveParams,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [ec_builtin_expr, expr_state, expr_m, expr_point] = builder.try_get_refs()?;
+    let ec_builtin = ec_builtin_expr.try_unpack_single()?;
+    let [sx, sy, random_ptr] = expr_state.try_unpack()?;
+    let [m] = expr_m.try_unpack()?;
+    let [px, py] = expr_point.try_unpack()?;
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut bits_call_steps: i32 = 0;
+    let mut per_bit_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(0) ec_builtin;
+        deref sx;
+        deref sy;
+        deref random_ptr;
+        deref px;
+        deref py;
+        deref m;
+    };
+    casm_build_extend! {casm_builder,
+        // EcOpSoftware is a wrapper that provides a clean scope for the computation, where local
+        // variables can be allocated.
+        // Push EcOpSoftware arguments.
+        tempvar ec_op_arg_ec_builtin = ec_builtin;
+        tempvar ec_op_arg_random_ptr = random_ptr;
+        tempvar ec_op_arg_sx = sx;
+        tempvar ec_op_arg_sy = sy;
+        tempvar ec_op_arg_px = px;
+        tempvar ec_op_arg_py = py;
+        tempvar ec_op_arg_m = m;
+        let (final_ec_builtin, result_x, result_y, final_random_ptr) = call EcOpSoftware;
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        EcOpSoftware:
+        // The quotients of `M` by `2**192`, `2**196`, `2**197` and `2**251`.
+        localvar q192;
+        localvar q196;
+        localvar q197;
+        localvar q251;
+        ap += 4;
+        const n_bits_192 = 192;
+        // Push EcOpBits arguments.
+        tempvar bits_sx = ec_op_arg_sx;
+        tempvar bits_sy = ec_op_arg_sy;
+        tempvar bits_qx = ec_op_arg_px;
+        tempvar bits_qy = ec_op_arg_py;
+        tempvar bits_m = ec_op_arg_m;
+        tempvar bits_n = n_bits_192;
+        let (sx, sy, qx, qy, m) = call EcOpBits;
+        assert q192 = m;
+        const n_bits_4 = 4;
+        tempvar n = n_bits_4;
+        rescope {
+            bits_sx = sx, bits_sy = sy, bits_qx = qx, bits_qy = qy, bits_m = m, bits_n = n,
+            q192 = q192, q196 = q196, q197 = q197, q251 = q251,
+            ec_op_arg_ec_builtin = ec_op_arg_ec_builtin,
+            ec_op_arg_random_ptr = ec_op_arg_random_ptr,
+            ec_op_arg_m = ec_op_arg_m
+        };
+        let (sx, sy, qx, qy, m) = call EcOpBits;
+        assert q196 = m;
+        const n_bits_1 = 1;
+        tempvar n = n_bits_1;
+        rescope {
+            bits_sx = sx, bits_sy = sy, bits_qx = qx, bits_qy = qy, bits_m = m, bits_n = n,
+            q192 = q192, q196 = q196, q197 = q197, q251 = q251,
+            ec_op_arg_ec_builtin = ec_op_arg_ec_builtin,
+            ec_op_arg_random_ptr = ec_op_arg_random_ptr,
+            ec_op_arg_m = ec_op_arg_m
+        };
+        let (sx, sy, qx, qy, m) = call EcOpBits;
+        assert q197 = m;
+    };
+    // Split just to avoid recursion limit when the macro is parsed.
+    casm_build_extend! {casm_builder,
+        const n_bits_54 = 54;
+        tempvar n = n_bits_54;
+        rescope {
+            bits_sx = sx, bits_sy = sy, bits_qx = qx, bits_qy = qy, bits_m = m, bits_n = n,
+            q192 = q192, q196 = q196, q197 = q197, q251 = q251,
+            ec_op_arg_ec_builtin = ec_op_arg_ec_builtin,
+            ec_op_arg_random_ptr = ec_op_arg_random_ptr,
+            ec_op_arg_m = ec_op_arg_m
+        };
+        let (sx, sy, qx, qy, m) = call EcOpBits;
+        assert q251 = m;
+        const n_bits_1 = 1;
+        tempvar n = n_bits_1;
+        rescope {
+            bits_sx = sx, bits_sy = sy, bits_qx = qx, bits_qy = qy, bits_m = m, bits_n = n,
+            q192 = q192, q196 = q196, q197 = q197, q251 = q251,
+            ec_op_arg_ec_builtin = ec_op_arg_ec_builtin,
+            ec_op_arg_random_ptr = ec_op_arg_random_ptr,
+            ec_op_arg_m = ec_op_arg_m
+        };
+        let (sx, sy, qx, qy, m) = call EcOpBits;
+        // All the 252 bits were consumed, so the bits represent `M` as an integer.
+        const zero = 0;
+        assert m = zero;
+    };
+    casm_build_extend! {casm_builder,
+        // Verify the represented integer is smaller than the prime `2**251 + 17 * 2**192 + 1`,
+        // which may only be violated if bit 251 is set.
+        const one = 1;
+        const two = 2;
+        const sixteen = 16;
+        const pow54 = BigInt::from(1) << 54;
+        const pow192 = BigInt::from(1) << 192;
+        tempvar zero_cell = zero;
+        // If bit 251 is set, bits 197 to 250 are not set.
+        tempvar q197_excess = q197 - pow54;
+        assert zero_cell = q251 * q197_excess;
+        // If bits 251 and 196 are set, bits 192 to 195 represent at most 1, and if they represent
+        // 1, the bits below 192 are not set.
+        tempvar q197_doubled = q197 * two;
+        tempvar bit196 = q196 - q197_doubled;
+        tempvar top_bits_set = q251 * bit196;
+        tempvar q196_shifted = q196 * sixteen;
+        tempvar mid_bits = q192 - q196_shifted;
+        tempvar masked_mid_bits = top_bits_set * mid_bits;
+        tempvar mid_bits_minus_1 = mid_bits - one;
+        assert zero_cell = masked_mid_bits * mid_bits_minus_1;
+        tempvar q192_shifted = q192 * pow192;
+        tempvar low_bits = ec_op_arg_m - q192_shifted;
+        assert zero_cell = masked_mid_bits * low_bits;
+        // Push the returned variables.
+        tempvar returned_ec_builtin = ec_op_arg_ec_builtin;
+        tempvar returned_result_x = sx;
+        tempvar returned_result_y = sy;
+        tempvar returned_random_ptr = ec_op_arg_random_ptr;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    casm_build_extend! {casm_builder,
+        // Goes over the `bits_n` lowest bits of `bits_m`, where `bits_n` is not 0. Returns the
+        // updated `S` and `Q`, and the quotient of `bits_m` by `2**bits_n`.
+        // The variables are placed at the same offsets as at the end of a loop iteration.
+        EcOpBits:
+        const one = 1;
+        localvar one_cell = one;
+        tempvar m = bits_m;
+        tempvar n = bits_n;
+    };
+    skip_cells(&mut casm_builder, 10);
+    casm_build_extend! {casm_builder,
+        tempvar qx = bits_qx;
+        tempvar qy = bits_qy;
+    };
+    skip_cells(&mut casm_builder, 8);
+    casm_build_extend! {casm_builder,
+        tempvar sx = bits_sx;
+        tempvar sy = bits_sy;
+        rescope { sx = sx, sy = sy, qx = qx, qy = qy, m = m, n = n, one_cell = one_cell };
+        #{ bits_call_steps += steps; steps = 0; }
+        EcOpBitsLoop:
+        const one = 1;
+        const two = 2;
+        const three = 3;
+        const alpha = curve.alpha.clone();
+        tempvar bit;
+        tempvar next_m;
+        hint DivMod { lhs: m, rhs: two } into { quotient: next_m, remainder: bit };
+        tempvar next_n = n - one;
+        // Verify `m = 2 * next_m + bit`, where `bit` is 0 or 1.
+        assert bit = bit * bit;
+        tempvar next_m_doubled = next_m * two;
+        assert m = next_m_doubled + bit;
+        // The slope of the tangent at `Q` is `(3 * qx^2 + alpha) / (2 * qy)`, where `qy` is not 0
+        // as the order of the curve is odd.
+        tempvar qx2 = qx * qx;
+        tempvar qx2_times_3 = qx2 * three;
+        tempvar numerator = qx2_times_3 + alpha;
+        tempvar denominator = qy + qy;
+    };
+    let (next_qx, next_qy) =
+        add_ec_points_inner(&mut casm_builder, (qx, qy), qx, numerator, denominator);
+    casm_build_extend! {casm_builder,
+        jump AddPoint if bit != 0;
+    };
+    // Aligning with the AP change of adding the point.
+    skip_cells(&mut casm_builder, 8);
+    casm_build_extend! {casm_builder,
+        tempvar next_sx = sx;
+        tempvar next_sy = sy;
+        rescope {
+            sx = next_sx, sy = next_sy, qx = next_qx, qy = next_qy, m = next_m, n = next_n,
+            one_cell = one_cell
+        };
+        jump LoopEnd;
+        AddPoint:
+        // Verify `sx != qx`, so the slope is well defined. As `S` is based on a random point, this
+        // only fails with negligible probability.
+        tempvar x_diff = sx - qx;
+        tempvar x_diff_inv = one_cell / x_diff;
+        tempvar y_diff = sy - qy;
+    };
+    let (next_sx, next_sy) = add_ec_points_inner(&mut casm_builder, (sx, sy), qx, y_diff, x_diff);
+    casm_build_extend! {casm_builder,
+        rescope {
+            sx = next_sx, sy = next_sy, qx = next_qx, qy = next_qy, m = next_m, n = next_n,
+            one_cell = one_cell
+        };
+        LoopEnd:
+        jump EcOpBitsLoop if n != 0;
+        #{ per_bit_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_sx = sx;
+        tempvar returned_sy = sy;
+        tempvar returned_qx = qx;
+        tempvar returned_qy = qy;
+        tempvar returned_m = m;
+        ret;
+        #{ bits_call_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // The bits are consumed by 5 calls to `EcOpBits`, of 192, 4, 1, 54 and 1 bits.
+    let bits_calls = 5;
+    assert_eq!(
+        ConstCost { steps: fixed_steps + bits_calls * bits_call_steps, holes: 0, range_checks: 0 },
+        SOFTWARE_EC_OP_FIXED_COST
+    );
+    assert_eq!(
+        ConstCost { steps: per_bit_steps, holes: 0, range_checks: 0 },
+        SOFTWARE_EC_OP_PER_BIT_COST
+    );
+    // Manually counted, as the builder does not track the AP change of called code: 48 cells in
+    // the main code and in `EcOpSoftware`, 30 cells per call to `EcOpBits`, and 25 cells per bit.
+    assert_eq!(
+        48 + 30 * bits_calls as usize + 25 * SOFTWARE_EC_OP_N_BITS as usize,
+        SOFTWARE_EC_STATE_ADD_MUL_AP_CHANGE
+    );
+    let CasmBuildResult { instructions, branches: [(state, _)] } =
+        casm_builder.build(["Fallthrough"]);
+
+    let mut compiled_invocation = builder.build(
+        instructions,
+        vec![],
+        [[
+            ReferenceExpression { cells: vec![state.get_adjusted(final_ec_builtin)] },
+            ReferenceExpression {
+                cells: vec![
+                    state.get_adjusted(result_x),
+                    state.get_adjusted(result_y),
+                    state.get_adjusted(final_random_ptr),
+                ],
+            },
+        ]
+        .into_iter()]
+        .into_iter(),
+    );
+    // The computation allocates many cells above the variables that were on the stack, so they are
+    // no longer at its top.
+    for branch_changes in &mut compiled_invocation.results {
+        branch_changes.clear_old_stack = true;
+        branch_changes.new_stack_size = 0;
+    }
+    Ok(compiled_invocation)
+}
+
+/// Handles instruction for computing the multi-scalar multiplication of an array of
+/// `(scalar, point)` pairs.
+/// The pairs are accumulated by a loop using the EC op builtin, whose cost, including the cost of
+/// the builtin taken from the builtin costs, is withdrawn from the gas builtin at runtime.
+fn build_ec_msm(
+    builder: CompiledInvocationBuilder<'_>,
+    curve: &CurveParams,
+) -> Result<CompiledInvocation, InvocationError> {
+    if builder.program_info.metadata.software_ec_op {
+        return Err(InvocationError::NotImplementedStr {
+            invocation: builder.invocation.clone(),
+            message: "`ec_msm` requires the EC op builtin.".into(),
+        });
+    }
+    let [expr_range_check, expr_gas_builtin, expr_ec_builtin, expr_builtin_costs, expr_pairs] =
+        builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let ec_builtin = expr_ec_builtin.try_unpack_single()?;
+    let builtin_costs = expr_builtin_costs.try_unpack_single()?;
+    let [pairs_start, pairs_end] = expr_pairs.try_unpack()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let ec_op_cost_offset = CostTokenType::EcOp.offset_in_builtin_costs();
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_pair_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref gas_builtin;
+        deref ec_builtin;
+        deref builtin_costs;
+        deref pairs_start;
+        deref pairs_end;
+    };
+    casm_build_extend! {casm_builder,
+        const per_pair_cost = EC_MSM_PER_PAIR_COST.cost();
+        const pair_size = 3;
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar ec_op_cost = builtin_costs[ec_op_cost_offset];
+        tempvar pair_cost = ec_op_cost + per_pair_cost;
+        tempvar n_cells = pairs_end - pairs_start;
+        tempvar n_pairs = n_cells / pair_size;
+        tempvar required_gas = n_pairs * pair_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        // Sample a random point on the curve, used as the initial EC state.
+        tempvar y2;
+        tempvar aux0;
+        tempvar aux1;
+        tempvar aux2;
+        tempvar random_x;
+        tempvar random_y;
+        hint RandomEcPoint {} into { x: random_x, y: random_y };
+    };
+    // Assert that the random point is on the curve by passing `y2` as both the left and right hand
+    // side of the curve equation.
+    compute_ec_equation(&mut casm_builder, curve, random_x, random_y, [aux0, aux1, aux2], y2, y2);
+    casm_build_extend! {casm_builder,
+        jump NonEmpty if n_pairs != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the multiplication function.
+        tempvar msm_range_check = range_check;
+        tempvar msm_ec_builtin = ec_builtin;
+        tempvar msm_pairs = pairs_start;
+        let (final_range_check, final_gas_builtin, final_ec_builtin, result_x, result_y) =
+            call Msm;
+        jump Done;
+        Empty:
+        // The sum is the zero point - return the values in the same layout as the multiplication
+        // function.
+        const zero = 0;
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        tempvar empty_ec_builtin = ec_builtin;
+        tempvar empty_x = zero;
+        tempvar empty_y = zero;
+        rescope {
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_ec_builtin = empty_ec_builtin,
+            result_x = empty_x,
+            result_y = empty_y
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Adds `M * Q` to the state `(random_x, random_y)` for each of the `n_pairs` pairs starting
+        // at `msm_pairs`, where `n_pairs` is not 0, and subtracts the random point from the result.
+        Msm:
+        tempvar loop_ec_builtin = msm_ec_builtin;
+        tempvar sx = random_x;
+        tempvar sy = random_y;
+        tempvar pairs = msm_pairs;
+        tempvar remaining = n_pairs;
+        rescope {
+            loop_ec_builtin = loop_ec_builtin,
+            sx = sx,
+            sy = sy,
+            pairs = pairs,
+            remaining = remaining,
+            msm_range_check = msm_range_check,
+            updated_gas = updated_gas,
+            random_x = random_x,
+            random_y = random_y
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        MsmLoop:
+        const one = 1;
+        const pair_size = 3;
+        tempvar m = pairs[0];
+        tempvar qx = pairs[1];
+        tempvar qy = pairs[2];
+        assert sx = *(loop_ec_builtin++);
+        assert sy = *(loop_ec_builtin++);
+        assert qx = *(loop_ec_builtin++);
+        assert qy = *(loop_ec_builtin++);
+        assert m = *(loop_ec_builtin++);
+        let rx = *(loop_ec_builtin++);
+        let ry = *(loop_ec_builtin++);
+        tempvar next_ec_builtin = loop_ec_builtin;
+        tempvar next_x = rx;
+        tempvar next_y = ry;
+        tempvar next_pairs = pairs + pair_size;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            loop_ec_builtin = next_ec_builtin,
+            sx = next_x,
+            sy = next_y,
+            pairs = next_pairs,
+            remaining = next_remaining,
+            msm_range_check = msm_range_check,
+            updated_gas = updated_gas,
+            random_x = random_x,
+            random_y = random_y
+        };
+        jump MsmLoop if remaining != 0;
+        #{ per_pair_steps += steps; steps = 0; }
+    };
+    // Subtract the random point from the state, as done by `ec_state_try_finalize_nz`, returning
+    // the zero point if they are equal.
+    casm_build_extend! {casm_builder,
+        const zero = 0;
+        tempvar sum_x;
+        tempvar sum_y;
+        tempvar denominator = sx - random_x;
+        jump NotSameX if denominator != 0;
+        // Assert the result is the zero point (the other option is that the state is the negation
+        // of the random point, and doubling is not allowed).
+        assert sy = random_y;
+        assert sum_x = zero;
+        assert sum_y = zero;
+    };
+    // Align with the AP change of the addition below.
+    skip_cells(&mut casm_builder, 8);
+    casm_build_extend! {casm_builder,
+        jump Finalized;
+        NotSameX:
+        tempvar numerator = sy + random_y;
+    };
+    let (difference_x, difference_y) =
+        add_ec_points_inner(&mut casm_builder, (sx, sy), random_x, numerator, denominator);
+    casm_build_extend! {casm_builder,
+        assert sum_x = difference_x;
+        assert sum_y = difference_y;
+        Finalized:
+        // Push the returned variables.
+        tempvar returned_range_check = msm_range_check;
+        tempvar returned_gas_builtin = updated_gas;
+        tempvar returned_ec_builtin = loop_ec_builtin;
+        tempvar returned_x = sum_x;
+        tempvar returned_y = sum_y;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(ConstCost { steps: fixed_steps, holes: 0, range_checks: 1 }, EC_MSM_FIXED_COST);
+    assert_eq!(
+        ConstCost { steps: per_pair_steps, holes: 0, range_checks: 0 },
+        EC_MSM_PER_PAIR_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches: [(state, _), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Failure"]);
+    assert_eq!(failure_state.steps, 10, "Must match the failure branch cost.");
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[1],
+        ApChange::Known(failure_state.ap_change)
+    );
+
+    Ok(builder.build(
+        instructions,
+        failure_relocations
+            .into_iter()
+            .map(|instruction_idx| RelocationEntry {
+                instruction_idx,
+                relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+            })
+            .collect(),
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_ec_builtin)] },
+                ReferenceExpression {
+                    cells: vec![state.get_adjusted(result_x), state.get_adjusted(result_y)],
+                },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(ec_builtin)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
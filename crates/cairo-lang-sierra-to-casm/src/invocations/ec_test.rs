@@ -0,0 +1,111 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_casm::builder::{CasmBuildResult, CasmBuilder};
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_casm::res;
+use cairo_lang_sierra_ap_change::core_libfunc_ap_change::SOFTWARE_EC_STATE_ADD_MUL_AP_CHANGE;
+use indoc::indoc;
+use itertools::join;
+use num_bigint::BigInt;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{compute_rhs, CurveParams};
+use crate::invocations::test_utils::{compile_libfunc, compile_libfunc_ex, ReducedBranchChanges};
+use crate::ref_expr;
+
+#[test_case(CurveParams::stark_curve(),
+            indoc! {"
+                [ap + 0] = [fp + -3] * [fp + -3], ap++;
+                [ap + 0] = [ap + -1] * [fp + -3], ap++;
+                [ap + 0] = [fp + -3] + 3141592653589793238462643383279502884197169399375105820974944592307816406665, ap++;
+                [ap + 0] = [ap + -2] + [ap + -1], ap++;
+            "};
+            "stark curve")]
+#[test_case(CurveParams { alpha: BigInt::from(2), beta: BigInt::from(7) },
+            indoc! {"
+                [ap + 0] = [fp + -3] * [fp + -3], ap++;
+                [ap + 0] = [ap + -1] + 2, ap++;
+                [ap + 0] = [ap + -1] * [fp + -3], ap++;
+                [ap + 0] = [ap + -1] + 7, ap++;
+            "};
+            "alpha 2")]
+fn test_compute_rhs(curve: CurveParams, expected_casm: &str) {
+    let mut casm_builder = CasmBuilder::default();
+    let x = casm_builder.add_var(CellExpression::from_res_operand(res!([fp - 3])));
+    let auxiliary_vars = std::array::from_fn(|_| casm_builder.alloc_var(false));
+    let rhs = casm_builder.alloc_var(false);
+    compute_rhs(&mut casm_builder, &curve, x, auxiliary_vars, rhs);
+    let CasmBuildResult { instructions, .. } = casm_builder.build(["Fallthrough"]);
+    assert_eq!(join(instructions.iter().map(|inst| format!("{inst};\n")), ""), expected_casm);
+}
+
+#[test]
+fn test_ec_point_from_x_parity_nz() {
+    let compiled = compile_libfunc(
+        "ec_point_from_x_parity_nz",
+        vec![ref_expr!([fp + 1]), ref_expr!([fp + 2]), ref_expr!([fp + 3])],
+    );
+    assert_eq!(
+        compiled.results,
+        vec![
+            ReducedBranchChanges {
+                refs: vec![ref_expr!([fp + 1] + 3), ref_expr!([fp + 2], [ap - 2])],
+                ap_change: ApChange::Known(16),
+            },
+            ReducedBranchChanges { refs: vec![ref_expr!([fp + 1])], ap_change: ApChange::Known(7) },
+        ]
+    );
+}
+
+#[test]
+fn test_ec_msm() {
+    let compiled = compile_libfunc(
+        "ec_msm",
+        vec![
+            ref_expr!([fp + 1]),
+            ref_expr!([fp + 2]),
+            ref_expr!([fp + 3]),
+            ref_expr!([fp + 4]),
+            ref_expr!([fp + 5], [fp + 6]),
+        ],
+    );
+    assert_eq!(
+        compiled.results,
+        vec![
+            ReducedBranchChanges {
+                refs: vec![
+                    ref_expr!([ap - 5]),
+                    ref_expr!([ap - 4]),
+                    ref_expr!([ap - 3]),
+                    ref_expr!([ap - 2], [ap - 1]),
+                ],
+                ap_change: ApChange::Unknown,
+            },
+            ReducedBranchChanges {
+                refs: vec![ref_expr!([fp + 1] + 1), ref_expr!([fp + 2]), ref_expr!([fp + 3])],
+                ap_change: ApChange::Known(8),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_ec_state_add_mul_software() {
+    let compiled = compile_libfunc_ex(
+        "ec_state_add_mul",
+        vec![
+            ref_expr!([fp + 1]),
+            ref_expr!([fp + 2], [fp + 3], [fp + 4]),
+            ref_expr!([fp + 5]),
+            ref_expr!([fp + 6], [fp + 7]),
+        ],
+        true,
+    );
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: vec![ref_expr!([ap - 4]), ref_expr!([ap - 3], [ap - 2], [ap - 1])],
+            ap_change: ApChange::Known(SOFTWARE_EC_STATE_ADD_MUL_AP_CHANGE),
+        }]
+    );
+}
@@ -0,0 +1,46 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::ecdsa::EcdsaConcreteLibfunc;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::add_input_variables;
+
+/// Builds instructions for Sierra ECDSA operations.
+pub fn build(
+    libfunc: &EcdsaConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        EcdsaConcreteLibfunc::Verify(_) => build_ecdsa_verify(builder),
+    }
+}
+
+/// Handles instruction for verifying an ECDSA signature.
+///
+/// The signature is registered in the builtin by a hint, and the builtin then validates it when
+/// the public key and the message hash are written to its instance.
+fn build_ecdsa_verify(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [ecdsa, message_hash, public_key, signature_r, signature_s] =
+        builder.try_get_single_cells()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref message_hash;
+        deref public_key;
+        deref signature_r;
+        deref signature_s;
+        buffer(1) ecdsa;
+    };
+    casm_build_extend! {casm_builder,
+        hint AddEcdsaSignature {ecdsa_ptr: ecdsa, r: signature_r, s: signature_s} into {};
+        assert public_key = *(ecdsa++);
+        assert message_hash = *(ecdsa++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[ecdsa]], None)],
+        Default::default(),
+    ))
+}
@@ -0,0 +1,869 @@
+use cairo_lang_casm::builder::{CasmBuildResult, CasmBuilder, Var};
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_casm::operand::{CellRef, Register};
+use cairo_lang_sierra::extensions::encoding::EncodingConcreteLibfunc;
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
+use cairo_lang_sierra_ap_change::core_libfunc_ap_change::core_libfunc_ap_change;
+use cairo_lang_sierra_ap_change::ApChange;
+use cairo_lang_sierra_gas::core_libfunc_cost::{
+    BASE64_ENCODE_FIXED_COST, BASE64_ENCODE_PER_GROUP_COST, HEX_DECODE_FIXED_COST,
+    HEX_DECODE_PER_BYTE_COST, HEX_ENCODE_FIXED_COST, HEX_ENCODE_PER_BYTE_COST,
+};
+use cairo_lang_sierra_gas::objects::ConstCost;
+use itertools::{chain, Itertools};
+use num_bigint::BigInt;
+
+use super::misc::get_pointer_after_program_code;
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{add_input_variables, get_non_fallthrough_statement_id};
+use crate::references::ReferenceExpression;
+use crate::relocations::{InstructionsWithRelocations, Relocation, RelocationEntry};
+
+/// The hex digits, by their value.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// The base64 characters, by their value.
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The classes of the bytes for hex decoding, as `(lower bound, upper bound, offset, is_invalid)`.
+/// The classes are sorted and cover all the bytes, and the value of a hex digit in a valid class is
+/// the byte minus the offset of the class.
+const HEX_DECODE_CLASSES: [(u8, u8, u8, u8); 7] = [
+    (0, b'0' - 1, 0, 1),
+    (b'0', b'9', b'0', 0),
+    (b'9' + 1, b'A' - 1, 0, 1),
+    (b'A', b'F', b'A' - 10, 0),
+    (b'F' + 1, b'a' - 1, 0, 1),
+    (b'a', b'f', b'a' - 10, 0),
+    (b'f' + 1, u8::MAX, 0, 1),
+];
+
+/// The tables used by the encoding libfuncs, which are placed in the const data segments of the
+/// program.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EncodingTable {
+    /// The hex digits, by their value.
+    HexDigits,
+    /// The columns of `HEX_DECODE_CLASSES`, one after the other.
+    HexDecodeClasses,
+    /// The base64 characters, by their value.
+    Base64Chars,
+}
+impl EncodingTable {
+    /// Returns the table used by `libfunc`.
+    pub fn used_by(libfunc: &EncodingConcreteLibfunc) -> Self {
+        match libfunc {
+            EncodingConcreteLibfunc::HexEncode(_) => Self::HexDigits,
+            EncodingConcreteLibfunc::HexDecode(_) => Self::HexDecodeClasses,
+            EncodingConcreteLibfunc::Base64Encode(_) => Self::Base64Chars,
+        }
+    }
+
+    /// Returns the values of the table.
+    pub fn values(self) -> Vec<BigInt> {
+        match self {
+            Self::HexDigits => HEX_DIGITS.iter().copied().map(BigInt::from).collect(),
+            Self::HexDecodeClasses => chain!(
+                HEX_DECODE_CLASSES.map(|(lower, _, _, _)| lower),
+                HEX_DECODE_CLASSES.map(|(_, upper, _, _)| upper),
+                HEX_DECODE_CLASSES.map(|(_, _, offset, _)| offset),
+                HEX_DECODE_CLASSES.map(|(_, _, _, is_invalid)| is_invalid),
+            )
+            .map(BigInt::from)
+            .collect(),
+            Self::Base64Chars => BASE64_CHARS.iter().copied().map(BigInt::from).collect(),
+        }
+    }
+}
+
+/// Builds instructions for Sierra encoding operations.
+pub fn build(
+    libfunc: &EncodingConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        EncodingConcreteLibfunc::HexEncode(_) => build_hex_encode(builder),
+        EncodingConcreteLibfunc::HexDecode(_) => build_hex_decode(builder),
+        EncodingConcreteLibfunc::Base64Encode(_) => build_base64_encode(builder),
+    }
+}
+
+/// Adds a variable pointing to the table used by the libfunc to `casm_builder`, which must not
+/// have any instructions yet. Returns the variable, along with the instructions computing it, which
+/// are placed before the instructions of `casm_builder`.
+fn add_table_ptr(
+    builder: &CompiledInvocationBuilder<'_>,
+    casm_builder: &mut CasmBuilder,
+    table: EncodingTable,
+) -> Result<(Var, InstructionsWithRelocations), InvocationError> {
+    let consts_info = builder.program_info.consts_info;
+    let table_offset = consts_info
+        .table_offsets
+        .get(&table)
+        .expect("The tables used by the program are in its const data segments.");
+    // The const data segments end where the program ends, so the offset from the end is negative.
+    let offset = -i32::try_from(consts_info.values.len() - table_offset)
+        .map_err(|_| InvocationError::IntegerOverflow)?;
+    let (table_ptr_instructions, ap_change) = get_pointer_after_program_code(offset);
+    casm_builder.increase_ap_change(ap_change);
+    // The pointer is in the last cell allocated by the instructions.
+    let table_ptr = casm_builder.add_var(CellExpression::Deref(CellRef {
+        register: Register::AP,
+        offset: ap_change as i16 - 1,
+    }));
+    Ok((table_ptr, table_ptr_instructions))
+}
+
+/// Handles a Sierra statement for encoding a byte array as hex digits.
+/// Each byte is split into its two nibbles, which are translated into digits using a table of the
+/// hex digits.
+fn build_hex_encode(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_gas_builtin, expr_bytes] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let [bytes_start, bytes_end] = expr_bytes.try_unpack()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_byte_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref gas_builtin;
+        deref bytes_start;
+        deref bytes_end;
+    };
+    let (digits, table_ptr_instructions) =
+        add_table_ptr(&builder, &mut casm_builder, EncodingTable::HexDigits)?;
+    // The pointer to the table is computed on all the paths.
+    fixed_steps += table_ptr_instructions.cost.steps;
+    casm_build_extend! {casm_builder,
+        const per_byte_cost = HEX_ENCODE_PER_BYTE_COST.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar n_bytes = bytes_end - bytes_start;
+        tempvar required_gas = n_bytes * per_byte_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        tempvar encoded_start;
+        hint AllocSegment {} into {dst: encoded_start};
+        ap += 1;
+        jump NonEmpty if n_bytes != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the encoding function.
+        tempvar encode_range_check = range_check;
+        tempvar encode_src = bytes_start;
+        tempvar encode_dst = encoded_start;
+        let (final_range_check, final_gas_builtin, final_encoded_start, final_encoded_end) =
+            call HexEncode;
+        jump Done;
+        Empty:
+        // Nothing to encode - return the values in the same layout as the encoding function.
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        tempvar empty_encoded_start = encoded_start;
+        tempvar empty_encoded_end = encoded_start;
+        rescope {
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_encoded_start = empty_encoded_start,
+            final_encoded_end = empty_encoded_end
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Encodes the `n_bytes` bytes starting at `encode_src` into `encode_dst`, where `n_bytes`
+        // is not 0.
+        HexEncode:
+        tempvar loop_range_check = encode_range_check;
+        tempvar src = encode_src;
+        tempvar dst = encode_dst;
+        tempvar remaining = n_bytes;
+        rescope {
+            loop_range_check = loop_range_check,
+            src = src,
+            dst = dst,
+            remaining = remaining,
+            updated_gas = updated_gas,
+            encoded_start = encoded_start,
+            digits = digits
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        HexEncodeLoop:
+        const one = 1;
+        const two = 2;
+        const sixteen = 16;
+        const nibble_bound_shift = (BigInt::from(u128::MAX) + 1 - 16) as BigInt;
+        tempvar byte = src[0];
+        // Split the byte into nibbles: `low < 16`, and `high` is small, so
+        // `byte = 16 * high + low` holds over the integers, and `high < 16` as well.
+        tempvar high;
+        tempvar low;
+        hint DivMod {lhs: byte, rhs: sixteen} into {quotient: high, remainder: low};
+        tempvar sixteen_high = high * sixteen;
+        assert byte = sixteen_high + low;
+        assert high = *(loop_range_check++);
+        assert low = *(loop_range_check++);
+        tempvar low_gap = low + nibble_bound_shift;
+        assert low_gap = *(loop_range_check++);
+        tempvar high_digit_ptr = digits + high;
+        tempvar high_digit = high_digit_ptr[0];
+        assert high_digit = dst[0];
+        tempvar low_digit_ptr = digits + low;
+        tempvar low_digit = low_digit_ptr[0];
+        assert low_digit = dst[1];
+        tempvar next_range_check = loop_range_check;
+        tempvar next_src = src + one;
+        tempvar next_dst = dst + two;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            loop_range_check = next_range_check,
+            src = next_src,
+            dst = next_dst,
+            remaining = next_remaining,
+            updated_gas = updated_gas,
+            encoded_start = encoded_start,
+            digits = digits
+        };
+        jump HexEncodeLoop if remaining != 0;
+        #{ per_byte_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_range_check = loop_range_check;
+        tempvar returned_gas_builtin = updated_gas;
+        tempvar returned_encoded_start = encoded_start;
+        tempvar returned_encoded_end = dst;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(ConstCost { steps: fixed_steps, holes: 0, range_checks: 1 }, HEX_ENCODE_FIXED_COST);
+    assert_eq!(
+        ConstCost { steps: per_byte_steps, holes: 0, range_checks: 3 },
+        HEX_ENCODE_PER_BYTE_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches: [(state, _), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Failure"]);
+    assert_eq!(
+        failure_state.steps as i32 + table_ptr_instructions.cost.steps,
+        10,
+        "Must match the failure branch cost."
+    );
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[1],
+        ApChange::Known(failure_state.ap_change)
+    );
+    // The instructions computing the pointer to the table come before the builder's instructions.
+    let n_table_ptr_instructions = table_ptr_instructions.instructions.len();
+    let relocations = chain!(
+        table_ptr_instructions.relocations,
+        failure_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx: n_table_ptr_instructions + instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        }),
+    )
+    .collect();
+
+    Ok(builder.build(
+        chain!(table_ptr_instructions.instructions, instructions).collect(),
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression {
+                    cells: vec![
+                        state.get_adjusted(final_encoded_start),
+                        state.get_adjusted(final_encoded_end),
+                    ],
+                },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
+
+/// Handles a Sierra statement for decoding a byte array of hex digits.
+/// The class of each byte is found by a hint, and verified using a table of the classes in
+/// `HEX_DECODE_CLASSES`, which also gives whether the class is of hex digits and their offset.
+fn build_hex_decode(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_gas_builtin, expr_digits] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let [digits_start, digits_end] = expr_digits.try_unpack()?;
+    let (invalid_statement_id, failure_handle_statement_id) =
+        match builder.invocation.branches.as_slice() {
+            [
+                BranchInfo { target: BranchTarget::Fallthrough, .. },
+                BranchInfo { target: BranchTarget::Statement(invalid_statement_id), .. },
+                BranchInfo { target: BranchTarget::Statement(failure_handle_statement_id), .. },
+            ] => (*invalid_statement_id, *failure_handle_statement_id),
+            _ => panic!("malformed invocation"),
+        };
+    // The offsets of the columns of the classes table.
+    let n_classes = HEX_DECODE_CLASSES.len() as i16;
+    let (upper_bounds_offset, offsets_offset, is_invalid_offset) =
+        (n_classes, 2 * n_classes, 3 * n_classes);
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_byte_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref gas_builtin;
+        deref digits_start;
+        deref digits_end;
+    };
+    let (classes, table_ptr_instructions) =
+        add_table_ptr(&builder, &mut casm_builder, EncodingTable::HexDecodeClasses)?;
+    // The pointer to the table is computed on all the paths.
+    fixed_steps += table_ptr_instructions.cost.steps;
+    casm_build_extend! {casm_builder,
+        const per_byte_cost = HEX_DECODE_PER_BYTE_COST.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        const zero = 0;
+        const two = 2;
+        tempvar n_digits = digits_end - digits_start;
+        // Split the lowest bit of `n_digits`: `odd` is a bit, and `n_bytes` is small, so
+        // `n_digits = 2 * n_bytes + odd` holds over the integers.
+        tempvar n_bytes;
+        tempvar odd;
+        hint DivMod {lhs: n_digits, rhs: two} into {quotient: n_bytes, remainder: odd};
+        tempvar odd_squared = odd * odd;
+        assert odd_squared = odd;
+        tempvar twice_n_bytes = n_bytes * two;
+        assert n_digits = twice_n_bytes + odd;
+        assert n_bytes = *(range_check++);
+        jump Odd if odd != 0;
+        tempvar required_gas = n_bytes * per_byte_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        tempvar decoded_start;
+        hint AllocSegment {} into {dst: decoded_start};
+        ap += 1;
+        jump NonEmpty if n_bytes != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the decoding function.
+        tempvar decode_range_check = range_check;
+        tempvar decode_src = digits_start;
+        tempvar decode_dst = decoded_start;
+        let (
+            is_invalid,
+            final_range_check,
+            final_gas_builtin,
+            final_decoded_start,
+            final_decoded_end
+        ) = call HexDecode;
+        jump Done;
+        Empty:
+        // Nothing to decode - return the values in the same layout as the decoding function.
+        tempvar empty_is_invalid = zero;
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        tempvar empty_decoded_start = decoded_start;
+        tempvar empty_decoded_end = decoded_start;
+        rescope {
+            is_invalid = empty_is_invalid,
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_decoded_start = empty_decoded_start,
+            final_decoded_end = empty_decoded_end
+        };
+        jump Done;
+        Odd:
+        // An odd number of digits is invalid - return the values in the same layout as the
+        // decoding function, with the digits instead of the decoded array.
+        tempvar odd_is_invalid = odd;
+        tempvar odd_range_check = range_check;
+        tempvar odd_gas_builtin = gas_builtin;
+        tempvar odd_decoded_start = digits_start;
+        tempvar odd_decoded_end = digits_end;
+        rescope {
+            is_invalid = odd_is_invalid,
+            final_range_check = odd_range_check,
+            final_gas_builtin = odd_gas_builtin,
+            final_decoded_start = odd_decoded_start,
+            final_decoded_end = odd_decoded_end
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Decodes the `n_bytes` pairs of digits starting at `decode_src` into `decode_dst`, where
+        // `n_bytes` is not 0. Returns early, with the gas of the bytes left refunded, on a byte
+        // which is not a hex digit.
+        HexDecode:
+        tempvar loop_range_check = decode_range_check;
+        tempvar src = decode_src;
+        tempvar dst = decode_dst;
+        tempvar remaining = n_bytes;
+        rescope {
+            loop_range_check = loop_range_check,
+            src = src,
+            dst = dst,
+            remaining = remaining,
+            updated_gas = updated_gas,
+            decoded_start = decoded_start,
+            classes = classes
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        HexDecodeLoop:
+        const one = 1;
+        const two = 2;
+        const sixteen = 16;
+        const n_classes_imm = n_classes;
+        const class_bound_shift = (BigInt::from(u128::MAX) + 1 - n_classes) as BigInt;
+        // Verify that `high_digit` is in the class at `high_class`, where `0 <= high_class < 7`.
+        tempvar high_digit = src[0];
+        tempvar high_class;
+        hint FindRangeIndex {
+            value: high_digit, bounds: classes, n_bounds: n_classes_imm
+        } into {dst: high_class};
+        assert high_class = *(loop_range_check++);
+        tempvar high_class_gap = high_class + class_bound_shift;
+        assert high_class_gap = *(loop_range_check++);
+        tempvar high_class_ptr = classes + high_class;
+        tempvar high_lower_bound = high_class_ptr[0];
+        tempvar high_above_lower = high_digit - high_lower_bound;
+        assert high_above_lower = *(loop_range_check++);
+        tempvar high_upper_bound = high_class_ptr[upper_bounds_offset];
+        tempvar high_below_upper = high_upper_bound - high_digit;
+        assert high_below_upper = *(loop_range_check++);
+        tempvar high_is_invalid = high_class_ptr[is_invalid_offset];
+        jump HexDecodeInvalidHigh if high_is_invalid != 0;
+        tempvar high_offset = high_class_ptr[offsets_offset];
+        tempvar high = high_digit - high_offset;
+    };
+    casm_build_extend! {casm_builder,
+        // Verify that `low_digit` is in the class at `low_class`, where `0 <= low_class < 7`.
+        tempvar low_digit = src[1];
+        tempvar low_class;
+        hint FindRangeIndex {
+            value: low_digit, bounds: classes, n_bounds: n_classes_imm
+        } into {dst: low_class};
+        assert low_class = *(loop_range_check++);
+        tempvar low_class_gap = low_class + class_bound_shift;
+        assert low_class_gap = *(loop_range_check++);
+        tempvar low_class_ptr = classes + low_class;
+        tempvar low_lower_bound = low_class_ptr[0];
+        tempvar low_above_lower = low_digit - low_lower_bound;
+        assert low_above_lower = *(loop_range_check++);
+        tempvar low_upper_bound = low_class_ptr[upper_bounds_offset];
+        tempvar low_below_upper = low_upper_bound - low_digit;
+        assert low_below_upper = *(loop_range_check++);
+        tempvar low_is_invalid = low_class_ptr[is_invalid_offset];
+        jump HexDecodeInvalidLow if low_is_invalid != 0;
+        tempvar low_offset = low_class_ptr[offsets_offset];
+        tempvar low = low_digit - low_offset;
+    };
+    casm_build_extend! {casm_builder,
+        tempvar sixteen_high = high * sixteen;
+        tempvar byte = sixteen_high + low;
+        assert byte = dst[0];
+        tempvar next_range_check = loop_range_check;
+        tempvar next_src = src + two;
+        tempvar next_dst = dst + one;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            loop_range_check = next_range_check,
+            src = next_src,
+            dst = next_dst,
+            remaining = next_remaining,
+            updated_gas = updated_gas,
+            decoded_start = decoded_start,
+            classes = classes
+        };
+        jump HexDecodeLoop if remaining != 0;
+        #{ per_byte_steps += steps; steps = 0; }
+        // Push the returned variables.
+        const zero = 0;
+        tempvar returned_is_invalid = zero;
+        tempvar returned_range_check = loop_range_check;
+        tempvar returned_gas_builtin = updated_gas;
+        tempvar returned_decoded_start = decoded_start;
+        tempvar returned_decoded_end = dst;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    casm_build_extend! {casm_builder,
+        HexDecodeInvalidHigh:
+        // Refund the gas of the bytes after the current one.
+        const per_byte_cost = HEX_DECODE_PER_BYTE_COST.cost();
+        tempvar high_bytes_left = remaining - one;
+        tempvar high_refund = high_bytes_left * per_byte_cost;
+        tempvar high_refunded_gas = updated_gas + high_refund;
+        // Push the returned variables.
+        tempvar high_returned_is_invalid = high_is_invalid;
+        tempvar high_returned_range_check = loop_range_check;
+        tempvar high_returned_gas_builtin = high_refunded_gas;
+        tempvar high_returned_decoded_start = decoded_start;
+        tempvar high_returned_decoded_end = dst;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    casm_build_extend! {casm_builder,
+        HexDecodeInvalidLow:
+        // Refund the gas of the bytes after the current one.
+        const per_byte_cost = HEX_DECODE_PER_BYTE_COST.cost();
+        tempvar low_bytes_left = remaining - one;
+        tempvar low_refund = low_bytes_left * per_byte_cost;
+        tempvar low_refunded_gas = updated_gas + low_refund;
+        // Push the returned variables.
+        tempvar low_returned_is_invalid = low_is_invalid;
+        tempvar low_returned_range_check = loop_range_check;
+        tempvar low_returned_gas_builtin = low_refunded_gas;
+        tempvar low_returned_decoded_start = decoded_start;
+        tempvar low_returned_decoded_end = dst;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        jump Invalid if is_invalid != 0;
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(ConstCost { steps: fixed_steps, holes: 0, range_checks: 2 }, HEX_DECODE_FIXED_COST);
+    assert_eq!(
+        ConstCost { steps: per_byte_steps, holes: 0, range_checks: 8 },
+        HEX_DECODE_PER_BYTE_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches:
+            [(state, _), (invalid_state, invalid_relocations), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Invalid", "Failure"]);
+    assert_eq!(
+        failure_state.steps as i32 + table_ptr_instructions.cost.steps,
+        16,
+        "Must match the failure branch cost."
+    );
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[2],
+        ApChange::Known(failure_state.ap_change)
+    );
+    // The instructions computing the pointer to the table come before the builder's instructions.
+    // Relocations are applied in a single pass, so they must be sorted by instruction index.
+    let n_table_ptr_instructions = table_ptr_instructions.instructions.len();
+    let relocations = chain!(
+        table_ptr_instructions.relocations,
+        chain!(
+            invalid_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+                instruction_idx: n_table_ptr_instructions + instruction_idx,
+                relocation: Relocation::RelativeStatementId(invalid_statement_id),
+            }),
+            failure_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+                instruction_idx: n_table_ptr_instructions + instruction_idx,
+                relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+            }),
+        )
+        .sorted_by_key(|entry| entry.instruction_idx),
+    )
+    .collect();
+
+    Ok(builder.build(
+        chain!(table_ptr_instructions.instructions, instructions).collect(),
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression {
+                    cells: vec![
+                        state.get_adjusted(final_decoded_start),
+                        state.get_adjusted(final_decoded_end),
+                    ],
+                },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![invalid_state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![invalid_state.get_adjusted(final_gas_builtin)] },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
+
+/// Handles a Sierra statement for encoding the whole 3-byte groups of a byte array as base64.
+/// Each group is split into four 6-bit values, which are translated into characters using a table
+/// of the base64 characters.
+fn build_base64_encode(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_gas_builtin, expr_bytes] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let [bytes_start, bytes_end] = expr_bytes.try_unpack()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_group_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(4) range_check;
+        deref gas_builtin;
+        deref bytes_start;
+        deref bytes_end;
+    };
+    let (chars, table_ptr_instructions) =
+        add_table_ptr(&builder, &mut casm_builder, EncodingTable::Base64Chars)?;
+    // The pointer to the table is computed on all the paths.
+    fixed_steps += table_ptr_instructions.cost.steps;
+    casm_build_extend! {casm_builder,
+        const per_group_cost = BASE64_ENCODE_PER_GROUP_COST.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        const three = 3;
+        const trailing_bound_shift = (BigInt::from(u128::MAX) + 1 - 3) as BigInt;
+        tempvar n_bytes = bytes_end - bytes_start;
+        // Split `n_bytes` into whole groups: `n_trailing < 3`, and `n_groups` is small, so
+        // `n_bytes = 3 * n_groups + n_trailing` holds over the integers.
+        tempvar n_groups;
+        tempvar n_trailing;
+        hint DivMod {lhs: n_bytes, rhs: three} into {quotient: n_groups, remainder: n_trailing};
+        tempvar three_n_groups = n_groups * three;
+        assert n_bytes = three_n_groups + n_trailing;
+        assert n_groups = *(range_check++);
+        assert n_trailing = *(range_check++);
+        tempvar n_trailing_gap = n_trailing + trailing_bound_shift;
+        assert n_trailing_gap = *(range_check++);
+        tempvar required_gas = n_groups * per_group_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        tempvar encoded_start;
+        hint AllocSegment {} into {dst: encoded_start};
+        ap += 1;
+        jump NonEmpty if n_groups != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the encoding function.
+        tempvar encode_range_check = range_check;
+        tempvar encode_src = bytes_start;
+        tempvar encode_dst = encoded_start;
+        let (final_range_check, final_gas_builtin, final_encoded_start, final_encoded_end) =
+            call Base64Encode;
+        jump Done;
+        Empty:
+        // Nothing to encode - return the values in the same layout as the encoding function.
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        tempvar empty_encoded_start = encoded_start;
+        tempvar empty_encoded_end = encoded_start;
+        rescope {
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_encoded_start = empty_encoded_start,
+            final_encoded_end = empty_encoded_end
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Encodes the `n_groups` groups of 3 bytes starting at `encode_src` into `encode_dst`,
+        // where `n_groups` is not 0.
+        Base64Encode:
+        tempvar loop_range_check = encode_range_check;
+        tempvar src = encode_src;
+        tempvar dst = encode_dst;
+        tempvar remaining = n_groups;
+        rescope {
+            loop_range_check = loop_range_check,
+            src = src,
+            dst = dst,
+            remaining = remaining,
+            updated_gas = updated_gas,
+            encoded_start = encoded_start,
+            chars = chars
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        Base64EncodeLoop:
+        const one = 1;
+        const three = 3;
+        const four = 4;
+        const byte_shift = 256;
+        const sextet_shift = 64;
+        const sextet_bound_shift = (BigInt::from(u128::MAX) + 1 - 64) as BigInt;
+        tempvar byte0 = src[0];
+        tempvar group0 = byte0 * byte_shift;
+        tempvar byte1 = src[1];
+        tempvar group01 = group0 + byte1;
+        tempvar group01_shifted = group01 * byte_shift;
+        tempvar byte2 = src[2];
+        tempvar group = group01_shifted + byte2;
+        // Split the group into sextets: `sextet1`, `sextet2` and `sextet3` are smaller than 64,
+        // and `sextet0` is small, so `group = 64**3 * sextet0 + 64**2 * sextet1 + 64 * sextet2 +
+        // sextet3` holds over the integers, and `sextet0 < 64` as well.
+        tempvar sextet0;
+        tempvar sextet1;
+        tempvar sextet2;
+        tempvar sextet3;
+        tempvar group_div_64;
+        tempvar group_div_4096;
+        hint DivMod {
+            lhs: group, rhs: sextet_shift
+        } into {quotient: group_div_64, remainder: sextet3};
+        hint DivMod {
+            lhs: group_div_64, rhs: sextet_shift
+        } into {quotient: group_div_4096, remainder: sextet2};
+        hint DivMod {
+            lhs: group_div_4096, rhs: sextet_shift
+        } into {quotient: sextet0, remainder: sextet1};
+        tempvar sextet0_shifted = sextet0 * sextet_shift;
+        tempvar sextets01 = sextet0_shifted + sextet1;
+        tempvar sextets01_shifted = sextets01 * sextet_shift;
+        tempvar sextets012 = sextets01_shifted + sextet2;
+        tempvar sextets012_shifted = sextets012 * sextet_shift;
+        assert group = sextets012_shifted + sextet3;
+        assert sextet0 = *(loop_range_check++);
+        assert sextet1 = *(loop_range_check++);
+        tempvar sextet1_gap = sextet1 + sextet_bound_shift;
+        assert sextet1_gap = *(loop_range_check++);
+        assert sextet2 = *(loop_range_check++);
+        tempvar sextet2_gap = sextet2 + sextet_bound_shift;
+        assert sextet2_gap = *(loop_range_check++);
+        assert sextet3 = *(loop_range_check++);
+        tempvar sextet3_gap = sextet3 + sextet_bound_shift;
+        assert sextet3_gap = *(loop_range_check++);
+    };
+    casm_build_extend! {casm_builder,
+        tempvar char0_ptr = chars + sextet0;
+        tempvar char0 = char0_ptr[0];
+        assert char0 = dst[0];
+        tempvar char1_ptr = chars + sextet1;
+        tempvar char1 = char1_ptr[0];
+        assert char1 = dst[1];
+        tempvar char2_ptr = chars + sextet2;
+        tempvar char2 = char2_ptr[0];
+        assert char2 = dst[2];
+        tempvar char3_ptr = chars + sextet3;
+        tempvar char3 = char3_ptr[0];
+        assert char3 = dst[3];
+        tempvar next_range_check = loop_range_check;
+        tempvar next_src = src + three;
+        tempvar next_dst = dst + four;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            loop_range_check = next_range_check,
+            src = next_src,
+            dst = next_dst,
+            remaining = next_remaining,
+            updated_gas = updated_gas,
+            encoded_start = encoded_start,
+            chars = chars
+        };
+        jump Base64EncodeLoop if remaining != 0;
+        #{ per_group_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_range_check = loop_range_check;
+        tempvar returned_gas_builtin = updated_gas;
+        tempvar returned_encoded_start = encoded_start;
+        tempvar returned_encoded_end = dst;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(
+        ConstCost { steps: fixed_steps, holes: 0, range_checks: 4 },
+        BASE64_ENCODE_FIXED_COST
+    );
+    assert_eq!(
+        ConstCost { steps: per_group_steps, holes: 0, range_checks: 7 },
+        BASE64_ENCODE_PER_GROUP_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches: [(state, _), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Failure"]);
+    assert_eq!(
+        failure_state.steps as i32 + table_ptr_instructions.cost.steps,
+        16,
+        "Must match the failure branch cost."
+    );
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[1],
+        ApChange::Known(failure_state.ap_change)
+    );
+    // The instructions computing the pointer to the table come before the builder's instructions.
+    let n_table_ptr_instructions = table_ptr_instructions.instructions.len();
+    let relocations = chain!(
+        table_ptr_instructions.relocations,
+        failure_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx: n_table_ptr_instructions + instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        }),
+    )
+    .collect();
+
+    Ok(builder.build(
+        chain!(table_ptr_instructions.instructions, instructions).collect(),
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression {
+                    cells: vec![
+                        state.get_adjusted(final_encoded_start),
+                        state.get_adjusted(final_encoded_end),
+                    ],
+                },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
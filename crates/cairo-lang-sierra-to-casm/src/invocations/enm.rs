@@ -22,9 +22,12 @@ pub fn build(
     builder: CompiledInvocationBuilder<'_>,
 ) -> Result<CompiledInvocation, InvocationError> {
     match libfunc {
-        EnumConcreteLibfunc::Init(EnumInitConcreteLibfunc { index, num_variants, .. }) => {
-            build_enum_init(builder, *index, *num_variants)
-        }
+        EnumConcreteLibfunc::Init(EnumInitConcreteLibfunc {
+            index,
+            num_variants,
+            variant_types,
+            ..
+        }) => build_enum_init(builder, *index, *num_variants, variant_types),
         EnumConcreteLibfunc::Match(_) | EnumConcreteLibfunc::SnapshotMatch(_) => {
             build_enum_match(builder)
         }
@@ -49,10 +52,13 @@ pub fn build(
 /// [ap] = 0; ap++
 /// [ap] = 8; ap++
 /// ```
+/// Enums with a zero niche are represented by the value of the initialized variant, or by zero for
+/// their zero-sized variant - without a variant selector.
 fn build_enum_init(
     builder: CompiledInvocationBuilder<'_>,
     index: usize,
     num_variants: usize,
+    variant_types: &[ConcreteTypeId],
 ) -> Result<CompiledInvocation, InvocationError> {
     let [expression] = builder.try_get_refs()?;
     let init_arg_cells = &expression.cells;
@@ -90,10 +96,24 @@ fn build_enum_init(
     if init_arg_cells.len() != variant_size as usize {
         return Err(InvocationError::InvalidReferenceExpressionForArgument);
     }
-    // Pad the variant to match the size of the largest variant
     let concrete_enum_type = &builder.libfunc.output_types()[0][0];
     let enum_size = get_enum_size(&builder.program_info, concrete_enum_type)
         .ok_or(InvocationError::UnknownTypeData)?;
+    let variant_sizes = variant_types
+        .iter()
+        .map(|ty| builder.program_info.type_sizes.get(ty).cloned())
+        .collect::<Option<Vec<_>>>()
+        .ok_or(InvocationError::UnknownTypeData)?;
+    if has_zero_niche(enum_size, &variant_sizes) {
+        let cells = if variant_size == 0 {
+            vec![CellExpression::Immediate(BigInt::from(0))]
+        } else {
+            init_arg_cells.clone()
+        };
+        let output_expressions = [ReferenceExpression { cells }].into_iter();
+        return Ok(builder.build_only_reference_changes(output_expressions));
+    }
+    // Pad the variant to match the size of the largest variant
     let num_padding = enum_size - 1 - variant_size;
     let inner_value = chain!(
         repeat_n(CellExpression::Immediate(BigInt::from(0)), num_padding as usize),
@@ -115,15 +135,8 @@ fn build_enum_match(
 ) -> Result<CompiledInvocation, InvocationError> {
     let concrete_enum_type = &builder.libfunc.param_signatures()[0].ty;
     let [expression] = builder.try_get_refs()?;
-    let matched_var = EnumView::try_get_view(expression, &builder.program_info, concrete_enum_type)
-        .map_err(|_| InvocationError::InvalidReferenceExpressionForArgument)?;
-    // Verify variant_selector is of type deref. This is the case with an enum_value
-    // that was validly created and then stored.
-    let variant_selector =
-        try_extract_matches!(matched_var.variant_selector, CellExpression::Deref)
-            .ok_or(InvocationError::InvalidReferenceExpressionForArgument)?;
 
-    let mut branch_output_sizes: Vec<usize> = Vec::new();
+    let mut branch_output_sizes: Vec<i16> = Vec::new();
     for branch_outputs in &builder.libfunc.output_types() {
         // Each branch has a single output.
         let branch_output = &branch_outputs[0];
@@ -132,14 +145,28 @@ fn build_enum_match(
             .type_sizes
             .get(branch_output)
             .ok_or(InvocationError::UnknownTypeData)?;
-        branch_output_sizes.push(*branch_output_size as usize);
+        branch_output_sizes.push(*branch_output_size);
     }
+    let enum_size = get_enum_size(&builder.program_info, concrete_enum_type)
+        .ok_or(InvocationError::UnknownTypeData)?;
+    if has_zero_niche(enum_size, &branch_output_sizes) {
+        let expression = expression.clone();
+        return build_enum_match_zero_niche(builder, expression, &branch_output_sizes);
+    }
+
+    let matched_var = EnumView::try_get_view(expression, &builder.program_info, concrete_enum_type)
+        .map_err(|_| InvocationError::InvalidReferenceExpressionForArgument)?;
+    // Verify variant_selector is of type deref. This is the case with an enum_value
+    // that was validly created and then stored.
+    let variant_selector =
+        try_extract_matches!(matched_var.variant_selector, CellExpression::Deref)
+            .ok_or(InvocationError::InvalidReferenceExpressionForArgument)?;
     let output_expressions = branch_output_sizes.into_iter().map(|size| {
         // The size of an output must be smaller than the size of `matched_var.inner_value` as the
         // size of inner_value is fixed and is calculated as the max of the sizes of all the
         // variants (which are the outputs in all the branches). Thus it is guaranteed that the
         // iter we generate here is of size `size` (and not less).
-        let padding_size = matched_var.inner_value.len() - size;
+        let padding_size = matched_var.inner_value.len() - size as usize;
         vec![ReferenceExpression {
             cells: matched_var.inner_value.iter().skip(padding_size).cloned().collect(),
         }]
@@ -267,6 +294,59 @@ fn build_enum_match_long(
     Ok(builder.build(ctx.instructions, relocations, output_expressions))
 }
 
+/// Handles statement for matching an enum with a zero niche - represented by a single cell holding
+/// zero for its zero-sized variant, and the value of its `NonZero` variant otherwise.
+/// For example, with this setup
+/// ```ignore
+/// type u128_ty = u128;
+/// type non_zero_ty = NonZero<u128_ty>;
+/// type unit_ty = Tuple;
+/// type Option = Enum<non_zero_ty, unit_ty>;
+/// libfunc match_option = enum_match<Option>;
+/// ````
+/// this "Sierra statement"
+/// ```ignore
+/// match_option(enum_var=[ap-10]) {fallthrough(some=[ap-10]), 2000(none)};
+/// ```
+/// translates to these casm instructions:
+/// ```ignore
+/// jmp rel 4 if [ap-10] != 0
+/// jmp rel <jump_offset_2000>
+/// ```
+/// If the `NonZero` variant is the second variant, only a jump to it if the value is non-zero is
+/// required.
+fn build_enum_match_zero_niche(
+    builder: CompiledInvocationBuilder<'_>,
+    expression: ReferenceExpression,
+    branch_output_sizes: &[i16],
+) -> Result<CompiledInvocation, InvocationError> {
+    // Verify the value is of type deref. This is the case with an enum_value that was validly
+    // created and then stored.
+    let value = match &expression.cells[..] {
+        [CellExpression::Deref(value)] => *value,
+        _ => return Err(InvocationError::InvalidReferenceExpressionForArgument),
+    };
+    let statement_id = match &builder.invocation.branches[1] {
+        BranchInfo { target: BranchTarget::Statement(statement_id), .. } => *statement_id,
+        _ => panic!("malformed invocation"),
+    };
+    let (instructions, jump_instruction_idx) = if branch_output_sizes[0] == 0 {
+        (casm! { jmp rel 0 if value != 0; }.instructions, 0)
+    } else {
+        // Skip the jump to the second (zero-sized) variant if the value is non-zero.
+        (casm! { jmp rel 4 if value != 0; jmp rel 0; }.instructions, 1)
+    };
+    let relocations = vec![RelocationEntry {
+        instruction_idx: jump_instruction_idx,
+        relocation: Relocation::RelativeStatementId(statement_id),
+    }];
+    let output_expressions = branch_output_sizes.iter().map(|size| {
+        let cells = if *size == 0 { vec![] } else { expression.cells.clone() };
+        vec![ReferenceExpression { cells }].into_iter()
+    });
+    Ok(builder.build(instructions, relocations, output_expressions))
+}
+
 /// A struct representing an actual enum value in the Sierra program.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EnumView {
@@ -321,3 +401,10 @@ fn get_enum_size(
 ) -> Option<i16> {
     Some(program_info.type_sizes.get(concrete_enum_type)?.to_owned())
 }
+
+/// Returns whether an enum of the given size, with variants of the given sizes, has a zero niche -
+/// i.e. it is represented without a variant selector, as it is no larger than its largest variant.
+/// See `cairo_lang_sierra_type_size::get_type_size_map`.
+fn has_zero_niche(enum_size: i16, variant_sizes: &[i16]) -> bool {
+    variant_sizes.iter().any(|size| *size >= enum_size)
+}
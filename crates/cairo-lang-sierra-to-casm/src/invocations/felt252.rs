@@ -7,9 +7,11 @@ use cairo_lang_sierra::extensions::felt252::{
 };
 use num_bigint::BigInt;
 
-use super::misc::build_is_zero;
+use super::misc::{build_is_zero, validate_under_half_prime};
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
-use crate::invocations::{add_input_variables, CostValidationInfo};
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
+};
 use crate::references::ReferenceExpression;
 
 #[cfg(test)]
@@ -29,6 +31,7 @@ pub fn build(
             Felt252OperationWithConstConcreteLibfunc { operator, c, .. },
         )) => build_felt252_op_with_const(builder, *operator, c.clone()),
         Felt252Concrete::IsZero(_) => build_is_zero(builder),
+        Felt252Concrete::Sqrt(_) => build_felt252_sqrt(builder),
         Felt252Concrete::Const(libfunc) => Ok(builder.build_only_reference_changes(
             [ReferenceExpression::from_cell(CellExpression::Immediate(libfunc.c.clone()))]
                 .into_iter(),
@@ -100,3 +103,55 @@ fn felt252_to_cell_operator(op: Felt252BinaryOperator) -> CellOperator {
         Felt252BinaryOperator::Div => CellOperator::Div,
     }
 }
+
+/// Handles the `felt252_sqrt` libfunc.
+fn build_felt252_sqrt(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_single_cells()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref value;
+    };
+
+    // Guess the root, by either computing the square root of `value`, or of `3 * value`.
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        tempvar root;
+        hint FieldSqrt {val: value} into {sqrt: root};
+        tempvar square = root * root;
+        tempvar diff = square - value;
+        jump VerifyNonResidue if diff != 0;
+        jump IsResidue;
+        VerifyNonResidue:
+        // Check that `root^2 = 3 * value`.
+        const three = (3);
+        assert square = value * three;
+        // Note that `value != 0`: otherwise `root^2 = 3 * value = 0` implies `root = 0` and we
+        // would have `root^2 = value` so this branch wouldn't have been chosen.
+        // Since 3 is not a quadratic residue in the field, it follows that `value` is not a
+        // quadratic residue.
+        jump NonResidue;
+
+        IsResidue:
+    };
+
+    // Check that root < PRIME / 2 to enforce a deterministic behavior (otherwise, the prover can
+    // choose either root or -root).
+    validate_under_half_prime(&mut casm_builder, root, range_check);
+
+    let non_residue = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[root]], None),
+            ("NonResidue", &[&[range_check]], Some(non_residue)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
@@ -643,7 +643,7 @@ fn validate_felt252_lt(casm_builder: &mut CasmBuilder, range_check: Var, a: Var,
 ///   One is less than PRIME / 3 + 2 ** 129.
 ///   Another is less than PRIME / 2 + 2 ** 129.
 /// Since the sum of the lengths of these two arcs is less than PRIME, there is no wrap-around.
-fn validate_felt252_le(casm_builder: &mut CasmBuilder, range_check: Var, a: Var, b: Var) {
+pub fn validate_felt252_le(casm_builder: &mut CasmBuilder, range_check: Var, a: Var, b: Var) {
     casm_build_extend! {casm_builder,
         const one = 1;
         const minus_1 = -1;
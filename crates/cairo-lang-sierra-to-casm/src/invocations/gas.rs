@@ -7,6 +7,7 @@ use num_bigint::BigInt;
 
 use super::misc::get_pointer_after_program_code;
 use super::{misc, CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::environment::gas_wallet::GasWallet;
 use crate::invocations::{
     add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
 };
@@ -22,6 +23,7 @@ pub fn build(
         GasConcreteLibfunc::WithdrawGas(_) => build_withdraw_gas(builder),
         GasConcreteLibfunc::RedepositGas(_) => build_redeposit_gas(builder),
         GasConcreteLibfunc::GetAvailableGas(_) => misc::build_dup(builder),
+        GasConcreteLibfunc::Checkpoint(_) => build_gas_checkpoint(builder),
         GasConcreteLibfunc::BuiltinWithdrawGas(_) => build_builtin_withdraw_gas(builder),
         GasConcreteLibfunc::GetBuiltinCosts(_) => build_get_builtin_costs(builder),
     }
@@ -104,6 +106,38 @@ fn build_redeposit_gas(
     ))
 }
 
+/// Handles the gas_checkpoint invocation.
+/// The gas in the gas wallet was already withdrawn from the gas counter, but is not used yet, so it
+/// is added to the value of the counter.
+fn build_gas_checkpoint(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let wallet_value = match &builder.environment.gas_wallet {
+        GasWallet::Value(value) => value.get(&CostTokenType::Const).copied().unwrap_or_default(),
+        GasWallet::Disabled => 0,
+    };
+    let gas_counter_value = builder.try_get_single_cells::<1>()?[0]
+        .to_deref()
+        .ok_or(InvocationError::InvalidReferenceExpressionForArgument)?;
+    let gas_counter = CellExpression::Deref(gas_counter_value);
+    let available_gas = if wallet_value == 0 {
+        gas_counter.clone()
+    } else {
+        CellExpression::BinOp {
+            op: CellOperator::Add,
+            a: gas_counter_value,
+            b: DerefOrImmediate::Immediate(wallet_value.into()),
+        }
+    };
+    Ok(builder.build_only_reference_changes(
+        [
+            ReferenceExpression::from_cell(gas_counter),
+            ReferenceExpression::from_cell(available_gas),
+        ]
+        .into_iter(),
+    ))
+}
+
 /// Handles the withdraw_gas invocation with the builtin costs arguement.
 fn build_builtin_withdraw_gas(
     builder: CompiledInvocationBuilder<'_>,
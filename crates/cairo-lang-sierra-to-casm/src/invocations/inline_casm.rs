@@ -0,0 +1,42 @@
+use cairo_lang_casm::builder::{CasmBuilder, Var};
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::parser::parse_straight_line_code;
+use cairo_lang_sierra::extensions::inline_casm::InlineCasmConcreteLibfunc;
+use itertools::Itertools;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+
+/// Builds instructions for running a block of inline CASM code.
+/// The inputs are pushed to the stack, so the code can reference them relative to ap, and the
+/// outputs are the last cells allocated by the code.
+pub fn build(
+    libfunc: &InlineCasmConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    if builder.refs.len() != libfunc.num_inputs {
+        return Err(InvocationError::WrongNumberOfArguments {
+            expected: libfunc.num_inputs,
+            actual: builder.refs.len(),
+        });
+    }
+    let code =
+        parse_straight_line_code(&libfunc.code).map_err(|_| InvocationError::InvalidGenericArg)?;
+    let mut casm_builder = CasmBuilder::default();
+    let mut inputs = vec![];
+    for reference in builder.refs {
+        let input = casm_builder.add_var(reference.expression.try_unpack_single()?.clone());
+        casm_build_extend!(casm_builder, tempvar input_copy = input;);
+        inputs.push(input_copy);
+    }
+    let output_vars = casm_builder
+        .inline_straight_line_code(code, &inputs, libfunc.num_outputs)
+        .into_iter()
+        .map(|var| [var])
+        .collect_vec();
+    let outputs: Vec<&[Var]> = output_vars.iter().map(|vars| &vars[..]).collect();
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &outputs, None)],
+        Default::default(),
+    ))
+}
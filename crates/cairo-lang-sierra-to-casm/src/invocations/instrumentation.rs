@@ -0,0 +1,35 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::instrumentation::InstrumentationConcreteLibfunc;
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+
+/// Builds Casm instructions for instrumentation operations.
+pub fn build(
+    libfunc: &InstrumentationConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        InstrumentationConcreteLibfunc::Hit(libfunc) => build_hit(builder, &libfunc.id),
+    }
+}
+
+/// Builds Casm instructions for the `instrumentation_hit<id>()` libfunc.
+fn build_hit(
+    builder: CompiledInvocationBuilder<'_>,
+    id: &BigInt,
+) -> Result<CompiledInvocation, InvocationError> {
+    let mut casm_builder = CasmBuilder::default();
+    casm_build_extend! {casm_builder,
+        const id = id.clone();
+        hint InstrumentationHit {id: id} into {};
+        // Since we can't have hints not carried on actual instructions.
+        ap += 0;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[], None)],
+        Default::default(),
+    ))
+}
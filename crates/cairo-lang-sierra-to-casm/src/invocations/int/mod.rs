@@ -1,6 +1,7 @@
 use cairo_lang_casm::builder::CasmBuilder;
 use cairo_lang_casm::casm_build_extend;
 use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_sierra::extensions::int::unsigned::UintUncheckedOperator;
 use cairo_lang_sierra::extensions::int::{IntConstConcreteLibfunc, IntTraits};
 
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
@@ -13,6 +14,7 @@ pub mod unsigned;
 pub mod unsigned128;
 pub mod unsigned256;
 pub mod unsigned512;
+pub mod unsigned96;
 
 /// Builds invocations for uint const values.
 fn build_const<TIntTraits: IntTraits>(
@@ -45,3 +47,36 @@ pub fn build_small_wide_mul(
         CostValidationInfo::default(),
     ))
 }
+
+/// Handles an unchecked uint operation, computed as a felt252 operation without verifying that the
+/// result is in the range of the type.
+pub fn build_uint_unchecked_op(
+    builder: CompiledInvocationBuilder<'_>,
+    operator: UintUncheckedOperator,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [a, b] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref a;
+        deref_or_immediate b;
+    };
+    let res = match operator {
+        UintUncheckedOperator::Add => {
+            casm_build_extend!(casm_builder, let res = a + b;);
+            res
+        }
+        UintUncheckedOperator::Sub => {
+            casm_build_extend!(casm_builder, let res = a - b;);
+            res
+        }
+        UintUncheckedOperator::Mul => {
+            casm_build_extend!(casm_builder, let res = a * b;);
+            res
+        }
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[res]], None)],
+        CostValidationInfo::default(),
+    ))
+}
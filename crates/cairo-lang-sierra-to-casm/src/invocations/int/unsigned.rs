@@ -8,8 +8,8 @@ use cairo_lang_sierra::extensions::int::{IntMulTraits, IntOperator};
 use cairo_lang_sierra::extensions::is_zero::IsZeroTraits;
 use num_bigint::{BigInt, ToBigInt};
 
-use super::{build_const, build_small_wide_mul};
-use crate::invocations::misc::validate_under_limit;
+use super::{build_const, build_small_wide_mul, build_uint_unchecked_op};
+use crate::invocations::misc::BoundValidation;
 use crate::invocations::{
     add_input_variables, bitwise, get_non_fallthrough_statement_id, misc, CompiledInvocation,
     CompiledInvocationBuilder, CostValidationInfo, InvocationError,
@@ -17,7 +17,7 @@ use crate::invocations::{
 
 /// Handles a small uint overflowing add operation.
 /// All parameters values are smaller than `limit`.
-fn build_small_uint_overflowing_add(
+pub fn build_small_uint_overflowing_add(
     builder: CompiledInvocationBuilder<'_>,
     limit: u128,
 ) -> Result<CompiledInvocation, InvocationError> {
@@ -110,70 +110,6 @@ fn build_small_uint_overflowing_sub(
     ))
 }
 
-/// Handles a small uint conversion from felt252.
-fn build_small_uint_from_felt252<const LIMIT: u128, const K: u8>(
-    builder: CompiledInvocationBuilder<'_>,
-) -> Result<CompiledInvocation, InvocationError> {
-    let [range_check, value] = builder.try_get_single_cells()?;
-    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
-    let mut casm_builder = CasmBuilder::default();
-    add_input_variables! {casm_builder,
-        buffer(2) range_check;
-        deref value;
-    };
-    casm_build_extend! {casm_builder,
-        let orig_range_check = range_check;
-        const limit = LIMIT;
-        tempvar is_small;
-        hint TestLessThan {lhs: value, rhs: limit} into {dst: is_small};
-        jump IsSmall if is_small != 0;
-        tempvar shifted_value = value - limit;
-    }
-    match K {
-        1 => {
-            let auxiliary_vars: [_; 4] = std::array::from_fn(|_| casm_builder.alloc_var(false));
-            validate_under_limit::<K>(
-                &mut casm_builder,
-                &(-Felt252::from(LIMIT)).to_biguint().to_bigint().unwrap(),
-                shifted_value,
-                range_check,
-                &auxiliary_vars,
-            );
-            casm_build_extend! {casm_builder, jump Done;};
-        }
-        2 => {
-            let auxiliary_vars: [_; 5] = std::array::from_fn(|_| casm_builder.alloc_var(false));
-            validate_under_limit::<K>(
-                &mut casm_builder,
-                &(-Felt252::from(LIMIT)).to_biguint().to_bigint().unwrap(),
-                shifted_value,
-                range_check,
-                &auxiliary_vars,
-            );
-        }
-        _ => unreachable!("Only K value of 1 or 2 are supported."),
-    }
-    casm_build_extend! {casm_builder,
-        IsSmall:
-        assert value = *(range_check++);
-        // value + 2**128 - limit < 2**128 ==> value < limit
-        const fixer_limit = (u128::MAX - LIMIT + 1);
-        tempvar value_upper_limit = value + fixer_limit;
-        assert value_upper_limit = *(range_check++);
-    };
-    Ok(builder.build_from_casm_builder(
-        casm_builder,
-        [
-            ("Fallthrough", &[&[range_check], &[value]], None),
-            ("Done", &[&[range_check]], Some(failure_handle_statement_id)),
-        ],
-        CostValidationInfo {
-            range_check_info: Some((orig_range_check, range_check)),
-            extra_costs: None,
-        },
-    ))
-}
-
 /// Handles a small uint conversion from felt252.
 fn build_divmod<const BOUND: u128>(
     builder: CompiledInvocationBuilder<'_>,
@@ -299,8 +235,15 @@ pub fn build_uint<TUintTraits: UintTraits + IntMulTraits + IsZeroTraits, const L
                 build_small_uint_overflowing_sub(builder, BigInt::from(LIMIT))
             }
         },
+        UintConcrete::UncheckedOperation(libfunc) => {
+            build_uint_unchecked_op(builder, libfunc.operator)
+        }
         UintConcrete::ToFelt252(_) => misc::build_identity(builder),
-        UintConcrete::FromFelt252(_) => build_small_uint_from_felt252::<LIMIT, 2>(builder),
+        UintConcrete::FromFelt252(_) => misc::build_try_from_felt252_under_bound(
+            builder,
+            BigInt::from(LIMIT),
+            BoundValidation::Small,
+        ),
         UintConcrete::IsZero(_) => misc::build_is_zero(builder),
         UintConcrete::Divmod(_) => build_divmod::<LIMIT>(builder),
         UintConcrete::Bitwise(_) => bitwise::build(builder),
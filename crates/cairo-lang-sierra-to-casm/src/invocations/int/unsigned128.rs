@@ -5,7 +5,7 @@ use cairo_lang_sierra::extensions::int::IntOperator;
 use num_bigint::BigInt;
 use num_traits::{Num, One};
 
-use super::build_const;
+use super::{build_const, build_uint_unchecked_op};
 use crate::invocations::{
     add_input_variables, bitwise, get_non_fallthrough_statement_id, misc, CompiledInvocation,
     CompiledInvocationBuilder, CostValidationInfo, InvocationError,
@@ -21,6 +21,9 @@ pub fn build(
             IntOperator::OverflowingAdd => build_u128_overflowing_add(builder),
             IntOperator::OverflowingSub => build_u128_overflowing_sub(builder),
         },
+        Uint128Concrete::UncheckedOperation(libfunc) => {
+            build_uint_unchecked_op(builder, libfunc.operator)
+        }
         Uint128Concrete::Divmod(_) => build_u128_divmod(builder),
         Uint128Concrete::GuaranteeMul(_) => build_u128_guarantee_mul(builder),
         Uint128Concrete::MulGuaranteeVerify(_) => build_u128_mul_guarantee_verify(builder),
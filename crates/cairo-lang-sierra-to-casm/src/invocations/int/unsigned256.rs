@@ -17,6 +17,7 @@ pub fn build(
         Uint256Concrete::IsZero(_) => build_u256_is_zero(builder),
         Uint256Concrete::Divmod(_) => build_u256_divmod(builder),
         Uint256Concrete::SquareRoot(_) => build_u256_sqrt(builder),
+        Uint256Concrete::InvModN(_) => build_u256_inv_mod_n(builder),
     }
 }
 
@@ -324,3 +325,192 @@ fn build_u256_sqrt(
         },
     ))
 }
+
+/// Generates casm instructions for `u256_guarantee_inv_mod_n`.
+fn build_u256_inv_mod_n(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, b, n] = builder.try_get_refs()?;
+    let [range_check] = range_check.try_unpack()?;
+    let [b0, b1] = b.try_unpack()?;
+    let [n0, n1] = n.try_unpack()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(10) range_check;
+        deref b0;
+        deref b1;
+        deref n0;
+        deref n1;
+    };
+
+    casm_build_extend! {casm_builder,
+        const zero = 0;
+        const one = 1;
+        const u128_bound_minus_i16_upper_bound = u128::MAX - i16::MAX as u128;
+        const i16_lower_bound = BigInt::from(i16::MIN);
+        const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+        let orig_range_check = range_check;
+
+        // Whether the inverse exists.
+        tempvar has_inverse;
+        // The inverse if it exists, or a witness that it does not.
+        tempvar x0;
+        tempvar x1;
+        // The multiple of `n`, such that `b * x - n * y = has_inverse`.
+        tempvar y0;
+        tempvar y1;
+
+        // Find the inverse in a hint.
+        hint U256InvModN {
+            b0: b0,
+            b1: b1,
+            n0: n0,
+            n1: n1
+        } into {
+            has_inverse: has_inverse,
+            x0: x0,
+            x1: x1,
+            y0: y0,
+            y1: y1
+        };
+
+        // Verify the hint ranges.
+        assert x0 = *(range_check++);
+        assert x1 = *(range_check++);
+        assert y0 = *(range_check++);
+        assert y1 = *(range_check++);
+        assert has_inverse = has_inverse * has_inverse;
+
+        // Assert `x` is less than `n`.
+        tempvar diff1 = n1 - x1;
+        tempvar diff0 = n0 - x0;
+        tempvar diff0_min_1 = diff0 - one;
+        jump HighDiff if diff1 != 0;
+        assert diff0_min_1 = *(range_check++);
+        jump After;
+    HighDiff:
+        assert diff1 = *(range_check++);
+    After:
+    }
+    // Do basic calculations.
+    casm_build_extend! {casm_builder,
+        tempvar b0x0_low;
+        tempvar b0x0_high;
+        hint WideMul128 { lhs: b0, rhs: x0 } into { low: b0x0_low, high: b0x0_high };
+        tempvar b0x1_low;
+        tempvar b0x1_high;
+        hint WideMul128 { lhs: b0, rhs: x1 } into { low: b0x1_low, high: b0x1_high };
+        tempvar b1x0_low;
+        tempvar b1x0_high;
+        hint WideMul128 { lhs: b1, rhs: x0 } into { low: b1x0_low, high: b1x0_high };
+        tempvar b1x1_low;
+        tempvar b1x1_high;
+        hint WideMul128 { lhs: b1, rhs: x1 } into { low: b1x1_low, high: b1x1_high };
+        tempvar n0y0_low;
+        tempvar n0y0_high;
+        hint WideMul128 { lhs: n0, rhs: y0 } into { low: n0y0_low, high: n0y0_high };
+        tempvar n0y1_low;
+        tempvar n0y1_high;
+        hint WideMul128 { lhs: n0, rhs: y1 } into { low: n0y1_low, high: n0y1_high };
+        tempvar n1y0_low;
+        tempvar n1y0_high;
+        hint WideMul128 { lhs: n1, rhs: y0 } into { low: n1y0_low, high: n1y0_high };
+        tempvar n1y1_low;
+        tempvar n1y1_high;
+        hint WideMul128 { lhs: n1, rhs: y1 } into { low: n1y1_low, high: n1y1_high };
+        // Allocate the memory cells for the hints.
+        ap += 16;
+    }
+    casm_build_extend! {casm_builder,
+        // Validating `b * x - n * y - has_inverse = 0`.
+        // Validate limb0.
+        tempvar part0 = b0x0_low - n0y0_low;
+        tempvar part1 = part0 - has_inverse;
+        // Divide by 2**128 and check that we got an integer in [-2**15, 2**15).
+        // This validates that we couldn't have wrapped around the prime in the division.
+        tempvar leftover = part1 / u128_limit;
+        tempvar a = leftover + u128_bound_minus_i16_upper_bound;
+        assert a = *(range_check++);
+        tempvar a = leftover - i16_lower_bound;
+        assert a = *(range_check++);
+        // Validate limb1.
+        tempvar part0 = leftover + b0x0_high;
+        tempvar part1 = part0 + b0x1_low;
+        tempvar part2 = part1 + b1x0_low;
+        tempvar part3 = part2 - n0y0_high;
+        tempvar part4 = part3 - n0y1_low;
+        tempvar part5 = part4 - n1y0_low;
+        tempvar leftover = part5 / u128_limit;
+        tempvar a = leftover + u128_bound_minus_i16_upper_bound;
+        assert a = *(range_check++);
+        tempvar a = leftover - i16_lower_bound;
+        assert a = *(range_check++);
+        // Validate limb2.
+        tempvar part0 = leftover + b0x1_high;
+        tempvar part1 = part0 + b1x0_high;
+        tempvar part2 = part1 + b1x1_low;
+        tempvar part3 = part2 - n0y1_high;
+        tempvar part4 = part3 - n1y0_high;
+        tempvar part5 = part4 - n1y1_low;
+        tempvar leftover = part5 / u128_limit;
+        tempvar a = leftover + u128_bound_minus_i16_upper_bound;
+        assert a = *(range_check++);
+        tempvar a = leftover - i16_lower_bound;
+        assert a = *(range_check++);
+        // Validate limb3.
+        assert n1y1_high = leftover + b1x1_high;
+
+        jump Success if has_inverse != 0;
+        // `b * x = n * y` for `0 < x < n` proves that `b` has no inverse modulo `n`.
+        // `x` may only be 0 if `n` is 1, where no (non-zero) inverse exists either.
+        jump Failure if x0 != 0;
+        jump Failure if x1 != 0;
+        assert n1 = zero;
+        assert n0 = one;
+        jump Failure;
+    Success:
+    };
+
+    let target_statement_id = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            (
+                "Fallthrough",
+                &[
+                    &[range_check],
+                    &[x0, x1],
+                    &[b0, x0, b0x0_high, b0x0_low],
+                    &[b0, x1, b0x1_high, b0x1_low],
+                    &[b1, x0, b1x0_high, b1x0_low],
+                    &[b1, x1, b1x1_high, b1x1_low],
+                    &[n0, y0, n0y0_high, n0y0_low],
+                    &[n0, y1, n0y1_high, n0y1_low],
+                    &[n1, y0, n1y0_high, n1y0_low],
+                    &[n1, y1, n1y1_high, n1y1_low],
+                ],
+                None,
+            ),
+            (
+                "Failure",
+                &[
+                    &[range_check],
+                    &[b0, x0, b0x0_high, b0x0_low],
+                    &[b0, x1, b0x1_high, b0x1_low],
+                    &[b1, x0, b1x0_high, b1x0_low],
+                    &[b1, x1, b1x1_high, b1x1_low],
+                    &[n0, y0, n0y0_high, n0y0_low],
+                    &[n0, y1, n0y1_high, n0y1_low],
+                    &[n1, y0, n1y0_high, n1y0_low],
+                    &[n1, y1, n1y1_high, n1y1_low],
+                ],
+                Some(target_statement_id),
+            ),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
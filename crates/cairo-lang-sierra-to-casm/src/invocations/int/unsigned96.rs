@@ -0,0 +1,27 @@
+use cairo_lang_sierra::extensions::int::unsigned96::{Uint96Concrete, U96};
+use num_bigint::BigInt;
+
+use super::unsigned::build_small_uint_overflowing_add;
+use super::{build_const, build_small_wide_mul};
+use crate::invocations::misc::BoundValidation;
+use crate::invocations::{misc, CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+
+/// Builds instructions for Sierra u96 operations.
+pub fn build(
+    libfunc: &Uint96Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Uint96Concrete::Const(libfunc) => build_const(libfunc, builder),
+        Uint96Concrete::Equal(_) => misc::build_cell_eq(builder),
+        Uint96Concrete::ToFelt252(_) => misc::build_identity(builder),
+        Uint96Concrete::FromFelt252(_) => misc::build_try_from_felt252_under_bound(
+            builder,
+            BigInt::from(U96::LIMIT),
+            BoundValidation::Small,
+        ),
+        Uint96Concrete::IsZero(_) => misc::build_is_zero(builder),
+        Uint96Concrete::OverflowingAdd(_) => build_small_uint_overflowing_add(builder, U96::LIMIT),
+        Uint96Concrete::WideMul(_) => build_small_wide_mul(builder),
+    }
+}
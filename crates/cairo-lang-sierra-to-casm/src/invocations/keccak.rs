@@ -0,0 +1,206 @@
+use cairo_lang_casm::builder::{CasmBuilder, Var};
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::keccak::{KeccakConcreteLibfunc, KECCAK_STATE_LANES};
+use itertools::{zip_eq, Itertools};
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{add_input_variables, CostValidationInfo};
+
+#[cfg(test)]
+#[path = "keccak_test.rs"]
+mod test;
+
+/// The number of bits in a lane of the Keccak state.
+const LANE_BITS: usize = 64;
+/// The number of bits in a word of the Keccak builtin.
+const WORD_BITS: usize = 200;
+/// The number of input words of an instance of the Keccak builtin, which is also the number of its
+/// output words.
+const N_WORDS: usize = 8;
+
+/// The part of a lane of the state held by a single word of the builtin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct LanePart {
+    lane: usize,
+    word: usize,
+    width: usize,
+}
+
+/// Returns the parts of the lanes of the state, from its lowest bits.
+///
+/// Both the lanes and the words of the builtin hold the state in little-endian order, so a lane
+/// crossing the boundary between two words is split into a low part and a high part.
+fn lane_parts() -> Vec<LanePart> {
+    let mut parts = vec![];
+    let mut bit = 0;
+    while bit < N_WORDS * WORD_BITS {
+        let lane = bit / LANE_BITS;
+        let word = bit / WORD_BITS;
+        let end = ((lane + 1) * LANE_BITS).min((word + 1) * WORD_BITS);
+        parts.push(LanePart { lane, word, width: end - bit });
+        bit = end;
+    }
+    parts
+}
+
+/// Builds instructions for Sierra Keccak operations.
+pub fn build(
+    libfunc: &KeccakConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        KeccakConcreteLibfunc::Permutation(_) => build_keccak_permutation(builder),
+    }
+}
+
+/// Handles instruction for computing the Keccak-f[1600] permutation over 25 u64 lanes.
+///
+/// The lanes are packed into the 8 input words of the builtin, splitting the lanes crossing word
+/// boundaries. The output words are then split back into lanes by hints, and the parts are range
+/// checked and repacked to be compared with the words the builtin computed.
+fn build_keccak_permutation(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, keccak, lanes @ ..] =
+        builder.try_get_single_cells::<{ 2 + KECCAK_STATE_LANES }>()?;
+    let parts = lane_parts();
+    // Both parts of each split input lane, and every part of the output, are range checked twice.
+    let n_split_lanes = parts.len() - KECCAK_STATE_LANES;
+    let n_range_checks = 2 * (2 * n_split_lanes + parts.len());
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(n_range_checks as i16) range_check;
+        buffer((2 * N_WORDS) as i16) keccak;
+    };
+    let mut lane_vars = vec![];
+    for lane in lanes {
+        add_input_variables!(casm_builder, deref lane;);
+        lane_vars.push(lane);
+    }
+    casm_build_extend!(casm_builder, let orig_range_check = range_check;);
+
+    // Split the lanes crossing word boundaries.
+    let mut part_vars = vec![];
+    for (lane, lane_var) in lane_vars.into_iter().enumerate() {
+        match parts.iter().filter(|part| part.lane == lane).collect_vec()[..] {
+            [_] => part_vars.push(lane_var),
+            [low_part, high_part] => {
+                casm_build_extend! {casm_builder,
+                    const low_bound = BigInt::from(1) << low_part.width;
+                    tempvar high;
+                    tempvar low;
+                    hint DivMod {lhs: lane_var, rhs: low_bound} into {
+                        quotient: high, remainder: low
+                    };
+                };
+                validate_width(&mut casm_builder, range_check, low, low_part.width);
+                validate_width(&mut casm_builder, range_check, high, high_part.width);
+                casm_build_extend! {casm_builder,
+                    tempvar shifted_high = high * low_bound;
+                    assert lane_var = shifted_high + low;
+                };
+                part_vars.extend([low, high]);
+            }
+            _ => unreachable!("A lane is held by at most two words."),
+        }
+    }
+    // Write the input words.
+    for word in 0..N_WORDS {
+        let word_parts = zip_eq(&parts, &part_vars)
+            .filter(|(part, _)| part.word == word)
+            .map(|(part, var)| (part.width, *var))
+            .collect_vec();
+        let packed = pack_parts(&mut casm_builder, &word_parts);
+        casm_build_extend!(casm_builder, assert packed = *(keccak++););
+    }
+    // Split the output words into the parts of the lanes, and verify the split.
+    let mut output_part_vars = vec![];
+    for word in 0..N_WORDS {
+        let widths =
+            parts.iter().filter(|part| part.word == word).map(|part| part.width).collect_vec();
+        // Copying the word makes the builtin deduce it before the hints read it.
+        casm_build_extend!(casm_builder, tempvar word_var = *(keccak++););
+        let mut word_part_vars = vec![];
+        let mut rest = word_var;
+        for width in &widths[..widths.len() - 1] {
+            casm_build_extend! {casm_builder,
+                const part_bound = BigInt::from(1) << *width;
+                tempvar part;
+                tempvar next_rest;
+                hint DivMod {lhs: rest, rhs: part_bound} into {
+                    quotient: next_rest, remainder: part
+                };
+            };
+            word_part_vars.push(part);
+            rest = next_rest;
+        }
+        word_part_vars.push(rest);
+        for (part, width) in zip_eq(&word_part_vars, &widths) {
+            validate_width(&mut casm_builder, range_check, *part, *width);
+        }
+        // As the parts are range checked, the repacked value is smaller than `2**200`, so it is
+        // equal to the word only if the parts are its split.
+        let word_parts = zip_eq(widths, word_part_vars.iter().copied()).collect_vec();
+        let packed = pack_parts(&mut casm_builder, &word_parts);
+        casm_build_extend!(casm_builder, assert packed = word_var;);
+        output_part_vars.extend(word_part_vars);
+    }
+    // Join the parts of the output lanes crossing word boundaries.
+    let mut output_lane_vars = vec![];
+    for lane in 0..KECCAK_STATE_LANES {
+        let parts_of_lane =
+            zip_eq(&parts, &output_part_vars).filter(|(part, _)| part.lane == lane).collect_vec();
+        match parts_of_lane[..] {
+            [(_, lane_var)] => output_lane_vars.push([*lane_var]),
+            [(low_part, low), (_, high)] => {
+                let (low, high) = (*low, *high);
+                casm_build_extend! {casm_builder,
+                    const low_bound = BigInt::from(1) << low_part.width;
+                    tempvar shifted_high = high * low_bound;
+                    tempvar joined = shifted_high + low;
+                };
+                output_lane_vars.push([joined]);
+            }
+            _ => unreachable!("A lane is held by at most two words."),
+        }
+    }
+
+    let output_vars = [[range_check], [keccak]].into_iter().chain(output_lane_vars).collect_vec();
+    let success_vars = output_vars.iter().map(|vars| &vars[..]).collect_vec();
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &success_vars, None)],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Adds the range checks verifying that `value` is smaller than `2**width`, for `width < 128`.
+fn validate_width(casm_builder: &mut CasmBuilder, range_check: Var, value: Var, width: usize) {
+    casm_build_extend! {casm_builder,
+        const shift = (BigInt::from(1) << 128) - (BigInt::from(1) << width);
+        assert value = *(range_check++);
+        tempvar shifted_value = value + shift;
+        assert shifted_value = *(range_check++);
+    };
+}
+
+/// Packs parts of the given widths, starting from the lowest bits, into a single value.
+fn pack_parts(casm_builder: &mut CasmBuilder, parts: &[(usize, Var)]) -> Var {
+    let ((_, highest), lower) = parts.split_last().expect("No parts to pack.");
+    let mut packed = *highest;
+    for (width, part) in lower.iter().rev() {
+        let part = *part;
+        casm_build_extend! {casm_builder,
+            const shift = BigInt::from(1) << *width;
+            tempvar shifted = packed * shift;
+            tempvar next_packed = shifted + part;
+        };
+        packed = next_packed;
+    }
+    packed
+}
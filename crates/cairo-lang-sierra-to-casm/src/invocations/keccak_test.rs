@@ -0,0 +1,60 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_casm::operand::{CellRef, Register};
+use itertools::{chain, Itertools};
+use pretty_assertions::assert_eq;
+
+use super::{lane_parts, LanePart, LANE_BITS, N_WORDS, WORD_BITS};
+use crate::invocations::test_utils::{compile_libfunc, ReducedBranchChanges};
+use crate::ref_expr;
+use crate::references::ReferenceExpression;
+
+/// Returns a reference expression to the given cell.
+fn cell_ref(register: Register, offset: i16) -> ReferenceExpression {
+    ReferenceExpression::from_cell(CellExpression::Deref(CellRef { register, offset }))
+}
+
+#[test]
+fn test_lane_parts() {
+    let parts = lane_parts();
+    assert_eq!(parts.iter().map(|part| part.width).sum::<usize>(), N_WORDS * WORD_BITS);
+    // The lanes crossing word boundaries.
+    assert_eq!(
+        parts.iter().filter(|part| part.width < LANE_BITS).copied().collect_vec(),
+        [(3, 8), (6, 16), (9, 24), (12, 32), (15, 40), (18, 48), (21, 56)]
+            .into_iter()
+            .enumerate()
+            .flat_map(|(word, (lane, low_width))| [
+                LanePart { lane, word, width: low_width },
+                LanePart { lane, word: word + 1, width: LANE_BITS - low_width },
+            ])
+            .collect_vec()
+    );
+}
+
+#[test]
+fn test_keccak_permutation() {
+    let compiled = compile_libfunc(
+        "keccak_permutation",
+        chain!(
+            [ref_expr!([fp + 1]), ref_expr!([fp + 2])],
+            (3..28).map(|i| cell_ref(Register::FP, i))
+        )
+        .collect(),
+    );
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: chain!(
+                [ref_expr!([fp + 1] + 92), ref_expr!([fp + 2] + 16)],
+                [
+                    -149, -147, -145, -13, -130, -128, -11, -113, -111, -9, -96, -94, -7, -79, -77,
+                    -5, -62, -60, -3, -45, -43, -1, -28, -26, -25
+                ]
+                .map(|offset| cell_ref(Register::AP, offset))
+            )
+            .collect(),
+            ap_change: ApChange::Known(233),
+        }]
+    );
+}
@@ -0,0 +1,281 @@
+use cairo_lang_casm::builder::{CasmBuildResult, CasmBuilder};
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra::extensions::merkle::MerkleHashTraits;
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
+use cairo_lang_sierra_ap_change::core_libfunc_ap_change::core_libfunc_ap_change;
+use cairo_lang_sierra_ap_change::ApChange;
+use cairo_lang_sierra_gas::core_libfunc_cost::{
+    MERKLE_VERIFY_PEDERSEN_FIXED_COST, MERKLE_VERIFY_PEDERSEN_PER_LEVEL_COST,
+    MERKLE_VERIFY_POSEIDON_FIXED_COST, MERKLE_VERIFY_POSEIDON_PER_LEVEL_COST,
+};
+use cairo_lang_sierra_gas::objects::ConstCost;
+use itertools::{chain, Itertools};
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::add_input_variables;
+use crate::references::ReferenceExpression;
+use crate::relocations::{Relocation, RelocationEntry};
+
+/// Handles a Sierra statement for verifying a Merkle path from a leaf to a root.
+/// The path is hashed by a loop, whose cost, including the cost of the hash builtin taken from the
+/// builtin costs, is withdrawn from the gas builtin at runtime.
+pub fn build_merkle_verify<TMerkleHashTraits: MerkleHashTraits>(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let (per_level_const_cost, fixed_const_cost) = match TMerkleHashTraits::COST_TOKEN_TYPE {
+        CostTokenType::Pedersen => {
+            (MERKLE_VERIFY_PEDERSEN_PER_LEVEL_COST, MERKLE_VERIFY_PEDERSEN_FIXED_COST)
+        }
+        CostTokenType::Poseidon => {
+            (MERKLE_VERIFY_POSEIDON_PER_LEVEL_COST, MERKLE_VERIFY_POSEIDON_FIXED_COST)
+        }
+        token_type => panic!("Unsupported Merkle hash builtin: {}.", token_type.name()),
+    };
+    let [
+        expr_range_check,
+        expr_gas_builtin,
+        expr_hash_builtin,
+        expr_builtin_costs,
+        expr_leaf,
+        expr_index,
+        expr_path,
+        expr_root,
+    ] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let hash_builtin = expr_hash_builtin.try_unpack_single()?;
+    let builtin_costs = expr_builtin_costs.try_unpack_single()?;
+    let leaf = expr_leaf.try_unpack_single()?;
+    let index = expr_index.try_unpack_single()?;
+    let [path_start, path_end] = expr_path.try_unpack()?;
+    let root = expr_root.try_unpack_single()?;
+    let (invalid_statement_id, failure_handle_statement_id) =
+        match builder.invocation.branches.as_slice() {
+            [
+                BranchInfo { target: BranchTarget::Fallthrough, .. },
+                BranchInfo { target: BranchTarget::Statement(invalid_statement_id), .. },
+                BranchInfo { target: BranchTarget::Statement(failure_handle_statement_id), .. },
+            ] => (*invalid_statement_id, *failure_handle_statement_id),
+            _ => panic!("malformed invocation"),
+        };
+    let hash_cost_offset = TMerkleHashTraits::COST_TOKEN_TYPE.offset_in_builtin_costs();
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_level_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref gas_builtin;
+        deref hash_builtin;
+        deref builtin_costs;
+        deref leaf;
+        deref index;
+        deref path_start;
+        deref path_end;
+        deref root;
+    };
+    casm_build_extend! {casm_builder,
+        const per_level_cost = per_level_const_cost.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar hash_cost = builtin_costs[hash_cost_offset];
+        tempvar level_cost = hash_cost + per_level_cost;
+        tempvar n_levels = path_end - path_start;
+        tempvar required_gas = n_levels * level_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        jump NonEmpty if n_levels != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the hashing function.
+        tempvar verify_range_check = range_check;
+        tempvar verify_hash_builtin = hash_builtin;
+        tempvar verify_leaf = leaf;
+        tempvar verify_index = index;
+        tempvar verify_path = path_start;
+        tempvar verify_root = root;
+        let (root_diff, remaining_index, final_range_check, final_gas_builtin, final_hash_builtin) =
+            call MerkleVerify;
+        jump Done;
+        Empty:
+        // The leaf is the root - return the values in the same layout as the hashing function.
+        tempvar empty_root_diff = leaf - root;
+        tempvar empty_remaining_index = index;
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        tempvar empty_hash_builtin = hash_builtin;
+        rescope {
+            root_diff = empty_root_diff,
+            remaining_index = empty_remaining_index,
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_hash_builtin = empty_hash_builtin
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Hashes `verify_leaf` with the `n_levels` siblings starting at `verify_path`, where
+        // `n_levels` is not 0. Returns the difference between the computed root and `verify_root`,
+        // and the bits of `verify_index` left after consuming a bit per level.
+        MerkleVerify:
+        tempvar loop_range_check = verify_range_check;
+        tempvar loop_hash_builtin = verify_hash_builtin;
+        tempvar node = verify_leaf;
+        tempvar node_index = verify_index;
+        tempvar path = verify_path;
+        tempvar remaining = n_levels;
+        rescope {
+            loop_range_check = loop_range_check,
+            loop_hash_builtin = loop_hash_builtin,
+            node = node,
+            node_index = node_index,
+            path = path,
+            remaining = remaining,
+            updated_gas = updated_gas,
+            verify_root = verify_root
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        MerkleVerifyLoop:
+        const one = 1;
+        const two = 2;
+        tempvar sibling = path[0];
+        // Split the lowest bit of `node_index`: `bit` is a bit, and `parent_index` is small, so
+        // `node_index = 2 * parent_index + bit` holds over the integers.
+        tempvar parent_index;
+        tempvar bit;
+        hint DivMod {lhs: node_index, rhs: two} into {quotient: parent_index, remainder: bit};
+        tempvar bit_squared = bit * bit;
+        assert bit_squared = bit;
+        tempvar twice_parent_index = parent_index * two;
+        assert node_index = twice_parent_index + bit;
+        assert parent_index = *(loop_range_check++);
+        // The node is the left child if `bit` is 0, and the right child otherwise.
+        tempvar sibling_diff = sibling - node;
+        tempvar bit_sibling_diff = bit * sibling_diff;
+        tempvar left = node + bit_sibling_diff;
+        tempvar right = sibling - bit_sibling_diff;
+    };
+    let parent = match TMerkleHashTraits::COST_TOKEN_TYPE {
+        CostTokenType::Pedersen => {
+            casm_build_extend! {casm_builder,
+                assert left = *(loop_hash_builtin++);
+                assert right = *(loop_hash_builtin++);
+                let parent = *(loop_hash_builtin++);
+            };
+            parent
+        }
+        _ => {
+            // The Poseidon hash of two elements is the first element of the permutation of
+            // `(left, right, 2)`.
+            casm_build_extend! {casm_builder,
+                tempvar capacity = two;
+                assert left = *(loop_hash_builtin++);
+                assert right = *(loop_hash_builtin++);
+                assert capacity = *(loop_hash_builtin++);
+                let parent = *(loop_hash_builtin++);
+                let _s1 = *(loop_hash_builtin++);
+                let _s2 = *(loop_hash_builtin++);
+            };
+            parent
+        }
+    };
+    casm_build_extend! {casm_builder,
+        tempvar next_range_check = loop_range_check;
+        tempvar next_hash_builtin = loop_hash_builtin;
+        tempvar next_node = parent;
+        tempvar next_node_index = parent_index;
+        tempvar next_path = path + one;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            loop_range_check = next_range_check,
+            loop_hash_builtin = next_hash_builtin,
+            node = next_node,
+            node_index = next_node_index,
+            path = next_path,
+            remaining = next_remaining,
+            updated_gas = updated_gas,
+            verify_root = verify_root
+        };
+        jump MerkleVerifyLoop if remaining != 0;
+        #{ per_level_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_root_diff = node - verify_root;
+        tempvar returned_remaining_index = node_index;
+        tempvar returned_range_check = loop_range_check;
+        tempvar returned_gas_builtin = updated_gas;
+        tempvar returned_hash_builtin = loop_hash_builtin;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        // The path is valid only if it leads to the root, and consumed all the bits of the index.
+        jump Invalid if root_diff != 0;
+        jump Invalid if remaining_index != 0;
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(ConstCost { steps: fixed_steps, holes: 0, range_checks: 1 }, fixed_const_cost);
+    assert_eq!(
+        ConstCost { steps: per_level_steps, holes: 0, range_checks: 1 },
+        per_level_const_cost
+    );
+    let CasmBuildResult {
+        instructions,
+        branches:
+            [(state, _), (invalid_state, invalid_relocations), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Invalid", "Failure"]);
+    assert_eq!(failure_state.steps, 9, "Must match the failure branch cost.");
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[2],
+        ApChange::Known(failure_state.ap_change)
+    );
+    // Relocations are applied in a single pass, so they must be sorted by instruction index.
+    let relocations = chain!(
+        invalid_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(invalid_statement_id),
+        }),
+        failure_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        }),
+    )
+    .sorted_by_key(|entry| entry.instruction_idx)
+    .collect();
+
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_hash_builtin)] },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![invalid_state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![invalid_state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression { cells: vec![invalid_state.get_adjusted(final_hash_builtin)] },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(hash_builtin)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
@@ -1,15 +1,17 @@
+use cairo_felt::Felt252;
 use cairo_lang_casm::builder::{CasmBuilder, Var};
 use cairo_lang_casm::cell_expression::CellExpression;
 use cairo_lang_casm::{casm, casm_build_extend};
 use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
 use cairo_lang_sierra_gas::objects::ConstCost;
 use itertools::Itertools;
-use num_bigint::BigInt;
+use num_bigint::{BigInt, ToBigInt};
 
 use super::{
     get_non_fallthrough_statement_id, CompiledInvocation, CompiledInvocationBuilder,
-    InvocationError,
+    CostValidationInfo, InvocationError,
 };
+use crate::compiler::VmVersion;
 use crate::invocations::add_input_variables;
 use crate::references::ReferenceExpression;
 use crate::relocations::{InstructionsWithRelocations, Relocation, RelocationEntry};
@@ -191,19 +193,23 @@ pub fn validate_under_limit<const K: u8>(
             let (x, y, x_part, y_fixed) =
                 auxiliary_vars.iter().cloned().collect_tuple().expect("Wrong amount of vars.");
             casm_build_extend! {casm_builder,
-                hint LinearSplit {
-                    value: value,
-                    scalar: a_imm, max_x: u128_limit_minus_1
-                } into {x: x, y: y};
-                assert x_part = x * a_imm;
-                assert value = x_part + y;
-                // x <= max_x = 2**128 - 1
-                assert x = *(range_check++);
-                // y < 2**128
-                assert y = *(range_check++);
-                // y + 2**128 - B < 2**128 ==> y < B
-                assert y_fixed = y + b_imm_fix;
-                assert y_fixed = *(range_check++);
+                nondet {
+                    guess hint LinearSplit {
+                        value: value,
+                        scalar: a_imm, max_x: u128_limit_minus_1
+                    } into {x: x, y: y};
+                    verify {
+                        assert x_part = x * a_imm;
+                        assert value = x_part + y;
+                        // x <= max_x = 2**128 - 1
+                        assert x = *(range_check++);
+                        // y < 2**128
+                        assert y = *(range_check++);
+                        // y + 2**128 - B < 2**128 ==> y < B
+                        assert y_fixed = y + b_imm_fix;
+                        assert y_fixed = *(range_check++);
+                    }
+                };
             };
         }
         2 => {
@@ -211,19 +217,24 @@ pub fn validate_under_limit<const K: u8>(
                 auxiliary_vars.iter().cloned().collect_tuple().expect("Wrong amount of vars.");
             casm_build_extend! {casm_builder,
                 const u128_limit_minus_2 = u128::MAX - 1;
-                hint LinearSplit {
-                    value: value,
-                    scalar: a_imm, max_x: u128_limit_minus_2
-                } into {x: x, y: y};
-                assert x_part = x * a_imm;
-                assert value = x_part + y;
-                // y < 2**128
-                assert y = *(range_check++);
-                // y + 2**128 - B < 2**128 ==> y < B
-                assert y_fixed = y + b_imm_fix;
-                assert y_fixed = *(range_check++);
+                nondet {
+                    guess hint LinearSplit {
+                        value: value,
+                        scalar: a_imm, max_x: u128_limit_minus_2
+                    } into {x: x, y: y};
+                    verify {
+                        assert x_part = x * a_imm;
+                        assert value = x_part + y;
+                        // y < 2**128
+                        assert y = *(range_check++);
+                        // y + 2**128 - B < 2**128 ==> y < B
+                        assert y_fixed = y + b_imm_fix;
+                        assert y_fixed = *(range_check++);
+                        // x < 2**128
+                        assert x = *(range_check++);
+                    }
+                };
                 // x < 2**128 && x != 2**128 - 1 ==> x < 2**128 - 1
-                assert x = *(range_check++);
                 assert diff = x - u128_limit_minus_1;
                 jump Done if diff != 0;
                 fail;
@@ -233,6 +244,141 @@ pub fn validate_under_limit<const K: u8>(
     }
 }
 
+/// Adds a hint allocating memory for `size` cells into `dst`, carried by the next instruction.
+/// VMs before [VmVersion::V1] do not support the `AllocConstantSize` hint, so a new segment is
+/// allocated instead.
+pub fn add_alloc_constant_size_hint(
+    casm_builder: &mut CasmBuilder,
+    vm_version: VmVersion,
+    size: BigInt,
+    dst: Var,
+) {
+    if vm_version >= VmVersion::V1 {
+        casm_build_extend!(casm_builder,
+            const size = size;
+            hint AllocConstantSize { size: size } into { dst: dst };
+        );
+    } else {
+        casm_build_extend!(casm_builder, hint AllocSegment {} into { dst: dst };);
+    }
+}
+
+/// Validates that `value` is smaller than `PRIME / 2`, using 3 range checks.
+/// This makes the choice between `value` and `-value` deterministic, for example when guessing a
+/// square root.
+pub fn validate_under_half_prime(casm_builder: &mut CasmBuilder, value: Var, range_check: Var) {
+    let auxiliary_vars: [_; 4] = std::array::from_fn(|_| casm_builder.alloc_var(false));
+    validate_under_limit::<1>(
+        casm_builder,
+        // Note that `1/2 (mod PRIME) = (PRIME + 1) / 2 = ceil(PRIME / 2)`.
+        // Thus, `value < 1/2 (mod PRIME)` if and only if `value < PRIME / 2`.
+        &(Felt252::from(1) / Felt252::from(2)).to_biguint().to_bigint().unwrap(),
+        value,
+        range_check,
+        &auxiliary_vars,
+    );
+}
+
+/// The way [build_try_from_felt252_under_bound] validates that a value is under its bound.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoundValidation {
+    /// For bounds of at most 2**128 - a value under the bound is validated by range checking both
+    /// it and `value + 2**128 - bound`, and a value over the bound using [validate_under_limit]
+    /// with `K == 2`.
+    Small,
+    /// For any bound - both cases are validated using [validate_under_limit] with `K == 1`.
+    LinearSplit,
+}
+
+/// Builds a libfunc that tries to convert a felt252 into a type with values in the range
+/// [0, `bound`).
+///
+/// A hint guesses whether the value is under `bound`, and the guess is verified on both branches:
+/// on success `value` is validated to be under `bound`, and on failure `value - bound` is validated
+/// to be under `PRIME - bound`.
+pub fn build_try_from_felt252_under_bound(
+    builder: CompiledInvocationBuilder<'_>,
+    bound: BigInt,
+    validation: BoundValidation,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref value;
+    };
+    // The linear split variables are shared by both branches, so they are allocated upfront.
+    let linear_split_vars: Option<[_; 4]> = (validation == BoundValidation::LinearSplit)
+        .then(|| std::array::from_fn(|_| casm_builder.alloc_var(false)));
+    casm_build_extend! {casm_builder,
+        const limit = bound.clone();
+        let orig_range_check = range_check;
+        tempvar is_under_bound;
+        hint TestLessThan {lhs: value, rhs: limit} into {dst: is_under_bound};
+        jump UnderBound if is_under_bound != 0;
+        tempvar shifted_value = value - limit;
+    }
+    let shifted_limit = Felt252::prime().to_bigint().unwrap() - &bound;
+    match linear_split_vars {
+        None => {
+            let u128_limit = BigInt::from(u128::MAX) + 1;
+            assert!(
+                bound <= u128_limit,
+                "Small bound validation requires a bound of at most 2**128."
+            );
+            let auxiliary_vars: [_; 5] = std::array::from_fn(|_| casm_builder.alloc_var(false));
+            // Jumps to `Done` once validated.
+            validate_under_limit::<2>(
+                &mut casm_builder,
+                &shifted_limit,
+                shifted_value,
+                range_check,
+                &auxiliary_vars,
+            );
+            casm_build_extend! {casm_builder,
+                UnderBound:
+                assert value = *(range_check++);
+                // value + 2**128 - bound < 2**128 ==> value < bound
+                const fixer_limit = (u128_limit - bound) as BigInt;
+                tempvar value_upper_limit = value + fixer_limit;
+                assert value_upper_limit = *(range_check++);
+            };
+        }
+        Some(auxiliary_vars) => {
+            validate_under_limit::<1>(
+                &mut casm_builder,
+                &shifted_limit,
+                shifted_value,
+                range_check,
+                &auxiliary_vars,
+            );
+            casm_build_extend! {casm_builder,
+                jump Done;
+                UnderBound:
+            };
+            validate_under_limit::<1>(
+                &mut casm_builder,
+                &bound,
+                value,
+                range_check,
+                &auxiliary_vars,
+            );
+        }
+    }
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Done", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
 /// Helper function to generate code that computes a pointer after the end of the program code,
 /// at a given offset. This can be used for global constants.
 ///
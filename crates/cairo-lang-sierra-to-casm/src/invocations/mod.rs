@@ -21,6 +21,7 @@ use itertools::{chain, zip_eq, Itertools};
 use thiserror::Error;
 use {cairo_lang_casm, cairo_lang_sierra};
 
+use crate::compiler::{ConstsInfo, VmVersion};
 use crate::environment::frame_state::{FrameState, FrameStateError};
 use crate::environment::Environment;
 use crate::metadata::Metadata;
@@ -31,28 +32,45 @@ use crate::references::{
 use crate::relocations::{InstructionsWithRelocations, Relocation, RelocationEntry};
 
 mod array;
+mod bitfield;
 mod bitwise;
+mod blake;
 mod boolean;
+mod bounded_int;
 mod boxing;
+mod bytes31;
 mod casts;
+mod circuit;
+mod const_array;
 mod debug;
 mod ec;
+mod ecdsa;
+mod encoding;
 mod enm;
 mod felt252;
 mod felt252_dict;
 mod function_call;
 mod gas;
+mod inline_casm;
+mod instrumentation;
 mod int;
+mod keccak;
 mod mem;
+mod merkle;
 mod misc;
+mod modular;
 mod nullable;
+mod oracle;
 mod pedersen;
 mod poseidon;
+mod rlp;
 mod starknet;
 mod structure;
 
-#[cfg(test)]
-mod test_utils;
+#[cfg(any(feature = "testing", test))]
+pub mod test_utils;
+
+pub use encoding::EncodingTable;
 
 #[derive(Error, Debug, Eq, PartialEq)]
 pub enum InvocationError {
@@ -269,6 +287,10 @@ impl<'a> InvocationApChangeInfoProvider for CompiledInvocationBuilder<'a> {
             .copied()
             .unwrap_or(0) as usize
     }
+
+    fn software_ec_op(&self) -> bool {
+        self.program_info.metadata.software_ec_op
+    }
 }
 
 impl<'a> InvocationCostInfoProvider for CompiledInvocationBuilder<'a> {
@@ -289,6 +311,10 @@ impl<'a> InvocationCostInfoProvider for CompiledInvocationBuilder<'a> {
     fn token_usages(&self, token_type: CostTokenType) -> usize {
         InvocationApChangeInfoProvider::token_usages(self, token_type)
     }
+
+    fn software_ec_op(&self) -> bool {
+        InvocationApChangeInfoProvider::software_ec_op(self)
+    }
 }
 
 /// Information required for validating libfunc cost.
@@ -557,6 +583,10 @@ impl CompiledInvocationBuilder<'_> {
 pub struct ProgramInfo<'a> {
     pub metadata: &'a Metadata,
     pub type_sizes: &'a TypeSizeMap,
+    /// The const data segments of the program.
+    pub consts_info: &'a ConstsInfo,
+    /// The version of the VM the program is compiled for.
+    pub vm_version: VmVersion,
 }
 
 /// Given a Sierra invocation statement and concrete libfunc, creates a compiled casm representation
@@ -574,8 +604,16 @@ pub fn compile_invocation(
     match libfunc {
         CoreConcreteLibfunc::Felt252(libfunc) => felt252::build(libfunc, builder),
         CoreConcreteLibfunc::Bool(libfunc) => boolean::build(libfunc, builder),
+        CoreConcreteLibfunc::Bitfield(libfunc) => bitfield::build(libfunc, builder),
+        CoreConcreteLibfunc::Blake(libfunc) => blake::build(libfunc, builder),
+        CoreConcreteLibfunc::BoundedInt(libfunc) => bounded_int::build(libfunc, builder),
+        CoreConcreteLibfunc::Bytes31(libfunc) => bytes31::build(libfunc, builder),
         CoreConcreteLibfunc::Cast(libfunc) => casts::build(libfunc, builder),
+        CoreConcreteLibfunc::Circuit(libfunc) => circuit::build(libfunc, builder),
+        CoreConcreteLibfunc::ConstAsSpan(libfunc) => const_array::build(libfunc, builder),
         CoreConcreteLibfunc::Ec(libfunc) => ec::build(libfunc, builder),
+        CoreConcreteLibfunc::Ecdsa(libfunc) => ecdsa::build(libfunc, builder),
+        CoreConcreteLibfunc::Encoding(libfunc) => encoding::build(libfunc, builder),
         CoreConcreteLibfunc::Uint8(libfunc) => {
             int::unsigned::build_uint::<_, 0x100>(libfunc, builder)
         }
@@ -588,6 +626,7 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Uint64(libfunc) => {
             int::unsigned::build_uint::<_, 0x10000000000000000>(libfunc, builder)
         }
+        CoreConcreteLibfunc::Uint96(libfunc) => int::unsigned96::build(libfunc, builder),
         CoreConcreteLibfunc::Uint128(libfunc) => int::unsigned128::build(libfunc, builder),
         CoreConcreteLibfunc::Uint256(libfunc) => int::unsigned256::build(libfunc, builder),
         CoreConcreteLibfunc::Uint512(libfunc) => int::unsigned512::build(libfunc, builder),
@@ -597,11 +636,15 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Sint64(libfunc) => int::signed::build_sint(libfunc, builder),
         CoreConcreteLibfunc::Sint128(libfunc) => int::signed128::build(libfunc, builder),
         CoreConcreteLibfunc::Gas(libfunc) => gas::build(libfunc, builder),
+        CoreConcreteLibfunc::InlineCasm(libfunc) => inline_casm::build(libfunc, builder),
+        CoreConcreteLibfunc::Instrumentation(libfunc) => instrumentation::build(libfunc, builder),
+        CoreConcreteLibfunc::Keccak(libfunc) => keccak::build(libfunc, builder),
         CoreConcreteLibfunc::BranchAlign(_) => misc::build_branch_align(builder),
         CoreConcreteLibfunc::Array(libfunc) => array::build(libfunc, builder),
         CoreConcreteLibfunc::Drop(_) => misc::build_drop(builder),
         CoreConcreteLibfunc::Dup(_) => misc::build_dup(builder),
         CoreConcreteLibfunc::Mem(libfunc) => mem::build(libfunc, builder),
+        CoreConcreteLibfunc::Modular(libfunc) => modular::build(libfunc, builder),
         CoreConcreteLibfunc::UnwrapNonZero(_) => misc::build_identity(builder),
         CoreConcreteLibfunc::FunctionCall(libfunc) => function_call::build(libfunc, builder),
         CoreConcreteLibfunc::UnconditionalJump(_) => misc::build_jump(builder),
@@ -612,8 +655,10 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Felt252Dict(libfunc) => felt252_dict::build_dict(libfunc, builder),
         CoreConcreteLibfunc::Pedersen(libfunc) => pedersen::build(libfunc, builder),
         CoreConcreteLibfunc::Poseidon(libfunc) => poseidon::build(libfunc, builder),
+        CoreConcreteLibfunc::Rlp(libfunc) => rlp::build(libfunc, builder),
         CoreConcreteLibfunc::StarkNet(libfunc) => starknet::build(libfunc, builder),
         CoreConcreteLibfunc::Nullable(libfunc) => nullable::build(libfunc, builder),
+        CoreConcreteLibfunc::Oracle(libfunc) => oracle::build(libfunc, builder),
         CoreConcreteLibfunc::Debug(libfunc) => debug::build(libfunc, builder),
         CoreConcreteLibfunc::SnapshotTake(_) => misc::build_dup(builder),
         CoreConcreteLibfunc::Felt252DictEntry(libfunc) => {
@@ -0,0 +1,163 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::modular::{
+    ModularConcreteLibfunc, MOD_BUILTIN_INSTANCE_SIZE, MOD_BUILTIN_N_LIMBS,
+};
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{add_input_variables, CostValidationInfo};
+
+#[cfg(test)]
+#[path = "modular_test.rs"]
+mod test;
+
+/// Builds instructions for Sierra mod builtin operations.
+pub fn build(
+    libfunc: &ModularConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        ModularConcreteLibfunc::AddMod(_) => build_mod_builtin_operation(builder, false),
+        ModularConcreteLibfunc::MulMod(_) => build_mod_builtin_operation(builder, true),
+    }
+}
+
+/// Handles instruction for applying a single operation of the AddMod builtin, or of the MulMod
+/// builtin if `is_mul`, to the u384 values `a` and `b`.
+///
+/// The operands are written to the RangeCheck96 builtin, followed by the result - deduced by the
+/// builtin - and the offsets of the operation within these values, so that all the limbs are range
+/// checked. The builtin instance then points to both. As the builtin only constrains the result
+/// modulo `modulus`, the limbs of `modulus - 1 - result` are also range checked, so that the result
+/// is smaller than `modulus`.
+fn build_mod_builtin_operation(
+    builder: CompiledInvocationBuilder<'_>,
+    is_mul: bool,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check96, expr_builtin, expr_a, expr_b, expr_modulus] =
+        builder.try_get_refs()?;
+    let range_check96 = expr_range_check96.try_unpack_single()?;
+    let builtin = expr_builtin.try_unpack_single()?;
+    let a: [_; MOD_BUILTIN_N_LIMBS] = expr_a.try_unpack()?;
+    let b: [_; MOD_BUILTIN_N_LIMBS] = expr_b.try_unpack()?;
+    let modulus: [_; MOD_BUILTIN_N_LIMBS] = expr_modulus.try_unpack()?;
+
+    // The values hold `a`, `b` and the result, followed by the offsets of the operation and by
+    // `modulus - 1 - result`.
+    let result_offset = 2 * MOD_BUILTIN_N_LIMBS;
+    let offsets_offset = 3 * MOD_BUILTIN_N_LIMBS;
+    let bound_offset = offsets_offset + 3;
+    let values_size = bound_offset + MOD_BUILTIN_N_LIMBS;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(values_size as i16) range_check96;
+        buffer(MOD_BUILTIN_INSTANCE_SIZE as i16) builtin;
+    };
+    let mut limb_vars = vec![];
+    for limb in a.into_iter().chain(b).chain(modulus) {
+        add_input_variables!(casm_builder, deref limb;);
+        limb_vars.push(limb);
+    }
+    let (operand_limbs, modulus_limbs) = limb_vars.split_at(2 * MOD_BUILTIN_N_LIMBS);
+
+    casm_build_extend! {casm_builder,
+        const values_size_imm = values_size;
+        let orig_builtin = builtin;
+        let values = range_check96;
+        let new_range_check96 = values + values_size_imm;
+    };
+    for (i, limb) in operand_limbs.iter().enumerate() {
+        let limb = *limb;
+        let limb_offset = i as i16;
+        casm_build_extend!(casm_builder, assert limb = values[limb_offset];);
+    }
+    for (i, operand_offset) in [0, MOD_BUILTIN_N_LIMBS, result_offset].into_iter().enumerate() {
+        let offset_cell = (offsets_offset + i) as i16;
+        casm_build_extend! {casm_builder,
+            const operand_offset_imm = operand_offset;
+            tempvar operand_offset_var = operand_offset_imm;
+            assert operand_offset_var = values[offset_cell];
+        };
+    }
+    // Fill the builtin instance.
+    for limb in modulus_limbs {
+        let limb = *limb;
+        casm_build_extend!(casm_builder, assert limb = *(builtin++););
+    }
+    casm_build_extend! {casm_builder,
+        const offsets_offset_imm = offsets_offset;
+        const n_operations_imm = 1;
+        const zero = 0;
+        assert values = *(builtin++);
+        tempvar offsets = values + offsets_offset_imm;
+        assert offsets = *(builtin++);
+        tempvar n_operations = n_operations_imm;
+        assert n_operations = *(builtin++);
+    };
+    // The hint is carried by the first read of the result.
+    if is_mul {
+        casm_build_extend! {casm_builder,
+            hint FillModBuiltins {
+                add_mod_ptr: zero, n_add_mods: zero,
+                mul_mod_ptr: orig_builtin, n_mul_mods: n_operations
+            } into {};
+        };
+    } else {
+        casm_build_extend! {casm_builder,
+            hint FillModBuiltins {
+                add_mod_ptr: orig_builtin, n_add_mods: n_operations,
+                mul_mod_ptr: zero, n_mul_mods: zero
+            } into {};
+        };
+    }
+    let mut result_limbs = vec![];
+    for i in 0..MOD_BUILTIN_N_LIMBS {
+        let limb_offset = (result_offset + i) as i16;
+        casm_build_extend!(casm_builder, tempvar result_limb = values[limb_offset];);
+        result_limbs.push(result_limb);
+    }
+    // Subtract `result + 1` from `modulus` limb by limb. The borrow of each limb is guessed, and
+    // there is no borrow from beyond the last limb, so the difference is non-negative.
+    casm_build_extend! {casm_builder,
+        const one = 1;
+        const u96_limit = (BigInt::from(1) << 96) as BigInt;
+    };
+    let mut borrow_in = one;
+    for (i, (result_limb, modulus_limb)) in result_limbs.iter().zip(modulus_limbs).enumerate() {
+        let (result_limb, modulus_limb) = (*result_limb, *modulus_limb);
+        let diff_cell = (bound_offset + i) as i16;
+        casm_build_extend!(casm_builder, tempvar subtrahend = result_limb + borrow_in;);
+        if i + 1 == MOD_BUILTIN_N_LIMBS {
+            casm_build_extend! {casm_builder,
+                tempvar diff = modulus_limb - subtrahend;
+                assert diff = values[diff_cell];
+            };
+            break;
+        }
+        casm_build_extend! {casm_builder,
+            tempvar borrow;
+            nondet {
+                guess hint TestLessThan {lhs: modulus_limb, rhs: subtrahend} into {dst: borrow};
+                verify {
+                    // The borrow is either 0 or 1.
+                    assert borrow = borrow * borrow;
+                }
+            };
+            tempvar borrowed = borrow * u96_limit;
+            tempvar lifted_modulus_limb = modulus_limb + borrowed;
+            tempvar diff = lifted_modulus_limb - subtrahend;
+            assert diff = values[diff_cell];
+        };
+        borrow_in = borrow;
+    }
+
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[new_range_check96], &[builtin], &result_limbs], None)],
+        CostValidationInfo {
+            range_check_info: Some((values, new_range_check96)),
+            extra_costs: None,
+        },
+    ))
+}
@@ -0,0 +1,43 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_casm::operand::{CellRef, Register};
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use crate::invocations::test_utils::{compile_libfunc, ReducedBranchChanges};
+use crate::ref_expr;
+use crate::references::ReferenceExpression;
+
+/// Returns a reference expression to the given consecutive cells.
+fn cells_ref(register: Register, offsets: std::ops::Range<i16>) -> ReferenceExpression {
+    ReferenceExpression {
+        cells: offsets.map(|offset| CellExpression::Deref(CellRef { register, offset })).collect(),
+    }
+}
+
+#[test_case("u384_add_mod"; "add_mod")]
+#[test_case("u384_mul_mod"; "mul_mod")]
+fn test_mod_builtin_operation(libfunc: &str) {
+    let compiled = compile_libfunc(
+        libfunc,
+        vec![
+            ref_expr!([fp + 1]),
+            ref_expr!([fp + 2]),
+            cells_ref(Register::FP, 3..7),
+            cells_ref(Register::FP, 7..11),
+            cells_ref(Register::FP, 11..15),
+        ],
+    );
+    assert_eq!(compiled.instructions.len(), 48);
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: vec![
+                ref_expr!([fp + 1] + 19),
+                ref_expr!([fp + 2] + 7),
+                cells_ref(Register::AP, -21..-17),
+            ],
+            ap_change: ApChange::Known(26),
+        }]
+    );
+}
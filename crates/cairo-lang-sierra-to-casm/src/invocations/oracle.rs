@@ -0,0 +1,66 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::hints::CoreHint;
+use cairo_lang_sierra::extensions::oracle::OracleConcreteLibfunc;
+use cairo_lang_utils::bigint::BigIntAsHex;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{add_input_variables, CostValidationInfo};
+
+/// Builds instructions for Sierra oracle operations.
+///
+/// The output span is written by a hint, and only its size is range checked - the callers must
+/// check the values themselves.
+pub fn build(
+    libfunc: &OracleConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        OracleConcreteLibfunc::Call(libfunc) => {
+            let mut casm_builder = CasmBuilder::default();
+            let [range_check, input] = builder.try_get_refs()?;
+            let range_check = range_check.try_unpack_single()?;
+            let [input_start, input_end] = input.try_unpack()?;
+
+            add_input_variables! {casm_builder,
+                buffer(0) range_check;
+                deref input_start;
+                deref input_end;
+            }
+
+            casm_build_extend! {casm_builder,
+                let orig_range_check = range_check;
+                tempvar output_size;
+                tempvar output_start;
+                tempvar output_end;
+            }
+
+            casm_builder.add_hint(
+                |[input_start, input_end], [output_start, output_end]| CoreHint::Oracle {
+                    selector: BigIntAsHex { value: libfunc.selector.clone() },
+                    input_start,
+                    input_end,
+                    output_start,
+                    output_end,
+                },
+                [input_start, input_end],
+                [output_start, output_end],
+            );
+
+            casm_build_extend! {casm_builder,
+                ap += 3;
+                assert output_size = output_end - output_start;
+                assert output_size = *(range_check++);
+            }
+
+            Ok(builder.build_from_casm_builder(
+                casm_builder,
+                [("Fallthrough", &[&[range_check], &[output_start, output_end]], None)],
+                CostValidationInfo {
+                    range_check_info: Some((orig_range_check, range_check)),
+                    extra_costs: None,
+                },
+            ))
+        }
+    }
+}
@@ -2,12 +2,23 @@
 #[path = "pedersen_test.rs"]
 mod test;
 
-use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::builder::{CasmBuildResult, CasmBuilder};
 use cairo_lang_casm::casm_build_extend;
-use cairo_lang_sierra::extensions::pedersen::PedersenConcreteLibfunc;
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra::extensions::pedersen::{PedersenConcreteLibfunc, PedersenMerkleHashTraits};
+use cairo_lang_sierra_ap_change::core_libfunc_ap_change::core_libfunc_ap_change;
+use cairo_lang_sierra_ap_change::ApChange;
+use cairo_lang_sierra_gas::core_libfunc_cost::{
+    PEDERSEN_HASH_SPAN_FIXED_COST, PEDERSEN_HASH_SPAN_PER_ELEMENT_COST,
+};
+use cairo_lang_sierra_gas::objects::ConstCost;
+use num_bigint::BigInt;
 
+use super::merkle::build_merkle_verify;
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
-use crate::invocations::add_input_variables;
+use crate::invocations::{add_input_variables, get_non_fallthrough_statement_id};
+use crate::references::ReferenceExpression;
+use crate::relocations::{Relocation, RelocationEntry};
 
 /// Builds instructions for Sierra pedersen operations.
 pub fn build(
@@ -16,6 +27,10 @@ pub fn build(
 ) -> Result<CompiledInvocation, InvocationError> {
     match libfunc {
         PedersenConcreteLibfunc::PedersenHash(_) => build_pedersen_hash(builder),
+        PedersenConcreteLibfunc::MerkleVerify(_) => {
+            build_merkle_verify::<PedersenMerkleHashTraits>(builder)
+        }
+        PedersenConcreteLibfunc::HashSpan(_) => build_pedersen_hash_span(builder),
     }
 }
 
@@ -42,3 +57,177 @@ fn build_pedersen_hash(
         Default::default(),
     ))
 }
+
+/// Handles a Sierra statement for chaining the Pedersen hash over a span of felt252s.
+/// The span is hashed by a loop, whose cost, including the cost of the Pedersen builtin taken from
+/// the builtin costs, is withdrawn from the gas builtin at runtime.
+fn build_pedersen_hash_span(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [
+        expr_range_check,
+        expr_gas_builtin,
+        expr_pedersen,
+        expr_builtin_costs,
+        expr_initial_state,
+        expr_span,
+    ] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let pedersen = expr_pedersen.try_unpack_single()?;
+    let builtin_costs = expr_builtin_costs.try_unpack_single()?;
+    let initial_state = expr_initial_state.try_unpack_single()?;
+    let [span_start, span_end] = expr_span.try_unpack()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let pedersen_cost_offset = CostTokenType::Pedersen.offset_in_builtin_costs();
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_element_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref gas_builtin;
+        deref pedersen;
+        deref builtin_costs;
+        deref initial_state;
+        deref span_start;
+        deref span_end;
+    };
+    casm_build_extend! {casm_builder,
+        const per_element_cost = PEDERSEN_HASH_SPAN_PER_ELEMENT_COST.cost();
+        const u128_bound = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar pedersen_cost = builtin_costs[pedersen_cost_offset];
+        tempvar element_cost = pedersen_cost + per_element_cost;
+        tempvar n_elements = span_end - span_start;
+        tempvar required_gas = n_elements * element_cost;
+        tempvar has_enough_gas;
+        hint TestLessThanOrEqual {lhs: required_gas, rhs: gas_builtin} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        // Prove that `gas_builtin < required_gas`.
+        tempvar gas_builtin_plus_bound = gas_builtin + u128_bound;
+        tempvar gas_diff = gas_builtin_plus_bound - required_gas;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_builtin - required_gas;
+        assert updated_gas = *(range_check++);
+        jump NonEmpty if n_elements != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the arguments of the hashing function.
+        tempvar hash_range_check = range_check;
+        tempvar hash_pedersen = pedersen;
+        tempvar hash_state = initial_state;
+        tempvar hash_src = span_start;
+        let (final_range_check, final_gas_builtin, final_pedersen, final_state) =
+            call PedersenHashSpan;
+        jump Done;
+        Empty:
+        // The state is unchanged - return the values in the same layout as the hashing function.
+        tempvar empty_range_check = range_check;
+        tempvar empty_gas_builtin = updated_gas;
+        tempvar empty_pedersen = pedersen;
+        tempvar empty_state = initial_state;
+        rescope {
+            final_range_check = empty_range_check,
+            final_gas_builtin = empty_gas_builtin,
+            final_pedersen = empty_pedersen,
+            final_state = empty_state
+        };
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Hashes the `n_elements` elements starting at `hash_src` into `hash_state`, where
+        // `n_elements` is not 0.
+        PedersenHashSpan:
+        tempvar loop_pedersen = hash_pedersen;
+        tempvar loop_state = hash_state;
+        tempvar src = hash_src;
+        tempvar remaining = n_elements;
+        rescope {
+            loop_pedersen = loop_pedersen,
+            loop_state = loop_state,
+            src = src,
+            remaining = remaining,
+            hash_range_check = hash_range_check,
+            updated_gas = updated_gas
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        PedersenHashSpanLoop:
+        const one = 1;
+        tempvar element = src[0];
+        assert loop_state = *(loop_pedersen++);
+        assert element = *(loop_pedersen++);
+        let hashed = *(loop_pedersen++);
+        tempvar next_pedersen = loop_pedersen;
+        tempvar next_state = hashed;
+        tempvar next_src = src + one;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            loop_pedersen = next_pedersen,
+            loop_state = next_state,
+            src = next_src,
+            remaining = next_remaining,
+            hash_range_check = hash_range_check,
+            updated_gas = updated_gas
+        };
+        jump PedersenHashSpanLoop if remaining != 0;
+        #{ per_element_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_range_check = hash_range_check;
+        tempvar returned_gas_builtin = updated_gas;
+        tempvar returned_pedersen = loop_pedersen;
+        tempvar returned_state = loop_state;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(
+        ConstCost { steps: fixed_steps, holes: 0, range_checks: 1 },
+        PEDERSEN_HASH_SPAN_FIXED_COST
+    );
+    assert_eq!(
+        ConstCost { steps: per_element_steps, holes: 0, range_checks: 0 },
+        PEDERSEN_HASH_SPAN_PER_ELEMENT_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches: [(state, _), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Failure"]);
+    assert_eq!(failure_state.steps, 9, "Must match the failure branch cost.");
+    assert_eq!(
+        core_libfunc_ap_change(builder.libfunc, &builder)[1],
+        ApChange::Known(failure_state.ap_change)
+    );
+    let relocations = failure_relocations
+        .into_iter()
+        .map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        })
+        .collect();
+
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_gas_builtin)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_pedersen)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_state)] },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(range_check)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(gas_builtin)] },
+                ReferenceExpression { cells: vec![failure_state.get_adjusted(pedersen)] },
+            ]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
@@ -1,7 +1,8 @@
 use cairo_lang_casm::builder::CasmBuilder;
 use cairo_lang_casm::casm_build_extend;
-use cairo_lang_sierra::extensions::poseidon::PoseidonConcreteLibfunc;
+use cairo_lang_sierra::extensions::poseidon::{PoseidonConcreteLibfunc, PoseidonMerkleHashTraits};
 
+use super::merkle::build_merkle_verify;
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
 use crate::invocations::add_input_variables;
 
@@ -12,6 +13,9 @@ pub fn build(
 ) -> Result<CompiledInvocation, InvocationError> {
     match libfunc {
         PoseidonConcreteLibfunc::HadesPermutation(_) => build_poseidon_permutation(builder),
+        PoseidonConcreteLibfunc::MerkleVerify(_) => {
+            build_merkle_verify::<PoseidonMerkleHashTraits>(builder)
+        }
     }
 }
 
@@ -0,0 +1,478 @@
+use cairo_lang_casm::builder::{CasmBuildResult, CasmBuilder};
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::rlp::RlpConcreteLibfunc;
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
+use cairo_lang_sierra_gas::core_libfunc_cost::{
+    BYTES_READ_U128_BE_FIXED_COST, BYTES_READ_U128_BE_MAX_BYTES, BYTES_READ_U128_BE_PER_BYTE_COST,
+    RLP_DECODE_HEADER_FIXED_COST, RLP_DECODE_HEADER_MAX_LENGTH_BYTES,
+    RLP_DECODE_HEADER_PER_LENGTH_BYTE_COST,
+};
+use cairo_lang_sierra_gas::objects::ConstCost;
+use itertools::{chain, Itertools};
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{add_input_variables, get_non_fallthrough_statement_id};
+use crate::references::ReferenceExpression;
+use crate::relocations::{Relocation, RelocationEntry};
+
+/// Builds instructions for Sierra RLP and big-endian decoding operations.
+pub fn build(
+    libfunc: &RlpConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        RlpConcreteLibfunc::ReadU128Be(_) => build_bytes_read_u128_be(builder),
+        RlpConcreteLibfunc::DecodeHeader(_) => build_rlp_decode_header(builder),
+    }
+}
+
+/// Handles a Sierra statement for reading a big-endian u128 from bytes of a byte array.
+/// The bytes are read by a loop, which is charged for the maximal number of bytes, as the number
+/// of bytes is bounded.
+fn build_bytes_read_u128_be(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_bytes, expr_offset, expr_len] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let [bytes_start, bytes_end] = expr_bytes.try_unpack()?;
+    let offset = expr_offset.try_unpack_single()?;
+    let len = expr_len.try_unpack_single()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    // Counters for the amount of steps in the generated code.
+    let mut fixed_steps: i32 = 0;
+    let mut per_byte_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref bytes_start;
+        deref bytes_end;
+        deref offset;
+        deref len;
+    };
+    casm_build_extend! {casm_builder,
+        const one = 1;
+        const max_len = BYTES_READ_U128_BE_MAX_BYTES;
+        const len_bound_shift = (BigInt::from(u128::MAX) - BYTES_READ_U128_BE_MAX_BYTES) as BigInt;
+        tempvar n_bytes = bytes_end - bytes_start;
+        tempvar end_offset = offset + len;
+        tempvar len_fits;
+        hint TestLessThanOrEqual {lhs: len, rhs: max_len} into {dst: len_fits};
+        jump LenFits if len_fits != 0;
+        // Prove that `len > max_len`.
+        tempvar len_gap = len - max_len;
+        tempvar len_excess = len_gap - one;
+        assert len_excess = *(range_check++);
+        tempvar failure_range_check = range_check;
+        rescope {failure_range_check = failure_range_check};
+        jump Failure;
+        LenFits:
+        // Prove that `len <= max_len`.
+        tempvar len_slack = len + len_bound_shift;
+        assert len_slack = *(range_check++);
+        tempvar in_bounds;
+        hint TestLessThanOrEqual {lhs: end_offset, rhs: n_bytes} into {dst: in_bounds};
+        jump InBounds if in_bounds != 0;
+        // Prove that `end_offset > n_bytes`.
+        tempvar overflow = end_offset - n_bytes;
+        tempvar overflow_gap = overflow - one;
+        assert overflow_gap = *(range_check++);
+        tempvar out_of_bounds_range_check = range_check;
+        rescope {failure_range_check = out_of_bounds_range_check};
+        jump Failure;
+        InBounds:
+        tempvar bounds_slack = n_bytes - end_offset;
+        assert bounds_slack = *(range_check++);
+        tempvar read_range_check = range_check;
+        jump NonEmpty if len != 0;
+        jump Empty;
+        NonEmpty:
+        // Push the rest of the arguments of the reading function.
+        tempvar read_src = bytes_start + offset;
+        tempvar read_len = len;
+        let (final_range_check, final_value) = call ReadBe;
+        jump Done;
+        Empty:
+        // Nothing to read - return the values in the same layout as the reading function.
+        const zero = 0;
+        tempvar empty_range_check = read_range_check;
+        tempvar empty_value = zero;
+        rescope {final_range_check = empty_range_check, final_value = empty_value};
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Reads the `read_len` bytes starting at `read_src` as a big-endian value, where `read_len`
+        // is not 0.
+        ReadBe:
+        const zero = 0;
+        tempvar value = zero;
+        tempvar src = read_src;
+        tempvar remaining = read_len;
+        rescope {
+            value = value,
+            src = src,
+            remaining = remaining,
+            read_range_check = read_range_check
+        };
+        #{ fixed_steps += steps; steps = 0; }
+        ReadBeLoop:
+        const one = 1;
+        const byte_base = 256;
+        tempvar byte = src[0];
+        tempvar shifted_value = value * byte_base;
+        tempvar next_value = shifted_value + byte;
+        tempvar next_src = src + one;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            value = next_value,
+            src = next_src,
+            remaining = next_remaining,
+            read_range_check = read_range_check
+        };
+        jump ReadBeLoop if remaining != 0;
+        #{ per_byte_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_range_check = read_range_check;
+        tempvar returned_value = value;
+        ret;
+        #{ fixed_steps += steps; steps = 0; }
+        Done:
+        #{ fixed_steps += steps; steps = 0; }
+    };
+    // Manually counted, range check uses are marked in the builder code.
+    assert_eq!(
+        ConstCost { steps: fixed_steps, holes: 0, range_checks: 2 },
+        BYTES_READ_U128_BE_FIXED_COST
+    );
+    assert_eq!(
+        ConstCost { steps: per_byte_steps, holes: 0, range_checks: 0 },
+        BYTES_READ_U128_BE_PER_BYTE_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches: [(state, _), (failure_state, failure_relocations)],
+    } = casm_builder.build(["Fallthrough", "Failure"]);
+    assert_eq!(failure_state.steps, 11, "Must match the failure branch cost.");
+    let relocations = failure_relocations
+        .into_iter()
+        .map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(failure_handle_statement_id),
+        })
+        .collect();
+
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(final_value)] },
+            ]
+            .into_iter(),
+            vec![ReferenceExpression {
+                cells: vec![failure_state.get_adjusted(failure_range_check)],
+            }]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
+
+/// Handles a Sierra statement for decoding the header of an RLP item.
+/// The prefix byte is classified by hints, verified with range checks. The length of the payload
+/// of the long forms is read by a loop, which is charged for the maximal number of length bytes, as
+/// the number of length bytes is bounded.
+fn build_rlp_decode_header(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_bytes, expr_offset] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?;
+    let [bytes_start, bytes_end] = expr_bytes.try_unpack()?;
+    let offset = expr_offset.try_unpack_single()?;
+    let (list_statement_id, invalid_statement_id) = match builder.invocation.branches.as_slice() {
+        [
+            BranchInfo { target: BranchTarget::Fallthrough, .. },
+            BranchInfo { target: BranchTarget::Statement(list_statement_id), .. },
+            BranchInfo { target: BranchTarget::Statement(invalid_statement_id), .. },
+        ] => (*list_statement_id, *invalid_statement_id),
+        _ => panic!("malformed invocation"),
+    };
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1;
+    // Counters for the amount of steps in the length reading function.
+    let mut read_length_fixed_steps: i32 = 0;
+    let mut per_length_byte_steps: i32 = 0;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref bytes_start;
+        deref bytes_end;
+        deref offset;
+    };
+    casm_build_extend! {casm_builder,
+        const one = 1;
+        const string_base = 0x80;
+        const list_base = 0xc0;
+        const long_code = 56;
+        const max_length_bytes = RLP_DECODE_HEADER_MAX_LENGTH_BYTES;
+        const string_base_shift = (u128_bound.clone() - 0x80) as BigInt;
+        const list_base_shift = (u128_bound.clone() - 0xc0) as BigInt;
+        const long_code_shift = (u128_bound.clone() - 56) as BigInt;
+        const length_bytes_bound_shift =
+            (u128_bound - RLP_DECODE_HEADER_MAX_LENGTH_BYTES - 1) as BigInt;
+        tempvar n_bytes = bytes_end - bytes_start;
+        tempvar prefix_in_bounds;
+        hint TestLessThan {lhs: offset, rhs: n_bytes} into {dst: prefix_in_bounds};
+        jump PrefixInBounds if prefix_in_bounds != 0;
+        // Prove that `offset >= n_bytes`.
+        tempvar prefix_overflow = offset - n_bytes;
+        assert prefix_overflow = *(range_check++);
+        tempvar invalid_range_check = range_check;
+        rescope {invalid_range_check = invalid_range_check};
+        jump Invalid;
+        PrefixInBounds:
+        // The offset right after the prefix byte, which is also the offset of the payload of the
+        // short forms.
+        tempvar prefix_end = offset + one;
+        tempvar prefix_slack = n_bytes - prefix_end;
+        assert prefix_slack = *(range_check++);
+        tempvar prefix_ptr = bytes_start + offset;
+        tempvar prefix = prefix_ptr[0];
+        tempvar is_single;
+        hint TestLessThan {lhs: prefix, rhs: string_base} into {dst: is_single};
+        jump Single if is_single != 0;
+        tempvar string_code = prefix - string_base;
+        assert string_code = *(range_check++);
+        // The prefix without the base of its kind, where `is_list` is 0 for strings.
+        tempvar length_code;
+        tempvar is_list;
+        hint TestLessThanOrEqual {lhs: list_base, rhs: prefix} into {dst: is_list};
+        jump ListPrefix if is_list != 0;
+        // Prove that `prefix < list_base`.
+        tempvar string_gap = prefix + list_base_shift;
+        assert string_gap = *(range_check++);
+        assert length_code = string_code;
+        jump Classified;
+        ListPrefix:
+        tempvar list_code = prefix - list_base;
+        assert list_code = *(range_check++);
+        assert length_code = list_code;
+        Classified:
+        tempvar is_short;
+        hint TestLessThan {lhs: length_code, rhs: long_code} into {dst: is_short};
+        jump Short if is_short != 0;
+    };
+    casm_build_extend! {casm_builder,
+        // A long form - the length of the payload is in the `length_code - 55` bytes after the
+        // prefix.
+        tempvar long_gap = length_code - long_code;
+        assert long_gap = *(range_check++);
+        tempvar length_bytes = long_gap + one;
+        tempvar length_bytes_fit;
+        hint TestLessThanOrEqual {lhs: length_bytes, rhs: max_length_bytes} into {dst: length_bytes_fit};
+        jump LengthBytesFit if length_bytes_fit != 0;
+        // Prove that `length_bytes > max_length_bytes`.
+        tempvar length_bytes_excess = long_gap - max_length_bytes;
+        assert length_bytes_excess = *(range_check++);
+        tempvar too_long_range_check = range_check;
+        rescope {invalid_range_check = too_long_range_check};
+        jump Invalid;
+        LengthBytesFit:
+        tempvar length_bytes_slack = length_bytes + length_bytes_bound_shift;
+        assert length_bytes_slack = *(range_check++);
+        tempvar long_payload_offset = prefix_end + length_bytes;
+        tempvar length_in_bounds;
+        hint TestLessThanOrEqual {lhs: long_payload_offset, rhs: n_bytes} into {dst: length_in_bounds};
+        jump LengthInBounds if length_in_bounds != 0;
+        // Prove that `long_payload_offset > n_bytes`.
+        tempvar length_overflow = long_payload_offset - n_bytes;
+        tempvar length_overflow_gap = length_overflow - one;
+        assert length_overflow_gap = *(range_check++);
+        tempvar length_out_of_bounds_range_check = range_check;
+        rescope {invalid_range_check = length_out_of_bounds_range_check};
+        jump Invalid;
+        LengthInBounds:
+        tempvar length_slack = n_bytes - long_payload_offset;
+        assert length_slack = *(range_check++);
+        // Push the arguments of the length reading function.
+        tempvar read_range_check = range_check;
+        tempvar read_n_bytes = n_bytes;
+        tempvar read_payload_offset = long_payload_offset;
+        tempvar read_is_list = is_list;
+        tempvar read_src = prefix_ptr + one;
+        tempvar read_len = length_bytes;
+        let (
+            checked_range_check,
+            checked_n_bytes,
+            checked_payload_offset,
+            checked_is_list,
+            checked_payload_len
+        ) = call ReadLength;
+        jump Check;
+    };
+    casm_build_extend! {casm_builder,
+        Single:
+        // Prove that `prefix < string_base` - the prefix is the payload itself.
+        tempvar single_gap = prefix + string_base_shift;
+        assert single_gap = *(range_check++);
+        const zero = 0;
+        tempvar single_range_check = range_check;
+        tempvar single_n_bytes = n_bytes;
+        tempvar single_payload_offset = offset;
+        tempvar single_is_list = zero;
+        tempvar single_payload_len = one;
+        rescope {
+            checked_range_check = single_range_check,
+            checked_n_bytes = single_n_bytes,
+            checked_payload_offset = single_payload_offset,
+            checked_is_list = single_is_list,
+            checked_payload_len = single_payload_len
+        };
+        jump Check;
+        Short:
+        // Prove that `length_code < long_code` - the length code is the length of the payload.
+        tempvar short_gap = length_code + long_code_shift;
+        assert short_gap = *(range_check++);
+        tempvar short_range_check = range_check;
+        tempvar short_n_bytes = n_bytes;
+        tempvar short_payload_offset = prefix_end;
+        tempvar short_is_list = is_list;
+        tempvar short_payload_len = length_code;
+        rescope {
+            checked_range_check = short_range_check,
+            checked_n_bytes = short_n_bytes,
+            checked_payload_offset = short_payload_offset,
+            checked_is_list = short_is_list,
+            checked_payload_len = short_payload_len
+        };
+        jump Check;
+    };
+    casm_build_extend! {casm_builder,
+        Check:
+        const one = 1;
+        tempvar payload_end = checked_payload_offset + checked_payload_len;
+        tempvar payload_in_bounds;
+        hint TestLessThanOrEqual {lhs: payload_end, rhs: checked_n_bytes} into {dst: payload_in_bounds};
+        jump PayloadInBounds if payload_in_bounds != 0;
+        // Prove that `payload_end > n_bytes`.
+        tempvar payload_overflow = payload_end - checked_n_bytes;
+        tempvar payload_overflow_gap = payload_overflow - one;
+        assert payload_overflow_gap = *(checked_range_check++);
+        tempvar payload_out_of_bounds_range_check = checked_range_check;
+        rescope {invalid_range_check = payload_out_of_bounds_range_check};
+        jump Invalid;
+        PayloadInBounds:
+        tempvar payload_slack = checked_n_bytes - payload_end;
+        assert payload_slack = *(checked_range_check++);
+        tempvar final_range_check = checked_range_check;
+        jump List if checked_is_list != 0;
+        jump Done;
+    };
+    casm_build_extend! {casm_builder,
+        // Reads the payload length from the `read_len` bytes starting at `read_src`, where
+        // `read_len` is not 0.
+        ReadLength:
+        const zero = 0;
+        tempvar value = zero;
+        tempvar src = read_src;
+        tempvar remaining = read_len;
+        rescope {
+            value = value,
+            src = src,
+            remaining = remaining,
+            read_range_check = read_range_check,
+            read_n_bytes = read_n_bytes,
+            read_payload_offset = read_payload_offset,
+            read_is_list = read_is_list
+        };
+        #{ read_length_fixed_steps += steps; steps = 0; }
+        ReadLengthLoop:
+        const one = 1;
+        const byte_base = 256;
+        tempvar byte = src[0];
+        tempvar shifted_value = value * byte_base;
+        tempvar next_value = shifted_value + byte;
+        tempvar next_src = src + one;
+        tempvar next_remaining = remaining - one;
+        rescope {
+            value = next_value,
+            src = next_src,
+            remaining = next_remaining,
+            read_range_check = read_range_check,
+            read_n_bytes = read_n_bytes,
+            read_payload_offset = read_payload_offset,
+            read_is_list = read_is_list
+        };
+        jump ReadLengthLoop if remaining != 0;
+        #{ per_length_byte_steps += steps; steps = 0; }
+        // Push the returned variables.
+        tempvar returned_range_check = read_range_check;
+        tempvar returned_n_bytes = read_n_bytes;
+        tempvar returned_payload_offset = read_payload_offset;
+        tempvar returned_is_list = read_is_list;
+        tempvar returned_payload_len = value;
+        ret;
+        #{ read_length_fixed_steps += steps; steps = 0; }
+        Done:
+    };
+    assert_eq!(
+        ConstCost { steps: per_length_byte_steps, holes: 0, range_checks: 0 },
+        RLP_DECODE_HEADER_PER_LENGTH_BYTE_COST
+    );
+    let CasmBuildResult {
+        instructions,
+        branches: [(state, _), (list_state, list_relocations), (invalid_state, invalid_relocations)],
+    } = casm_builder.build(["Fallthrough", "List", "Invalid"]);
+    // All the branches are charged for the most expensive path, range check uses are marked in
+    // the builder code.
+    let max_steps =
+        [&state, &list_state, &invalid_state].map(|state| state.steps).into_iter().max();
+    assert_eq!(
+        ConstCost {
+            steps: max_steps.unwrap() as i32 + read_length_fixed_steps,
+            holes: 0,
+            range_checks: 6
+        },
+        RLP_DECODE_HEADER_FIXED_COST
+    );
+    let relocations = chain!(
+        list_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(list_statement_id),
+        }),
+        invalid_relocations.into_iter().map(|instruction_idx| RelocationEntry {
+            instruction_idx,
+            relocation: Relocation::RelativeStatementId(invalid_statement_id),
+        })
+    )
+    .sorted_by_key(|relocation| relocation.instruction_idx)
+    .collect();
+
+    Ok(builder.build(
+        instructions,
+        relocations,
+        [
+            vec![
+                ReferenceExpression { cells: vec![state.get_adjusted(final_range_check)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(checked_payload_offset)] },
+                ReferenceExpression { cells: vec![state.get_adjusted(checked_payload_len)] },
+            ]
+            .into_iter(),
+            vec![
+                ReferenceExpression { cells: vec![list_state.get_adjusted(final_range_check)] },
+                ReferenceExpression {
+                    cells: vec![list_state.get_adjusted(checked_payload_offset)],
+                },
+                ReferenceExpression { cells: vec![list_state.get_adjusted(checked_payload_len)] },
+            ]
+            .into_iter(),
+            vec![ReferenceExpression {
+                cells: vec![invalid_state.get_adjusted(invalid_range_check)],
+            }]
+            .into_iter(),
+        ]
+        .into_iter(),
+    ))
+}
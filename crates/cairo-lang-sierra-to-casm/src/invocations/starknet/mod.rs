@@ -1,18 +1,17 @@
-use cairo_felt::Felt252;
 use cairo_lang_casm::builder::CasmBuilder;
 use cairo_lang_casm::casm_build_extend;
 use cairo_lang_casm::hints::StarknetHint;
 use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use cairo_lang_sierra_gas::core_libfunc_cost::SYSTEM_CALL_COST;
 use itertools::Itertools;
-use num_bigint::{BigInt, ToBigInt};
+use num_bigint::BigInt;
 
 use self::storage::{
     build_storage_address_from_base_and_offset, build_storage_base_address_from_felt252,
 };
 use super::misc::{build_identity, build_single_cell_const};
 use super::{misc, CompiledInvocation, CompiledInvocationBuilder};
-use crate::invocations::misc::validate_under_limit;
+use crate::invocations::misc::BoundValidation;
 use crate::invocations::{
     add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo, InvocationError,
 };
@@ -36,7 +35,11 @@ pub fn build(
         StarkNetConcreteLibfunc::ClassHashTryFromFelt252(_)
         | StarkNetConcreteLibfunc::ContractAddressTryFromFelt252(_)
         | StarkNetConcreteLibfunc::StorageAddressTryFromFelt252(_) => {
-            build_u251_try_from_felt252(builder)
+            misc::build_try_from_felt252_under_bound(
+                builder,
+                BigInt::from(1) << 251,
+                BoundValidation::LinearSplit,
+            )
         }
         StarkNetConcreteLibfunc::ClassHashToFelt252(_)
         | StarkNetConcreteLibfunc::ContractAddressToFelt252(_)
@@ -82,52 +85,6 @@ pub fn build(
     }
 }
 
-/// builds a libfunc that tries to convert a felt252 to type with values in the range[0, 2**251).
-pub fn build_u251_try_from_felt252(
-    builder: CompiledInvocationBuilder<'_>,
-) -> Result<CompiledInvocation, InvocationError> {
-    let addr_bound: BigInt = BigInt::from(1) << 251;
-    let [range_check, value] = builder.try_get_single_cells()?;
-    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
-    let mut casm_builder = CasmBuilder::default();
-    add_input_variables! {casm_builder,
-        buffer(2) range_check;
-        deref value;
-    };
-    let auxiliary_vars: [_; 4] = std::array::from_fn(|_| casm_builder.alloc_var(false));
-    casm_build_extend! {casm_builder,
-        const limit = addr_bound.clone();
-        let orig_range_check = range_check;
-        tempvar is_valid_address;
-        hint TestLessThan {lhs: value, rhs: limit} into {dst: is_valid_address};
-        jump IsValidAddress if is_valid_address != 0;
-        tempvar shifted_value = value - limit;
-    }
-    validate_under_limit::<1>(
-        &mut casm_builder,
-        &(Felt252::prime().to_bigint().unwrap() - addr_bound.clone()),
-        shifted_value,
-        range_check,
-        &auxiliary_vars,
-    );
-    casm_build_extend! {casm_builder,
-        jump Failure;
-        IsValidAddress:
-    };
-    validate_under_limit::<1>(&mut casm_builder, &addr_bound, value, range_check, &auxiliary_vars);
-    Ok(builder.build_from_casm_builder(
-        casm_builder,
-        [
-            ("Fallthrough", &[&[range_check], &[value]], None),
-            ("Failure", &[&[range_check]], Some(failure_handle_statement_id)),
-        ],
-        CostValidationInfo {
-            range_check_info: Some((orig_range_check, range_check)),
-            extra_costs: None,
-        },
-    ))
-}
-
 /// Builds instructions for Starknet system calls.
 pub fn build_syscalls<const INPUT_COUNT: usize, const OUTPUT_COUNT: usize>(
     builder: CompiledInvocationBuilder<'_>,
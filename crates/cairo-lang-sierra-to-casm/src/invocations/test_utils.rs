@@ -1,3 +1,5 @@
+//! Utilities for testing the compilation of a single libfunc invocation, given its references.
+
 use cairo_lang_casm::ap_change::ApChange;
 use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
@@ -17,6 +19,7 @@ use cairo_lang_sierra_type_size::TypeSizeMap;
 use itertools::{zip_eq, Itertools};
 
 use super::{compile_invocation, CompiledInvocation, ProgramInfo};
+use crate::compiler::{ConstsInfo, VmVersion};
 use crate::environment::gas_wallet::GasWallet;
 use crate::environment::Environment;
 use crate::metadata::Metadata;
@@ -231,6 +234,16 @@ impl std::fmt::Debug for ReducedCompiledInvocation {
 ///
 /// Currently, only works if all the libfunc's types (both inputs and output) are of size 1.
 pub fn compile_libfunc(libfunc: &str, refs: Vec<ReferenceExpression>) -> ReducedCompiledInvocation {
+    compile_libfunc_ex(libfunc, refs, false)
+}
+
+/// Compiles a libfunc into a [ReducedCompiledInvocation], as [compile_libfunc] does.
+/// `software_ec_op` - whether `ec_state_add_mul` is lowered without the EC op builtin.
+pub fn compile_libfunc_ex(
+    libfunc: &str,
+    refs: Vec<ReferenceExpression>,
+    software_ec_op: bool,
+) -> ReducedCompiledInvocation {
     let long_id = cairo_lang_sierra::ConcreteLibfuncLongIdParser::new()
         .parse(libfunc.to_string().as_str())
         .unwrap();
@@ -248,6 +261,9 @@ pub fn compile_libfunc(libfunc: &str, refs: Vec<ReferenceExpression>) -> Reduced
             type_sizes.insert(var.ty.clone(), 1);
         }
     }
+    // The segments used by the libfunc are the only const data segments.
+    let mut consts_info = ConstsInfo::default();
+    consts_info.add_libfunc_segments(&libfunc);
     let program_info = ProgramInfo {
         metadata: &Metadata {
             ap_change_info: ApChangeInfo {
@@ -258,8 +274,13 @@ pub fn compile_libfunc(libfunc: &str, refs: Vec<ReferenceExpression>) -> Reduced
                 variable_values: Default::default(),
                 function_costs: Default::default(),
             },
+            gas_solving_timings: vec![],
+            software_ec_op,
+            enum_zero_niche: false,
         },
         type_sizes: &type_sizes,
+        consts_info: &consts_info,
+        vm_version: VmVersion::default(),
     };
 
     let args: Vec<ReferenceValue> = zip_eq(refs.into_iter(), libfunc.param_signatures())
@@ -1,6 +1,7 @@
 //! CASM backend. Compiles from Sierra down to CASM. See [cairo_lang_sierra] and [cairo_lang_casm]
 
 pub mod annotations;
+pub mod builtins;
 // TODO(ilya): Reduce the size of CompilationError.
 #[allow(clippy::result_large_err)]
 pub mod compiler;
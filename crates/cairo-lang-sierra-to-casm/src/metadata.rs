@@ -4,6 +4,7 @@ use cairo_lang_sierra::program::Program;
 use cairo_lang_sierra_ap_change::ap_change_info::ApChangeInfo;
 use cairo_lang_sierra_ap_change::{calc_ap_changes, ApChangeError};
 use cairo_lang_sierra_gas::gas_info::GasInfo;
+use cairo_lang_sierra_gas::solving::{SolvingConfig, SolvingStrategy, SolvingTiming};
 use cairo_lang_sierra_gas::{
     calc_gas_postcost_info, calc_gas_precost_info, compute_precost_info, CostError,
 };
@@ -16,6 +17,12 @@ pub struct Metadata {
     pub ap_change_info: ApChangeInfo,
     /// Gas information for validating Sierra code and taking the appropriate amount of gas.
     pub gas_info: GasInfo,
+    /// The timings of solving the gas equations, empty if the gas information was not computed.
+    pub gas_solving_timings: Vec<SolvingTiming>,
+    /// Whether `ec_state_add_mul` is lowered without the EC op builtin.
+    pub software_ec_op: bool,
+    /// Whether enums with a zero niche are represented without a variant selector.
+    pub enum_zero_niche: bool,
 }
 
 /// Error for metadata calculations.
@@ -31,6 +38,18 @@ pub enum MetadataError {
 #[derive(Default)]
 pub struct MetadataComputationConfig {
     pub function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
+    /// Configuration for solving the gas equations - allows falling back to cheaper strategies
+    /// for programs where solving the equations of the whole program is too slow or fails.
+    pub gas_solving_config: SolvingConfig,
+    /// Lowers `ec_state_add_mul` with a double-and-add loop instead of the EC op builtin, for
+    /// layouts without it. The loop advances AP by thousands of cells, so the variables on the
+    /// stack before the call are not considered to be on the stack after it.
+    pub software_ec_op: bool,
+    /// Represents enums with a zero niche - a zero-sized variant and a single cell `NonZero`
+    /// variant, such as `Option<NonZero<T>>` - by a single cell without a variant selector. This
+    /// changes the layout of such enums, so it must be used consistently by all the code handling
+    /// the values of the program.
+    pub enum_zero_niche: bool,
 }
 
 /// Calculates the metadata for a Sierra program.
@@ -50,13 +69,21 @@ pub fn calc_metadata(
             )
         })
         .collect();
-    let pre_gas_info = calc_gas_precost_info(program, pre_function_set_costs)?;
-    let pre_gas_info2 = compute_precost_info(program)?;
-    pre_gas_info.assert_eq(&pre_gas_info2);
+    let (pre_gas_info, pre_timings) =
+        calc_gas_precost_info(program, pre_function_set_costs, &config.gas_solving_config)?;
+    // Other strategies may find different, non-optimal, solutions.
+    if pre_timings.iter().all(|timing| !timing.solved || timing.strategy == SolvingStrategy::Global)
+    {
+        let pre_gas_info2 = compute_precost_info(program)?;
+        pre_gas_info.assert_eq(&pre_gas_info2);
+    }
 
-    let ap_change_info = calc_ap_changes(program, |idx, token_type| {
-        pre_gas_info.variable_values[(idx, token_type)] as usize
-    })?;
+    let ap_change_info = calc_ap_changes(
+        program,
+        config.software_ec_op,
+        config.enum_zero_niche,
+        |idx, token_type| pre_gas_info.variable_values[(idx, token_type)] as usize,
+    )?;
 
     let post_function_set_costs = config
         .function_set_costs
@@ -71,10 +98,21 @@ pub fn calc_metadata(
             )
         })
         .collect();
-    let post_gas_info =
-        calc_gas_postcost_info(program, post_function_set_costs, &pre_gas_info, |idx| {
-            ap_change_info.variable_values.get(&idx).copied().unwrap_or_default()
-        })?;
+    let (post_gas_info, post_timings) = calc_gas_postcost_info(
+        program,
+        post_function_set_costs,
+        &pre_gas_info,
+        |idx| ap_change_info.variable_values.get(&idx).copied().unwrap_or_default(),
+        config.software_ec_op,
+        config.enum_zero_niche,
+        &config.gas_solving_config,
+    )?;
 
-    Ok(Metadata { ap_change_info, gas_info: pre_gas_info.combine(post_gas_info) })
+    Ok(Metadata {
+        ap_change_info,
+        gas_info: pre_gas_info.combine(post_gas_info),
+        gas_solving_timings: [pre_timings, post_timings].concat(),
+        software_ec_op: config.software_ec_op,
+        enum_zero_niche: config.enum_zero_niche,
+    })
 }
@@ -13,8 +13,8 @@ pub enum Relocation {
     /// Adds program_offset(StatementIdx) and subtracts the program offset of the casm instruction
     /// that is being relocated.
     RelativeStatementId(StatementIdx),
-    /// Adds the offset between the current statement index and the end of the program code
-    /// segment.
+    /// Adds the offset between the current statement index and the end of the program - following
+    /// the program code and its const data segments.
     EndOfProgram,
 }
 
@@ -23,11 +23,12 @@ impl Relocation {
         &self,
         instruction_offset: CodeOffset,
         statement_offsets: &[CodeOffset],
+        program_end: CodeOffset,
         instruction: &mut Instruction,
     ) {
         let target_pc = match self {
             Relocation::RelativeStatementId(statement_idx) => statement_offsets[statement_idx.0],
-            Relocation::EndOfProgram => *statement_offsets.last().unwrap(),
+            Relocation::EndOfProgram => program_end,
         };
 
         match instruction {
@@ -92,6 +93,7 @@ pub struct RelocationEntry {
 pub fn relocate_instructions(
     relocations: &[RelocationEntry],
     statement_offsets: &[usize],
+    program_end: usize,
     instructions: &mut [Instruction],
 ) {
     let mut program_offset = 0;
@@ -102,7 +104,7 @@ pub fn relocate_instructions(
             Some(RelocationEntry { instruction_idx: relocation_idx, relocation })
                 if *relocation_idx == instruction_idx =>
             {
-                relocation.apply(program_offset, statement_offsets, instruction);
+                relocation.apply(program_offset, statement_offsets, program_end, instruction);
                 relocation_entry = relocations_iter.next();
             }
             _ => (),
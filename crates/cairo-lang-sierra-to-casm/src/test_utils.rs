@@ -2,11 +2,15 @@ use std::fs;
 use std::path::PathBuf;
 
 use cairo_lang_sierra::program::Program;
+use cairo_lang_sierra::ProgramParser;
 use cairo_lang_sierra_ap_change::ap_change_info::ApChangeInfo;
 use cairo_lang_sierra_ap_change::calc_ap_changes;
 use cairo_lang_sierra_gas::gas_info::GasInfo;
+use cairo_lang_test_utils::parse_test_file::TestFileRunner;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::Itertools;
 
+use crate::compiler::{compile_for_vm_version, VmVersion};
 use crate::metadata::{calc_metadata, Metadata, MetadataComputationConfig};
 
 /// Builds the metadata for a Sierra program.
@@ -16,14 +20,19 @@ pub fn build_metadata(program: &Program, calculate_gas_info: bool) -> Metadata {
             .expect("Failed calculating gas or ap change.")
     } else {
         Metadata {
-            ap_change_info: calc_ap_changes(program, |_, _| 0).unwrap_or(ApChangeInfo {
-                function_ap_change: Default::default(),
-                variable_values: Default::default(),
-            }),
+            ap_change_info: calc_ap_changes(program, false, false, |_, _| 0).unwrap_or(
+                ApChangeInfo {
+                    function_ap_change: Default::default(),
+                    variable_values: Default::default(),
+                },
+            ),
             gas_info: GasInfo {
                 variable_values: Default::default(),
                 function_costs: Default::default(),
             },
+            gas_solving_timings: vec![],
+            software_ec_op: false,
+            enum_zero_niche: false,
         }
     }
 }
@@ -44,3 +53,40 @@ pub fn strip_comments_and_linebreaks(program: &str) -> String {
         .join("\n")
         + "\n";
 }
+
+/// Compiles a Sierra program to casm for the given VM version, returning the expectation test
+/// outputs - the `casm` code and, if `calculate_gas_info` is true, the `function_costs` of the
+/// program's functions. Gas usage is checked only if `calculate_gas_info` is true.
+pub fn compile_to_casm_outputs(
+    program: &Program,
+    calculate_gas_info: bool,
+    vm_version: VmVersion,
+) -> OrderedHashMap<String, String> {
+    let metadata = build_metadata(program, calculate_gas_info);
+    let casm = compile_for_vm_version(program, &metadata, calculate_gas_info, vm_version)
+        .unwrap()
+        .to_string();
+    let mut outputs = OrderedHashMap::from([("casm".into(), casm)]);
+    if calculate_gas_info {
+        let function_costs_str = metadata
+            .gas_info
+            .function_costs
+            .iter()
+            .map(|(func_id, cost)| format!("{func_id}: {cost:?}"))
+            .join("\n");
+        outputs.insert("function_costs".into(), function_costs_str);
+    }
+    outputs
+}
+
+/// Runner for expectation tests of compiling Sierra code to casm, for use with
+/// [cairo_lang_test_utils::test_file_test_with_runner].
+/// Reads the program from the `sierra_code` tag, and outputs the `casm` and `function_costs` tags.
+#[derive(Default)]
+pub struct SierraToCasmTestRunner;
+impl TestFileRunner for SierraToCasmTestRunner {
+    fn run(&mut self, inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+        let program = ProgramParser::new().parse(&inputs["sierra_code"]).unwrap();
+        compile_to_casm_outputs(&program, true, VmVersion::default())
+    }
+}
@@ -1,3 +1,4 @@
+use cairo_lang_sierra::extensions::circuit::CircuitTypeConcrete;
 use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType, CoreTypeConcrete};
 use cairo_lang_sierra::extensions::starknet::StarkNetTypeConcrete;
 use cairo_lang_sierra::ids::ConcreteTypeId;
@@ -8,9 +9,11 @@ use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 pub type TypeSizeMap = UnorderedHashMap<ConcreteTypeId, i16>;
 
 /// Returns a mapping for the sizes of all types for the given program.
+/// `enum_zero_niche` - whether enums with a zero niche are represented without a variant selector.
 pub fn get_type_size_map(
     program: &Program,
     registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    enum_zero_niche: bool,
 ) -> Option<TypeSizeMap> {
     let mut type_sizes = TypeSizeMap::default();
     for declaration in &program.type_declarations {
@@ -20,11 +23,18 @@ pub fn get_type_size_map(
             | CoreTypeConcrete::Bitwise(_)
             | CoreTypeConcrete::BuiltinCosts(_)
             | CoreTypeConcrete::EcOp(_)
+            | CoreTypeConcrete::Ecdsa(_)
+            | CoreTypeConcrete::Keccak(_)
+            | CoreTypeConcrete::AddMod(_)
+            | CoreTypeConcrete::MulMod(_)
             | CoreTypeConcrete::Nullable(_)
             | CoreTypeConcrete::Uint8(_)
             | CoreTypeConcrete::Uint16(_)
             | CoreTypeConcrete::Uint32(_)
             | CoreTypeConcrete::Uint64(_)
+            | CoreTypeConcrete::Uint96(_)
+            | CoreTypeConcrete::Bytes31(_)
+            | CoreTypeConcrete::BoundedInt(_)
             | CoreTypeConcrete::Uint128(_)
             | CoreTypeConcrete::Sint8(_)
             | CoreTypeConcrete::Sint16(_)
@@ -32,6 +42,7 @@ pub fn get_type_size_map(
             | CoreTypeConcrete::Sint64(_)
             | CoreTypeConcrete::Sint128(_)
             | CoreTypeConcrete::RangeCheck(_)
+            | CoreTypeConcrete::RangeCheck96(_)
             | CoreTypeConcrete::Box(_)
             | CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::System(_))
             | CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::StorageBaseAddress(_))
@@ -43,11 +54,24 @@ pub fn get_type_size_map(
             | CoreTypeConcrete::Poseidon(_)
             | CoreTypeConcrete::Felt252Dict(_)
             | CoreTypeConcrete::Felt252DictEntry(_)
-            | CoreTypeConcrete::SegmentArena(_) => Some(1),
+            | CoreTypeConcrete::SegmentArena(_)
+            | CoreTypeConcrete::Circuit(CircuitTypeConcrete::CircuitData(_))
+            | CoreTypeConcrete::Circuit(CircuitTypeConcrete::CircuitOutputs(_)) => Some(1),
             CoreTypeConcrete::Array(_)
             | CoreTypeConcrete::Span(_)
             | CoreTypeConcrete::EcPoint(_)
-            | CoreTypeConcrete::SquashedFelt252Dict(_) => Some(2),
+            | CoreTypeConcrete::SquashedFelt252Dict(_)
+            | CoreTypeConcrete::Circuit(CircuitTypeConcrete::CircuitInputAccumulator(_))
+            | CoreTypeConcrete::Circuit(CircuitTypeConcrete::CircuitDescriptor(_)) => Some(2),
+            CoreTypeConcrete::Circuit(
+                CircuitTypeConcrete::CircuitInput(_)
+                | CircuitTypeConcrete::AddModGate(_)
+                | CircuitTypeConcrete::SubModGate(_)
+                | CircuitTypeConcrete::MulModGate(_)
+                | CircuitTypeConcrete::InverseGate(_)
+                | CircuitTypeConcrete::Circuit(_),
+            )
+            | CoreTypeConcrete::ConstArray(_) => Some(0),
             CoreTypeConcrete::NonZero(wrapped_ty)
             | CoreTypeConcrete::Snapshot(wrapped_ty)
             | CoreTypeConcrete::Uninitialized(wrapped_ty) => {
@@ -55,6 +79,14 @@ pub fn get_type_size_map(
             }
             CoreTypeConcrete::EcState(_) => Some(3),
             CoreTypeConcrete::Uint128MulGuarantee(_) => Some(4),
+            CoreTypeConcrete::Enum(enum_type)
+                if enum_zero_niche
+                    && has_zero_niche(&enum_type.variants, registry, &type_sizes) =>
+            {
+                // The enum is represented by the value of its `NonZero` variant, or by zero for its
+                // zero-sized variant - without a variant selector.
+                Some(1)
+            }
             CoreTypeConcrete::Enum(enum_type) => {
                 let mut size = 1;
                 for variant in &enum_type.variants {
@@ -74,3 +106,26 @@ pub fn get_type_size_map(
     }
     Some(type_sizes)
 }
+
+/// Returns whether an enum with the given variants has a zero niche - i.e. it has exactly two
+/// variants, one of which is zero-sized and the other a single cell `NonZero` value. Such an enum
+/// is represented by a single cell, holding zero for the zero-sized variant, and the value of the
+/// other variant otherwise.
+fn has_zero_niche(
+    variants: &[ConcreteTypeId],
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    type_sizes: &TypeSizeMap,
+) -> bool {
+    let is_zero_sized = |ty: &ConcreteTypeId| type_sizes.get(ty) == Some(&0);
+    let is_single_cell_non_zero = |ty: &ConcreteTypeId| {
+        matches!(registry.get_type(ty), Ok(CoreTypeConcrete::NonZero(_)))
+            && type_sizes.get(ty) == Some(&1)
+    };
+    match variants {
+        [first, second] => {
+            (is_zero_sized(first) && is_single_cell_non_zero(second))
+                || (is_single_cell_non_zero(first) && is_zero_sized(second))
+        }
+        _ => false,
+    }
+}
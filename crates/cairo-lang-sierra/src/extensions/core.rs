@@ -1,18 +1,28 @@
 use super::ap_tracking::ApTrackingLibfunc;
 use super::array::{ArrayLibfunc, ArrayType};
+use super::bitfield::BitfieldLibfunc;
 use super::bitwise::BitwiseType;
+use super::blake::BlakeLibfunc;
 use super::boolean::BoolLibfunc;
+use super::bounded_int::{BoundedIntLibfunc, BoundedIntType};
 use super::branch_align::BranchAlignLibfunc;
+use super::bytes31::{Bytes31Libfunc, Bytes31Type};
 use super::casts::CastLibfunc;
+use super::circuit::{CircuitLibfunc, CircuitType};
+use super::const_array::{ConstArrayType, ConstAsSpanLibfunc};
 use super::debug::DebugLibfunc;
 use super::drop::DropLibfunc;
 use super::duplicate::DupLibfunc;
 use super::ec::{EcLibfunc, EcOpType, EcPointType, EcStateType};
+use super::ecdsa::{EcdsaLibfunc, EcdsaType};
+use super::encoding::EncodingLibfunc;
 use super::enm::{EnumLibfunc, EnumType};
 use super::felt252_dict::{
     Felt252DictEntryLibfunc, Felt252DictEntryType, Felt252DictLibfunc, Felt252DictType,
 };
 use super::gas::BuiltinCostsType;
+use super::inline_casm::InlineCasmLibfunc;
+use super::instrumentation::InstrumentationLibfunc;
 use super::int::signed::{
     Sint16Libfunc, Sint16Type, Sint32Libfunc, Sint32Type, Sint64Libfunc, Sint64Type, Sint8Libfunc,
     Sint8Type,
@@ -25,6 +35,9 @@ use super::int::unsigned::{
 use super::int::unsigned128::{U128MulGuaranteeType, Uint128Libfunc, Uint128Type};
 use super::int::unsigned256::Uint256Libfunc;
 use super::int::unsigned512::Uint512Libfunc;
+use super::int::unsigned96::{Uint96Libfunc, Uint96Type};
+use super::keccak::{KeccakLibfunc, KeccakType};
+use super::modular::{AddModType, ModularLibfunc, MulModType};
 use super::modules::boxing::{BoxLibfunc, BoxType};
 use super::modules::felt252::{Felt252Libfunc, Felt252Type};
 use super::modules::function_call::FunctionCallLibfunc;
@@ -33,9 +46,11 @@ use super::modules::mem::MemLibfunc;
 use super::modules::non_zero::{NonZeroType, UnwrapNonZeroLibfunc};
 use super::modules::unconditional_jump::UnconditionalJumpLibfunc;
 use super::nullable::{NullableLibfunc, NullableType};
+use super::oracle::OracleLibfunc;
 use super::pedersen::{PedersenLibfunc, PedersenType};
 use super::poseidon::{PoseidonLibfunc, PoseidonType};
-use super::range_check::RangeCheckType;
+use super::range_check::{RangeCheck96Type, RangeCheckType};
+use super::rlp::RlpLibfunc;
 use super::segment_arena::SegmentArenaType;
 use super::snapshot::{SnapshotTakeLibfunc, SnapshotType};
 use super::span::SpanType;
@@ -50,16 +65,25 @@ define_type_hierarchy! {
         Array(ArrayType),
         Bitwise(BitwiseType),
         Box(BoxType),
+        BoundedInt(BoundedIntType),
+        Bytes31(Bytes31Type),
+        Circuit(CircuitType),
+        ConstArray(ConstArrayType),
         EcOp(EcOpType),
         EcPoint(EcPointType),
         EcState(EcStateType),
+        Ecdsa(EcdsaType),
         Felt252(Felt252Type),
         GasBuiltin(GasBuiltinType),
+        Keccak(KeccakType),
+        AddMod(AddModType),
+        MulMod(MulModType),
         BuiltinCosts(BuiltinCostsType),
         Uint8(Uint8Type),
         Uint16(Uint16Type),
         Uint32(Uint32Type),
         Uint64(Uint64Type),
+        Uint96(Uint96Type),
         Uint128(Uint128Type),
         Uint128MulGuarantee(U128MulGuaranteeType),
         Sint8(Sint8Type),
@@ -70,6 +94,7 @@ define_type_hierarchy! {
         NonZero(NonZeroType),
         Nullable(NullableType),
         RangeCheck(RangeCheckType),
+        RangeCheck96(RangeCheck96Type),
         Uninitialized(UninitializedType),
         Enum(EnumType),
         Struct(StructType),
@@ -89,20 +114,32 @@ define_libfunc_hierarchy! {
     pub enum CoreLibfunc {
         ApTracking(ApTrackingLibfunc),
         Array(ArrayLibfunc),
+        Bitfield(BitfieldLibfunc),
+        Blake(BlakeLibfunc),
         BranchAlign(BranchAlignLibfunc),
         Bool(BoolLibfunc),
         Box(BoxLibfunc),
+        BoundedInt(BoundedIntLibfunc),
+        Bytes31(Bytes31Libfunc),
         Cast(CastLibfunc),
+        Circuit(CircuitLibfunc),
+        ConstAsSpan(ConstAsSpanLibfunc),
         Drop(DropLibfunc),
         Dup(DupLibfunc),
         Ec(EcLibfunc),
+        Ecdsa(EcdsaLibfunc),
+        Encoding(EncodingLibfunc),
         Felt252(Felt252Libfunc),
         FunctionCall(FunctionCallLibfunc),
         Gas(GasLibfunc),
+        InlineCasm(InlineCasmLibfunc),
+        Instrumentation(InstrumentationLibfunc),
+        Keccak(KeccakLibfunc),
         Uint8(Uint8Libfunc),
         Uint16(Uint16Libfunc),
         Uint32(Uint32Libfunc),
         Uint64(Uint64Libfunc),
+        Uint96(Uint96Libfunc),
         Uint128(Uint128Libfunc),
         Uint256(Uint256Libfunc),
         Uint512(Uint512Libfunc),
@@ -112,7 +149,9 @@ define_libfunc_hierarchy! {
         Sint64(Sint64Libfunc),
         Sint128(Sint128Libfunc),
         Mem(MemLibfunc),
+        Modular(ModularLibfunc),
         Nullable(NullableLibfunc),
+        Oracle(OracleLibfunc),
         UnwrapNonZero(UnwrapNonZeroLibfunc),
         UnconditionalJump(UnconditionalJumpLibfunc),
         Enum(EnumLibfunc),
@@ -121,6 +160,7 @@ define_libfunc_hierarchy! {
         Felt252DictEntry(Felt252DictEntryLibfunc),
         Pedersen(PedersenLibfunc),
         Poseidon(PoseidonLibfunc),
+        Rlp(RlpLibfunc),
         StarkNet(StarkNetLibfunc),
         Debug(DebugLibfunc),
         SnapshotTake(SnapshotTakeLibfunc),
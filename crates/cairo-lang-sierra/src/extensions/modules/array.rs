@@ -1,3 +1,10 @@
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+use super::casts::get_int_type_range;
+use super::felt252::Felt252Type;
+use super::gas::GasBuiltinType;
+use super::int::unsigned128::Uint128Type;
 use super::range_check::RangeCheckType;
 use super::snapshot::snapshot_ty;
 use super::starknet::getter::boxed_ty;
@@ -11,7 +18,9 @@ use crate::extensions::types::{
     GenericTypeArgGenericType, GenericTypeArgGenericTypeWrapper, TypeInfo,
 };
 use crate::extensions::{
-    args_as_single_type, NamedType, OutputVarReferenceInfo, SpecializationError,
+    args_as_single_type, NamedLibfunc, NamedType, NoGenericArgsGenericLibfunc,
+    OutputVarReferenceInfo, SignatureBasedConcreteLibfunc, SpecializationContext,
+    SpecializationError,
 };
 use crate::ids::{ConcreteTypeId, GenericTypeId};
 use crate::program::GenericArg;
@@ -51,8 +60,14 @@ define_libfunc_hierarchy! {
         PopFront(ArrayPopFrontLibfunc),
         PopFrontConsume(ArrayPopFrontConsumeLibfunc),
         Get(ArrayGetLibfunc),
+        GetUsize(ArrayGetUsizeLibfunc),
         Slice(ArraySliceLibfunc),
+        ExtendFromSpan(ArrayExtendFromSpanLibfunc),
+        SpanEq(SpanEqLibfunc),
+        SpanBinarySearch(SpanBinarySearchLibfunc),
+        SpanSortVerified(SpanSortVerifiedLibfunc),
         Len(ArrayLenLibfunc),
+        LenExceeds(ArrayLenExceedsLibfunc),
         SnapshotPopFront(ArraySnapshotPopFrontLibfunc),
         SnapshotPopBack(ArraySnapshotPopBackLibfunc),
     }, ArrayConcreteLibfunc
@@ -105,6 +120,76 @@ impl SignatureAndTypeGenericLibfunc for ArrayLenLibfuncWrapped {
 }
 pub type ArrayLenLibfunc = WrapSignatureAndTypeGenericLibfunc<ArrayLenLibfuncWrapped>;
 
+/// Libfunc for checking whether the length of an array snapshot exceeds the given maximal length,
+/// in a single bounds check - as done by entry points limiting the size of their calldata before
+/// deserializing it.
+#[derive(Default)]
+pub struct ArrayLenExceedsLibfunc {}
+impl NamedLibfunc for ArrayLenExceedsLibfunc {
+    type Concrete = ArrayLenExceedsConcreteLibfunc;
+    const STR_ID: &'static str = "array_len_exceeds";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let (ty, max_len) = match args {
+            [GenericArg::Type(ty), GenericArg::Value(max_len)] => (ty.clone(), max_len),
+            [_, _] => return Err(SpecializationError::UnsupportedGenericArg),
+            _ => return Err(SpecializationError::WrongNumberOfGenericArgs),
+        };
+        if max_len.is_negative() || max_len > &BigInt::from(u32::MAX) {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        let arr_ty = context.get_wrapped_concrete_type(ArrayType::id(), ty)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let checked_vars = vec![OutputVarInfo::new_builtin(range_check_type.clone(), 0)];
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type).with_allow_add_const(),
+                ParamSignature::new(snapshot_ty(context, arr_ty)?),
+            ],
+            branch_signatures: vec![
+                // Within the maximal length.
+                BranchSignature {
+                    vars: checked_vars.clone(),
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Exceeds the maximal length.
+                BranchSignature {
+                    vars: checked_vars,
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        let signature = <Self as NamedLibfunc>::specialize_signature(self, context.upcast(), args)?;
+        let [GenericArg::Type(ty), GenericArg::Value(max_len)] = args else {
+            unreachable!("The generic args were validated by `specialize_signature`.");
+        };
+        Ok(ArrayLenExceedsConcreteLibfunc { ty: ty.clone(), max_len: max_len.clone(), signature })
+    }
+}
+
+pub struct ArrayLenExceedsConcreteLibfunc {
+    pub ty: ConcreteTypeId,
+    pub max_len: BigInt,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for ArrayLenExceedsConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
 /// Libfunc for pushing a value into the end of an array.
 #[derive(Default)]
 pub struct ArrayAppendLibfuncWrapped {}
@@ -267,6 +352,49 @@ impl SignatureAndTypeGenericLibfunc for ArrayGetLibfuncWrapped {
 }
 pub type ArrayGetLibfunc = WrapSignatureAndTypeGenericLibfunc<ArrayGetLibfuncWrapped>;
 
+/// Libfunc for fetching a snapshot of a value from a specific array index, without boxing it.
+#[derive(Default)]
+pub struct ArrayGetUsizeLibfuncWrapped {}
+impl SignatureAndTypeGenericLibfunc for ArrayGetUsizeLibfuncWrapped {
+    const STR_ID: &'static str = "array_get_usize";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        ty: ConcreteTypeId,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let arr_type = context.get_wrapped_concrete_type(ArrayType::id(), ty.clone())?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let index_type = context.get_concrete_type(ArrayIndexType::id(), &[])?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+            ParamSignature::new(snapshot_ty(context, arr_type)?),
+            ParamSignature::new(index_type),
+        ];
+        let rc_output_info = OutputVarInfo::new_builtin(range_check_type, 0);
+        let branch_signatures = vec![
+            // Success - returns rc and the snapshot of the element.
+            BranchSignature {
+                vars: vec![
+                    rc_output_info.clone(),
+                    OutputVarInfo {
+                        ty: snapshot_ty(context, ty)?,
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+            // Failure - index out of bounds, returns only the range check buffer.
+            BranchSignature {
+                vars: vec![rc_output_info],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+pub type ArrayGetUsizeLibfunc = WrapSignatureAndTypeGenericLibfunc<ArrayGetUsizeLibfuncWrapped>;
+
 /// Libfunc for getting a slice of an array snapshot.
 #[derive(Default)]
 pub struct ArraySliceLibfuncWrapped {}
@@ -316,6 +444,256 @@ impl SignatureAndTypeGenericLibfunc for ArraySliceLibfuncWrapped {
 }
 pub type ArraySliceLibfunc = WrapSignatureAndTypeGenericLibfunc<ArraySliceLibfuncWrapped>;
 
+/// Libfunc for appending all the elements of an array snapshot (the content of a span) to the end
+/// of an array. The copy loop is charged for at runtime, per copied cell, from the gas builtin.
+#[derive(Default)]
+pub struct ArrayExtendFromSpanLibfuncWrapped {}
+impl SignatureAndTypeGenericLibfunc for ArrayExtendFromSpanLibfuncWrapped {
+    const STR_ID: &'static str = "array_extend_from_span";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        ty: ConcreteTypeId,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        // The elements are copied out of the snapshot, so they must be duplicatable.
+        if !context.as_type_specialization_context().get_type_info(ty.clone())?.duplicatable {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        let arr_ty = context.get_wrapped_concrete_type(ArrayType::id(), ty)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+            ParamSignature::new(gas_builtin_type.clone()),
+            ParamSignature::new(arr_ty.clone()),
+            ParamSignature::new(snapshot_ty(context, arr_ty.clone())?),
+        ];
+        let branch_signatures = vec![
+            // Success - all the elements were appended.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo {
+                        ty: range_check_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                    },
+                    OutputVarInfo {
+                        ty: gas_builtin_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: arr_ty.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 2 },
+                    },
+                ],
+                ap_change: SierraApChange::Unknown,
+            },
+            // Failure - not enough gas for copying the elements, the array is unchanged.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo::new_builtin(range_check_type, 0),
+                    OutputVarInfo {
+                        ty: gas_builtin_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: arr_ty,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 2 },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+pub type ArrayExtendFromSpanLibfunc =
+    WrapSignatureAndTypeGenericLibfunc<ArrayExtendFromSpanLibfuncWrapped>;
+
+/// Libfunc for comparing the content of two array snapshots (spans) cell by cell. The comparison
+/// loop is charged for at runtime, per compared cell, from the gas builtin.
+///
+/// Only supported for spans of felt252s and integers, as the cells of other types do not
+/// necessarily determine their equality - e.g. comparing boxes would compare their addresses.
+#[derive(Default)]
+pub struct SpanEqLibfuncWrapped {}
+impl SignatureAndTypeGenericLibfunc for SpanEqLibfuncWrapped {
+    const STR_ID: &'static str = "span_eq";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        ty: ConcreteTypeId,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let generic_id =
+            context.as_type_specialization_context().get_type_info(ty.clone())?.long_id.generic_id;
+        if generic_id != Felt252Type::ID && get_int_type_range(&generic_id).is_none() {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        let arr_snapshot_ty =
+            snapshot_ty(context, context.get_wrapped_concrete_type(ArrayType::id(), ty)?)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+            ParamSignature::new(gas_builtin_type.clone()),
+            ParamSignature::new(arr_snapshot_ty.clone()),
+            ParamSignature::new(arr_snapshot_ty),
+        ];
+        let compared_vars = vec![
+            OutputVarInfo {
+                ty: range_check_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+            },
+            OutputVarInfo {
+                ty: gas_builtin_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+            },
+        ];
+        let branch_signatures = vec![
+            // Equal.
+            BranchSignature { vars: compared_vars.clone(), ap_change: SierraApChange::Unknown },
+            // Not equal.
+            BranchSignature { vars: compared_vars, ap_change: SierraApChange::Unknown },
+            // Failure - not enough gas for comparing the elements.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo::new_builtin(range_check_type, 0),
+                    OutputVarInfo {
+                        ty: gas_builtin_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+pub type SpanEqLibfunc = WrapSignatureAndTypeGenericLibfunc<SpanEqLibfuncWrapped>;
+
+/// Libfunc for searching for a value in a span of `u128`s sorted in ascending order.
+///
+/// The search is done by a CASM loop, and its cost is withdrawn from the gas builtin at runtime,
+/// per iteration.
+#[derive(Default)]
+pub struct SpanBinarySearchLibfunc {}
+impl NoGenericArgsGenericLibfunc for SpanBinarySearchLibfunc {
+    const STR_ID: &'static str = "span_binary_search";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let u128_ty = context.get_concrete_type(Uint128Type::id(), &[])?;
+        let arr_snapshot_ty = snapshot_ty(
+            context,
+            context.get_wrapped_concrete_type(ArrayType::id(), u128_ty.clone())?,
+        )?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let index_type = context.get_concrete_type(ArrayIndexType::id(), &[])?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+            ParamSignature::new(gas_builtin_type.clone()),
+            ParamSignature::new(arr_snapshot_ty),
+            ParamSignature::new(u128_ty),
+        ];
+        let searched_vars = vec![
+            OutputVarInfo {
+                ty: range_check_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+            },
+            OutputVarInfo {
+                ty: gas_builtin_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+            },
+            OutputVarInfo {
+                ty: index_type,
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 2 },
+            },
+        ];
+        let branch_signatures = vec![
+            // Found - returns the index of the found element.
+            BranchSignature { vars: searched_vars.clone(), ap_change: SierraApChange::Unknown },
+            // Not found - returns the index where the value should be inserted.
+            BranchSignature { vars: searched_vars, ap_change: SierraApChange::Unknown },
+            // Failure - not enough gas for completing the search.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo {
+                        ty: range_check_type,
+                        ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                    },
+                    OutputVarInfo {
+                        ty: gas_builtin_type,
+                        ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                    },
+                ],
+                ap_change: SierraApChange::Unknown,
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+
+/// Libfunc for creating a sorted copy of a span of `u128`s.
+///
+/// The sorting permutation is supplied by a hint, and only verified by the CASM code, which costs
+/// a constant amount per element. The cost is withdrawn from the gas builtin at runtime.
+#[derive(Default)]
+pub struct SpanSortVerifiedLibfunc {}
+impl NoGenericArgsGenericLibfunc for SpanSortVerifiedLibfunc {
+    const STR_ID: &'static str = "span_sort_verified";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let u128_ty = context.get_concrete_type(Uint128Type::id(), &[])?;
+        let arr_ty = context.get_wrapped_concrete_type(ArrayType::id(), u128_ty)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+            ParamSignature::new(gas_builtin_type.clone()),
+            ParamSignature::new(snapshot_ty(context, arr_ty.clone())?),
+        ];
+        let branch_signatures = vec![
+            // Success - returns the sorted array.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo {
+                        ty: range_check_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                    },
+                    OutputVarInfo {
+                        ty: gas_builtin_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: arr_ty,
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 2 },
+                    },
+                ],
+                ap_change: SierraApChange::Unknown,
+            },
+            // Failure - not enough gas for verifying the sorted elements.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo::new_builtin(range_check_type, 0),
+                    OutputVarInfo {
+                        ty: gas_builtin_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+
 /// Libfunc for popping the first value from the beginning of an array snapshot.
 #[derive(Default)]
 pub struct ArraySnapshotPopFrontLibfuncWrapped {}
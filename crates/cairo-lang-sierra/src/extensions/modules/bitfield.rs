@@ -0,0 +1,223 @@
+//! Sierra example:
+//! ```ignore
+//! type u8 = u8;
+//! type u32 = u32;
+//! type Layout = Struct<ut@Tuple, u8, u32>;
+//! libfunc pack = bitfield_pack<Layout>;
+//! libfunc unpack = bitfield_unpack<Layout>;
+//! ...
+//! pack(fields) -> (packed);
+//! unpack(rc, packed) { fallthrough(rc, small, big) 2000(rc) };
+//! ```
+//! The first field of the layout is stored in the lowest bits of the packed value.
+
+use super::felt252::Felt252Type;
+use super::int::unsigned::{Uint16Type, Uint32Type, Uint64Type, Uint8Type};
+use super::int::unsigned128::Uint128Type;
+use super::range_check::RangeCheckType;
+use super::structure::StructType;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::{
+    args_as_single_type, NamedLibfunc, NamedType, OutputVarReferenceInfo,
+    SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, UserTypeId};
+use crate::program::GenericArg;
+
+/// The maximal total width of the fields of a bitfield layout, so that any packed value is
+/// smaller than the field's prime.
+pub const BITFIELD_MAX_TOTAL_WIDTH: usize = 251;
+
+define_libfunc_hierarchy! {
+    pub enum BitfieldLibfunc {
+        Pack(BitfieldPackLibfunc),
+        Unpack(BitfieldUnpackLibfunc),
+    }, BitfieldConcreteLibfunc
+}
+
+/// A bitfield layout, given as a tuple of unsigned integer types.
+struct BitfieldLayout {
+    /// The tuple type of the layout.
+    ty: ConcreteTypeId,
+    /// The types of the fields of the layout.
+    fields: Vec<ConcreteTypeId>,
+    /// The widths in bits of the fields of the layout.
+    widths: Vec<usize>,
+}
+impl BitfieldLayout {
+    /// Extracts the layout from the generic args of a bitfield libfunc.
+    fn new(
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self, SpecializationError> {
+        let ty = args_as_single_type(args)?;
+        let long_id = context.get_type_info(ty.clone())?.long_id;
+        let tuple_id = GenericArg::UserType(UserTypeId::from_string("Tuple"));
+        let fields = match long_id.generic_args.split_first() {
+            Some((first, fields)) if long_id.generic_id == StructType::ID && *first == tuple_id => {
+                fields
+                    .iter()
+                    .map(|arg| match arg {
+                        GenericArg::Type(ty) => Ok(ty.clone()),
+                        _ => Err(SpecializationError::UnsupportedGenericArg),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            _ => return Err(SpecializationError::UnsupportedGenericArg),
+        };
+        let widths = fields
+            .iter()
+            .map(|field| {
+                let generic_id = context.get_type_info(field.clone())?.long_id.generic_id;
+                if generic_id == Uint8Type::ID {
+                    Ok(8)
+                } else if generic_id == Uint16Type::ID {
+                    Ok(16)
+                } else if generic_id == Uint32Type::ID {
+                    Ok(32)
+                } else if generic_id == Uint64Type::ID {
+                    Ok(64)
+                } else if generic_id == Uint128Type::ID {
+                    Ok(128)
+                } else {
+                    Err(SpecializationError::UnsupportedGenericArg)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if widths.is_empty() || widths.iter().sum::<usize>() > BITFIELD_MAX_TOTAL_WIDTH {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        Ok(Self { ty, fields, widths })
+    }
+}
+
+pub struct BitfieldConcreteLibfuncInfo {
+    pub signature: LibfuncSignature,
+    /// The widths in bits of the fields of the layout, starting from the lowest bits.
+    pub widths: Vec<usize>,
+}
+impl SignatureBasedConcreteLibfunc for BitfieldConcreteLibfuncInfo {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for packing the fields of a tuple of unsigned integers into a single felt252.
+#[derive(Default)]
+pub struct BitfieldPackLibfunc {}
+impl BitfieldPackLibfunc {
+    /// Creates the specialization of the pack libfunc with the given template arguments.
+    fn specialize_concrete_lib_func(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<BitfieldConcreteLibfuncInfo, SpecializationError> {
+        let BitfieldLayout { ty, widths, .. } = BitfieldLayout::new(context, args)?;
+        let ref_info = if widths.len() == 1 {
+            OutputVarReferenceInfo::PartialParam { param_idx: 0 }
+        } else {
+            OutputVarReferenceInfo::NewTempVar { idx: 0 }
+        };
+        Ok(BitfieldConcreteLibfuncInfo {
+            signature: LibfuncSignature::new_non_branch_ex(
+                vec![ParamSignature::new(ty)],
+                vec![OutputVarInfo {
+                    ty: context.get_concrete_type(Felt252Type::id(), &[])?,
+                    ref_info,
+                }],
+                SierraApChange::Known { new_vars_only: false },
+            ),
+            widths,
+        })
+    }
+}
+impl NamedLibfunc for BitfieldPackLibfunc {
+    type Concrete = BitfieldConcreteLibfuncInfo;
+    const STR_ID: &'static str = "bitfield_pack";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(self.specialize_concrete_lib_func(context, args)?.signature)
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        self.specialize_concrete_lib_func(context.upcast(), args)
+    }
+}
+
+/// Libfunc for unpacking a felt252 into the fields of a tuple of unsigned integers.
+/// Fails if the value doesn't fit in the total width of the fields.
+/// On success, the fields are returned as separate variables.
+#[derive(Default)]
+pub struct BitfieldUnpackLibfunc {}
+impl BitfieldUnpackLibfunc {
+    /// Creates the specialization of the unpack libfunc with the given template arguments.
+    fn specialize_concrete_lib_func(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<BitfieldConcreteLibfuncInfo, SpecializationError> {
+        let BitfieldLayout { fields, widths, .. } = BitfieldLayout::new(context, args)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let rc_output_info = OutputVarInfo::new_builtin(range_check_type.clone(), 0);
+        Ok(BitfieldConcreteLibfuncInfo {
+            signature: LibfuncSignature {
+                param_signatures: vec![
+                    ParamSignature::new(range_check_type).with_allow_add_const(),
+                    ParamSignature::new(context.get_concrete_type(Felt252Type::id(), &[])?),
+                ],
+                branch_signatures: vec![
+                    // Success.
+                    BranchSignature {
+                        vars: [rc_output_info.clone()]
+                            .into_iter()
+                            .chain(fields.into_iter().map(|ty| OutputVarInfo {
+                                ty,
+                                ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                            }))
+                            .collect(),
+                        ap_change: SierraApChange::Known { new_vars_only: false },
+                    },
+                    // Failure.
+                    BranchSignature {
+                        vars: vec![rc_output_info],
+                        ap_change: SierraApChange::Known { new_vars_only: false },
+                    },
+                ],
+                fallthrough: Some(0),
+            },
+            widths,
+        })
+    }
+}
+impl NamedLibfunc for BitfieldUnpackLibfunc {
+    type Concrete = BitfieldConcreteLibfuncInfo;
+    const STR_ID: &'static str = "bitfield_unpack";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(self.specialize_concrete_lib_func(context, args)?.signature)
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        self.specialize_concrete_lib_func(context.upcast(), args)
+    }
+}
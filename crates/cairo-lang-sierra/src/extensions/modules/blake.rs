@@ -0,0 +1,84 @@
+use itertools::{chain, repeat_n};
+
+use super::bitwise::BitwiseType;
+use super::int::unsigned::Uint32Type;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+
+/// The number of u32 words in the Blake2s state.
+pub const BLAKE2S_STATE_WORDS: usize = 8;
+/// The number of u32 words in a Blake2s message block.
+pub const BLAKE2S_BLOCK_WORDS: usize = 16;
+
+define_libfunc_hierarchy! {
+    pub enum BlakeLibfunc {
+        Compress(Blake2sCompressLibfunc),
+        Finalize(Blake2sFinalizeLibfunc),
+    }, BlakeConcreteLibfunc
+}
+
+/// Libfunc for applying the Blake2s compression function to a block that is not the last block of
+/// the message. Gets the 8 words of the state, the number of bytes compressed so far - including
+/// the given block - and the 16 words of the block, and returns the updated state.
+///
+/// The 32-bit operations of the compression are computed using the Bitwise builtin.
+#[derive(Default)]
+pub struct Blake2sCompressLibfunc {}
+impl NoGenericArgsGenericLibfunc for Blake2sCompressLibfunc {
+    const STR_ID: &'static str = "blake2s_compress";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        blake2s_compression_signature(context)
+    }
+}
+
+/// Libfunc for applying the Blake2s compression function to the last block of the message.
+/// Same as `blake2s_compress`, except that the block is marked as the final one.
+#[derive(Default)]
+pub struct Blake2sFinalizeLibfunc {}
+impl NoGenericArgsGenericLibfunc for Blake2sFinalizeLibfunc {
+    const STR_ID: &'static str = "blake2s_finalize";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        blake2s_compression_signature(context)
+    }
+}
+
+/// Returns the signature of the Blake2s compression libfuncs.
+fn blake2s_compression_signature(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<LibfuncSignature, SpecializationError> {
+    let bitwise_ty = context.get_concrete_type(BitwiseType::id(), &[])?;
+    let u32_ty = context.get_concrete_type(Uint32Type::id(), &[])?;
+    Ok(LibfuncSignature::new_non_branch_ex(
+        chain!(
+            [ParamSignature::new(bitwise_ty.clone()).with_allow_add_const()],
+            // The state, the byte count and the message block.
+            repeat_n(
+                ParamSignature::new(u32_ty.clone()),
+                BLAKE2S_STATE_WORDS + 1 + BLAKE2S_BLOCK_WORDS
+            )
+        )
+        .collect(),
+        chain!(
+            [OutputVarInfo::new_builtin(bitwise_ty, 0)],
+            repeat_n(
+                OutputVarInfo { ty: u32_ty, ref_info: OutputVarReferenceInfo::SimpleDerefs },
+                BLAKE2S_STATE_WORDS
+            )
+        )
+        .collect(),
+        SierraApChange::Known { new_vars_only: false },
+    ))
+}
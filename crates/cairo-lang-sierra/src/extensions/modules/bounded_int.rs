@@ -0,0 +1,350 @@
+//! Sierra example:
+//! ```ignore
+//! type RangeCheck = RangeCheck;
+//! type Digit = BoundedInt<0, 9>;
+//! type NonZeroDigit = BoundedInt<1, 9>;
+//! type DigitsSum = BoundedInt<1, 18>;
+//! type DigitsQuotient = BoundedInt<0, 9>;
+//! type DigitsRemainder = BoundedInt<0, 8>;
+//! libfunc add = bounded_int_add<Digit, NonZeroDigit>;
+//! libfunc div_rem = bounded_int_div_rem<Digit, NonZeroDigit>;
+//! ...
+//! add(a, b) -> (sum);
+//! div_rem(rc, a, b) -> (rc, q, r);
+//! ```
+//! The bounds of the outputs are computed from the bounds of the inputs at specialization time, so
+//! the operations never overflow, and require no range checks other than for the division.
+
+use std::ops::Shl;
+
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+
+use super::range_check::RangeCheckType;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::type_specialization_context::TypeSpecializationContext;
+use crate::extensions::types::TypeInfo;
+use crate::extensions::{
+    ConcreteType, GenericLibfunc, NamedLibfunc, NamedType, OutputVarReferenceInfo,
+    SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, GenericLibfuncId, GenericTypeId};
+use crate::program::GenericArg;
+
+/// The log2 of the bound on the absolute values of bounded integers. Values are smaller than half
+/// of the field's prime in absolute value, so each value has a single representation as a
+/// felt252, and arithmetic on them never wraps around.
+pub const BOUNDED_INT_ABS_BOUND_LOG2: usize = 250;
+/// The log2 of the bound on the divisors and the quotients of a bounded integer division, so that
+/// they can be range checked.
+pub const BOUNDED_INT_DIV_BOUND_LOG2: usize = 128;
+/// The log2 of the bound on the product of the divisor and the quotient of a bounded integer
+/// division, so that verifying the division never wraps around the field's prime.
+pub const BOUNDED_INT_DIV_PRODUCT_BOUND_LOG2: usize = 251;
+
+/// The inclusive range of the values of a bounded integer type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoundedIntRange {
+    pub min: BigInt,
+    pub max: BigInt,
+}
+impl BoundedIntRange {
+    /// Returns the range if it is not empty and its values are within the supported bounds.
+    pub fn new(min: BigInt, max: BigInt) -> Result<Self, SpecializationError> {
+        let abs_bound = BigInt::one().shl(BOUNDED_INT_ABS_BOUND_LOG2);
+        if min > max || min.abs() >= abs_bound || max.abs() >= abs_bound {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        Ok(Self { min, max })
+    }
+
+    /// Returns the generic args of the bounded integer type with this range.
+    fn generic_args(&self) -> [GenericArg; 2] {
+        [GenericArg::Value(self.min.clone()), GenericArg::Value(self.max.clone())]
+    }
+}
+
+/// Type for integers within the inclusive range given by its generic args.
+#[derive(Default)]
+pub struct BoundedIntType {}
+impl NamedType for BoundedIntType {
+    type Concrete = BoundedIntConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("BoundedInt");
+
+    fn specialize(
+        &self,
+        _context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        let [GenericArg::Value(min), GenericArg::Value(max)] = args else {
+            return Err(if args.len() == 2 {
+                SpecializationError::UnsupportedGenericArg
+            } else {
+                SpecializationError::WrongNumberOfGenericArgs
+            });
+        };
+        Ok(BoundedIntConcreteType {
+            info: TypeInfo {
+                long_id: Self::concrete_type_long_id(args),
+                storable: true,
+                droppable: true,
+                duplicatable: true,
+                zero_sized: false,
+            },
+            range: BoundedIntRange::new(min.clone(), max.clone())?,
+        })
+    }
+}
+
+pub struct BoundedIntConcreteType {
+    pub info: TypeInfo,
+    pub range: BoundedIntRange,
+}
+impl ConcreteType for BoundedIntConcreteType {
+    fn info(&self) -> &TypeInfo {
+        &self.info
+    }
+}
+
+/// Returns the range of the given bounded integer type.
+fn bounded_int_range(
+    context: &dyn SignatureSpecializationContext,
+    ty: &ConcreteTypeId,
+) -> Result<BoundedIntRange, SpecializationError> {
+    let long_id = context.get_type_info(ty.clone())?.long_id;
+    match &long_id.generic_args[..] {
+        [GenericArg::Value(min), GenericArg::Value(max)]
+            if long_id.generic_id == BoundedIntType::ID =>
+        {
+            BoundedIntRange::new(min.clone(), max.clone())
+        }
+        _ => Err(SpecializationError::UnsupportedGenericArg),
+    }
+}
+
+/// Returns the bounded integer type with the given range.
+fn bounded_int_ty(
+    context: &dyn SignatureSpecializationContext,
+    range: &BoundedIntRange,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    context.get_concrete_type(BoundedIntType::id(), &range.generic_args())
+}
+
+/// Returns the two bounded integer types given as the generic args of a binary operation, along
+/// with their ranges.
+fn binary_operation_args(
+    context: &dyn SignatureSpecializationContext,
+    args: &[GenericArg],
+) -> Result<[(ConcreteTypeId, BoundedIntRange); 2], SpecializationError> {
+    let [GenericArg::Type(lhs), GenericArg::Type(rhs)] = args else {
+        return Err(if args.len() == 2 {
+            SpecializationError::UnsupportedGenericArg
+        } else {
+            SpecializationError::WrongNumberOfGenericArgs
+        });
+    };
+    Ok([
+        (lhs.clone(), bounded_int_range(context, lhs)?),
+        (rhs.clone(), bounded_int_range(context, rhs)?),
+    ])
+}
+
+define_libfunc_hierarchy! {
+    pub enum BoundedIntLibfunc {
+        BinaryOperation(BoundedIntBinaryOperationLibfunc),
+        DivRem(BoundedIntDivRemLibfunc),
+    }, BoundedIntConcreteLibfunc
+}
+
+/// Bounded integer binary operators which never overflow.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BoundedIntBinaryOperator {
+    Add,
+    Sub,
+    Mul,
+}
+impl BoundedIntBinaryOperator {
+    /// Returns the range of the results of the operator on values of the given ranges.
+    fn result_range(
+        &self,
+        lhs: &BoundedIntRange,
+        rhs: &BoundedIntRange,
+    ) -> Result<BoundedIntRange, SpecializationError> {
+        match self {
+            Self::Add => BoundedIntRange::new(&lhs.min + &rhs.min, &lhs.max + &rhs.max),
+            Self::Sub => BoundedIntRange::new(&lhs.min - &rhs.max, &lhs.max - &rhs.min),
+            Self::Mul => {
+                let products = [
+                    &lhs.min * &rhs.min,
+                    &lhs.min * &rhs.max,
+                    &lhs.max * &rhs.min,
+                    &lhs.max * &rhs.max,
+                ];
+                BoundedIntRange::new(
+                    products.iter().min().unwrap().clone(),
+                    products.iter().max().unwrap().clone(),
+                )
+            }
+        }
+    }
+}
+
+/// Libfunc for bounded integer binary operations.
+pub struct BoundedIntBinaryOperationLibfunc {
+    pub operator: BoundedIntBinaryOperator,
+}
+impl BoundedIntBinaryOperationLibfunc {
+    fn new(operator: BoundedIntBinaryOperator) -> Self {
+        Self { operator }
+    }
+    const ADD: &str = "bounded_int_add";
+    const SUB: &str = "bounded_int_sub";
+    const MUL: &str = "bounded_int_mul";
+}
+impl GenericLibfunc for BoundedIntBinaryOperationLibfunc {
+    type Concrete = BoundedIntBinaryOpConcreteLibfunc;
+
+    fn supported_ids() -> Vec<GenericLibfuncId> {
+        vec![
+            GenericLibfuncId::from(Self::ADD),
+            GenericLibfuncId::from(Self::SUB),
+            GenericLibfuncId::from(Self::MUL),
+        ]
+    }
+
+    fn by_id(id: &GenericLibfuncId) -> Option<Self> {
+        match id.0.as_str() {
+            Self::ADD => Some(Self::new(BoundedIntBinaryOperator::Add)),
+            Self::SUB => Some(Self::new(BoundedIntBinaryOperator::Sub)),
+            Self::MUL => Some(Self::new(BoundedIntBinaryOperator::Mul)),
+            _ => None,
+        }
+    }
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let [(lhs, lhs_range), (rhs, rhs_range)] = binary_operation_args(context, args)?;
+        let result_range = self.operator.result_range(&lhs_range, &rhs_range)?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature::new(lhs), ParamSignature::new(rhs)],
+            vec![OutputVarInfo {
+                ty: bounded_int_ty(context, &result_range)?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(BoundedIntBinaryOpConcreteLibfunc {
+            operator: self.operator,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
+pub struct BoundedIntBinaryOpConcreteLibfunc {
+    pub operator: BoundedIntBinaryOperator,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for BoundedIntBinaryOpConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for the division with remainder of a non-negative bounded integer by a positive one.
+/// The divisor and the quotient must be smaller than 2**128, and the product of the divisor by
+/// the quotient must be at most 2**251, so that the division may be verified with range checks.
+#[derive(Default)]
+pub struct BoundedIntDivRemLibfunc {}
+impl BoundedIntDivRemLibfunc {
+    /// Creates the specialization of the libfunc with the given template arguments.
+    fn specialize_concrete_lib_func(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<BoundedIntDivRemConcreteLibfunc, SpecializationError> {
+        let [(lhs, lhs_range), (rhs, rhs_range)] = binary_operation_args(context, args)?;
+        if lhs_range.min.is_negative() || !rhs_range.min.is_positive() {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        let quotient_range =
+            BoundedIntRange::new(&lhs_range.min / &rhs_range.max, &lhs_range.max / &rhs_range.min)?;
+        let remainder_range = BoundedIntRange::new(
+            BigInt::zero(),
+            (&rhs_range.max - BigInt::one()).min(lhs_range.max.clone()),
+        )?;
+        let div_bound = BigInt::one().shl(BOUNDED_INT_DIV_BOUND_LOG2);
+        if rhs_range.max >= div_bound
+            || quotient_range.max >= div_bound
+            || (&quotient_range.max + BigInt::one()) * &rhs_range.max
+                > BigInt::one().shl(BOUNDED_INT_DIV_PRODUCT_BOUND_LOG2)
+        {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let signature = LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+                ParamSignature::new(lhs),
+                ParamSignature::new(rhs),
+            ],
+            vec![
+                OutputVarInfo::new_builtin(range_check_type, 0),
+                OutputVarInfo {
+                    ty: bounded_int_ty(context, &quotient_range)?,
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                },
+                OutputVarInfo {
+                    ty: bounded_int_ty(context, &remainder_range)?,
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+                },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        );
+        Ok(BoundedIntDivRemConcreteLibfunc { signature, quotient_range })
+    }
+}
+impl NamedLibfunc for BoundedIntDivRemLibfunc {
+    type Concrete = BoundedIntDivRemConcreteLibfunc;
+    const STR_ID: &'static str = "bounded_int_div_rem";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(self.specialize_concrete_lib_func(context, args)?.signature)
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        self.specialize_concrete_lib_func(context.upcast(), args)
+    }
+}
+
+pub struct BoundedIntDivRemConcreteLibfunc {
+    pub signature: LibfuncSignature,
+    /// The range of the quotient, which is verified by the libfunc.
+    pub quotient_range: BoundedIntRange,
+}
+impl SignatureBasedConcreteLibfunc for BoundedIntDivRemConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
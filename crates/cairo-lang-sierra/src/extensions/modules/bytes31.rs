@@ -0,0 +1,152 @@
+use num_bigint::BigInt;
+
+use super::consts::{ConstGenLibfunc, WrapConstGenLibfunc};
+use super::felt252::Felt252Type;
+use super::int::unsigned::Uint8Type;
+use super::range_check::RangeCheckType;
+use super::try_from_felt252::{TryFromFelt252, TryFromFelt252Libfunc};
+use super::utils::reinterpret_cast_signature;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
+    SpecializationError,
+};
+use crate::ids::GenericTypeId;
+
+/// Type for bytes31 - a word of (at most) 31 bytes, a value in the range [0, 2 ** 248).
+#[derive(Default)]
+pub struct Bytes31Type {}
+impl NoGenericArgsGenericType for Bytes31Type {
+    const ID: GenericTypeId = GenericTypeId::new_inline("bytes31");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const ZERO_SIZED: bool = false;
+}
+
+define_libfunc_hierarchy! {
+    pub enum Bytes31Libfunc {
+        Const(Bytes31ConstLibfunc),
+        ToFelt252(Bytes31ToFelt252Libfunc),
+        TryFromFelt252(TryFromFelt252Libfunc<Bytes31TryFromFelt252Trait>),
+        AppendU8(Bytes31AppendU8Libfunc),
+        SplitU8(Bytes31SplitU8Libfunc),
+    }, Bytes31ConcreteLibfunc
+}
+
+/// Libfunc for creating a constant bytes31.
+#[derive(Default)]
+pub struct Bytes31ConstLibfuncWrapped {}
+impl ConstGenLibfunc for Bytes31ConstLibfuncWrapped {
+    const STR_ID: &'static str = "bytes31_const";
+    const GENERIC_TYPE_ID: GenericTypeId = <Bytes31Type as NoGenericArgsGenericType>::ID;
+
+    fn bound() -> BigInt {
+        BigInt::from(2).pow(248)
+    }
+}
+
+pub type Bytes31ConstLibfunc = WrapConstGenLibfunc<Bytes31ConstLibfuncWrapped>;
+
+/// Libfunc for converting a bytes31 into a felt252.
+#[derive(Default)]
+pub struct Bytes31ToFelt252Libfunc {}
+impl NoGenericArgsGenericLibfunc for Bytes31ToFelt252Libfunc {
+    const STR_ID: &'static str = "bytes31_to_felt252";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(reinterpret_cast_signature(
+            context.get_concrete_type(Bytes31Type::id(), &[])?,
+            context.get_concrete_type(Felt252Type::id(), &[])?,
+        ))
+    }
+}
+
+/// Libfunc for attempting to convert a felt252 into a bytes31.
+#[derive(Default)]
+pub struct Bytes31TryFromFelt252Trait;
+impl TryFromFelt252 for Bytes31TryFromFelt252Trait {
+    const STR_ID: &'static str = "bytes31_try_from_felt252";
+    const GENERIC_TYPE_ID: GenericTypeId = <Bytes31Type as NoGenericArgsGenericType>::ID;
+}
+
+/// Libfunc for appending a byte to a bytes31, as its new lowest byte - `value * 256 + byte`.
+/// Branches to the failure branch if the result does not fit in 31 bytes, that is, if
+/// `value >= 2 ** 240`.
+#[derive(Default)]
+pub struct Bytes31AppendU8Libfunc {}
+impl NoGenericArgsGenericLibfunc for Bytes31AppendU8Libfunc {
+    const STR_ID: &'static str = "bytes31_append_u8";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let bytes31_ty = context.get_concrete_type(Bytes31Type::id(), &[])?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let rc_output_info = OutputVarInfo::new_builtin(range_check_type.clone(), 0);
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type).with_allow_add_const(),
+                ParamSignature::new(bytes31_ty.clone()),
+                ParamSignature::new(context.get_concrete_type(Uint8Type::id(), &[])?),
+            ],
+            branch_signatures: vec![
+                // Success.
+                BranchSignature {
+                    vars: vec![
+                        rc_output_info.clone(),
+                        OutputVarInfo {
+                            ty: bytes31_ty,
+                            ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Full.
+                BranchSignature {
+                    vars: vec![rc_output_info],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for splitting the lowest byte off a bytes31 - returns `(value / 256, value % 256)`.
+#[derive(Default)]
+pub struct Bytes31SplitU8Libfunc {}
+impl NoGenericArgsGenericLibfunc for Bytes31SplitU8Libfunc {
+    const STR_ID: &'static str = "bytes31_split_u8";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let bytes31_ty = context.get_concrete_type(Bytes31Type::id(), &[])?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+                ParamSignature::new(bytes31_ty.clone()),
+            ],
+            vec![
+                OutputVarInfo::new_builtin(range_check_type, 0),
+                OutputVarInfo { ty: bytes31_ty, ref_info: OutputVarReferenceInfo::SimpleDerefs },
+                OutputVarInfo {
+                    ty: context.get_concrete_type(Uint8Type::id(), &[])?,
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
@@ -1,7 +1,14 @@
+use std::ops::Shl;
+
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
 
+use super::int::signed::{Sint16Type, Sint32Type, Sint64Type, Sint8Type};
+use super::int::signed128::Sint128Type;
 use super::int::unsigned::{Uint16Type, Uint32Type, Uint64Type, Uint8Type};
 use super::int::unsigned128::Uint128Type;
+use super::int::unsigned96::Uint96Type;
 use super::range_check::RangeCheckType;
 use super::utils::reinterpret_cast_signature;
 use crate::define_libfunc_hierarchy;
@@ -13,7 +20,7 @@ use crate::extensions::{
     args_as_two_types, NamedLibfunc, NamedType, OutputVarReferenceInfo,
     SignatureBasedConcreteLibfunc, SpecializationError,
 };
-use crate::ids::ConcreteTypeId;
+use crate::ids::{ConcreteTypeId, GenericTypeId};
 use crate::program::GenericArg;
 
 define_libfunc_hierarchy! {
@@ -23,38 +30,85 @@ define_libfunc_hierarchy! {
     }, CastConcreteLibfunc
 }
 
-/// Returns a map from (concrete) integer type to the number of bits in the type.
-fn get_type_to_nbits_map(
-    context: &dyn SignatureSpecializationContext,
-) -> UnorderedHashMap<ConcreteTypeId, usize> {
-    vec![
-        (Uint8Type::ID, 8),
-        (Uint16Type::ID, 16),
-        (Uint32Type::ID, 32),
-        (Uint64Type::ID, 64),
-        (Uint128Type::ID, 128),
+/// The range of values of an integer type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntRange {
+    /// The minimal value of the type.
+    pub lower: BigInt,
+    /// One more than the maximal value of the type.
+    pub upper: BigInt,
+}
+impl IntRange {
+    /// Creates the range of the values between `min` and `max`, inclusive.
+    fn closed(min: impl Into<BigInt>, max: impl Into<BigInt>) -> Self {
+        Self { lower: min.into(), upper: max.into() + 1 }
+    }
+
+    /// Returns the number of values in the range.
+    pub fn size(&self) -> BigInt {
+        &self.upper - &self.lower
+    }
+
+    /// Returns whether all the values of `self` are values of `other` as well.
+    fn is_subset_of(&self, other: &Self) -> bool {
+        other.lower <= self.lower && self.upper <= other.upper
+    }
+
+    /// Returns whether `value` is in the range.
+    pub fn contains(&self, value: &BigInt) -> bool {
+        &self.lower <= value && value < &self.upper
+    }
+}
+
+/// The integer types and their ranges of values.
+/// Casts are supported between any two types of this table, so an integer type added to it can be
+/// converted to and from all the others.
+fn int_type_ranges() -> [(GenericTypeId, IntRange); 11] {
+    [
+        (Uint8Type::ID, IntRange::closed(u8::MIN, u8::MAX)),
+        (Uint16Type::ID, IntRange::closed(u16::MIN, u16::MAX)),
+        (Uint32Type::ID, IntRange::closed(u32::MIN, u32::MAX)),
+        (Uint64Type::ID, IntRange::closed(u64::MIN, u64::MAX)),
+        (Uint96Type::ID, IntRange { lower: BigInt::zero(), upper: BigInt::one().shl(96) }),
+        (Uint128Type::ID, IntRange::closed(u128::MIN, u128::MAX)),
+        (Sint8Type::ID, IntRange::closed(i8::MIN, i8::MAX)),
+        (Sint16Type::ID, IntRange::closed(i16::MIN, i16::MAX)),
+        (Sint32Type::ID, IntRange::closed(i32::MIN, i32::MAX)),
+        (Sint64Type::ID, IntRange::closed(i64::MIN, i64::MAX)),
+        (Sint128Type::ID, IntRange::closed(i128::MIN, i128::MAX)),
     ]
-    .into_iter()
-    .filter_map(|(generic_type, n_bits)| {
-        Some((context.get_concrete_type(generic_type, &[]).ok()?, n_bits))
-    })
-    .collect()
 }
 
-/// Returns the number of bits for the given types.
-// TODO(lior): Convert to a generic function that can take arbitrary number of arguments once
-//   `try_map` is a stable feature.
-fn get_n_bits(
+/// Returns the range of values of the integer type with the given generic id, if it is one of the
+/// castable integer types.
+pub fn get_int_type_range(generic_id: &GenericTypeId) -> Option<IntRange> {
+    int_type_ranges().into_iter().find(|(id, _)| id == generic_id).map(|(_, range)| range)
+}
+
+/// Returns a map from (concrete) integer type to the range of values of the type.
+fn get_type_to_range_map(
+    context: &dyn SignatureSpecializationContext,
+) -> UnorderedHashMap<ConcreteTypeId, IntRange> {
+    int_type_ranges()
+        .into_iter()
+        .filter_map(|(generic_type, range)| {
+            Some((context.get_concrete_type(generic_type, &[]).ok()?, range))
+        })
+        .collect()
+}
+
+/// Returns the ranges of values of the given types.
+fn get_ranges(
     context: &dyn SignatureSpecializationContext,
     from_type: &ConcreteTypeId,
     to_type: &ConcreteTypeId,
-) -> Result<(usize, usize), SpecializationError> {
-    let type_to_n_bits = get_type_to_nbits_map(context);
-    let from_nbits =
-        *type_to_n_bits.get(from_type).ok_or(SpecializationError::UnsupportedGenericArg)?;
-    let to_nbits =
-        *type_to_n_bits.get(to_type).ok_or(SpecializationError::UnsupportedGenericArg)?;
-    Ok((from_nbits, to_nbits))
+) -> Result<(IntRange, IntRange), SpecializationError> {
+    let type_to_range = get_type_to_range_map(context);
+    let from_range =
+        type_to_range.get(from_type).cloned().ok_or(SpecializationError::UnsupportedGenericArg)?;
+    let to_range =
+        type_to_range.get(to_type).cloned().ok_or(SpecializationError::UnsupportedGenericArg)?;
+    Ok((from_range, to_range))
 }
 
 /// Libfunc for casting from one type to another where any input value can fit into the destination
@@ -70,9 +124,9 @@ impl SignatureOnlyGenericLibfunc for UpcastLibfunc {
         args: &[GenericArg],
     ) -> Result<LibfuncSignature, SpecializationError> {
         let (from_ty, to_ty) = args_as_two_types(args)?;
-        let (from_nbits, to_nbits) = get_n_bits(context, &from_ty, &to_ty)?;
+        let (from_range, to_range) = get_ranges(context, &from_ty, &to_ty)?;
 
-        let is_valid = from_nbits <= to_nbits;
+        let is_valid = from_range.is_subset_of(&to_range);
         if !is_valid {
             return Err(SpecializationError::UnsupportedGenericArg);
         }
@@ -85,9 +139,23 @@ impl SignatureOnlyGenericLibfunc for UpcastLibfunc {
 pub struct DowncastConcreteLibfunc {
     pub signature: LibfuncSignature,
     pub from_ty: ConcreteTypeId,
-    pub from_nbits: usize,
+    pub from_range: IntRange,
     pub to_ty: ConcreteTypeId,
-    pub to_nbits: usize,
+    pub to_range: IntRange,
+}
+impl DowncastConcreteLibfunc {
+    /// Returns whether the cast checks that the value is at least the minimal value of the
+    /// destination type, as values of the source type may be smaller.
+    pub fn checks_lower_bound(&self) -> bool {
+        self.from_range.lower < self.to_range.lower
+    }
+
+    /// Returns whether the cast checks that the value is at most the maximal value of the
+    /// destination type, as values of the source type may be larger. The upper bound is also
+    /// checked for casts between types of the same range, so that some bound is always checked.
+    pub fn checks_upper_bound(&self) -> bool {
+        self.from_range.upper > self.to_range.upper || !self.checks_lower_bound()
+    }
 }
 impl SignatureBasedConcreteLibfunc for DowncastConcreteLibfunc {
     fn signature(&self) -> &LibfuncSignature {
@@ -96,7 +164,7 @@ impl SignatureBasedConcreteLibfunc for DowncastConcreteLibfunc {
 }
 
 /// Libfunc for casting from one type to another where the input value may not fit into the
-/// destination type. For example, from u64 to u8.
+/// destination type. For example, from u64 to u8, or from i8 to u8.
 #[derive(Default)]
 pub struct DowncastLibfunc {}
 impl NamedLibfunc for DowncastLibfunc {
@@ -109,9 +177,10 @@ impl NamedLibfunc for DowncastLibfunc {
         args: &[GenericArg],
     ) -> Result<LibfuncSignature, SpecializationError> {
         let (from_ty, to_ty) = args_as_two_types(args)?;
-        let (from_nbits, to_nbits) = get_n_bits(context, &from_ty, &to_ty)?;
+        let (from_range, to_range) = get_ranges(context, &from_ty, &to_ty)?;
 
-        let is_valid = from_nbits >= to_nbits;
+        // Casts to a type containing all the values of the source type are upcasts.
+        let is_valid = from_range == to_range || !from_range.is_subset_of(&to_range);
         if !is_valid {
             return Err(SpecializationError::UnsupportedGenericArg);
         }
@@ -151,14 +220,14 @@ impl NamedLibfunc for DowncastLibfunc {
         args: &[GenericArg],
     ) -> Result<Self::Concrete, SpecializationError> {
         let (from_ty, to_ty) = args_as_two_types(args)?;
-        let (from_nbits, to_nbits) = get_n_bits(context.upcast(), &from_ty, &to_ty)?;
+        let (from_range, to_range) = get_ranges(context.upcast(), &from_ty, &to_ty)?;
 
         Ok(DowncastConcreteLibfunc {
             signature: self.specialize_signature(context.upcast(), args)?,
             from_ty,
-            from_nbits,
+            from_range,
             to_ty,
-            to_nbits,
+            to_range,
         })
     }
 }
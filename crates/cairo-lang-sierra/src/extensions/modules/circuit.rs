@@ -0,0 +1,740 @@
+//! Sierra example:
+//! ```ignore
+//! type RangeCheck96 = RangeCheck96;
+//! type AddMod = AddMod;
+//! type MulMod = MulMod;
+//! type u96 = u96;
+//! type u384 = Struct<ut@core::modular::u384, u96, u96, u96, u96>;
+//! type In0 = CircuitInput<0>;
+//! type In1 = CircuitInput<1>;
+//! type Sum = AddModGate<In0, In1>;
+//! type Inverse = InverseGate<Sum>;
+//! type Product = MulModGate<Inverse, In0>;
+//! type Outputs = Struct<ut@Tuple, Product>;
+//! type C = Circuit<Outputs>;
+//! type Accumulator = CircuitInputAccumulator<C>;
+//! type Data = CircuitData<C>;
+//! type Descriptor = CircuitDescriptor<C>;
+//! type Evaluated = CircuitOutputs<C>;
+//! libfunc init = init_circuit_data<C>;
+//! libfunc add_input = add_circuit_input<C>;
+//! libfunc get_descriptor = get_circuit_descriptor<C>;
+//! libfunc eval = eval_circuit<C>;
+//! libfunc get_output = get_circuit_output<C, Product>;
+//! ...
+//! init(rc96) -> (rc96, acc);
+//! add_input(acc, in0) { fallthrough(data) 5(acc) };
+//! ...
+//! get_descriptor() -> (descriptor);
+//! eval(add_mod, mul_mod, descriptor, data, modulus) -> (add_mod, mul_mod, outputs);
+//! get_output(outputs) -> (product);
+//! ```
+//! A circuit is described by its type - the gates computing its outputs from its inputs, modulo the
+//! modulus given when evaluating it. The values of the circuit - its inputs, the constant one if
+//! used by an inverse gate, and the outputs of its gates - are held in the memory of the
+//! RangeCheck96 builtin, so each of their limbs is validated to be a u96. The gates are evaluated
+//! by the AddMod and MulMod builtins.
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use num_traits::ToPrimitive;
+
+use super::modular::{get_u384_type, AddModType, MulModType, MOD_BUILTIN_N_LIMBS};
+use super::range_check::RangeCheck96Type;
+use super::structure::StructType;
+use crate::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::type_specialization_context::TypeSpecializationContext;
+use crate::extensions::types::{
+    GenericTypeArgGenericType, GenericTypeArgGenericTypeWrapper, InfoOnlyConcreteType, TypeInfo,
+};
+use crate::extensions::{
+    args_as_single_type, args_as_two_types, NamedLibfunc, NamedType, OutputVarReferenceInfo,
+    SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, GenericTypeId};
+use crate::program::{ConcreteTypeLongId, GenericArg};
+use crate::{define_libfunc_hierarchy, define_type_hierarchy};
+
+/// The maximal number of values of a circuit, so that the offsets of its values, and of the mod
+/// builtin instances evaluating it - each taking the limbs of the modulus and 3 more cells - fit in
+/// the offsets of instructions.
+pub const MAX_CIRCUIT_VALUES: usize = i16::MAX as usize / (MOD_BUILTIN_N_LIMBS + 3);
+
+define_type_hierarchy! {
+    pub enum CircuitType {
+        CircuitInput(CircuitInputType),
+        AddModGate(AddModGateType),
+        SubModGate(SubModGateType),
+        MulModGate(MulModGateType),
+        InverseGate(InverseGateType),
+        Circuit(CircuitDefinitionType),
+        CircuitInputAccumulator(CircuitInputAccumulatorType),
+        CircuitData(CircuitDataType),
+        CircuitDescriptor(CircuitDescriptorType),
+        CircuitOutputs(CircuitOutputsType),
+    }, CircuitTypeConcrete
+}
+
+define_libfunc_hierarchy! {
+    pub enum CircuitLibfunc {
+        InitCircuitData(InitCircuitDataLibfunc),
+        AddInput(AddCircuitInputLibfunc),
+        GetDescriptor(GetCircuitDescriptorLibfunc),
+        Eval(EvalCircuitLibfunc),
+        GetOutput(GetCircuitOutputLibfunc),
+    }, CircuitConcreteLibfunc
+}
+
+/// Returns the type info of the types describing a circuit - its inputs, gates and the circuit
+/// itself. These types only exist at the type level, so they hold no value.
+fn circuit_description_info(long_id: ConcreteTypeLongId) -> InfoOnlyConcreteType {
+    InfoOnlyConcreteType {
+        info: TypeInfo {
+            long_id,
+            storable: true,
+            droppable: false,
+            duplicatable: false,
+            zero_sized: true,
+        },
+    }
+}
+
+/// Returns whether the type with the given generic id is an input or a gate of a circuit.
+fn is_circuit_value(generic_id: &GenericTypeId) -> bool {
+    [
+        CircuitInputType::ID,
+        AddModGateType::ID,
+        SubModGateType::ID,
+        MulModGateType::ID,
+        InverseGateType::ID,
+    ]
+    .contains(generic_id)
+}
+
+/// Returns the type info of a gate of the given long id, validating its operands are inputs or
+/// gates of a circuit.
+fn gate_info(
+    context: &dyn TypeSpecializationContext,
+    long_id: ConcreteTypeLongId,
+) -> Result<InfoOnlyConcreteType, SpecializationError> {
+    for arg in &long_id.generic_args {
+        let GenericArg::Type(ty) = arg else {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        };
+        if !is_circuit_value(&context.get_type_info(ty.clone())?.long_id.generic_id) {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+    }
+    Ok(circuit_description_info(long_id))
+}
+
+/// Type for the input of a circuit at the index given by its generic arg.
+#[derive(Default)]
+pub struct CircuitInputType {}
+impl NamedType for CircuitInputType {
+    type Concrete = InfoOnlyConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("CircuitInput");
+
+    fn specialize(
+        &self,
+        _context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(index)] if index.to_usize().is_some() => {
+                Ok(circuit_description_info(Self::concrete_type_long_id(args)))
+            }
+            [_] => Err(SpecializationError::UnsupportedGenericArg),
+            _ => Err(SpecializationError::WrongNumberOfGenericArgs),
+        }
+    }
+}
+
+/// Type for a gate computing the sum of its two operands modulo the modulus of the circuit.
+#[derive(Default)]
+pub struct AddModGateType {}
+impl NamedType for AddModGateType {
+    type Concrete = InfoOnlyConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("AddModGate");
+
+    fn specialize(
+        &self,
+        context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        args_as_two_types(args)?;
+        gate_info(context, Self::concrete_type_long_id(args))
+    }
+}
+
+/// Type for a gate computing the difference of its two operands modulo the modulus of the circuit.
+#[derive(Default)]
+pub struct SubModGateType {}
+impl NamedType for SubModGateType {
+    type Concrete = InfoOnlyConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("SubModGate");
+
+    fn specialize(
+        &self,
+        context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        args_as_two_types(args)?;
+        gate_info(context, Self::concrete_type_long_id(args))
+    }
+}
+
+/// Type for a gate computing the product of its two operands modulo the modulus of the circuit.
+#[derive(Default)]
+pub struct MulModGateType {}
+impl NamedType for MulModGateType {
+    type Concrete = InfoOnlyConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("MulModGate");
+
+    fn specialize(
+        &self,
+        context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        args_as_two_types(args)?;
+        gate_info(context, Self::concrete_type_long_id(args))
+    }
+}
+
+/// Type for a gate computing the inverse of its operand modulo the modulus of the circuit.
+/// The evaluation of the circuit fails if the operand is not invertible.
+#[derive(Default)]
+pub struct InverseGateType {}
+impl NamedType for InverseGateType {
+    type Concrete = InfoOnlyConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("InverseGate");
+
+    fn specialize(
+        &self,
+        context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        args_as_single_type(args)?;
+        gate_info(context, Self::concrete_type_long_id(args))
+    }
+}
+
+/// Type for a circuit, given the struct of its outputs as its generic arg.
+#[derive(Default)]
+pub struct CircuitDefinitionType {}
+impl NamedType for CircuitDefinitionType {
+    type Concrete = InfoOnlyConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("Circuit");
+
+    fn specialize(
+        &self,
+        context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        get_circuit_info(context, &args_as_single_type(args)?)?;
+        Ok(circuit_description_info(Self::concrete_type_long_id(args)))
+    }
+}
+
+/// Returns the type info of a type holding a value of the circuit it wraps.
+fn circuit_value_info(
+    long_id: ConcreteTypeLongId,
+    wrapped_info: TypeInfo,
+    copyable: bool,
+) -> Result<TypeInfo, SpecializationError> {
+    if wrapped_info.long_id.generic_id != CircuitDefinitionType::ID {
+        return Err(SpecializationError::UnsupportedGenericArg);
+    }
+    Ok(TypeInfo {
+        long_id,
+        storable: true,
+        droppable: true,
+        duplicatable: copyable,
+        zero_sized: false,
+    })
+}
+
+/// Type for the accumulator of the inputs of a circuit - the pointers to the start of its values
+/// and to the next input to fill.
+#[derive(Default)]
+pub struct CircuitInputAccumulatorTypeWrapped {}
+impl GenericTypeArgGenericType for CircuitInputAccumulatorTypeWrapped {
+    const ID: GenericTypeId = GenericTypeId::new_inline("CircuitInputAccumulator");
+
+    fn calc_info(
+        &self,
+        long_id: ConcreteTypeLongId,
+        wrapped_info: TypeInfo,
+    ) -> Result<TypeInfo, SpecializationError> {
+        circuit_value_info(long_id, wrapped_info, false)
+    }
+}
+pub type CircuitInputAccumulatorType =
+    GenericTypeArgGenericTypeWrapper<CircuitInputAccumulatorTypeWrapped>;
+
+/// Type for the values of a circuit with all its inputs filled - the pointer to their start.
+#[derive(Default)]
+pub struct CircuitDataTypeWrapped {}
+impl GenericTypeArgGenericType for CircuitDataTypeWrapped {
+    const ID: GenericTypeId = GenericTypeId::new_inline("CircuitData");
+
+    fn calc_info(
+        &self,
+        long_id: ConcreteTypeLongId,
+        wrapped_info: TypeInfo,
+    ) -> Result<TypeInfo, SpecializationError> {
+        circuit_value_info(long_id, wrapped_info, false)
+    }
+}
+pub type CircuitDataType = GenericTypeArgGenericTypeWrapper<CircuitDataTypeWrapped>;
+
+/// Type for the descriptor of a circuit - the pointers to the offsets of the values of the
+/// operations of the AddMod and MulMod builtins evaluating it.
+#[derive(Default)]
+pub struct CircuitDescriptorTypeWrapped {}
+impl GenericTypeArgGenericType for CircuitDescriptorTypeWrapped {
+    const ID: GenericTypeId = GenericTypeId::new_inline("CircuitDescriptor");
+
+    fn calc_info(
+        &self,
+        long_id: ConcreteTypeLongId,
+        wrapped_info: TypeInfo,
+    ) -> Result<TypeInfo, SpecializationError> {
+        circuit_value_info(long_id, wrapped_info, true)
+    }
+}
+pub type CircuitDescriptorType = GenericTypeArgGenericTypeWrapper<CircuitDescriptorTypeWrapped>;
+
+/// Type for the values of an evaluated circuit - the pointer to their start.
+#[derive(Default)]
+pub struct CircuitOutputsTypeWrapped {}
+impl GenericTypeArgGenericType for CircuitOutputsTypeWrapped {
+    const ID: GenericTypeId = GenericTypeId::new_inline("CircuitOutputs");
+
+    fn calc_info(
+        &self,
+        long_id: ConcreteTypeLongId,
+        wrapped_info: TypeInfo,
+    ) -> Result<TypeInfo, SpecializationError> {
+        circuit_value_info(long_id, wrapped_info, true)
+    }
+}
+pub type CircuitOutputsType = GenericTypeArgGenericTypeWrapper<CircuitOutputsTypeWrapped>;
+
+/// The layout of the values of a circuit, and the mod builtin operations evaluating its gates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitInfo {
+    /// The number of inputs of the circuit - the first of its values.
+    pub n_inputs: usize,
+    /// The number of values of the circuit.
+    pub n_values: usize,
+    /// The index of the value holding the constant one, following the inputs, if an inverse gate
+    /// uses it.
+    pub one_index: Option<usize>,
+    /// The index of the value of each input and gate of the circuit.
+    pub values: UnorderedHashMap<ConcreteTypeId, usize>,
+    /// The indices of the values `a`, `b` and `c` of each AddMod builtin operation, asserting that
+    /// `a + b = c` modulo the modulus.
+    pub add_offsets: Vec<[usize; 3]>,
+    /// The indices of the values `a`, `b` and `c` of each MulMod builtin operation, asserting that
+    /// `a * b = c` modulo the modulus.
+    pub mul_offsets: Vec<[usize; 3]>,
+}
+
+/// A gate of a circuit, along with the types of its operands.
+enum CircuitGate {
+    Add(ConcreteTypeId, ConcreteTypeId),
+    Sub(ConcreteTypeId, ConcreteTypeId),
+    Mul(ConcreteTypeId, ConcreteTypeId),
+    Inverse(ConcreteTypeId),
+}
+
+/// Returns the info of the circuit with the given outputs struct type.
+pub fn get_circuit_info(
+    context: &dyn TypeSpecializationContext,
+    outputs_ty: &ConcreteTypeId,
+) -> Result<CircuitInfo, SpecializationError> {
+    let long_id = context.get_type_info(outputs_ty.clone())?.long_id;
+    if long_id.generic_id != StructType::ID {
+        return Err(SpecializationError::UnsupportedGenericArg);
+    }
+    let mut inputs = OrderedHashMap::<ConcreteTypeId, usize>::default();
+    let mut gates = vec![];
+    let mut visited_gates = OrderedHashSet::<ConcreteTypeId>::default();
+    for arg in long_id.generic_args.iter().skip(1) {
+        let GenericArg::Type(ty) = arg else {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        };
+        collect_circuit_values(context, ty, &mut inputs, &mut gates, &mut visited_gates)?;
+    }
+    // The inputs must be exactly the indices from 0, so that they are filled in order.
+    let n_inputs = inputs.len();
+    let input_indices: OrderedHashSet<usize> = inputs.iter().map(|(_, index)| *index).collect();
+    if n_inputs == 0
+        || input_indices.len() != n_inputs
+        || input_indices.iter().any(|i| *i >= n_inputs)
+    {
+        return Err(SpecializationError::UnsupportedGenericArg);
+    }
+    let one_index =
+        gates.iter().any(|(_, gate)| matches!(gate, CircuitGate::Inverse(_))).then_some(n_inputs);
+    let first_gate_index = n_inputs + usize::from(one_index.is_some());
+    let mut values: UnorderedHashMap<ConcreteTypeId, usize> = inputs.into_iter().collect();
+    for (i, (ty, _)) in gates.iter().enumerate() {
+        values.insert(ty.clone(), first_gate_index + i);
+    }
+    let n_values = first_gate_index + gates.len();
+    if n_values > MAX_CIRCUIT_VALUES {
+        return Err(SpecializationError::UnsupportedGenericArg);
+    }
+    let mut add_offsets = vec![];
+    let mut mul_offsets = vec![];
+    for (ty, gate) in &gates {
+        let output = values[ty];
+        match gate {
+            CircuitGate::Add(lhs, rhs) => add_offsets.push([values[lhs], values[rhs], output]),
+            // `lhs - rhs = output` is evaluated as `rhs + output = lhs`.
+            CircuitGate::Sub(lhs, rhs) => add_offsets.push([values[rhs], output, values[lhs]]),
+            CircuitGate::Mul(lhs, rhs) => mul_offsets.push([values[lhs], values[rhs], output]),
+            // `1 / operand = output` is evaluated as `operand * output = 1`.
+            CircuitGate::Inverse(operand) => {
+                mul_offsets.push([values[operand], output, one_index.unwrap()])
+            }
+        }
+    }
+    Ok(CircuitInfo { n_inputs, n_values, one_index, values, add_offsets, mul_offsets })
+}
+
+/// Collects the input or gate of type `ty` of a circuit, along with the values it depends on.
+/// The gates are collected in their evaluation order, after the gates they depend on.
+fn collect_circuit_values(
+    context: &dyn TypeSpecializationContext,
+    ty: &ConcreteTypeId,
+    inputs: &mut OrderedHashMap<ConcreteTypeId, usize>,
+    gates: &mut Vec<(ConcreteTypeId, CircuitGate)>,
+    visited_gates: &mut OrderedHashSet<ConcreteTypeId>,
+) -> Result<(), SpecializationError> {
+    if inputs.contains_key(ty) || visited_gates.contains(ty) {
+        return Ok(());
+    }
+    let long_id = context.get_type_info(ty.clone())?.long_id;
+    let gate = match (long_id.generic_id, &long_id.generic_args[..]) {
+        (generic_id, [GenericArg::Value(index)]) if generic_id == CircuitInputType::ID => {
+            let index = index.to_usize().ok_or(SpecializationError::UnsupportedGenericArg)?;
+            inputs.insert(ty.clone(), index);
+            return Ok(());
+        }
+        (generic_id, [GenericArg::Type(lhs), GenericArg::Type(rhs)])
+            if generic_id == AddModGateType::ID =>
+        {
+            CircuitGate::Add(lhs.clone(), rhs.clone())
+        }
+        (generic_id, [GenericArg::Type(lhs), GenericArg::Type(rhs)])
+            if generic_id == SubModGateType::ID =>
+        {
+            CircuitGate::Sub(lhs.clone(), rhs.clone())
+        }
+        (generic_id, [GenericArg::Type(lhs), GenericArg::Type(rhs)])
+            if generic_id == MulModGateType::ID =>
+        {
+            CircuitGate::Mul(lhs.clone(), rhs.clone())
+        }
+        (generic_id, [GenericArg::Type(operand)]) if generic_id == InverseGateType::ID => {
+            CircuitGate::Inverse(operand.clone())
+        }
+        _ => return Err(SpecializationError::UnsupportedGenericArg),
+    };
+    let operands = match &gate {
+        CircuitGate::Add(lhs, rhs) | CircuitGate::Sub(lhs, rhs) | CircuitGate::Mul(lhs, rhs) => {
+            vec![lhs, rhs]
+        }
+        CircuitGate::Inverse(operand) => vec![operand],
+    };
+    for operand in operands {
+        collect_circuit_values(context, operand, inputs, gates, visited_gates)?;
+    }
+    visited_gates.insert(ty.clone());
+    gates.push((ty.clone(), gate));
+    Ok(())
+}
+
+/// Returns the circuit type given as the generic args of a circuit libfunc, along with its info.
+fn circuit_arg(
+    context: &dyn SignatureSpecializationContext,
+    circuit_ty: ConcreteTypeId,
+) -> Result<(ConcreteTypeId, CircuitInfo), SpecializationError> {
+    let long_id = context.get_type_info(circuit_ty.clone())?.long_id;
+    if long_id.generic_id != CircuitDefinitionType::ID {
+        return Err(SpecializationError::UnsupportedGenericArg);
+    }
+    let outputs_ty = args_as_single_type(&long_id.generic_args)?;
+    let circuit_info = get_circuit_info(context.as_type_specialization_context(), &outputs_ty)?;
+    Ok((circuit_ty, circuit_info))
+}
+
+/// Concrete libfunc operating on a circuit, along with the info of the circuit.
+pub struct ConcreteCircuitLibfunc {
+    pub signature: LibfuncSignature,
+    pub circuit_info: CircuitInfo,
+}
+impl SignatureBasedConcreteLibfunc for ConcreteCircuitLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Trait for implementing a libfunc operating on the circuit given as its generic arg.
+pub trait CircuitLibfuncTrait: Default {
+    const STR_ID: &'static str;
+
+    /// Returns the signature of the libfunc for the given circuit.
+    fn specialize_circuit_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        circuit_ty: ConcreteTypeId,
+        circuit_info: &CircuitInfo,
+    ) -> Result<LibfuncSignature, SpecializationError>;
+}
+
+/// Wrapper for a libfunc operating on the circuit given as its generic arg.
+#[derive(Default)]
+pub struct WrapCircuitLibfunc<T: CircuitLibfuncTrait>(T);
+impl<T: CircuitLibfuncTrait> NamedLibfunc for WrapCircuitLibfunc<T> {
+    type Concrete = ConcreteCircuitLibfunc;
+    const STR_ID: &'static str = T::STR_ID;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let (circuit_ty, circuit_info) = circuit_arg(context, args_as_single_type(args)?)?;
+        self.0.specialize_circuit_signature(context, circuit_ty, &circuit_info)
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        let context = context.upcast();
+        let (circuit_ty, circuit_info) = circuit_arg(context, args_as_single_type(args)?)?;
+        Ok(ConcreteCircuitLibfunc {
+            signature: self.0.specialize_circuit_signature(context, circuit_ty, &circuit_info)?,
+            circuit_info,
+        })
+    }
+}
+
+/// Libfunc for reserving the values of a circuit in the RangeCheck96 builtin, returning an
+/// accumulator for filling its inputs.
+#[derive(Default)]
+pub struct InitCircuitDataLibfuncWrapped {}
+impl CircuitLibfuncTrait for InitCircuitDataLibfuncWrapped {
+    const STR_ID: &'static str = "init_circuit_data";
+
+    fn specialize_circuit_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        circuit_ty: ConcreteTypeId,
+        _circuit_info: &CircuitInfo,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check96_ty = context.get_concrete_type(RangeCheck96Type::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature::new(range_check96_ty.clone()).with_allow_add_const()],
+            vec![
+                OutputVarInfo::new_builtin(range_check96_ty, 0),
+                OutputVarInfo {
+                    ty: context
+                        .get_wrapped_concrete_type(CircuitInputAccumulatorType::id(), circuit_ty)?,
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+pub type InitCircuitDataLibfunc = WrapCircuitLibfunc<InitCircuitDataLibfuncWrapped>;
+
+/// Libfunc for filling the next input of a circuit. Returns the circuit data if all the inputs
+/// are filled, and the accumulator otherwise.
+#[derive(Default)]
+pub struct AddCircuitInputLibfuncWrapped {}
+impl CircuitLibfuncTrait for AddCircuitInputLibfuncWrapped {
+    const STR_ID: &'static str = "add_circuit_input";
+
+    fn specialize_circuit_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        circuit_ty: ConcreteTypeId,
+        _circuit_info: &CircuitInfo,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let accumulator_ty = context
+            .get_wrapped_concrete_type(CircuitInputAccumulatorType::id(), circuit_ty.clone())?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(accumulator_ty.clone()),
+                ParamSignature::new(get_u384_type(context)?),
+            ],
+            branch_signatures: vec![
+                // All the inputs are filled.
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: context.get_wrapped_concrete_type(CircuitDataType::id(), circuit_ty)?,
+                        ref_info: OutputVarReferenceInfo::PartialParam { param_idx: 0 },
+                    }],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // More inputs are expected.
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: accumulator_ty,
+                        ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                    }],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+pub type AddCircuitInputLibfunc = WrapCircuitLibfunc<AddCircuitInputLibfuncWrapped>;
+
+/// Libfunc for writing the offsets of the values of the mod builtin operations evaluating a
+/// circuit.
+#[derive(Default)]
+pub struct GetCircuitDescriptorLibfuncWrapped {}
+impl CircuitLibfuncTrait for GetCircuitDescriptorLibfuncWrapped {
+    const STR_ID: &'static str = "get_circuit_descriptor";
+
+    fn specialize_circuit_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        circuit_ty: ConcreteTypeId,
+        _circuit_info: &CircuitInfo,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![],
+            vec![OutputVarInfo {
+                ty: context.get_wrapped_concrete_type(CircuitDescriptorType::id(), circuit_ty)?,
+                ref_info: OutputVarReferenceInfo::SimpleDerefs,
+            }],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+pub type GetCircuitDescriptorLibfunc = WrapCircuitLibfunc<GetCircuitDescriptorLibfuncWrapped>;
+
+/// Libfunc for evaluating a circuit modulo the given u384 modulus, using the AddMod and MulMod
+/// builtins.
+///
+/// The inputs are expected to be smaller than the modulus. The evaluation fails if the modulus is
+/// zero, or if the operand of an inverse gate is not invertible.
+#[derive(Default)]
+pub struct EvalCircuitLibfuncWrapped {}
+impl CircuitLibfuncTrait for EvalCircuitLibfuncWrapped {
+    const STR_ID: &'static str = "eval_circuit";
+
+    fn specialize_circuit_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        circuit_ty: ConcreteTypeId,
+        _circuit_info: &CircuitInfo,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let add_mod_ty = context.get_concrete_type(AddModType::id(), &[])?;
+        let mul_mod_ty = context.get_concrete_type(MulModType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature::new(add_mod_ty.clone()).with_allow_add_const(),
+                ParamSignature::new(mul_mod_ty.clone()).with_allow_add_const(),
+                ParamSignature::new(
+                    context.get_wrapped_concrete_type(
+                        CircuitDescriptorType::id(),
+                        circuit_ty.clone(),
+                    )?,
+                ),
+                ParamSignature::new(
+                    context.get_wrapped_concrete_type(CircuitDataType::id(), circuit_ty.clone())?,
+                ),
+                ParamSignature::new(get_u384_type(context)?),
+            ],
+            vec![
+                OutputVarInfo::new_builtin(add_mod_ty, 0),
+                OutputVarInfo::new_builtin(mul_mod_ty, 1),
+                OutputVarInfo {
+                    ty: context.get_wrapped_concrete_type(CircuitOutputsType::id(), circuit_ty)?,
+                    ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 3 },
+                },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+pub type EvalCircuitLibfunc = WrapCircuitLibfunc<EvalCircuitLibfuncWrapped>;
+
+/// Libfunc for getting the value of an input or a gate of an evaluated circuit, given as the
+/// second generic arg.
+///
+/// Each limb of the value is a u96, and the value is congruent to the evaluated one modulo the
+/// modulus - but it is not guaranteed to be smaller than the modulus.
+#[derive(Default)]
+pub struct GetCircuitOutputLibfunc {}
+impl GetCircuitOutputLibfunc {
+    /// Creates the specialization of the libfunc with the given template arguments.
+    fn specialize_concrete_lib_func(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<GetCircuitOutputConcreteLibfunc, SpecializationError> {
+        let (circuit_ty, output_ty) = args_as_two_types(args)?;
+        let (circuit_ty, circuit_info) = circuit_arg(context, circuit_ty)?;
+        let value_index = *circuit_info
+            .values
+            .get(&output_ty)
+            .ok_or(SpecializationError::UnsupportedGenericArg)?;
+        let signature = LibfuncSignature::new_non_branch(
+            vec![context.get_wrapped_concrete_type(CircuitOutputsType::id(), circuit_ty)?],
+            vec![OutputVarInfo {
+                ty: get_u384_type(context)?,
+                ref_info: OutputVarReferenceInfo::SimpleDerefs,
+            }],
+            SierraApChange::Known { new_vars_only: false },
+        );
+        Ok(GetCircuitOutputConcreteLibfunc { signature, value_index })
+    }
+}
+impl NamedLibfunc for GetCircuitOutputLibfunc {
+    type Concrete = GetCircuitOutputConcreteLibfunc;
+    const STR_ID: &'static str = "get_circuit_output";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(self.specialize_concrete_lib_func(context, args)?.signature)
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        self.specialize_concrete_lib_func(context.upcast(), args)
+    }
+}
+
+pub struct GetCircuitOutputConcreteLibfunc {
+    pub signature: LibfuncSignature,
+    /// The index of the requested value among the values of the circuit.
+    pub value_index: usize,
+}
+impl SignatureBasedConcreteLibfunc for GetCircuitOutputConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
@@ -0,0 +1,169 @@
+//! Sierra example:
+//! ```ignore
+//! type u8 = u8;
+//! type ArrayU8 = Array<u8>;
+//! type SnapshotArrayU8 = Snapshot<ArrayU8>;
+//! type Digits = ConstArray<u8, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9>;
+//! libfunc digits_as_span = const_as_span<Digits>;
+//! ...
+//! digits_as_span() -> (digits);
+//! ```
+//! The values of the const arrays used by the program are emitted after the program code, so
+//! getting a span over them costs the same regardless of their number.
+
+use num_bigint::BigInt;
+
+use super::array::ArrayType;
+use super::casts::get_int_type_range;
+use super::felt252::Felt252Type;
+use super::snapshot::snapshot_ty;
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, SierraApChange, SignatureSpecializationContext,
+    SpecializationContext,
+};
+use crate::extensions::type_specialization_context::TypeSpecializationContext;
+use crate::extensions::types::TypeInfo;
+use crate::extensions::{
+    args_as_single_type, ConcreteType, NamedLibfunc, NamedType, OutputVarReferenceInfo,
+    SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, GenericTypeId};
+use crate::program::GenericArg;
+
+/// Type for a constant array of values of the type given by its first generic arg.
+///
+/// The type has no runtime representation - its values are only accessible through
+/// `const_as_span`.
+#[derive(Default)]
+pub struct ConstArrayType {}
+impl NamedType for ConstArrayType {
+    type Concrete = ConstArrayConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("ConstArray");
+
+    fn specialize(
+        &self,
+        context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        let (ty, values) = const_array_args(context, args)?;
+        Ok(ConstArrayConcreteType {
+            info: TypeInfo {
+                long_id: Self::concrete_type_long_id(args),
+                storable: false,
+                droppable: false,
+                duplicatable: false,
+                zero_sized: true,
+            },
+            ty,
+            values,
+        })
+    }
+}
+
+pub struct ConstArrayConcreteType {
+    pub info: TypeInfo,
+    /// The type of the values of the array.
+    pub ty: ConcreteTypeId,
+    pub values: Vec<BigInt>,
+}
+impl ConcreteType for ConstArrayConcreteType {
+    fn info(&self) -> &TypeInfo {
+        &self.info
+    }
+}
+
+/// Returns the type and the values of a const array given its generic args.
+///
+/// The type must be felt252 or an integer type, and all the values must be in its range.
+fn const_array_args(
+    context: &dyn TypeSpecializationContext,
+    args: &[GenericArg],
+) -> Result<(ConcreteTypeId, Vec<BigInt>), SpecializationError> {
+    let (ty, value_args) = match args {
+        [GenericArg::Type(ty), value_args @ ..] => (ty, value_args),
+        [] => return Err(SpecializationError::WrongNumberOfGenericArgs),
+        _ => return Err(SpecializationError::UnsupportedGenericArg),
+    };
+    let generic_id = context.get_type_info(ty.clone())?.long_id.generic_id;
+    let range = if generic_id == Felt252Type::ID {
+        None
+    } else {
+        Some(get_int_type_range(&generic_id).ok_or(SpecializationError::UnsupportedGenericArg)?)
+    };
+    let values = value_args
+        .iter()
+        .map(|arg| match arg {
+            GenericArg::Value(value)
+                if range.as_ref().map_or(true, |range| range.contains(value)) =>
+            {
+                Ok(value.clone())
+            }
+            _ => Err(SpecializationError::UnsupportedGenericArg),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((ty.clone(), values))
+}
+
+/// Libfunc for getting a span over the values of a const array.
+#[derive(Default)]
+pub struct ConstAsSpanLibfunc {}
+impl NamedLibfunc for ConstAsSpanLibfunc {
+    type Concrete = ConstAsSpanConcreteLibfunc;
+    const STR_ID: &'static str = "const_as_span";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let (ty, _) = const_as_span_args(context, args)?;
+        let arr_ty = context.get_wrapped_concrete_type(ArrayType::id(), ty)?;
+        Ok(LibfuncSignature::new_non_branch(
+            vec![],
+            vec![OutputVarInfo {
+                ty: snapshot_ty(context, arr_ty)?,
+                ref_info: OutputVarReferenceInfo::SimpleDerefs,
+            }],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        let const_array_ty = args_as_single_type(args)?;
+        let (_, values) = const_as_span_args(context.upcast(), args)?;
+        Ok(ConstAsSpanConcreteLibfunc {
+            signature: self.specialize_signature(context.upcast(), args)?,
+            const_array_ty,
+            values,
+        })
+    }
+}
+
+/// Returns the type and the values of the const array given as the single generic arg of
+/// `const_as_span`.
+fn const_as_span_args(
+    context: &dyn SignatureSpecializationContext,
+    args: &[GenericArg],
+) -> Result<(ConcreteTypeId, Vec<BigInt>), SpecializationError> {
+    let long_id = context.get_type_info(args_as_single_type(args)?)?.long_id;
+    if long_id.generic_id != ConstArrayType::ID {
+        return Err(SpecializationError::UnsupportedGenericArg);
+    }
+    const_array_args(context.as_type_specialization_context(), &long_id.generic_args)
+}
+
+pub struct ConstAsSpanConcreteLibfunc {
+    pub signature: LibfuncSignature,
+    /// The const array type the span is over.
+    pub const_array_ty: ConcreteTypeId,
+    pub values: Vec<BigInt>,
+}
+impl SignatureBasedConcreteLibfunc for ConstAsSpanConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
@@ -1,6 +1,11 @@
+use super::array::ArrayType;
 use super::felt252::Felt252Type;
+use super::gas::{BuiltinCostsType, GasBuiltinType};
+use super::get_bool_type;
 use super::non_zero::nonzero_ty;
 use super::range_check::RangeCheckType;
+use super::snapshot::snapshot_ty;
+use super::structure::StructType;
 use crate::define_libfunc_hierarchy;
 use crate::extensions::lib_func::{
     BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
@@ -10,7 +15,8 @@ use crate::extensions::{
     NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
     SpecializationError,
 };
-use crate::ids::GenericTypeId;
+use crate::ids::{GenericTypeId, UserTypeId};
+use crate::program::GenericArg;
 
 // Type representing the EcOp builtin.
 #[derive(Default)]
@@ -47,14 +53,18 @@ impl NoGenericArgsGenericType for EcStateType {
 
 define_libfunc_hierarchy! {
     pub enum EcLibfunc {
+        Add(EcAddLibfunc),
         IsZero(EcIsZeroLibfunc),
         Neg(EcNegLibfunc),
+        PointDouble(EcPointDoubleLibfunc),
         StateAdd(EcStateAddLibfunc),
         TryNew(EcCreatePointLibfunc),
         StateFinalize(EcStateFinalizeLibfunc),
         StateInit(EcStateInitLibfunc),
         StateAddMul(EcStateAddMulLibfunc),
+        Msm(EcMsmLibfunc),
         PointFromX(EcPointFromXLibfunc),
+        PointFromXParity(EcPointFromXParityLibfunc),
         UnwrapPoint(EcUnwrapPointLibfunc),
         Zero(EcZeroLibfunc),
     }, EcConcreteLibfunc
@@ -120,6 +130,87 @@ impl NoGenericArgsGenericLibfunc for EcCreatePointLibfunc {
     }
 }
 
+/// Libfunc for computing the multi-scalar multiplication `M_1 * Q_1 + ... + M_n * Q_n` of an
+/// array of `(M, Q)` pairs, where each `M` is a scalar (felt252) and each `Q` is a non-zero EC
+/// point.
+///
+/// The pairs are accumulated with the EC op builtin into a single EC state, initialized to a
+/// random point which is subtracted at the end. The loop is charged at runtime, using the builtin
+/// costs for the cost of the EC op builtin.
+#[derive(Default)]
+pub struct EcMsmLibfunc {}
+impl NoGenericArgsGenericLibfunc for EcMsmLibfunc {
+    const STR_ID: &'static str = "ec_msm";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let ec_op_type = context.get_concrete_type(EcOpType::id(), &[])?;
+        let ecpoint_ty = context.get_concrete_type(EcPointType::id(), &[])?;
+        let pair_ty = context.get_concrete_type(
+            StructType::id(),
+            &[
+                GenericArg::UserType(UserTypeId::from_string("Tuple")),
+                GenericArg::Type(context.get_concrete_type(Felt252Type::id(), &[])?),
+                GenericArg::Type(nonzero_ty(context, &ecpoint_ty)?),
+            ],
+        )?;
+        let pairs_ty =
+            snapshot_ty(context, context.get_wrapped_concrete_type(ArrayType::id(), pair_ty)?)?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+            ParamSignature::new(gas_builtin_type.clone()),
+            ParamSignature::new(ec_op_type.clone()),
+            ParamSignature::new(context.get_concrete_type(BuiltinCostsType::id(), &[])?),
+            // Pairs.
+            ParamSignature::new(pairs_ty),
+        ];
+        let branch_signatures = vec![
+            // Success.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo {
+                        ty: range_check_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                    },
+                    OutputVarInfo {
+                        ty: gas_builtin_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: ec_op_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 2 },
+                    },
+                    OutputVarInfo {
+                        ty: ecpoint_ty,
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 3 },
+                    },
+                ],
+                ap_change: SierraApChange::Unknown,
+            },
+            // Failure - not enough gas for the multiplications.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo::new_builtin(range_check_type, 0),
+                    OutputVarInfo {
+                        ty: gas_builtin_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: ec_op_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 2 },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+
 /// Libfunc for creating an EC point from its x coordinate.
 /// If there exists `y` such that `(x, y)` is on the curve, either `(x, y)` or `(x, -y)` (both
 /// constitute valid points on the curve) is returned.
@@ -167,6 +258,57 @@ impl NoGenericArgsGenericLibfunc for EcPointFromXLibfunc {
     }
 }
 
+/// Libfunc for creating an EC point from its x coordinate and the parity of its y coordinate (as
+/// an integer in `[0, PRIME)`), as in compressed point encodings.
+/// If there exists `y` such that `(x, y)` is on the curve, the point among `(x, y)` and `(x, -y)`
+/// whose y coordinate has the given parity is returned.
+/// Otherwise, nothing is returned.
+#[derive(Default)]
+pub struct EcPointFromXParityLibfunc {}
+impl NoGenericArgsGenericLibfunc for EcPointFromXParityLibfunc {
+    const STR_ID: &'static str = "ec_point_from_x_parity_nz";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let felt252_ty = context.get_concrete_type(Felt252Type::id(), &[])?;
+        let ecpoint_ty = context.get_concrete_type(EcPointType::id(), &[])?;
+        let nonzero_ecpoint_ty = nonzero_ty(context, &ecpoint_ty)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+
+        let rc_output_info = OutputVarInfo::new_builtin(range_check_type.clone(), 0);
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type).with_allow_add_const(),
+                // `x` coordinate.
+                ParamSignature::new(felt252_ty),
+                // `y_parity` - whether the y coordinate is odd.
+                ParamSignature::new(get_bool_type(context)?),
+            ],
+            branch_signatures: vec![
+                // Success.
+                BranchSignature {
+                    vars: vec![
+                        rc_output_info.clone(),
+                        OutputVarInfo {
+                            ty: nonzero_ecpoint_ty,
+                            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Failure.
+                BranchSignature {
+                    vars: vec![rc_output_info],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
 /// Libfunc for unwrapping the x,y values of an EC point.
 #[derive(Default)]
 pub struct EcUnwrapPointLibfunc {}
@@ -216,6 +358,53 @@ impl NoGenericArgsGenericLibfunc for EcNegLibfunc {
     }
 }
 
+/// Libfunc for adding two EC points, any of which may be the zero point.
+#[derive(Default)]
+pub struct EcAddLibfunc {}
+impl NoGenericArgsGenericLibfunc for EcAddLibfunc {
+    const STR_ID: &'static str = "ec_add";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ecpoint_ty = context.get_concrete_type(EcPointType::id(), &[])?;
+
+        Ok(LibfuncSignature::new_non_branch(
+            vec![ecpoint_ty.clone(), ecpoint_ty.clone()],
+            vec![OutputVarInfo {
+                ty: ecpoint_ty,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+
+/// Libfunc for doubling a non-zero EC point.
+#[derive(Default)]
+pub struct EcPointDoubleLibfunc {}
+impl NoGenericArgsGenericLibfunc for EcPointDoubleLibfunc {
+    const STR_ID: &'static str = "ec_point_double";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ecpoint_ty = context.get_concrete_type(EcPointType::id(), &[])?;
+        let nonzero_ecpoint_ty = nonzero_ty(context, &ecpoint_ty)?;
+
+        Ok(LibfuncSignature::new_non_branch(
+            vec![nonzero_ecpoint_ty.clone()],
+            vec![OutputVarInfo {
+                ty: nonzero_ecpoint_ty,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+
 /// Libfunc for checking whether the given `EcPoint` is the zero point.
 #[derive(Default)]
 pub struct EcIsZeroLibfunc {}
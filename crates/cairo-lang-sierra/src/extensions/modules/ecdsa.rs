@@ -0,0 +1,60 @@
+use super::felt252::Felt252Type;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, SpecializationError,
+};
+use crate::ids::GenericTypeId;
+
+/// Type representing the ECDSA signature builtin.
+#[derive(Default)]
+pub struct EcdsaType {}
+impl NoGenericArgsGenericType for EcdsaType {
+    const ID: GenericTypeId = GenericTypeId::new_inline("Ecdsa");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = false;
+    const DROPPABLE: bool = false;
+    const ZERO_SIZED: bool = false;
+}
+
+define_libfunc_hierarchy! {
+    pub enum EcdsaLibfunc {
+        Verify(EcdsaVerifyLibfunc),
+    }, EcdsaConcreteLibfunc
+}
+
+/// Libfunc for verifying an ECDSA signature of a message hash by a public key, using the ECDSA
+/// signature builtin. Returns the updated builtin pointer.
+///
+/// The builtin rejects invalid signatures, so an invalid signature makes the run fail rather than
+/// being reported to the program.
+#[derive(Default)]
+pub struct EcdsaVerifyLibfunc {}
+impl NoGenericArgsGenericLibfunc for EcdsaVerifyLibfunc {
+    const STR_ID: &'static str = "ecdsa_verify";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ecdsa_ty = context.get_concrete_type(EcdsaType::id(), &[])?;
+        let felt252_param = ParamSignature::new(context.get_concrete_type(Felt252Type::id(), &[])?);
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature::new(ecdsa_ty.clone()).with_allow_add_const(),
+                // message_hash
+                felt252_param.clone(),
+                // public_key
+                felt252_param.clone(),
+                // signature_r
+                felt252_param.clone(),
+                // signature_s
+                felt252_param,
+            ],
+            vec![OutputVarInfo::new_builtin(ecdsa_ty, 0)],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
@@ -0,0 +1,137 @@
+use super::array::ArrayType;
+use super::gas::GasBuiltinType;
+use super::int::unsigned::Uint8Type;
+use super::range_check::RangeCheckType;
+use super::snapshot::snapshot_ty;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+use crate::ids::ConcreteTypeId;
+
+define_libfunc_hierarchy! {
+    pub enum EncodingLibfunc {
+        HexEncode(HexEncodeLibfunc),
+        HexDecode(HexDecodeLibfunc),
+        Base64Encode(Base64EncodeLibfunc),
+    }, EncodingConcreteLibfunc
+}
+
+/// Returns the types of the range check, the gas builtin and a byte array.
+fn encoding_types(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<(ConcreteTypeId, ConcreteTypeId, ConcreteTypeId), SpecializationError> {
+    let u8_ty = context.get_concrete_type(Uint8Type::id(), &[])?;
+    Ok((
+        context.get_concrete_type(RangeCheckType::id(), &[])?,
+        context.get_concrete_type(GasBuiltinType::id(), &[])?,
+        context.get_wrapped_concrete_type(ArrayType::id(), u8_ty)?,
+    ))
+}
+
+/// Returns the signature of an encoding libfunc, taking a byte array snapshot and returning the
+/// encoded byte array, with an optional branch for an invalid input before the failure branch.
+fn encoding_signature(
+    context: &dyn SignatureSpecializationContext,
+    with_invalid_branch: bool,
+) -> Result<LibfuncSignature, SpecializationError> {
+    let (range_check_type, gas_builtin_type, arr_ty) = encoding_types(context)?;
+    let param_signatures = vec![
+        ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+        ParamSignature::new(gas_builtin_type.clone()),
+        ParamSignature::new(snapshot_ty(context, arr_ty.clone())?),
+    ];
+    // Success - returns the encoded array.
+    let mut branch_signatures = vec![BranchSignature {
+        vars: vec![
+            OutputVarInfo {
+                ty: range_check_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+            },
+            OutputVarInfo {
+                ty: gas_builtin_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+            },
+            OutputVarInfo { ty: arr_ty, ref_info: OutputVarReferenceInfo::NewTempVar { idx: 2 } },
+        ],
+        ap_change: SierraApChange::Unknown,
+    }];
+    if with_invalid_branch {
+        // Invalid - the input is not a valid encoding.
+        branch_signatures.push(BranchSignature {
+            vars: vec![
+                OutputVarInfo {
+                    ty: range_check_type.clone(),
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+                OutputVarInfo {
+                    ty: gas_builtin_type.clone(),
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+            ],
+            ap_change: SierraApChange::Unknown,
+        });
+    }
+    // Failure - not enough gas for encoding the input.
+    branch_signatures.push(BranchSignature {
+        vars: vec![
+            OutputVarInfo::new_builtin(range_check_type, 0),
+            OutputVarInfo {
+                ty: gas_builtin_type,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+            },
+        ],
+        ap_change: SierraApChange::Known { new_vars_only: false },
+    });
+    Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+}
+
+/// Libfunc for encoding a byte array as lowercase hex digits, two per byte.
+/// The encoding loop is charged at runtime, according to the length of the array.
+#[derive(Default)]
+pub struct HexEncodeLibfunc {}
+impl NoGenericArgsGenericLibfunc for HexEncodeLibfunc {
+    const STR_ID: &'static str = "bytes_hex_encode";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        encoding_signature(context, false)
+    }
+}
+
+/// Libfunc for decoding a byte array of hex digits, in either case, two per byte.
+/// The input is invalid if it has an odd length or a byte which is not a hex digit.
+#[derive(Default)]
+pub struct HexDecodeLibfunc {}
+impl NoGenericArgsGenericLibfunc for HexDecodeLibfunc {
+    const STR_ID: &'static str = "bytes_hex_decode";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        encoding_signature(context, true)
+    }
+}
+
+/// Libfunc for encoding the whole 3-byte groups of a byte array as base64 (RFC 4648), four
+/// characters per group. The 1 or 2 trailing bytes that don't form a whole group are not encoded,
+/// and are left for the caller to encode with padding.
+#[derive(Default)]
+pub struct Base64EncodeLibfunc {}
+impl NoGenericArgsGenericLibfunc for Base64EncodeLibfunc {
+    const STR_ID: &'static str = "bytes_base64_encode";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        encoding_signature(context, false)
+    }
+}
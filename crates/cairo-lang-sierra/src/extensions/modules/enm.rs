@@ -119,6 +119,8 @@ pub struct EnumInitConcreteLibfunc {
     pub num_variants: usize,
     /// The index of the relevant variant from the enum.
     pub index: usize,
+    /// The types of the variants of the enum.
+    pub variant_types: Vec<ConcreteTypeId>,
 }
 impl SignatureBasedConcreteLibfunc for EnumInitConcreteLibfunc {
     fn signature(&self) -> &LibfuncSignature {
@@ -169,6 +171,7 @@ impl EnumInitLibfunc {
             ),
             num_variants,
             index,
+            variant_types,
         })
     }
 }
@@ -3,14 +3,15 @@ use num_traits::Zero;
 
 use super::is_zero::{IsZeroLibfunc, IsZeroTraits};
 use super::non_zero::nonzero_ty;
+use super::range_check::RangeCheckType;
 use crate::define_libfunc_hierarchy;
 use crate::extensions::lib_func::{
-    DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
-    SignatureSpecializationContext, SpecializationContext,
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext, SpecializationContext,
 };
 use crate::extensions::{
-    GenericLibfunc, NamedLibfunc, NamedType, NoGenericArgsGenericType, OutputVarReferenceInfo,
-    SignatureBasedConcreteLibfunc, SpecializationError,
+    GenericLibfunc, NamedLibfunc, NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType,
+    OutputVarReferenceInfo, SignatureBasedConcreteLibfunc, SpecializationError,
 };
 use crate::ids::{GenericLibfuncId, GenericTypeId};
 use crate::program::GenericArg;
@@ -32,6 +33,7 @@ define_libfunc_hierarchy! {
         BinaryOperation(Felt252BinaryOperationLibfunc),
         Const(Felt252ConstLibfunc),
         IsZero(Felt252JumpNotZeroLibfunc),
+        Sqrt(Felt252SqrtLibfunc),
     }, Felt252Concrete
 }
 
@@ -291,3 +293,45 @@ impl SignatureBasedConcreteLibfunc for Felt252ConstConcreteLibfunc {
         &self.signature
     }
 }
+
+/// Libfunc for computing the square root of a felt252.
+/// Returns the root smaller than `PRIME / 2` if the value is a quadratic residue, and branches
+/// otherwise.
+#[derive(Default)]
+pub struct Felt252SqrtLibfunc {}
+impl NoGenericArgsGenericLibfunc for Felt252SqrtLibfunc {
+    const STR_ID: &'static str = "felt252_sqrt";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let felt252_ty = context.get_concrete_type(Felt252Type::id(), &[])?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+                ParamSignature::new(felt252_ty.clone()),
+            ],
+            branch_signatures: vec![
+                // Quadratic residue.
+                BranchSignature {
+                    vars: vec![
+                        OutputVarInfo::new_builtin(range_check_type.clone(), 0),
+                        OutputVarInfo {
+                            ty: felt252_ty,
+                            ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Non-residue.
+                BranchSignature {
+                    vars: vec![OutputVarInfo::new_builtin(range_check_type, 0)],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
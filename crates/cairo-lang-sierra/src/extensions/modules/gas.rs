@@ -31,6 +31,7 @@ define_libfunc_hierarchy! {
         WithdrawGas(WithdrawGasLibfunc),
         RedepositGas(RedepositGasLibfunc),
         GetAvailableGas(GetAvailableGasLibfunc),
+        Checkpoint(GasCheckpointLibfunc),
         BuiltinWithdrawGas(BuiltinCostWithdrawGasLibfunc),
         GetBuiltinCosts(BuiltinCostGetBuiltinCostsLibfunc),
     }, GasConcreteLibfunc
@@ -133,6 +134,38 @@ impl NoGenericArgsGenericLibfunc for GetAvailableGasLibfunc {
     }
 }
 
+/// Libfunc for returning the amount of gas available, including the gas already withdrawn into the
+/// gas wallet and not yet used. Unlike the gas counter, the difference between two such values is
+/// exactly the gas consumed between them.
+#[derive(Default)]
+pub struct GasCheckpointLibfunc {}
+impl NoGenericArgsGenericLibfunc for GasCheckpointLibfunc {
+    const STR_ID: &'static str = "gas_checkpoint";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch(
+            vec![gas_builtin_type.clone()],
+            vec![
+                OutputVarInfo {
+                    ty: gas_builtin_type,
+                    ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+                },
+                OutputVarInfo {
+                    ty: context.get_concrete_type(Uint128Type::id(), &[])?,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                },
+            ],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
 /// Represents different type of costs.
 /// Note that if you add a type here you should update 'iter_precost'
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
@@ -0,0 +1,150 @@
+//! Sierra example:
+//! ```ignore
+//! type felt252 = felt252;
+//! libfunc square = inline_casm<1, 1, 1, 5987...>;
+//! ...
+//! square(x) -> (x_squared);
+//! ```
+//! The generic args are the number of inputs, the number of outputs, the ap change of the code, and
+//! the text of the code, encoded by [encode_inline_casm_code].
+
+use cairo_lang_casm::parser::parse_straight_line_code;
+use num_bigint::{BigInt, Sign};
+use num_traits::ToPrimitive;
+
+use super::felt252::Felt252Type;
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::{
+    NamedLibfunc, NamedType, OutputVarReferenceInfo, SignatureBasedConcreteLibfunc,
+    SpecializationError,
+};
+use crate::program::GenericArg;
+
+/// The maximal number of bytes of the code encoded in a single generic arg, so that it fits in a
+/// felt252.
+const CODE_CHUNK_SIZE: usize = 31;
+
+/// Encodes the text of CASM code as generic args values, each holding a chunk of its bytes.
+pub fn encode_inline_casm_code(code: &str) -> Vec<BigInt> {
+    code.as_bytes()
+        .chunks(CODE_CHUNK_SIZE)
+        .map(|chunk| BigInt::from_bytes_be(Sign::Plus, chunk))
+        .collect()
+}
+
+/// Decodes the text of CASM code encoded by [encode_inline_casm_code].
+fn decode_inline_casm_code(chunks: &[GenericArg]) -> Result<String, SpecializationError> {
+    let mut bytes = vec![];
+    for chunk in chunks {
+        match chunk {
+            GenericArg::Value(value) if value.sign() == Sign::Plus => {
+                let (_, chunk_bytes) = value.to_bytes_be();
+                if chunk_bytes.len() > CODE_CHUNK_SIZE {
+                    return Err(SpecializationError::UnsupportedGenericArg);
+                }
+                bytes.extend(chunk_bytes);
+            }
+            _ => return Err(SpecializationError::UnsupportedGenericArg),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| SpecializationError::UnsupportedGenericArg)
+}
+
+/// Extracts a non-negative integer from a generic arg.
+fn arg_as_usize(arg: &GenericArg) -> Result<usize, SpecializationError> {
+    match arg {
+        GenericArg::Value(value) => {
+            value.to_usize().ok_or(SpecializationError::UnsupportedGenericArg)
+        }
+        _ => Err(SpecializationError::UnsupportedGenericArg),
+    }
+}
+
+/// Libfunc for running a block of straight-line CASM code, with felt252 inputs and outputs.
+/// The inputs are pushed to the stack right before the code, and the outputs are the last cells
+/// allocated by it.
+#[derive(Default)]
+pub struct InlineCasmLibfunc {}
+impl InlineCasmLibfunc {
+    /// Creates the specialization of the inline CASM libfunc with the given template arguments.
+    fn specialize_concrete_lib_func(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<InlineCasmConcreteLibfunc, SpecializationError> {
+        let (num_inputs, num_outputs, ap_change, code) = match args {
+            [num_inputs, num_outputs, ap_change, code @ ..] if !code.is_empty() => (
+                arg_as_usize(num_inputs)?,
+                arg_as_usize(num_outputs)?,
+                arg_as_usize(ap_change)?,
+                decode_inline_casm_code(code)?,
+            ),
+            _ => return Err(SpecializationError::WrongNumberOfGenericArgs),
+        };
+        let parsed = parse_straight_line_code(&code)
+            .map_err(|_| SpecializationError::UnsupportedGenericArg)?;
+        if parsed.ap_change != ap_change
+            || num_outputs > ap_change
+            || parsed.validate_cells(num_inputs).is_err()
+        {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        let felt252_ty = context.get_concrete_type(Felt252Type::id(), &[])?;
+        Ok(InlineCasmConcreteLibfunc {
+            signature: LibfuncSignature::new_non_branch(
+                vec![ParamSignature::new(felt252_ty.clone()); num_inputs],
+                (0..num_outputs)
+                    .map(|idx| OutputVarInfo {
+                        ty: felt252_ty.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx },
+                    })
+                    .collect(),
+                SierraApChange::Known { new_vars_only: false },
+            ),
+            code,
+            num_inputs,
+            num_outputs,
+            ap_change,
+            num_instructions: parsed.instructions.len(),
+        })
+    }
+}
+impl NamedLibfunc for InlineCasmLibfunc {
+    type Concrete = InlineCasmConcreteLibfunc;
+    const STR_ID: &'static str = "inline_casm";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(self.specialize_concrete_lib_func(context, args)?.signature)
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        self.specialize_concrete_lib_func(context.upcast(), args)
+    }
+}
+
+pub struct InlineCasmConcreteLibfunc {
+    pub signature: LibfuncSignature,
+    /// The text of the CASM code.
+    pub code: String,
+    pub num_inputs: usize,
+    pub num_outputs: usize,
+    /// The ap change of the code itself, not including pushing the inputs.
+    pub ap_change: usize,
+    pub num_instructions: usize,
+}
+impl SignatureBasedConcreteLibfunc for InlineCasmConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
@@ -0,0 +1,63 @@
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    LibfuncSignature, SierraApChange, SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::{NamedLibfunc, SignatureBasedConcreteLibfunc, SpecializationError};
+use crate::program::GenericArg;
+
+define_libfunc_hierarchy! {
+    pub enum InstrumentationLibfunc {
+        Hit(InstrumentationHitLibfunc),
+    }, InstrumentationConcreteLibfunc
+}
+
+/// Libfunc reporting that the program reached the probe with the given id, for dynamic analyses
+/// of the program (such as coverage). Has no effect on the state of the program.
+#[derive(Default)]
+pub struct InstrumentationHitLibfunc {}
+impl NamedLibfunc for InstrumentationHitLibfunc {
+    type Concrete = InstrumentationHitConcreteLibfunc;
+    const STR_ID: &'static str = "instrumentation_hit";
+
+    fn specialize_signature(
+        &self,
+        _context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        match args {
+            [GenericArg::Value(id)] if !id.is_negative() => Ok(LibfuncSignature::new_non_branch(
+                vec![],
+                vec![],
+                SierraApChange::Known { new_vars_only: true },
+            )),
+            [_] => Err(SpecializationError::UnsupportedGenericArg),
+            _ => Err(SpecializationError::WrongNumberOfGenericArgs),
+        }
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        let signature = self.specialize_signature(context.upcast(), args)?;
+        let [GenericArg::Value(id)] = args else {
+            unreachable!("The generic arguments were validated by `specialize_signature`.");
+        };
+        Ok(InstrumentationHitConcreteLibfunc { id: id.clone(), signature })
+    }
+}
+
+pub struct InstrumentationHitConcreteLibfunc {
+    /// The id of the probe.
+    pub id: BigInt,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for InstrumentationHitConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
@@ -21,6 +21,7 @@ pub mod unsigned;
 pub mod unsigned128;
 pub mod unsigned256;
 pub mod unsigned512;
+pub mod unsigned96;
 
 /// Operators for integers.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
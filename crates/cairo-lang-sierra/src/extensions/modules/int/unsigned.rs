@@ -35,6 +35,12 @@ pub trait UintTraits: IntTraits {
     const DIVMOD: &'static str;
     /// The generic libfunc id that provides bitwise operations on two integers.
     const BITWISE: &'static str;
+    /// The generic libfunc id for unchecked addition.
+    const UNCHECKED_ADD: &'static str;
+    /// The generic libfunc id for unchecked subtraction.
+    const UNCHECKED_SUB: &'static str;
+    /// The generic libfunc id for unchecked multiplication.
+    const UNCHECKED_MUL: &'static str;
 }
 
 pub struct UintOperationConcreteLibfunc {
@@ -140,6 +146,91 @@ impl<TUintTraits: UintTraits> GenericLibfunc for UintOperationLibfunc<TUintTrait
     }
 }
 
+/// Operators for unchecked unsigned integer arithmetic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UintUncheckedOperator {
+    Add,
+    Sub,
+    Mul,
+}
+
+pub struct UintUncheckedOperationConcreteLibfunc {
+    pub operator: UintUncheckedOperator,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for UintUncheckedOperationConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for unchecked integer operations.
+/// The result is computed as a felt252 operation, and is not verified to be in the range of the
+/// type - it is the responsibility of the caller to make sure that the operation does not
+/// overflow.
+pub struct UintUncheckedOperationLibfunc<TUintTraits: UintTraits> {
+    pub operator: UintUncheckedOperator,
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> UintUncheckedOperationLibfunc<TUintTraits> {
+    const UNCHECKED_ADD: &'static str = TUintTraits::UNCHECKED_ADD;
+    const UNCHECKED_SUB: &'static str = TUintTraits::UNCHECKED_SUB;
+    const UNCHECKED_MUL: &'static str = TUintTraits::UNCHECKED_MUL;
+    fn new(operator: UintUncheckedOperator) -> Option<Self> {
+        Some(Self { operator, _phantom: PhantomData::default() })
+    }
+}
+impl<TUintTraits: UintTraits> GenericLibfunc for UintUncheckedOperationLibfunc<TUintTraits> {
+    type Concrete = UintUncheckedOperationConcreteLibfunc;
+
+    fn supported_ids() -> Vec<GenericLibfuncId> {
+        vec![
+            GenericLibfuncId::from(Self::UNCHECKED_ADD),
+            GenericLibfuncId::from(Self::UNCHECKED_SUB),
+            GenericLibfuncId::from(Self::UNCHECKED_MUL),
+        ]
+    }
+
+    fn by_id(id: &GenericLibfuncId) -> Option<Self> {
+        match id.0.as_str() {
+            id if id == Self::UNCHECKED_ADD => Self::new(UintUncheckedOperator::Add),
+            id if id == Self::UNCHECKED_SUB => Self::new(UintUncheckedOperator::Sub),
+            id if id == Self::UNCHECKED_MUL => Self::new(UintUncheckedOperator::Mul),
+            _ => None,
+        }
+    }
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        if !args.is_empty() {
+            return Err(SpecializationError::WrongNumberOfGenericArgs);
+        }
+        let ty = context.get_concrete_type(TUintTraits::GENERIC_TYPE_ID, &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature::new(ty.clone()), ParamSignature::new(ty.clone())],
+            vec![OutputVarInfo {
+                ty,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(UintUncheckedOperationConcreteLibfunc {
+            operator: self.operator,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
 /// Libfunc for calculating uint's square root.
 #[derive(Default)]
 pub struct UintSquareRootLibfunc<TUintTraits: UintTraits> {
@@ -246,6 +337,7 @@ define_libfunc_hierarchy! {
     pub enum UintLibfunc<TUintTraits: UintTraits + IntMulTraits + IsZeroTraits> {
         Const(IntConstLibfunc<TUintTraits>),
         Operation(UintOperationLibfunc<TUintTraits>),
+        UncheckedOperation(UintUncheckedOperationLibfunc<TUintTraits>),
         SquareRoot(UintSquareRootLibfunc<TUintTraits>),
         Equal(IntEqualLibfunc<TUintTraits>),
         ToFelt252(IntToFelt252Libfunc<TUintTraits>),
@@ -277,6 +369,9 @@ impl UintTraits for Uint8Traits {
     const SQUARE_ROOT_TYPE_ID: GenericTypeId = <Self as IntTraits>::GENERIC_TYPE_ID;
     const DIVMOD: &'static str = "u8_safe_divmod";
     const BITWISE: &'static str = "u8_bitwise";
+    const UNCHECKED_ADD: &'static str = "u8_unchecked_add";
+    const UNCHECKED_SUB: &'static str = "u8_unchecked_sub";
+    const UNCHECKED_MUL: &'static str = "u8_unchecked_mul";
 }
 
 impl IntMulTraits for Uint8Traits {
@@ -314,6 +409,9 @@ impl UintTraits for Uint16Traits {
     const SQUARE_ROOT_TYPE_ID: GenericTypeId = <Uint8Type as NamedType>::ID;
     const DIVMOD: &'static str = "u16_safe_divmod";
     const BITWISE: &'static str = "u16_bitwise";
+    const UNCHECKED_ADD: &'static str = "u16_unchecked_add";
+    const UNCHECKED_SUB: &'static str = "u16_unchecked_sub";
+    const UNCHECKED_MUL: &'static str = "u16_unchecked_mul";
 }
 
 impl IntMulTraits for Uint16Traits {
@@ -351,6 +449,9 @@ impl UintTraits for Uint32Traits {
     const SQUARE_ROOT_TYPE_ID: GenericTypeId = <Uint16Type as NamedType>::ID;
     const DIVMOD: &'static str = "u32_safe_divmod";
     const BITWISE: &'static str = "u32_bitwise";
+    const UNCHECKED_ADD: &'static str = "u32_unchecked_add";
+    const UNCHECKED_SUB: &'static str = "u32_unchecked_sub";
+    const UNCHECKED_MUL: &'static str = "u32_unchecked_mul";
 }
 
 impl IntMulTraits for Uint32Traits {
@@ -388,6 +489,9 @@ impl UintTraits for Uint64Traits {
     const SQUARE_ROOT_TYPE_ID: GenericTypeId = <Uint32Type as NamedType>::ID;
     const DIVMOD: &'static str = "u64_safe_divmod";
     const BITWISE: &'static str = "u64_bitwise";
+    const UNCHECKED_ADD: &'static str = "u64_unchecked_add";
+    const UNCHECKED_SUB: &'static str = "u64_unchecked_sub";
+    const UNCHECKED_MUL: &'static str = "u64_unchecked_mul";
 }
 
 impl IntMulTraits for Uint64Traits {
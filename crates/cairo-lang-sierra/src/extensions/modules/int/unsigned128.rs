@@ -1,6 +1,6 @@
 use super::unsigned::{
     Uint64Type, UintBitwiseLibfunc, UintDivmodLibfunc, UintOperationLibfunc, UintSquareRootLibfunc,
-    UintTraits,
+    UintTraits, UintUncheckedOperationLibfunc,
 };
 use super::{IntConstLibfunc, IntEqualLibfunc, IntToFelt252Libfunc, IntTraits, IntType};
 use crate::define_libfunc_hierarchy;
@@ -38,6 +38,7 @@ impl NoGenericArgsGenericType for U128MulGuaranteeType {
 define_libfunc_hierarchy! {
     pub enum Uint128Libfunc {
         Operation(UintOperationLibfunc<Uint128Traits>),
+        UncheckedOperation(UintUncheckedOperationLibfunc<Uint128Traits>),
         Divmod(UintDivmodLibfunc<Uint128Traits>),
         GuaranteeMul(U128GuaranteeMulLibfunc),
         MulGuaranteeVerify(U128MulGuaranteeVerifyLibfunc),
@@ -72,6 +73,9 @@ impl UintTraits for Uint128Traits {
     const SQUARE_ROOT_TYPE_ID: GenericTypeId = <Uint64Type as NamedType>::ID;
     const DIVMOD: &'static str = "u128_safe_divmod";
     const BITWISE: &'static str = "bitwise";
+    const UNCHECKED_ADD: &'static str = "u128_unchecked_add";
+    const UNCHECKED_SUB: &'static str = "u128_unchecked_sub";
+    const UNCHECKED_MUL: &'static str = "u128_unchecked_mul";
 }
 
 impl IsZeroTraits for Uint128Traits {
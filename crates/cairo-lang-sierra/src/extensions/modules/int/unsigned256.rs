@@ -1,3 +1,5 @@
+use itertools::chain;
+
 use super::unsigned128::{U128MulGuaranteeType, Uint128Type};
 use crate::define_libfunc_hierarchy;
 use crate::extensions::lib_func::{
@@ -16,6 +18,7 @@ define_libfunc_hierarchy! {
         IsZero(Uint256IsZeroLibfunc),
         Divmod(Uint256DivmodLibfunc),
         SquareRoot(Uint256SquareRootLibfunc),
+        InvModN(Uint256InvModNLibfunc),
     }, Uint256Concrete
 }
 
@@ -113,3 +116,55 @@ impl NoGenericArgsGenericLibfunc for Uint256SquareRootLibfunc {
         ))
     }
 }
+
+// Inverse Modulo N.
+#[derive(Default)]
+pub struct Uint256InvModNLibfunc;
+impl NoGenericArgsGenericLibfunc for Uint256InvModNLibfunc {
+    const STR_ID: &'static str = "u256_guarantee_inv_mod_n";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let u256_ty = get_u256_type(context)?;
+        let nz_ty = nonzero_ty(context, &u256_ty)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let rc_output = OutputVarInfo::new_builtin(range_check_type.clone(), 0);
+        let guarantee_output = OutputVarInfo {
+            ty: context.get_concrete_type(U128MulGuaranteeType::id(), &[])?,
+            ref_info: OutputVarReferenceInfo::SimpleDerefs,
+        };
+        let guarantee_outputs = vec![guarantee_output; 8];
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type).with_allow_add_const(),
+                ParamSignature::new(u256_ty),
+                ParamSignature::new(nz_ty.clone()),
+            ],
+            branch_signatures: vec![
+                // Inverse exists.
+                BranchSignature {
+                    vars: chain!(
+                        [
+                            rc_output.clone(),
+                            OutputVarInfo {
+                                ty: nz_ty,
+                                ref_info: OutputVarReferenceInfo::SimpleDerefs
+                            }
+                        ],
+                        guarantee_outputs.clone()
+                    )
+                    .collect(),
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // No inverse.
+                BranchSignature {
+                    vars: chain!([rc_output], guarantee_outputs).collect(),
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use num_bigint::BigInt;
+
+use super::{
+    IntConstLibfunc, IntEqualLibfunc, IntFromFelt252Libfunc, IntMulTraits, IntToFelt252Libfunc,
+    IntTraits, IntType, IntWideMulLibfunc,
+};
+use crate::define_libfunc_hierarchy;
+use crate::extensions::felt252::Felt252Type;
+use crate::extensions::is_zero::{IsZeroLibfunc, IsZeroTraits};
+use crate::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext,
+};
+use crate::extensions::range_check::RangeCheckType;
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+use crate::ids::GenericTypeId;
+
+/// A value of the u96 type - an unsigned integer smaller than 2**96.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U96(u128);
+impl U96 {
+    /// The number of bits of the type.
+    pub const BITS: u32 = 96;
+    /// The smallest value not representable by the type - 2**96.
+    pub const LIMIT: u128 = 1 << Self::BITS;
+
+    /// Returns the value as a u128.
+    pub fn value(&self) -> u128 {
+        self.0
+    }
+}
+impl TryFrom<u128> for U96 {
+    type Error = ();
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        if value < Self::LIMIT { Ok(Self(value)) } else { Err(()) }
+    }
+}
+impl TryFrom<BigInt> for U96 {
+    type Error = ();
+
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        u128::try_from(value).map_err(|_| ())?.try_into()
+    }
+}
+impl From<U96> for BigInt {
+    fn from(value: U96) -> Self {
+        value.0.into()
+    }
+}
+
+define_libfunc_hierarchy! {
+    pub enum Uint96Libfunc {
+        Const(IntConstLibfunc<Uint96Traits>),
+        Equal(IntEqualLibfunc<Uint96Traits>),
+        ToFelt252(IntToFelt252Libfunc<Uint96Traits>),
+        FromFelt252(IntFromFelt252Libfunc<Uint96Traits>),
+        IsZero(IsZeroLibfunc<Uint96Traits>),
+        OverflowingAdd(Uint96OverflowingAddLibfunc),
+        WideMul(IntWideMulLibfunc<Uint96Traits>),
+    }, Uint96Concrete
+}
+
+#[derive(Default)]
+pub struct Uint96Traits;
+
+impl IntTraits for Uint96Traits {
+    type IntType = U96;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("u96");
+    const IS_SMALL: bool = true;
+    const CONST: &'static str = "u96_const";
+    const EQUAL: &'static str = "u96_eq";
+    const TO_FELT252: &'static str = "u96_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "u96_try_from_felt252";
+}
+
+impl IntMulTraits for Uint96Traits {
+    const WIDE_MUL: &'static str = "u96_wide_mul";
+    // The product of two u96 values is smaller than 2**192, so it always fits in a felt252.
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Felt252Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Uint96Traits {
+    const IS_ZERO: &'static str = "u96_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Uint96Type as NamedType>::ID;
+}
+
+/// Type for u96.
+pub type Uint96Type = IntType<Uint96Traits>;
+
+/// Libfunc for u96 addition, branching on overflow.
+#[derive(Default)]
+pub struct Uint96OverflowingAddLibfunc;
+impl NoGenericArgsGenericLibfunc for Uint96OverflowingAddLibfunc {
+    const STR_ID: &'static str = "u96_overflowing_add";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(Uint96Type::id(), &[])?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let ty_param = ParamSignature::new(ty.clone());
+        // Both the non-overflowing and the overflowing branches return the (wrapped) sum.
+        let branch_signature = || BranchSignature {
+            vars: vec![
+                OutputVarInfo::new_builtin(range_check_type.clone(), 0),
+                OutputVarInfo {
+                    ty: ty.clone(),
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                },
+            ],
+            ap_change: SierraApChange::Known { new_vars_only: false },
+        };
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+                ty_param.clone(),
+                ty_param,
+            ],
+            branch_signatures: vec![branch_signature(), branch_signature()],
+            fallthrough: Some(0),
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use itertools::{chain, repeat_n};
+
+use super::int::unsigned::Uint64Type;
+use super::range_check::RangeCheckType;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
+    SpecializationError,
+};
+use crate::ids::GenericTypeId;
+
+/// The number of u64 lanes in the Keccak state.
+pub const KECCAK_STATE_LANES: usize = 25;
+
+/// Type representing the Keccak builtin.
+#[derive(Default)]
+pub struct KeccakType {}
+impl NoGenericArgsGenericType for KeccakType {
+    const ID: GenericTypeId = GenericTypeId::new_inline("Keccak");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = false;
+    const DROPPABLE: bool = false;
+    const ZERO_SIZED: bool = false;
+}
+
+define_libfunc_hierarchy! {
+    pub enum KeccakLibfunc {
+        Permutation(KeccakPermutationLibfunc),
+    }, KeccakConcreteLibfunc
+}
+
+/// Libfunc for computing the Keccak-f[1600] permutation over a state of 25 u64 lanes, using the
+/// Keccak builtin. Returns the 25 lanes of the state after the permutation (and the updated
+/// builtin pointers).
+///
+/// The builtin works on 200-bit words rather than on lanes, so the lanes are repacked on the way
+/// in and split on the way out - which is where the range checks are used.
+#[derive(Default)]
+pub struct KeccakPermutationLibfunc {}
+impl NoGenericArgsGenericLibfunc for KeccakPermutationLibfunc {
+    const STR_ID: &'static str = "keccak_permutation";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_ty = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let keccak_ty = context.get_concrete_type(KeccakType::id(), &[])?;
+        let u64_ty = context.get_concrete_type(Uint64Type::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            chain!(
+                [
+                    ParamSignature::new(range_check_ty.clone()).with_allow_add_const(),
+                    ParamSignature::new(keccak_ty.clone()).with_allow_add_const(),
+                ],
+                repeat_n(ParamSignature::new(u64_ty.clone()), KECCAK_STATE_LANES)
+            )
+            .collect(),
+            chain!(
+                [
+                    OutputVarInfo::new_builtin(range_check_ty, 0),
+                    OutputVarInfo::new_builtin(keccak_ty, 1),
+                ],
+                repeat_n(
+                    OutputVarInfo { ty: u64_ty, ref_info: OutputVarReferenceInfo::SimpleDerefs },
+                    KECCAK_STATE_LANES
+                )
+            )
+            .collect(),
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
@@ -0,0 +1,108 @@
+use std::marker::PhantomData;
+
+use super::array::ArrayType;
+use super::felt252::Felt252Type;
+use super::gas::{BuiltinCostsType, CostTokenType, GasBuiltinType};
+use super::int::unsigned128::Uint128Type;
+use super::range_check::RangeCheckType;
+use super::snapshot::snapshot_ty;
+use crate::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+use crate::ids::GenericTypeId;
+
+/// Trait for implementing a Merkle path verification library function for a hash builtin.
+pub trait MerkleHashTraits: Default {
+    /// The Merkle path verification library function id.
+    const MERKLE_VERIFY: &'static str;
+    /// The id of the generic type of the hash builtin.
+    const BUILTIN_TYPE_ID: GenericTypeId;
+    /// The cost token of a single invocation of the hash builtin.
+    const COST_TOKEN_TYPE: CostTokenType;
+}
+
+/// Libfunc for verifying a Merkle path from a leaf to a root.
+///
+/// Each element of the path is the sibling of the current node, which is hashed with it to get the
+/// parent node. The bits of the leaf's index, from the least significant, determine whether the
+/// current node is the right child (1) or the left child (0). The path is valid if the final node
+/// is the root and the index has no bits left. The hashing loop is charged at runtime, using the
+/// builtin costs for the cost of the hash builtin.
+#[derive(Default)]
+pub struct MerkleVerifyLibfunc<TMerkleHashTraits: MerkleHashTraits> {
+    _phantom: PhantomData<TMerkleHashTraits>,
+}
+impl<TMerkleHashTraits: MerkleHashTraits> NoGenericArgsGenericLibfunc
+    for MerkleVerifyLibfunc<TMerkleHashTraits>
+{
+    const STR_ID: &'static str = TMerkleHashTraits::MERKLE_VERIFY;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let hash_builtin_type =
+            context.get_concrete_type(TMerkleHashTraits::BUILTIN_TYPE_ID, &[])?;
+        let felt252_ty = context.get_concrete_type(Felt252Type::id(), &[])?;
+        let path_ty = snapshot_ty(
+            context,
+            context.get_wrapped_concrete_type(ArrayType::id(), felt252_ty.clone())?,
+        )?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+            ParamSignature::new(gas_builtin_type.clone()),
+            ParamSignature::new(hash_builtin_type.clone()).with_allow_add_const(),
+            ParamSignature::new(context.get_concrete_type(BuiltinCostsType::id(), &[])?),
+            // Leaf.
+            ParamSignature::new(felt252_ty.clone()),
+            // Index.
+            ParamSignature::new(context.get_concrete_type(Uint128Type::id(), &[])?),
+            // Path.
+            ParamSignature::new(path_ty),
+            // Root.
+            ParamSignature::new(felt252_ty),
+        ];
+        let verified_vars = vec![
+            OutputVarInfo {
+                ty: range_check_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+            },
+            OutputVarInfo {
+                ty: gas_builtin_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+            },
+            OutputVarInfo {
+                ty: hash_builtin_type.clone(),
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 2 },
+            },
+        ];
+        let branch_signatures = vec![
+            // Valid.
+            BranchSignature { vars: verified_vars.clone(), ap_change: SierraApChange::Unknown },
+            // Invalid.
+            BranchSignature { vars: verified_vars, ap_change: SierraApChange::Unknown },
+            // Failure - not enough gas for hashing the path.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo::new_builtin(range_check_type, 0),
+                    OutputVarInfo {
+                        ty: gas_builtin_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: hash_builtin_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 2 },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
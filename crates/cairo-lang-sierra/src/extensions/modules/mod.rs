@@ -1,5 +1,8 @@
+use self::array::ArrayType;
 use self::enm::EnumType;
+use self::felt252::Felt252Type;
 use self::int::unsigned128::Uint128Type;
+use self::snapshot::snapshot_ty;
 use self::structure::StructType;
 use super::lib_func::SignatureSpecializationContext;
 use super::{NamedType, SpecializationError};
@@ -8,29 +11,44 @@ use crate::program::GenericArg;
 
 pub mod ap_tracking;
 pub mod array;
+pub mod bitfield;
 pub mod bitwise;
+pub mod blake;
 pub mod boolean;
+pub mod bounded_int;
 pub mod boxing;
 pub mod branch_align;
+pub mod bytes31;
 pub mod casts;
+pub mod circuit;
+pub mod const_array;
 pub mod consts;
 pub mod debug;
 pub mod drop;
 pub mod duplicate;
 pub mod ec;
+pub mod ecdsa;
+pub mod encoding;
 pub mod enm;
 pub mod felt252;
 pub mod felt252_dict;
 pub mod function_call;
 pub mod gas;
+pub mod inline_casm;
+pub mod instrumentation;
 pub mod int;
 pub mod is_zero;
+pub mod keccak;
 pub mod mem;
+pub mod merkle;
+pub mod modular;
 pub mod non_zero;
 pub mod nullable;
+pub mod oracle;
 pub mod pedersen;
 pub mod poseidon;
 pub mod range_check;
+pub mod rlp;
 pub mod segment_arena;
 pub mod snapshot;
 pub mod span;
@@ -67,6 +85,23 @@ fn get_bool_type(
     )
 }
 
+/// User type for `Span<felt252>`.
+fn felt252_span_ty(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    let felt252_array_ty = context.get_wrapped_concrete_type(
+        ArrayType::id(),
+        context.get_concrete_type(Felt252Type::id(), &[])?,
+    )?;
+    context.get_concrete_type(
+        StructType::id(),
+        &[
+            GenericArg::UserType(UserTypeId::from_string("core::array::Span::<core::felt252>")),
+            GenericArg::Type(snapshot_ty(context, felt252_array_ty)?),
+        ],
+    )
+}
+
 /// Helper for u256 type def.
 fn get_u256_type(
     context: &dyn SignatureSpecializationContext,
@@ -0,0 +1,130 @@
+use super::int::unsigned96::Uint96Type;
+use super::range_check::RangeCheck96Type;
+use super::structure::StructType;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
+    SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, GenericTypeId, UserTypeId};
+use crate::program::GenericArg;
+
+/// The number of 96-bit limbs of the values the AddMod and MulMod builtins operate on.
+pub const MOD_BUILTIN_N_LIMBS: usize = 4;
+
+/// The number of cells of an instance of the AddMod and MulMod builtins: the limbs of the modulus,
+/// the pointer to the values, the pointer to the offsets of the operations, and the number of
+/// operations.
+pub const MOD_BUILTIN_INSTANCE_SIZE: usize = MOD_BUILTIN_N_LIMBS + 3;
+
+/// Type representing the AddMod builtin.
+#[derive(Default)]
+pub struct AddModType {}
+impl NoGenericArgsGenericType for AddModType {
+    const ID: GenericTypeId = GenericTypeId::new_inline("AddMod");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = false;
+    const DROPPABLE: bool = false;
+    const ZERO_SIZED: bool = false;
+}
+
+/// Type representing the MulMod builtin.
+#[derive(Default)]
+pub struct MulModType {}
+impl NoGenericArgsGenericType for MulModType {
+    const ID: GenericTypeId = GenericTypeId::new_inline("MulMod");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = false;
+    const DROPPABLE: bool = false;
+    const ZERO_SIZED: bool = false;
+}
+
+/// Helper for the u384 type def - a value of 4 u96 limbs, from the least significant one.
+pub fn get_u384_type(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    let u96_ty = context.get_concrete_type(Uint96Type::id(), &[])?;
+    context.get_concrete_type(
+        StructType::id(),
+        &[
+            GenericArg::UserType(UserTypeId::from_string("core::modular::u384")),
+            GenericArg::Type(u96_ty.clone()),
+            GenericArg::Type(u96_ty.clone()),
+            GenericArg::Type(u96_ty.clone()),
+            GenericArg::Type(u96_ty),
+        ],
+    )
+}
+
+define_libfunc_hierarchy! {
+    pub enum ModularLibfunc {
+        AddMod(U384AddModLibfunc),
+        MulMod(U384MulModLibfunc),
+    }, ModularConcreteLibfunc
+}
+
+/// Libfunc for computing `a + b` modulo `modulus` for u384 values, using the AddMod builtin.
+///
+/// `a` and `b` must be smaller than `modulus`, otherwise the builtin fails.
+#[derive(Default)]
+pub struct U384AddModLibfunc {}
+impl NoGenericArgsGenericLibfunc for U384AddModLibfunc {
+    const STR_ID: &'static str = "u384_add_mod";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let add_mod_ty = context.get_concrete_type(AddModType::id(), &[])?;
+        mod_builtin_signature(context, add_mod_ty)
+    }
+}
+
+/// Libfunc for computing `a * b` modulo `modulus` for u384 values, using the MulMod builtin.
+///
+/// `modulus` must be non-zero, otherwise the builtin fails.
+#[derive(Default)]
+pub struct U384MulModLibfunc {}
+impl NoGenericArgsGenericLibfunc for U384MulModLibfunc {
+    const STR_ID: &'static str = "u384_mul_mod";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let mul_mod_ty = context.get_concrete_type(MulModType::id(), &[])?;
+        mod_builtin_signature(context, mul_mod_ty)
+    }
+}
+
+/// Returns the signature of a libfunc applying a single operation of the given mod builtin to the
+/// operands `a` and `b`, modulo `modulus`.
+///
+/// The values of the operation are held by the RangeCheck96 builtin, so the limbs of the result are
+/// range checked. The result is also checked to be smaller than `modulus`, as the builtin only
+/// constrains it to be congruent to the result of the operation modulo `modulus`.
+fn mod_builtin_signature(
+    context: &dyn SignatureSpecializationContext,
+    builtin_ty: ConcreteTypeId,
+) -> Result<LibfuncSignature, SpecializationError> {
+    let range_check96_ty = context.get_concrete_type(RangeCheck96Type::id(), &[])?;
+    let u384_ty = get_u384_type(context)?;
+    Ok(LibfuncSignature::new_non_branch_ex(
+        vec![
+            ParamSignature::new(range_check96_ty.clone()).with_allow_add_const(),
+            ParamSignature::new(builtin_ty.clone()).with_allow_add_const(),
+            ParamSignature::new(u384_ty.clone()),
+            ParamSignature::new(u384_ty.clone()),
+            ParamSignature::new(u384_ty.clone()),
+        ],
+        vec![
+            OutputVarInfo::new_builtin(range_check96_ty, 0),
+            OutputVarInfo::new_builtin(builtin_ty, 1),
+            OutputVarInfo { ty: u384_ty, ref_info: OutputVarReferenceInfo::SimpleDerefs },
+        ],
+        SierraApChange::Known { new_vars_only: false },
+    ))
+}
@@ -0,0 +1,93 @@
+use num_bigint::BigInt;
+
+use super::felt252_span_ty;
+use super::range_check::RangeCheckType;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::{
+    NamedLibfunc, NamedType, OutputVarReferenceInfo, SignatureBasedConcreteLibfunc,
+    SpecializationError,
+};
+use crate::program::GenericArg;
+
+define_libfunc_hierarchy! {
+    pub enum OracleLibfunc {
+        Call(OracleCallLibfunc),
+    }, OracleConcreteLibfunc
+}
+
+/// Libfunc for calling the oracle with the given selector - a computation implemented by the
+/// runner, returning the serialized output for the serialized input.
+///
+/// The output is nondeterministic, so the program must verify it, as with any other hint. Only the
+/// size of the output span is range checked - its values are not checked in any way.
+#[derive(Default)]
+pub struct OracleCallLibfunc {}
+impl NamedLibfunc for OracleCallLibfunc {
+    type Concrete = OracleCallConcreteLibfunc;
+    const STR_ID: &'static str = "oracle_call";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        if args.len() != 1 {
+            return Err(SpecializationError::WrongNumberOfGenericArgs);
+        }
+
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let span_ty = felt252_span_ty(context)?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+                // input
+                ParamSignature::new(span_ty.clone()),
+            ],
+            branch_signatures: vec![BranchSignature {
+                vars: vec![
+                    OutputVarInfo::new_builtin(range_check_type, 0),
+                    // output
+                    OutputVarInfo {
+                        ty: span_ty,
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            }],
+            fallthrough: Some(0),
+        })
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(selector)] => Ok(OracleCallConcreteLibfunc {
+                selector: selector.clone(),
+                signature: <Self as NamedLibfunc>::specialize_signature(
+                    self,
+                    context.upcast(),
+                    args,
+                )?,
+            }),
+            [_] => Err(SpecializationError::UnsupportedGenericArg),
+            _ => Err(SpecializationError::WrongNumberOfGenericArgs),
+        }
+    }
+}
+
+pub struct OracleCallConcreteLibfunc {
+    pub selector: BigInt,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for OracleCallConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
@@ -1,8 +1,13 @@
+use super::array::ArrayType;
 use super::felt252::Felt252Type;
+use super::gas::{BuiltinCostsType, CostTokenType, GasBuiltinType};
+use super::merkle::{MerkleHashTraits, MerkleVerifyLibfunc};
+use super::range_check::RangeCheckType;
+use super::snapshot::snapshot_ty;
 use crate::define_libfunc_hierarchy;
 use crate::extensions::lib_func::{
-    DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
-    SignatureSpecializationContext,
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext,
 };
 use crate::extensions::{
     NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
@@ -24,6 +29,8 @@ impl NoGenericArgsGenericType for PedersenType {
 define_libfunc_hierarchy! {
     pub enum PedersenLibfunc {
         PedersenHash(PedersenHashLibfunc),
+        MerkleVerify(MerkleVerifyLibfunc<PedersenMerkleHashTraits>),
+        HashSpan(PedersenHashSpanLibfunc),
     }, PedersenConcreteLibfunc
 }
 
@@ -58,3 +65,87 @@ impl NoGenericArgsGenericLibfunc for PedersenHashLibfunc {
         ))
     }
 }
+
+/// Libfunc for chaining the Pedersen hash over a span of felt252s: starting from the given state,
+/// each element is hashed with the current state to get the next state. Returns the final state.
+///
+/// The hashing loop is charged at runtime, using the builtin costs for the cost of the Pedersen
+/// builtin - the second branch is taken if there is not enough gas for hashing the span.
+#[derive(Default)]
+pub struct PedersenHashSpanLibfunc {}
+impl NoGenericArgsGenericLibfunc for PedersenHashSpanLibfunc {
+    const STR_ID: &'static str = "pedersen_hash_span";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let pedersen_type = context.get_concrete_type(PedersenType::id(), &[])?;
+        let felt252_ty = context.get_concrete_type(Felt252Type::id(), &[])?;
+        let span_ty = snapshot_ty(
+            context,
+            context.get_wrapped_concrete_type(ArrayType::id(), felt252_ty.clone())?,
+        )?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+            ParamSignature::new(gas_builtin_type.clone()),
+            ParamSignature::new(pedersen_type.clone()).with_allow_add_const(),
+            ParamSignature::new(context.get_concrete_type(BuiltinCostsType::id(), &[])?),
+            // Initial state.
+            ParamSignature::new(felt252_ty.clone()),
+            // Span.
+            ParamSignature::new(span_ty),
+        ];
+        let branch_signatures = vec![
+            // Success.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo {
+                        ty: range_check_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                    },
+                    OutputVarInfo {
+                        ty: gas_builtin_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: pedersen_type.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 2 },
+                    },
+                    OutputVarInfo {
+                        ty: felt252_ty,
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 3 },
+                    },
+                ],
+                ap_change: SierraApChange::Unknown,
+            },
+            // Failure - not enough gas for hashing the span.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo::new_builtin(range_check_type, 0),
+                    OutputVarInfo {
+                        ty: gas_builtin_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: pedersen_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 2 },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+
+/// Traits for verifying Merkle paths hashed using the Pedersen builtin.
+#[derive(Default)]
+pub struct PedersenMerkleHashTraits;
+impl MerkleHashTraits for PedersenMerkleHashTraits {
+    const MERKLE_VERIFY: &'static str = "merkle_verify_pedersen";
+    const BUILTIN_TYPE_ID: GenericTypeId = <PedersenType as NoGenericArgsGenericType>::ID;
+    const COST_TOKEN_TYPE: CostTokenType = CostTokenType::Pedersen;
+}
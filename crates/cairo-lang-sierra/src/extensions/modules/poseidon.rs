@@ -1,4 +1,6 @@
 use super::felt252::Felt252Type;
+use super::gas::CostTokenType;
+use super::merkle::{MerkleHashTraits, MerkleVerifyLibfunc};
 use crate::define_libfunc_hierarchy;
 use crate::extensions::lib_func::{
     DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
@@ -24,6 +26,7 @@ impl NoGenericArgsGenericType for PoseidonType {
 define_libfunc_hierarchy! {
     pub enum PoseidonLibfunc {
         HadesPermutation(HadesPermutationLibfunc),
+        MerkleVerify(MerkleVerifyLibfunc<PoseidonMerkleHashTraits>),
     }, PoseidonConcreteLibfunc
 }
 
@@ -62,3 +65,12 @@ impl NoGenericArgsGenericLibfunc for HadesPermutationLibfunc {
         ))
     }
 }
+
+/// Traits for verifying Merkle paths hashed using the Poseidon builtin.
+#[derive(Default)]
+pub struct PoseidonMerkleHashTraits;
+impl MerkleHashTraits for PoseidonMerkleHashTraits {
+    const MERKLE_VERIFY: &'static str = "merkle_verify_poseidon";
+    const BUILTIN_TYPE_ID: GenericTypeId = <PoseidonType as NoGenericArgsGenericType>::ID;
+    const COST_TOKEN_TYPE: CostTokenType = CostTokenType::Poseidon;
+}
@@ -11,3 +11,14 @@ impl NoGenericArgsGenericType for RangeCheckType {
     const DROPPABLE: bool = false;
     const ZERO_SIZED: bool = false;
 }
+
+/// Type for Range Check 96 builtin - validating values are in the range [0, 2**96).
+#[derive(Default)]
+pub struct RangeCheck96Type {}
+impl NoGenericArgsGenericType for RangeCheck96Type {
+    const ID: GenericTypeId = GenericTypeId::new_inline("RangeCheck96");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = false;
+    const DROPPABLE: bool = false;
+    const ZERO_SIZED: bool = false;
+}
@@ -0,0 +1,134 @@
+use super::array::ArrayType;
+use super::int::unsigned::{Uint32Type, Uint8Type};
+use super::int::unsigned128::Uint128Type;
+use super::range_check::RangeCheckType;
+use super::snapshot::snapshot_ty;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+
+define_libfunc_hierarchy! {
+    pub enum RlpLibfunc {
+        ReadU128Be(BytesReadU128BeLibfunc),
+        DecodeHeader(RlpDecodeHeaderLibfunc),
+    }, RlpConcreteLibfunc
+}
+
+/// Libfunc for reading a big-endian u128 from up to 16 bytes of a byte array, at a given offset
+/// and length. Fails if the length is more than 16, or the bytes are out of the array's bounds.
+#[derive(Default)]
+pub struct BytesReadU128BeLibfunc {}
+impl NoGenericArgsGenericLibfunc for BytesReadU128BeLibfunc {
+    const STR_ID: &'static str = "bytes_read_u128_be";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let u8_ty = context.get_concrete_type(Uint8Type::id(), &[])?;
+        let arr_ty = context.get_wrapped_concrete_type(ArrayType::id(), u8_ty)?;
+        let u32_ty = context.get_concrete_type(Uint32Type::id(), &[])?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+                ParamSignature::new(snapshot_ty(context, arr_ty)?),
+                // Offset.
+                ParamSignature::new(u32_ty.clone()),
+                // Length.
+                ParamSignature::new(u32_ty),
+            ],
+            branch_signatures: vec![
+                // Success.
+                BranchSignature {
+                    vars: vec![
+                        OutputVarInfo {
+                            ty: range_check_type.clone(),
+                            ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                        },
+                        OutputVarInfo {
+                            ty: context.get_concrete_type(Uint128Type::id(), &[])?,
+                            ref_info: OutputVarReferenceInfo::NewTempVar { idx: 1 },
+                        },
+                    ],
+                    ap_change: SierraApChange::Unknown,
+                },
+                // Failure.
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: range_check_type,
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                    }],
+                    ap_change: SierraApChange::Unknown,
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for decoding the header of an RLP item of a byte array, at a given offset.
+/// Returns the offset and the length of the item's payload, in the branch of the item's kind -
+/// string or list. The item is invalid if its header or payload are out of the array's bounds, or
+/// if the length of its payload doesn't fit in 4 bytes. The encoding is not checked to be
+/// canonical.
+#[derive(Default)]
+pub struct RlpDecodeHeaderLibfunc {}
+impl NoGenericArgsGenericLibfunc for RlpDecodeHeaderLibfunc {
+    const STR_ID: &'static str = "rlp_decode_header";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let u8_ty = context.get_concrete_type(Uint8Type::id(), &[])?;
+        let arr_ty = context.get_wrapped_concrete_type(ArrayType::id(), u8_ty)?;
+        let u32_ty = context.get_concrete_type(Uint32Type::id(), &[])?;
+        // The branches of the item kinds, returning the offset and length of the payload.
+        let item_branch_signature = || BranchSignature {
+            vars: vec![
+                OutputVarInfo {
+                    ty: range_check_type.clone(),
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+                OutputVarInfo {
+                    ty: u32_ty.clone(),
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+                OutputVarInfo {
+                    ty: u32_ty.clone(),
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+            ],
+            ap_change: SierraApChange::Unknown,
+        };
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+                ParamSignature::new(snapshot_ty(context, arr_ty)?),
+                ParamSignature::new(u32_ty.clone()),
+            ],
+            branch_signatures: vec![
+                // String.
+                item_branch_signature(),
+                // List.
+                item_branch_signature(),
+                // Invalid.
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: range_check_type,
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                    }],
+                    ap_change: SierraApChange::Unknown,
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
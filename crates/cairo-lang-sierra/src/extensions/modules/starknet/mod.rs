@@ -41,7 +41,7 @@ use self::storage::{
 use self::syscalls::KeccakLibfunc;
 use self::testing::TestingLibfunc;
 use super::array::ArrayType;
-use super::felt252::Felt252Type;
+use super::felt252_span_ty;
 use super::int::unsigned::Uint64Type;
 use super::snapshot::snapshot_ty;
 use super::structure::StructType;
@@ -88,23 +88,6 @@ define_libfunc_hierarchy! {
     }, StarkNetConcreteLibfunc
 }
 
-/// User type for `Span<felt252>`.
-fn felt252_span_ty(
-    context: &dyn SignatureSpecializationContext,
-) -> Result<ConcreteTypeId, SpecializationError> {
-    let felt252_array_ty = context.get_wrapped_concrete_type(
-        ArrayType::id(),
-        context.get_concrete_type(Felt252Type::id(), &[])?,
-    )?;
-    context.get_concrete_type(
-        StructType::id(),
-        &[
-            GenericArg::UserType(UserTypeId::from_string("core::array::Span::<core::felt252>")),
-            GenericArg::Type(snapshot_ty(context, felt252_array_ty)?),
-        ],
-    )
-}
-
 /// User type for `Span<u64>`.
 fn u64_span_ty(
     context: &dyn SignatureSpecializationContext,
@@ -3,6 +3,7 @@ use num_bigint::BigInt;
 use test_case::test_case;
 
 use super::core::{CoreLibfunc, CoreType};
+use super::inline_casm::encode_inline_casm_code;
 use super::lib_func::{SierraApChange, SignatureSpecializationContext, SpecializationContext};
 use super::types::TypeInfo;
 use super::SpecializationError::{
@@ -27,6 +28,18 @@ fn value_arg(v: i64) -> GenericArg {
     GenericArg::Value(BigInt::from(v))
 }
 
+fn inline_casm_args(
+    num_inputs: i64,
+    num_outputs: i64,
+    ap_change: i64,
+    code: &str,
+) -> Vec<GenericArg> {
+    [value_arg(num_inputs), value_arg(num_outputs), value_arg(ap_change)]
+        .into_iter()
+        .chain(encode_inline_casm_code(code).into_iter().map(GenericArg::Value))
+        .collect()
+}
+
 struct MockSpecializationContext {
     mapping: BiMap<ConcreteTypeId, ConcreteTypeLongId>,
 }
@@ -40,14 +53,29 @@ impl TypeSpecializationContext for MockSpecializationContext {
     fn try_get_type_info(&self, id: ConcreteTypeId) -> Option<TypeInfo> {
         if id == "T".into()
             || id == "felt252".into()
+            || id == "u8".into()
+            || id == "u32".into()
+            || id == "u96".into()
+            || id == "bytes31".into()
             || id == "u128".into()
+            || id == "i8".into()
+            || id == "i16".into()
             || id == "Option".into()
             || id == "NonZeroFelt252".into()
             || id == "NonZeroInt".into()
             || id == "Tuple<>".into()
             || id == "U128AndFelt252".into()
+            || id == "TupleU8U128".into()
+            || id == "TupleU128U128".into()
             || id == "StorageAddress".into()
             || id == "ContractAddress".into()
+            || id == "BuiltinCosts".into()
+            || id == "Digit".into()
+            || id == "NonZeroDigit".into()
+            || id == "DigitsSum".into()
+            || id == "DigitsDiff".into()
+            || id == "DigitsProduct".into()
+            || id == "DigitsRemainder".into()
         {
             Some(TypeInfo {
                 long_id: self.mapping.get_by_left(&id)?.clone(),
@@ -56,7 +84,8 @@ impl TypeSpecializationContext for MockSpecializationContext {
                 duplicatable: true,
                 zero_sized: false,
             })
-        } else if id == "ArrayFelt252".into() || id == "ArrayU128".into() {
+        } else if id == "ArrayFelt252".into() || id == "ArrayU8".into() || id == "ArrayU128".into()
+        {
             Some(TypeInfo {
                 long_id: self.mapping.get_by_left(&id)?.clone(),
                 storable: true,
@@ -75,6 +104,8 @@ impl TypeSpecializationContext for MockSpecializationContext {
         } else if id == "GasBuiltin".into()
             || id == "System".into()
             || id == "RangeCheck".into()
+            || id == "Pedersen".into()
+            || id == "Poseidon".into()
             || id == "NonDupEnum".into()
             || id == "NonDupStruct".into()
         {
@@ -85,7 +116,33 @@ impl TypeSpecializationContext for MockSpecializationContext {
                 duplicatable: false,
                 zero_sized: false,
             })
-        } else if id == "SnapshotRangeCheck".into() || id == "SnapshotArrayU128".into() {
+        } else if id == "In0".into()
+            || id == "In1".into()
+            || id == "Sum".into()
+            || id == "InverseSum".into()
+            || id == "CircuitOutputs".into()
+            || id == "MissingInputOutputs".into()
+        {
+            Some(TypeInfo {
+                long_id: self.mapping.get_by_left(&id)?.clone(),
+                storable: true,
+                droppable: false,
+                duplicatable: false,
+                zero_sized: true,
+            })
+        } else if id == "ConstDigits".into() {
+            Some(TypeInfo {
+                long_id: self.mapping.get_by_left(&id)?.clone(),
+                storable: false,
+                droppable: false,
+                duplicatable: false,
+                zero_sized: true,
+            })
+        } else if id == "SnapshotRangeCheck".into()
+            || id == "SnapshotArrayU8".into()
+            || id == "SnapshotArrayU128".into()
+            || id == "SnapshotArrayFelt252".into()
+        {
             Some(TypeInfo {
                 long_id: self.mapping.get_by_left(&id)?.clone(),
                 storable: true,
@@ -147,6 +204,43 @@ impl SpecializationContext for MockSpecializationContext {
 #[test_case("RangeCheck", vec![type_arg("T")] => Err(WrongNumberOfGenericArgs); "RangeCheck<T>")]
 #[test_case("felt252", vec![] => Ok(()); "felt252")]
 #[test_case("felt252", vec![type_arg("T")] => Err(WrongNumberOfGenericArgs); "felt252<T>")]
+#[test_case("u96", vec![] => Ok(()); "u96")]
+#[test_case("bytes31", vec![] => Ok(()); "bytes31")]
+#[test_case("BoundedInt", vec![value_arg(0), value_arg(9)] => Ok(()); "BoundedInt<0, 9>")]
+#[test_case("BoundedInt", vec![value_arg(-9), value_arg(-9)] => Ok(()); "BoundedInt<-9, -9>")]
+#[test_case("BoundedInt", vec![value_arg(9), value_arg(0)]
+            => Err(UnsupportedGenericArg); "BoundedInt<9, 0>")]
+#[test_case("BoundedInt", vec![value_arg(0), GenericArg::Value(BigInt::from(1) << 250)]
+            => Err(UnsupportedGenericArg); "BoundedInt<0, 2**250>")]
+#[test_case("BoundedInt", vec![value_arg(0), type_arg("T")]
+            => Err(UnsupportedGenericArg); "BoundedInt<0, T>")]
+#[test_case("BoundedInt", vec![value_arg(0)] => Err(WrongNumberOfGenericArgs); "BoundedInt<0>")]
+#[test_case("CircuitInput", vec![value_arg(0)] => Ok(()); "CircuitInput<0>")]
+#[test_case("CircuitInput", vec![value_arg(-1)] => Err(UnsupportedGenericArg); "CircuitInput<-1>")]
+#[test_case("CircuitInput", vec![type_arg("T")] => Err(UnsupportedGenericArg); "CircuitInput<T>")]
+#[test_case("AddModGate", vec![type_arg("In0"), type_arg("In1")] => Ok(());
+            "AddModGate<In0, In1>")]
+#[test_case("MulModGate", vec![type_arg("Sum"), type_arg("In1")] => Ok(());
+            "MulModGate<Sum, In1>")]
+#[test_case("SubModGate", vec![type_arg("In0"), type_arg("felt252")]
+            => Err(UnsupportedGenericArg); "SubModGate<In0, felt252>")]
+#[test_case("InverseGate", vec![type_arg("Sum")] => Ok(()); "InverseGate<Sum>")]
+#[test_case("InverseGate", vec![type_arg("In0"), type_arg("In1")]
+            => Err(WrongNumberOfGenericArgs); "InverseGate<In0, In1>")]
+#[test_case("Circuit", vec![type_arg("CircuitOutputs")] => Ok(()); "Circuit<CircuitOutputs>")]
+#[test_case("Circuit", vec![type_arg("MissingInputOutputs")] => Err(UnsupportedGenericArg);
+            "Circuit<MissingInputOutputs>")]
+#[test_case("Circuit", vec![type_arg("Sum")] => Err(UnsupportedGenericArg); "Circuit<Sum>")]
+#[test_case("ConstArray", vec![type_arg("u8"), value_arg(0), value_arg(255)] => Ok(());
+            "ConstArray<u8, 0, 255>")]
+#[test_case("ConstArray", vec![type_arg("u8"), value_arg(256)] => Err(UnsupportedGenericArg);
+            "ConstArray<u8, 256>")]
+#[test_case("ConstArray", vec![type_arg("i8"), value_arg(-128)] => Ok(()); "ConstArray<i8, -128>")]
+#[test_case("ConstArray", vec![type_arg("felt252")] => Ok(()); "ConstArray<felt252>")]
+#[test_case("ConstArray", vec![type_arg("ArrayU8"), value_arg(1)] => Err(UnsupportedGenericArg);
+            "ConstArray<ArrayU8, 1>")]
+#[test_case("ConstArray", vec![value_arg(1)] => Err(UnsupportedGenericArg); "ConstArray<1>")]
+#[test_case("ConstArray", vec![] => Err(WrongNumberOfGenericArgs); "ConstArray")]
 #[test_case("u128", vec![] => Ok(()); "u128")]
 #[test_case("u128", vec![type_arg("T")] => Err(WrongNumberOfGenericArgs); "u128<T>")]
 #[test_case("Array", vec![type_arg("u128")] => Ok(()); "Array<u128>")]
@@ -217,10 +311,60 @@ fn find_type_specialization(
 #[test_case("array_append", vec![type_arg("u128")] => Ok(()); "array_append<u128>")]
 #[test_case("array_get", vec![] => Err(WrongNumberOfGenericArgs); "array_get")]
 #[test_case("array_get", vec![type_arg("u128")] => Ok(()); "array_get<u128>")]
+#[test_case("array_get_usize", vec![] => Err(WrongNumberOfGenericArgs); "array_get_usize")]
+#[test_case("array_get_usize", vec![type_arg("u128")] => Ok(()); "array_get_usize<u128>")]
+#[test_case("array_extend_from_span", vec![] => Err(WrongNumberOfGenericArgs);
+            "array_extend_from_span")]
+#[test_case("array_extend_from_span", vec![type_arg("u128")] => Ok(());
+            "array_extend_from_span<u128>")]
+#[test_case("array_extend_from_span", vec![type_arg("ArrayU128")] => Err(UnsupportedGenericArg);
+            "array_extend_from_span<ArrayU128>")]
+#[test_case("span_eq", vec![] => Err(WrongNumberOfGenericArgs); "span_eq")]
+#[test_case("span_eq", vec![type_arg("u128")] => Ok(()); "span_eq<u128>")]
+#[test_case("span_eq", vec![type_arg("felt252")] => Ok(()); "span_eq<felt252>")]
+#[test_case("span_eq", vec![type_arg("ArrayU128")] => Err(UnsupportedGenericArg);
+            "span_eq<ArrayU128>")]
+#[test_case("span_binary_search", vec![] => Ok(()); "span_binary_search")]
+#[test_case("span_binary_search", vec![type_arg("u128")] => Err(WrongNumberOfGenericArgs);
+            "span_binary_search<u128>")]
+#[test_case("span_sort_verified", vec![] => Ok(()); "span_sort_verified")]
+#[test_case("span_sort_verified", vec![type_arg("u128")] => Err(WrongNumberOfGenericArgs);
+            "span_sort_verified<u128>")]
+#[test_case("merkle_verify_pedersen", vec![] => Ok(()); "merkle_verify_pedersen")]
+#[test_case("merkle_verify_poseidon", vec![] => Ok(()); "merkle_verify_poseidon")]
+#[test_case("pedersen_hash_span", vec![] => Ok(()); "pedersen_hash_span")]
+#[test_case("merkle_verify_poseidon", vec![type_arg("felt252")] => Err(WrongNumberOfGenericArgs);
+            "merkle_verify_poseidon<felt252>")]
+#[test_case("bytes_hex_encode", vec![] => Ok(()); "bytes_hex_encode")]
+#[test_case("bytes_hex_decode", vec![] => Ok(()); "bytes_hex_decode")]
+#[test_case("bytes_base64_encode", vec![] => Ok(()); "bytes_base64_encode")]
+#[test_case("bytes_hex_encode", vec![type_arg("u8")] => Err(WrongNumberOfGenericArgs);
+            "bytes_hex_encode<u8>")]
+#[test_case("bytes_read_u128_be", vec![] => Ok(()); "bytes_read_u128_be")]
+#[test_case("rlp_decode_header", vec![] => Ok(()); "rlp_decode_header")]
+#[test_case("rlp_decode_header", vec![type_arg("u8")] => Err(WrongNumberOfGenericArgs);
+            "rlp_decode_header<u8>")]
+#[test_case("bitfield_pack", vec![type_arg("TupleU8U128")] => Ok(()); "bitfield_pack<TupleU8U128>")]
+#[test_case("bitfield_unpack", vec![type_arg("TupleU8U128")] => Ok(());
+            "bitfield_unpack<TupleU8U128>")]
+#[test_case("bitfield_pack", vec![type_arg("TupleU128U128")] => Err(UnsupportedGenericArg);
+            "bitfield_pack<TupleU128U128>")]
+#[test_case("bitfield_unpack", vec![type_arg("U128AndFelt252")] => Err(UnsupportedGenericArg);
+            "bitfield_unpack<U128AndFelt252>")]
+#[test_case("bitfield_pack", vec![] => Err(WrongNumberOfGenericArgs); "bitfield_pack")]
+#[test_case("inline_casm", inline_casm_args(2, 1, 1, "[ap + 0] = [ap + -2] * [ap + -1], ap++")
+            => Ok(()); "inline_casm<mul>")]
+#[test_case("inline_casm", inline_casm_args(1, 1, 2, "[ap + 0] = [ap + -1] + 1, ap++")
+            => Err(UnsupportedGenericArg); "inline_casm<wrong_ap_change>")]
+#[test_case("inline_casm", inline_casm_args(0, 0, 0, "jmp rel 0")
+            => Err(UnsupportedGenericArg); "inline_casm<jmp>")]
+#[test_case("inline_casm", vec![value_arg(0), value_arg(0), value_arg(0)]
+            => Err(WrongNumberOfGenericArgs); "inline_casm<no_code>")]
 #[test_case("array_len", vec![] => Err(WrongNumberOfGenericArgs); "array_len")]
 #[test_case("array_len", vec![type_arg("u128")] => Ok(()); "array_len<u128>")]
 #[test_case("withdraw_gas", vec![value_arg(0)] => Err(WrongNumberOfGenericArgs); "withdraw_gas<0>")]
 #[test_case("withdraw_gas", vec![] => Ok(()); "withdraw_gas")]
+#[test_case("gas_checkpoint", vec![] => Ok(()); "gas_checkpoint")]
 #[test_case("redeposit_gas", vec![value_arg(0)] => Err(WrongNumberOfGenericArgs); "redeposit_gas<0>")]
 #[test_case("redeposit_gas", vec![] => Ok(()); "redeposit_gas")]
 #[test_case("felt252_add", vec![] => Ok(()); "felt252_add")]
@@ -230,9 +374,62 @@ fn find_type_specialization(
 #[test_case("felt252_is_zero", vec![] => Ok(()); "felt252_is_zero<>")]
 #[test_case("felt252_is_zero", vec![type_arg("felt252")]
             => Err(WrongNumberOfGenericArgs); "felt252_is_zero<int>")]
+#[test_case("felt252_sqrt", vec![] => Ok(()); "felt252_sqrt")]
 #[test_case("u128_overflowing_add", vec![] => Ok(()); "u128_overflowing_add")]
 #[test_case("u128_overflowing_sub", vec![] => Ok(()); "u128_overflowing_sub")]
 #[test_case("u128_safe_divmod", vec![] => Ok(()); "u128_safe_divmod")]
+#[test_case("u128_unchecked_add", vec![] => Ok(()); "u128_unchecked_add")]
+#[test_case("u8_unchecked_mul", vec![] => Ok(()); "u8_unchecked_mul")]
+#[test_case("u64_unchecked_sub", vec![type_arg("u64")]
+            => Err(WrongNumberOfGenericArgs); "u64_unchecked_sub<u64>")]
+#[test_case("u96_const", vec![value_arg(8)] => Ok(()); "u96_const<8>")]
+#[test_case("u96_const", vec![GenericArg::Value(BigInt::from(1) << 96)]
+            => Err(UnsupportedGenericArg); "u96_const<2**96>")]
+#[test_case("u96_overflowing_add", vec![] => Ok(()); "u96_overflowing_add")]
+#[test_case("u96_try_from_felt252", vec![] => Ok(()); "u96_try_from_felt252")]
+#[test_case("u96_wide_mul", vec![] => Ok(()); "u96_wide_mul")]
+#[test_case("bytes31_const", vec![value_arg(8)] => Ok(()); "bytes31_const<8>")]
+#[test_case("bytes31_const", vec![GenericArg::Value(BigInt::from(1) << 248)]
+            => Err(UnsupportedGenericArg); "bytes31_const<2**248>")]
+#[test_case("bytes31_to_felt252", vec![] => Ok(()); "bytes31_to_felt252")]
+#[test_case("bytes31_try_from_felt252", vec![] => Ok(()); "bytes31_try_from_felt252")]
+#[test_case("bytes31_append_u8", vec![] => Ok(()); "bytes31_append_u8")]
+#[test_case("bytes31_split_u8", vec![] => Ok(()); "bytes31_split_u8")]
+#[test_case("bounded_int_add", vec![type_arg("Digit"), type_arg("NonZeroDigit")] => Ok(());
+            "bounded_int_add<Digit, NonZeroDigit>")]
+#[test_case("bounded_int_sub", vec![type_arg("Digit"), type_arg("NonZeroDigit")] => Ok(());
+            "bounded_int_sub<Digit, NonZeroDigit>")]
+#[test_case("bounded_int_mul", vec![type_arg("Digit"), type_arg("Digit")] => Ok(());
+            "bounded_int_mul<Digit, Digit>")]
+#[test_case("bounded_int_add", vec![type_arg("Digit"), type_arg("felt252")]
+            => Err(UnsupportedGenericArg); "bounded_int_add<Digit, felt252>")]
+#[test_case("bounded_int_add", vec![type_arg("Digit")]
+            => Err(WrongNumberOfGenericArgs); "bounded_int_add<Digit>")]
+#[test_case("bounded_int_div_rem", vec![type_arg("Digit"), type_arg("NonZeroDigit")] => Ok(());
+            "bounded_int_div_rem<Digit, NonZeroDigit>")]
+#[test_case("bounded_int_div_rem", vec![type_arg("NonZeroDigit"), type_arg("Digit")]
+            => Err(UnsupportedGenericArg); "bounded_int_div_rem<NonZeroDigit, Digit>")]
+#[test_case("bounded_int_div_rem", vec![type_arg("DigitsDiff"), type_arg("NonZeroDigit")]
+            => Err(UnsupportedGenericArg); "bounded_int_div_rem<DigitsDiff, NonZeroDigit>")]
+#[test_case("instrumentation_hit", vec![value_arg(3)] => Ok(()); "instrumentation_hit<3>")]
+#[test_case("instrumentation_hit", vec![value_arg(-1)]
+            => Err(UnsupportedGenericArg); "instrumentation_hit<-1>")]
+#[test_case("instrumentation_hit", vec![] => Err(WrongNumberOfGenericArgs); "instrumentation_hit")]
+#[test_case("upcast", vec![type_arg("u8"), type_arg("u96")] => Ok(()); "upcast<u8, u96>")]
+#[test_case("upcast", vec![type_arg("u128"), type_arg("u96")]
+            => Err(UnsupportedGenericArg); "upcast<u128, u96>")]
+#[test_case("downcast", vec![type_arg("u128"), type_arg("u96")] => Ok(()); "downcast<u128, u96>")]
+#[test_case("downcast", vec![type_arg("u8"), type_arg("u96")]
+            => Err(UnsupportedGenericArg); "downcast<u8, u96>")]
+#[test_case("upcast", vec![type_arg("i8"), type_arg("i16")] => Ok(()); "upcast<i8, i16>")]
+#[test_case("upcast", vec![type_arg("u8"), type_arg("i16")] => Ok(()); "upcast<u8, i16>")]
+#[test_case("upcast", vec![type_arg("i8"), type_arg("u8")]
+            => Err(UnsupportedGenericArg); "upcast<i8, u8>")]
+#[test_case("downcast", vec![type_arg("i16"), type_arg("i8")] => Ok(()); "downcast<i16, i8>")]
+#[test_case("downcast", vec![type_arg("i8"), type_arg("u8")] => Ok(()); "downcast<i8, u8>")]
+#[test_case("downcast", vec![type_arg("u8"), type_arg("i8")] => Ok(()); "downcast<u8, i8>")]
+#[test_case("downcast", vec![type_arg("i8"), type_arg("i16")]
+            => Err(UnsupportedGenericArg); "downcast<i8, i16>")]
 #[test_case("u128_const", vec![value_arg(8)] => Ok(()); "u128_const<8>")]
 #[test_case("u128_const", vec![] => Err(UnsupportedGenericArg); "u128_const")]
 #[test_case("storage_base_address_const", vec![value_arg(8)] => Ok(()); "storage_base_address_const<8>")]
@@ -314,6 +511,10 @@ Ok(());"enum_init<Option,1>")]
 #[test_case("snapshot_take", vec![type_arg("felt252")] => Ok(()); "snapshot_take<felt252>")]
 #[test_case("snapshot_take", vec![type_arg("SnapshotRangeCheck")] => Ok(());
             "snapshot_take<SnapshotRangeCheck>")]
+#[test_case("const_as_span", vec![type_arg("ConstDigits")] => Ok(());
+            "const_as_span<ConstDigits>")]
+#[test_case("const_as_span", vec![type_arg("u8")] => Err(UnsupportedGenericArg);
+            "const_as_span<u8>")]
 fn find_libfunc_specialization(
     id: &str,
     generic_args: Vec<GenericArg>,
@@ -18,6 +18,7 @@ pub mod fmt;
 pub mod ids;
 pub mod program;
 pub mod program_registry;
+pub mod prune;
 pub mod simulation;
 #[cfg(test)]
 mod test_utils;
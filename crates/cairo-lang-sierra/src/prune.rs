@@ -0,0 +1,223 @@
+//! Removal of the code of a Sierra program that is unreachable from its entry points.
+
+use std::collections::{HashSet, VecDeque};
+
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use thiserror::Error;
+
+use crate::extensions::core::{CoreLibfunc, CoreType};
+use crate::extensions::function_call::FunctionCallLibfunc;
+use crate::extensions::{ConcreteLibfunc, NamedLibfunc};
+use crate::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId};
+use crate::program::{
+    BranchInfo, BranchTarget, GenericArg, Invocation, Program, Statement, StatementIdx,
+};
+use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
+
+#[cfg(test)]
+#[path = "prune_test.rs"]
+mod test;
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum PruneError {
+    #[error("Entry point `{0}` is not a function of the program.")]
+    MissingEntryPoint(FunctionId),
+    #[error(transparent)]
+    ProgramRegistryError(#[from] Box<ProgramRegistryError>),
+}
+
+/// A report of the code removed from a program by [prune_unreachable_code].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PruneReport {
+    /// The removed functions.
+    pub functions: Vec<FunctionId>,
+    /// The number of removed statements.
+    pub n_statements: usize,
+    /// The removed libfunc declarations.
+    pub libfuncs: Vec<ConcreteLibfuncId>,
+    /// The removed type declarations.
+    pub types: Vec<ConcreteTypeId>,
+}
+impl std::fmt::Display for PruneReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Pruned {} functions, {} statements, {} libfuncs and {} types.",
+            self.functions.len(),
+            self.n_statements,
+            self.libfuncs.len(),
+            self.types.len()
+        )?;
+        for function in &self.functions {
+            writeln!(f, "function: {function}")?;
+        }
+        for libfunc in &self.libfuncs {
+            writeln!(f, "libfunc: {libfunc}")?;
+        }
+        for ty in &self.types {
+            writeln!(f, "type: {ty}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Removes the functions, statements, libfunc declarations and type declarations of `program` that
+/// are unreachable from the functions in `entry_points`.
+/// Returns the pruned program and a report of what was removed.
+pub fn prune_unreachable_code(
+    program: &Program,
+    entry_points: &[FunctionId],
+) -> Result<(Program, PruneReport), PruneError> {
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
+    let mut reachable_functions = HashSet::<FunctionId>::new();
+    let mut reachable_statements = vec![false; program.statements.len()];
+    let mut queue = VecDeque::<StatementIdx>::new();
+    for function_id in entry_points {
+        let function = registry
+            .get_function(function_id)
+            .map_err(|_| PruneError::MissingEntryPoint(function_id.clone()))?;
+        reachable_functions.insert(function_id.clone());
+        queue.push_back(function.entry_point);
+    }
+    while let Some(idx) = queue.pop_front() {
+        if std::mem::replace(&mut reachable_statements[idx.0], true) {
+            continue;
+        }
+        let Statement::Invocation(invocation) = &program.statements[idx.0] else { continue };
+        for branch in &invocation.branches {
+            queue.push_back(idx.next(&branch.target));
+        }
+        if let Some(function_id) = called_function(program, &invocation.libfunc_id) {
+            if reachable_functions.insert(function_id.clone()) {
+                queue.push_back(registry.get_function(&function_id)?.entry_point);
+            }
+        }
+    }
+
+    // Map the reachable statements to their new indices.
+    let mut new_indices = vec![None; program.statements.len()];
+    let mut n_kept_statements = 0;
+    for (idx, reachable) in reachable_statements.iter().enumerate() {
+        if *reachable {
+            new_indices[idx] = Some(StatementIdx(n_kept_statements));
+            n_kept_statements += 1;
+        }
+    }
+    let remap = |idx: &StatementIdx| new_indices[idx.0].unwrap();
+    let statements: Vec<Statement> = program
+        .statements
+        .iter()
+        .zip(&reachable_statements)
+        .filter(|(_, reachable)| **reachable)
+        .map(|(statement, _)| match statement {
+            Statement::Invocation(invocation) => Statement::Invocation(Invocation {
+                libfunc_id: invocation.libfunc_id.clone(),
+                args: invocation.args.clone(),
+                branches: invocation
+                    .branches
+                    .iter()
+                    .map(|branch| BranchInfo {
+                        target: match &branch.target {
+                            BranchTarget::Fallthrough => BranchTarget::Fallthrough,
+                            BranchTarget::Statement(idx) => BranchTarget::Statement(remap(idx)),
+                        },
+                        results: branch.results.clone(),
+                    })
+                    .collect(),
+            }),
+            Statement::Return(vars) => Statement::Return(vars.clone()),
+        })
+        .collect();
+    let (funcs, removed_funcs): (Vec<_>, Vec<_>) =
+        program.funcs.iter().partition(|function| reachable_functions.contains(&function.id));
+    let funcs = funcs
+        .into_iter()
+        .map(|function| {
+            let mut function = function.clone();
+            function.entry_point = remap(&function.entry_point);
+            function
+        })
+        .collect::<Vec<_>>();
+
+    // Collect the libfuncs used by the remaining statements, and the types they depend on.
+    let used_libfuncs: HashSet<&ConcreteLibfuncId> = statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Invocation(invocation) => Some(&invocation.libfunc_id),
+            Statement::Return(_) => None,
+        })
+        .collect();
+    let mut used_types = OrderedHashSet::<ConcreteTypeId>::default();
+    for function in &funcs {
+        used_types.extend(function.signature.param_types.iter().cloned());
+        used_types.extend(function.signature.ret_types.iter().cloned());
+    }
+    for declaration in &program.libfunc_declarations {
+        if !used_libfuncs.contains(&declaration.id) {
+            continue;
+        }
+        let libfunc = registry.get_libfunc(&declaration.id)?;
+        used_types.extend(libfunc.param_signatures().iter().map(|param| param.ty.clone()));
+        used_types.extend(
+            libfunc
+                .branch_signatures()
+                .iter()
+                .flat_map(|branch| &branch.vars)
+                .map(|var| var.ty.clone()),
+        );
+        used_types.extend(generic_args_types(&declaration.long_id.generic_args));
+    }
+    // Add the types the used types depend on.
+    let mut pending_types: Vec<ConcreteTypeId> = used_types.iter().cloned().collect();
+    while let Some(ty) = pending_types.pop() {
+        let Some(declaration) =
+            program.type_declarations.iter().find(|declaration| declaration.id == ty)
+        else {
+            continue;
+        };
+        for inner_ty in generic_args_types(&declaration.long_id.generic_args) {
+            if used_types.insert(inner_ty.clone()) {
+                pending_types.push(inner_ty);
+            }
+        }
+    }
+
+    let (type_declarations, removed_types): (Vec<_>, Vec<_>) = program
+        .type_declarations
+        .iter()
+        .cloned()
+        .partition(|declaration| used_types.contains(&declaration.id));
+    let (libfunc_declarations, removed_libfuncs): (Vec<_>, Vec<_>) = program
+        .libfunc_declarations
+        .iter()
+        .cloned()
+        .partition(|declaration| used_libfuncs.contains(&declaration.id));
+    let report = PruneReport {
+        functions: removed_funcs.into_iter().map(|function| function.id.clone()).collect(),
+        n_statements: program.statements.len() - statements.len(),
+        libfuncs: removed_libfuncs.into_iter().map(|declaration| declaration.id).collect(),
+        types: removed_types.into_iter().map(|declaration| declaration.id).collect(),
+    };
+    Ok((Program { type_declarations, libfunc_declarations, statements, funcs }, report))
+}
+
+/// Returns the user function called by the given libfunc, if it is a function call.
+fn called_function(program: &Program, libfunc_id: &ConcreteLibfuncId) -> Option<FunctionId> {
+    let declaration =
+        program.libfunc_declarations.iter().find(|declaration| declaration.id == *libfunc_id)?;
+    if declaration.long_id.generic_id.0 != FunctionCallLibfunc::STR_ID {
+        return None;
+    }
+    match declaration.long_id.generic_args.as_slice() {
+        [GenericArg::UserFunc(function_id)] => Some(function_id.clone()),
+        _ => None,
+    }
+}
+
+/// Returns the types appearing in the given generic args.
+fn generic_args_types(args: &[GenericArg]) -> impl Iterator<Item = ConcreteTypeId> + '_ {
+    args.iter().filter_map(|arg| match arg {
+        GenericArg::Type(ty) => Some(ty.clone()),
+        _ => None,
+    })
+}
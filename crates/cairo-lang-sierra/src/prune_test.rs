@@ -0,0 +1,122 @@
+use indoc::indoc;
+use test_log::test;
+
+use super::{prune_unreachable_code, PruneError, PruneReport};
+use crate::ProgramParser;
+
+#[test]
+fn prune_unreachable_function() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            type u128 = u128;
+            type NonZeroU128 = NonZero<u128>;
+            libfunc call_helper = function_call<user@helper>;
+            libfunc felt252_const_1 = felt252_const<1>;
+            libfunc u128_const_2 = u128_const<2>;
+            libfunc u128_is_zero = u128_is_zero;
+            libfunc drop_nz_u128 = drop<NonZeroU128>;
+            call_helper() -> ([0]);
+            return([0]);
+            felt252_const_1() -> ([0]);
+            return([0]);
+            u128_const_2() -> ([0]);
+            u128_is_zero([0]) { fallthrough() 7([1]) };
+            return();
+            drop_nz_u128([1]) -> ();
+            return();
+            main@0() -> (felt252);
+            helper@2() -> (felt252);
+            unused@4() -> ();
+        "})
+        .unwrap();
+    let (pruned, report) = prune_unreachable_code(&program, &["main".into()]).unwrap();
+    assert_eq!(
+        pruned,
+        ProgramParser::new()
+            .parse(indoc! {"
+                type felt252 = felt252;
+                libfunc call_helper = function_call<user@helper>;
+                libfunc felt252_const_1 = felt252_const<1>;
+                call_helper() -> ([0]);
+                return([0]);
+                felt252_const_1() -> ([0]);
+                return([0]);
+                main@0() -> (felt252);
+                helper@2() -> (felt252);
+            "})
+            .unwrap()
+    );
+    assert_eq!(
+        report,
+        PruneReport {
+            functions: vec!["unused".into()],
+            n_statements: 5,
+            libfuncs: vec!["u128_const_2".into(), "u128_is_zero".into(), "drop_nz_u128".into()],
+            types: vec!["u128".into(), "NonZeroU128".into()],
+        }
+    );
+}
+
+#[test]
+fn prune_remaps_branch_targets() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type u128 = u128;
+            type NonZeroU128 = NonZero<u128>;
+            libfunc u128_const_2 = u128_const<2>;
+            libfunc u128_is_zero = u128_is_zero;
+            libfunc drop_nz_u128 = drop<NonZeroU128>;
+            return();
+            u128_const_2() -> ([0]);
+            u128_is_zero([0]) { fallthrough() 4([1]) };
+            return();
+            drop_nz_u128([1]) -> ();
+            return();
+            unused@0() -> ();
+            main@1() -> ();
+        "})
+        .unwrap();
+    let (pruned, report) = prune_unreachable_code(&program, &["main".into()]).unwrap();
+    assert_eq!(
+        pruned,
+        ProgramParser::new()
+            .parse(indoc! {"
+                type u128 = u128;
+                type NonZeroU128 = NonZero<u128>;
+                libfunc u128_const_2 = u128_const<2>;
+                libfunc u128_is_zero = u128_is_zero;
+                libfunc drop_nz_u128 = drop<NonZeroU128>;
+                u128_const_2() -> ([0]);
+                u128_is_zero([0]) { fallthrough() 3([1]) };
+                return();
+                drop_nz_u128([1]) -> ();
+                return();
+                main@0() -> ();
+            "})
+            .unwrap()
+    );
+    assert_eq!(
+        report,
+        PruneReport {
+            functions: vec!["unused".into()],
+            n_statements: 1,
+            libfuncs: vec![],
+            types: vec![],
+        }
+    );
+}
+
+#[test]
+fn prune_missing_entry_point() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            return();
+            main@0() -> ();
+        "})
+        .unwrap();
+    assert_eq!(
+        prune_unreachable_code(&program, &["other".into()]),
+        Err(PruneError::MissingEntryPoint("other".into()))
+    );
+}
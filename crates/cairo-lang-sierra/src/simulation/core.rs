@@ -23,10 +23,10 @@ use crate::extensions::felt252::{
 use crate::extensions::felt252_dict::Felt252DictConcreteLibfunc;
 use crate::extensions::function_call::FunctionCallConcreteLibfunc;
 use crate::extensions::gas::GasConcreteLibfunc::{
-    BuiltinWithdrawGas, GetAvailableGas, GetBuiltinCosts, RedepositGas, WithdrawGas,
+    BuiltinWithdrawGas, Checkpoint, GetAvailableGas, GetBuiltinCosts, RedepositGas, WithdrawGas,
 };
 use crate::extensions::int::unsigned::{
-    Uint16Concrete, Uint32Concrete, Uint64Concrete, Uint8Concrete,
+    Uint16Concrete, Uint32Concrete, Uint64Concrete, Uint8Concrete, UintUncheckedOperator,
 };
 use crate::extensions::int::unsigned128::Uint128Concrete;
 use crate::extensions::int::{IntConstConcreteLibfunc, IntOperator};
@@ -43,6 +43,11 @@ fn get_beta() -> BigInt {
         .unwrap()
 }
 
+/// Returns the prime of the felt252 field.
+fn get_prime() -> BigInt {
+    (BigInt::from(1) << 251) + (BigInt::from(17) << 192) + 1
+}
+
 // TODO(spapini): Proper errors when converting from bigint to u128.
 /// Simulates the run of a single libfunc. Returns the value representations of the outputs, and
 /// the chosen branch given the inputs.
@@ -118,7 +123,8 @@ pub fn simulate<
             }?;
             Ok((vec![CoreValue::GasBuiltin(gas_counter + count)], 0))
         }
-        Gas(GetAvailableGas(_)) => {
+        // The gas wallet is not simulated, so a checkpoint is the value of the gas counter.
+        Gas(GetAvailableGas(_) | Checkpoint(_)) => {
             let gas_counter = match &inputs[..] {
                 [CoreValue::GasBuiltin(value)] => Ok(value),
                 [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -173,20 +179,22 @@ pub fn simulate<
                 _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
             }
         }
-        Array(ArrayConcreteLibfunc::Get(_)) => match &inputs[..] {
-            [CoreValue::RangeCheck, CoreValue::Array(_), CoreValue::Uint64(_)] => {
-                let mut iter = inputs.into_iter();
-                iter.next(); // Ignore range check.
-                let arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
-                let idx = extract_matches!(iter.next().unwrap(), CoreValue::Uint64) as usize;
-                match arr.get(idx).cloned() {
-                    Some(element) => Ok((vec![CoreValue::RangeCheck, element], 0)),
-                    None => Ok((vec![CoreValue::RangeCheck], 1)),
+        Array(ArrayConcreteLibfunc::Get(_) | ArrayConcreteLibfunc::GetUsize(_)) => {
+            match &inputs[..] {
+                [CoreValue::RangeCheck, CoreValue::Array(_), CoreValue::Uint64(_)] => {
+                    let mut iter = inputs.into_iter();
+                    iter.next(); // Ignore range check.
+                    let arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
+                    let idx = extract_matches!(iter.next().unwrap(), CoreValue::Uint64) as usize;
+                    match arr.get(idx).cloned() {
+                        Some(element) => Ok((vec![CoreValue::RangeCheck, element], 0)),
+                        None => Ok((vec![CoreValue::RangeCheck], 1)),
+                    }
                 }
+                [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
             }
-            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
-            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-        },
+        }
         Array(ArrayConcreteLibfunc::Slice(_)) => match &inputs[..] {
             [
                 CoreValue::RangeCheck,
@@ -218,8 +226,20 @@ pub fn simulate<
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Array(ArrayConcreteLibfunc::LenExceeds(libfunc)) => match &inputs[..] {
+            [CoreValue::RangeCheck, CoreValue::Array(arr)] => {
+                let exceeds = arr.len().to_bigint().unwrap() > libfunc.max_len;
+                Ok((vec![CoreValue::RangeCheck], usize::from(exceeds)))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Array(ArrayConcreteLibfunc::SnapshotPopFront(_)) => todo!(),
         Array(ArrayConcreteLibfunc::SnapshotPopBack(_)) => todo!(),
+        Array(ArrayConcreteLibfunc::ExtendFromSpan(_)) => todo!(),
+        Array(ArrayConcreteLibfunc::SpanEq(_)) => todo!(),
+        Array(ArrayConcreteLibfunc::SpanBinarySearch(_)) => todo!(),
+        Array(ArrayConcreteLibfunc::SpanSortVerified(_)) => todo!(),
         Uint8(libfunc) => simulate_u8_libfunc(libfunc, &inputs),
         Uint16(libfunc) => simulate_u16_libfunc(libfunc, &inputs),
         Uint32(libfunc) => simulate_u32_libfunc(libfunc, &inputs),
@@ -314,12 +334,49 @@ pub fn simulate<
         CoreConcreteLibfunc::Poseidon(_) => {
             unimplemented!("Simulation of the Poseidon hash function is not implemented yet.");
         }
+        CoreConcreteLibfunc::Ecdsa(_) => {
+            unimplemented!("Simulation of ECDSA signature verification is not implemented yet.");
+        }
+        CoreConcreteLibfunc::Keccak(_) => {
+            unimplemented!("Simulation of the Keccak permutation is not implemented yet.");
+        }
+        CoreConcreteLibfunc::Circuit(_) => {
+            unimplemented!("Simulation of circuit evaluation is not implemented yet.");
+        }
+        CoreConcreteLibfunc::ConstAsSpan(_) => {
+            unimplemented!("Simulation of const data segments is not implemented yet.");
+        }
+        CoreConcreteLibfunc::Modular(_) => {
+            unimplemented!("Simulation of the mod builtins is not implemented yet.");
+        }
+        CoreConcreteLibfunc::InlineCasm(_) => {
+            unimplemented!("Simulation of inline CASM is not supported.");
+        }
+        CoreConcreteLibfunc::Instrumentation(_) => Ok((vec![], 0)),
+        CoreConcreteLibfunc::Bitfield(_) => {
+            unimplemented!("Simulation of the bitfield functions is not implemented yet.");
+        }
+        CoreConcreteLibfunc::Blake(_) => {
+            unimplemented!("Simulation of the Blake2s compression is not implemented yet.");
+        }
+        CoreConcreteLibfunc::BoundedInt(_) => {
+            unimplemented!("Simulation of the bounded integer functions is not implemented yet.");
+        }
+        CoreConcreteLibfunc::Encoding(_) => {
+            unimplemented!("Simulation of the encoding functions is not implemented yet.");
+        }
+        CoreConcreteLibfunc::Rlp(_) => {
+            unimplemented!("Simulation of the RLP functions is not implemented yet.");
+        }
         CoreConcreteLibfunc::StarkNet(_) => {
             unimplemented!("Simulation of the StarkNet functionalities is not implemented yet.")
         }
         CoreConcreteLibfunc::Nullable(_) => {
             unimplemented!("Simulation of nullable is not implemented yet.")
         }
+        CoreConcreteLibfunc::Oracle(_) => {
+            unimplemented!("Simulation of oracles is not supported.")
+        }
         CoreConcreteLibfunc::Debug(_) => {
             if inputs.len() == 1 {
                 let arr = extract_matches!(&inputs[0], CoreValue::Array);
@@ -347,6 +404,8 @@ pub fn simulate<
         },
         CoreConcreteLibfunc::Cast(_) => unimplemented!(),
         CoreConcreteLibfunc::Felt252DictEntry(_) => unimplemented!(),
+        CoreConcreteLibfunc::Uint96(_) => unimplemented!(),
+        CoreConcreteLibfunc::Bytes31(_) => unimplemented!(),
         CoreConcreteLibfunc::Uint256(_) => unimplemented!(),
         CoreConcreteLibfunc::Uint512(_) => unimplemented!(),
     }
@@ -448,7 +507,7 @@ fn simulate_u128_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint128Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint128(value)] => {
+            [CoreValue::Uint128(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -465,6 +524,19 @@ fn simulate_u128_libfunc(
             [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint128Concrete::UncheckedOperation(libfunc) => match inputs {
+            [CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)] => {
+                // Overflowing is undefined for unchecked operations, so wrapping is as good as any.
+                let value = match libfunc.operator {
+                    UintUncheckedOperator::Add => lhs.wrapping_add(*rhs),
+                    UintUncheckedOperator::Sub => lhs.wrapping_sub(*rhs),
+                    UintUncheckedOperator::Mul => lhs.wrapping_mul(*rhs),
+                };
+                Ok((vec![CoreValue::Uint128(value)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint128Concrete::Divmod(_) => match inputs {
             [CoreValue::RangeCheck, CoreValue::Uint128(lhs), CoreValue::NonZero(non_zero)] => {
                 if let CoreValue::Uint128(rhs) = **non_zero {
@@ -558,6 +630,19 @@ fn simulate_u8_libfunc(
             [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint8Concrete::UncheckedOperation(libfunc) => match inputs {
+            [CoreValue::Uint8(lhs), CoreValue::Uint8(rhs)] => {
+                // Overflowing is undefined for unchecked operations, so wrapping is as good as any.
+                let value = match libfunc.operator {
+                    UintUncheckedOperator::Add => lhs.wrapping_add(*rhs),
+                    UintUncheckedOperator::Sub => lhs.wrapping_sub(*rhs),
+                    UintUncheckedOperator::Mul => lhs.wrapping_mul(*rhs),
+                };
+                Ok((vec![CoreValue::Uint8(value)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint8Concrete::SquareRoot(_) => match inputs {
             [CoreValue::RangeCheck, CoreValue::Uint8(value)] => {
                 let root = BigInt::from(*value).sqrt();
@@ -576,7 +661,7 @@ fn simulate_u8_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint8Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint8(value)] => {
+            [CoreValue::Uint8(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -627,6 +712,19 @@ fn simulate_u16_libfunc(
             [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint16Concrete::UncheckedOperation(libfunc) => match inputs {
+            [CoreValue::Uint16(lhs), CoreValue::Uint16(rhs)] => {
+                // Overflowing is undefined for unchecked operations, so wrapping is as good as any.
+                let value = match libfunc.operator {
+                    UintUncheckedOperator::Add => lhs.wrapping_add(*rhs),
+                    UintUncheckedOperator::Sub => lhs.wrapping_sub(*rhs),
+                    UintUncheckedOperator::Mul => lhs.wrapping_mul(*rhs),
+                };
+                Ok((vec![CoreValue::Uint16(value)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint16Concrete::SquareRoot(_) => match inputs {
             [CoreValue::RangeCheck, CoreValue::Uint16(value)] => {
                 let root = BigInt::from(*value).sqrt();
@@ -645,7 +743,7 @@ fn simulate_u16_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint16Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint16(value)] => {
+            [CoreValue::Uint16(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -696,6 +794,19 @@ fn simulate_u32_libfunc(
             [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint32Concrete::UncheckedOperation(libfunc) => match inputs {
+            [CoreValue::Uint32(lhs), CoreValue::Uint32(rhs)] => {
+                // Overflowing is undefined for unchecked operations, so wrapping is as good as any.
+                let value = match libfunc.operator {
+                    UintUncheckedOperator::Add => lhs.wrapping_add(*rhs),
+                    UintUncheckedOperator::Sub => lhs.wrapping_sub(*rhs),
+                    UintUncheckedOperator::Mul => lhs.wrapping_mul(*rhs),
+                };
+                Ok((vec![CoreValue::Uint32(value)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint32Concrete::SquareRoot(_) => match inputs {
             [CoreValue::RangeCheck, CoreValue::Uint32(value)] => {
                 let root = BigInt::from(*value).sqrt();
@@ -714,7 +825,7 @@ fn simulate_u32_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint32Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint32(value)] => {
+            [CoreValue::Uint32(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -765,6 +876,19 @@ fn simulate_u64_libfunc(
             [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint64Concrete::UncheckedOperation(libfunc) => match inputs {
+            [CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)] => {
+                // Overflowing is undefined for unchecked operations, so wrapping is as good as any.
+                let value = match libfunc.operator {
+                    UintUncheckedOperator::Add => lhs.wrapping_add(*rhs),
+                    UintUncheckedOperator::Sub => lhs.wrapping_sub(*rhs),
+                    UintUncheckedOperator::Mul => lhs.wrapping_mul(*rhs),
+                };
+                Ok((vec![CoreValue::Uint64(value)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint64Concrete::SquareRoot(_) => match inputs {
             [CoreValue::RangeCheck, CoreValue::Uint64(value)] => {
                 let root = BigInt::from(*value).sqrt();
@@ -783,7 +907,7 @@ fn simulate_u64_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint64Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint64(value)] => {
+            [CoreValue::Uint64(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -870,11 +994,12 @@ fn simulate_felt252_libfunc(
         },
         Felt252Concrete::IsZero(_) => {
             match inputs {
-                [CoreValue::Felt252(value)] if value.is_zero() => {
+                // The values are not reduced, so they are zero if they are multiples of the prime.
+                [CoreValue::Felt252(value)] if (value % get_prime()).is_zero() => {
                     // Zero - jumping to the failure branch.
                     Ok((vec![], 0))
                 }
-                [CoreValue::Felt252(value)] if !value.is_zero() => {
+                [CoreValue::Felt252(value)] => {
                     // Non-zero - jumping to the success branch and providing a NonZero wrap to the
                     // given value.
                     Ok((vec![CoreValue::NonZero(Box::new(CoreValue::Felt252(value.clone())))], 1))
@@ -883,5 +1008,6 @@ fn simulate_felt252_libfunc(
                 _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
             }
         }
+        Felt252Concrete::Sqrt(_) => todo!("Support felt252_sqrt operation."),
     }
 }
@@ -7,20 +7,32 @@ use crate::program::{ConcreteTypeLongId, GenericArg};
 pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
     let mut elements = BiMap::new();
     elements.insert("T".into(), as_type_long_id("T", &[]));
+    elements.insert("u8".into(), as_type_long_id("u8", &[]));
     elements.insert("u32".into(), as_type_long_id("u32", &[]));
     elements.insert("u64".into(), as_type_long_id("u64", &[]));
+    elements.insert("u96".into(), as_type_long_id("u96", &[]));
+    elements.insert("bytes31".into(), as_type_long_id("bytes31", &[]));
     elements.insert("u128".into(), as_type_long_id("u128", &[]));
+    elements.insert("i8".into(), as_type_long_id("i8", &[]));
+    elements.insert("i16".into(), as_type_long_id("i16", &[]));
     elements.insert("felt252".into(), as_type_long_id("felt252", &[]));
     elements.insert("Tuple<>".into(), as_named_type_long_id("Struct", "Tuple", &[]));
     elements.insert(
         "U128AndFelt252".into(),
         as_named_type_long_id("Struct", "U128AndFelt252", &["u128", "felt252"]),
     );
+    elements
+        .insert("TupleU8U128".into(), as_named_type_long_id("Struct", "Tuple", &["u8", "u128"]));
+    elements.insert(
+        "TupleU128U128".into(),
+        as_named_type_long_id("Struct", "Tuple", &["u128", "u128"]),
+    );
     elements
         .insert("Option".into(), as_named_type_long_id("Enum", "Option", &["felt252", "Tuple<>"]));
     elements.insert("NonZeroFelt252".into(), as_type_long_id("NonZero", &["felt252"]));
     elements.insert("NonZeroU128".into(), as_type_long_id("NonZero", &["u128"]));
     elements.insert("ArrayFelt252".into(), as_type_long_id("Array", &["felt252"]));
+    elements.insert("ArrayU8".into(), as_type_long_id("Array", &["u8"]));
     elements.insert("ArrayU128".into(), as_type_long_id("Array", &["u128"]));
     elements.insert("BoxU128".into(), as_type_long_id("Box", &["u128"]));
     elements.insert("UninitializedFelt252".into(), as_type_long_id("Uninitialized", &["felt252"]));
@@ -28,11 +40,16 @@ pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
     elements.insert("GasBuiltin".into(), as_type_long_id("GasBuiltin", &[]));
     elements.insert("RangeCheck".into(), as_type_long_id("RangeCheck", &[]));
     elements.insert("System".into(), as_type_long_id("System", &[]));
+    elements.insert("Pedersen".into(), as_type_long_id("Pedersen", &[]));
+    elements.insert("Poseidon".into(), as_type_long_id("Poseidon", &[]));
+    elements.insert("BuiltinCosts".into(), as_type_long_id("BuiltinCosts", &[]));
     elements.insert("StorageBaseAddress".into(), as_type_long_id("StorageBaseAddress", &[]));
     elements.insert("StorageAddress".into(), as_type_long_id("StorageAddress", &[]));
     elements.insert("ContractAddress".into(), as_type_long_id("ContractAddress", &[]));
     elements.insert("SnapshotRangeCheck".into(), as_type_long_id("Snapshot", &["RangeCheck"]));
+    elements.insert("SnapshotArrayU8".into(), as_type_long_id("Snapshot", &["ArrayU8"]));
     elements.insert("SnapshotArrayU128".into(), as_type_long_id("Snapshot", &["ArrayU128"]));
+    elements.insert("SnapshotArrayFelt252".into(), as_type_long_id("Snapshot", &["ArrayFelt252"]));
     elements.insert("SnapshotU128".into(), as_type_long_id("Snapshot", &["u128"]));
     elements.insert(
         "NonDupStruct".into(),
@@ -44,6 +61,23 @@ pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
         as_named_type_long_id("Enum", "NonDupEnum", &["felt252", "RangeCheck"]),
     );
     elements.insert("SnapshotNonDupEnum".into(), as_type_long_id("Snapshot", &["NonDupEnum"]));
+    elements.insert("Digit".into(), as_bounded_int_long_id(0, 9));
+    elements.insert("NonZeroDigit".into(), as_bounded_int_long_id(1, 9));
+    elements.insert("DigitsSum".into(), as_bounded_int_long_id(1, 18));
+    elements.insert("DigitsDiff".into(), as_bounded_int_long_id(-9, 8));
+    elements.insert("DigitsProduct".into(), as_bounded_int_long_id(0, 81));
+    elements.insert("DigitsRemainder".into(), as_bounded_int_long_id(0, 8));
+    elements.insert("In0".into(), as_circuit_input_long_id(0));
+    elements.insert("In1".into(), as_circuit_input_long_id(1));
+    elements.insert("Sum".into(), as_type_long_id("AddModGate", &["In0", "In1"]));
+    elements.insert("InverseSum".into(), as_type_long_id("InverseGate", &["Sum"]));
+    elements.insert(
+        "CircuitOutputs".into(),
+        as_named_type_long_id("Struct", "Tuple", &["InverseSum", "In0"]),
+    );
+    elements
+        .insert("MissingInputOutputs".into(), as_named_type_long_id("Struct", "Tuple", &["In1"]));
+    elements.insert("ConstDigits".into(), as_const_array_long_id("u8", &[1, 2, 3]));
     elements
 }
 
@@ -54,6 +88,31 @@ fn as_type_long_id(name: &str, args: &[&str]) -> ConcreteTypeLongId {
     }
 }
 
+fn as_bounded_int_long_id(min: i64, max: i64) -> ConcreteTypeLongId {
+    ConcreteTypeLongId {
+        generic_id: "BoundedInt".into(),
+        generic_args: vec![GenericArg::Value(min.into()), GenericArg::Value(max.into())],
+    }
+}
+
+fn as_circuit_input_long_id(index: i64) -> ConcreteTypeLongId {
+    ConcreteTypeLongId {
+        generic_id: "CircuitInput".into(),
+        generic_args: vec![GenericArg::Value(index.into())],
+    }
+}
+
+fn as_const_array_long_id(ty: &str, values: &[i64]) -> ConcreteTypeLongId {
+    ConcreteTypeLongId {
+        generic_id: "ConstArray".into(),
+        generic_args: chain!(
+            [GenericArg::Type(ConcreteTypeId::from(ty))],
+            values.iter().map(|value| GenericArg::Value((*value).into()))
+        )
+        .collect(),
+    }
+}
+
 fn as_named_type_long_id(genetic_name: &str, user_name: &str, args: &[&str]) -> ConcreteTypeLongId {
     ConcreteTypeLongId {
         generic_id: genetic_name.into(),
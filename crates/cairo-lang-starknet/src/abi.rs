@@ -22,8 +22,8 @@ use thiserror::Error;
 
 use crate::plugin::aux_data::StarkNetEventAuxData;
 use crate::plugin::consts::{
-    CONSTRUCTOR_ATTR, CONTRACT_STATE_NAME, EVENT_ATTR, EVENT_TYPE_NAME, EXTERNAL_ATTR,
-    INTERFACE_ATTR, L1_HANDLER_ATTR,
+    CONSTRUCTOR_ATTR, CONTRACT_STATE_NAME, EVENT_ATTR, EVENT_TRAIT_NAME, EVENT_TYPE_NAME,
+    EXTERNAL_ATTR, INTERFACE_ATTR, L1_HANDLER_ATTR,
 };
 use crate::plugin::events::{EventData, EventFieldKind};
 
@@ -130,6 +130,10 @@ impl AbiBuilder {
             let Some(aux_data) = mapper.0.as_any(
                             ).downcast_ref::<StarkNetEventAuxData>() else { continue; };
             let concrete_trait_id = db.impl_def_concrete_trait(impl_id)?;
+            // The generated file of an enum event also contains the `Into` impls of its variants.
+            if concrete_trait_id.trait_id(db).name(db.upcast()) != EVENT_TRAIT_NAME {
+                continue;
+            }
             let event_type =
                 extract_matches!(concrete_trait_id.generic_args(db)[0], GenericArgumentId::Type);
             builder.event_derive_data.insert(event_type, aux_data.event_data.clone());
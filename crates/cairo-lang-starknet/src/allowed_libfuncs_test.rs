@@ -26,6 +26,35 @@ fn experimental_list_includes_all() {
         "set_signature",
         "pop_log",
         "get_available_gas",
+        "gas_checkpoint",
+        "inline_casm",
+        "oracle_call",
+        "instrumentation_hit",
+        "u8_unchecked_add",
+        "u8_unchecked_sub",
+        "u8_unchecked_mul",
+        "u16_unchecked_add",
+        "u16_unchecked_sub",
+        "u16_unchecked_mul",
+        "u32_unchecked_add",
+        "u32_unchecked_sub",
+        "u32_unchecked_mul",
+        "u64_unchecked_add",
+        "u64_unchecked_sub",
+        "u64_unchecked_mul",
+        "u128_unchecked_add",
+        "u128_unchecked_sub",
+        "u128_unchecked_mul",
+        "ecdsa_verify",
+        "keccak_permutation",
+        "u384_add_mod",
+        "u384_mul_mod",
+        "init_circuit_data",
+        "add_circuit_input",
+        "get_circuit_descriptor",
+        "eval_circuit",
+        "get_circuit_output",
+        "const_as_span",
     ];
     pretty_assertions::assert_eq!(
         lookup_allowed_libfuncs_list(ListSelector::ListName(BUILTIN_ALL_LIBFUNCS_LIST.to_string()))
@@ -4,40 +4,35 @@ mod test;
 
 use cairo_lang_casm::hints::{Hint, PythonicHint};
 use cairo_lang_sierra::extensions::array::ArrayType;
-use cairo_lang_sierra::extensions::bitwise::BitwiseType;
-use cairo_lang_sierra::extensions::ec::EcOpType;
 use cairo_lang_sierra::extensions::enm::EnumType;
 use cairo_lang_sierra::extensions::felt252::Felt252Type;
 use cairo_lang_sierra::extensions::gas::{CostTokenType, GasBuiltinType};
-use cairo_lang_sierra::extensions::pedersen::PedersenType;
-use cairo_lang_sierra::extensions::poseidon::PoseidonType;
-use cairo_lang_sierra::extensions::range_check::RangeCheckType;
-use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
 use cairo_lang_sierra::extensions::snapshot::SnapshotType;
 use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
 use cairo_lang_sierra::extensions::structure::StructType;
 use cairo_lang_sierra::extensions::NamedType;
 use cairo_lang_sierra::ids::{ConcreteTypeId, GenericTypeId};
 use cairo_lang_sierra::program::{ConcreteTypeLongId, GenericArg, TypeDeclaration};
-use cairo_lang_sierra_to_casm::compiler::CompilationError;
+use cairo_lang_sierra_to_casm::builtins::{builtin_info, is_builtin_order};
+use cairo_lang_sierra_to_casm::compiler::{AssembledCairoProgram, CompilationError};
 use cairo_lang_sierra_to_casm::metadata::{
     calc_metadata, MetadataComputationConfig, MetadataError,
 };
 use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint, BigUintAsHex};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
-use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
-use convert_case::{Case, Casing};
 use itertools::{chain, Itertools};
 use num_bigint::BigUint;
 use num_integer::Integer;
 use num_traits::{Num, Signed};
 use serde::{Deserialize, Serialize};
+use starknet_crypto::{poseidon_hash_many, FieldElement};
 use thiserror::Error;
 
 use crate::allowed_libfuncs::AllowedLibfuncsError;
 use crate::compiler_version::current_compiler_version_id;
+use crate::contract::starknet_keccak;
 use crate::contract_class::{ContractClass, ContractEntryPoint};
-use crate::felt252_serde::{sierra_from_felt252s, Felt252SerdeError};
+use crate::felt252_serde::Felt252SerdeError;
 
 /// The expected gas cost of an entrypoint.
 pub const ENTRY_POINT_COST: i32 = 10000;
@@ -225,7 +220,7 @@ impl CasmContractClass {
             }
         }
 
-        let (_, _, program) = sierra_from_felt252s(&contract_class.sierra_program)?;
+        let program = contract_class.extract_sierra_program()?;
         for entry_points in [
             &contract_class.entry_points_by_type.constructor,
             &contract_class.entry_points_by_type.external,
@@ -249,6 +244,7 @@ impl CasmContractClass {
             function_set_costs: entrypoint_ids
                 .map(|id| (id, [(CostTokenType::Const, ENTRY_POINT_COST)].into()))
                 .collect(),
+            ..Default::default()
         };
         let metadata = calc_metadata(&program, metadata_computation_config)?;
 
@@ -256,34 +252,18 @@ impl CasmContractClass {
         let cairo_program =
             cairo_lang_sierra_to_casm::compiler::compile(&program, &metadata, gas_usage_check)?;
 
-        let mut bytecode = vec![];
-        let mut hints = vec![];
-        for instruction in cairo_program.instructions {
-            if !instruction.hints.is_empty() {
-                hints.push((bytecode.len(), instruction.hints.clone()))
-            }
-            bytecode.extend(instruction.assemble().encode().iter().map(|big_int| {
+        // The const data segments are assembled right after the code, as part of the bytecode.
+        let AssembledCairoProgram { bytecode, hints } = cairo_program.assemble();
+        let bytecode = bytecode
+            .iter()
+            .map(|big_int| {
                 let (_q, reminder) = big_int.magnitude().div_rem(&prime);
 
                 BigUintAsHex {
                     value: if big_int.is_negative() { &prime - reminder } else { reminder },
                 }
-            }))
-        }
-
-        let builtin_types = UnorderedHashSet::<GenericTypeId>::from_iter(
-            [
-                RangeCheckType::id(),
-                BitwiseType::id(),
-                PedersenType::id(),
-                EcOpType::id(),
-                PoseidonType::id(),
-                SegmentArenaType::id(),
-                GasBuiltinType::id(),
-                SystemType::id(),
-            ]
-            .into_iter(),
-        );
+            })
+            .collect();
 
         let as_casm_entry_point = |contract_entry_point: ContractEntryPoint| {
             let Some(function) = program.funcs.get(contract_entry_point.function_idx) else {
@@ -315,7 +295,8 @@ impl CasmContractClass {
             }
 
             for type_id in input_builtins.iter() {
-                if !builtin_types.contains(type_resolver.get_generic_id(type_id)) {
+                let builtin = builtin_info(type_resolver.get_generic_id(type_id));
+                if !builtin.map_or(false, |builtin| builtin.starknet) {
                     return Err(StarknetSierraCompilationError::InvalidBuiltinType(
                         type_id.clone(),
                     ));
@@ -324,9 +305,13 @@ impl CasmContractClass {
             let (system_ty, builtins) = input_builtins.split_last().unwrap();
             let (gas_ty, builtins) = builtins.split_last().unwrap();
 
-            // Check that the last builtins are gas and system.
+            // Check that the builtins are in the expected order, and that the last builtins are
+            // gas and system.
             if *type_resolver.get_generic_id(system_ty) != SystemType::id()
                 || *type_resolver.get_generic_id(gas_ty) != GasBuiltinType::id()
+                || !is_builtin_order(
+                    input_builtins.iter().map(|type_id| type_resolver.get_generic_id(type_id)),
+                )
             {
                 return Err(
                     StarknetSierraCompilationError::InvalidEntryPointSignatureWrongBuiltinsOrder,
@@ -336,7 +321,7 @@ impl CasmContractClass {
             let builtins = builtins
                 .iter()
                 .map(|type_id| {
-                    type_resolver.get_generic_id(type_id).0.as_str().to_case(Case::Snake)
+                    builtin_info(type_resolver.get_generic_id(type_id)).unwrap().name.into()
                 })
                 .collect_vec();
 
@@ -415,3 +400,127 @@ pub struct CasmContractEntryPoints {
     #[serde(rename = "CONSTRUCTOR")]
     pub constructor: Vec<CasmContractEntryPoint>,
 }
+
+/// The version of the compiled class, as encoded in the compiled class hash.
+pub const COMPILED_CLASS_VERSION: &str = "COMPILED_CLASS_V1";
+
+/// The structure hashed by the Starknet OS to compute the compiled class hash of a
+/// [CasmContractClass], enabling independent recomputation and verification of the hash.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CasmContractClassHashPreimage {
+    /// The short string encoding of [COMPILED_CLASS_VERSION].
+    pub compiled_class_version: BigUintAsHex,
+    pub entry_points_by_type: CasmContractEntryPointsHashPreimage,
+    /// The bytecode of the class, split into the segments hashed separately.
+    /// The bytecode is currently always a single segment.
+    pub bytecode_segments: Vec<Vec<BigUintAsHex>>,
+    /// The starknet keccak of the JSON serialization of the class's hints.
+    /// Not part of the hash computed by the OS, but binds the hints to the preimage.
+    pub hints_digest: BigUintAsHex,
+}
+
+/// The hashed data of a single entry point of a [CasmContractClass].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CasmContractEntryPointHashPreimage {
+    pub selector: BigUintAsHex,
+    pub offset: usize,
+    /// The short string encodings of the entry point's builtins.
+    pub builtins: Vec<BigUintAsHex>,
+}
+impl From<&CasmContractEntryPoint> for CasmContractEntryPointHashPreimage {
+    fn from(entry_point: &CasmContractEntryPoint) -> Self {
+        Self {
+            selector: BigUintAsHex { value: entry_point.selector.clone() },
+            offset: entry_point.offset,
+            builtins: entry_point.builtins.iter().map(|name| encode_short_string(name)).collect(),
+        }
+    }
+}
+
+/// The hashed entry points of a [CasmContractClass], in the order they are hashed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CasmContractEntryPointsHashPreimage {
+    #[serde(rename = "EXTERNAL")]
+    pub external: Vec<CasmContractEntryPointHashPreimage>,
+    #[serde(rename = "L1_HANDLER")]
+    pub l1_handler: Vec<CasmContractEntryPointHashPreimage>,
+    #[serde(rename = "CONSTRUCTOR")]
+    pub constructor: Vec<CasmContractEntryPointHashPreimage>,
+}
+
+impl CasmContractClass {
+    /// Returns the preimage of the compiled class hash of the class.
+    pub fn hash_preimage(&self) -> CasmContractClassHashPreimage {
+        let entry_points = |entry_points: &[CasmContractEntryPoint]| {
+            entry_points.iter().map(CasmContractEntryPointHashPreimage::from).collect()
+        };
+        CasmContractClassHashPreimage {
+            compiled_class_version: encode_short_string(COMPILED_CLASS_VERSION),
+            entry_points_by_type: CasmContractEntryPointsHashPreimage {
+                external: entry_points(&self.entry_points_by_type.external),
+                l1_handler: entry_points(&self.entry_points_by_type.l1_handler),
+                constructor: entry_points(&self.entry_points_by_type.constructor),
+            },
+            bytecode_segments: vec![self.bytecode.clone()],
+            hints_digest: BigUintAsHex {
+                value: starknet_keccak(serde_json::to_string(&self.hints).unwrap().as_bytes()),
+            },
+        }
+    }
+}
+
+impl CasmContractClassHashPreimage {
+    /// Returns the compiled class hash, computed from the preimage as done by the Starknet OS.
+    /// The hints digest is not part of the hash, and segmented bytecode is hashed as the
+    /// concatenation of its segments.
+    pub fn compiled_class_hash(&self) -> Result<BigUint, StarknetSierraCompilationError> {
+        let entry_points_hash = |entry_points: &[CasmContractEntryPointHashPreimage]|
+         -> Result<FieldElement, StarknetSierraCompilationError> {
+            let mut elements = vec![];
+            for entry_point in entry_points {
+                elements.push(to_field_element(&entry_point.selector)?);
+                elements.push(FieldElement::from(entry_point.offset));
+                elements.push(poseidon_hash_many(
+                    &entry_point
+                        .builtins
+                        .iter()
+                        .map(to_field_element)
+                        .collect::<Result<Vec<_>, _>>()?,
+                ));
+            }
+            Ok(poseidon_hash_many(&elements))
+        };
+        let bytecode = self
+            .bytecode_segments
+            .iter()
+            .flatten()
+            .map(to_field_element)
+            .collect::<Result<Vec<_>, _>>()?;
+        let hash = poseidon_hash_many(&[
+            to_field_element(&self.compiled_class_version)?,
+            entry_points_hash(&self.entry_points_by_type.external)?,
+            entry_points_hash(&self.entry_points_by_type.l1_handler)?,
+            entry_points_hash(&self.entry_points_by_type.constructor)?,
+            poseidon_hash_many(&bytecode),
+        ]);
+        Ok(BigUint::from_bytes_be(&hash.to_bytes_be()))
+    }
+}
+
+impl CasmContractClass {
+    /// Returns the compiled class hash of the class.
+    pub fn compiled_class_hash(&self) -> Result<BigUint, StarknetSierraCompilationError> {
+        self.hash_preimage().compiled_class_hash()
+    }
+}
+
+/// Converts a felt252 value to a [FieldElement], failing if it is out of range.
+fn to_field_element(value: &BigUintAsHex) -> Result<FieldElement, StarknetSierraCompilationError> {
+    FieldElement::from_byte_slice_be(&value.value.to_bytes_be())
+        .map_err(|_| StarknetSierraCompilationError::ValueOutOfRange)
+}
+
+/// Encodes an ascii string as a Cairo short string.
+fn encode_short_string(s: &str) -> BigUintAsHex {
+    BigUintAsHex { value: BigUint::from_bytes_be(s.as_bytes()) }
+}
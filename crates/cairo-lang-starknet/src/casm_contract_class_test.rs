@@ -50,3 +50,30 @@ fn test_casm_contract_from_contract_class_failure(example_file_name: &str) {
         Err(StarknetSierraCompilationError::ValueOutOfRange)
     );
 }
+
+/// Tests that the compiled class hash preimage of <test_case>.cairo is the same as in
+/// <test_case>.class_hash_preimage.json.
+#[test_case("minimal_contract")]
+#[test_case("hello_starknet")]
+fn test_casm_contract_hash_preimage(example_file_name: &str) {
+    let contract_class = get_test_contract(format!("{example_file_name}.cairo").as_str());
+    let casm_contract = CasmContractClass::from_contract_class(contract_class, false).unwrap();
+
+    compare_contents_or_fix_with_path(
+        &get_example_file_path(format!("{example_file_name}.class_hash_preimage.json").as_str()),
+        serde_json::to_string_pretty(&casm_contract.hash_preimage()).unwrap() + "\n",
+    );
+}
+
+/// Tests the compiled class hash of <test_case>.cairo.
+#[test_case(
+    "minimal_contract",
+    "0x46f2882281342dea7694207216f95d925ba08ef4be0cff5e81e9057f49ef3c2"
+)]
+#[test_case("hello_starknet", "0xdf4d3042eec107abe704619f13d92bbe01a58029311b7a1886b23dcbb4ea87")]
+fn test_compiled_class_hash(example_file_name: &str, expected_hash: &str) {
+    let contract_class = get_test_contract(format!("{example_file_name}.cairo").as_str());
+    let casm_contract = CasmContractClass::from_contract_class(contract_class, false).unwrap();
+
+    assert_eq!(format!("{:#x}", casm_contract.compiled_class_hash().unwrap()), expected_hash);
+}
@@ -131,6 +131,46 @@ fn get_generated_contract_module(
     }
 }
 
+/// Returns the auxiliary data the plugins attached to the code of the given contract, grouped by
+/// the names of the plugins.
+pub fn get_contract_aux_data(
+    db: &dyn SemanticGroup,
+    contract: &ContractDeclaration,
+) -> anyhow::Result<OrderedHashMap<String, Vec<serde_json::Value>>> {
+    let generated_module_id = get_generated_contract_module(db, contract)?;
+    let mut aux_data = OrderedHashMap::<String, Vec<serde_json::Value>>::default();
+    let mut modules = vec![generated_module_id];
+    while let Some(module_id) = modules.pop() {
+        let generated_file_infos = db
+            .module_generated_file_infos(module_id)
+            .to_option()
+            .with_context(|| "Failed to get the generated files of a contract module.")?;
+        // The first generated file info of a submodule is the info of the file the submodule was
+        // generated from, which was already handled as part of its parent module. The exception
+        // is the contract module itself, as its parent module is not part of the contract.
+        let skip = if module_id == generated_module_id { 0 } else { 1 };
+        for generated_file_info in generated_file_infos.iter().skip(skip) {
+            let Some(generated_file_info) = generated_file_info else { continue; };
+            let Some(mapper) = generated_file_info.aux_data.0.as_any(
+            ).downcast_ref::<DynPluginAuxData>() else { continue; };
+            let Some(artifact_aux_data) = mapper.artifact_aux_data() else { continue; };
+            aux_data
+                .entry(artifact_aux_data.plugin_name.to_string())
+                .or_default()
+                .push(artifact_aux_data.data);
+        }
+        modules.extend(
+            db.module_submodules_ids(module_id)
+                .to_option()
+                .with_context(|| "Failed to get the submodules of a contract module.")?
+                .into_iter()
+                .rev()
+                .map(ModuleId::Submodule),
+        );
+    }
+    Ok(aux_data)
+}
+
 /// Sierra informaton of a contract.
 pub struct ContractInfo {
     /// Sierra function of the constructor.
@@ -162,14 +202,7 @@ fn analyze_contract<T: SierraIdReplacer>(
     contract: &ContractDeclaration,
     replacer: &T,
 ) -> anyhow::Result<(cairo_felt::Felt252, ContractInfo)> {
-    // Extract class hash.
-    let item =
-        db.module_item_by_name(contract.module_id(), "TEST_CLASS_HASH".into()).unwrap().unwrap();
-    let constant_id = extract_matches!(item, ModuleItemId::Constant);
-    let value =
-        extract_matches!(db.constant_semantic_data(constant_id).unwrap().value, Expr::Literal)
-            .value;
-    let class_hash = Felt252::try_from(value).unwrap();
+    let class_hash = get_test_class_hash(db.upcast(), contract);
 
     // Extract functions.
     let SemanticEntryPoints { external, l1_handler, constructor } =
@@ -192,6 +225,17 @@ fn analyze_contract<T: SierraIdReplacer>(
     Ok((class_hash, contract_info))
 }
 
+/// Returns the class hash a contract has in tests, as set by its `TEST_CLASS_HASH` constant.
+pub fn get_test_class_hash(db: &dyn SemanticGroup, contract: &ContractDeclaration) -> Felt252 {
+    let item =
+        db.module_item_by_name(contract.module_id(), "TEST_CLASS_HASH".into()).unwrap().unwrap();
+    let constant_id = extract_matches!(item, ModuleItemId::Constant);
+    let value =
+        extract_matches!(db.constant_semantic_data(constant_id).unwrap().value, Expr::Literal)
+            .value;
+    Felt252::try_from(value).unwrap()
+}
+
 /// Converts a function to a Sierra function.
 /// Returns the selector and the sierra function id.
 pub fn get_selector_and_sierra_function<T: SierraIdReplacer>(
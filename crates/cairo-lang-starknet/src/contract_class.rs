@@ -9,10 +9,12 @@ use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_sierra::program::Program;
 use cairo_lang_sierra_generator::canonical_id_replacer::CanonicalReplacer;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::{replace_sierra_ids_in_program, SierraIdReplacer};
 use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint, BigUintAsHex};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{chain, Itertools};
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
@@ -24,9 +26,10 @@ use crate::allowed_libfuncs::{
 };
 use crate::compiler_version::{self};
 use crate::contract::{
-    find_contracts, get_module_functions, get_selector_and_sierra_function, ContractDeclaration,
+    find_contracts, get_contract_aux_data, get_module_functions, get_selector_and_sierra_function,
+    ContractDeclaration,
 };
-use crate::felt252_serde::sierra_to_felt252s;
+use crate::felt252_serde::{sierra_from_felt252s, sierra_to_felt252s, Felt252SerdeError};
 use crate::plugin::consts::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
 use crate::plugin::StarkNetPlugin;
 
@@ -50,6 +53,21 @@ pub struct ContractClass {
     pub contract_class_version: String,
     pub entry_points_by_type: ContractEntryPoints,
     pub abi: Option<Contract>,
+    /// Auxiliary data attached to the contract by compiler plugins, grouped by plugin name.
+    #[serde(default, skip_serializing_if = "OrderedHashMap::is_empty")]
+    pub aux_data: OrderedHashMap<String, Vec<serde_json::Value>>,
+}
+
+impl ContractClass {
+    /// Extracts the Sierra program from the contract class, with the debug names of its ids
+    /// populated from the debug info, if there is one.
+    pub fn extract_sierra_program(&self) -> Result<Program, Felt252SerdeError> {
+        let (_, _, mut sierra_program) = sierra_from_felt252s(&self.sierra_program)?;
+        if let Some(debug_info) = &self.sierra_program_debug_info {
+            debug_info.populate(&mut sierra_program);
+        }
+        Ok(sierra_program)
+    }
 }
 
 const DEFAULT_CONTRACT_CLASS_VERSION: &str = "0.1.0";
@@ -173,9 +191,11 @@ fn compile_contract_with_prepared_and_checked_db(
         .to_option()
         .with_context(|| "Compilation failed without any diagnostics.")?;
 
-    if compiler_config.replace_ids {
+    let add_debug_info = compiler_config.replace_ids || compiler_config.add_debug_info;
+    if add_debug_info {
         sierra_program = Arc::new(replace_sierra_ids_in_program(db, &sierra_program));
     }
+    // The canonical ids keep the debug names, so the debug info matches the serialized program.
     let replacer = CanonicalReplacer::from_program(&sierra_program);
     let sierra_program = replacer.apply(&sierra_program);
 
@@ -191,15 +211,15 @@ fn compile_contract_with_prepared_and_checked_db(
             compiler_version::current_compiler_version_id(),
             &sierra_program,
         )?,
-        sierra_program_debug_info: Some(cairo_lang_sierra::debug_info::DebugInfo::extract(
-            &sierra_program,
-        )),
+        sierra_program_debug_info: add_debug_info
+            .then(|| cairo_lang_sierra::debug_info::DebugInfo::extract(&sierra_program)),
         contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
         entry_points_by_type,
         abi: Some(
             AbiBuilder::submodule_as_contract_abi(db, contract.submodule_id)
                 .with_context(|| "Could not create ABI from contract submodule")?,
         ),
+        aux_data: get_contract_aux_data(db, contract)?,
     };
     Ok(contract_class)
 }
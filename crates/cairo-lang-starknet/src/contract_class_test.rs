@@ -1,4 +1,21 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::setup_project;
+use cairo_lang_compiler::CompilerConfig;
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::plugin::{
+    AsDynGeneratedFileAuxData, AsDynMacroPlugin, DynPluginAuxData, PluginArtifactAuxData,
+    PluginAuxData, PluginMappedDiagnostic, SemanticPlugin,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{ast, Terminal};
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use indoc::indoc;
 use num_bigint::BigUint;
 use pretty_assertions::assert_eq;
@@ -7,10 +24,12 @@ use test_case::test_case;
 use crate::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
 use crate::compiler_version;
 use crate::contract_class::{
-    ContractClass, ContractEntryPoint, ContractEntryPoints, DEFAULT_CONTRACT_CLASS_VERSION,
+    compile_contract_in_prepared_db, ContractClass, ContractEntryPoint, ContractEntryPoints,
+    DEFAULT_CONTRACT_CLASS_VERSION,
 };
 use crate::felt252_serde::sierra_from_felt252s;
-use crate::test_utils::{get_example_file_path, get_test_contract};
+use crate::plugin::StarkNetPlugin;
+use crate::test_utils::{get_example_file_path, get_test_contract, get_test_contract_with_config};
 
 #[test]
 fn test_serialization() {
@@ -26,6 +45,7 @@ fn test_serialization() {
             constructor: vec![],
         },
         abi: None,
+        aux_data: Default::default(),
     };
 
     let serialized = serde_json::to_string_pretty(&contract).unwrap();
@@ -105,3 +125,105 @@ fn test_compile_path(example_file_name: &str) {
         sierra_program.to_string(),
     );
 }
+
+/// Tests that the debug info is only added to the contract class when requested, and that it names
+/// all the user functions of the extracted Sierra program.
+#[test_case(false, false => false; "no_debug_info")]
+#[test_case(true, false => true; "replace_ids")]
+#[test_case(false, true => true; "add_debug_info")]
+fn test_debug_info(replace_ids: bool, add_debug_info: bool) -> bool {
+    let contract = get_test_contract_with_config(
+        "minimal_contract.cairo",
+        CompilerConfig { replace_ids, add_debug_info, ..CompilerConfig::default() },
+    );
+    let Some(debug_info) = &contract.sierra_program_debug_info else {
+        return false;
+    };
+    let sierra_program = contract.extract_sierra_program().unwrap();
+    assert_eq!(debug_info.user_func_names.len(), sierra_program.funcs.len());
+    assert!(sierra_program.funcs.iter().all(|func| func.id.debug_name.is_some()));
+    true
+}
+
+/// A plugin attaching the name of every struct to the compilation artifacts.
+#[derive(Debug, Default)]
+struct StructNamesPlugin;
+impl MacroPlugin for StructNamesPlugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        let ast::Item::Struct(struct_ast) = item_ast else { return PluginResult::default() };
+        let name = struct_ast.name(db).text(db);
+        PluginResult {
+            code: Some(PluginGeneratedFile {
+                name: format!("{name}_name").into(),
+                content: String::new(),
+                aux_data: DynGeneratedFileAuxData::new(DynPluginAuxData::new(StructNameAuxData {
+                    name: name.to_string(),
+                })),
+            }),
+            diagnostics: vec![],
+            remove_original_item: false,
+        }
+    }
+}
+impl AsDynMacroPlugin for StructNamesPlugin {
+    fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
+    where
+        Self: 'a,
+    {
+        self
+    }
+}
+impl SemanticPlugin for StructNamesPlugin {}
+
+#[derive(Debug, PartialEq, Eq)]
+struct StructNameAuxData {
+    name: String,
+}
+impl GeneratedFileAuxData for StructNameAuxData {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn eq(&self, other: &dyn GeneratedFileAuxData) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() { self == other } else { false }
+    }
+}
+impl AsDynGeneratedFileAuxData for StructNameAuxData {
+    fn as_dyn_macro_token(&self) -> &(dyn GeneratedFileAuxData + 'static) {
+        self
+    }
+}
+impl PluginAuxData for StructNameAuxData {
+    fn map_diag(
+        &self,
+        _db: &(dyn SemanticGroup + 'static),
+        _diag: &dyn std::any::Any,
+    ) -> Option<PluginMappedDiagnostic> {
+        None
+    }
+
+    fn artifact_aux_data(&self) -> Option<PluginArtifactAuxData> {
+        PluginArtifactAuxData::new("struct_names", &self.name).ok()
+    }
+}
+
+#[test]
+fn test_plugin_aux_data() {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .with_semantic_plugin(Arc::new(StructNamesPlugin::default()))
+        .build()
+        .unwrap();
+    let main_crate_ids =
+        setup_project(&mut db, Path::new(&get_example_file_path("minimal_contract.cairo")))
+            .unwrap();
+    let contract =
+        compile_contract_in_prepared_db(&db, None, main_crate_ids, CompilerConfig::default())
+            .unwrap();
+    assert_eq!(
+        contract.aux_data,
+        OrderedHashMap::from_iter([("struct_names".to_string(), vec!["ContractState".into()])])
+    );
+    let serialized = serde_json::to_string(&contract).unwrap();
+    assert_eq!(contract, serde_json::from_str(&serialized).unwrap());
+}
@@ -13,6 +13,8 @@ pub mod contract_class;
 mod felt252_serde;
 mod felt252_vec_compression;
 pub mod plugin;
+pub mod rust_bindings;
+pub mod typescript_types;
 
 #[cfg(test)]
 mod test_utils;
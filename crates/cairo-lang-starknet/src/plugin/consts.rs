@@ -4,6 +4,7 @@ pub const L1_HANDLER_MODULE: &str = "__l1_handler";
 pub const CONSTRUCTOR_MODULE: &str = "__constructor";
 pub const STORAGE_STRUCT_NAME: &str = "Storage";
 pub const EVENT_TYPE_NAME: &str = "Event";
+pub const EVENT_TRAIT_NAME: &str = "Event";
 pub const CONTRACT_STATE_NAME: &str = "ContractState";
 
 // TODO(spapini): Remove this attribute. It's for the old contract syntax.
@@ -17,18 +18,7 @@ pub const EXTERNAL_ATTR: &str = "external";
 pub const L1_HANDLER_ATTR: &str = "l1_handler";
 pub const CONSTRUCTOR_ATTR: &str = "constructor";
 pub(super) const RAW_OUTPUT_ATTR: &str = "raw_output";
+pub(super) const MAX_CALLDATA_SIZE_ATTR: &str = "max_calldata_size";
 
 pub(super) const L1_HANDLER_FIRST_PARAM_NAME: &str = "from_address";
 pub(super) const CALLDATA_PARAM_NAME: &str = "__calldata__";
-
-/// Starknet OS required implicit precedence.
-pub(super) const IMPLICIT_PRECEDENCE: &[&str] = &[
-    "Pedersen",
-    "RangeCheck",
-    "Bitwise",
-    "EcOp",
-    "Poseidon",
-    "SegmentArena",
-    "GasBuiltin",
-    "System",
-];
@@ -36,6 +36,7 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
                      `{CONTRACT_ATTR}` instead.",
                 ),
                 stable_ptr: module_ast.stable_ptr().untyped(),
+                lint_id: None,
             }],
             remove_original_item: false,
         };
@@ -50,6 +51,7 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
             diagnostics: vec![PluginDiagnostic {
                 message: "Contracts without body are not supported.".to_string(),
                 stable_ptr: module_ast.stable_ptr().untyped(),
+                lint_id: None,
             }],
             remove_original_item: false,
         };
@@ -62,6 +64,7 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
             diagnostics: vec![PluginDiagnostic {
                 message: "Contracts must define a 'Storage' struct.".to_string(),
                 stable_ptr: module_ast.stable_ptr().untyped(),
+                lint_id: None,
             }],
             remove_original_item: false,
         };
@@ -73,6 +76,7 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
             diagnostics: vec![PluginDiagnostic {
                 message: "'Storage' struct must be annotated with #[storage].".to_string(),
                 stable_ptr: module_ast.stable_ptr().untyped(),
+                lint_id: None,
             }],
             remove_original_item: false,
         };
@@ -115,6 +119,7 @@ pub fn handle_contract_by_storage(
                 diagnostics: vec![PluginDiagnostic {
                     message: "Contracts without body are not supported.".to_string(),
                     stable_ptr: empty_body.stable_ptr().untyped(),
+                    lint_id: None,
                 }],
                 remove_original_item: false,
             });
@@ -154,6 +159,7 @@ pub fn handle_contract_by_storage(
                              `Event`."
                         ),
                         stable_ptr,
+                        lint_id: None,
                     });
                 }
                 (false, true) => {
@@ -163,6 +169,7 @@ pub fn handle_contract_by_storage(
                              #[{EVENT_ATTR}]."
                         ),
                         stable_ptr,
+                        lint_id: None,
                     });
                     // The attribute is missing, but we still can't create an empty event.
                     has_event = true;
@@ -396,6 +403,7 @@ fn forbid_attribute_in_external_impl(
                 "The '{attr_name}' attribute is not allowed inside a contract external impl."
             ),
             stable_ptr: attr.stable_ptr().untyped(),
+            lint_id: None,
         });
     }
 }
@@ -418,6 +426,7 @@ fn handle_entry_point(
         diagnostics.push(PluginDiagnostic {
             message: "Contract entry points cannot have generic arguments".to_string(),
             stable_ptr: generic_params.stable_ptr().untyped(),
+            lint_id: None,
         })
     }
 
@@ -476,6 +485,7 @@ fn validate_l1_handler_first_parameter(
                 message: "The second parameter of an L1 handler must be of type `felt252`."
                     .to_string(),
                 stable_ptr: first_param.stable_ptr().untyped(),
+                lint_id: None,
             });
         }
 
@@ -487,6 +497,7 @@ fn validate_l1_handler_first_parameter(
                 message: "The second parameter of an L1 handler must be named 'from_address'."
                     .to_string(),
                 stable_ptr: first_param.stable_ptr().untyped(),
+                lint_id: None,
             });
         }
     } else {
@@ -494,6 +505,7 @@ fn validate_l1_handler_first_parameter(
             message: "An L1 handler must have the 'from_address' as its second parameter."
                 .to_string(),
             stable_ptr: params.stable_ptr().untyped(),
+            lint_id: None,
         });
     };
 }
@@ -26,6 +26,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                      `{INTERFACE_ATTR}` instead.",
                 ),
                 stable_ptr: trait_ast.stable_ptr().untyped(),
+                lint_id: None,
             }],
             remove_original_item: false,
         };
@@ -41,6 +42,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                 diagnostics: vec![PluginDiagnostic {
                     message: "ABIs without body are not supported.".to_string(),
                     stable_ptr: empty_body.stable_ptr().untyped(),
+                    lint_id: None,
                 }],
                 remove_original_item: false,
             };
@@ -69,6 +71,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     diagnostics.push(PluginDiagnostic {
                         message: "ABI functions must have a `self` parameter.".to_string(),
                         stable_ptr: declaration.stable_ptr().untyped(),
+                        lint_id: None,
                     });
                     continue;
                 };
@@ -76,6 +79,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     diagnostics.push(PluginDiagnostic {
                         message: "The `self` parameter must be named `self`.".to_string(),
                         stable_ptr: self_param.stable_ptr().untyped(),
+                        lint_id: None,
                     });
                     skip_generation = true;
                 }
@@ -88,6 +92,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                             message: "`ref` parameters are not supported in the ABI of a contract."
                                 .to_string(),
                             stable_ptr: param.modifiers(db).stable_ptr().untyped(),
+                            lint_id: None,
                         })
                     }
 
@@ -97,6 +102,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                         diagnostics.push(PluginDiagnostic {
                             message: "Parameter name `__calldata__` cannot be used.".to_string(),
                             stable_ptr: param.name(db).stable_ptr().untyped(),
+                            lint_id: None,
                         })
                     }
 
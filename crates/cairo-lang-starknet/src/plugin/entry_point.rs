@@ -1,5 +1,9 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_semantic::patcher::RewriteNode;
+use cairo_lang_sierra_to_casm::builtins::starknet_builtins;
+use cairo_lang_syntax::attribute::structured::{
+    AttributeArg, AttributeArgVariant, AttributeStructurize,
+};
 use cairo_lang_syntax::node::ast::{
     self, Attribute, FunctionWithBody, OptionArgListParenthesized, OptionReturnTypeClause,
 };
@@ -7,9 +11,10 @@ use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
 use itertools::Itertools;
+use num_traits::ToPrimitive;
 
 use super::consts::{
-    CONSTRUCTOR_ATTR, EXTERNAL_ATTR, IMPLICIT_PRECEDENCE, L1_HANDLER_ATTR, RAW_OUTPUT_ATTR,
+    CONSTRUCTOR_ATTR, EXTERNAL_ATTR, L1_HANDLER_ATTR, MAX_CALLDATA_SIZE_ATTR, RAW_OUTPUT_ATTR,
 };
 use super::utils::{is_felt252_span, is_ref_param};
 
@@ -67,18 +72,21 @@ pub fn generate_entry_point_wrapper(
         return Err(vec![PluginDiagnostic{
             message: "The first paramater of an entry point must be `self`.".into(),
             stable_ptr: sig.stable_ptr().untyped(),
+            lint_id: None,
         }]);
     };
     if first_param.name(db).text(db) != "self" {
         return Err(vec![PluginDiagnostic {
             message: "The first paramater of an entry point must be `self`.".into(),
             stable_ptr: first_param.stable_ptr().untyped(),
+            lint_id: None,
         }]);
     };
     let is_snapshot = matches!(first_param.type_clause(db).ty(db), ast::Expr::Unary(_));
     // TODO(spapini): Check modifiers and type.
 
     let raw_output = function.has_attr(db, RAW_OUTPUT_ATTR);
+    let max_calldata_size = get_max_calldata_size(db, &mut diagnostics, function);
     let input_data_short_err = "'Input too short for arguments'";
     for param in params {
         let arg_name = format!("__arg_{}", param.name(db).text(db));
@@ -90,6 +98,7 @@ pub fn generate_entry_point_wrapper(
             diagnostics.push(PluginDiagnostic {
                 message: format!("`{RAW_OUTPUT_ATTR}` functions cannot have `ref` parameters."),
                 stable_ptr: param.modifiers(db).stable_ptr().untyped(),
+                lint_id: None,
             });
         }
 
@@ -141,6 +150,7 @@ pub fn generate_entry_point_wrapper(
         diagnostics.push(PluginDiagnostic {
             message: format!("`{RAW_OUTPUT_ATTR}` functions must return `Span::<felt252>`."),
             stable_ptr: ret_type_ptr,
+            lint_id: None,
         });
     }
 
@@ -170,17 +180,32 @@ pub fn generate_entry_point_wrapper(
         .into(),
     );
 
+    // The Starknet OS passes the builtins in the order entry points receive them.
     let implicit_precedence = RewriteNode::Text(format!("#[implicit_precedence({})]", {
-        IMPLICIT_PRECEDENCE.iter().join(", ")
+        starknet_builtins().map(|builtin| builtin.generic_id.0.as_str()).join(", ")
     }));
 
     let arg_definitions = RewriteNode::Text(arg_definitions.join("\n"));
 
+    // Rejecting oversized calldata before deserializing it, so that its deserialization can't be
+    // used to make the entry point consume an arbitrary amount of gas.
+    let calldata_size_check = RewriteNode::Text(match max_calldata_size {
+        Some(max_calldata_size) => format!(
+            "
+            if array::array_len_exceeds::<felt252, {max_calldata_size}>(data.snapshot) {{
+                let mut err_data = array::array_new();
+                array::array_append(ref err_data, 'Calldata too long');
+                panic(err_data);
+            }}"
+        ),
+        None => "".to_string(),
+    });
+
     Ok(RewriteNode::interpolate_patched(
         "$implicit_precedence$
         fn $function_name$(mut data: Span::<felt252>) -> Span::<felt252> {
             internal::revoke_ap_tracking();
-            gas::withdraw_gas().expect('Out of gas');
+            gas::withdraw_gas().expect('Out of gas');$calldata_size_check$
             $arg_definitions$
             if !array::SpanTrait::is_empty(data) {
                 // Force the inclusion of `System` in the list of implicits.
@@ -198,12 +223,42 @@ pub fn generate_entry_point_wrapper(
             ("function_name".to_string(), function_name),
             ("output_handling".to_string(), output_handling),
             ("arg_definitions".to_string(), arg_definitions),
+            ("calldata_size_check".to_string(), calldata_size_check),
             ("implicit_precedence".to_string(), implicit_precedence),
         ]
         .into(),
     ))
 }
 
+/// Returns the maximal calldata size of the entry point, declared by its `max_calldata_size`
+/// attribute, if any. Adds a diagnostic if the attribute is malformed.
+fn get_max_calldata_size(
+    db: &dyn SyntaxGroup,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    function: &FunctionWithBody,
+) -> Option<u32> {
+    let attr = function.find_attr(db, MAX_CALLDATA_SIZE_ATTR)?.structurize(db);
+    if let [
+        AttributeArg {
+            variant: AttributeArgVariant::Unnamed { value: ast::Expr::Literal(literal), .. },
+            ..
+        },
+    ] = &attr.args[..]
+    {
+        if let Some(max_calldata_size) =
+            literal.numeric_value(db).and_then(|value| value.to_u32())
+        {
+            return Some(max_calldata_size);
+        }
+    }
+    diagnostics.push(PluginDiagnostic {
+        message: format!("`{MAX_CALLDATA_SIZE_ATTR}` expects a single `u32` literal argument."),
+        stable_ptr: attr.stable_ptr.untyped(),
+        lint_id: None,
+    });
+    None
+}
+
 /// Checks if the item is marked with an external attribute. Also validates the attribute.
 pub fn has_external_attribute(
     db: &dyn SyntaxGroup,
@@ -225,6 +280,7 @@ pub fn validate_external_v0(
         diagnostics.push(PluginDiagnostic {
             message: "Only #[external(v0)] is supported.".to_string(),
             stable_ptr: attr.stable_ptr().untyped(),
+            lint_id: None,
         });
     }
 }
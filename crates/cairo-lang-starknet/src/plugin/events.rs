@@ -50,6 +50,7 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
         diagnostics.push(PluginDiagnostic {
             message: "Event structs with generic arguments are unsupported".to_string(),
             stable_ptr: generic_params.stable_ptr().untyped(),
+            lint_id: None,
         });
         return PluginResult{ code: None, diagnostics, remove_original_item: false };
     };
@@ -138,6 +139,7 @@ fn get_field_kind(
         diagnostics.push(PluginDiagnostic {
             message: "Nested event fields are currently unsupported".to_string(),
             stable_ptr: member.stable_ptr().untyped(),
+            lint_id: None,
         });
     }
     // Currently, serde fields are unsupported.
@@ -145,6 +147,7 @@ fn get_field_kind(
         diagnostics.push(PluginDiagnostic {
             message: "Serde event fields are currently unsupported".to_string(),
             stable_ptr: member.stable_ptr().untyped(),
+            lint_id: None,
         });
     }
 
@@ -170,6 +173,7 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
         diagnostics.push(PluginDiagnostic {
             message: "Event enums with generic arguments are unsupported".to_string(),
             stable_ptr: generic_params.stable_ptr().untyped(),
+            lint_id: None,
         });
         return PluginResult{ code: None, diagnostics, remove_original_item: false };
     };
@@ -64,6 +64,7 @@ pub fn handle_storage_struct(
                 diagnostics.push(PluginDiagnostic {
                     message: "Non `LegacyMap` mapping is not yet supported.".to_string(),
                     stable_ptr: type_ast.stable_ptr().untyped(),
+                    lint_id: None,
                 });
             }
             None => {
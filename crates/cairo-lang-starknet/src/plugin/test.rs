@@ -71,6 +71,7 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         contract: "contract",
         events: "events",
         raw_output: "raw_output",
+        max_calldata_size: "max_calldata_size",
         storage: "storage",
         hello_starknet: "hello_starknet",
         dispatcher: "dispatcher",
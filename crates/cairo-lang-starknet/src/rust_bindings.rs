@@ -0,0 +1,383 @@
+//! Generation of Rust bindings of a contract from its ABI.
+//!
+//! The generated module has a struct for each external function of the contract and one for its
+//! deployment, holding the inputs as typed fields and serializing them to calldata. The structs
+//! and enums of the ABI are generated as Rust types, serialized the same way the `Serde` trait of
+//! Cairo serializes them.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use cairo_lang_utils::try_extract_matches;
+use convert_case::{Case, Casing};
+use indoc::formatdoc;
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::abi::{Contract, Enum, Function, Input, Item, Struct};
+use crate::contract::starknet_keccak;
+
+#[cfg(test)]
+#[path = "rust_bindings_test.rs"]
+mod test;
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum RustBindingsError {
+    #[error("Type `{0}` is not supported.")]
+    UnsupportedType(String),
+    #[error("Types `{0}` and `{1}` have the same name in Rust.")]
+    DuplicateTypeName(String, String),
+}
+
+/// The code defining the serialization to calldata, at the top of every generated module.
+const PRELUDE: &str = r#"use cairo_felt::Felt252;
+
+/// A value serializable to calldata, the same way as by the `Serde` trait of Cairo.
+pub trait CairoSerde {
+    /// Appends the serialization of the value to `output`.
+    fn serialize(&self, output: &mut Vec<Felt252>);
+}
+
+impl CairoSerde for Felt252 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        output.push(self.clone());
+    }
+}
+
+macro_rules! impl_cairo_serde_for_int {
+    ($($ty:ty),*) => {
+        $(impl CairoSerde for $ty {
+            fn serialize(&self, output: &mut Vec<Felt252>) {
+                output.push(Felt252::from(*self));
+            }
+        })*
+    };
+}
+impl_cairo_serde_for_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl<T: CairoSerde> CairoSerde for Vec<T> {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        output.push(Felt252::from(self.len()));
+        for value in self {
+            value.serialize(output);
+        }
+    }
+}
+
+impl CairoSerde for () {
+    fn serialize(&self, _output: &mut Vec<Felt252>) {}
+}
+
+macro_rules! impl_cairo_serde_for_tuple {
+    ($($name:ident),*) => {
+        impl<$($name: CairoSerde),*> CairoSerde for ($($name,)*) {
+            #[allow(non_snake_case)]
+            fn serialize(&self, output: &mut Vec<Felt252>) {
+                let ($($name,)*) = self;
+                $($name.serialize(output);)*
+            }
+        }
+    };
+}
+impl_cairo_serde_for_tuple!(A);
+impl_cairo_serde_for_tuple!(A, B);
+impl_cairo_serde_for_tuple!(A, B, C);
+impl_cairo_serde_for_tuple!(A, B, C, D);
+"#;
+
+/// The maximal size of a tuple supported by the prelude.
+const MAX_TUPLE_SIZE: usize = 4;
+
+/// Generates the code of a Rust module with bindings of the contract with the given ABI.
+pub fn generate_rust_bindings(
+    abi: &Contract,
+    contract_name: &str,
+) -> Result<String, RustBindingsError> {
+    let mut functions = vec![];
+    let mut constructor_inputs: &[Input] = &[];
+    let mut types = vec![];
+    for item in &abi.items {
+        match item {
+            Item::Function(function) => functions.push(function),
+            Item::Interface(interface) => functions.extend(
+                interface
+                    .items
+                    .iter()
+                    .filter_map(|item| try_extract_matches!(item, Item::Function)),
+            ),
+            Item::Constructor(constructor) => constructor_inputs = &constructor.inputs,
+            Item::Struct(_) | Item::Enum(_) => types.push(item),
+            Item::L1Handler(_) | Item::Event(_) | Item::Impl(_) => {}
+        }
+    }
+
+    // Maps the ABI name of each type to its Rust name.
+    let mut type_names = HashMap::<&str, String>::new();
+    let mut abi_type_names = HashMap::<String, &str>::new();
+    for item in &types {
+        let name = match item {
+            Item::Struct(Struct { name, .. }) | Item::Enum(Enum { name, .. }) => name.as_str(),
+            _ => unreachable!("Only structs and enums are collected."),
+        };
+        let rust_name = type_ident(name);
+        if let Some(other) = abi_type_names.insert(rust_name.clone(), name) {
+            return Err(RustBindingsError::DuplicateTypeName(other.into(), name.into()));
+        }
+        type_names.insert(name, rust_name);
+    }
+    let generator = BindingsGenerator { type_names };
+
+    let mut code = format!(
+        "//! Rust bindings of the `{contract_name}` contract, generated from its ABI.\n\n{PRELUDE}"
+    );
+    for item in types {
+        code.push('\n');
+        match item {
+            Item::Struct(item) => generator.write_struct(&mut code, item)?,
+            Item::Enum(item) => generator.write_enum(&mut code, item)?,
+            _ => unreachable!("Only structs and enums are collected."),
+        }
+    }
+    for function in functions {
+        code.push('\n');
+        generator.write_call(&mut code, function)?;
+    }
+    code.push('\n');
+    generator.write_inputs_struct(
+        &mut code,
+        "/// The deployment of the contract, with the inputs of its constructor.",
+        "Deploy",
+        constructor_inputs,
+        "",
+    )?;
+    Ok(code)
+}
+
+/// Generator of the code of the types and calls of a single contract.
+struct BindingsGenerator<'a> {
+    /// The Rust names of the structs and enums of the ABI, by their ABI names.
+    type_names: HashMap<&'a str, String>,
+}
+impl BindingsGenerator<'_> {
+    /// Writes a Rust struct for a Cairo struct.
+    fn write_struct(&self, code: &mut String, item: &Struct) -> Result<(), RustBindingsError> {
+        let name = &self.type_names[item.name.as_str()];
+        writeln!(code, "/// The `{}` struct.", item.name).unwrap();
+        writeln!(code, "#[derive(Clone, Debug, PartialEq)]").unwrap();
+        writeln!(code, "pub struct {name} {{").unwrap();
+        for member in &item.members {
+            writeln!(code, "    pub {}: {},", member.name, self.rust_type(&member.ty)?).unwrap();
+        }
+        writeln!(code, "}}").unwrap();
+        writeln!(code, "impl CairoSerde for {name} {{").unwrap();
+        writeln!(code, "    fn serialize(&self, output: &mut Vec<Felt252>) {{").unwrap();
+        for member in &item.members {
+            writeln!(code, "        self.{}.serialize(output);", member.name).unwrap();
+        }
+        writeln!(code, "    }}").unwrap();
+        writeln!(code, "}}").unwrap();
+        Ok(())
+    }
+
+    /// Writes a Rust enum for a Cairo enum. Variants of the unit type have no fields.
+    fn write_enum(&self, code: &mut String, item: &Enum) -> Result<(), RustBindingsError> {
+        let name = &self.type_names[item.name.as_str()];
+        writeln!(code, "/// The `{}` enum.", item.name).unwrap();
+        writeln!(code, "#[derive(Clone, Debug, PartialEq)]").unwrap();
+        writeln!(code, "pub enum {name} {{").unwrap();
+        for variant in &item.variants {
+            let variant_name = variant.name.to_case(Case::UpperCamel);
+            if variant.ty == "()" {
+                writeln!(code, "    {variant_name},").unwrap();
+            } else {
+                writeln!(code, "    {variant_name}({}),", self.rust_type(&variant.ty)?).unwrap();
+            }
+        }
+        writeln!(code, "}}").unwrap();
+        writeln!(code, "impl CairoSerde for {name} {{").unwrap();
+        writeln!(code, "    fn serialize(&self, output: &mut Vec<Felt252>) {{").unwrap();
+        writeln!(code, "        match self {{").unwrap();
+        for (idx, variant) in item.variants.iter().enumerate() {
+            let variant_name = variant.name.to_case(Case::UpperCamel);
+            if variant.ty == "()" {
+                writeln!(
+                    code,
+                    "            Self::{variant_name} => output.push(Felt252::from({idx}_usize)),"
+                )
+                .unwrap();
+            } else {
+                writeln!(code, "            Self::{variant_name}(value) => {{").unwrap();
+                writeln!(code, "                output.push(Felt252::from({idx}_usize));").unwrap();
+                writeln!(code, "                value.serialize(output);").unwrap();
+                writeln!(code, "            }}").unwrap();
+            }
+        }
+        writeln!(code, "        }}").unwrap();
+        writeln!(code, "    }}").unwrap();
+        writeln!(code, "}}").unwrap();
+        Ok(())
+    }
+
+    /// Writes the struct of a call to an external function, with its selector.
+    fn write_call(&self, code: &mut String, function: &Function) -> Result<(), RustBindingsError> {
+        let selector = starknet_keccak(function.name.as_bytes());
+        let entry_point_items = formatdoc! {r#"
+                /// The name of the called entry point.
+                pub const ENTRY_POINT_NAME: &str = {:?};
+
+                /// Returns the selector of the called entry point.
+                pub fn selector() -> Felt252 {{
+                    Felt252::parse_bytes(b"{selector:x}", 16).unwrap()
+                }}
+
+            "#,
+            function.name
+        };
+        self.write_inputs_struct(
+            code,
+            &format!("/// A call to the `{}` entry point.", function.name),
+            &format!("{}Call", function.name.to_case(Case::UpperCamel)),
+            &function.inputs,
+            &entry_point_items,
+        )
+    }
+
+    /// Writes a struct with the given inputs as fields, and an impl with the given items and a
+    /// method returning its calldata.
+    fn write_inputs_struct(
+        &self,
+        code: &mut String,
+        doc: &str,
+        name: &str,
+        inputs: &[Input],
+        impl_items: &str,
+    ) -> Result<(), RustBindingsError> {
+        writeln!(code, "{doc}").unwrap();
+        writeln!(code, "#[derive(Clone, Debug, PartialEq)]").unwrap();
+        if inputs.is_empty() {
+            writeln!(code, "pub struct {name} {{}}").unwrap();
+        } else {
+            writeln!(code, "pub struct {name} {{").unwrap();
+            for input in inputs {
+                writeln!(code, "    pub {}: {},", input.name, self.rust_type(&input.ty)?).unwrap();
+            }
+            writeln!(code, "}}").unwrap();
+        }
+        writeln!(code, "impl {name} {{").unwrap();
+        for line in impl_items.lines() {
+            if line.is_empty() {
+                writeln!(code).unwrap();
+            } else {
+                writeln!(code, "    {line}").unwrap();
+            }
+        }
+        writeln!(code, "    /// Returns the serialization of the inputs.").unwrap();
+        writeln!(code, "    pub fn calldata(&self) -> Vec<Felt252> {{").unwrap();
+        if inputs.is_empty() {
+            writeln!(code, "        vec![]").unwrap();
+        } else {
+            writeln!(code, "        let mut calldata = vec![];").unwrap();
+            for input in inputs {
+                writeln!(code, "        self.{}.serialize(&mut calldata);", input.name).unwrap();
+            }
+            writeln!(code, "        calldata").unwrap();
+        }
+        writeln!(code, "    }}").unwrap();
+        writeln!(code, "}}").unwrap();
+        Ok(())
+    }
+
+    /// Returns the Rust type of the given ABI type.
+    fn rust_type(&self, ty: &str) -> Result<String, RustBindingsError> {
+        // Snapshots are serialized as the snapshot type.
+        let ty = ty.trim_start_matches('@');
+        if let Some(name) = self.type_names.get(ty) {
+            return Ok(name.clone());
+        }
+        if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+            let inner_types = split_type_args(inner);
+            if inner_types.len() > MAX_TUPLE_SIZE {
+                return Err(RustBindingsError::UnsupportedType(ty.into()));
+            }
+            return Ok(match &inner_types[..] {
+                [inner_ty] => format!("({},)", self.rust_type(inner_ty)?),
+                _ => format!(
+                    "({})",
+                    inner_types
+                        .iter()
+                        .map(|inner_ty| self.rust_type(inner_ty))
+                        .collect::<Result<Vec<_>, _>>()?
+                        .join(", ")
+                ),
+            });
+        }
+        let (base, args) = split_generic_type(ty);
+        Ok(match (base, &args[..]) {
+            ("core::felt252", [])
+            | ("core::starknet::contract_address::ContractAddress", [])
+            | ("core::starknet::class_hash::ClassHash", [])
+            | ("core::starknet::storage_access::StorageAddress", [])
+            | ("core::starknet::storage_access::StorageBaseAddress", []) => "Felt252".into(),
+            ("core::integer::u96", []) => "u128".into(),
+            (
+                "core::integer::u8"
+                | "core::integer::u16"
+                | "core::integer::u32"
+                | "core::integer::u64"
+                | "core::integer::u128"
+                | "core::integer::i8"
+                | "core::integer::i16"
+                | "core::integer::i32"
+                | "core::integer::i64"
+                | "core::integer::i128",
+                [],
+            ) => base.trim_start_matches("core::integer::").into(),
+            ("core::array::Array", [inner_ty]) => format!("Vec<{}>", self.rust_type(inner_ty)?),
+            _ => return Err(RustBindingsError::UnsupportedType(ty.into())),
+        })
+    }
+}
+
+/// Returns the name of the Rust type of a struct or enum of the ABI, e.g. `SpanFelt252` for
+/// `core::array::Span::<core::felt252>`.
+pub(crate) fn type_ident(ty: &str) -> String {
+    let ty = ty.trim_start_matches('@');
+    if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+        return format!("Tuple{}", split_type_args(inner).into_iter().map(type_ident).join(""));
+    }
+    let (base, args) = split_generic_type(ty);
+    let name = base.rsplit("::").next().unwrap_or(base).to_case(Case::UpperCamel);
+    format!("{name}{}", args.into_iter().map(type_ident).join(""))
+}
+
+/// Splits a type into its generic type and its generic args, e.g.
+/// `core::array::Array::<core::felt252>` into `core::array::Array` and `[core::felt252]`.
+pub(crate) fn split_generic_type(ty: &str) -> (&str, Vec<&str>) {
+    match ty.find("::<") {
+        Some(idx) if ty.ends_with('>') => (&ty[..idx], split_type_args(&ty[idx + 3..ty.len() - 1])),
+        _ => (ty, vec![]),
+    }
+}
+
+/// Splits comma separated types, ignoring the commas nested in generic args and tuples.
+pub(crate) fn split_type_args(args: &str) -> Vec<&str> {
+    let mut res = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (idx, c) in args.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                res.push(args[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = args[start..].trim();
+    if !last.is_empty() {
+        res.push(last);
+    }
+    res
+}
@@ -0,0 +1,189 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::{generate_rust_bindings, RustBindingsError, PRELUDE};
+use crate::abi::Contract;
+
+#[test]
+fn test_rust_bindings() {
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          {
+            "type": "impl",
+            "name": "CounterImpl",
+            "interface_name": "test::ICounter"
+          },
+          {
+            "type": "struct",
+            "name": "core::integer::u256",
+            "members": [
+              { "name": "low", "type": "core::integer::u128" },
+              { "name": "high", "type": "core::integer::u128" }
+            ]
+          },
+          {
+            "type": "enum",
+            "name": "core::option::Option::<core::felt252>",
+            "variants": [
+              { "name": "Some", "type": "core::felt252" },
+              { "name": "None", "type": "()" }
+            ]
+          },
+          {
+            "type": "interface",
+            "name": "test::ICounter",
+            "items": [
+              {
+                "type": "function",
+                "name": "increase_by",
+                "inputs": [
+                  { "name": "amount", "type": "core::integer::u256" },
+                  { "name": "limit", "type": "core::option::Option::<core::felt252>" }
+                ],
+                "outputs": [],
+                "state_mutability": "external"
+              },
+              {
+                "type": "function",
+                "name": "get_counter",
+                "inputs": [],
+                "outputs": [{ "type": "core::integer::u256" }],
+                "state_mutability": "view"
+              }
+            ]
+          },
+          {
+            "type": "constructor",
+            "name": "constructor",
+            "inputs": [
+              { "name": "owner", "type": "core::starknet::contract_address::ContractAddress" },
+              {
+                "name": "initial",
+                "type": "core::array::Array::<(core::felt252, core::integer::u8)>"
+              }
+            ]
+          }
+        ]
+    "#})
+    .unwrap();
+    let bindings = generate_rust_bindings(&abi, "Counter").unwrap();
+    let bindings = bindings
+        .strip_prefix(&format!(
+            "//! Rust bindings of the `Counter` contract, generated from its ABI.\n\n{PRELUDE}\n"
+        ))
+        .unwrap();
+    assert_eq!(
+        bindings,
+        indoc! {r#"
+            /// The `core::integer::u256` struct.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct U256 {
+                pub low: u128,
+                pub high: u128,
+            }
+            impl CairoSerde for U256 {
+                fn serialize(&self, output: &mut Vec<Felt252>) {
+                    self.low.serialize(output);
+                    self.high.serialize(output);
+                }
+            }
+
+            /// The `core::option::Option::<core::felt252>` enum.
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum OptionFelt252 {
+                Some(Felt252),
+                None,
+            }
+            impl CairoSerde for OptionFelt252 {
+                fn serialize(&self, output: &mut Vec<Felt252>) {
+                    match self {
+                        Self::Some(value) => {
+                            output.push(Felt252::from(0_usize));
+                            value.serialize(output);
+                        }
+                        Self::None => output.push(Felt252::from(1_usize)),
+                    }
+                }
+            }
+
+            /// A call to the `increase_by` entry point.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct IncreaseByCall {
+                pub amount: U256,
+                pub limit: OptionFelt252,
+            }
+            impl IncreaseByCall {
+                /// The name of the called entry point.
+                pub const ENTRY_POINT_NAME: &str = "increase_by";
+
+                /// Returns the selector of the called entry point.
+                pub fn selector() -> Felt252 {
+                    Felt252::parse_bytes(b"2062af23a5f83a88a7621d85153a9d8e529ceaac9e0e8d7f9928cfed8e4fe32", 16).unwrap()
+                }
+
+                /// Returns the serialization of the inputs.
+                pub fn calldata(&self) -> Vec<Felt252> {
+                    let mut calldata = vec![];
+                    self.amount.serialize(&mut calldata);
+                    self.limit.serialize(&mut calldata);
+                    calldata
+                }
+            }
+
+            /// A call to the `get_counter` entry point.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct GetCounterCall {}
+            impl GetCounterCall {
+                /// The name of the called entry point.
+                pub const ENTRY_POINT_NAME: &str = "get_counter";
+
+                /// Returns the selector of the called entry point.
+                pub fn selector() -> Felt252 {
+                    Felt252::parse_bytes(b"3370263ab53343580e77063a719a5865004caff7f367ec136a6cdd34b6786ca", 16).unwrap()
+                }
+
+                /// Returns the serialization of the inputs.
+                pub fn calldata(&self) -> Vec<Felt252> {
+                    vec![]
+                }
+            }
+
+            /// The deployment of the contract, with the inputs of its constructor.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct Deploy {
+                pub owner: Felt252,
+                pub initial: Vec<(Felt252, u8)>,
+            }
+            impl Deploy {
+                /// Returns the serialization of the inputs.
+                pub fn calldata(&self) -> Vec<Felt252> {
+                    let mut calldata = vec![];
+                    self.owner.serialize(&mut calldata);
+                    self.initial.serialize(&mut calldata);
+                    calldata
+                }
+            }
+        "#}
+    );
+}
+
+#[test]
+fn test_rust_bindings_unsupported_type() {
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          {
+            "type": "function",
+            "name": "foo",
+            "inputs": [{ "name": "value", "type": "core::box::Box::<core::felt252>" }],
+            "outputs": [],
+            "state_mutability": "external"
+          }
+        ]
+    "#})
+    .unwrap();
+    assert_eq!(
+        generate_rust_bindings(&abi, "Foo"),
+        Err(RustBindingsError::UnsupportedType("core::box::Box::<core::felt252>".into()))
+    );
+}
@@ -33,6 +33,18 @@ static SHARED_DB: Lazy<Mutex<RootDatabase>> = Lazy::new(|| {
 
 /// Returns the compiled test contract, with replaced ids.
 pub fn get_test_contract(example_file_name: &str) -> crate::contract_class::ContractClass {
+    get_test_contract_with_config(
+        example_file_name,
+        CompilerConfig { replace_ids: true, ..CompilerConfig::default() },
+    )
+}
+
+/// Returns the compiled test contract, compiled with the given configuration and the list of all
+/// the libfuncs.
+pub fn get_test_contract_with_config(
+    example_file_name: &str,
+    compiler_config: CompilerConfig<'_>,
+) -> crate::contract_class::ContractClass {
     let path = get_example_file_path(example_file_name);
     let mut locked_db = test_lock(&SHARED_DB);
     // Setting up the contract path.
@@ -44,9 +56,8 @@ pub fn get_test_contract(example_file_name: &str) -> crate::contract_class::Cont
         None,
         main_crate_ids,
         CompilerConfig {
-            replace_ids: true,
             allowed_libfuncs_list_name: Some(BUILTIN_ALL_LIBFUNCS_LIST.to_string()),
-            ..CompilerConfig::default()
+            ..compiler_config
         },
     )
     .expect("compile_path failed")
@@ -0,0 +1,255 @@
+//! Generation of TypeScript type definitions of a contract from its ABI.
+//!
+//! Field elements and integers are represented as `BigNumberish` values, the representation
+//! accepted by the common Starknet JS libraries. Structs and struct events are generated as
+//! interfaces, and enums and enum events as unions of single-key objects, keyed by the variant.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use cairo_lang_utils::try_extract_matches;
+use thiserror::Error;
+
+use crate::abi::{Contract, Enum, Event, EventKind, Function, Item, StateMutability, Struct};
+use crate::plugin::events::EventFieldKind;
+use crate::rust_bindings::{split_generic_type, split_type_args, type_ident};
+
+#[cfg(test)]
+#[path = "typescript_types_test.rs"]
+mod test;
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum TypeScriptTypesError {
+    #[error("Type `{0}` is not supported.")]
+    UnsupportedType(String),
+    #[error("Types `{0}` and `{1}` have the same name in TypeScript.")]
+    DuplicateTypeName(String, String),
+}
+
+/// The header of every generated file.
+const HEADER: &str = "\
+// Type definitions of a contract, generated from its ABI.
+
+/** A field element or an integer, as a number, a bigint, or a decimal or hex string. */
+export type BigNumberish = number | bigint | string;
+";
+
+/// Generates TypeScript type definitions (the content of a `.d.ts` file) of the entry points,
+/// events and types of the contract with the given ABI.
+pub fn generate_typescript_types(abi: &Contract) -> Result<String, TypeScriptTypesError> {
+    let mut functions = vec![];
+    let mut constructor = None;
+    let mut types = vec![];
+    for item in &abi.items {
+        match item {
+            Item::Function(function) => functions.push(function),
+            Item::Interface(interface) => functions.extend(
+                interface
+                    .items
+                    .iter()
+                    .filter_map(|item| try_extract_matches!(item, Item::Function)),
+            ),
+            Item::Constructor(item) => constructor = Some(item),
+            Item::Struct(Struct { name, .. }) | Item::Enum(Enum { name, .. })
+                if is_builtin(name) => {}
+            Item::Struct(_) | Item::Enum(_) | Item::Event(_) => types.push(item),
+            Item::L1Handler(_) | Item::Impl(_) => {}
+        }
+    }
+
+    // Maps the ABI name of each type to its TypeScript name.
+    let mut type_names = HashMap::<&str, String>::new();
+    let mut abi_type_names = HashMap::<String, &str>::new();
+    for item in &types {
+        let name = match item {
+            Item::Struct(Struct { name, .. })
+            | Item::Enum(Enum { name, .. })
+            | Item::Event(Event { name, .. }) => name.as_str(),
+            _ => unreachable!("Only types and events are collected."),
+        };
+        let ts_name = type_ident(name);
+        if let Some(other) = abi_type_names.insert(ts_name.clone(), name) {
+            return Err(TypeScriptTypesError::DuplicateTypeName(other.into(), name.into()));
+        }
+        type_names.insert(name, ts_name);
+    }
+    let generator = TypesGenerator { type_names };
+
+    let mut code = HEADER.to_string();
+    for item in types {
+        code.push('\n');
+        match item {
+            Item::Struct(item) => generator.write_struct(&mut code, item)?,
+            Item::Enum(item) => generator.write_enum(&mut code, item)?,
+            Item::Event(item) => generator.write_event(&mut code, item)?,
+            _ => unreachable!("Only types and events are collected."),
+        }
+    }
+    code.push('\n');
+    writeln!(code, "/** The entry points of the contract. */").unwrap();
+    writeln!(code, "export interface ContractEntryPoints {{").unwrap();
+    for function in functions {
+        generator.write_function(&mut code, function)?;
+    }
+    writeln!(code, "}}").unwrap();
+    code.push('\n');
+    writeln!(code, "/** The inputs of the constructor of the contract. */").unwrap();
+    writeln!(code, "export interface ConstructorInputs {{").unwrap();
+    for input in constructor.map(|constructor| &constructor.inputs[..]).unwrap_or_default() {
+        writeln!(code, "  {}: {};", input.name, generator.ts_type(&input.ty)?).unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+    Ok(code)
+}
+
+/// Returns whether the given struct or enum is mapped to a builtin TypeScript type, rather than
+/// generated.
+fn is_builtin(name: &str) -> bool {
+    name == "core::bool" || split_generic_type(name).0 == "core::array::Span"
+}
+
+/// Generator of the type definitions of a single contract.
+struct TypesGenerator<'a> {
+    /// The TypeScript names of the structs, enums and events of the ABI, by their ABI names.
+    type_names: HashMap<&'a str, String>,
+}
+impl TypesGenerator<'_> {
+    /// Writes an interface for a Cairo struct.
+    fn write_struct(&self, code: &mut String, item: &Struct) -> Result<(), TypeScriptTypesError> {
+        writeln!(code, "/** The `{}` struct. */", item.name).unwrap();
+        writeln!(code, "export interface {} {{", self.type_names[item.name.as_str()]).unwrap();
+        for member in &item.members {
+            writeln!(code, "  {}: {};", member.name, self.ts_type(&member.ty)?).unwrap();
+        }
+        writeln!(code, "}}").unwrap();
+        Ok(())
+    }
+
+    /// Writes a union type for a Cairo enum.
+    fn write_enum(&self, code: &mut String, item: &Enum) -> Result<(), TypeScriptTypesError> {
+        writeln!(code, "/** The `{}` enum. */", item.name).unwrap();
+        let variants =
+            item.variants.iter().map(|variant| (variant.name.as_str(), variant.ty.as_str()));
+        self.write_union(code, &self.type_names[item.name.as_str()], variants)
+    }
+
+    /// Writes an interface for a struct event, or a union type for an enum event.
+    fn write_event(&self, code: &mut String, item: &Event) -> Result<(), TypeScriptTypesError> {
+        let name = &self.type_names[item.name.as_str()];
+        writeln!(code, "/** The `{}` event. */", item.name).unwrap();
+        match &item.kind {
+            EventKind::Struct { members } => {
+                writeln!(code, "export interface {name} {{").unwrap();
+                for member in members {
+                    if member.kind == EventFieldKind::KeySerde {
+                        writeln!(code, "  /** A key of the event. */").unwrap();
+                    }
+                    writeln!(code, "  {}: {};", member.name, self.ts_type(&member.ty)?).unwrap();
+                }
+                writeln!(code, "}}").unwrap();
+            }
+            EventKind::Enum { variants } => {
+                let variants =
+                    variants.iter().map(|variant| (variant.name.as_str(), variant.ty.as_str()));
+                self.write_union(code, name, variants)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a union type of single-key objects, one for each of the given variants.
+    fn write_union<'v>(
+        &self,
+        code: &mut String,
+        name: &str,
+        variants: impl Iterator<Item = (&'v str, &'v str)>,
+    ) -> Result<(), TypeScriptTypesError> {
+        let mut variants = variants.peekable();
+        if variants.peek().is_none() {
+            writeln!(code, "export type {name} = never;").unwrap();
+            return Ok(());
+        }
+        write!(code, "export type {name} =").unwrap();
+        for (variant_name, ty) in variants {
+            write!(code, "\n  | {{ {variant_name}: {} }}", self.ts_type(ty)?).unwrap();
+        }
+        writeln!(code, ";").unwrap();
+        Ok(())
+    }
+
+    /// Writes the method of an entry point, returning a promise of its outputs.
+    fn write_function(
+        &self,
+        code: &mut String,
+        function: &Function,
+    ) -> Result<(), TypeScriptTypesError> {
+        let params = function
+            .inputs
+            .iter()
+            .map(|input| Ok(format!("{}: {}", input.name, self.ts_type(&input.ty)?)))
+            .collect::<Result<Vec<_>, TypeScriptTypesError>>()?;
+        let outputs = function
+            .outputs
+            .iter()
+            .map(|output| self.ts_type(&output.ty))
+            .collect::<Result<Vec<_>, _>>()?;
+        let output = match &outputs[..] {
+            [] => "void".to_string(),
+            [output] => output.clone(),
+            _ => format!("[{}]", outputs.join(", ")),
+        };
+        let mutability = match function.state_mutability {
+            StateMutability::External => "external",
+            StateMutability::View => "view",
+        };
+        writeln!(code, "  /** The `{}` {mutability} function. */", function.name).unwrap();
+        writeln!(code, "  {}({}): Promise<{output}>;", function.name, params.join(", ")).unwrap();
+        Ok(())
+    }
+
+    /// Returns the TypeScript type of the given ABI type.
+    fn ts_type(&self, ty: &str) -> Result<String, TypeScriptTypesError> {
+        // Snapshots are represented as the snapshot type.
+        let ty = ty.trim_start_matches('@');
+        if let Some(name) = self.type_names.get(ty) {
+            return Ok(name.clone());
+        }
+        if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+            return Ok(format!(
+                "[{}]",
+                split_type_args(inner)
+                    .into_iter()
+                    .map(|inner_ty| self.ts_type(inner_ty))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ));
+        }
+        let (base, args) = split_generic_type(ty);
+        Ok(match (base, &args[..]) {
+            ("core::bool", []) => "boolean".into(),
+            ("core::felt252", [])
+            | ("core::starknet::contract_address::ContractAddress", [])
+            | ("core::starknet::class_hash::ClassHash", [])
+            | ("core::starknet::storage_access::StorageAddress", [])
+            | ("core::starknet::storage_access::StorageBaseAddress", []) => "BigNumberish".into(),
+            (
+                "core::integer::u8"
+                | "core::integer::u16"
+                | "core::integer::u32"
+                | "core::integer::u64"
+                | "core::integer::u96"
+                | "core::integer::u128"
+                | "core::integer::i8"
+                | "core::integer::i16"
+                | "core::integer::i32"
+                | "core::integer::i64"
+                | "core::integer::i128",
+                [],
+            ) => "BigNumberish".into(),
+            ("core::array::Array" | "core::array::Span", [inner_ty]) => {
+                format!("{}[]", self.ts_type(inner_ty)?)
+            }
+            _ => return Err(TypeScriptTypesError::UnsupportedType(ty.into())),
+        })
+    }
+}
@@ -0,0 +1,178 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::{generate_typescript_types, TypeScriptTypesError};
+use crate::abi::Contract;
+
+#[test]
+fn test_typescript_types() {
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          {
+            "type": "impl",
+            "name": "CounterImpl",
+            "interface_name": "test::ICounter"
+          },
+          {
+            "type": "struct",
+            "name": "core::integer::u256",
+            "members": [
+              { "name": "low", "type": "core::integer::u128" },
+              { "name": "high", "type": "core::integer::u128" }
+            ]
+          },
+          {
+            "type": "enum",
+            "name": "core::bool",
+            "variants": [
+              { "name": "False", "type": "()" },
+              { "name": "True", "type": "()" }
+            ]
+          },
+          {
+            "type": "struct",
+            "name": "core::array::Span::<core::felt252>",
+            "members": [
+              { "name": "snapshot", "type": "@core::array::Array::<core::felt252>" }
+            ]
+          },
+          {
+            "type": "enum",
+            "name": "core::option::Option::<core::felt252>",
+            "variants": [
+              { "name": "Some", "type": "core::felt252" },
+              { "name": "None", "type": "()" }
+            ]
+          },
+          {
+            "type": "interface",
+            "name": "test::ICounter",
+            "items": [
+              {
+                "type": "function",
+                "name": "increase_by",
+                "inputs": [
+                  { "name": "amount", "type": "core::integer::u256" },
+                  { "name": "limit", "type": "core::option::Option::<core::felt252>" }
+                ],
+                "outputs": [],
+                "state_mutability": "external"
+              },
+              {
+                "type": "function",
+                "name": "get_counter",
+                "inputs": [],
+                "outputs": [{ "type": "core::integer::u256" }],
+                "state_mutability": "view"
+              },
+              {
+                "type": "function",
+                "name": "is_owner",
+                "inputs": [{ "name": "values", "type": "core::array::Span::<core::felt252>" }],
+                "outputs": [{ "type": "core::bool" }],
+                "state_mutability": "view"
+              }
+            ]
+          },
+          {
+            "type": "constructor",
+            "name": "constructor",
+            "inputs": [
+              { "name": "owner", "type": "core::starknet::contract_address::ContractAddress" },
+              {
+                "name": "initial",
+                "type": "core::array::Array::<(core::felt252, core::integer::u8)>"
+              }
+            ]
+          },
+          {
+            "type": "event",
+            "name": "test::counter::Increased",
+            "kind": "struct",
+            "members": [
+              {
+                "name": "caller",
+                "type": "core::starknet::contract_address::ContractAddress",
+                "kind": "key"
+              },
+              { "name": "amount", "type": "core::integer::u256", "kind": "data" }
+            ]
+          },
+          {
+            "type": "event",
+            "name": "test::counter::Event",
+            "kind": "enum",
+            "variants": [
+              { "name": "Increased", "type": "test::counter::Increased", "kind": "nested" }
+            ]
+          }
+        ]
+    "#})
+    .unwrap();
+    assert_eq!(
+        generate_typescript_types(&abi).unwrap(),
+        indoc! {r#"
+            // Type definitions of a contract, generated from its ABI.
+
+            /** A field element or an integer, as a number, a bigint, or a decimal or hex string. */
+            export type BigNumberish = number | bigint | string;
+
+            /** The `core::integer::u256` struct. */
+            export interface U256 {
+              low: BigNumberish;
+              high: BigNumberish;
+            }
+
+            /** The `core::option::Option::<core::felt252>` enum. */
+            export type OptionFelt252 =
+              | { Some: BigNumberish }
+              | { None: [] };
+
+            /** The `test::counter::Increased` event. */
+            export interface Increased {
+              /** A key of the event. */
+              caller: BigNumberish;
+              amount: U256;
+            }
+
+            /** The `test::counter::Event` event. */
+            export type Event =
+              | { Increased: Increased };
+
+            /** The entry points of the contract. */
+            export interface ContractEntryPoints {
+              /** The `increase_by` external function. */
+              increase_by(amount: U256, limit: OptionFelt252): Promise<void>;
+              /** The `get_counter` view function. */
+              get_counter(): Promise<U256>;
+              /** The `is_owner` view function. */
+              is_owner(values: BigNumberish[]): Promise<boolean>;
+            }
+
+            /** The inputs of the constructor of the contract. */
+            export interface ConstructorInputs {
+              owner: BigNumberish;
+              initial: [BigNumberish, BigNumberish][];
+            }
+        "#}
+    );
+}
+
+#[test]
+fn test_typescript_types_duplicate_names() {
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          { "type": "struct", "name": "test::a::Point", "members": [] },
+          { "type": "struct", "name": "test::b::Point", "members": [] }
+        ]
+    "#})
+    .unwrap();
+    assert_eq!(
+        generate_typescript_types(&abi),
+        Err(TypeScriptTypesError::DuplicateTypeName(
+            "test::a::Point".into(),
+            "test::b::Point".into()
+        ))
+    );
+}
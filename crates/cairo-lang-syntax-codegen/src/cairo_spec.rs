@@ -148,6 +148,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_separated_list("MatchArms", "MatchArm", "TerminalComma")
     .add_struct(StructBuilder::new("MatchArm")
+        .node("attributes" ,"AttributeList")
         .node("pattern", "Pattern")
         .node("arrow", "TerminalMatchArrow")
         .node("expression", "Expr")
@@ -3088,16 +3088,18 @@ pub struct MatchArm {
     children: Vec<SyntaxNode>,
 }
 impl MatchArm {
-    pub const INDEX_PATTERN: usize = 0;
-    pub const INDEX_ARROW: usize = 1;
-    pub const INDEX_EXPRESSION: usize = 2;
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_PATTERN: usize = 1;
+    pub const INDEX_ARROW: usize = 2;
+    pub const INDEX_EXPRESSION: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
         pattern: PatternGreen,
         arrow: TerminalMatchArrowGreen,
         expression: ExprGreen,
     ) -> MatchArmGreen {
-        let children: Vec<GreenId> = vec![pattern.0, arrow.0, expression.0];
+        let children: Vec<GreenId> = vec![attributes.0, pattern.0, arrow.0, expression.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
         MatchArmGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::MatchArm,
@@ -3106,14 +3108,17 @@ impl MatchArm {
     }
 }
 impl MatchArm {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
     pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
-        Pattern::from_syntax_node(db, self.children[0].clone())
+        Pattern::from_syntax_node(db, self.children[1].clone())
     }
     pub fn arrow(&self, db: &dyn SyntaxGroup) -> TerminalMatchArrow {
-        TerminalMatchArrow::from_syntax_node(db, self.children[1].clone())
+        TerminalMatchArrow::from_syntax_node(db, self.children[2].clone())
     }
     pub fn expression(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[2].clone())
+        Expr::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -3134,6 +3139,7 @@ impl TypedSyntaxNode for MatchArm {
             kind: SyntaxKind::MatchArm,
             details: GreenNodeDetails::Node {
                 children: vec![
+                    AttributeList::missing(db).0,
                     Pattern::missing(db).0,
                     TerminalMatchArrow::missing(db).0,
                     Expr::missing(db).0,
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use cairo_felt::Felt252;
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use cairo_lang_starknet::abi::{Contract, EnumVariant, EventField, EventKind, Item, StructMember};
+use cairo_lang_starknet::contract::starknet_keccak;
+use cairo_lang_starknet::plugin::events::EventFieldKind;
+use itertools::Itertools;
+use num_traits::ToPrimitive;
+
+#[cfg(test)]
+#[path = "abi_decoder_test.rs"]
+mod test;
+
+/// The ABI type of `u256`, displayed as a single number.
+const U256_TYPE: &str = "core::integer::u256";
+/// The ABI type of `felt252`, displayed along with its short string interpretation if printable.
+const FELT252_TYPE: &str = "core::felt252";
+/// The prefixes of the ABI types serialized as arrays.
+const ARRAY_TYPE_PREFIXES: [&str; 2] = ["core::array::Array::<", "core::array::Span::<"];
+
+/// Decodes values and events serialized by a contract into a display form, according to the
+/// contract's ABI.
+pub struct AbiDecoder<'a> {
+    /// The members of the ABI structs, by struct name.
+    structs: HashMap<&'a str, &'a [StructMember]>,
+    /// The variants of the ABI enums, by enum name.
+    enums: HashMap<&'a str, &'a [EnumVariant]>,
+    /// The ABI events, by event name.
+    events: HashMap<&'a str, &'a EventKind>,
+    /// The name of the event emitted by the contract - the one not nested in any other event.
+    root_event: Option<&'a str>,
+}
+impl<'a> AbiDecoder<'a> {
+    pub fn new(abi: &'a Contract) -> Self {
+        let mut structs = HashMap::new();
+        let mut enums = HashMap::new();
+        let mut events = HashMap::new();
+        for item in &abi.items {
+            match item {
+                Item::Struct(item) => {
+                    structs.insert(item.name.as_str(), &item.members[..]);
+                }
+                Item::Enum(item) => {
+                    enums.insert(item.name.as_str(), &item.variants[..]);
+                }
+                Item::Event(item) => {
+                    events.insert(item.name.as_str(), &item.kind);
+                }
+                _ => {}
+            }
+        }
+        let nested_events: Vec<&str> = events
+            .values()
+            .flat_map(|kind| event_fields(kind))
+            .filter(|field| field.kind == EventFieldKind::Nested)
+            .map(|field| field.ty.as_str())
+            .collect();
+        let root_event = events.keys().copied().find(|name| !nested_events.contains(name));
+        Self { structs, enums, events, root_event }
+    }
+
+    /// Decodes an event emitted by the contract, given its keys and data.
+    /// Returns `None` if the event does not match the ABI.
+    pub fn decode_event(&self, keys: &[Felt252], data: &[Felt252]) -> Option<String> {
+        let mut keys = keys.iter();
+        let mut data = data.iter();
+        let decoded = self.decode_event_of_type(self.root_event?, &mut keys, &mut data)?;
+        // All the keys and data should be consumed by the event.
+        (keys.next().is_none() && data.next().is_none()).then_some(decoded)
+    }
+
+    /// Decodes an event of type `ty`, consuming its keys and data from the given iterators.
+    fn decode_event_of_type<'b>(
+        &self,
+        ty: &str,
+        keys: &mut impl Iterator<Item = &'b Felt252>,
+        data: &mut impl Iterator<Item = &'b Felt252>,
+    ) -> Option<String> {
+        match self.events.get(ty)? {
+            EventKind::Struct { members } => {
+                let members = members
+                    .iter()
+                    .map(|member| {
+                        let value = self.decode_event_field(member, keys, data)?;
+                        Some(format!("{}: {value}", member.name))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format_struct(ty, &members))
+            }
+            EventKind::Enum { variants } => {
+                let selector = keys.next()?;
+                let variant = variants.iter().find(|variant| {
+                    &Felt252::from(starknet_keccak(variant.name.as_bytes())) == selector
+                })?;
+                let value = self.decode_event_field(variant, keys, data)?;
+                Some(format!("{ty}::{}({value})", variant.name))
+            }
+        }
+    }
+
+    /// Decodes a member or variant of an event, consuming its keys and data from the given
+    /// iterators.
+    fn decode_event_field<'b>(
+        &self,
+        field: &EventField,
+        keys: &mut impl Iterator<Item = &'b Felt252>,
+        data: &mut impl Iterator<Item = &'b Felt252>,
+    ) -> Option<String> {
+        match field.kind {
+            EventFieldKind::KeySerde => self.decode_value(&field.ty, keys),
+            EventFieldKind::DataSerde => self.decode_value(&field.ty, data),
+            EventFieldKind::Nested => self.decode_event_of_type(&field.ty, keys, data),
+        }
+    }
+
+    /// Decodes a value of type `ty`, consuming its serialization from `felts`.
+    /// Types the ABI does not describe are decoded as a single felt252.
+    pub fn decode_value<'b>(
+        &self,
+        ty: &str,
+        felts: &mut impl Iterator<Item = &'b Felt252>,
+    ) -> Option<String> {
+        if ty == U256_TYPE {
+            let low = felts.next()?.to_biguint();
+            let high = felts.next()?.to_biguint();
+            return Some(((high << 128_usize) + low).to_string());
+        }
+        if let Some(members) = self.structs.get(ty) {
+            let members = members
+                .iter()
+                .map(|member| {
+                    Some(format!("{}: {}", member.name, self.decode_value(&member.ty, felts)?))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            return Some(format_struct(ty, &members));
+        }
+        if let Some(variants) = self.enums.get(ty) {
+            let variant = variants.get(felts.next()?.to_usize()?)?;
+            return Some(if variant.ty == "()" {
+                format!("{ty}::{}", variant.name)
+            } else {
+                format!("{ty}::{}({})", variant.name, self.decode_value(&variant.ty, felts)?)
+            });
+        }
+        if let Some(element_ty) =
+            ARRAY_TYPE_PREFIXES.iter().find_map(|prefix| ty.strip_prefix(prefix)?.strip_suffix('>'))
+        {
+            let len = felts.next()?.to_usize()?;
+            let elements = (0..len)
+                .map(|_| self.decode_value(element_ty, felts))
+                .collect::<Option<Vec<_>>>()?;
+            return Some(format!("[{}]", elements.join(", ")));
+        }
+        if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+            let elements = split_tuple_types(inner)
+                .into_iter()
+                .map(|element_ty| self.decode_value(element_ty, felts))
+                .collect::<Option<Vec<_>>>()?;
+            return Some(format!("({})", elements.join(", ")));
+        }
+        let value = felts.next()?;
+        Some(match as_cairo_short_string(value) {
+            Some(as_string)
+                if ty == FELT252_TYPE
+                    && !as_string.is_empty()
+                    && !as_string.chars().any(|c| c.is_ascii_control()) =>
+            {
+                format!("{value} ('{as_string}')")
+            }
+            _ => value.to_string(),
+        })
+    }
+}
+
+/// Formats a struct value given its type and its formatted members.
+fn format_struct(ty: &str, members: &[String]) -> String {
+    if members.is_empty() {
+        format!("{ty} {{}}")
+    } else {
+        format!("{ty} {{ {} }}", members.join(", "))
+    }
+}
+
+/// Returns the members or variants of an event.
+fn event_fields(kind: &EventKind) -> &[EventField] {
+    match kind {
+        EventKind::Struct { members } => members,
+        EventKind::Enum { variants } => variants,
+    }
+}
+
+/// Splits the comma separated element types of a tuple type, ignoring commas of nested types.
+fn split_tuple_types(types: &str) -> Vec<&str> {
+    if types.trim().is_empty() {
+        return vec![];
+    }
+    let mut depth = 0;
+    let mut start = 0;
+    let mut result = vec![];
+    for (i, c) in types.char_indices() {
+        match c {
+            '(' | '<' => depth += 1,
+            ')' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(types[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    result.push(types[start..].trim());
+    result.into_iter().filter(|ty| !ty.is_empty()).collect_vec()
+}
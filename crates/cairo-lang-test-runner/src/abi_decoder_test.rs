@@ -0,0 +1,106 @@
+use cairo_felt::Felt252;
+use cairo_lang_starknet::abi::{
+    Contract, Enum, EnumVariant, Event, EventField, EventKind, Item, Struct, StructMember,
+};
+use cairo_lang_starknet::contract::starknet_keccak;
+use cairo_lang_starknet::plugin::events::EventFieldKind;
+use test_case::test_case;
+
+use super::AbiDecoder;
+
+/// Returns an event field with the given name, type and kind.
+fn event_field(name: &str, ty: &str, kind: EventFieldKind) -> EventField {
+    EventField { name: name.into(), ty: ty.into(), kind }
+}
+
+/// Returns the ABI of a contract emitting `Transfer` and `Flagged` events.
+fn test_abi() -> Contract {
+    Contract {
+        items: vec![
+            Item::Struct(Struct {
+                name: "core::integer::u256".into(),
+                members: vec![
+                    StructMember { name: "low".into(), ty: "core::integer::u128".into() },
+                    StructMember { name: "high".into(), ty: "core::integer::u128".into() },
+                ],
+            }),
+            Item::Enum(Enum {
+                name: "core::bool".into(),
+                variants: vec![
+                    EnumVariant { name: "False".into(), ty: "()".into() },
+                    EnumVariant { name: "True".into(), ty: "()".into() },
+                ],
+            }),
+            Item::Event(Event {
+                name: "test::Transfer".into(),
+                kind: EventKind::Struct {
+                    members: vec![
+                        event_field("from", "core::felt252", EventFieldKind::KeySerde),
+                        event_field("to", "core::felt252", EventFieldKind::DataSerde),
+                        event_field("value", "core::integer::u256", EventFieldKind::DataSerde),
+                    ],
+                },
+            }),
+            Item::Event(Event {
+                name: "test::Flagged".into(),
+                kind: EventKind::Struct {
+                    members: vec![
+                        event_field("flag", "core::bool", EventFieldKind::DataSerde),
+                        event_field(
+                            "ids",
+                            "core::array::Array::<(core::felt252, core::integer::u8)>",
+                            EventFieldKind::DataSerde,
+                        ),
+                    ],
+                },
+            }),
+            Item::Event(Event {
+                name: "test::Event".into(),
+                kind: EventKind::Enum {
+                    variants: vec![
+                        event_field("Transfer", "test::Transfer", EventFieldKind::Nested),
+                        event_field("Flagged", "test::Flagged", EventFieldKind::Nested),
+                    ],
+                },
+            }),
+        ],
+    }
+}
+
+/// Returns the key selecting the variant `name` of the contract's event.
+fn selector(name: &str) -> Felt252 {
+    Felt252::from(starknet_keccak(name.as_bytes()))
+}
+
+#[test_case(
+    vec![selector("Transfer"), Felt252::from_bytes_be(b"alice")],
+    vec![Felt252::from(2), Felt252::from(5), Felt252::from(1)],
+    Some(
+        "test::Event::Transfer(test::Transfer { from: 418430673765 ('alice'), to: 2, \
+         value: 340282366920938463463374607431768211461 })"
+    );
+    "struct event"
+)]
+#[test_case(
+    vec![selector("Flagged")],
+    [1, 2, 3, 4, 5, 6].map(Felt252::from).to_vec(),
+    Some("test::Event::Flagged(test::Flagged { flag: core::bool::True, ids: [(3, 4), (5, 6)] })");
+    "enum and array members"
+)]
+#[test_case(
+    vec![selector("Flagged")],
+    [1, 2, 3].map(Felt252::from).to_vec(),
+    None;
+    "missing data"
+)]
+#[test_case(
+    vec![selector("Transfer"), Felt252::from(1)],
+    [2, 3, 4, 5].map(Felt252::from).to_vec(),
+    None;
+    "extra data"
+)]
+#[test_case(vec![selector("Unknown")], vec![], None; "unknown variant")]
+fn test_decode_event(keys: Vec<Felt252>, data: Vec<Felt252>, expected: Option<&str>) {
+    let abi = test_abi();
+    assert_eq!(AbiDecoder::new(&abi).decode_event(&keys, &data).as_deref(), expected);
+}
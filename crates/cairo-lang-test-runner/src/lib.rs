@@ -1,6 +1,7 @@
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
+use abi_decoder::AbiDecoder;
 use anyhow::{bail, Context, Result};
 use cairo_felt::Felt252;
 use cairo_lang_compiler::db::RootDatabase;
@@ -13,7 +14,7 @@ use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_runner::short_string::as_cairo_short_string;
-use cairo_lang_runner::{RunResultValue, SierraCasmRunner};
+use cairo_lang_runner::{RunResultValue, SierraCasmRunner, StarknetState};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::functions::GenericFunctionId;
 use cairo_lang_semantic::{ConcreteFunction, FunctionLongId};
@@ -22,9 +23,10 @@ use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
+use cairo_lang_starknet::abi::{AbiBuilder, Contract};
 use cairo_lang_starknet::casm_contract_class::ENTRY_POINT_COST;
 use cairo_lang_starknet::contract::{
-    find_contracts, get_contracts_info, get_module_functions, ContractInfo,
+    find_contracts, get_contracts_info, get_module_functions, get_test_class_hash, ContractInfo,
 };
 use cairo_lang_starknet::plugin::consts::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
 use cairo_lang_starknet::plugin::StarkNetPlugin;
@@ -37,6 +39,7 @@ use test_config::{try_extract_test_config, TestConfig};
 
 use crate::test_config::{PanicExpectation, TestExpectation};
 
+mod abi_decoder;
 pub mod plugin;
 mod test_config;
 
@@ -168,8 +171,22 @@ impl TestRunner {
           .collect_vec();
         let filtered_out = total_tests_count - named_tests.len();
         let contracts_info = get_contracts_info(db, self.main_crate_ids.clone(), &replacer)?;
-        let TestsSummary { passed, failed, ignored, failed_run_results } =
-            run_tests(named_tests, sierra_program, function_set_costs, contracts_info)?;
+        // ABIs are only used for displaying failures, so contracts without a valid ABI are
+        // skipped.
+        let contracts_abis = find_contracts(db, &self.main_crate_ids)
+            .iter()
+            .filter_map(|contract| {
+                let abi = AbiBuilder::submodule_as_contract_abi(db, contract.submodule_id).ok()?;
+                Some((get_test_class_hash(db, contract), abi))
+            })
+            .collect();
+        let TestsSummary { passed, failed, ignored, failed_run_results } = run_tests(
+            named_tests,
+            sierra_program,
+            function_set_costs,
+            contracts_info,
+            contracts_abis,
+        )?;
         if failed.is_empty() {
             println!(
                 "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
@@ -183,7 +200,7 @@ impl TestRunner {
             println!("failures:");
             for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
                 print!("   {failure} - ");
-                match run_result {
+                match run_result.value {
                     RunResultValue::Success(_) => {
                         println!("expected panic but finished successfully.");
                     }
@@ -198,6 +215,9 @@ impl TestRunner {
                         println!("].")
                     }
                 }
+                for event in run_result.events {
+                    println!("      emitted {event}");
+                }
             }
             println!();
             bail!(
@@ -214,16 +234,24 @@ impl TestRunner {
 /// The status of a ran test.
 enum TestStatus {
     Success,
-    Fail(RunResultValue),
+    Fail(FailedRunResult),
     Ignore,
 }
 
+/// The result of a failed test run.
+struct FailedRunResult {
+    /// The value the test run ended with.
+    value: RunResultValue,
+    /// The events emitted during the run and not popped, decoded for display.
+    events: Vec<String>,
+}
+
 /// Summary data of the ran tests.
 pub struct TestsSummary {
     passed: Vec<String>,
     failed: Vec<String>,
     ignored: Vec<String>,
-    failed_run_results: Vec<RunResultValue>,
+    failed_run_results: Vec<FailedRunResult>,
 }
 
 /// Runs the tests and process the results for a summary.
@@ -232,10 +260,11 @@ pub fn run_tests(
     sierra_program: cairo_lang_sierra::program::Program,
     function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
     contracts_info: OrderedHashMap<Felt252, ContractInfo>,
+    contracts_abis: OrderedHashMap<Felt252, Contract>,
 ) -> anyhow::Result<TestsSummary> {
     let runner = SierraCasmRunner::new(
         sierra_program,
-        Some(MetadataComputationConfig { function_set_costs }),
+        Some(MetadataComputationConfig { function_set_costs, ..Default::default() }),
         contracts_info,
     )
     .with_context(|| "Failed setting up runner.")?;
@@ -260,18 +289,34 @@ pub fn run_tests(
                     Default::default(),
                 )
                 .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
+            let fail = |value| {
+                let events = result
+                    .starknet_state
+                    .remaining_events()
+                    .map(|(contract_address, keys, data)| {
+                        decode_event(
+                            &result.starknet_state,
+                            &contracts_abis,
+                            contract_address,
+                            keys,
+                            data,
+                        )
+                    })
+                    .collect();
+                TestStatus::Fail(FailedRunResult { value, events })
+            };
             Ok((
                 name,
                 match &result.value {
                     RunResultValue::Success(_) => match test.expectation {
                         TestExpectation::Success => TestStatus::Success,
-                        TestExpectation::Panics(_) => TestStatus::Fail(result.value),
+                        TestExpectation::Panics(_) => fail(result.value),
                     },
                     RunResultValue::Panic(value) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Fail(result.value),
+                        TestExpectation::Success => fail(result.value),
                         TestExpectation::Panics(panic_expectation) => match panic_expectation {
                             PanicExpectation::Exact(expected) if value != &expected => {
-                                TestStatus::Fail(result.value)
+                                fail(result.value)
                             }
                             _ => TestStatus::Success,
                         },
@@ -306,6 +351,29 @@ pub fn run_tests(
     wrapped_summary.into_inner().unwrap()
 }
 
+/// Decodes an event emitted by the contract at `contract_address` for display, using the ABI of
+/// the contract's class. Falls back to the raw keys and data if the event cannot be decoded.
+fn decode_event(
+    starknet_state: &StarknetState,
+    contracts_abis: &OrderedHashMap<Felt252, Contract>,
+    contract_address: &Felt252,
+    keys: &[Felt252],
+    data: &[Felt252],
+) -> String {
+    let decoded = starknet_state
+        .deployed_class_hash(contract_address)
+        .and_then(|class_hash| contracts_abis.get(class_hash))
+        .and_then(|abi| AbiDecoder::new(abi).decode_event(keys, data));
+    match decoded {
+        Some(decoded) => format!("by {contract_address}: {decoded}"),
+        None => format!(
+            "by {contract_address}: keys: [{}], data: [{}]",
+            keys.iter().join(", "),
+            data.iter().join(", ")
+        ),
+    }
+}
+
 /// Finds the tests in the requested crates.
 fn find_all_tests(
     db: &dyn SemanticGroup,
@@ -322,7 +390,13 @@ fn find_all_tests(
               module_items.iter().filter_map(|item| {
                   let ModuleItemId::FreeFunction(func_id) = item else { return None };
                   let Ok(attrs) = db.function_with_body_attributes(FunctionWithBodyId::Free(*func_id)) else { return None };
-                  Some((*func_id, try_extract_test_config(db.upcast(), attrs).unwrap()?))
+                  let test_config = try_extract_test_config(db.upcast(), attrs).unwrap()?;
+                  // Tests with parameters are run through the tests generated for their cases.
+                  let Ok(signature) = db.free_function_signature(*func_id) else { return None };
+                  if !signature.params.is_empty() {
+                      return None;
+                  }
+                  Some((*func_id, test_config))
               }),
           );
         }
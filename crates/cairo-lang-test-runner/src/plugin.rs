@@ -1,30 +1,57 @@
 use std::sync::Arc;
 
-use cairo_lang_defs::plugin::{MacroPlugin, PluginResult};
-use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin};
-use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
-use cairo_lang_syntax::node::ast;
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
+use cairo_lang_syntax::attribute::structured::{
+    Attribute, AttributeArgVariant, AttributeListStructurize,
+};
 use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use itertools::Itertools;
 
 use crate::test_config::try_extract_test_config;
 
-/// Plugin to create diagnostics for tests attributes.
+#[cfg(test)]
+#[path = "plugin_test.rs"]
+mod test;
+
+/// The attribute marking a function setting up the value of the test parameters of the same name.
+pub const FIXTURE_ATTR: &str = "fixture";
+/// The attribute adding a case of a test with parameters, with the arguments of the case.
+pub const TEST_CASE_ATTR: &str = "test_case";
+
+/// Plugin to create diagnostics for tests attributes, and to expand tests with parameters.
+///
+/// A test with parameters is not run directly. Instead, a test is generated for each of its
+/// `#[test_case(...)]` attributes, passing the arguments of the case - either by order or by the
+/// name of the parameter. Parameters without an argument are set up by calling the `#[fixture]`
+/// function of the same name, so tests may share their setup code.
 #[derive(Debug, Default)]
 #[non_exhaustive]
 pub struct TestPlugin;
 
 impl MacroPlugin for TestPlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
-        PluginResult {
-            code: None,
-            diagnostics: if let ast::Item::FreeFunction(free_func_ast) = item_ast {
-                try_extract_test_config(db, free_func_ast.attributes(db).structurize(db)).err()
-            } else {
-                None
+        let ast::Item::FreeFunction(free_func_ast) = item_ast else {
+            return PluginResult::default();
+        };
+        let attrs = free_func_ast.attributes(db).structurize(db);
+        let params = free_func_ast.declaration(db).signature(db).parameters(db).elements(db);
+        let mut diagnostics = validate_fixture(&attrs, &params);
+        let mut code = None;
+        match try_extract_test_config(db, attrs.clone()) {
+            Ok(Some(_)) if diagnostics.is_empty() => {
+                match generate_test_cases(db, &free_func_ast, &attrs, &params) {
+                    Ok(test_cases) => code = test_cases,
+                    Err(test_case_diagnostics) => diagnostics.extend(test_case_diagnostics),
+                }
             }
-            .unwrap_or_default(),
-            remove_original_item: false,
+            Ok(_) => {}
+            Err(test_diagnostics) => diagnostics.extend(test_diagnostics),
         }
+        PluginResult { code, diagnostics, remove_original_item: false }
     }
 }
 impl AsDynMacroPlugin for TestPlugin {
@@ -36,3 +63,166 @@ impl AsDynMacroPlugin for TestPlugin {
     }
 }
 impl SemanticPlugin for TestPlugin {}
+
+/// Returns the diagnostics of the `#[fixture]` attribute of a function, if it has one.
+fn validate_fixture(attrs: &[Attribute], params: &[ast::Param]) -> Vec<PluginDiagnostic> {
+    let Some(attr) = attrs.iter().find(|attr| attr.id.as_str() == FIXTURE_ATTR) else {
+        return vec![];
+    };
+    let mut diagnostics = vec![];
+    let mut add_diagnostic = |message: &str| {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: attr.id_stable_ptr.untyped(),
+            message: message.into(),
+            lint_id: None,
+        })
+    };
+    if !attr.args.is_empty() {
+        add_diagnostic("Attribute should not have arguments.");
+    }
+    if attrs.iter().any(|attr| attr.id.as_str() == "test") {
+        add_diagnostic("A fixture may not be a test.");
+    }
+    if !params.is_empty() {
+        add_diagnostic("A fixture should not have parameters.");
+    }
+    diagnostics
+}
+
+/// Generates the tests running the cases of a test with parameters. Returns `None` for tests
+/// without parameters, which are run directly.
+fn generate_test_cases(
+    db: &dyn SyntaxGroup,
+    func_ast: &ast::FunctionWithBody,
+    attrs: &[Attribute],
+    params: &[ast::Param],
+) -> Result<Option<PluginGeneratedFile>, Vec<PluginDiagnostic>> {
+    let test_cases = attrs.iter().filter(|attr| attr.id.as_str() == TEST_CASE_ATTR).collect_vec();
+    if params.is_empty() {
+        return if test_cases.is_empty() {
+            Ok(None)
+        } else {
+            Err(test_cases
+                .into_iter()
+                .map(|attr| PluginDiagnostic {
+                    stable_ptr: attr.id_stable_ptr.untyped(),
+                    message: "Test cases require the test to have parameters.".into(),
+                    lint_id: None,
+                })
+                .collect())
+        };
+    }
+    let name = func_ast.declaration(db).name(db).text(db);
+    // The attributes of the test, other than its cases, apply to each of the generated tests.
+    let test_attrs = func_ast
+        .attributes(db)
+        .elements(db)
+        .into_iter()
+        .filter(|attr| attr.attr(db).as_syntax_node().get_text_without_trivia(db) != TEST_CASE_ATTR)
+        .map(|attr| attr.as_syntax_node().get_text_without_trivia(db))
+        .collect_vec();
+    let mut diagnostics = vec![];
+    let mut content = String::new();
+    let cases = if test_cases.is_empty() {
+        vec![(format!("{name}_with_fixtures"), None)]
+    } else {
+        test_cases
+            .into_iter()
+            .enumerate()
+            .map(|(index, attr)| (format!("{name}_case_{index}"), Some(attr)))
+            .collect()
+    };
+    for (case_name, attr) in cases {
+        let values = match attr.map(|attr| case_values(db, attr, params)) {
+            Some(Ok(values)) => values,
+            Some(Err(case_diagnostics)) => {
+                diagnostics.extend(case_diagnostics);
+                continue;
+            }
+            None => vec![None; params.len()],
+        };
+        for test_attr in &test_attrs {
+            content.push_str(&format!("{test_attr}\n"));
+        }
+        content.push_str(&format!("fn {case_name}() {{\n"));
+        let mut args = vec![];
+        for (param, value) in params.iter().zip(values) {
+            let param_name = param.name(db).text(db);
+            let ty = param.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db);
+            let value = value.unwrap_or_else(|| format!("{param_name}()"));
+            if matches!(&param.modifiers(db).elements(db)[..], [ast::Modifier::Ref(_)]) {
+                content.push_str(&format!("    let mut {param_name}: {ty} = {value};\n"));
+                args.push(format!("ref {param_name}"));
+            } else {
+                content.push_str(&format!("    let {param_name}: {ty} = {value};\n"));
+                args.push(param_name.to_string());
+            }
+        }
+        content.push_str(&format!("    {name}({});\n}}\n", args.join(", ")));
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+    Ok(Some(PluginGeneratedFile {
+        name: "test_cases".into(),
+        content,
+        aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
+    }))
+}
+
+/// Returns the code of the argument given to each of the parameters by a `#[test_case(...)]`
+/// attribute, if any.
+fn case_values(
+    db: &dyn SyntaxGroup,
+    attr: &Attribute,
+    params: &[ast::Param],
+) -> Result<Vec<Option<String>>, Vec<PluginDiagnostic>> {
+    let param_names = params.iter().map(|param| param.name(db).text(db)).collect_vec();
+    let mut values = vec![None; params.len()];
+    let mut diagnostics = vec![];
+    let mut next_unnamed_index = 0;
+    for arg in &attr.args {
+        let mut add_diagnostic = |message: String| {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: arg.arg_stable_ptr.untyped(),
+                message,
+                lint_id: None,
+            })
+        };
+        let (index, value) = match &arg.variant {
+            AttributeArgVariant::Unnamed { value, .. } => {
+                if next_unnamed_index == params.len() {
+                    add_diagnostic("Test case has more arguments than test parameters.".into());
+                    continue;
+                }
+                next_unnamed_index += 1;
+                (next_unnamed_index - 1, value)
+            }
+            AttributeArgVariant::Named { name, value, .. } => {
+                let Some(index) = param_names.iter().position(|param_name| param_name == name)
+                else {
+                    add_diagnostic(format!("Test has no parameter named `{name}`."));
+                    continue;
+                };
+                (index, value)
+            }
+            AttributeArgVariant::FieldInitShorthand { .. } => {
+                add_diagnostic("Test case arguments should be values or named values.".into());
+                continue;
+            }
+        };
+        if values[index].is_some() {
+            add_diagnostic(format!(
+                "Test case has multiple arguments for parameter `{}`.",
+                param_names[index]
+            ));
+            continue;
+        }
+        values[index] = Some(value.as_syntax_node().get_text_without_trivia(db));
+    }
+    if diagnostics.is_empty() {
+        Ok(values)
+    } else {
+        Err(diagnostics)
+    }
+}
@@ -0,0 +1,128 @@
+use cairo_lang_defs::plugin::MacroPlugin;
+use cairo_lang_parser::test_utils::create_virtual_file;
+use cairo_lang_parser::utils::{get_syntax_file_and_diagnostics, SimpleParserDatabase};
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::TestPlugin;
+
+/// Runs the plugin on the items of the given code, and returns the generated code along with the
+/// messages of the diagnostics.
+fn expand(code: &str) -> (String, Vec<String>) {
+    let db = &SimpleParserDatabase::default();
+    let file_id = create_virtual_file(db, "lib.cairo", code);
+    let (syntax_file, diagnostics) = get_syntax_file_and_diagnostics(db, file_id, code);
+    assert!(diagnostics.is_empty(), "Unexpected diagnostics:\n{}", diagnostics.format(db));
+    let mut generated = String::new();
+    let mut messages = vec![];
+    for item in syntax_file.items(db).elements(db) {
+        let result = TestPlugin::default().generate_code(db, item);
+        if let Some(file) = result.code {
+            generated.push_str(&file.content);
+        }
+        messages.extend(result.diagnostics.into_iter().map(|diagnostic| diagnostic.message));
+    }
+    (generated, messages)
+}
+
+#[test]
+fn test_expand_test_cases() {
+    let (generated, messages) = expand(indoc! {"
+        #[fixture]
+        fn state() -> felt252 {
+            5
+        }
+
+        #[test]
+        #[available_gas(1000)]
+        #[test_case(1, 2)]
+        #[test_case(b: 4, a: 3)]
+        fn test_add(a: felt252, b: felt252, ref state: felt252) {
+            state += a + b;
+        }
+    "});
+
+    assert_eq!(
+        generated,
+        indoc! {"
+            #[test]
+            #[available_gas(1000)]
+            fn test_add_case_0() {
+                let a: felt252 = 1;
+                let b: felt252 = 2;
+                let mut state: felt252 = state();
+                test_add(a, b, ref state);
+            }
+            #[test]
+            #[available_gas(1000)]
+            fn test_add_case_1() {
+                let a: felt252 = 3;
+                let b: felt252 = 4;
+                let mut state: felt252 = state();
+                test_add(a, b, ref state);
+            }
+        "}
+    );
+    assert!(messages.is_empty(), "Unexpected diagnostics: {messages:?}");
+}
+
+#[test]
+fn test_expand_fixtures() {
+    let (generated, messages) = expand(indoc! {"
+        #[test]
+        fn test_no_params() {}
+
+        #[test]
+        #[should_panic]
+        fn test_with_fixture(state: felt252) {}
+    "});
+
+    assert_eq!(
+        generated,
+        indoc! {"
+            #[test]
+            #[should_panic]
+            fn test_with_fixture_with_fixtures() {
+                let state: felt252 = state();
+                test_with_fixture(state);
+            }
+        "}
+    );
+    assert!(messages.is_empty(), "Unexpected diagnostics: {messages:?}");
+}
+
+#[test]
+fn test_expand_diagnostics() {
+    let (generated, messages) = expand(indoc! {"
+        #[fixture]
+        #[test]
+        fn bad_fixture(x: felt252) {}
+
+        #[test]
+        #[test_case(1)]
+        fn test_no_params() {}
+
+        #[test]
+        #[test_case(1, 2)]
+        #[test_case(c: 1)]
+        #[test_case(1, a: 2)]
+        fn test_one_param(a: felt252) {}
+
+        #[test_case(1)]
+        fn not_a_test(a: felt252) {}
+    "});
+
+    assert_eq!(generated, "");
+    assert_eq!(
+        messages,
+        vec![
+            "A fixture may not be a test.",
+            "A fixture should not have parameters.",
+            "Test cases require the test to have parameters.",
+            "Test case has more arguments than test parameters.",
+            "Test has no parameter named `c`.",
+            "Test case has multiple arguments for parameter `a`.",
+            "Attribute should only appear on tests.",
+        ]
+    );
+}
@@ -4,6 +4,7 @@ use cairo_lang_syntax::attribute::structured::{Attribute, AttributeArg, Attribut
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_utils::OptionHelper;
+use itertools::chain;
 use num_traits::ToPrimitive;
 
 /// Expectation for a panic case.
@@ -42,19 +43,25 @@ pub fn try_extract_test_config(
     let ignore_attr = attrs.iter().find(|attr| attr.id.as_str() == "ignore");
     let available_gas_attr = attrs.iter().find(|attr| attr.id.as_str() == "available_gas");
     let should_panic_attr = attrs.iter().find(|attr| attr.id.as_str() == "should_panic");
+    let test_case_attrs = attrs.iter().filter(|attr| attr.id.as_str() == "test_case");
     let mut diagnostics = vec![];
     if let Some(attr) = test_attr {
         if !attr.args.is_empty() {
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.id_stable_ptr.untyped(),
                 message: "Attribute should not have arguments.".into(),
+                lint_id: None,
             });
         }
     } else {
-        for attr in [ignore_attr, available_gas_attr, should_panic_attr].into_iter().flatten() {
+        for attr in chain!(
+            [ignore_attr, available_gas_attr, should_panic_attr].into_iter().flatten(),
+            test_case_attrs
+        ) {
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.id_stable_ptr.untyped(),
                 message: "Attribute should only appear on tests.".into(),
+                lint_id: None,
             });
         }
     }
@@ -63,6 +70,7 @@ pub fn try_extract_test_config(
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.id_stable_ptr.untyped(),
                 message: "Attribute should not have arguments.".into(),
+                lint_id: None,
             });
         }
         true
@@ -82,6 +90,7 @@ pub fn try_extract_test_config(
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.id_stable_ptr.untyped(),
                 message: "Attribute should have a single value argument.".into(),
+                lint_id: None,
             });
             None
         }
@@ -100,6 +109,7 @@ pub fn try_extract_test_config(
                         message: "Expected panic must be of the form `expected: <tuple of \
                                   felt252s>`."
                             .into(),
+                        lint_id: None,
                     });
                 }),
             )
@@ -11,7 +11,9 @@ mod fib_struct;
 mod fib_u128_checked;
 mod fib_u128;
 mod fib_unary;
+mod oracle_isqrt;
 mod hash_chain_gas;
 mod hash_chain;
 mod pedersen_test;
 mod testing;
+mod verified_hints;
@@ -0,0 +1,11 @@
+#[oracle]
+extern fn isqrt(value: u128) -> u128;
+
+// Computes the integer square root of `value` by an oracle, verifying its result.
+fn oracle_isqrt(value: u128) -> u128 {
+    let root = isqrt(value);
+    let square = root * root;
+    assert(square <= value, 'root too large');
+    assert(value - square <= root + root, 'root too small');
+    root
+}
@@ -0,0 +1,13 @@
+use option::OptionTrait;
+
+// Uses libfuncs whose results are guessed by hints and verified by the code following them.
+fn verified_hints(a: u128, b: u128, x: felt252) -> (u64, u128, u128, u128, u128, felt252) {
+    let sqrt = integer::u128_sqrt(a);
+    let (q, r) = integer::u128_safe_divmod(a, integer::u128_as_non_zero(b));
+    let (q256, r256) = integer::u256_safe_div_rem(
+        u256 { low: a, high: b }, integer::u256_as_non_zero(u256 { low: b, high: 0 })
+    );
+    let p = ec::ec_mul(ec::ec_point_from_x(x).unwrap(), 3);
+    let (px, _) = ec::ec_point_unwrap(ec::ec_point_non_zero(p));
+    (sqrt, q, r, q256.low, r256.low, px)
+}
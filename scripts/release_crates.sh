@@ -22,11 +22,15 @@ cargo publish --package cairo-lang-plugins && \
 cargo publish --package cairo-lang-lowering && \
 cargo publish --package cairo-lang-sierra-generator && \
 cargo publish --package cairo-lang-compiler && \
+cargo publish --package cairo-lang-daemon && \
 cargo publish --package cairo-lang-starknet && \
+cargo publish --package cairo-lang-capi && \
+cargo publish --package cairo-lang-python && \
 cargo publish --package cairo-lang-runner && \
 cargo publish --package cairo-lang-test-runner && \
 cargo publish --package cairo-lang-language-server && \
 cargo publish --package cairo-compile && \
+cargo publish --package cairo-compile-daemon && \
 cargo publish --package cairo-format && \
 cargo publish --package cairo-language-server && \
 cargo publish --package cairo-run && \
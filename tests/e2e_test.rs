@@ -9,12 +9,12 @@ use cairo_lang_filesystem::ids::FlagId;
 use cairo_lang_semantic::test_utils::setup_test_module;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
-use cairo_lang_sierra_to_casm::test_utils::build_metadata;
+use cairo_lang_sierra_to_casm::compiler::VmVersion;
+use cairo_lang_sierra_to_casm::test_utils::compile_to_casm_outputs;
 use cairo_lang_test_utils::parse_test_file::TestFileRunner;
 use cairo_lang_test_utils::test_lock;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
-use itertools::Itertools;
 use once_cell::sync::Lazy;
 
 /// Salsa database configured to find the corelib, when reused by different tests should be able to
@@ -36,12 +36,15 @@ cairo_lang_test_utils::test_file_test_with_runner!(
     "e2e_test_data/libfuncs",
     {
         array: "array",
+        bitfield: "bitfield",
         bitwise: "bitwise",
         bool: "bool",
         box_: "box",
         builtin_costs: "builtin_costs",
         casts: "casts",
         ec: "ec",
+        ecdsa: "ecdsa",
+        encoding: "encoding",
         enum_: "enum",
         enum_snapshot: "enum_snapshot",
         felt252_dict: "felt252_dict",
@@ -51,8 +54,11 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         i32: "i32",
         i64: "i64",
         i8: "i8",
+        inline_casm: "inline_casm",
+        merkle: "merkle",
         nullable: "nullable",
         poseidon: "poseidon",
+        rlp: "rlp",
         snapshot: "snapshot",
         u128: "u128",
         u16: "u16",
@@ -88,41 +94,95 @@ cairo_lang_test_utils::test_file_test_with_runner!(
     SmallE2ETestRunner
 );
 
+/// A configuration for compiling the code of an e2e test.
+///
+/// Tests are compiled with the configuration of their runner, and additionally with every
+/// configuration listed in their optional `configs` tag. Each line of the tag describes a
+/// configuration by the switches it applies on top of the configuration of the runner - e.g.
+/// `no_inlining no_gas` - and the outputs for it are expected in tags suffixed by the description -
+/// e.g. `casm[no_inlining no_gas]`.
+#[derive(Clone, Copy, Debug)]
+struct E2eTestConfig {
+    /// Whether to inline the functions that should be inlined.
+    inlining: bool,
+    /// Whether to automatically add `withdraw_gas` calls in code cycles.
+    add_withdraw_gas: bool,
+    /// Whether to calculate the gas usage of the code, and check it when compiling to casm.
+    gas: bool,
+    /// The version of the VM to compile the code for.
+    vm_version: VmVersion,
+}
+impl Default for E2eTestConfig {
+    fn default() -> Self {
+        Self { inlining: true, add_withdraw_gas: true, gas: true, vm_version: VmVersion::default() }
+    }
+}
+impl E2eTestConfig {
+    /// Returns this configuration with the switches of the given description applied.
+    fn with_switches(mut self, description: &str) -> Self {
+        for switch in description.split_whitespace() {
+            match switch {
+                "no_inlining" => self.inlining = false,
+                "no_add_withdraw_gas" => self.add_withdraw_gas = false,
+                "no_gas" => self.gas = false,
+                "vm_v0" => self.vm_version = VmVersion::V0,
+                _ => panic!("Unknown e2e test config switch: `{switch}`."),
+            }
+        }
+        self
+    }
+}
+
+/// Runs an e2e test with the given configuration, and with the configurations listed in its
+/// `configs` tag, as described in [E2eTestConfig].
+fn run_e2e_test(
+    inputs: &OrderedHashMap<String, String>,
+    config: E2eTestConfig,
+) -> OrderedHashMap<String, String> {
+    let mut outputs = compile_e2e_test(inputs["cairo"].as_str(), config);
+    for description in inputs.get("configs").into_iter().flat_map(|configs| configs.lines()) {
+        let description = description.trim();
+        let config_outputs =
+            compile_e2e_test(inputs["cairo"].as_str(), config.with_switches(description));
+        outputs.extend(
+            config_outputs.into_iter().map(|(tag, value)| (format!("{tag}[{description}]"), value)),
+        );
+    }
+    outputs
+}
+
+/// Compiles the given code with the given configuration, returning the e2e test outputs - the
+/// `sierra_code`, the `casm` and the `function_costs` (if gas is calculated).
+fn compile_e2e_test(cairo_code: &str, config: E2eTestConfig) -> OrderedHashMap<String, String> {
+    let mut locked_db = test_lock(&SHARED_DB);
+    // The flags are set on every run, as the database is shared between the tests.
+    let add_withdraw_gas_flag_id = FlagId::new(locked_db.snapshot().upcast(), "add_withdraw_gas");
+    locked_db.set_flag(
+        add_withdraw_gas_flag_id,
+        Some(Arc::new(Flag::AddWithdrawGas(config.add_withdraw_gas))),
+    );
+    let inlining_flag_id = FlagId::new(locked_db.snapshot().upcast(), "inlining");
+    locked_db.set_flag(inlining_flag_id, Some(Arc::new(Flag::Inlining(config.inlining))));
+    // Parse code and create semantic model.
+    let test_module = setup_test_module(locked_db.deref_mut(), cairo_code).unwrap();
+    let db = locked_db.snapshot();
+    DiagnosticsReporter::stderr().ensure(&db).unwrap();
+
+    // Compile to Sierra.
+    let sierra_program = db.get_sierra_program(vec![test_module.crate_id]).unwrap();
+    let sierra_program = replace_sierra_ids_in_program(&db, &sierra_program);
+
+    // Compile to casm.
+    let mut outputs = compile_to_casm_outputs(&sierra_program, config.gas, config.vm_version);
+    outputs.insert("sierra_code".into(), sierra_program.to_string());
+    outputs
+}
+
 #[derive(Default)]
 struct SmallE2ETestRunner;
 impl TestFileRunner for SmallE2ETestRunner {
     fn run(&mut self, inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
-        let mut locked_db = test_lock(&SHARED_DB);
-        // Parse code and create semantic model.
-        let test_module =
-            setup_test_module(locked_db.deref_mut(), inputs["cairo"].as_str()).unwrap();
-        let db = locked_db.snapshot();
-        DiagnosticsReporter::stderr().ensure(&db).unwrap();
-
-        // Compile to Sierra.
-        let sierra_program = db.get_sierra_program(vec![test_module.crate_id]).unwrap();
-        let sierra_program = replace_sierra_ids_in_program(&db, &sierra_program);
-        let sierra_program_str = sierra_program.to_string();
-
-        // Compute the metadata.
-        let metadata = build_metadata(&sierra_program, true);
-        let function_costs_str = metadata
-            .gas_info
-            .function_costs
-            .iter()
-            .map(|(func_id, cost)| format!("{func_id}: {cost:?}"))
-            .join("\n");
-
-        // Compile to casm.
-        let casm = cairo_lang_sierra_to_casm::compiler::compile(&sierra_program, &metadata, true)
-            .unwrap()
-            .to_string();
-
-        OrderedHashMap::from([
-            ("casm".into(), casm),
-            ("function_costs".into(), function_costs_str),
-            ("sierra_code".into(), sierra_program_str),
-        ])
+        run_e2e_test(inputs, E2eTestConfig::default())
     }
 }
 
@@ -130,39 +190,6 @@ impl TestFileRunner for SmallE2ETestRunner {
 struct SmallE2ETestRunnerSkipAddGas;
 impl TestFileRunner for SmallE2ETestRunnerSkipAddGas {
     fn run(&mut self, inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
-        let mut locked_db = test_lock(&SHARED_DB);
-        let add_withdraw_gas_flag_id =
-            FlagId::new(locked_db.snapshot().upcast(), "add_withdraw_gas");
-        locked_db.set_flag(add_withdraw_gas_flag_id, Some(Arc::new(Flag::AddWithdrawGas(false))));
-        // Parse code and create semantic model.
-        let test_module =
-            setup_test_module(locked_db.deref_mut(), inputs["cairo"].as_str()).unwrap();
-        let db = locked_db.snapshot();
-        DiagnosticsReporter::stderr().ensure(&db).unwrap();
-
-        // Compile to Sierra.
-        let sierra_program = db.get_sierra_program(vec![test_module.crate_id]).unwrap();
-        let sierra_program = replace_sierra_ids_in_program(&db, &sierra_program);
-        let sierra_program_str = sierra_program.to_string();
-
-        // Compute the metadata.
-        let metadata = build_metadata(&sierra_program, true);
-        let function_costs_str = metadata
-            .gas_info
-            .function_costs
-            .iter()
-            .map(|(func_id, cost)| format!("{func_id}: {cost:?}"))
-            .join("\n");
-
-        // Compile to casm.
-        let casm = cairo_lang_sierra_to_casm::compiler::compile(&sierra_program, &metadata, true)
-            .unwrap()
-            .to_string();
-
-        OrderedHashMap::from([
-            ("casm".into(), casm),
-            ("function_costs".into(), function_costs_str),
-            ("sierra_code".into(), sierra_program_str),
-        ])
+        run_e2e_test(inputs, E2eTestConfig::default().with_switches("no_add_withdraw_gas"))
     }
 }
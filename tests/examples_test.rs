@@ -3,6 +3,7 @@ use std::sync::{Arc, Mutex};
 
 use assert_matches::assert_matches;
 use cairo_felt::{felt_str as felt252_str, Felt252};
+use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
@@ -11,12 +12,18 @@ use cairo_lang_filesystem::db::FilesGroupEx;
 use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::{CrateId, FlagId};
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
-use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST};
+use cairo_lang_runner::entry_code::{EntryCodeGenerator, StarknetEntryCodeGenerator};
+use cairo_lang_runner::oracle::Oracles;
+use cairo_lang_runner::{
+    Arg, RunResultValue, RunnerError, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST,
+};
+use cairo_lang_sierra::program::Function;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_lang_sierra_to_casm::test_utils::build_metadata;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
 use cairo_lang_utils::{extract_matches, Upcast};
+use cairo_vm::serde::deserialize_program::BuiltinName;
 use itertools::Itertools;
 use rstest::{fixture, rstest};
 
@@ -102,6 +109,7 @@ fn checked_compile_to_sierra(
 #[case::hash_chain_gas("hash_chain_gas")]
 #[case::pedersen_test("pedersen_test")]
 #[case::testing("testing")]
+#[case::verified_hints("verified_hints")]
 fn cairo_to_sierra(#[case] name: &str, example_dir_data: &ExampleDirData) {
     compare_contents_or_fix(
         name,
@@ -139,6 +147,7 @@ fn cairo_to_sierra_auto_gas(#[case] name: &str, example_dir_data: &ExampleDirDat
 #[case::hash_chain_gas("hash_chain_gas", true)]
 #[case::pedersen_test("pedersen_test", false)]
 #[case::testing("testing", false)]
+#[case::verified_hints("verified_hints", false)]
 fn cairo_to_casm(
     #[case] name: &str,
     #[case] enable_gas_checks: bool,
@@ -270,6 +279,13 @@ fn run_function(
     RunResultValue::Success(vec![felt252_str!(
         "2dca1ad81a6107a9ef68c69f791bcdbda1df257aab76bd43ded73d96ed6227d", 16)]))]
 #[case::testing("testing", &[], None, None, RunResultValue::Success(vec![]))]
+#[case::verified_hints(
+    "verified_hints",
+    &[1000, 7, 2].map(Felt252::from), None, None,
+    RunResultValue::Success(
+        [31, 142, 6, 142, 6].map(Felt252::from).into_iter().chain([felt252_str!(
+            "2487022122646670935820827916939009759173528418533294120424274553965672711259"
+        )]).collect()))]
 fn run_function_test(
     #[case] name: &str,
     #[case] params: &[Felt252],
@@ -328,3 +344,104 @@ fn run_fib_array_len(#[case] n: usize, #[case] last: usize, example_dir_data: &E
         [_, _, actual_last, actual_len] if actual_last == &Felt252::from(last) && actual_len == &Felt252::from(n)
     );
 }
+
+/// An entry code generator running the function with fixed arguments, regardless of the given
+/// ones.
+struct FixedArgsEntryCodeGenerator(Vec<Felt252>);
+impl EntryCodeGenerator for FixedArgsEntryCodeGenerator {
+    fn create_entry_code(
+        &self,
+        runner: &SierraCasmRunner,
+        func: &Function,
+        _args: &[Arg],
+        initial_gas: usize,
+    ) -> Result<(Vec<Instruction>, Vec<BuiltinName>), RunnerError> {
+        let args = self.0.iter().cloned().map(Arg::Value).collect_vec();
+        StarknetEntryCodeGenerator.create_entry_code(runner, func, &args, initial_gas)
+    }
+
+    fn create_code_footer(&self, runner: &SierraCasmRunner) -> Vec<Instruction> {
+        StarknetEntryCodeGenerator.create_code_footer(runner)
+    }
+}
+
+#[rstest]
+fn run_with_custom_entry_code(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("fib", example_dir_data, false),
+        None,
+        Default::default(),
+    )
+    .expect("Failed setting up runner.")
+    .with_entry_code_generator(Box::new(FixedArgsEntryCodeGenerator(
+        [1, 1, 7].map(Felt252::from).to_vec(),
+    )));
+    let result = runner
+        .run_function_with_starknet_context(
+            runner.find_function("").expect("Failed finding the function."),
+            &[],
+            None,
+            Default::default(),
+        )
+        .expect("Failed running the function.");
+    assert_eq!(result.value, RunResultValue::Success(vec![Felt252::from(21)]));
+}
+
+/// Tests running a function calling an oracle, which verifies the result of the oracle.
+#[rstest]
+#[case::valid_root(31, RunResultValue::Success(vec![Felt252::from(31)]))]
+#[case::root_too_large(32, RunResultValue::Panic(vec![Felt252::from_bytes_be(b"root too large")]))]
+#[case::root_too_small(30, RunResultValue::Panic(vec![Felt252::from_bytes_be(b"root too small")]))]
+fn run_with_oracle(
+    #[case] oracle_root: usize,
+    #[case] expected_result: RunResultValue,
+    example_dir_data: &ExampleDirData,
+) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("oracle_isqrt", example_dir_data, false),
+        None,
+        Default::default(),
+    )
+    .expect("Failed setting up runner.")
+    .with_oracle_handler(Box::new(
+        Oracles::default().with("isqrt", move |_input| Ok(vec![Felt252::from(oracle_root)])),
+    ));
+    let result = runner
+        .run_function_with_starknet_context(
+            runner.find_function("oracle_isqrt").expect("Failed finding the function."),
+            &[Arg::Value(Felt252::from(1000))],
+            None,
+            Default::default(),
+        )
+        .expect("Failed running the function.");
+    assert_eq!(result.value, expected_result);
+}
+
+/// Tests that perturbing the results of the hints of a function is rejected by the code verifying
+/// them.
+#[rstest]
+#[case::fib_u128_checked("fib_u128_checked", &[1, 1, 10].map(Felt252::from), None)]
+#[case::fib_u128("fib_u128", &[1, 1, 10].map(Felt252::from), None)]
+#[case::hash_chain_gas("hash_chain_gas", &[3].map(Felt252::from), Some(100000))]
+#[case::verified_hints("verified_hints", &[1000, 7, 2].map(Felt252::from), None)]
+fn hints_soundness_test(
+    #[case] name: &str,
+    #[case] params: &[Felt252],
+    #[case] available_gas: Option<usize>,
+    example_dir_data: &ExampleDirData,
+) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra(name, example_dir_data, false),
+        if available_gas.is_some() { Some(Default::default()) } else { None },
+        Default::default(),
+    )
+    .expect("Failed setting up runner.");
+    let unsound_hints = runner
+        .find_unsound_hints(
+            runner.find_function("").expect("Failed finding the function."),
+            &params.iter().cloned().map(Arg::Value).collect_vec(),
+            available_gas,
+        )
+        .expect("Failed running the function.");
+    assert!(unsound_hints.is_empty(), "Accepted perturbed hint results: {unsound_hints:#?}");
+}